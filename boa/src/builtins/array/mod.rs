@@ -15,6 +15,7 @@ mod tests;
 
 use crate::{
     builtins::array::array_iterator::{ArrayIterationKind, ArrayIterator},
+    builtins::iterable::IteratorRecord,
     builtins::BuiltIn,
     builtins::Number,
     object::{ConstructorBuilder, FunctionBuilder, GcObject, ObjectData, PROTOTYPE},
@@ -51,6 +52,16 @@ impl BuiltIn for Array {
             .callable(true)
             .constructable(false)
             .build();
+        context.array_values_function = Some(values_function.clone());
+
+        let get_species = FunctionBuilder::new(context, Self::get_species)
+            .name("get [Symbol.species]")
+            .constructable(false)
+            .callable(true)
+            .build();
+
+        let symbol_unscopables = WellKnownSymbols::unscopables();
+        let unscopables = Self::unscopables_object(context);
 
         let array = ConstructorBuilder::with_standard_object(
             context,
@@ -100,8 +111,27 @@ impl BuiltIn for Array {
         .method(Self::reduce_right, "reduceRight", 2)
         .method(Self::keys, "keys", 0)
         .method(Self::entries, "entries", 0)
+        .method(Self::at, "at", 1)
+        .method(Self::copy_within, "copyWithin", 2)
+        .method(Self::find_last, "findLast", 1)
+        .method(Self::find_last_index, "findLastIndex", 1)
+        .method(Self::sort, "sort", 1)
+        .method(Self::splice, "splice", 2)
+        .property(
+            symbol_unscopables,
+            unscopables,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
         // Static Methods
         .static_method(Self::is_array, "isArray", 1)
+        .static_method(Self::from, "from", 1)
+        .static_method(Self::of, "of", 0)
+        .static_accessor(
+            WellKnownSymbols::species(),
+            Some(get_species),
+            None,
+            Attribute::CONFIGURABLE,
+        )
         .build();
 
         (Self::NAME, array.into(), Self::attribute())
@@ -216,6 +246,94 @@ impl Array {
         array
     }
 
+    /// Creates a new array object using the constructor of `original`.
+    ///
+    /// Subclasses of `Array` and objects with a custom `@@species` get instances of their
+    /// own kind from methods like `map` or `slice`; everything else gets a plain array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arrayspeciescreate
+    pub(crate) fn array_species_create(
+        original: &GcObject,
+        length: usize,
+        context: &mut Context,
+    ) -> Result<GcObject> {
+        // 1. Let isArray be ? IsArray(originalArray).
+        // 2. If isArray is false, return ? ArrayCreate(length).
+        if !Value::from(original.clone()).is_array(context)? {
+            return Self::array_create_checked(length, context);
+        }
+
+        // 3. Let C be ? Get(originalArray, "constructor").
+        let mut c = original.get(&"constructor".into(), original.clone().into(), context)?;
+
+        // 5. If Type(C) is Object, then
+        if let Some(constructor) = c.as_object() {
+            // a. Set C to ? Get(C, @@species).
+            c = constructor.get(
+                &WellKnownSymbols::species().into(),
+                constructor.clone().into(),
+                context,
+            )?;
+            // b. If C is null, set C to undefined.
+            if c.is_null() {
+                c = Value::undefined();
+            }
+        }
+
+        // 6. If C is undefined, return ? ArrayCreate(length).
+        if c.is_undefined() {
+            return Self::array_create_checked(length, context);
+        }
+
+        // 7. If IsConstructor(C) is false, throw a TypeError exception.
+        // 8. Return ? Construct(C, « 𝔽(length) »).
+        match c.as_object() {
+            Some(constructor) if constructor.is_constructable() => {
+                let new = constructor.construct(&[length.into()], c.clone(), context)?;
+                new.as_object().ok_or_else(|| {
+                    context.construct_type_error("species constructor did not return an object")
+                })
+            }
+            _ => {
+                Err(context.construct_type_error("Array species constructor is not a constructor"))
+            }
+        }
+    }
+
+    /// `ArrayCreate` for lengths computed at runtime, throwing a `RangeError` if the length
+    /// doesn't fit in an array.
+    fn array_create_checked(length: usize, context: &mut Context) -> Result<GcObject> {
+        let length = match u32::try_from(length) {
+            Ok(length) if length != u32::MAX => length,
+            _ => return Err(context.construct_range_error("Invalid array length")),
+        };
+        Ok(Self::array_create(length, None, context)
+            .as_object()
+            .expect("'array' should be an object"))
+    }
+
+    /// Creates the result array of `Array.from` and `Array.of`: an instance of `this` if it is
+    /// a constructor, or a plain array otherwise.
+    fn construct_or_array_create(
+        constructor: &Value,
+        length: Option<usize>,
+        context: &mut Context,
+    ) -> Result<GcObject> {
+        match constructor.as_object() {
+            Some(object) if object.is_constructable() => {
+                let args: Vec<Value> = length.into_iter().map(Value::from).collect();
+                let new = object.construct(&args, constructor.clone(), context)?;
+                new.as_object().ok_or_else(|| {
+                    context.construct_type_error("Array constructor did not return an object")
+                })
+            }
+            _ => Self::array_create_checked(length.unwrap_or(0), context),
+        }
+    }
+
     /// Creates a new `Array` instance.
     pub(crate) fn new_array(context: &Context) -> Value {
         let array = Value::new_object(context);
@@ -232,6 +350,32 @@ impl Array {
         array
     }
 
+    /// Creates a new `Array` instance holding the given elements.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createarrayfromlist
+    pub(crate) fn create_array_from_list<I>(elements: I, context: &Context) -> Value
+    where
+        I: IntoIterator<Item = Value>,
+    {
+        let array = Self::new_array(context);
+        let mut length = 0;
+        for (index, element) in elements.into_iter().enumerate() {
+            array.set_property(index, DataDescriptor::new(element, Attribute::all()));
+            length = index + 1;
+        }
+        array.set_property(
+            "length",
+            DataDescriptor::new(
+                length,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+            ),
+        );
+        array
+    }
+
     /// Utility function for creating array objects.
     ///
     /// `array_obj` can be any array with prototype already set (it will be wiped and
@@ -285,6 +429,14 @@ impl Array {
         Ok(array_ptr.clone())
     }
 
+    /// Utility function which appends a hole to an existing array object, like the elision of
+    /// `[1, , 3]`, by only increasing its length
+    pub(crate) fn add_hole_to_array_object(array_ptr: &Value, context: &mut Context) -> Result<()> {
+        let orig_length = array_ptr.get_field("length", context)?.to_length(context)?;
+        array_ptr.set_field("length", Value::from(orig_length.wrapping_add(1)), context)?;
+        Ok(())
+    }
+
     /// `Array.isArray( arg )`
     ///
     /// The isArray function takes one argument arg, and returns the Boolean value true
@@ -296,11 +448,195 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.isarray
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/isArray
-    pub(crate) fn is_array(_: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
-        match args.get(0).and_then(|x| x.as_object()) {
-            Some(object) => Ok(Value::from(object.borrow().is_array())),
-            None => Ok(Value::from(false)),
+    pub(crate) fn is_array(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Ok(args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .is_array(context)?
+            .into())
+    }
+
+    /// `Array.from( items [ , mapfn [ , thisArg ] ] )`
+    ///
+    /// Creates a new array from an iterable or array-like object, optionally mapping every
+    /// element through `mapfn`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.from
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/from
+    pub(crate) fn from(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let items = args.get(0).cloned().unwrap_or_default();
+        let map_fn = args.get(1).cloned().unwrap_or_default();
+        let this_arg = args.get(2).cloned().unwrap_or_default();
+
+        // 2. If mapfn is undefined, let mapping be false.
+        // 3. Else, if IsCallable(mapfn) is false, throw a TypeError exception.
+        let mapping = !map_fn.is_undefined();
+        if mapping && !map_fn.is_function() {
+            return context.throw_type_error("Array.from: mapper is not callable");
+        }
+
+        // 4. Let usingIterator be ? GetMethod(items, @@iterator).
+        let using_iterator = if items.is_null_or_undefined() {
+            None
+        } else {
+            items
+                .to_object(context)?
+                .get_method(context, WellKnownSymbols::iterator())?
+        };
+
+        // 5. If usingIterator is not undefined, then
+        if let Some(using_iterator) = using_iterator {
+            // a. If IsConstructor(C) is true, let A be ? Construct(C).
+            // b. Else, let A be ! ArrayCreate(0).
+            let mut a = Self::construct_or_array_create(this, None, context)?;
+
+            // c. Let iteratorRecord be ? GetIterator(items, sync, usingIterator).
+            let iterator_object = using_iterator.call(&items, &[], context)?;
+            if !iterator_object.is_object() {
+                return context.throw_type_error("Array.from: iterator is not an object");
+            }
+            let next_function = iterator_object.get_field("next", context)?;
+            let iterator = IteratorRecord::new(iterator_object, next_function);
+
+            // d. Let k be 0.
+            // e. Repeat,
+            let mut k = 0;
+            loop {
+                // iii. Let next be ? IteratorStep(iteratorRecord).
+                let next = iterator.next(context)?;
+                // iv. If next is false, then
+                if next.is_done() {
+                    // 1. Perform ? Set(A, "length", 𝔽(k), true).
+                    // 2. Return A.
+                    let a = Value::from(a);
+                    a.set_field("length", k, context)?;
+                    return Ok(a);
+                }
+
+                // vi. If mapping is true, then
+                //     1. Let mappedValue be Call(mapfn, thisArg, « nextValue, 𝔽(k) »).
+                //     2. IfAbruptCloseIterator(mappedValue, iteratorRecord).
+                // vii. Else, let mappedValue be nextValue.
+                let mapped_value = if mapping {
+                    match context.call(&map_fn, &this_arg, &[next.value(), k.into()]) {
+                        Ok(value) => value,
+                        Err(error) => return iterator.close(Err(error), context),
+                    }
+                } else {
+                    next.value()
+                };
+
+                // viii. Let defineStatus be CreateDataPropertyOrThrow(A, Pk, mappedValue).
+                // ix. If defineStatus is an abrupt completion, return ? IteratorClose(iteratorRecord, defineStatus).
+                if let Err(error) = a.define_property_or_throw(
+                    k,
+                    DataDescriptor::new(mapped_value, Attribute::all()),
+                    context,
+                ) {
+                    return iterator.close(Err(error), context);
+                }
+
+                // x. Set k to k + 1.
+                k += 1;
+            }
+        }
+
+        // 6. NOTE: items is not an Iterable so assume it is an array-like object.
+        // 7. Let arrayLike be ! ToObject(items).
+        let array_like = items.to_object(context)?;
+        // 8. Let len be ? LengthOfArrayLike(arrayLike).
+        let len = array_like
+            .get(&"length".into(), array_like.clone().into(), context)?
+            .to_length(context)?;
+
+        // 9. If IsConstructor(C) is true, then
+        //    a. Let A be ? Construct(C, « 𝔽(len) »).
+        // 10. Else,
+        //    a. Let A be ? ArrayCreate(len).
+        let mut a = Self::construct_or_array_create(this, Some(len), context)?;
+
+        // 11. Let k be 0.
+        // 12. Repeat, while k < len,
+        for k in 0..len {
+            // b. Let kValue be ? Get(arrayLike, Pk).
+            let k_value = array_like.get(&k.into(), array_like.clone().into(), context)?;
+            // c. If mapping is true, then
+            //    i. Let mappedValue be ? Call(mapfn, thisArg, « kValue, 𝔽(k) »).
+            // d. Else, let mappedValue be kValue.
+            let mapped_value = if mapping {
+                context.call(&map_fn, &this_arg, &[k_value, k.into()])?
+            } else {
+                k_value
+            };
+            // e. Perform ? CreateDataPropertyOrThrow(A, Pk, mappedValue).
+            a.define_property_or_throw(
+                k,
+                DataDescriptor::new(mapped_value, Attribute::all()),
+                context,
+            )?;
+        }
+
+        // 13. Perform ? Set(A, "length", 𝔽(len), true).
+        // 14. Return A.
+        let a = Value::from(a);
+        a.set_field("length", len, context)?;
+        Ok(a)
+    }
+
+    /// `Array.of( ...items )`
+    ///
+    /// Creates a new array from a variable number of arguments, regardless of their number
+    /// or type.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.of
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/of
+    pub(crate) fn of(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 4. If IsConstructor(C) is true, then
+        //    a. Let A be ? Construct(C, « lenNumber »).
+        // 5. Else,
+        //    a. Let A be ? ArrayCreate(len).
+        let mut a = Self::construct_or_array_create(this, Some(args.len()), context)?;
+
+        // 6. Let k be 0.
+        // 7. Repeat, while k < len,
+        for (k, item) in args.iter().enumerate() {
+            // c. Perform ? CreateDataPropertyOrThrow(A, Pk, kValue).
+            a.define_property_or_throw(
+                k,
+                DataDescriptor::new(item.clone(), Attribute::all()),
+                context,
+            )?;
         }
+
+        // 8. Perform ? Set(A, "length", lenNumber, true).
+        // 9. Return A.
+        let a = Value::from(a);
+        a.set_field("length", args.len(), context)?;
+        Ok(a)
+    }
+
+    /// `get Array [ @@species ]`
+    ///
+    /// The `Array [ @@species ]` accessor property returns the Array constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-array-@@species
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/@@species
+    fn get_species(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
+        // 1. Return the this value.
+        Ok(this.clone())
     }
 
     /// `Array.prototype.concat(...arguments)`
@@ -316,30 +652,91 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.concat
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/concat
     pub(crate) fn concat(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        if args.is_empty() {
-            // If concat is called with no arguments, it returns the original array
-            return Ok(this.clone());
+        // 1. Let O be ? ToObject(this value).
+        let o = this.to_object(context)?;
+        // 2. Let A be ? ArraySpeciesCreate(O, 0).
+        let mut a = Self::array_species_create(&o, 0, context)?;
+
+        // 3. Let n be 0.
+        let mut n = 0;
+        // 4. Prepend O to items.
+        // 5. For each element E of items, do
+        for item in std::iter::once(Value::from(o)).chain(args.iter().cloned()) {
+            // a. Let spreadable be ? IsConcatSpreadable(E).
+            // b. If spreadable is true, then
+            if Self::is_concat_spreadable(&item, context)? {
+                let e = item
+                    .as_object()
+                    .expect("spreadable value must be an object");
+                // ii. Let len be ? LengthOfArrayLike(E).
+                let len = item.get_field("length", context)?.to_length(context)?;
+                // iii. If n + len > 2^53 - 1, throw a TypeError exception.
+                if n + len > Number::MAX_SAFE_INTEGER as usize {
+                    return context.throw_type_error("Invalid array length");
+                }
+                // iv. Repeat, while k < len,
+                for k in 0..len {
+                    // 1. Let P be ! ToString(𝔽(k)).
+                    // 2. Let exists be ? HasProperty(E, P).
+                    // 3. If exists is true, then
+                    if e.has_property(&k.into(), context)? {
+                        // a. Let subElement be ? Get(E, P).
+                        let sub_element = e.get(&k.into(), item.clone(), context)?;
+                        // b. Perform ? CreateDataPropertyOrThrow(A, ! ToString(𝔽(n)), subElement).
+                        a.define_property_or_throw(
+                            n,
+                            DataDescriptor::new(sub_element, Attribute::all()),
+                            context,
+                        )?;
+                    }
+                    n += 1;
+                }
+            // c. Else,
+            } else {
+                // ii. If n ≥ 2^53 - 1, throw a TypeError exception.
+                if n >= Number::MAX_SAFE_INTEGER as usize {
+                    return context.throw_type_error("Invalid array length");
+                }
+                // iii. Perform ? CreateDataPropertyOrThrow(A, ! ToString(𝔽(n)), E).
+                a.define_property_or_throw(
+                    n,
+                    DataDescriptor::new(item, Attribute::all()),
+                    context,
+                )?;
+                n += 1;
+            }
         }
 
-        // Make a new array (using this object as the prototype basis for the new
-        // one)
-        let mut new_values: Vec<Value> = Vec::new();
+        // 6. Perform ? Set(A, "length", 𝔽(n), true).
+        let a = Value::from(a);
+        a.set_field("length", n, context)?;
+
+        // 7. Return A.
+        Ok(a)
+    }
 
-        let this_length = this.get_field("length", context)?.to_length(context)?;
-        for n in 0..this_length {
-            new_values.push(this.get_field(n, context)?);
+    /// Abstract operation `IsConcatSpreadable ( O )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isconcatspreadable
+    fn is_concat_spreadable(o: &Value, context: &mut Context) -> Result<bool> {
+        // 1. If Type(O) is not Object, return false.
+        if !o.is_object() {
+            return Ok(false);
         }
 
-        for concat_array in args {
-            let concat_length = concat_array
-                .get_field("length", context)?
-                .to_length(context)?;
-            for n in 0..concat_length {
-                new_values.push(concat_array.get_field(n, context)?);
-            }
+        // 2. Let spreadable be ? Get(O, @@isConcatSpreadable).
+        let spreadable = o.get_field(WellKnownSymbols::is_concat_spreadable(), context)?;
+
+        // 3. If spreadable is not undefined, return ! ToBoolean(spreadable).
+        if !spreadable.is_undefined() {
+            return Ok(spreadable.to_boolean());
         }
 
-        Self::construct_array(this, &new_values, context)
+        // 4. Return ? IsArray(O).
+        o.is_array(context)
     }
 
     /// `Array.prototype.push( ...items )`
@@ -355,8 +752,20 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.push
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/push
     pub(crate) fn push(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        let new_array = Self::add_to_array_object(this, args, context)?;
-        new_array.get_field("length", context)
+        let mut o = this.to_object(context)?;
+        let len = this.get_field("length", context)?.to_length(context)?;
+
+        if len + args.len() > Number::MAX_SAFE_INTEGER as usize {
+            return context.throw_type_error("Invalid array length");
+        }
+
+        for (n, value) in args.iter().enumerate() {
+            o.set_or_throw(len + n, value.clone(), context)?;
+        }
+
+        let len = Value::from(len + args.len());
+        o.set_or_throw("length", len.clone(), context)?;
+        Ok(len)
     }
 
     /// `Array.prototype.pop()`
@@ -370,15 +779,17 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.pop
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/pop
     pub(crate) fn pop(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let mut o = this.to_object(context)?;
         let curr_length = this.get_field("length", context)?.to_length(context)?;
 
         if curr_length < 1 {
+            o.set_or_throw("length", 0, context)?;
             return Ok(Value::undefined());
         }
         let pop_index = curr_length.wrapping_sub(1);
-        let pop_value: Value = this.get_field(pop_index.to_string(), context)?;
-        this.remove_property(pop_index);
-        this.set_field("length", Value::from(pop_index), context)?;
+        let pop_value: Value = this.get_field(pop_index, context)?;
+        o.delete_property_or_throw(pop_index, context)?;
+        o.set_or_throw("length", pop_index, context)?;
         Ok(pop_value)
     }
 
@@ -498,6 +909,7 @@ impl Array {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/reverse
     #[allow(clippy::else_if_without_else)]
     pub(crate) fn reverse(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let mut o = this.to_object(context)?;
         let len = this.get_field("length", context)?.to_length(context)?;
 
         let middle = len.wrapping_div(2);
@@ -512,18 +924,18 @@ impl Array {
             let lower_value = this.get_field(lower, context)?;
 
             if upper_exists && lower_exists {
-                this.set_property(upper, DataDescriptor::new(lower_value, Attribute::all()));
-                this.set_property(lower, DataDescriptor::new(upper_value, Attribute::all()));
+                o.set_or_throw(lower, upper_value, context)?;
+                o.set_or_throw(upper, lower_value, context)?;
             } else if upper_exists {
-                this.set_property(lower, DataDescriptor::new(upper_value, Attribute::all()));
-                this.remove_property(upper);
+                o.set_or_throw(lower, upper_value, context)?;
+                o.delete_property_or_throw(upper, context)?;
             } else if lower_exists {
-                this.set_property(upper, DataDescriptor::new(lower_value, Attribute::all()));
-                this.remove_property(lower);
+                o.delete_property_or_throw(lower, context)?;
+                o.set_or_throw(upper, lower_value, context)?;
             }
         }
 
-        Ok(this.clone())
+        Ok(o.into())
     }
 
     /// `Array.prototype.shift()`
@@ -537,10 +949,11 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.shift
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/shift
     pub(crate) fn shift(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let mut o = this.to_object(context)?;
         let len = this.get_field("length", context)?.to_length(context)?;
 
         if len == 0 {
-            this.set_field("length", 0, context)?;
+            o.set_or_throw("length", 0, context)?;
             return Ok(Value::undefined());
         }
 
@@ -550,17 +963,17 @@ impl Array {
             let from = k;
             let to = k.wrapping_sub(1);
 
-            let from_value = this.get_field(from, context)?;
-            if from_value.is_undefined() {
-                this.remove_property(to);
+            if o.has_property(&from.into(), context)? {
+                let from_value = this.get_field(from, context)?;
+                o.set_or_throw(to, from_value, context)?;
             } else {
-                this.set_property(to, DataDescriptor::new(from_value, Attribute::all()));
+                o.delete_property_or_throw(to, context)?;
             }
         }
 
         let final_index = len.wrapping_sub(1);
-        this.remove_property(final_index);
-        this.set_field("length", Value::from(final_index), context)?;
+        o.delete_property_or_throw(final_index, context)?;
+        o.set_or_throw("length", final_index, context)?;
 
         Ok(first)
     }
@@ -578,6 +991,7 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.unshift
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/unshift
     pub(crate) fn unshift(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let mut o = this.to_object(context)?;
         let len = this.get_field("length", context)?.to_length(context)?;
 
         let arg_c = args.len();
@@ -587,26 +1001,20 @@ impl Array {
                 let from = k.wrapping_sub(1);
                 let to = k.wrapping_add(arg_c).wrapping_sub(1);
 
-                let from_value = this.get_field(from, context)?;
-                if from_value.is_undefined() {
-                    this.remove_property(to);
+                if o.has_property(&from.into(), context)? {
+                    let from_value = this.get_field(from, context)?;
+                    o.set_or_throw(to, from_value, context)?;
                 } else {
-                    this.set_property(to, DataDescriptor::new(from_value, Attribute::all()));
+                    o.delete_property_or_throw(to, context)?;
                 }
             }
-            for j in 0..arg_c {
-                this.set_property(
-                    j,
-                    DataDescriptor::new(
-                        args.get(j).expect("Could not get argument").clone(),
-                        Attribute::all(),
-                    ),
-                );
+            for (j, arg) in args.iter().enumerate() {
+                o.set_or_throw(j, arg.clone(), context)?;
             }
         }
 
         let temp = len.wrapping_add(arg_c);
-        this.set_field("length", Value::from(temp), context)?;
+        o.set_or_throw("length", temp, context)?;
         Ok(Value::from(temp))
     }
 
@@ -681,12 +1089,13 @@ impl Array {
             return context.throw_range_error("Invalid array length");
         }
 
-        let new = Self::new_array(context);
+        let o = this.to_object(context)?;
+        let new = Self::array_species_create(&o, length, context)?.into();
 
         let values = (0..length)
             .map(|idx| {
                 let element = this.get_field(idx, context)?;
-                let args = [element, Value::from(idx), new.clone()];
+                let args = [element, Value::from(idx), this.clone()];
 
                 context.call(&callback, &this_val, &args)
             })
@@ -888,6 +1297,202 @@ impl Array {
         Ok(Value::integer(-1))
     }
 
+    /// `Array.prototype.findLast( predicate [ , thisArg ] )`
+    ///
+    /// Iterates the array in descending order and returns the value of the first element
+    /// that satisfies the predicate, or `undefined` if there is none.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.findlast
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/findLast
+    pub(crate) fn find_last(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Ok(
+            Self::find_via_predicate_from_end(this, args, "findLast", context)?
+                .map(|(_, value)| value)
+                .unwrap_or_default(),
+        )
+    }
+
+    /// `Array.prototype.findLastIndex( predicate [ , thisArg ] )`
+    ///
+    /// Iterates the array in descending order and returns the index of the first element
+    /// that satisfies the predicate, or `-1` if there is none.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.findlastindex
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/findLastIndex
+    pub(crate) fn find_last_index(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        Ok(
+            match Self::find_via_predicate_from_end(this, args, "findLastIndex", context)? {
+                Some((index, _)) => Value::from(index),
+                None => Value::integer(-1),
+            },
+        )
+    }
+
+    /// Shared implementation of `findLast` and `findLastIndex`, returning the index and value
+    /// of the matching element.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-findviapredicate
+    fn find_via_predicate_from_end(
+        this: &Value,
+        args: &[Value],
+        name: &str,
+        context: &mut Context,
+    ) -> Result<Option<(usize, Value)>> {
+        // 1. Let O be ? ToObject(this value).
+        let o = this.to_object(context)?;
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = this.get_field("length", context)?.to_length(context)?;
+
+        // 3. If IsCallable(predicate) is false, throw a TypeError exception.
+        let predicate = args.get(0).cloned().unwrap_or_default();
+        if !predicate.is_function() {
+            context.throw_type_error(format!(
+                "Array.prototype.{}: predicate is not callable",
+                name
+            ))?;
+        }
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+
+        // 4. Let k be len - 1.
+        // 5. Repeat, while k ≥ 0,
+        for k in (0..len).rev() {
+            // b. Let kValue be ? Get(O, Pk).
+            let k_value = o.get(&k.into(), o.clone().into(), context)?;
+            // c. Let testResult be ! ToBoolean(? Call(predicate, thisArg, « kValue, 𝔽(k), O »)).
+            let test_result = context
+                .call(
+                    &predicate,
+                    &this_arg,
+                    &[k_value.clone(), k.into(), o.clone().into()],
+                )?
+                .to_boolean();
+            // d. If testResult is true, return kValue.
+            if test_result {
+                return Ok(Some((k, k_value)));
+            }
+        }
+
+        // 6. Return undefined.
+        Ok(None)
+    }
+
+    /// `Array.prototype.at( index )`
+    ///
+    /// Returns the element at the given index, counting back from the end of the array for
+    /// negative indices.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.at
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/at
+    pub(crate) fn at(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let O be ? ToObject(this value).
+        let o = this.to_object(context)?;
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = this.get_field("length", context)?.to_length(context)? as i64;
+
+        // 3. Let relativeIndex be ? ToIntegerOrInfinity(index).
+        // 4. If relativeIndex ≥ 0, then
+        //    a. Let k be relativeIndex.
+        // 5. Else,
+        //    a. Let k be len + relativeIndex.
+        let k = match args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_integer_or_infinity(context)?
+        {
+            IntegerOrInfinity::Integer(i) if i >= 0 => i,
+            IntegerOrInfinity::Integer(i) => len + i,
+            _ => return Ok(Value::undefined()),
+        };
+
+        // 6. If k < 0 or k ≥ len, return undefined.
+        if k < 0 || k >= len {
+            return Ok(Value::undefined());
+        }
+
+        // 7. Return ? Get(O, ! ToString(𝔽(k))).
+        o.get(&(k as usize).into(), o.clone().into(), context)
+    }
+
+    /// `Array.prototype.copyWithin( target, start [ , end ] )`
+    ///
+    /// Copies the sequence of elements between `start` and `end` to the position starting at
+    /// `target`, without modifying the length of the array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.copywithin
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/copyWithin
+    pub(crate) fn copy_within(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        // 1. Let O be ? ToObject(this value).
+        let mut o = this.to_object(context)?;
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = this.get_field("length", context)?.to_length(context)?;
+
+        // 3-5. Let to be the relative target index.
+        let to = Self::get_relative_start(context, args.get(0), len)?;
+        // 6-8. Let from be the relative start index.
+        let from = Self::get_relative_start(context, args.get(1), len)?;
+        // 9-11. Let final be the relative end index.
+        let fin = Self::get_relative_end(context, args.get(2), len)?;
+
+        // 12. Let count be min(final - from, len - to).
+        let count = min(fin.saturating_sub(from), len - to);
+
+        // 13. If from < to and to < from + count, copy the elements in descending order
+        //     so the source isn't overwritten before it is read.
+        let offsets: Box<dyn Iterator<Item = usize>> = if from < to && to < from + count {
+            Box::new((0..count).rev())
+        } else {
+            Box::new(0..count)
+        };
+
+        // 15. Repeat, while count > 0,
+        for offset in offsets {
+            let from_key = from + offset;
+            let to_key = to + offset;
+            // c. If fromPresent is true, then
+            if o.has_property(&from_key.into(), context)? {
+                // i. Let fromVal be ? Get(O, fromKey).
+                // ii. Perform ? Set(O, toKey, fromVal, true).
+                let from_val = o.get(&from_key.into(), o.clone().into(), context)?;
+                o.set_or_throw(to_key, from_val, context)?;
+            // d. Else,
+            } else {
+                // i. Perform ? DeletePropertyOrThrow(O, toKey).
+                o.delete_property_or_throw(to_key, context)?;
+            }
+        }
+
+        // 16. Return O.
+        Ok(o.into())
+    }
+
     /// `Array.prototype.flat( [depth] )`
     ///
     /// This method creates a new array with all sub-array elements concatenated into it
@@ -922,7 +1527,7 @@ impl Array {
         };
 
         // 5. Let A be ArraySpeciesCreate(O, 0)
-        let new_array = Self::new_array(context);
+        let new_array = Self::array_species_create(&this.to_object(context)?, 0, context)?.into();
 
         // 6. Perform FlattenIntoArray(A, O, sourceLen, 0, depthNum)
         let len = Self::flatten_into_array(
@@ -968,7 +1573,7 @@ impl Array {
         let this_arg = args.get(1).cloned().unwrap_or(o);
 
         // 4. Let A be ArraySpeciesCreate(O, 0)
-        let new_array = Self::new_array(context);
+        let new_array = Self::array_species_create(&this.to_object(context)?, 0, context)?.into();
 
         // 5. Perform FlattenIntoArray(A, O, sourceLen, 0, 1, mapperFunction, thisArg)
         let depth = Value::Integer(1).to_integer_or_infinity(context)?;
@@ -1118,6 +1723,7 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.fill
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/fill
     pub(crate) fn fill(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let mut o = this.to_object(context)?;
         let len = this.get_field("length", context)?.to_length(context)?;
 
         let default_value = Value::undefined();
@@ -1126,10 +1732,10 @@ impl Array {
         let fin = Self::get_relative_end(context, args.get(2), len)?;
 
         for i in start..fin {
-            this.set_property(i, DataDescriptor::new(value.clone(), Attribute::all()));
+            o.set_or_throw(i, value.clone(), context)?;
         }
 
-        Ok(this.clone())
+        Ok(o.into())
     }
 
     /// `Array.prototype.includes( valueToFind [, fromIndex] )`
@@ -1177,28 +1783,269 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.slice
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/slice
     pub(crate) fn slice(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        let new_array = Self::new_array(context);
+        let o = this.to_object(context)?;
 
         let len = this.get_field("length", context)?.to_length(context)?;
         let from = Self::get_relative_start(context, args.get(0), len)?;
         let to = Self::get_relative_end(context, args.get(1), len)?;
 
         let span = max(to.saturating_sub(from), 0);
-        if span > 2usize.pow(32) - 1 {
-            return context.throw_range_error("Invalid array length");
-        }
-        let mut new_array_len: i32 = 0;
+        let mut new_array = Self::array_species_create(&o, span, context)?;
+        let mut new_array_len = 0;
         for i in from..from.saturating_add(span) {
-            new_array.set_property(
-                new_array_len,
-                DataDescriptor::new(this.get_field(i, context)?, Attribute::all()),
-            );
-            new_array_len = new_array_len.saturating_add(1);
+            if o.has_property(&i.into(), context)? {
+                new_array.define_property_or_throw(
+                    new_array_len,
+                    DataDescriptor::new(this.get_field(i, context)?, Attribute::all()),
+                    context,
+                )?;
+            }
+            new_array_len += 1;
         }
-        new_array.set_field("length", Value::from(new_array_len), context)?;
+        let new_array = Value::from(new_array);
+        new_array.set_field("length", new_array_len, context)?;
         Ok(new_array)
     }
 
+    /// `Array.prototype.splice( start, deleteCount, ...items )`
+    ///
+    /// Removes `deleteCount` elements starting at `start`, inserts `items` in their place and
+    /// returns an array containing the removed elements.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.splice
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/splice
+    pub(crate) fn splice(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let O be ? ToObject(this value).
+        let mut o = this.to_object(context)?;
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = this.get_field("length", context)?.to_length(context)?;
+        // 3-6. Let actualStart be the relative start index.
+        let actual_start = Self::get_relative_start(context, args.get(0), len)?;
+
+        let items = args.get(2..).unwrap_or_default();
+        let item_count = items.len();
+
+        let actual_delete_count = match args.len() {
+            // 7. If start is not present, then
+            //    a. Let actualDeleteCount be 0.
+            0 => 0,
+            // 8. Else if deleteCount is not present, then
+            //    a. Let actualDeleteCount be len - actualStart.
+            1 => len - actual_start,
+            // 9. Else,
+            //    b. Let dc be ? ToIntegerOrInfinity(deleteCount).
+            //    c. Let actualDeleteCount be the result of clamping dc between 0 and len - actualStart.
+            _ => match args[1].to_integer_or_infinity(context)? {
+                IntegerOrInfinity::Integer(i) if i > 0 => min(i as usize, len - actual_start),
+                IntegerOrInfinity::PositiveInfinity => len - actual_start,
+                _ => 0,
+            },
+        };
+
+        // 10. If len + itemCount - actualDeleteCount > 2^53 - 1, throw a TypeError exception.
+        if len + item_count - actual_delete_count > Number::MAX_SAFE_INTEGER as usize {
+            return context.throw_type_error("Invalid array length");
+        }
+
+        // 11. Let A be ? ArraySpeciesCreate(O, actualDeleteCount).
+        let mut a = Self::array_species_create(&o, actual_delete_count, context)?;
+
+        // 12. Let k be 0.
+        // 13. Repeat, while k < actualDeleteCount,
+        for k in 0..actual_delete_count {
+            // a. Let from be ! ToString(𝔽(actualStart + k)).
+            let from = actual_start + k;
+            // b. Let fromPresent be ? HasProperty(O, from).
+            // c. If fromPresent is true, then
+            if o.has_property(&from.into(), context)? {
+                // i. Let fromValue be ? Get(O, from).
+                let from_value = o.get(&from.into(), o.clone().into(), context)?;
+                // ii. Perform ? CreateDataPropertyOrThrow(A, ! ToString(𝔽(k)), fromValue).
+                a.define_property_or_throw(
+                    k,
+                    DataDescriptor::new(from_value, Attribute::all()),
+                    context,
+                )?;
+            }
+        }
+
+        // 14. Perform ? Set(A, "length", 𝔽(actualDeleteCount), true).
+        let a = Value::from(a);
+        a.set_field("length", actual_delete_count, context)?;
+
+        // 15-16. Move the elements after the deleted ones to make room for the new items, in
+        //        the direction that doesn't overwrite elements before they are moved.
+        let moved = actual_start..(len - actual_delete_count);
+        let moved: Box<dyn Iterator<Item = usize>> = if item_count < actual_delete_count {
+            Box::new(moved)
+        } else {
+            Box::new(moved.rev())
+        };
+        if item_count != actual_delete_count {
+            for k in moved {
+                let from = k + actual_delete_count;
+                let to = k + item_count;
+                if o.has_property(&from.into(), context)? {
+                    let from_value = o.get(&from.into(), o.clone().into(), context)?;
+                    o.set_or_throw(to, from_value, context)?;
+                } else {
+                    o.delete_property_or_throw(to, context)?;
+                }
+            }
+        }
+
+        // 15.c. Delete the elements left past the new length.
+        for k in ((len - actual_delete_count + item_count)..len).rev() {
+            o.delete_property_or_throw(k, context)?;
+        }
+
+        // 17. For each element E of items, do
+        for (k, item) in items.iter().enumerate() {
+            // a. Perform ? Set(O, ! ToString(𝔽(k)), E, true).
+            o.set_or_throw(actual_start + k, item.clone(), context)?;
+        }
+
+        // 18. Perform ? Set(O, "length", 𝔽(len - actualDeleteCount + itemCount), true).
+        o.set_or_throw("length", len - actual_delete_count + item_count, context)?;
+
+        // 19. Return A.
+        Ok(a)
+    }
+
+    /// `Array.prototype.sort( comparefn )`
+    ///
+    /// Sorts the elements of the array in place using a stable sort. Elements are compared
+    /// using `comparefn` if given, and by their string value otherwise; `undefined` values
+    /// are sorted to the end and holes are removed.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.sort
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/sort
+    pub(crate) fn sort(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. If comparefn is not undefined and IsCallable(comparefn) is false, throw a TypeError exception.
+        let comparefn = args.get(0).cloned().unwrap_or_default();
+        if !comparefn.is_undefined() && !comparefn.is_function() {
+            return context.throw_type_error(
+                "The comparison function must be either a function or undefined",
+            );
+        }
+
+        // 2. Let obj be ? ToObject(this value).
+        let mut obj = this.to_object(context)?;
+        // 3. Let len be ? LengthOfArrayLike(obj).
+        let len = this.get_field("length", context)?.to_length(context)?;
+
+        // 5. Let sortedList be ? SortIndexedProperties(obj, len, SortCompare, skip-holes).
+        let mut items = Vec::new();
+        for k in 0..len {
+            if obj.has_property(&k.into(), context)? {
+                items.push(obj.get(&k.into(), obj.clone().into(), context)?);
+            }
+        }
+        let sorted = Self::merge_sort(items, &mut |x, y| {
+            Self::sort_compare(x, y, &comparefn, context)
+        })?;
+
+        // 6. Let itemCount be the number of elements in sortedList.
+        let item_count = sorted.len();
+
+        // 7-8. Set every property below itemCount to the sorted values.
+        for (j, value) in sorted.into_iter().enumerate() {
+            obj.set_or_throw(j, value, context)?;
+        }
+
+        // 9. Repeat, while j < len, delete the properties left over by holes.
+        for j in item_count..len {
+            obj.delete_property_or_throw(j, context)?;
+        }
+
+        // 10. Return obj.
+        Ok(obj.into())
+    }
+
+    /// Stable merge sort of `items` using a fallible comparison function.
+    ///
+    /// User comparators can be inconsistent or throw, so this doesn't rely on the standard
+    /// library sorts, which may panic on comparators that aren't a total order.
+    fn merge_sort<F>(mut items: Vec<Value>, compare: &mut F) -> Result<Vec<Value>>
+    where
+        F: FnMut(&Value, &Value) -> Result<std::cmp::Ordering>,
+    {
+        if items.len() <= 1 {
+            return Ok(items);
+        }
+
+        let right = items.split_off(items.len() / 2);
+        let left = Self::merge_sort(items, compare)?;
+        let right = Self::merge_sort(right, compare)?;
+
+        let mut merged = Vec::with_capacity(left.len() + right.len());
+        let mut left = left.into_iter().peekable();
+        let mut right = right.into_iter().peekable();
+        while let (Some(l), Some(r)) = (left.peek(), right.peek()) {
+            // Only take from the right when it is strictly smaller, to keep the sort stable.
+            if compare(r, l)? == std::cmp::Ordering::Less {
+                merged.extend(right.next());
+            } else {
+                merged.extend(left.next());
+            }
+        }
+        merged.extend(left);
+        merged.extend(right);
+
+        Ok(merged)
+    }
+
+    /// Abstract operation `SortCompare ( x, y )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-sortcompare
+    fn sort_compare(
+        x: &Value,
+        y: &Value,
+        comparefn: &Value,
+        context: &mut Context,
+    ) -> Result<std::cmp::Ordering> {
+        use std::cmp::Ordering;
+
+        match (x.is_undefined(), y.is_undefined()) {
+            // 1. If x and y are both undefined, return +0𝔽.
+            (true, true) => return Ok(Ordering::Equal),
+            // 2. If x is undefined, return 1𝔽.
+            (true, false) => return Ok(Ordering::Greater),
+            // 3. If y is undefined, return -1𝔽.
+            (false, true) => return Ok(Ordering::Less),
+            (false, false) => {}
+        }
+
+        // 4. If comparefn is not undefined, then
+        if !comparefn.is_undefined() {
+            // a. Let v be ? ToNumber(? Call(comparefn, undefined, « x, y »)).
+            let v = context
+                .call(comparefn, &Value::undefined(), &[x.clone(), y.clone()])?
+                .to_number(context)?;
+            // b. If v is NaN, return +0𝔽.
+            // c. Return v.
+            return Ok(v.partial_cmp(&0.0).unwrap_or(Ordering::Equal));
+        }
+
+        // 5. Let xString be ? ToString(x).
+        let x = x.to_string(context)?;
+        // 6. Let yString be ? ToString(y).
+        let y = y.to_string(context)?;
+        // 7-11. Compare the strings by their code units.
+        Ok(x.encode_utf16().cmp(y.encode_utf16()))
+    }
+
     /// `Array.prototype.filter( callback, [ thisArg ] )`
     ///
     /// For each element in the array the callback function is called, and a new
@@ -1222,13 +2069,14 @@ impl Array {
 
         let length = this.get_field("length", context)?.to_length(context)?;
 
-        let new = Self::new_array(context);
+        let o = this.to_object(context)?;
+        let new = Self::array_species_create(&o, 0, context)?.into();
 
         let values = (0..length)
             .map(|idx| {
                 let element = this.get_field(idx, context)?;
 
-                let args = [element.clone(), Value::from(idx), new.clone()];
+                let args = [element.clone(), Value::from(idx), this.clone()];
 
                 let callback_result = context.call(&callback, &this_val, &args)?;
 
@@ -1505,6 +2353,48 @@ impl Array {
         ))
     }
 
+    /// Creates the `Array.prototype[@@unscopables]` object, listing the methods hidden from
+    /// `with` statements.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype-@@unscopables
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/@@unscopables
+    fn unscopables_object(context: &mut Context) -> GcObject {
+        // 1. Let unscopableList be ! OrdinaryObjectCreate(null).
+        let unscopables = Value::new_object(context);
+        unscopables
+            .as_object()
+            .expect("'unscopables' should be an object")
+            .set_prototype_instance(Value::null());
+
+        // 2-14. Perform ! CreateDataPropertyOrThrow(unscopableList, name, true) for each name.
+        for name in &[
+            "at",
+            "copyWithin",
+            "entries",
+            "fill",
+            "find",
+            "findIndex",
+            "findLast",
+            "findLastIndex",
+            "flat",
+            "flatMap",
+            "includes",
+            "keys",
+            "values",
+        ] {
+            unscopables.set_property(*name, DataDescriptor::new(true, Attribute::all()));
+        }
+
+        // 15. Return unscopableList.
+        unscopables
+            .as_object()
+            .expect("'unscopables' should be an object")
+    }
+
     /// Represents the algorithm to calculate `relativeStart` (or `k`) in array functions.
     pub(super) fn get_relative_start(
         context: &mut Context,
@@ -1014,7 +1014,7 @@ fn reduce() {
     );
     assert_eq!(
         result,
-        "\"Reduce was called on an empty array and with no initial value\""
+        "\"Reduce was called on an empty array and with no initial value at line 3, col 13\""
     );
 
     // Array with no defined elements
@@ -1033,7 +1033,7 @@ fn reduce() {
     );
     assert_eq!(
         result,
-        "\"Reduce was called on an empty array and with no initial value\""
+        "\"Reduce was called on an empty array and with no initial value at line 6, col 13\""
     );
 
     // No callback
@@ -1047,7 +1047,10 @@ fn reduce() {
         }
     "#,
     );
-    assert_eq!(result, "\"Reduce was called without a callback\"");
+    assert_eq!(
+        result,
+        "\"Reduce was called without a callback at line 3, col 13\""
+    );
 }
 
 #[test]
@@ -1136,7 +1139,7 @@ fn reduce_right() {
     );
     assert_eq!(
         result,
-        "\"reduceRight was called on an empty array and with no initial value\""
+        "\"reduceRight was called on an empty array and with no initial value at line 3, col 13\""
     );
 
     // Array with no defined elements
@@ -1155,7 +1158,7 @@ fn reduce_right() {
     );
     assert_eq!(
         result,
-        "\"reduceRight was called on an empty array and with no initial value\""
+        "\"reduceRight was called on an empty array and with no initial value at line 6, col 13\""
     );
 
     // No callback
@@ -1169,7 +1172,10 @@ fn reduce_right() {
         }
     "#,
     );
-    assert_eq!(result, "\"reduceRight was called without a callback\"");
+    assert_eq!(
+        result,
+        "\"reduceRight was called without a callback at line 3, col 13\""
+    );
 }
 
 #[test]
@@ -1343,7 +1349,7 @@ fn array_spread_non_iterable() {
         try {
             const array2 = [...5];
         } catch (err) {
-            err.name === "TypeError" && err.message === "Not an iterable"
+            err.name === "TypeError" && err.message === "Not an iterable at line 3, col 28"
         }
     "#;
     assert_eq!(forward(&mut context, init), "true");
@@ -1479,3 +1485,402 @@ fn array_length_is_not_enumerable() {
     let desc = array.get_property("length").unwrap();
     assert!(!desc.enumerable());
 }
+
+#[test]
+fn concat_does_not_modify_this() {
+    let mut context = Context::new();
+    let init = r#"
+        var arr = [1, 2];
+        var spreadable = { length: 2, 0: "a", 1: "b" };
+        spreadable[Symbol.isConcatSpreadable] = true;
+        var sparse = [4, 5, 6];
+        delete sparse[1];
+        var result = arr.concat(3, sparse, spreadable);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "arr.length"), "2");
+    assert_eq!(forward(&mut context, "result.length"), "8");
+    assert_eq!(
+        forward(&mut context, "result.slice(0, 4).join()"),
+        "\"1,2,3,4\""
+    );
+    assert_eq!(forward(&mut context, "result.slice(5).join()"), "\"6,a,b\"");
+    assert_eq!(forward(&mut context, "4 in result"), "false");
+    assert_eq!(forward(&mut context, "arr.concat() !== arr"), "true");
+}
+
+#[test]
+fn sort() {
+    let mut context = Context::new();
+    let init = r#"
+        var numbers = [10, 9, 1, 100, 2];
+        var holes = [3, undefined, 0, 1, 0, 2];
+        delete holes[2];
+        delete holes[4];
+        holes.sort();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "numbers.sort() === numbers"), "true");
+    assert_eq!(forward(&mut context, "numbers.join()"), "\"1,10,100,2,9\"");
+    assert_eq!(
+        forward(&mut context, "numbers.sort((a, b) => a - b).join()"),
+        "\"1,2,9,10,100\""
+    );
+    assert_eq!(forward(&mut context, "holes.length"), "6");
+    assert_eq!(
+        forward(&mut context, "holes.slice(0, 3).join()"),
+        "\"1,2,3\""
+    );
+    assert_eq!(forward(&mut context, "holes[3] === undefined"), "true");
+    assert_eq!(forward(&mut context, "3 in holes"), "true");
+    assert_eq!(forward(&mut context, "4 in holes || 5 in holes"), "false");
+}
+
+#[test]
+fn sort_literal_holes() {
+    let mut context = Context::new();
+    let init = r#"
+        var arr = [3, , 1];
+        arr.sort();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "arr.length"), "3");
+    assert_eq!(forward(&mut context, "arr[0] + ',' + arr[1]"), "\"1,3\"");
+    assert_eq!(forward(&mut context, "2 in arr"), "false");
+}
+
+#[test]
+fn literal_holes() {
+    let mut context = Context::new();
+    let init = r#"
+        var middle = [1, , 3];
+        var trailing = [1, , ];
+        var spread = [, ...[1, 2], , 3];
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "middle.length"), "3");
+    assert_eq!(forward(&mut context, "1 in middle"), "false");
+    assert_eq!(forward(&mut context, "trailing.length"), "2");
+    assert_eq!(forward(&mut context, "1 in trailing"), "false");
+    assert_eq!(forward(&mut context, "[,].length + [].length"), "1");
+    assert_eq!(forward(&mut context, "spread.length"), "5");
+    assert_eq!(forward(&mut context, "0 in spread || 3 in spread"), "false");
+    assert_eq!(
+        forward(&mut context, "spread[1] + spread[2] + spread[4]"),
+        "6"
+    );
+    assert_eq!(forward(&mut context, "0 in [undefined]"), "true");
+}
+
+#[test]
+fn sort_is_stable() {
+    let mut context = Context::new();
+    let init = r#"
+        var items = [];
+        for (var i = 0; i < 50; i++) {
+            items.push({ key: i % 3, index: i });
+        }
+        items.sort((a, b) => a.key - b.key);
+        var stable = true;
+        for (var i = 1; i < items.length; i++) {
+            if (items[i - 1].key === items[i].key && items[i - 1].index > items[i].index) {
+                stable = false;
+            }
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "stable"), "true");
+    assert_eq!(forward(&mut context, "items[0].key + items[49].key"), "2");
+}
+
+#[test]
+fn sort_errors() {
+    let mut context = Context::new();
+    let init = r#"
+        function thrower() { throw new RangeError("comparator"); }
+        function error(f) {
+            try { f(); } catch (e) { return e.constructor.name; }
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "error(() => [2, 1].sort(1))"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "error(() => [2, 1].sort(thrower))"),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[3, 1, 2].sort(() => Math.random() - 0.5).length"
+        ),
+        "3"
+    );
+}
+
+#[test]
+fn splice() {
+    let mut context = Context::new();
+    let init = r#"
+        var arr = [1, 2, 3, 4, 5];
+        var removed = arr.splice(1, 2, "a", "b", "c");
+        var shrink = [1, 2, 3, 4, 5];
+        var shrunk = shrink.splice(-2);
+        var none = [1, 2];
+        var nothing = none.splice();
+        var insert = [1, 2];
+        insert.splice(1, 0, "x");
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "removed.join()"), "\"2,3\"");
+    assert_eq!(forward(&mut context, "arr.join()"), "\"1,a,b,c,4,5\"");
+    assert_eq!(forward(&mut context, "shrunk.join()"), "\"4,5\"");
+    assert_eq!(forward(&mut context, "shrink.join()"), "\"1,2,3\"");
+    assert_eq!(forward(&mut context, "shrink.length"), "3");
+    assert_eq!(forward(&mut context, "nothing.length"), "0");
+    assert_eq!(forward(&mut context, "none.length"), "2");
+    assert_eq!(forward(&mut context, "insert.join()"), "\"1,x,2\"");
+}
+
+#[test]
+fn from() {
+    let mut context = Context::new();
+    let init = r#"
+        var from_array = Array.from([1, 2, 3], function (v, k) { return v * 10 + k + this.add; }, { add: 100 });
+        var from_string = Array.from("abc");
+        var from_set = Array.from(new Set([1, 1, 2]));
+        var from_array_like = Array.from({ length: 3, 0: "a", 2: "c" });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "from_array.join()"),
+        "\"110,121,132\""
+    );
+    assert_eq!(forward(&mut context, "from_string.join()"), "\"a,b,c\"");
+    assert_eq!(forward(&mut context, "from_set.join()"), "\"1,2\"");
+    assert_eq!(forward(&mut context, "from_array_like.length"), "3");
+    assert_eq!(
+        forward(&mut context, "from_array_like[1] === undefined"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.isArray(Array.from.call(undefined, [1]))"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Array.from([], 1) } catch (e) { e.constructor.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn of() {
+    let mut context = Context::new();
+    assert_eq!(forward(&mut context, "Array.of(7).length"), "1");
+    assert_eq!(forward(&mut context, "Array.of(7)[0]"), "7");
+    assert_eq!(
+        forward(&mut context, "Array.of(1, 2, 3).join()"),
+        "\"1,2,3\""
+    );
+    assert_eq!(forward(&mut context, "Array.of().length"), "0");
+}
+
+#[test]
+fn copy_within() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "[1, 2, 3, 4, 5].copyWithin(0, 3).join()"),
+        "\"4,5,3,4,5\""
+    );
+    assert_eq!(
+        forward(&mut context, "[1, 2, 3, 4, 5].copyWithin(1, 0, 3).join()"),
+        "\"1,1,2,3,5\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[1, 2, 3, 4, 5].copyWithin(-2, -3, -1).join()"
+        ),
+        "\"1,2,3,3,4\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "var holes = [1, 2, 3]; delete holes[1]; holes.copyWithin(0, 1); 0 in holes"
+        ),
+        "false"
+    );
+}
+
+#[test]
+fn find_last() {
+    let mut context = Context::new();
+    forward(&mut context, "var arr = [1, 2, 3, 4];");
+    assert_eq!(forward(&mut context, "arr.findLast(v => v % 2 === 1)"), "3");
+    assert_eq!(
+        forward(&mut context, "arr.findLastIndex(v => v % 2 === 1)"),
+        "2"
+    );
+    assert_eq!(
+        forward(&mut context, "arr.findLast(v => v > 4) === undefined"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "arr.findLastIndex(v => v > 4)"), "-1");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { arr.findLast() } catch (e) { e.constructor.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn at() {
+    let mut context = Context::new();
+    forward(&mut context, "var arr = ['a', 'b', 'c'];");
+    assert_eq!(forward(&mut context, "arr.at(0)"), "\"a\"");
+    assert_eq!(forward(&mut context, "arr.at(-1)"), "\"c\"");
+    assert_eq!(forward(&mut context, "arr.at(1.5)"), "\"b\"");
+    assert_eq!(forward(&mut context, "arr.at(3) === undefined"), "true");
+    assert_eq!(forward(&mut context, "arr.at(-4) === undefined"), "true");
+    assert_eq!(
+        forward(&mut context, "arr.at(-Infinity) === undefined"),
+        "true"
+    );
+}
+
+#[test]
+fn unscopables() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        "var unscopables = Array.prototype[Symbol.unscopables];",
+    );
+    assert_eq!(
+        forward(&mut context, "Object.getPrototypeOf(unscopables) === null"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "unscopables.at && unscopables.findLast && unscopables.values"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "unscopables.map === undefined"),
+        "true"
+    );
+}
+
+#[test]
+fn species() {
+    let mut context = Context::new();
+    let init = r#"
+        class MyArray extends Array {}
+        var mine = new MyArray(1, 2, 3, 4);
+        var NoSpecies = function () {};
+        var plain = [1, 2];
+        plain.constructor = {};
+        plain.constructor[Symbol.species] = NoSpecies;
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "Array[Symbol.species] === Array"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "mine.map(x => x) instanceof MyArray"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "mine.filter(x => x % 2) instanceof MyArray"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "mine.slice(1) instanceof MyArray"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "mine.splice(0, 1) instanceof MyArray"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "mine.concat([5]) instanceof MyArray"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "plain.slice(0) instanceof NoSpecies"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Array.isArray(plain.slice(0))"),
+        "false"
+    );
+}
+
+#[test]
+fn values_is_symbol_iterator() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.prototype.values === Array.prototype[Symbol.iterator]"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn mutators_on_frozen_arrays() {
+    let mut context = Context::new();
+
+    for method in &[
+        "push(3)",
+        "pop()",
+        "shift()",
+        "unshift(0)",
+        "reverse()",
+        "fill(0)",
+        "sort()",
+        "splice(0, 1)",
+        "copyWithin(0, 1)",
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                format!(
+                    "try {{ Object.freeze([2, 1]).{} }} catch (e) {{ e.name }}",
+                    method
+                )
+            ),
+            "\"TypeError\"",
+            "{}",
+            method
+        );
+    }
+    assert_eq!(
+        forward(
+            &mut context,
+            "var arr = Object.preventExtensions([1, 2]); \
+             try { arr.push(3) } catch (e) { e.name + arr.length + (2 in arr) }"
+        ),
+        "\"TypeError2false\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "var arr = Object.seal([1, 2]); arr.reverse(); arr.join()"
+        ),
+        "\"2,1\""
+    );
+}
@@ -0,0 +1,352 @@
+//! This module implements the global `ArrayBuffer` object.
+//!
+//! The `ArrayBuffer` object is used to represent a generic, fixed-length raw binary data buffer.
+//! Its contents cannot be manipulated directly, only through a `DataView` or a typed array
+//! viewing the buffer.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-arraybuffer-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer
+
+use crate::{
+    builtins::BuiltIn,
+    gc::{Finalize, Trace},
+    object::{ConstructorBuilder, FunctionBuilder, GcObject, Object, ObjectData, PROTOTYPE},
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::IntegerOrInfinity,
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The data of an `ArrayBuffer` object.
+///
+/// The bytes of the buffer are owned by the buffer, so a host can move a `Vec<u8>` into a buffer
+/// with [`Context::create_array_buffer`], and get it back with [`ArrayBuffer::detach`], without
+/// copying it.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct ArrayBuffer {
+    data: Option<Vec<u8>>,
+}
+
+impl BuiltIn for ArrayBuffer {
+    const NAME: &'static str = "ArrayBuffer";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let get_species = FunctionBuilder::new(context, Self::get_species)
+            .name("get [Symbol.species]")
+            .constructable(false)
+            .build();
+
+        let get_byte_length = FunctionBuilder::new(context, Self::get_byte_length)
+            .name("get byteLength")
+            .constructable(false)
+            .build();
+
+        let array_buffer = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().array_buffer_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .static_method(Self::is_view, "isView", 1)
+        .static_accessor(
+            WellKnownSymbols::species(),
+            Some(get_species),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .accessor(
+            "byteLength",
+            Some(get_byte_length),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .method(Self::slice, "slice", 2)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build();
+
+        (Self::NAME, array_buffer.into(), Self::attribute())
+    }
+}
+
+impl ArrayBuffer {
+    pub(crate) const LENGTH: usize = 1;
+
+    /// Creates the data of a buffer holding the given bytes.
+    #[inline]
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self { data: Some(bytes) }
+    }
+
+    /// Returns the bytes of the buffer, or `None` if the buffer is detached.
+    #[inline]
+    pub fn bytes(&self) -> Option<&[u8]> {
+        self.data.as_deref()
+    }
+
+    /// Returns the bytes of the buffer mutably, or `None` if the buffer is detached.
+    #[inline]
+    pub fn bytes_mut(&mut self) -> Option<&mut [u8]> {
+        self.data.as_deref_mut()
+    }
+
+    /// Returns the length of the buffer in bytes, which is `0` for a detached buffer.
+    #[inline]
+    pub fn byte_length(&self) -> usize {
+        self.data.as_ref().map_or(0, Vec::len)
+    }
+
+    /// Returns `true` if the buffer has been detached.
+    #[inline]
+    pub fn is_detached(&self) -> bool {
+        self.data.is_none()
+    }
+
+    /// Detaches the buffer, returning its bytes.
+    ///
+    /// Every view of a detached buffer has a length of `0`, and most operations on them throw a
+    /// `TypeError`. Returns `None` if the buffer was already detached.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-detacharraybuffer
+    #[inline]
+    pub fn detach(&mut self) -> Option<Vec<u8>> {
+        self.data.take()
+    }
+
+    /// Creates a new `ArrayBuffer` object holding the given bytes, with the given prototype.
+    pub(crate) fn create(bytes: Vec<u8>, prototype: Value) -> GcObject {
+        GcObject::new(Object::with_prototype(
+            prototype,
+            ObjectData::ArrayBuffer(Self::new(bytes)),
+        ))
+    }
+
+    /// The abstract operation `AllocateArrayBuffer`.
+    ///
+    /// Creates a new zeroed buffer of `byte_length` bytes, whose prototype is taken from the
+    /// given constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-allocatearraybuffer
+    pub(crate) fn allocate(
+        constructor: &Value,
+        byte_length: usize,
+        context: &mut Context,
+    ) -> Result<GcObject> {
+        let prototype = constructor
+            .as_object()
+            .map(|object| object.get(&PROTOTYPE.into(), constructor.clone(), context))
+            .transpose()?
+            .filter(Value::is_object)
+            .unwrap_or_else(|| {
+                context
+                    .standard_objects()
+                    .array_buffer_object()
+                    .prototype()
+                    .into()
+            });
+
+        let mut bytes = Vec::new();
+        if bytes.try_reserve_exact(byte_length).is_err() {
+            return Err(context.construct_range_error("cannot allocate an array buffer this large"));
+        }
+        bytes.resize(byte_length, 0);
+
+        Ok(Self::create(bytes, prototype))
+    }
+
+    /// `new ArrayBuffer( length )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer-length
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/ArrayBuffer
+    pub(crate) fn constructor(
+        new_target: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin ArrayBuffer constructor without new is forbidden",
+            );
+        }
+
+        let byte_length = args.get(0).cloned().unwrap_or_default().to_index(context)?;
+        Ok(Self::allocate(new_target, byte_length, context)?.into())
+    }
+
+    /// `ArrayBuffer.isView( arg )`
+    ///
+    /// Returns `true` if the argument is a typed array or a `DataView`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.isview
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/isView
+    pub(crate) fn is_view(_: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
+        let is_view = args
+            .get(0)
+            .and_then(Value::as_object)
+            .map_or(false, |object| {
+                let object = object.borrow();
+                object.is_typed_array() || object.is_data_view()
+            });
+        Ok(is_view.into())
+    }
+
+    /// `get ArrayBuffer [ @@species ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer-@@species
+    fn get_species(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
+        Ok(this.clone())
+    }
+
+    /// `get ArrayBuffer.prototype.byteLength`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer.prototype.bytelength
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/byteLength
+    pub(crate) fn get_byte_length(
+        this: &Value,
+        _: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        if let Some(object) = this.as_object() {
+            if let Some(buffer) = object.borrow().as_array_buffer() {
+                return Ok(buffer.byte_length().into());
+            }
+        }
+        context.throw_type_error("ArrayBuffer.prototype.byteLength called on incompatible receiver")
+    }
+
+    /// `ArrayBuffer.prototype.slice( start, end )`
+    ///
+    /// Returns a new `ArrayBuffer` holding a copy of the bytes from `start` up to `end`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.prototype.slice
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/slice
+    pub(crate) fn slice(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let object = match this.as_object() {
+            Some(object) if object.borrow().as_array_buffer().is_some() => object,
+            _ => {
+                return context.throw_type_error(
+                    "ArrayBuffer.prototype.slice called on incompatible receiver",
+                )
+            }
+        };
+        let len = Self::checked_byte_length(&object, context)?;
+
+        let first = Self::relative_index(args.get(0), len, 0, context)?;
+        let end = Self::relative_index(args.get(1), len, len, context)?;
+        let new_len = end.saturating_sub(first);
+
+        let default_constructor = context
+            .standard_objects()
+            .array_buffer_object()
+            .constructor();
+        let constructor = object.species_constructor(default_constructor.into(), context)?;
+        let new = constructor
+            .as_object()
+            .expect("species constructor must be an object")
+            .construct(&[new_len.into()], constructor.clone(), context)?;
+
+        let new_object = match new.as_object() {
+            Some(new_object) if new_object.borrow().as_array_buffer().is_some() => new_object,
+            _ => {
+                return context
+                    .throw_type_error("species constructor did not return an ArrayBuffer")
+            }
+        };
+        if Self::checked_byte_length(&new_object, context)? < new_len {
+            return context
+                .throw_type_error("species constructor returned a too small ArrayBuffer");
+        }
+        if GcObject::equals(&new_object, &object) {
+            return context.throw_type_error("species constructor returned the same ArrayBuffer");
+        }
+
+        let bytes = match object
+            .borrow()
+            .as_array_buffer()
+            .and_then(ArrayBuffer::bytes)
+        {
+            Some(bytes) => bytes[first..first + new_len].to_vec(),
+            None => return context.throw_type_error("cannot slice a detached ArrayBuffer"),
+        };
+        if let Some(target) = new_object
+            .borrow_mut()
+            .as_array_buffer_mut()
+            .and_then(ArrayBuffer::bytes_mut)
+        {
+            target[..new_len].copy_from_slice(&bytes);
+        }
+
+        Ok(new)
+    }
+
+    /// Returns the length of the given `ArrayBuffer` object, throwing a `TypeError` if it is
+    /// detached.
+    fn checked_byte_length(object: &GcObject, context: &mut Context) -> Result<usize> {
+        match object.borrow().as_array_buffer() {
+            Some(buffer) if !buffer.is_detached() => Ok(buffer.byte_length()),
+            _ => Err(context.construct_type_error("ArrayBuffer is detached")),
+        }
+    }
+
+    /// Resolves a relative index argument, which counts from the end when negative, clamping it
+    /// between `0` and `len`.
+    pub(crate) fn relative_index(
+        arg: Option<&Value>,
+        len: usize,
+        default: usize,
+        context: &mut Context,
+    ) -> Result<usize> {
+        let arg = match arg {
+            Some(arg) if !arg.is_undefined() => arg,
+            _ => return Ok(default),
+        };
+        Ok(match arg.to_integer_or_infinity(context)? {
+            IntegerOrInfinity::NegativeInfinity => 0,
+            IntegerOrInfinity::Integer(i) if i < 0 => (len as i64 + i).max(0) as usize,
+            IntegerOrInfinity::Integer(i) => (i as usize).min(len),
+            IntegerOrInfinity::PositiveInfinity => len,
+        })
+    }
+}
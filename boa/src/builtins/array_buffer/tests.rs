@@ -0,0 +1,109 @@
+use crate::{forward, Context};
+
+#[test]
+fn constructor() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "new ArrayBuffer(8).byteLength"), "8");
+    assert_eq!(forward(&mut context, "new ArrayBuffer().byteLength"), "0");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { ArrayBuffer(8) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new ArrayBuffer(-1) } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.prototype.toString.call(new ArrayBuffer(1))"
+        ),
+        "\"[object ArrayBuffer]\""
+    );
+}
+
+#[test]
+fn slice() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var bytes = new Uint8Array([1, 2, 3, 4, 5]);
+        var copy = bytes.buffer.slice(1, -1);
+        bytes[1] = 42;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "copy.byteLength"), "3");
+    assert_eq!(
+        forward(&mut context, "new Uint8Array(copy).join()"),
+        "\"2,3,4\""
+    );
+    assert_eq!(
+        forward(&mut context, "bytes.buffer.slice(-2).byteLength"),
+        "2"
+    );
+    assert_eq!(
+        forward(&mut context, "bytes.buffer.slice(4, 1).byteLength"),
+        "0"
+    );
+}
+
+#[test]
+fn is_view() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var buffer = new ArrayBuffer(4);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "ArrayBuffer.isView(buffer)"), "false");
+    assert_eq!(
+        forward(&mut context, "ArrayBuffer.isView(new Int8Array(buffer))"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "ArrayBuffer.isView(new DataView(buffer))"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "ArrayBuffer.isView([])"), "false");
+}
+
+#[test]
+fn shared_with_host() {
+    let mut context = Context::new();
+
+    let buffer = context.create_array_buffer(vec![1, 2, 3, 4]);
+    context.register_global_property("buffer", buffer.clone(), Default::default());
+
+    let init = r#"
+        var view = new Uint8Array(buffer);
+        view[0] = 10;
+        view[3] = view[3] + 10;
+        "#;
+    forward(&mut context, init);
+
+    let bytes = buffer
+        .borrow_mut()
+        .as_array_buffer_mut()
+        .and_then(|buffer| buffer.detach());
+    assert_eq!(bytes, Some(vec![10, 2, 3, 14]));
+
+    assert_eq!(forward(&mut context, "buffer.byteLength"), "0");
+    assert_eq!(forward(&mut context, "view.length"), "0");
+    assert_eq!(forward(&mut context, "view[0]"), "undefined");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { buffer.slice(0) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
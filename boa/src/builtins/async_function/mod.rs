@@ -0,0 +1,164 @@
+//! This module implements the execution of async functions.
+//!
+//! Calling an async function creates a new promise and starts running the body of the function
+//! in a [`Coroutine`]. Every `await` suspends the coroutine until the awaited promise settles,
+//! and the promise of the call is settled with the completion of the body.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-async-function-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/async_function
+
+use crate::{
+    builtins::{
+        function::{bind_parameters, Captures},
+        promise::{Promise, PromiseCapability},
+    },
+    environment::lexical_environment::Environment,
+    exec::coroutine::{Coroutine, CoroutineState, ResumeAction},
+    gc::{Finalize, Trace},
+    object::FunctionBuilder,
+    syntax::ast::node::{FormalParameter, RcStatementList},
+    Context, Value,
+};
+use std::rc::Rc;
+
+#[cfg(test)]
+mod tests;
+
+/// The state of a running async function call.
+#[derive(Debug, Trace, Finalize)]
+struct AsyncFunctionState {
+    /// The suspended body, `None` while it is running.
+    coroutine: Option<Coroutine>,
+
+    /// The capability of the promise returned by the call.
+    capability: PromiseCapability,
+}
+
+/// Async functions are ordinary function objects with an asynchronous body.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AsyncFunction;
+
+impl AsyncFunction {
+    /// Starts running the body of an async function in the given function environment, in a
+    /// frame of the call stack with the given name.
+    ///
+    /// Returns the promise that is settled once the body completes.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-asyncfunctionstart
+    pub(crate) fn start(
+        function_name: Box<str>,
+        body: RcStatementList,
+        params: Rc<[FormalParameter]>,
+        args: &[Value],
+        environment: Environment,
+        context: &mut Context,
+    ) -> Value {
+        // 1. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let capability = Promise::new_capability(context);
+        let promise = capability.promise().clone();
+
+        // Errors while binding the parameters reject the promise
+        context.push_environment(environment.clone());
+        let result = context.with_strict(body.strict(), |context| {
+            bind_parameters(&params, args, context)
+        });
+        context.pop_environment();
+        if let Err(error) = result {
+            let _ = context.call(capability.reject(), &Value::undefined(), &[error]);
+            return promise;
+        }
+
+        let coroutine = Coroutine::new(function_name, environment, body);
+
+        let state = Captures::new(AsyncFunctionState {
+            coroutine: Some(coroutine),
+            capability,
+        });
+
+        // 2. Perform ! AsyncFunctionStart(promiseCapability, FunctionBody).
+        Self::step(&state, ResumeAction::Next(Value::undefined()), context);
+
+        // 3. Return Completion { [[Type]]: return, [[Value]]: promiseCapability.[[Promise]], [[Target]]: empty }.
+        promise
+    }
+
+    /// Resumes the body of the function until the next `await` or its completion.
+    fn step(state: &Captures, action: ResumeAction, context: &mut Context) {
+        let mut coroutine = match state
+            .downcast_mut::<AsyncFunctionState>()
+            .expect("captures must be an async function state")
+            .coroutine
+            .take()
+        {
+            Some(coroutine) => coroutine,
+            None => return,
+        };
+
+        let result = coroutine.resume(action, context);
+
+        let capability = {
+            let mut state = state
+                .downcast_mut::<AsyncFunctionState>()
+                .expect("captures must be an async function state");
+            if !coroutine.is_done() {
+                state.coroutine = Some(coroutine);
+            }
+            state.capability.clone()
+        };
+
+        match result {
+            // Await ( value ), steps 3-9.
+            Ok(CoroutineState::Suspended(promise)) => {
+                let promise = promise.as_object().expect("only promises can be awaited");
+
+                let on_fulfilled = FunctionBuilder::closure(
+                    context,
+                    |_, args, state, context| {
+                        let value = args.get(0).cloned().unwrap_or_default();
+                        Self::step(state, ResumeAction::Next(value), context);
+                        Ok(Value::undefined())
+                    },
+                    state.clone(),
+                )
+                .name("")
+                .length(1)
+                .build();
+
+                let on_rejected = FunctionBuilder::closure(
+                    context,
+                    |_, args, state, context| {
+                        let reason = args.get(0).cloned().unwrap_or_default();
+                        Self::step(state, ResumeAction::Throw(reason), context);
+                        Ok(Value::undefined())
+                    },
+                    state.clone(),
+                )
+                .name("")
+                .length(1)
+                .build();
+
+                Promise::perform_promise_then(
+                    &promise,
+                    &on_fulfilled.into(),
+                    &on_rejected.into(),
+                    None,
+                    context,
+                );
+            }
+            // AsyncBlockStart ( promiseCapability, asyncBody, asyncContext ), steps 3.d-f.
+            Ok(CoroutineState::Complete(Ok(value))) => {
+                let _ = context.call(capability.resolve(), &Value::undefined(), &[value]);
+            }
+            Ok(CoroutineState::Complete(Err(error))) | Err(error) => {
+                let _ = context.call(capability.reject(), &Value::undefined(), &[error]);
+            }
+        }
+    }
+}
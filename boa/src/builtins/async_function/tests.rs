@@ -0,0 +1,315 @@
+use crate::{forward, Context};
+
+#[test]
+fn returns_promise() {
+    let mut context = Context::new();
+    let init = r#"
+        async function f() { return 1; }
+        var result;
+        var promise = f();
+        promise.then(value => { result = value; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(promise) === Promise.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "result"), "1");
+}
+
+#[test]
+fn await_value() {
+    let mut context = Context::new();
+    let init = r#"
+        async function f(x) {
+            let a = await x;
+            let b = await new Promise(resolve => resolve(2));
+            return a + b;
+        }
+        var result;
+        f(40).then(value => { result = value; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result"), "42");
+}
+
+#[test]
+fn await_suspends() {
+    let mut context = Context::new();
+    let init = r#"
+        var order = [];
+        async function f() {
+            order.push("before");
+            await undefined;
+            order.push("after");
+        }
+        f();
+        order.push("sync");
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "order.join()"),
+        "\"before,sync,after\""
+    );
+}
+
+#[test]
+fn await_rejection_throws() {
+    let mut context = Context::new();
+    let init = r#"
+        async function fail() { throw "error"; }
+        async function f() {
+            try {
+                await fail();
+            } catch (e) {
+                return "caught " + e;
+            }
+        }
+        var result;
+        f().then(value => { result = value; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result"), "\"caught error\"");
+}
+
+#[test]
+fn throw_rejects() {
+    let mut context = Context::new();
+    let init = r#"
+        async function f() {
+            await 1;
+            throw new Error("failed");
+        }
+        var result;
+        f().then(undefined, reason => { result = reason.message; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result"), "\"failed\"");
+}
+
+#[test]
+fn nested_calls() {
+    let mut context = Context::new();
+    let init = r#"
+        async function inner(x) { return (await x) * 2; }
+        async function outer() {
+            var sum = 0;
+            for (var i = 1; i <= 3; i++) {
+                sum += await inner(i);
+            }
+            return sum;
+        }
+        var result;
+        outer().then(value => { result = value; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result"), "12");
+}
+
+#[test]
+fn async_function_expression() {
+    let mut context = Context::new();
+    let init = r#"
+        var f = async function named() { return await "value"; };
+        var result;
+        f().then(value => { result = value; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result"), "\"value\"");
+    assert_eq!(forward(&mut context, "f.name"), "\"named\"");
+}
+
+#[test]
+fn not_constructable() {
+    let mut context = Context::new();
+    let init = r#"
+        async function f() {}
+        try { new f(); } catch (e) { e.name }
+        "#;
+    assert_eq!(forward(&mut context, init), "\"TypeError\"");
+}
+
+#[test]
+fn await_outside_async_function() {
+    let mut context = Context::new();
+    let result = forward(&mut context, "function f() { await 1; }");
+    assert!(result.starts_with("Uncaught \"SyntaxError\""));
+}
+
+#[test]
+fn pending_async_function_is_collected() {
+    let mut context = Context::new();
+    let init = r#"
+        var collected = [];
+        var registry = new FinalizationRegistry(held => collected.push(held));
+        (function () {
+            var held = {};
+            registry.register(held, "held");
+            async function f(value) { await new Promise(() => {}); return value; }
+            f(held);
+        })();
+        "#;
+    forward(&mut context, init);
+
+    crate::gc::force_collect();
+    context.run_jobs().unwrap();
+    crate::gc::force_collect();
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "collected.join()"), "\"held\"");
+}
+
+#[test]
+fn async_arrow_function() {
+    let mut context = Context::new();
+    let init = r#"
+        var o = {
+            value: 2,
+            run() { return (async x => this.value * await x)(3); },
+        };
+        var f = async (a, b) => await a + b;
+        var results = [];
+        o.run().then(value => results.push(value));
+        f(1, 2).then(value => results.push(value));
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "results.join()"), "\"6,3\"");
+    assert_eq!(
+        forward(&mut context, "try { new f(); } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn async_methods() {
+    let mut context = Context::new();
+    let init = r#"
+        var o = { async m() { return await 1; }, async: 2 };
+        class C {
+            async m() { return await 3; }
+            static async s() { return 4; }
+        }
+        var results = [];
+        o.m().then(value => results.push(value));
+        new C().m().then(value => results.push(value));
+        C.s().then(value => results.push(value));
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "results.join()"), "\"4,1,3\"");
+    assert_eq!(forward(&mut context, "o.async"), "2");
+}
+
+#[test]
+fn stack_after_await() {
+    let mut context = Context::new();
+    let init = r#"
+        function frames() {
+            return new Error().stack.split("\n").slice(2).map(line => line.trim().split(" ")[1]).join();
+        }
+        var results = [];
+        async function f() {
+            results.push(frames());
+            await null;
+            results.push(frames());
+        }
+        var o = {
+            async m() {
+                await f();
+                results.push(frames());
+            }
+        };
+        o.m();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "results.join(' ')"),
+        "\"f,m,<global> f,<global> m,<global>\""
+    );
+}
+
+#[test]
+fn many_pending_async_functions() {
+    let mut context = Context::new();
+    let init = r#"
+        var started = 0;
+        async function f() {
+            started++;
+            await new Promise(() => {});
+        }
+        for (var i = 0; i < 70000; i++) {
+            f();
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "started"), "70000");
+}
+
+#[test]
+fn suspended_values_are_collected() {
+    let mut context = Context::new();
+    let init = r#"
+        var collected = [];
+        var registry = new FinalizationRegistry(held => collected.push(held));
+        (function () {
+            var held = {};
+            registry.register(held, "held");
+            async function f() { return [held, await new Promise(() => {})]; }
+            held.promise = f();
+        })();
+        "#;
+    forward(&mut context, init);
+
+    crate::gc::force_collect();
+    context.run_jobs().unwrap();
+    crate::gc::force_collect();
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "collected.join()"), "\"held\"");
+}
+
+#[test]
+fn resume_inside_expressions_and_statements() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        var calls = 0;
+        function count() { return ++calls; }
+        async function f() {
+            var sum = 0;
+            for (var i = 0; i < 3; i++) {
+                sum += await i;
+            }
+            log.push([count(), await count(), count()].join("-"));
+            try {
+                await Promise.reject("error");
+            } catch (e) {
+                log.push("caught " + e);
+            } finally {
+                log.push(await "finally");
+            }
+            var { a, b = await "b" } = { a: await "a" };
+            log.push(a + b);
+            log.push(Math.max(1, await 2, ...[await 3]));
+            log.push(`t${await 1}${await 2}`);
+            switch (await "x") {
+                case await "y":
+                    log.push("y");
+                    break;
+                case "x":
+                    log.push(await "x");
+            }
+            var o = { p: await 1, q: await 2 };
+            log.push(o.p + "" + o.q);
+            class C { [await "m"]() { return "method"; } }
+            log.push(new C().m());
+            return sum;
+        }
+        f().then(sum => log.push(sum));
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"1-2-3,caught error,finally,ab,3,t12,x,12,method,3\""
+    );
+}
@@ -59,6 +59,34 @@ impl BigInt {
         self.0.to_f64().unwrap_or(f64::INFINITY)
     }
 
+    /// Converts the BigInt to a 64-bit unsigned integer, wrapping it modulo 2^64.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-tobiguint64
+    #[inline]
+    pub(crate) fn to_big_uint64(&self) -> u64 {
+        let (sign, digits) = self.0.to_u64_digits();
+        let low = digits.first().copied().unwrap_or(0);
+        if sign == num_bigint::Sign::Minus {
+            low.wrapping_neg()
+        } else {
+            low
+        }
+    }
+
+    /// Converts the BigInt to a 64-bit signed integer, wrapping it modulo 2^64.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-tobigint64
+    #[inline]
+    pub(crate) fn to_big_int64(&self) -> i64 {
+        self.to_big_uint64() as i64
+    }
+
     #[inline]
     pub(crate) fn from_str(string: &str) -> Option<Self> {
         match num_bigint::BigInt::from_str(string) {
@@ -74,6 +102,12 @@ impl From<i64> for BigInt {
     }
 }
 
+impl From<u64> for BigInt {
+    fn from(n: u64) -> BigInt {
+        BigInt(num_bigint::BigInt::from(n))
+    }
+}
+
 impl From<i32> for BigInt {
     fn from(n: i32) -> BigInt {
         BigInt(num_bigint::BigInt::from(n))
@@ -90,7 +90,7 @@ fn bigint_function_conversion_from_rational_with_fractional_part() {
     "#;
     assert_eq!(
         forward(&mut context, scenario),
-        "\"TypeError: The number 0.1 cannot be converted to a BigInt because it is not an integer\""
+        "\"TypeError: The number 0.1 cannot be converted to a BigInt because it is not an integer at line 3, col 13\""
     );
 }
 
@@ -107,7 +107,7 @@ fn bigint_function_conversion_from_null() {
     "#;
     assert_eq!(
         forward(&mut context, scenario),
-        "\"TypeError: cannot convert null to a BigInt\""
+        "\"TypeError: cannot convert null to a BigInt at line 3, col 13\""
     );
 }
 
@@ -124,7 +124,7 @@ fn bigint_function_conversion_from_undefined() {
     "#;
     assert_eq!(
         forward(&mut context, scenario),
-        "\"TypeError: cannot convert undefined to a BigInt\""
+        "\"TypeError: cannot convert undefined to a BigInt at line 3, col 13\""
     );
 }
 
@@ -314,18 +314,15 @@ impl Console {
     /// [spec]: https://console.spec.whatwg.org/#trace
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/trace
     pub(crate) fn trace(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        if !args.is_empty() {
-            logger(
-                LogMessage::Log(formatter(args, context)?),
-                context.console(),
-            );
+        let label = if args.is_empty() {
+            "Trace".to_string()
+        } else {
+            format!("Trace: {}", formatter(args, context)?)
+        };
+        logger(LogMessage::Log(label), context.console());
 
-            /* TODO: get and print stack trace */
-            logger(
-                LogMessage::Log("Not implemented: <stack trace>".to_string()),
-                context.console(),
-            )
-        }
+        let stack_trace = context.executor().call_stack.capture();
+        logger(LogMessage::Log(stack_trace.to_string()), context.console());
 
         Ok(Value::undefined())
     }
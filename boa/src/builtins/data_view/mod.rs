@@ -0,0 +1,523 @@
+//! This module implements the global `DataView` object.
+//!
+//! A `DataView` provides a low-level interface for reading and writing numbers of any type to an
+//! `ArrayBuffer`, in either byte order.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-dataview-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView
+
+use crate::{
+    builtins::{typed_array::TypedArrayKind, ArrayBuffer, BuiltIn},
+    gc::{Finalize, Trace},
+    object::{ConstructorBuilder, FunctionBuilder, GcObject, Object, ObjectData, PROTOTYPE},
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The data of a `DataView` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct DataView {
+    buffer: GcObject,
+    byte_offset: usize,
+    byte_length: usize,
+}
+
+impl BuiltIn for DataView {
+    const NAME: &'static str = "DataView";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let get_buffer = FunctionBuilder::new(context, Self::get_buffer)
+            .name("get buffer")
+            .constructable(false)
+            .build();
+        let get_byte_length = FunctionBuilder::new(context, Self::get_byte_length)
+            .name("get byteLength")
+            .constructable(false)
+            .build();
+        let get_byte_offset = FunctionBuilder::new(context, Self::get_byte_offset)
+            .name("get byteOffset")
+            .constructable(false)
+            .build();
+
+        let data_view = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().data_view_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .accessor("buffer", Some(get_buffer), None, Attribute::CONFIGURABLE)
+        .accessor(
+            "byteLength",
+            Some(get_byte_length),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .accessor(
+            "byteOffset",
+            Some(get_byte_offset),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .method(Self::get_big_int64, "getBigInt64", 1)
+        .method(Self::get_big_uint64, "getBigUint64", 1)
+        .method(Self::get_float32, "getFloat32", 1)
+        .method(Self::get_float64, "getFloat64", 1)
+        .method(Self::get_int8, "getInt8", 1)
+        .method(Self::get_int16, "getInt16", 1)
+        .method(Self::get_int32, "getInt32", 1)
+        .method(Self::get_uint8, "getUint8", 1)
+        .method(Self::get_uint16, "getUint16", 1)
+        .method(Self::get_uint32, "getUint32", 1)
+        .method(Self::set_big_int64, "setBigInt64", 2)
+        .method(Self::set_big_uint64, "setBigUint64", 2)
+        .method(Self::set_float32, "setFloat32", 2)
+        .method(Self::set_float64, "setFloat64", 2)
+        .method(Self::set_int8, "setInt8", 2)
+        .method(Self::set_int16, "setInt16", 2)
+        .method(Self::set_int32, "setInt32", 2)
+        .method(Self::set_uint8, "setUint8", 2)
+        .method(Self::set_uint16, "setUint16", 2)
+        .method(Self::set_uint32, "setUint32", 2)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build();
+
+        (Self::NAME, data_view.into(), Self::attribute())
+    }
+}
+
+impl DataView {
+    pub(crate) const LENGTH: usize = 1;
+
+    /// Returns the `ArrayBuffer` object viewed by the `DataView`.
+    #[inline]
+    pub fn buffer(&self) -> &GcObject {
+        &self.buffer
+    }
+
+    /// Returns `true` if the buffer viewed by the `DataView` has been detached.
+    #[inline]
+    pub fn is_detached(&self) -> bool {
+        self.buffer
+            .borrow()
+            .as_array_buffer()
+            .map_or(true, ArrayBuffer::is_detached)
+    }
+
+    /// `new DataView( buffer [ , byteOffset [ , byteLength ] ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview-buffer-byteoffset-bytelength
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/DataView
+    pub(crate) fn constructor(
+        new_target: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin DataView constructor without new is forbidden",
+            );
+        }
+
+        let buffer = match args.get(0).and_then(Value::as_object) {
+            Some(buffer) if buffer.borrow().as_array_buffer().is_some() => buffer,
+            _ => return context.throw_type_error("DataView: argument is not an ArrayBuffer"),
+        };
+        let offset = args.get(1).cloned().unwrap_or_default().to_index(context)?;
+
+        let buffer_byte_length = match buffer.borrow().as_array_buffer() {
+            Some(data) if !data.is_detached() => data.byte_length(),
+            _ => return context.throw_type_error("DataView: ArrayBuffer is detached"),
+        };
+        if offset > buffer_byte_length {
+            return context.throw_range_error(format!(
+                "DataView: start offset {} is outside the bounds of the buffer",
+                offset
+            ));
+        }
+
+        let byte_length = match args.get(2) {
+            Some(length) if !length.is_undefined() => {
+                let byte_length = length.to_index(context)?;
+                if offset + byte_length > buffer_byte_length {
+                    return context
+                        .throw_range_error(format!("DataView: invalid length {}", byte_length));
+                }
+                byte_length
+            }
+            _ => buffer_byte_length - offset,
+        };
+
+        let prototype = match new_target.as_object() {
+            Some(object) => object.get(&PROTOTYPE.into(), new_target.clone(), context)?,
+            None => Value::undefined(),
+        };
+        let prototype = if prototype.is_object() {
+            prototype
+        } else {
+            context
+                .standard_objects()
+                .data_view_object()
+                .prototype()
+                .into()
+        };
+
+        let data_view = GcObject::new(Object::with_prototype(
+            prototype,
+            ObjectData::DataView(Self {
+                buffer,
+                byte_offset: offset,
+                byte_length,
+            }),
+        ));
+        Ok(data_view.into())
+    }
+
+    /// Gets the data of a `DataView` receiver, throwing a `TypeError` for any other value.
+    fn this_data_view(this: &Value, context: &mut Context) -> Result<Self> {
+        let data_view = this
+            .as_object()
+            .and_then(|object| object.borrow().as_data_view().cloned());
+        match data_view {
+            Some(data_view) => Ok(data_view),
+            None => Err(context.construct_type_error("value is not a DataView")),
+        }
+    }
+
+    /// `get DataView.prototype.buffer`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-dataview.prototype.buffer
+    fn get_buffer(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        Ok(Self::this_data_view(this, context)?.buffer.clone().into())
+    }
+
+    /// `get DataView.prototype.byteLength`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-dataview.prototype.bytelength
+    fn get_byte_length(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let data_view = Self::this_data_view(this, context)?;
+        if data_view.is_detached() {
+            return context.throw_type_error("DataView: ArrayBuffer is detached");
+        }
+        Ok(data_view.byte_length.into())
+    }
+
+    /// `get DataView.prototype.byteOffset`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-dataview.prototype.byteoffset
+    fn get_byte_offset(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let data_view = Self::this_data_view(this, context)?;
+        if data_view.is_detached() {
+            return context.throw_type_error("DataView: ArrayBuffer is detached");
+        }
+        Ok(data_view.byte_offset.into())
+    }
+
+    /// The abstract operation `GetViewValue`.
+    ///
+    /// The byte order is big endian unless the `littleEndian` argument is truthy.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getviewvalue
+    fn get_view_value(
+        this: &Value,
+        args: &[Value],
+        kind: TypedArrayKind,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let data_view = Self::this_data_view(this, context)?;
+        let index = args.get(0).cloned().unwrap_or_default().to_index(context)?;
+        let little_endian = args.get(1).map_or(false, Value::to_boolean);
+
+        let start = data_view.checked_start(index, kind, context)?;
+        let buffer = data_view.buffer.borrow();
+        let bytes = buffer
+            .as_array_buffer()
+            .and_then(ArrayBuffer::bytes)
+            .expect("buffer was checked not to be detached");
+        Ok(kind.read(&bytes[start..], little_endian))
+    }
+
+    /// The abstract operation `SetViewValue`.
+    ///
+    /// The byte order is big endian unless the `littleEndian` argument is truthy.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-setviewvalue
+    fn set_view_value(
+        this: &Value,
+        args: &[Value],
+        kind: TypedArrayKind,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let data_view = Self::this_data_view(this, context)?;
+        let index = args.get(0).cloned().unwrap_or_default().to_index(context)?;
+        let value = args.get(1).cloned().unwrap_or_default();
+        let value = kind.to_numeric(&value, context)?;
+        let little_endian = args.get(2).map_or(false, Value::to_boolean);
+
+        let start = data_view.checked_start(index, kind, context)?;
+        let mut buffer = data_view.buffer.borrow_mut();
+        let bytes = buffer
+            .as_array_buffer_mut()
+            .and_then(ArrayBuffer::bytes_mut)
+            .expect("buffer was checked not to be detached");
+        kind.write(&value, &mut bytes[start..], little_endian);
+        Ok(Value::undefined())
+    }
+
+    /// Returns the position in the buffer of an element at the given index of the view, throwing
+    /// if the buffer is detached or the element is out of the bounds of the view.
+    fn checked_start(
+        &self,
+        index: usize,
+        kind: TypedArrayKind,
+        context: &mut Context,
+    ) -> Result<usize> {
+        if self.is_detached() {
+            return Err(context.construct_type_error("DataView: ArrayBuffer is detached"));
+        }
+        match index.checked_add(kind.element_size()) {
+            Some(end) if end <= self.byte_length => Ok(self.byte_offset + index),
+            _ => {
+                Err(context
+                    .construct_range_error("DataView: offset is outside the bounds of the view"))
+            }
+        }
+    }
+
+    /// `DataView.prototype.getBigInt64( byteOffset [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.getbigint64
+    fn get_big_int64(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::get_view_value(this, args, TypedArrayKind::BigInt64, context)
+    }
+
+    /// `DataView.prototype.getBigUint64( byteOffset [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.getbiguint64
+    fn get_big_uint64(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::get_view_value(this, args, TypedArrayKind::BigUint64, context)
+    }
+
+    /// `DataView.prototype.getFloat32( byteOffset [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.getfloat32
+    fn get_float32(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::get_view_value(this, args, TypedArrayKind::Float32, context)
+    }
+
+    /// `DataView.prototype.getFloat64( byteOffset [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.getfloat64
+    fn get_float64(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::get_view_value(this, args, TypedArrayKind::Float64, context)
+    }
+
+    /// `DataView.prototype.getInt8( byteOffset )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.getint8
+    fn get_int8(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::get_view_value(this, args, TypedArrayKind::Int8, context)
+    }
+
+    /// `DataView.prototype.getInt16( byteOffset [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.getint16
+    fn get_int16(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::get_view_value(this, args, TypedArrayKind::Int16, context)
+    }
+
+    /// `DataView.prototype.getInt32( byteOffset [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.getint32
+    fn get_int32(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::get_view_value(this, args, TypedArrayKind::Int32, context)
+    }
+
+    /// `DataView.prototype.getUint8( byteOffset )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.getuint8
+    fn get_uint8(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::get_view_value(this, args, TypedArrayKind::Uint8, context)
+    }
+
+    /// `DataView.prototype.getUint16( byteOffset [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.getuint16
+    fn get_uint16(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::get_view_value(this, args, TypedArrayKind::Uint16, context)
+    }
+
+    /// `DataView.prototype.getUint32( byteOffset [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.getuint32
+    fn get_uint32(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::get_view_value(this, args, TypedArrayKind::Uint32, context)
+    }
+
+    /// `DataView.prototype.setBigInt64( byteOffset, value [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.setbigint64
+    fn set_big_int64(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::set_view_value(this, args, TypedArrayKind::BigInt64, context)
+    }
+
+    /// `DataView.prototype.setBigUint64( byteOffset, value [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.setbiguint64
+    fn set_big_uint64(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::set_view_value(this, args, TypedArrayKind::BigUint64, context)
+    }
+
+    /// `DataView.prototype.setFloat32( byteOffset, value [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.setfloat32
+    fn set_float32(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::set_view_value(this, args, TypedArrayKind::Float32, context)
+    }
+
+    /// `DataView.prototype.setFloat64( byteOffset, value [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.setfloat64
+    fn set_float64(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::set_view_value(this, args, TypedArrayKind::Float64, context)
+    }
+
+    /// `DataView.prototype.setInt8( byteOffset, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.setint8
+    fn set_int8(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::set_view_value(this, args, TypedArrayKind::Int8, context)
+    }
+
+    /// `DataView.prototype.setInt16( byteOffset, value [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.setint16
+    fn set_int16(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::set_view_value(this, args, TypedArrayKind::Int16, context)
+    }
+
+    /// `DataView.prototype.setInt32( byteOffset, value [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.setint32
+    fn set_int32(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::set_view_value(this, args, TypedArrayKind::Int32, context)
+    }
+
+    /// `DataView.prototype.setUint8( byteOffset, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.setuint8
+    fn set_uint8(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::set_view_value(this, args, TypedArrayKind::Uint8, context)
+    }
+
+    /// `DataView.prototype.setUint16( byteOffset, value [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.setuint16
+    fn set_uint16(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::set_view_value(this, args, TypedArrayKind::Uint16, context)
+    }
+
+    /// `DataView.prototype.setUint32( byteOffset, value [ , littleEndian ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.setuint32
+    fn set_uint32(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::set_view_value(this, args, TypedArrayKind::Uint32, context)
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{forward, Context};
+
+#[test]
+fn constructor() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var buffer = new ArrayBuffer(8);
+        var view = new DataView(buffer, 2, 4);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "view.buffer === buffer"), "true");
+    assert_eq!(forward(&mut context, "view.byteOffset"), "2");
+    assert_eq!(forward(&mut context, "view.byteLength"), "4");
+    assert_eq!(
+        forward(&mut context, "new DataView(buffer, 3).byteLength"),
+        "5"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new DataView(buffer, 9) } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new DataView(buffer, 4, 5) } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new DataView({}) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn endianness() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var view = new DataView(new ArrayBuffer(8));
+        view.setUint16(0, 0x1234);
+        view.setUint16(2, 0x1234, true);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "view.getUint8(0)"), "18");
+    assert_eq!(forward(&mut context, "view.getUint8(1)"), "52");
+    assert_eq!(forward(&mut context, "view.getUint8(2)"), "52");
+    assert_eq!(forward(&mut context, "view.getUint8(3)"), "18");
+    assert_eq!(forward(&mut context, "view.getUint16(2)"), "13330");
+    assert_eq!(forward(&mut context, "view.getUint16(2, true)"), "4660");
+}
+
+#[test]
+fn round_trip() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var view = new DataView(new ArrayBuffer(8));
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "view.setInt8(0, -1); view.getInt8(0)"),
+        "-1"
+    );
+    assert_eq!(forward(&mut context, "view.getUint8(0)"), "255");
+    assert_eq!(
+        forward(
+            &mut context,
+            "view.setInt32(0, -2, true); view.getInt32(0, true)"
+        ),
+        "-2"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "view.setUint32(4, 4294967295); view.getUint32(4)"
+        ),
+        "4294967295"
+    );
+    assert_eq!(
+        forward(&mut context, "view.setFloat32(0, 1.5); view.getFloat32(0)"),
+        "1.5"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "view.setFloat64(0, Math.PI, true); view.getFloat64(0, true) === Math.PI"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "view.setBigInt64(0, -5n); view.getBigInt64(0)"
+        ),
+        "-5n"
+    );
+    assert_eq!(
+        forward(&mut context, "view.getBigUint64(0)"),
+        "18446744073709551611n"
+    );
+}
+
+#[test]
+fn out_of_bounds() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var view = new DataView(new ArrayBuffer(4), 1);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { view.getInt32(0) } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { view.setInt8(3, 1) } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { view.getInt8(-1) } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { view.setBigInt64(0, undefined) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
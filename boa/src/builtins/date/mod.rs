@@ -1,15 +1,20 @@
+mod time_zone;
+
 #[cfg(test)]
 mod tests;
 
+pub use time_zone::{TimeZone, TimeZoneError};
+
 use crate::{
     builtins::BuiltIn,
     gc::{empty_trace, Finalize, Trace},
-    object::{ConstructorBuilder, ObjectData, PROTOTYPE},
+    object::{ConstructorBuilder, FunctionBuilder, ObjectData, PROTOTYPE},
     property::Attribute,
+    symbol::WellKnownSymbols,
     value::{PreferredType, Value},
     BoaProfiler, Context, Result,
 };
-use chrono::{prelude::*, Duration, LocalResult};
+use chrono::{prelude::*, Duration, TimeZone as _};
 use std::fmt::Display;
 
 /// The number of nanoseconds in a millisecond.
@@ -34,15 +39,6 @@ macro_rules! check_normal_opt {
     };
 }
 
-#[inline]
-fn ignore_ambiguity<T>(result: LocalResult<T>) -> Option<T> {
-    match result {
-        LocalResult::Ambiguous(v, _) => Some(v),
-        LocalResult::Single(v) => Some(v),
-        LocalResult::None => None,
-    }
-}
-
 macro_rules! getter_method {
     ($name:ident) => {{
         fn get_value(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
@@ -50,36 +46,48 @@ macro_rules! getter_method {
         }
         get_value
     }};
-    (Self::$name:ident) => {{
-        fn get_value(_: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
-            Ok(Value::from(Date::$name()))
+    (local $name:ident) => {{
+        fn get_value(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+            let date = this_time_value(this, context)?;
+            Ok(Value::from(date.$name(context.time_zone())))
         }
         get_value
     }};
 }
 
+/// Converts the argument of a setter at the given index to a number, returning `None` if it is
+/// missing or not finite.
+fn setter_argument(args: &[Value], index: usize, context: &mut Context) -> Option<f64> {
+    args.get(index).and_then(|value| {
+        value.to_numeric_number(context).map_or_else(
+            |_| None,
+            |value| {
+                if value == 0f64 || value.is_normal() {
+                    Some(value)
+                } else {
+                    None
+                }
+            },
+        )
+    })
+}
+
 macro_rules! setter_method {
     ($name:ident($($e:expr),* $(,)?)) => {{
         fn set_value(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
             let mut result = this_time_value(this, context)?;
-            result.$name(
-                $(
-                    args
-                        .get($e)
-                        .and_then(|value| {
-                            value.to_numeric_number(context).map_or_else(
-                                |_| None,
-                                |value| {
-                                    if value == 0f64 || value.is_normal() {
-                                        Some(value)
-                                    } else {
-                                        None
-                                    }
-                                },
-                            )
-                        })
-                ),*
-            );
+            result.$name($(setter_argument(args, $e, context)),*);
+
+            this.set_data(ObjectData::Date(result));
+            Ok(Value::from(result.get_time()))
+        }
+        set_value
+    }};
+    (local $name:ident($($e:expr),* $(,)?)) => {{
+        fn set_value(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+            let mut result = this_time_value(this, context)?;
+            let time_zone = context.time_zone().clone();
+            result.$name(&time_zone, $(setter_argument(args, $e, context)),*);
 
             this.set_data(ObjectData::Date(result));
             Ok(Value::from(result.get_time()))
@@ -91,12 +99,10 @@ macro_rules! setter_method {
 #[derive(Debug, Finalize, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Date(Option<NaiveDateTime>);
 
+/// Formats the `Date` like `Date.prototype.toString()`, in the time zone of the host.
 impl Display for Date {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self.to_local() {
-            Some(v) => write!(f, "{}", v.format("%a %b %d %Y %H:%M:%S GMT%:z")),
-            _ => write!(f, "Invalid Date"),
-        }
+        f.write_str(&self.to_string_in(&TimeZone::local()))
     }
 }
 
@@ -122,21 +128,33 @@ impl BuiltIn for Date {
     fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
+        let to_primitive = FunctionBuilder::new(context, Self::to_primitive)
+            .name("[Symbol.toPrimitive]")
+            .length(1)
+            .callable(true)
+            .constructable(false)
+            .build();
+
         let date_object = ConstructorBuilder::new(context, Self::constructor)
             .name(Self::NAME)
             .length(Self::LENGTH)
-            .method(getter_method!(get_date), "getDate", 0)
-            .method(getter_method!(get_day), "getDay", 0)
-            .method(getter_method!(get_full_year), "getFullYear", 0)
-            .method(getter_method!(get_hours), "getHours", 0)
-            .method(getter_method!(get_milliseconds), "getMilliseconds", 0)
-            .method(getter_method!(get_minutes), "getMinutes", 0)
-            .method(getter_method!(get_month), "getMonth", 0)
-            .method(getter_method!(get_seconds), "getSeconds", 0)
+            .property(
+                WellKnownSymbols::to_primitive(),
+                to_primitive,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .method(getter_method!(local get_date), "getDate", 0)
+            .method(getter_method!(local get_day), "getDay", 0)
+            .method(getter_method!(local get_full_year), "getFullYear", 0)
+            .method(getter_method!(local get_hours), "getHours", 0)
+            .method(getter_method!(local get_milliseconds), "getMilliseconds", 0)
+            .method(getter_method!(local get_minutes), "getMinutes", 0)
+            .method(getter_method!(local get_month), "getMonth", 0)
+            .method(getter_method!(local get_seconds), "getSeconds", 0)
             .method(getter_method!(get_time), "getTime", 0)
-            .method(getter_method!(get_year), "getYear", 0)
+            .method(getter_method!(local get_year), "getYear", 0)
             .method(
-                getter_method!(Self::get_timezone_offset),
+                getter_method!(local get_timezone_offset),
                 "getTimezoneOffset",
                 0,
             )
@@ -152,14 +170,22 @@ impl BuiltIn for Date {
             .method(getter_method!(get_utc_minutes), "getUTCMinutes", 0)
             .method(getter_method!(get_utc_month), "getUTCMonth", 0)
             .method(getter_method!(get_utc_seconds), "getUTCSeconds", 0)
-            .method(setter_method!(set_date(0)), "setDate", 1)
-            .method(setter_method!(set_full_year(0, 1, 2)), "setFullYear", 1)
-            .method(setter_method!(set_hours(0, 1, 2, 3)), "setHours", 1)
-            .method(setter_method!(set_milliseconds(0)), "setMilliseconds", 1)
-            .method(setter_method!(set_minutes(0, 1, 2)), "setMinutes", 1)
-            .method(setter_method!(set_month(0, 1)), "setMonth", 1)
-            .method(setter_method!(set_seconds(0, 1)), "setSeconds", 1)
-            .method(setter_method!(set_year(0, 1, 2)), "setYear", 1)
+            .method(setter_method!(local set_date(0)), "setDate", 1)
+            .method(
+                setter_method!(local set_full_year(0, 1, 2)),
+                "setFullYear",
+                1,
+            )
+            .method(setter_method!(local set_hours(0, 1, 2, 3)), "setHours", 1)
+            .method(
+                setter_method!(local set_milliseconds(0)),
+                "setMilliseconds",
+                1,
+            )
+            .method(setter_method!(local set_minutes(0, 1, 2)), "setMinutes", 1)
+            .method(setter_method!(local set_month(0, 1)), "setMonth", 1)
+            .method(setter_method!(local set_seconds(0, 1)), "setSeconds", 1)
+            .method(setter_method!(local set_year(0, 1, 2)), "setYear", 1)
             .method(setter_method!(set_time(0)), "setTime", 1)
             .method(setter_method!(set_utc_date(0)), "setUTCDate", 1)
             .method(
@@ -176,13 +202,23 @@ impl BuiltIn for Date {
             .method(setter_method!(set_utc_minutes(0, 1, 2)), "setUTCMinutes", 1)
             .method(setter_method!(set_utc_month(0, 1)), "setUTCMonth", 1)
             .method(setter_method!(set_utc_seconds(0, 1)), "setUTCSeconds", 1)
-            .method(getter_method!(to_date_string), "toDateString", 0)
+            .method(getter_method!(local to_date_string), "toDateString", 0)
             .method(getter_method!(to_gmt_string), "toGMTString", 0)
             .method(getter_method!(to_iso_string), "toISOString", 0)
             .method(getter_method!(to_json), "toJSON", 0)
-            // Locale strings
-            .method(getter_method!(to_string), "toString", 0)
-            .method(getter_method!(to_time_string), "toTimeString", 0)
+            .method(
+                getter_method!(local to_locale_date_string),
+                "toLocaleDateString",
+                0,
+            )
+            .method(getter_method!(local to_locale_string), "toLocaleString", 0)
+            .method(
+                getter_method!(local to_locale_time_string),
+                "toLocaleTimeString",
+                0,
+            )
+            .method(getter_method!(local to_string_in), "toString", 0)
+            .method(getter_method!(local to_time_string), "toTimeString", 0)
             .method(getter_method!(to_utc_string), "toUTCString", 0)
             .method(getter_method!(value_of), "valueOf", 0)
             .static_method(Self::now, "now", 0)
@@ -207,19 +243,18 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-timeclip
     #[inline]
     pub fn time_clip(time: f64) -> Option<f64> {
-        if time.abs() > 8.64e15 {
+        if !time.is_finite() || time.abs() > 8.64e15 {
             None
         } else {
             Some(time)
         }
     }
 
-    /// Converts the `Date` to a local `DateTime`.
+    /// Converts the `Date` to a `DateTime` in the given time zone.
     ///
     /// If the `Date` is invalid (i.e. NAN), this function will return `None`.
-    pub fn to_local(&self) -> Option<DateTime<Local>> {
-        self.0
-            .map(|utc| Local::now().timezone().from_utc_datetime(&utc))
+    pub fn to_local(&self, time_zone: &TimeZone) -> Option<DateTime<FixedOffset>> {
+        self.0.map(|utc| time_zone.to_local(&utc))
     }
 
     /// Converts the `Date` to a UTC `DateTime`.
@@ -232,12 +267,13 @@ impl Date {
 
     /// Optionally sets the individual components of the `Date`.
     ///
-    /// Each component does not have to be within the range of valid values. For example, if `month` is too large
-    /// then `year` will be incremented by the required amount.
+    /// The components are in the local time of the given time zone. Each component does not have to be within the
+    /// range of valid values. For example, if `month` is too large then `year` will be incremented by the required
+    /// amount.
     #[allow(clippy::too_many_arguments)]
     pub fn set_components(
         &mut self,
-        utc: bool,
+        time_zone: &TimeZone,
         year: Option<f64>,
         month: Option<f64>,
         day: Option<f64>,
@@ -313,11 +349,11 @@ impl Date {
             return;
         }
 
-        let naive = if utc {
-            self.to_utc().map(|dt| dt.naive_utc())
-        } else {
-            self.to_local().map(|dt| dt.naive_local())
-        };
+        // Setting the year of an invalid date starts from +0, any other component leaves it invalid.
+        let naive = self
+            .to_local(time_zone)
+            .map(|dt| dt.naive_local())
+            .or_else(|| year.map(|_| NaiveDateTime::from_timestamp(0, 0)));
 
         self.0 = naive.and_then(|naive| {
             let year = year.unwrap_or_else(|| naive.year() as f64) as i32;
@@ -344,13 +380,7 @@ impl Date {
 
             NaiveDate::from_ymd_opt(year, month + 1, day + 1)
                 .and_then(|dt| dt.and_hms(0, 0, 0).checked_add_signed(duration))
-                .and_then(|dt| {
-                    if utc {
-                        Some(Utc.from_utc_datetime(&dt).naive_utc())
-                    } else {
-                        ignore_ambiguity(Local.from_local_datetime(&dt)).map(|dt| dt.naive_utc())
-                    }
-                })
+                .map(|dt| time_zone.to_utc(&dt))
                 .filter(|dt| Self::time_clip(dt.timestamp_millis() as f64).is_some())
         });
     }
@@ -371,7 +401,7 @@ impl Date {
         context: &mut Context,
     ) -> Result<Value> {
         if new_target.is_undefined() {
-            Ok(Self::make_date_string())
+            Ok(Self::make_date_string(context))
         } else {
             let prototype = new_target
                 .as_object()
@@ -405,8 +435,9 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date-constructor
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/Date
-    pub(crate) fn make_date_string() -> Value {
-        Value::from(Local::now().to_rfc3339())
+    pub(crate) fn make_date_string(context: &mut Context) -> Value {
+        let now = Utc::now().naive_utc();
+        Value::from(context.time_zone().to_local(&now).to_rfc3339())
     }
 
     /// `Date()`
@@ -448,12 +479,11 @@ impl Date {
                     Ok(dt) => Some(dt.naive_utc()),
                     _ => None,
                 },
-                tv => {
-                    let tv = tv.to_number(context)?;
+                tv => Self::time_clip(tv.to_number(context)?).and_then(|tv| {
                     let secs = (tv / 1_000f64) as i64;
                     let nsecs = ((tv % 1_000f64) * 1_000_000f64) as u32;
                     NaiveDateTime::from_timestamp_opt(secs, nsecs)
-                }
+                }),
             },
         };
 
@@ -519,8 +549,7 @@ impl Date {
 
         let final_date = NaiveDate::from_ymd_opt(year, month + 1, day)
             .and_then(|naive_date| naive_date.and_hms_milli_opt(hour, min, sec, milli))
-            .and_then(|local| ignore_ambiguity(Local.from_local_datetime(&local)))
-            .map(|local| local.naive_utc())
+            .map(|local| context.time_zone().to_utc(&local))
             .filter(|time| Self::time_clip(time.timestamp_millis() as f64).is_some());
 
         let date = Date(final_date);
@@ -538,8 +567,9 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getdate
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getDate
-    pub fn get_date(&self) -> f64 {
-        self.to_local().map_or(f64::NAN, |dt| dt.day() as f64)
+    pub fn get_date(&self, time_zone: &TimeZone) -> f64 {
+        self.to_local(time_zone)
+            .map_or(f64::NAN, |dt| dt.day() as f64)
     }
 
     /// `Date.prototype.getDay()`
@@ -553,8 +583,8 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getday
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getDay
-    pub fn get_day(&self) -> f64 {
-        self.to_local().map_or(f64::NAN, |dt| {
+    pub fn get_day(&self, time_zone: &TimeZone) -> f64 {
+        self.to_local(time_zone).map_or(f64::NAN, |dt| {
             let weekday = dt.weekday() as u32;
             let weekday = (weekday + 1) % 7; // 0 represents Monday in Chrono
             weekday as f64
@@ -571,8 +601,9 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getfullyear
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getFullYear
-    pub fn get_full_year(&self) -> f64 {
-        self.to_local().map_or(f64::NAN, |dt| dt.year() as f64)
+    pub fn get_full_year(&self, time_zone: &TimeZone) -> f64 {
+        self.to_local(time_zone)
+            .map_or(f64::NAN, |dt| dt.year() as f64)
     }
 
     /// `Date.prototype.getHours()`
@@ -585,8 +616,9 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.gethours
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getHours
-    pub fn get_hours(&self) -> f64 {
-        self.to_local().map_or(f64::NAN, |dt| dt.hour() as f64)
+    pub fn get_hours(&self, time_zone: &TimeZone) -> f64 {
+        self.to_local(time_zone)
+            .map_or(f64::NAN, |dt| dt.hour() as f64)
     }
 
     /// `Date.prototype.getMilliseconds()`
@@ -599,8 +631,8 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getmilliseconds
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getMilliseconds
-    pub fn get_milliseconds(&self) -> f64 {
-        self.to_local()
+    pub fn get_milliseconds(&self, time_zone: &TimeZone) -> f64 {
+        self.to_local(time_zone)
             .map_or(f64::NAN, |dt| dt.nanosecond() as f64 / NANOS_PER_MS as f64)
     }
 
@@ -614,8 +646,9 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getminutes
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getMinutes
-    pub fn get_minutes(&self) -> f64 {
-        self.to_local().map_or(f64::NAN, |dt| dt.minute() as f64)
+    pub fn get_minutes(&self, time_zone: &TimeZone) -> f64 {
+        self.to_local(time_zone)
+            .map_or(f64::NAN, |dt| dt.minute() as f64)
     }
 
     /// `Date.prototype.getMonth()`
@@ -629,8 +662,9 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getmonth
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getMonth
-    pub fn get_month(&self) -> f64 {
-        self.to_local().map_or(f64::NAN, |dt| dt.month0() as f64)
+    pub fn get_month(&self, time_zone: &TimeZone) -> f64 {
+        self.to_local(time_zone)
+            .map_or(f64::NAN, |dt| dt.month0() as f64)
     }
 
     /// `Date.prototype.getSeconds()`
@@ -643,8 +677,9 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getseconds
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getSeconds
-    pub fn get_seconds(&self) -> f64 {
-        self.to_local().map_or(f64::NAN, |dt| dt.second() as f64)
+    pub fn get_seconds(&self, time_zone: &TimeZone) -> f64 {
+        self.to_local(time_zone)
+            .map_or(f64::NAN, |dt| dt.second() as f64)
     }
 
     /// `Date.prototype.getYear()`
@@ -658,8 +693,8 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getyear
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getYear
-    pub fn get_year(&self) -> f64 {
-        self.to_local()
+    pub fn get_year(&self, time_zone: &TimeZone) -> f64 {
+        self.to_local(time_zone)
             .map_or(f64::NAN, |dt| dt.year() as f64 - 1900f64)
     }
 
@@ -680,8 +715,8 @@ impl Date {
 
     /// `Date.prototype.getTimeZoneOffset()`
     ///
-    /// The getTimezoneOffset() method returns the time zone difference, in minutes, from current locale (host system
-    /// settings) to UTC.
+    /// The `getTimezoneOffset()` method returns the difference, in minutes, between the specified date as evaluated
+    /// in UTC and as evaluated in the time zone of the context.
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
@@ -689,10 +724,10 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.gettimezoneoffset
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getTimezoneOffset
-    #[inline]
-    pub fn get_timezone_offset() -> f64 {
-        let offset_seconds = chrono::Local::now().offset().local_minus_utc() as f64;
-        offset_seconds / 60f64
+    pub fn get_timezone_offset(&self, time_zone: &TimeZone) -> f64 {
+        self.0.map_or(f64::NAN, |utc| {
+            -time_zone.offset_from_utc(&utc).local_minus_utc() as f64 / 60f64
+        })
     }
 
     /// `Date.prototype.getUTCDate()`
@@ -825,9 +860,9 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.setdate
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setDate
-    pub fn set_date(&mut self, day: Option<f64>) {
+    pub fn set_date(&mut self, time_zone: &TimeZone, day: Option<f64>) {
         if let Some(day) = day {
-            self.set_components(false, None, None, Some(day), None, None, None, None)
+            self.set_components(time_zone, None, None, Some(day), None, None, None, None)
         } else {
             self.0 = None
         }
@@ -844,9 +879,15 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.setfullyear
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setFullYear
-    pub fn set_full_year(&mut self, year: Option<f64>, month: Option<f64>, day: Option<f64>) {
+    pub fn set_full_year(
+        &mut self,
+        time_zone: &TimeZone,
+        year: Option<f64>,
+        month: Option<f64>,
+        day: Option<f64>,
+    ) {
         if let Some(year) = year {
-            self.set_components(false, Some(year), month, day, None, None, None, None)
+            self.set_components(time_zone, Some(year), month, day, None, None, None, None)
         } else {
             self.0 = None
         }
@@ -866,6 +907,7 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setHours
     pub fn set_hours(
         &mut self,
+        time_zone: &TimeZone,
         hour: Option<f64>,
         minute: Option<f64>,
         second: Option<f64>,
@@ -873,7 +915,7 @@ impl Date {
     ) {
         if let Some(hour) = hour {
             self.set_components(
-                false,
+                time_zone,
                 None,
                 None,
                 None,
@@ -897,9 +939,18 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.setmilliseconds
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setMilliseconds
-    pub fn set_milliseconds(&mut self, millisecond: Option<f64>) {
+    pub fn set_milliseconds(&mut self, time_zone: &TimeZone, millisecond: Option<f64>) {
         if let Some(millisecond) = millisecond {
-            self.set_components(false, None, None, None, None, None, None, Some(millisecond))
+            self.set_components(
+                time_zone,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(millisecond),
+            )
         } else {
             self.0 = None
         }
@@ -917,13 +968,14 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setMinutes
     pub fn set_minutes(
         &mut self,
+        time_zone: &TimeZone,
         minute: Option<f64>,
         second: Option<f64>,
         millisecond: Option<f64>,
     ) {
         if let Some(minute) = minute {
             self.set_components(
-                false,
+                time_zone,
                 None,
                 None,
                 None,
@@ -947,9 +999,9 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.setmonth
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setMonth
-    pub fn set_month(&mut self, month: Option<f64>, day: Option<f64>) {
+    pub fn set_month(&mut self, time_zone: &TimeZone, month: Option<f64>, day: Option<f64>) {
         if let Some(month) = month {
-            self.set_components(false, None, Some(month), day, None, None, None, None)
+            self.set_components(time_zone, None, Some(month), day, None, None, None, None)
         } else {
             self.0 = None
         }
@@ -965,10 +1017,15 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.setseconds
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setSeconds
-    pub fn set_seconds(&mut self, second: Option<f64>, millisecond: Option<f64>) {
+    pub fn set_seconds(
+        &mut self,
+        time_zone: &TimeZone,
+        second: Option<f64>,
+        millisecond: Option<f64>,
+    ) {
         if let Some(second) = second {
             self.set_components(
-                false,
+                time_zone,
                 None,
                 None,
                 None,
@@ -992,14 +1049,20 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.setyear
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setYear
-    pub fn set_year(&mut self, year: Option<f64>, month: Option<f64>, day: Option<f64>) {
+    pub fn set_year(
+        &mut self,
+        time_zone: &TimeZone,
+        year: Option<f64>,
+        month: Option<f64>,
+        day: Option<f64>,
+    ) {
         if let Some(mut year) = year {
             year += if (0f64..100f64).contains(&year) {
                 1900f64
             } else {
                 0f64
             };
-            self.set_components(false, Some(year), month, day, None, None, None, None)
+            self.set_components(time_zone, Some(year), month, day, None, None, None, None)
         } else {
             self.0 = None
         }
@@ -1018,9 +1081,12 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setTime
     pub fn set_time(&mut self, time: Option<f64>) {
         if let Some(time) = time {
-            let secs = (time / 1_000f64) as i64;
-            let nsecs = ((time % 1_000f64) * 1_000_000f64) as u32;
-            self.0 = ignore_ambiguity(Local.timestamp_opt(secs, nsecs)).map(|dt| dt.naive_utc());
+            let time = time as i64;
+            self.0 = NaiveDateTime::from_timestamp_opt(
+                time.div_euclid(MILLIS_PER_SECOND),
+                (time.rem_euclid(MILLIS_PER_SECOND) * NANOS_PER_MS) as u32,
+            )
+            .filter(|time| Self::time_clip(time.timestamp_millis() as f64).is_some());
         } else {
             self.0 = None
         }
@@ -1038,7 +1104,16 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCDate
     pub fn set_utc_date(&mut self, day: Option<f64>) {
         if let Some(day) = day {
-            self.set_components(true, None, None, Some(day), None, None, None, None)
+            self.set_components(
+                &TimeZone::utc(),
+                None,
+                None,
+                Some(day),
+                None,
+                None,
+                None,
+                None,
+            )
         } else {
             self.0 = None
         }
@@ -1057,7 +1132,16 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCFullYear
     pub fn set_utc_full_year(&mut self, year: Option<f64>, month: Option<f64>, day: Option<f64>) {
         if let Some(year) = year {
-            self.set_components(true, Some(year), month, day, None, None, None, None)
+            self.set_components(
+                &TimeZone::utc(),
+                Some(year),
+                month,
+                day,
+                None,
+                None,
+                None,
+                None,
+            )
         } else {
             self.0 = None
         }
@@ -1084,7 +1168,7 @@ impl Date {
     ) {
         if let Some(hour) = hour {
             self.set_components(
-                true,
+                &TimeZone::utc(),
                 None,
                 None,
                 None,
@@ -1110,7 +1194,16 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCMilliseconds
     pub fn set_utc_milliseconds(&mut self, millisecond: Option<f64>) {
         if let Some(millisecond) = millisecond {
-            self.set_components(true, None, None, None, None, None, None, Some(millisecond))
+            self.set_components(
+                &TimeZone::utc(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(millisecond),
+            )
         } else {
             self.0 = None
         }
@@ -1134,7 +1227,7 @@ impl Date {
     ) {
         if let Some(minute) = minute {
             self.set_components(
-                true,
+                &TimeZone::utc(),
                 None,
                 None,
                 None,
@@ -1160,7 +1253,16 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCMonth
     pub fn set_utc_month(&mut self, month: Option<f64>, day: Option<f64>) {
         if let Some(month) = month {
-            self.set_components(true, None, Some(month), day, None, None, None, None)
+            self.set_components(
+                &TimeZone::utc(),
+                None,
+                Some(month),
+                day,
+                None,
+                None,
+                None,
+                None,
+            )
         } else {
             self.0 = None
         }
@@ -1179,7 +1281,7 @@ impl Date {
     pub fn set_utc_seconds(&mut self, second: Option<f64>, millisecond: Option<f64>) {
         if let Some(second) = second {
             self.set_components(
-                true,
+                &TimeZone::utc(),
                 None,
                 None,
                 None,
@@ -1203,8 +1305,8 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.todatestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toDateString
-    pub fn to_date_string(&self) -> String {
-        self.to_local()
+    pub fn to_date_string(&self, time_zone: &TimeZone) -> String {
+        self.to_local(time_zone)
             .map(|date_time| date_time.format("%a %b %d %Y").to_string())
             .unwrap_or_else(|| "Invalid Date".to_string())
     }
@@ -1256,6 +1358,73 @@ impl Date {
         self.to_iso_string()
     }
 
+    /// `Date.prototype.toLocaleDateString()`
+    ///
+    /// The `toLocaleDateString()` method returns the date portion of a Date object in the format of the `en-US`
+    /// locale.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tolocaledatestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleDateString
+    pub fn to_locale_date_string(&self, time_zone: &TimeZone) -> String {
+        self.to_local(time_zone)
+            .map(|date_time| date_time.format("%-m/%-d/%Y").to_string())
+            .unwrap_or_else(|| "Invalid Date".to_string())
+    }
+
+    /// `Date.prototype.toLocaleString()`
+    ///
+    /// The `toLocaleString()` method returns a string representing the date and time of a Date object in the format
+    /// of the `en-US` locale.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tolocalestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleString
+    pub fn to_locale_string(&self, time_zone: &TimeZone) -> String {
+        self.to_local(time_zone)
+            .map(|date_time| date_time.format("%-m/%-d/%Y, %-I:%M:%S %p").to_string())
+            .unwrap_or_else(|| "Invalid Date".to_string())
+    }
+
+    /// `Date.prototype.toLocaleTimeString()`
+    ///
+    /// The `toLocaleTimeString()` method returns the time portion of a Date object in the format of the `en-US`
+    /// locale.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tolocaletimestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleTimeString
+    pub fn to_locale_time_string(&self, time_zone: &TimeZone) -> String {
+        self.to_local(time_zone)
+            .map(|date_time| date_time.format("%-I:%M:%S %p").to_string())
+            .unwrap_or_else(|| "Invalid Date".to_string())
+    }
+
+    /// `Date.prototype.toString()`
+    ///
+    /// The `toString()` method returns a string representing the specified Date object, in the given time zone.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tostring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toString
+    pub fn to_string_in(&self, time_zone: &TimeZone) -> String {
+        self.to_local(time_zone)
+            .map(|date_time| date_time.format("%a %b %d %Y %H:%M:%S GMT%:z").to_string())
+            .unwrap_or_else(|| "Invalid Date".to_string())
+    }
+
     /// `Date.prototype.toTimeString()`
     ///
     /// The `toTimeString()` method returns the time portion of a Date object in human readable form in American
@@ -1267,8 +1436,8 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.totimestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toTimeString
-    pub fn to_time_string(&self) -> String {
-        self.to_local()
+    pub fn to_time_string(&self, time_zone: &TimeZone) -> String {
+        self.to_local(time_zone)
             .map(|date_time| date_time.format("%H:%M:%S GMT%:z").to_string())
             .unwrap_or_else(|| "Invalid Date".to_string())
     }
@@ -1303,6 +1472,47 @@ impl Date {
         self.get_time()
     }
 
+    /// `Date.prototype [ @@toPrimitive ] ( hint )`
+    ///
+    /// The `[@@toPrimitive]()` method converts a `Date` object to a primitive value, preferring a
+    /// string unless the hint is `"number"`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-date.prototype-@@toprimitive
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/@@toPrimitive
+    pub(crate) fn to_primitive(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let object = match this {
+            Value::Object(ref object) => object,
+            _ => {
+                return context
+                    .throw_type_error("Date.prototype[Symbol.toPrimitive] called on non-object")
+            }
+        };
+
+        let hint = match args.get(0) {
+            Some(Value::String(ref hint))
+                if hint.as_str() == "string" || hint.as_str() == "default" =>
+            {
+                PreferredType::String
+            }
+            Some(Value::String(ref hint)) if hint.as_str() == "number" => PreferredType::Number,
+            _ => {
+                return context.throw_type_error(
+                    "Date.prototype[Symbol.toPrimitive] called with an invalid hint",
+                )
+            }
+        };
+
+        object.ordinary_to_primitive(context, hint)
+    }
+
     /// `Date.now()`
     ///
     /// The static `Date.now()` method returns the number of milliseconds elapsed since January 1, 1970 00:00:00 UTC.
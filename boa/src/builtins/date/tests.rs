@@ -45,7 +45,9 @@ fn date_display() {
     assert_eq!(
         format!(
             "[{}]",
-            cd.to_local().unwrap().format("%a %b %d %Y %H:%M:%S GMT%:z")
+            cd.to_local(&super::TimeZone::local())
+                .unwrap()
+                .format("%a %b %d %Y %H:%M:%S GMT%:z")
         ),
         format!("[{}]", cd)
     );
@@ -67,7 +69,10 @@ fn date_this_time_value() {
         .unwrap()
         .value();
 
-    assert_eq!(Value::string("\'this\' is not a Date"), *message_property);
+    assert_eq!(
+        Value::string("\'this\' is not a Date at line 1, col 1"),
+        *message_property
+    );
 }
 
 #[test]
@@ -409,7 +414,7 @@ fn date_proto_get_timezone_offset() -> Result<(), Box<dyn std::error::Error>> {
 
     let actual = forward_val(
         &mut context,
-        "new Date('August 19, 1975 23:15:30 GMT+07:00').getTimezoneOffset() === new Date('August 19, 1975 23:15:30 GMT-02:00').getTimezoneOffset()",
+        "new Date('1975-08-19T23:15:30+07:00').getTimezoneOffset() === new Date('1975-08-19T23:15:30-02:00').getTimezoneOffset()",
     );
 
     // NB: Host Settings, not TZ specified in the DateTime.
@@ -417,19 +422,20 @@ fn date_proto_get_timezone_offset() -> Result<(), Box<dyn std::error::Error>> {
 
     let actual = forward_val(
         &mut context,
-        "new Date('August 19, 1975 23:15:30 GMT+07:00').getTimezoneOffset()",
+        "new Date('1975-08-19T23:15:30+07:00').getTimezoneOffset()",
     );
 
-    // The value of now().offset() depends on the host machine, so we have to replicate the method code here.
-    let offset_seconds = chrono::Local::now().offset().local_minus_utc() as f64;
-    let offset_minutes = offset_seconds / 60f64;
+    // The offset depends on the host machine, so we have to replicate the method code here.
+    let date = NaiveDate::from_ymd(1975, 08, 19).and_hms(16, 15, 30);
+    let offset_seconds = Local.offset_from_utc_datetime(&date).local_minus_utc() as f64;
+    let offset_minutes = -offset_seconds / 60f64;
     assert_eq!(Ok(Value::Rational(offset_minutes)), actual);
 
     let actual = forward_val(
         &mut context,
         "new Date(1/0, 06, 08, 09, 16, 15, 779).getTimezoneOffset()",
     );
-    assert_eq!(Ok(Value::Rational(offset_minutes)), actual);
+    assert_eq!(Ok(Value::Rational(f64::NAN)), actual);
     Ok(())
 }
 
@@ -1378,3 +1384,139 @@ fn date_json() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn date_proto_to_primitive() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "new Date(0) == new Date(0).toString()"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "new Date(5)[Symbol.toPrimitive]('number')"),
+        "5"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Date(5)[Symbol.toPrimitive]('bogus') } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn date_time_zone() {
+    let mut context = Context::new();
+    context.set_time_zone(super::TimeZone::from_name("America/New_York").unwrap());
+
+    // Daylight saving time is in effect in July, but not in January.
+    let init = "var summer = new Date(Date.UTC(2021, 06, 01, 16, 30));\
+                var winter = new Date(Date.UTC(2021, 00, 01, 16, 30));";
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "summer.getHours()"), "12");
+    assert_eq!(forward(&mut context, "winter.getHours()"), "11");
+    assert_eq!(forward(&mut context, "summer.getTimezoneOffset()"), "240");
+    assert_eq!(forward(&mut context, "winter.getTimezoneOffset()"), "300");
+    assert_eq!(
+        forward(&mut context, "summer.toString()"),
+        "\"Thu Jul 01 2021 12:30:00 GMT-04:00\""
+    );
+
+    // Local components are interpreted in the time zone of the context.
+    assert_eq!(
+        forward(&mut context, "new Date(2021, 06, 01, 12, 30).getTime()"),
+        forward(&mut context, "summer.getTime()")
+    );
+    assert_eq!(
+        forward(&mut context, "winter.setMonth(6); winter.getUTCHours()"),
+        "15"
+    );
+    assert_eq!(
+        forward(&mut context, "winter.setUTCHours(20); winter.getHours()"),
+        "16"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "winter.setFullYear(2020, 11, 31); winter.toISOString()"
+        ),
+        "\"2020-12-31T21:30:00.000Z\""
+    );
+
+    context.set_time_zone(super::TimeZone::utc());
+    assert_eq!(forward(&mut context, "summer.getHours()"), "16");
+    assert_eq!(forward(&mut context, "summer.getTimezoneOffset()"), "0");
+}
+
+#[test]
+fn date_proto_to_locale_string() {
+    let mut context = Context::new();
+    context.set_time_zone(super::TimeZone::fixed(FixedOffset::east(2 * 3600)));
+
+    let init = "var date = new Date(Date.UTC(2021, 02, 05, 7, 4, 9));";
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "date.toLocaleString()"),
+        "\"3/5/2021, 9:04:09 AM\""
+    );
+    assert_eq!(
+        forward(&mut context, "date.toLocaleDateString()"),
+        "\"3/5/2021\""
+    );
+    assert_eq!(
+        forward(&mut context, "date.toLocaleTimeString()"),
+        "\"9:04:09 AM\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Date(1/0).toLocaleString()"),
+        "\"Invalid Date\""
+    );
+}
+
+#[test]
+fn date_setters_on_invalid_date() {
+    let mut context = Context::new();
+    context.set_time_zone(super::TimeZone::utc());
+
+    for setter in &[
+        "setDate",
+        "setHours",
+        "setMilliseconds",
+        "setMinutes",
+        "setMonth",
+        "setSeconds",
+        "setUTCDate",
+        "setUTCHours",
+        "setUTCMilliseconds",
+        "setUTCMinutes",
+        "setUTCMonth",
+        "setUTCSeconds",
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                format!("var date = new Date(NaN); date.{}(1)", setter)
+            ),
+            "NaN"
+        );
+        assert_eq!(forward(&mut context, "date.getTime()"), "NaN");
+    }
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Date(NaN).setFullYear(2020) === Date.UTC(2020, 0, 1)"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Date(NaN).setUTCFullYear(2020, 1) === Date.UTC(2020, 1, 1)"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "new Date(NaN).setTime(1)"), "1");
+}
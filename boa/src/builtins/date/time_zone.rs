@@ -0,0 +1,663 @@
+//! The time zones used by `Date` to convert between local time and UTC.
+//!
+//! Besides the time zone of the host and fixed offsets, named time zones of the
+//! [IANA time zone database][tzdb] are supported. They are read from the compiled tzdata files
+//! (the `TZif` format, described in [RFC 8536][rfc]) installed on the host, in the directory named
+//! by the `TZDIR` environment variable or in `/usr/share/zoneinfo`.
+//!
+//! [tzdb]: https://www.iana.org/time-zones
+//! [rfc]: https://datatracker.ietf.org/doc/html/rfc8536
+
+use chrono::{prelude::*, Duration, TimeZone as _};
+use std::{env, error, fmt, fs, io, path::PathBuf, rc::Rc};
+
+/// The directory holding the tzdata files when `TZDIR` is not set.
+const DEFAULT_TZDIR: &str = "/usr/share/zoneinfo";
+
+/// A time zone, used to compute the local time of `Date` objects.
+///
+/// The time zone of a [`Context`](crate::Context) can be changed with
+/// [`Context::set_time_zone`](crate::Context::set_time_zone).
+#[derive(Debug, Clone)]
+pub struct TimeZone(Repr);
+
+#[derive(Debug, Clone)]
+enum Repr {
+    /// The time zone of the host.
+    Local,
+    /// A fixed offset from UTC.
+    Fixed(FixedOffset),
+    /// A time zone of the tz database.
+    Zone(Rc<ZoneInfo>),
+}
+
+impl TimeZone {
+    /// The time zone of the host, as reported by the operating system.
+    #[inline]
+    pub fn local() -> Self {
+        Self(Repr::Local)
+    }
+
+    /// Coordinated Universal Time.
+    #[inline]
+    pub fn utc() -> Self {
+        Self::fixed(FixedOffset::east(0))
+    }
+
+    /// A time zone that is always at the given offset from UTC.
+    #[inline]
+    pub fn fixed(offset: FixedOffset) -> Self {
+        Self(Repr::Fixed(offset))
+    }
+
+    /// Loads the time zone with the given identifier, like `"Europe/Paris"`, from the tzdata
+    /// installed on the host.
+    ///
+    /// `"UTC"` is always available, even if the host has no tzdata.
+    pub fn from_name(name: &str) -> Result<Self, TimeZoneError> {
+        if name == "UTC" {
+            return Ok(Self::utc());
+        }
+
+        let valid = !name.is_empty()
+            && !name.starts_with('/')
+            && name.split('/').all(|part| !part.is_empty() && part != "..")
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '/' | '_' | '-' | '+'));
+        if !valid {
+            return Err(TimeZoneError::InvalidName(name.into()));
+        }
+
+        let mut path = env::var_os("TZDIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_TZDIR));
+        path.push(name);
+
+        let bytes = fs::read(path).map_err(TimeZoneError::Io)?;
+        Self::from_tzif(name, &bytes)
+    }
+
+    /// Creates the time zone with the given identifier from the contents of its `TZif` file.
+    ///
+    /// This can be used by hosts that bundle their own copy of the tz database.
+    pub fn from_tzif(name: &str, bytes: &[u8]) -> Result<Self, TimeZoneError> {
+        let zone = ZoneInfo::parse(name, bytes).ok_or(TimeZoneError::Malformed)?;
+        Ok(Self(Repr::Zone(Rc::new(zone))))
+    }
+
+    /// Returns the identifier of the time zone, if it was loaded from the tz database.
+    #[inline]
+    pub fn name(&self) -> Option<&str> {
+        match self.0 {
+            Repr::Zone(ref zone) => Some(&zone.name),
+            _ => None,
+        }
+    }
+
+    /// Returns the offset from UTC of the time zone at the given instant.
+    pub fn offset_from_utc(&self, utc: &NaiveDateTime) -> FixedOffset {
+        match self.0 {
+            Repr::Local => Local.offset_from_utc_datetime(utc),
+            Repr::Fixed(offset) => offset,
+            Repr::Zone(ref zone) => FixedOffset::east(zone.offset_at(utc.timestamp())),
+        }
+    }
+
+    /// Returns the offset from UTC of the time zone at the given local time.
+    ///
+    /// Local times that are repeated or skipped by a transition are interpreted with the offset
+    /// in use before the transition.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-utc-t
+    pub fn offset_from_local(&self, local: &NaiveDateTime) -> FixedOffset {
+        // Transitions are far enough apart that the offsets a day before and a day after are the
+        // ones on both sides of any transition near the local time.
+        let is_valid = |offset: FixedOffset| self.offset_from_utc(&(*local - offset)) == offset;
+
+        let before = self.offset_from_utc(&(*local - Duration::days(1)));
+        if is_valid(before) {
+            return before;
+        }
+
+        let after = self.offset_from_utc(&(*local + Duration::days(1)));
+        if is_valid(after) {
+            after
+        } else {
+            before
+        }
+    }
+
+    /// Converts the given instant to the local time of the time zone.
+    #[inline]
+    pub fn to_local(&self, utc: &NaiveDateTime) -> DateTime<FixedOffset> {
+        DateTime::from_utc(*utc, self.offset_from_utc(utc))
+    }
+
+    /// Converts the given local time of the time zone to an instant.
+    #[inline]
+    pub fn to_utc(&self, local: &NaiveDateTime) -> NaiveDateTime {
+        *local - self.offset_from_local(local)
+    }
+}
+
+impl Default for TimeZone {
+    #[inline]
+    fn default() -> Self {
+        Self::local()
+    }
+}
+
+/// An error that occurred while loading a time zone.
+#[derive(Debug)]
+pub enum TimeZoneError {
+    /// The identifier is not a valid time zone identifier.
+    InvalidName(Box<str>),
+    /// The tzdata file of the time zone could not be read.
+    Io(io::Error),
+    /// The tzdata file of the time zone is malformed.
+    Malformed,
+}
+
+impl fmt::Display for TimeZoneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidName(name) => write!(f, "invalid time zone identifier: {}", name),
+            Self::Io(err) => write!(f, "could not read the time zone data: {}", err),
+            Self::Malformed => f.write_str("malformed time zone data"),
+        }
+    }
+}
+
+impl error::Error for TimeZoneError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        if let Self::Io(err) = self {
+            Some(err)
+        } else {
+            None
+        }
+    }
+}
+
+/// The rules of a time zone of the tz database.
+#[derive(Debug)]
+struct ZoneInfo {
+    /// The identifier of the time zone.
+    name: Box<str>,
+    /// The offset in use before the first transition, in seconds.
+    initial: i32,
+    /// The transitions, as the instant they happen at and the offset in use after them.
+    transitions: Box<[(i64, i32)]>,
+    /// The rule used after the last transition.
+    rule: Option<PosixRule>,
+}
+
+impl ZoneInfo {
+    /// Parses the contents of a `TZif` file.
+    ///
+    /// More information:
+    ///  - [RFC 8536][rfc]
+    ///
+    /// [rfc]: https://datatracker.ietf.org/doc/html/rfc8536
+    fn parse(name: &str, bytes: &[u8]) -> Option<Self> {
+        let mut reader = Reader(bytes);
+
+        let (version, mut counts) = reader.header()?;
+        let mut time_size = 4;
+        if version >= b'2' {
+            // Skip the version 1 data block, which only holds 32-bit transition times.
+            reader.take(counts.data_len(time_size))?;
+            counts = reader.header()?.1;
+            time_size = 8;
+        }
+
+        let times = reader.take(counts.transitions * time_size)?;
+        let indices = reader.take(counts.transitions)?;
+        let types = reader.take(counts.types * 6)?;
+        reader.take(
+            counts.data_len(time_size) - counts.transitions * (time_size + 1) - counts.types * 6,
+        )?;
+
+        let offsets = types
+            .chunks_exact(6)
+            .map(|ty| i32::from_be_bytes([ty[0], ty[1], ty[2], ty[3]]))
+            .collect::<Vec<_>>();
+        let initial = *offsets.first()?;
+
+        let transitions = times
+            .chunks_exact(time_size)
+            .zip(indices)
+            .map(|(time, &index)| {
+                let time = if time_size == 8 {
+                    let mut buf = [0; 8];
+                    buf.copy_from_slice(time);
+                    i64::from_be_bytes(buf)
+                } else {
+                    i64::from(i32::from_be_bytes([time[0], time[1], time[2], time[3]]))
+                };
+                Some((time, *offsets.get(usize::from(index))?))
+            })
+            .collect::<Option<Box<[_]>>>()?;
+
+        // Version 2 files end with a POSIX TZ string, giving the rule used after the last
+        // transition.
+        let rule = if version >= b'2' {
+            let footer = std::str::from_utf8(reader.0).ok()?;
+            let footer = footer.strip_prefix('\n')?;
+            let footer = &footer[..footer.find('\n')?];
+            if footer.is_empty() {
+                None
+            } else {
+                Some(PosixRule::parse(footer)?)
+            }
+        } else {
+            None
+        };
+
+        Some(Self {
+            name: name.into(),
+            initial,
+            transitions,
+            rule,
+        })
+    }
+
+    /// Returns the offset in use at the given instant, in seconds.
+    fn offset_at(&self, time: i64) -> i32 {
+        // The number of transitions that happened at or before the instant.
+        let count = match self.transitions.binary_search_by(|&(at, _)| at.cmp(&time)) {
+            Ok(index) => index + 1,
+            Err(index) => index,
+        };
+
+        if count == self.transitions.len() {
+            if let Some(ref rule) = self.rule {
+                return rule.offset_at(time);
+            }
+        }
+
+        match count {
+            0 => self.initial,
+            count => self.transitions[count - 1].1,
+        }
+    }
+}
+
+/// A reader over the contents of a `TZif` file.
+struct Reader<'a>(&'a [u8]);
+
+/// The counts found in the header of a `TZif` file.
+struct Counts {
+    utc_indicators: usize,
+    std_indicators: usize,
+    leap_seconds: usize,
+    transitions: usize,
+    types: usize,
+    chars: usize,
+}
+
+impl Counts {
+    /// Returns the length of the data block following the header, for the given size of times.
+    fn data_len(&self, time_size: usize) -> usize {
+        self.transitions * (time_size + 1)
+            + self.types * 6
+            + self.chars
+            + self.leap_seconds * (time_size + 4)
+            + self.std_indicators
+            + self.utc_indicators
+    }
+}
+
+impl<'a> Reader<'a> {
+    /// Takes the given number of bytes.
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        if self.0.len() < len {
+            return None;
+        }
+        let (taken, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Some(taken)
+    }
+
+    /// Reads a header, returning the version of the file and its counts.
+    fn header(&mut self) -> Option<(u8, Counts)> {
+        let header = self.take(44)?;
+        if &header[..4] != b"TZif" {
+            return None;
+        }
+
+        let count = |index: usize| {
+            let start = 20 + index * 4;
+            let bytes = [
+                header[start],
+                header[start + 1],
+                header[start + 2],
+                header[start + 3],
+            ];
+            u32::from_be_bytes(bytes) as usize
+        };
+
+        Some((
+            header[4],
+            Counts {
+                utc_indicators: count(0),
+                std_indicators: count(1),
+                leap_seconds: count(2),
+                transitions: count(3),
+                types: count(4),
+                chars: count(5),
+            },
+        ))
+    }
+}
+
+/// A time zone rule, in the format of the POSIX `TZ` environment variable, like
+/// `EST5EDT,M3.2.0,M11.1.0`.
+///
+/// More information:
+///  - [POSIX reference][posix]
+///
+/// [posix]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/V1_chap08.html
+#[derive(Debug)]
+struct PosixRule {
+    /// The standard offset, in seconds east of UTC.
+    std_offset: i32,
+    /// The daylight saving time, if the time zone observes it.
+    dst: Option<DstRule>,
+}
+
+/// The daylight saving time of a [`PosixRule`].
+#[derive(Debug)]
+struct DstRule {
+    /// The daylight saving offset, in seconds east of UTC.
+    offset: i32,
+    /// The local (standard) time daylight saving time starts at.
+    start: (RuleDate, i32),
+    /// The local (daylight saving) time daylight saving time ends at.
+    end: (RuleDate, i32),
+}
+
+/// The day a [`DstRule`] starts or ends on.
+#[derive(Debug, Clone, Copy)]
+enum RuleDate {
+    /// `Jn`: the `n`th day of the year, between 1 and 365, never counting February 29.
+    Julian(u16),
+    /// `n`: the zero-based `n`th day of the year, counting February 29.
+    Ordinal(u16),
+    /// `Mm.w.d`: the day `d` (0 is Sunday) of the week `w` (5 is the last week) of the month `m`.
+    MonthWeekDay(u8, u8, u8),
+}
+
+impl PosixRule {
+    /// Parses a `TZ` string.
+    fn parse(rule: &str) -> Option<Self> {
+        let mut rule = rule.as_bytes();
+
+        Self::parse_name(&mut rule)?;
+        // POSIX offsets are positive west of Greenwich.
+        let std_offset = -Self::parse_time(&mut rule)?;
+        if rule.is_empty() {
+            return Some(Self {
+                std_offset,
+                dst: None,
+            });
+        }
+
+        Self::parse_name(&mut rule)?;
+        let offset = if matches!(rule.first(), None | Some(b',')) {
+            std_offset + 3600
+        } else {
+            -Self::parse_time(&mut rule)?
+        };
+
+        let (start, end) = if rule.is_empty() {
+            // The default rule of POSIX, which matches the United States.
+            (
+                (RuleDate::MonthWeekDay(3, 2, 0), 7200),
+                (RuleDate::MonthWeekDay(11, 1, 0), 7200),
+            )
+        } else {
+            rule = rule.strip_prefix(b",")?;
+            let start = Self::parse_date_time(&mut rule)?;
+            rule = rule.strip_prefix(b",")?;
+            let end = Self::parse_date_time(&mut rule)?;
+            (start, end)
+        };
+
+        if !rule.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            std_offset,
+            dst: Some(DstRule { offset, start, end }),
+        })
+    }
+
+    /// Parses a time zone abbreviation, either alphabetic or quoted with `<` and `>`.
+    fn parse_name(rule: &mut &[u8]) -> Option<()> {
+        let len = if rule.first() == Some(&b'<') {
+            rule.iter().position(|&c| c == b'>')? + 1
+        } else {
+            rule.iter()
+                .position(|c| !c.is_ascii_alphabetic())
+                .unwrap_or(rule.len())
+        };
+
+        if len < 3 {
+            return None;
+        }
+        *rule = &rule[len..];
+        Some(())
+    }
+
+    /// Parses a number made of ASCII digits.
+    fn parse_number(rule: &mut &[u8]) -> Option<i32> {
+        let len = rule
+            .iter()
+            .position(|c| !c.is_ascii_digit())
+            .unwrap_or(rule.len());
+        let number = std::str::from_utf8(&rule[..len]).ok()?.parse().ok()?;
+        *rule = &rule[len..];
+        Some(number)
+    }
+
+    /// Parses a signed `hh[:mm[:ss]]` time, in seconds.
+    fn parse_time(rule: &mut &[u8]) -> Option<i32> {
+        let sign = match rule.first() {
+            Some(b'-') => -1,
+            Some(b'+') => 1,
+            _ => 0,
+        };
+        if sign != 0 {
+            *rule = &rule[1..];
+        }
+
+        let mut time = Self::parse_number(rule)? * 3600;
+        for &unit in &[60, 1] {
+            match rule.strip_prefix(b":") {
+                Some(rest) => {
+                    *rule = rest;
+                    time += Self::parse_number(rule)? * unit;
+                }
+                None => break,
+            }
+        }
+
+        Some(if sign == -1 { -time } else { time })
+    }
+
+    /// Parses the date and optional time at which daylight saving time starts or ends.
+    fn parse_date_time(rule: &mut &[u8]) -> Option<(RuleDate, i32)> {
+        let date = match rule.first()? {
+            b'J' => {
+                *rule = &rule[1..];
+                RuleDate::Julian(Self::parse_number(rule)? as u16)
+            }
+            b'M' => {
+                *rule = &rule[1..];
+                let month = Self::parse_number(rule)? as u8;
+                *rule = rule.strip_prefix(b".")?;
+                let week = Self::parse_number(rule)? as u8;
+                *rule = rule.strip_prefix(b".")?;
+                let day = Self::parse_number(rule)? as u8;
+                RuleDate::MonthWeekDay(month, week, day)
+            }
+            _ => RuleDate::Ordinal(Self::parse_number(rule)? as u16),
+        };
+
+        let time = match rule.strip_prefix(b"/") {
+            Some(rest) => {
+                *rule = rest;
+                Self::parse_time(rule)?
+            }
+            None => 7200,
+        };
+
+        Some((date, time))
+    }
+
+    /// Returns the offset in use at the given instant, in seconds.
+    fn offset_at(&self, time: i64) -> i32 {
+        let dst = match self.dst {
+            Some(ref dst) => dst,
+            None => return self.std_offset,
+        };
+
+        let year = NaiveDateTime::from_timestamp(time + i64::from(self.std_offset), 0).year();
+        let start = dst
+            .start
+            .0
+            .to_timestamp(year)
+            .map(|day| day + i64::from(dst.start.1) - i64::from(self.std_offset));
+        let end = dst
+            .end
+            .0
+            .to_timestamp(year)
+            .map(|day| day + i64::from(dst.end.1) - i64::from(dst.offset));
+
+        let in_dst = match (start, end) {
+            // Northern hemisphere: daylight saving time is in the middle of the year.
+            (Some(start), Some(end)) if start < end => start <= time && time < end,
+            // Southern hemisphere: daylight saving time spans the new year.
+            (Some(start), Some(end)) => !(end <= time && time < start),
+            _ => false,
+        };
+
+        if in_dst {
+            dst.offset
+        } else {
+            self.std_offset
+        }
+    }
+}
+
+impl RuleDate {
+    /// Returns the timestamp of the midnight starting this day in the given year, as if it was
+    /// UTC.
+    fn to_timestamp(self, year: i32) -> Option<i64> {
+        let date = match self {
+            Self::Julian(day) => {
+                let leap = NaiveDate::from_ymd_opt(year, 2, 29).is_some();
+                let ordinal = if leap && day >= 60 { day + 1 } else { day };
+                NaiveDate::from_yo_opt(year, u32::from(ordinal))?
+            }
+            Self::Ordinal(day) => NaiveDate::from_yo_opt(year, u32::from(day) + 1)?,
+            Self::MonthWeekDay(month, week, weekday) => {
+                let first = NaiveDate::from_ymd_opt(year, u32::from(month), 1)?;
+                let first_weekday = first.weekday().num_days_from_sunday() as i64;
+                let mut day =
+                    (i64::from(weekday) - first_weekday).rem_euclid(7) + (i64::from(week) - 1) * 7;
+                let days_in_month = NaiveDate::from_ymd_opt(
+                    if month == 12 { year + 1 } else { year },
+                    if month == 12 { 1 } else { u32::from(month) + 1 },
+                    1,
+                )?
+                .signed_duration_since(first)
+                .num_days();
+                while day >= days_in_month {
+                    day -= 7;
+                }
+                first + Duration::days(day)
+            }
+        };
+
+        Some(date.and_hms(0, 0, 0).timestamp())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn posix_rule() {
+        let rule = PosixRule::parse("EST5EDT,M3.2.0,M11.1.0").unwrap();
+        let at = |y, m, d, h| NaiveDate::from_ymd(y, m, d).and_hms(h, 0, 0).timestamp();
+
+        assert_eq!(rule.offset_at(at(2040, 1, 15, 12)), -5 * 3600);
+        assert_eq!(rule.offset_at(at(2040, 7, 15, 12)), -4 * 3600);
+        // DST starts on March 11 2040 at 2:00 EST, or 7:00 UTC.
+        assert_eq!(rule.offset_at(at(2040, 3, 11, 6)), -5 * 3600);
+        assert_eq!(rule.offset_at(at(2040, 3, 11, 7)), -4 * 3600);
+        // DST ends on November 4 2040 at 2:00 EDT, or 6:00 UTC.
+        assert_eq!(rule.offset_at(at(2040, 11, 4, 5)), -4 * 3600);
+        assert_eq!(rule.offset_at(at(2040, 11, 4, 6)), -5 * 3600);
+
+        let rule = PosixRule::parse("<+1030>-10:30<+11>-11,M10.1.0,M4.1.0").unwrap();
+        assert_eq!(rule.offset_at(at(2040, 1, 15, 12)), 11 * 3600);
+        assert_eq!(rule.offset_at(at(2040, 7, 15, 12)), 10 * 3600 + 1800);
+
+        let rule = PosixRule::parse("<-03>3").unwrap();
+        assert_eq!(rule.offset_at(at(2040, 7, 15, 12)), -3 * 3600);
+
+        assert!(PosixRule::parse("E5").is_none());
+        assert!(PosixRule::parse("EST5EDT,M3.2.0").is_none());
+    }
+
+    #[test]
+    fn from_name() {
+        assert!(matches!(
+            TimeZone::from_name("../etc/passwd"),
+            Err(TimeZoneError::InvalidName(_))
+        ));
+        assert!(matches!(
+            TimeZone::from_name("/etc/localtime"),
+            Err(TimeZoneError::InvalidName(_))
+        ));
+        assert!(matches!(
+            TimeZone::from_tzif("Bogus", b"not a tzif file"),
+            Err(TimeZoneError::Malformed)
+        ));
+
+        let utc = TimeZone::from_name("UTC").unwrap();
+        let now = Utc::now().naive_utc();
+        assert_eq!(utc.offset_from_utc(&now), FixedOffset::east(0));
+    }
+
+    #[test]
+    fn local_time_around_transitions() {
+        let zone = TimeZone::from_name("America/New_York").unwrap();
+        assert_eq!(zone.name(), Some("America/New_York"));
+
+        let local = |m, d, h, min| NaiveDate::from_ymd(2021, m, d).and_hms(h, min, 0);
+
+        // 2:30 does not exist on March 14 2021, it is interpreted with the standard offset.
+        assert_eq!(
+            zone.offset_from_local(&local(3, 14, 2, 30)),
+            FixedOffset::west(5 * 3600)
+        );
+        // 1:30 happens twice on November 7 2021, the first one is used.
+        assert_eq!(
+            zone.offset_from_local(&local(11, 7, 1, 30)),
+            FixedOffset::west(4 * 3600)
+        );
+        assert_eq!(
+            zone.to_utc(&local(7, 1, 12, 0)),
+            NaiveDate::from_ymd(2021, 7, 1).and_hms(16, 0, 0)
+        );
+    }
+}
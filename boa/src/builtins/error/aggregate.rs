@@ -0,0 +1,132 @@
+//! This module implements the global `AggregateError` object.
+//!
+//! The `AggregateError` object represents an error when several errors need to be wrapped in a
+//! single error, like the rejection reasons of `Promise.any`.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-aggregate-error-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AggregateError
+
+use crate::{
+    builtins::{iterable::get_iterator, Array, BuiltIn, Error},
+    object::{ConstructorBuilder, PROTOTYPE},
+    profiler::BoaProfiler,
+    property::{Attribute, DataDescriptor},
+    Context, Result, Value,
+};
+
+/// JavaScript `AggregateError` implementation.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AggregateError;
+
+impl BuiltIn for AggregateError {
+    const NAME: &'static str = "AggregateError";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let error_prototype = context.standard_objects().error_object().prototype();
+        let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+        let aggregate_error_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().aggregate_error_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .inherit(error_prototype.into())
+        .property("name", Self::NAME, attribute)
+        .property("message", "", attribute)
+        .build();
+
+        (Self::NAME, aggregate_error_object.into(), Self::attribute())
+    }
+}
+
+impl AggregateError {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 2;
+
+    /// `AggregateError ( errors, message )`
+    ///
+    /// Create a new error object.
+    pub(crate) fn constructor(
+        new_target: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let prototype = new_target
+            .as_object()
+            .and_then(|obj| {
+                obj.get(&PROTOTYPE.into(), obj.clone().into(), context)
+                    .map(|o| o.as_object())
+                    .transpose()
+            })
+            .transpose()?
+            .unwrap_or_else(|| {
+                context
+                    .standard_objects()
+                    .aggregate_error_object()
+                    .prototype()
+            });
+        let mut obj = context.construct_object();
+        obj.set_prototype_instance(prototype.into());
+        let this = Value::from(obj);
+        if let Some(message) = args.get(1) {
+            if !message.is_undefined() {
+                this.set_field("message", message.to_string(context)?, context)?;
+            }
+        }
+
+        // Let errorsList be ? IterableToList(errors).
+        let iterator = get_iterator(context, args.get(0).cloned().unwrap_or_default())?;
+        let mut errors = Vec::new();
+        loop {
+            let next = iterator.next(context)?;
+            if next.is_done() {
+                break;
+            }
+            errors.push(next.value());
+        }
+        Self::set_errors(&this, &errors, context)?;
+
+        Error::capture_stack_trace(&this, context)?;
+        Ok(this)
+    }
+
+    /// Creates a new `AggregateError` wrapping the given errors.
+    pub(crate) fn create(errors: &[Value], message: &str, context: &mut Context) -> Result<Value> {
+        let prototype = context
+            .standard_objects()
+            .aggregate_error_object()
+            .prototype();
+        let mut obj = context.construct_object();
+        obj.set_prototype_instance(prototype.into());
+        let this = Value::from(obj);
+        this.set_field("message", message, context)?;
+        Self::set_errors(&this, errors, context)?;
+        Error::capture_stack_trace(&this, context)?;
+        Ok(this)
+    }
+
+    /// Defines the `errors` property of an `AggregateError` object.
+    fn set_errors(this: &Value, errors: &[Value], context: &mut Context) -> Result<()> {
+        let array = Array::new_array(context);
+        let array = Array::construct_array(&array, errors, context)?;
+        this.set_property(
+            "errors",
+            DataDescriptor::new(
+                array,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            ),
+        );
+        Ok(())
+    }
+}
@@ -13,8 +13,8 @@
 
 use crate::object::PROTOTYPE;
 use crate::{
-    builtins::BuiltIn,
-    object::{ConstructorBuilder, ObjectData},
+    builtins::{BuiltIn, Error},
+    object::ConstructorBuilder,
     profiler::BoaProfiler,
     property::Attribute,
     Context, Result, Value,
@@ -80,9 +80,7 @@ impl EvalError {
             }
         }
 
-        // This value is used by console.log and other routines to match Object type
-        // to its Javascript Identifier (global constructor method name)
-        this.set_data(ObjectData::Error);
+        Error::capture_stack_trace(&this, context)?;
         Ok(this)
     }
 }
@@ -14,10 +14,11 @@ use crate::{
     builtins::BuiltIn,
     object::{ConstructorBuilder, ObjectData, PROTOTYPE},
     profiler::BoaProfiler,
-    property::Attribute,
+    property::{Attribute, DataDescriptor},
     Context, Result, Value,
 };
 
+pub(crate) mod aggregate;
 pub(crate) mod eval;
 pub(crate) mod range;
 pub(crate) mod reference;
@@ -28,6 +29,7 @@ pub(crate) mod uri;
 #[cfg(test)]
 mod tests;
 
+pub(crate) use self::aggregate::AggregateError;
 pub(crate) use self::eval::EvalError;
 pub(crate) use self::r#type::TypeError;
 pub(crate) use self::range::RangeError;
@@ -96,10 +98,30 @@ impl Error {
             }
         }
 
+        Self::capture_stack_trace(&this, context)?;
+        Ok(this)
+    }
+
+    /// Marks the new object as an error, capturing the call stack in it.
+    ///
+    /// The stack trace is also exposed as the non-standard `stack` property of the error, made of
+    /// the string of the error followed by a line for each frame, like `    at f (line 1, col 5)`.
+    pub(crate) fn capture_stack_trace(this: &Value, context: &mut Context) -> Result<()> {
+        let stack_trace = context.executor().call_stack.capture();
+        let header = Self::to_string(this, &[], context)?.to_string(context)?;
+        let stack = format!("{}\n{}", header, stack_trace);
+
         // This value is used by console.log and other routines to match Object type
         // to its Javascript Identifier (global constructor method name)
-        this.set_data(ObjectData::Error);
-        Ok(this)
+        this.set_data(ObjectData::Error(stack_trace));
+        this.set_property(
+            "stack",
+            DataDescriptor::new(
+                stack,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            ),
+        );
+        Ok(())
     }
 
     /// `Error.prototype.toString()`
@@ -10,8 +10,8 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RangeError
 
 use crate::{
-    builtins::BuiltIn,
-    object::{ConstructorBuilder, ObjectData, PROTOTYPE},
+    builtins::{BuiltIn, Error},
+    object::{ConstructorBuilder, PROTOTYPE},
     profiler::BoaProfiler,
     property::Attribute,
     Context, Result, Value,
@@ -77,9 +77,7 @@ impl RangeError {
             }
         }
 
-        // This value is used by console.log and other routines to match Object type
-        // to its Javascript Identifier (global constructor method name)
-        this.set_data(ObjectData::Error);
+        Error::capture_stack_trace(&this, context)?;
         Ok(this)
     }
 }
@@ -84,3 +84,76 @@ fn uri_error_to_string() {
         "\"URIError\""
     );
 }
+
+#[test]
+fn aggregate_error_name() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "AggregateError.name"),
+        "\"AggregateError\""
+    );
+}
+
+#[test]
+fn aggregate_error_length() {
+    let mut context = Context::new();
+    assert_eq!(forward(&mut context, "AggregateError.length"), "2");
+}
+
+#[test]
+fn aggregate_error_errors() {
+    let mut context = Context::new();
+    let init = r#"
+        var error = new AggregateError(new Set([1, 2]), "hello");
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "error.message"), "\"hello\"");
+    assert_eq!(forward(&mut context, "error.errors.join()"), "\"1,2\"");
+    assert_eq!(
+        forward(&mut context, "error.toString()"),
+        "\"AggregateError: hello\""
+    );
+}
+
+#[test]
+fn error_stack() {
+    let mut context = Context::new();
+    let init = r#"
+        function inner() {
+            return new TypeError("boom");
+        }
+        function outer() {
+            return inner();
+        }
+        var error = outer();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "error.stack"),
+        "\"TypeError: boom\n    at inner (line 3, col 20)\n    at outer (line 6, col 20)\n    at <global> (line 8, col 21)\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.keys(error).includes('stack')"),
+        "false"
+    );
+}
+
+#[test]
+fn error_stack_after_return() {
+    let mut context = Context::new();
+    let init = r#"
+        function f() {}
+        f();
+        var error = null;
+        try {
+            null.foo;
+        } catch (e) {
+            error = new Error("late");
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "error.stack"),
+        "\"Error: late\n    at <global> (line 8, col 21)\""
+    );
+}
@@ -16,8 +16,8 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypeError
 
 use crate::{
-    builtins::BuiltIn,
-    object::{ConstructorBuilder, ObjectData, PROTOTYPE},
+    builtins::{BuiltIn, Error},
+    object::{ConstructorBuilder, PROTOTYPE},
     property::Attribute,
     BoaProfiler, Context, Result, Value,
 };
@@ -82,9 +82,7 @@ impl TypeError {
             }
         }
 
-        // This value is used by console.log and other routines to match Object type
-        // to its Javascript Identifier (global constructor method name)
-        this.set_data(ObjectData::Error);
+        Error::capture_stack_trace(&this, context)?;
         Ok(this)
     }
 }
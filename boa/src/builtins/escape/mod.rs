@@ -0,0 +1,158 @@
+//! This module implements the legacy global `escape` and `unescape` functions.
+//!
+//! Unlike the URI handling functions, they escape the UTF-16 code units of a string, as `%XX`
+//! below 256 and as `%uXXXX` otherwise.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-additional-properties-of-the-global-object
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/escape
+
+use crate::{
+    builtins::BuiltIn, object::FunctionBuilder, property::Attribute, BoaProfiler, Context, Result,
+    Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The JavaScript `escape` function.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Escape;
+
+impl BuiltIn for Escape {
+    const NAME: &'static str = "escape";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let function = FunctionBuilder::new(context, Self::escape)
+            .name(Self::NAME)
+            .length(1)
+            .callable(true)
+            .constructable(false)
+            .build();
+
+        (Self::NAME, function.into(), Self::attribute())
+    }
+}
+
+impl Escape {
+    /// `escape( string )`
+    ///
+    /// Escapes every code unit of the string, except the ASCII letters and digits and the
+    /// characters `@*_+-./`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-escape-string
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/escape
+    fn escape(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let string = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_string(context)?;
+
+        let mut result = String::with_capacity(string.len());
+        for unit in string.encode_utf16() {
+            match char::from_u32(u32::from(unit)) {
+                Some(c) if c.is_ascii_alphanumeric() || "@*_+-./".contains(c) => result.push(c),
+                _ if unit < 256 => result.push_str(&format!("%{:02X}", unit)),
+                _ => result.push_str(&format!("%u{:04X}", unit)),
+            }
+        }
+
+        Ok(result.into())
+    }
+}
+
+/// The JavaScript `unescape` function.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Unescape;
+
+impl BuiltIn for Unescape {
+    const NAME: &'static str = "unescape";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let function = FunctionBuilder::new(context, Self::unescape)
+            .name(Self::NAME)
+            .length(1)
+            .callable(true)
+            .constructable(false)
+            .build();
+
+        (Self::NAME, function.into(), Self::attribute())
+    }
+}
+
+impl Unescape {
+    /// `unescape( string )`
+    ///
+    /// Replaces the `%XX` and `%uXXXX` escape sequences of the string by the code units they
+    /// represent. Other `%` characters are kept as they are.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-unescape-string
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/unescape
+    fn unescape(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let string = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_string(context)?;
+
+        let units: Vec<u16> = string.encode_utf16().collect();
+        let mut result = Vec::with_capacity(units.len());
+        let mut k = 0;
+        while k < units.len() {
+            let unit = units[k];
+            k += 1;
+            if unit != u16::from(b'%') {
+                result.push(unit);
+                continue;
+            }
+
+            let long = units.get(k) == Some(&u16::from(b'u'));
+            let digits = if long {
+                units.get(k + 1..k + 5)
+            } else {
+                units.get(k..k + 2)
+            };
+            match digits.and_then(parse_hex) {
+                Some(escaped) => {
+                    result.push(escaped);
+                    k += if long { 5 } else { 2 };
+                }
+                None => result.push(unit),
+            }
+        }
+
+        Ok(String::from_utf16_lossy(&result).into())
+    }
+}
+
+/// Parses the hexadecimal digits of an escape sequence.
+fn parse_hex(digits: &[u16]) -> Option<u16> {
+    digits.iter().try_fold(0, |value, &digit| {
+        let digit = char::from_u32(u32::from(digit))?.to_digit(16)?;
+        Some(value * 16 + digit as u16)
+    })
+}
@@ -0,0 +1,30 @@
+use crate::{forward, Context};
+
+#[test]
+fn escape() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "escape('abc123@*_+-./')"),
+        "\"abc123@*_+-./\""
+    );
+    assert_eq!(
+        forward(&mut context, "escape('a b&ü€')"),
+        "\"a%20b%26%FC%u20AC\""
+    );
+    assert_eq!(forward(&mut context, "escape('😀')"), "\"%uD83D%uDE00\"");
+}
+
+#[test]
+fn unescape() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "unescape('a%20b%26%FC%u20AC')"),
+        "\"a b&ü€\""
+    );
+    assert_eq!(forward(&mut context, "unescape('%uD83D%uDE00')"), "\"😀\"");
+    // Incomplete escape sequences are kept.
+    assert_eq!(
+        forward(&mut context, "unescape('%u12%4%zz%')"),
+        "\"%u12%4%zz%\""
+    );
+}
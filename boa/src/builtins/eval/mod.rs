@@ -0,0 +1,197 @@
+//! This module implements the global `eval` function.
+//!
+//! The `eval()` function evaluates JavaScript code represented as a string.
+//!
+//! A call to `eval` through its name is a direct eval, which runs the code in the environment of
+//! the caller. Any other call, like `(0, eval)(code)` or `globalThis.eval(code)`, is an indirect
+//! eval, which runs the code in the global environment.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-eval-x
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/eval
+
+use crate::{
+    builtins::BuiltIn,
+    environment::{
+        declarative_environment_record::DeclarativeEnvironmentRecord,
+        lexical_environment::EnvironmentType,
+    },
+    exec::{Executable, InterpreterState},
+    object::FunctionBuilder,
+    property::Attribute,
+    syntax::{
+        ast::node::{Node, StatementList},
+        Parser,
+    },
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The JavaScript `eval` function.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Eval;
+
+impl BuiltIn for Eval {
+    const NAME: &'static str = "eval";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let eval = FunctionBuilder::new(context, Self::eval)
+            .name(Self::NAME)
+            .length(1)
+            .callable(true)
+            .constructable(false)
+            .build();
+        context.eval_function = Some(eval.clone());
+
+        (Self::NAME, eval.into(), Self::attribute())
+    }
+}
+
+impl Eval {
+    /// `eval( x )`
+    ///
+    /// Called as a function, `eval` is always an indirect eval. Direct evals are recognized by
+    /// the `Call` node, which calls `Eval::perform_eval` itself.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-eval-x
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/eval
+    fn eval(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let x = args.get(0).cloned().unwrap_or_default();
+        Self::perform_eval(&x, false, context)
+    }
+
+    /// `PerformEval ( x, callerRealm, strictCaller, direct )`
+    ///
+    /// Values other than strings are returned as they are. The code of a direct eval runs in the
+    /// environment of the caller, so its `var` declarations are added to the variable scope of
+    /// the caller, while its lexical declarations stay in the eval. Strict mode code keeps its
+    /// `var` declarations in the eval too.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performeval
+    pub(crate) fn perform_eval(x: &Value, direct: bool, context: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("PerformEval", "eval");
+
+        let source = match x {
+            Value::String(ref source) => source.clone(),
+            _ => return Ok(x.clone()),
+        };
+
+        if !context.eval_enabled() {
+            return context.throw_eval_error("code generation from strings is disabled");
+        }
+
+        // The code of a direct eval in strict mode code is strict mode code too.
+        let strict_caller = direct && context.strict();
+        let body = Parser::new(source.as_bytes(), strict_caller)
+            .parse_all()
+            .map_err(|e| context.construct_syntax_error(e.to_string()))?;
+
+        // A strict mode caller makes the eval strict even if its body has no directive.
+        let strict = strict_caller || body.strict();
+        context.with_strict(strict, |context| {
+            if direct {
+                Self::evaluate(&body, strict, context)
+            } else {
+                context.with_global_environment(|context| Self::evaluate(&body, strict, context))
+            }
+        })
+    }
+
+    /// Runs the code of an eval in a new declarative environment, on top of the current one.
+    ///
+    /// The environment of strict mode code is also its variable scope.
+    fn evaluate(body: &StatementList, strict: bool, context: &mut Context) -> Result<Value> {
+        let env = context.get_current_environment();
+        if strict {
+            context.push_environment(DeclarativeEnvironmentRecord::new_var_scope(Some(env)));
+        } else {
+            Self::eval_declaration_instantiation(body, context)?;
+            context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
+        }
+        let result = body.run(context);
+        context.pop_environment();
+
+        context
+            .executor()
+            .set_current_state(InterpreterState::Executing);
+
+        result
+    }
+
+    /// `EvalDeclarationInstantiation ( body, varEnv, lexEnv, privateEnv, strict )`
+    ///
+    /// The `var` and function declarations of the code are hoisted to the variable scope of the
+    /// caller, so they must not share their name with a lexical declaration of the blocks they
+    /// are hoisted through, or with a lexical declaration of the variable scope itself, like a
+    /// `let` declaration of the function body or of the global code.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-evaldeclarationinstantiation
+    fn eval_declaration_instantiation(body: &StatementList, context: &mut Context) -> Result<()> {
+        let mut var_names = body.var_declared_names();
+        var_names.extend(body.items().iter().filter_map(|node| match node {
+            Node::FunctionDecl(decl) => Some(decl.name()),
+            _ => None,
+        }));
+
+        let mut env = Some(context.get_current_environment());
+        while let Some(current) = env {
+            let (redeclared, outer) = {
+                let record = current.borrow();
+                let has_lexical_declaration = |name: &str| {
+                    if let Some(function) = record.as_function_environment() {
+                        function.has_lexical_declaration(name)
+                    } else if let Some(global) = record.as_global_environment() {
+                        global.has_lexical_declaration(name)
+                    } else {
+                        match record.get_environment_type() {
+                            EnvironmentType::Declarative => record.has_binding(name),
+                            // The objects of `with` statements do not conflict with declarations.
+                            _ => false,
+                        }
+                    }
+                };
+                let redeclared = var_names
+                    .iter()
+                    .find(|name| has_lexical_declaration(name))
+                    .copied();
+
+                // The walk ends at the variable scope the declarations are hoisted to.
+                let outer = if record.is_var_scope() {
+                    None
+                } else {
+                    record.get_outer_environment()
+                };
+                (redeclared, outer)
+            };
+            if let Some(name) = redeclared {
+                return Err(
+                    context.construct_syntax_error(format!("redeclaration of let {}", name))
+                );
+            }
+            env = outer;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,226 @@
+use crate::{forward, Context};
+
+#[test]
+fn eval_returns_completion_value() {
+    let mut context = Context::new();
+    assert_eq!(forward(&mut context, "eval('1 + 3')"), "4");
+    assert_eq!(
+        forward(&mut context, "eval('if (true) { 5 } else { 6 }')"),
+        "5"
+    );
+    assert_eq!(forward(&mut context, "eval('')"), "undefined");
+    assert_eq!(forward(&mut context, "eval.length"), "1");
+    assert_eq!(forward(&mut context, "eval.name"), "\"eval\"");
+}
+
+#[test]
+fn eval_non_string_argument() {
+    let mut context = Context::new();
+    let init = r#"
+        var obj = {};
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "eval(obj) === obj"), "true");
+    assert_eq!(forward(&mut context, "eval(42)"), "42");
+    assert_eq!(forward(&mut context, "eval()"), "undefined");
+}
+
+#[test]
+fn eval_syntax_error() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { eval('1 +'); } catch (e) { e instanceof SyntaxError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn direct_eval_uses_caller_environment() {
+    let mut context = Context::new();
+    let init = r#"
+        function f(a) {
+            let b = 2;
+            eval("var c = a + b;");
+            return c;
+        }
+        function g() {
+            eval("let d = 1; var e = d + 1;");
+            try {
+                d;
+            } catch (err) {
+                return [err.name, e];
+            }
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "f(1)"), "3");
+    assert_eq!(forward(&mut context, "g()[0]"), "\"ReferenceError\"");
+    assert_eq!(forward(&mut context, "g()[1]"), "2");
+    assert_eq!(
+        forward(&mut context, "try { c } catch (e) { e.name }"),
+        "\"ReferenceError\""
+    );
+}
+
+#[test]
+fn indirect_eval_uses_global_environment() {
+    let mut context = Context::new();
+    let init = r#"
+        var x = "global";
+        function direct() {
+            var x = "local";
+            return eval("x");
+        }
+        function indirect() {
+            var x = "local";
+            return (0, eval)("x");
+        }
+        function declare() {
+            globalThis.eval("var declared = 1;");
+        }
+        declare();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "direct()"), "\"local\"");
+    assert_eq!(forward(&mut context, "indirect()"), "\"global\"");
+    assert_eq!(forward(&mut context, "declared"), "1");
+}
+
+#[test]
+fn eval_var_redeclaring_let() {
+    let mut context = Context::new();
+    let init = r#"
+        function f() {
+            {
+                let x = 1;
+                eval("var x = 2;");
+            }
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { f(); } catch (e) { e instanceof SyntaxError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn eval_var_redeclaring_lexical_declaration_of_var_scope() {
+    let mut context = Context::new();
+    let init = r#"
+        function f() {
+            let v = 1;
+            eval("var v = 2");
+        }
+        function g() {
+            const v = 1;
+            eval("function v() {}");
+        }
+        function h(v) {
+            eval("var v = 2");
+            return v;
+        }
+        let x = 1;
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { f(); } catch (e) { e instanceof SyntaxError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { g(); } catch (e) { e instanceof SyntaxError }"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "h(1)"), "2");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { eval('var x = 2'); } catch (e) { e instanceof SyntaxError }"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "x"), "1");
+    assert_eq!(
+        forward(&mut context, "eval('\"use strict\"; var x = 2; x')"),
+        "2"
+    );
+}
+
+#[test]
+fn strict_eval_keeps_var_declarations() {
+    let mut context = Context::new();
+    let init = r#"
+        function f() {
+            eval("'use strict'; var v = 1; function g() { return v; }");
+            return [typeof v, typeof g];
+        }
+        function h() {
+            let w = 1;
+            return eval("'use strict'; var w = 2; w") + w;
+        }
+        var v = "global";
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "f().join()"), "\"string,undefined\"");
+    assert_eq!(forward(&mut context, "h()"), "3");
+    assert_eq!(
+        forward(&mut context, "(0, eval)('\"use strict\"; var i = 1; i')"),
+        "1"
+    );
+    assert_eq!(forward(&mut context, "typeof i"), "\"undefined\"");
+}
+
+#[test]
+fn direct_eval_in_strict_caller_keeps_var_declarations() {
+    let mut context = Context::new();
+    let init = r#"
+        function s() {
+            "use strict";
+            eval("var sv = 5");
+            return typeof sv;
+        }
+        function t() {
+            "use strict";
+            var tv = 1;
+            eval("var tv = 2; function tf() {}");
+            return [tv, typeof tf];
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "s()"), "\"undefined\"");
+    assert_eq!(forward(&mut context, "t().join()"), "\"1,undefined\"");
+    assert_eq!(forward(&mut context, "typeof sv"), "\"undefined\"");
+}
+
+#[test]
+fn eval_disabled() {
+    let mut context = Context::new();
+    context.set_eval_enabled(false);
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { eval('1'); } catch (e) { e instanceof EvalError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Function('return 1'); } catch (e) { e instanceof EvalError }"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "eval(1)"), "1");
+}
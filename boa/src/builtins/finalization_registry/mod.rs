@@ -0,0 +1,224 @@
+//! This module implements the global `FinalizationRegistry` object.
+//!
+//! A `FinalizationRegistry` calls a cleanup callback, with a held value, after each of the objects
+//! registered with it has been collected. The callbacks are run as jobs, by
+//! [`Context::run_jobs`](crate::Context::run_jobs).
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-finalization-registry-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry
+
+use crate::{
+    builtins::BuiltIn,
+    gc::{Finalize, Trace},
+    object::{ConstructorBuilder, GcObject, Object, ObjectData, WeakGcObject, PROTOTYPE},
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::same_value,
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// A registration of an object with a `FinalizationRegistry`.
+#[derive(Debug, Trace, Finalize)]
+struct Cell {
+    target: WeakGcObject,
+    held_value: Value,
+    unregister_token: Option<WeakGcObject>,
+}
+
+/// The internal representation of a `FinalizationRegistry` object.
+#[derive(Debug, Trace, Finalize)]
+pub struct FinalizationRegistry {
+    cleanup: GcObject,
+    cells: Vec<Cell>,
+}
+
+impl BuiltIn for FinalizationRegistry {
+    const NAME: &'static str = "FinalizationRegistry";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let finalization_registry_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context
+                .standard_objects()
+                .finalization_registry_object()
+                .clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .method(Self::register, "register", 2)
+        .method(Self::unregister, "unregister", 1)
+        .build();
+
+        (
+            Self::NAME,
+            finalization_registry_object.into(),
+            Self::attribute(),
+        )
+    }
+}
+
+impl FinalizationRegistry {
+    pub(crate) const LENGTH: usize = 1;
+
+    /// `new FinalizationRegistry( cleanupCallback )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry-cleanup-callback
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry/FinalizationRegistry
+    pub(crate) fn constructor(
+        new_target: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin FinalizationRegistry constructor without new is forbidden",
+            );
+        }
+
+        let cleanup = match args.get(0).and_then(Value::as_object) {
+            Some(cleanup) if cleanup.is_callable() => cleanup,
+            _ => {
+                return context
+                    .throw_type_error("FinalizationRegistry cleanup callback must be callable")
+            }
+        };
+
+        let prototype = new_target
+            .as_object()
+            .map(|object| object.get(&PROTOTYPE.into(), new_target.clone(), context))
+            .transpose()?
+            .filter(Value::is_object)
+            .unwrap_or_else(|| {
+                context
+                    .standard_objects()
+                    .finalization_registry_object()
+                    .prototype()
+                    .into()
+            });
+        let registry = GcObject::new(Object::with_prototype(
+            prototype,
+            ObjectData::FinalizationRegistry(Self {
+                cleanup,
+                cells: Vec::new(),
+            }),
+        ));
+        context.add_finalization_registry(&registry);
+
+        Ok(registry.into())
+    }
+
+    /// Removes the cells whose target has been collected, and returns the cleanup callback with
+    /// their held values.
+    pub(crate) fn take_cleared_cells(&mut self) -> (GcObject, Vec<Value>) {
+        let mut held_values = Vec::new();
+        let mut i = 0;
+        while i < self.cells.len() {
+            if self.cells[i].target.is_alive() {
+                i += 1;
+            } else {
+                held_values.push(self.cells.remove(i).held_value.clone());
+            }
+        }
+        (self.cleanup.clone(), held_values)
+    }
+
+    /// `FinalizationRegistry.prototype.register( target, heldValue [ , unregisterToken ] )`
+    ///
+    /// Registers the target, so that the cleanup callback is called with the held value once the
+    /// target is collected. The registration can be removed with the unregister token.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.register
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry/register
+    pub(crate) fn register(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let registry = match this.as_object() {
+            Some(object) if object.borrow().is_finalization_registry() => object,
+            _ => return context.throw_type_error("'this' is not a FinalizationRegistry"),
+        };
+
+        let held_value = args.get(1).cloned().unwrap_or_default();
+        let target = match args.get(0) {
+            Some(Value::Object(target)) if !same_value(&args[0], &held_value) => target.downgrade(),
+            Some(Value::Object(_)) => {
+                return context.throw_type_error("target and held value must not be the same")
+            }
+            _ => return context.throw_type_error("FinalizationRegistry target must be an object"),
+        };
+        let unregister_token = match args.get(2) {
+            Some(Value::Object(token)) => Some(token.downgrade()),
+            None | Some(Value::Undefined) => None,
+            _ => return context.throw_type_error("unregister token must be an object"),
+        };
+
+        if let Some(registry) = registry.borrow_mut().as_finalization_registry_mut() {
+            registry.cells.push(Cell {
+                target,
+                held_value,
+                unregister_token,
+            });
+        }
+        Ok(Value::undefined())
+    }
+
+    /// `FinalizationRegistry.prototype.unregister( unregisterToken )`
+    ///
+    /// Removes the registrations made with the unregister token, returning `true` if there were
+    /// any.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.unregister
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry/unregister
+    pub(crate) fn unregister(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let registry = match this.as_object() {
+            Some(object) if object.borrow().is_finalization_registry() => object,
+            _ => return context.throw_type_error("'this' is not a FinalizationRegistry"),
+        };
+        let token = match args.get(0).and_then(Value::as_object) {
+            Some(token) => token,
+            None => return context.throw_type_error("unregister token must be an object"),
+        };
+
+        let mut registry = registry.borrow_mut();
+        let cells = match registry.as_finalization_registry_mut() {
+            Some(registry) => &mut registry.cells,
+            None => return Ok(false.into()),
+        };
+        let len = cells.len();
+        cells.retain(|cell| {
+            !cell
+                .unregister_token
+                .as_ref()
+                .map_or(false, |unregister_token| unregister_token.ptr_eq(&token))
+        });
+        Ok((cells.len() != len).into())
+    }
+}
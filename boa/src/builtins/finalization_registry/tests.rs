@@ -0,0 +1,116 @@
+use crate::{forward, Context};
+
+#[test]
+fn register() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var registry = new FinalizationRegistry(held => {});
+        var target = {};
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "registry.register(target, 1)"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { registry.register(1, 1) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { registry.register(target, target) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { registry.register(target, 1, 1) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new FinalizationRegistry() } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { FinalizationRegistry(() => {}) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(registry)"),
+        "\"[object FinalizationRegistry]\""
+    );
+}
+
+#[test]
+fn cleanup_callbacks() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var kept = {};
+        var collected = [];
+        var registry = new FinalizationRegistry(held => collected.push(held));
+        registry.register(kept, "kept");
+        registry.register({}, "first");
+        registry.register({}, "second");
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "collected.length"), "0");
+
+    // `forward` runs the jobs, so `eval` is used before the cleanup callbacks are called.
+    crate::gc::force_collect();
+    let length = context.eval("collected.length").unwrap();
+    assert_eq!(length.as_number(), Some(0.0));
+
+    context.run_jobs().unwrap();
+    assert_eq!(
+        forward(&mut context, "collected.join()"),
+        "\"first,second\""
+    );
+
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "collected.length"), "2");
+}
+
+#[test]
+fn unregister() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var token = {};
+        var collected = [];
+        var registry = new FinalizationRegistry(held => collected.push(held));
+        registry.register({}, "first", token);
+        registry.register({}, "second", token);
+        registry.register({}, "third");
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "registry.unregister(token)"), "true");
+    assert_eq!(forward(&mut context, "registry.unregister(token)"), "false");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { registry.unregister(1) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+
+    crate::gc::force_collect();
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "collected.join()"), "\"third\"");
+}
@@ -0,0 +1,297 @@
+//! This module implements the `arguments` objects of functions.
+//!
+//! Strict mode functions, and functions whose parameters are not all plain names, get an
+//! unmapped `arguments` object, which is an ordinary object holding a copy of the arguments.
+//! Other functions get a mapped `arguments` object, an exotic object whose indices stay linked
+//! to the bindings of the parameters: writing `arguments[0]` changes the first parameter, and
+//! assigning the first parameter changes `arguments[0]`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-arguments-exotic-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/arguments
+
+use crate::{
+    environment::lexical_environment::Environment,
+    gc::{Finalize, Trace},
+    object::{GcObject, Object, ObjectData},
+    property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
+    symbol::WellKnownSymbols,
+    syntax::ast::node::{declaration::BindingTarget, FormalParameter},
+    value::{RcString, Value},
+    Context, Result,
+};
+use gc::{Gc, GcCell};
+
+/// The kind of an `arguments` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub enum Arguments {
+    /// An ordinary object holding a copy of the arguments.
+    Unmapped,
+    /// An exotic object whose indices are linked to the bindings of the parameters.
+    Mapped(MappedArguments),
+}
+
+/// The internal slots of a mapped `arguments` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct MappedArguments {
+    /// The environment of the call, holding the bindings of the parameters.
+    environment: Environment,
+    /// The names of the parameters linked to the indices of the object, which are removed when
+    /// the indices are deleted or redefined.
+    ///
+    /// This takes the place of the `[[ParameterMap]]` object of the specification.
+    names: Gc<GcCell<Vec<Option<RcString>>>>,
+}
+
+impl MappedArguments {
+    /// Returns the name of the parameter linked to the given key, if any.
+    fn mapped_name(&self, key: &PropertyKey) -> Option<RcString> {
+        match key {
+            PropertyKey::Index(index) => {
+                self.names.borrow().get(*index as usize).cloned().flatten()
+            }
+            _ => None,
+        }
+    }
+
+    /// Removes the link between the given key and its parameter.
+    fn unmap(&self, key: &PropertyKey) {
+        if let PropertyKey::Index(index) = key {
+            if let Some(name) = self.names.borrow_mut().get_mut(*index as usize) {
+                *name = None;
+            }
+        }
+    }
+
+    /// Reads the value of a parameter.
+    fn get_parameter(&self, name: &str, context: &mut Context) -> Result<Value> {
+        self.environment
+            .borrow()
+            .get_binding_value(name, false, context)
+    }
+
+    /// Assigns the value of a parameter.
+    fn set_parameter(&self, name: &str, value: Value, context: &mut Context) -> Result<()> {
+        self.environment
+            .borrow_mut()
+            .set_mutable_binding(name, value, false, context)
+    }
+
+    /// `[[GetOwnProperty]]` for a mapped index.
+    ///
+    /// Returns `None` for the keys which are not linked to a parameter, which are ordinary
+    /// properties.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arguments-exotic-objects-getownproperty-p
+    pub(crate) fn get_own_property(
+        &self,
+        object: &GcObject,
+        key: &PropertyKey,
+        context: &mut Context,
+    ) -> Option<Result<Option<PropertyDescriptor>>> {
+        let name = self.mapped_name(key)?;
+        Some(match object.ordinary_get_own_property(key) {
+            Some(PropertyDescriptor::Data(ref data)) => self
+                .get_parameter(&name, context)
+                .map(|value| Some(DataDescriptor::new(value, data.attributes()).into())),
+            desc => Ok(desc),
+        })
+    }
+
+    /// `[[DefineOwnProperty]]` of a mapped `arguments` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arguments-exotic-objects-defineownproperty-p-desc
+    pub(crate) fn define_own_property(
+        &self,
+        object: &mut GcObject,
+        key: PropertyKey,
+        desc: PropertyDescriptor,
+        context: &mut Context,
+    ) -> Result<bool> {
+        let name = self.mapped_name(&key);
+        if !object.ordinary_define_own_property(key.clone(), desc.clone()) {
+            return Ok(false);
+        }
+
+        if let Some(name) = name {
+            match desc {
+                PropertyDescriptor::Accessor(_) => self.unmap(&key),
+                PropertyDescriptor::Data(ref data) => {
+                    self.set_parameter(&name, data.value(), context)?;
+                    if !data.writable() {
+                        self.unmap(&key);
+                    }
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    /// `[[Get]]` for a mapped index.
+    ///
+    /// Returns `None` for the keys which are not linked to a parameter, which are ordinary
+    /// properties.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arguments-exotic-objects-get-p-receiver
+    pub(crate) fn get(&self, key: &PropertyKey, context: &mut Context) -> Option<Result<Value>> {
+        self.mapped_name(key)
+            .map(|name| self.get_parameter(&name, context))
+    }
+
+    /// Assigns the parameter linked to a mapped index, before the property itself is set.
+    ///
+    /// The parameter is only assigned if the object is the receiver of the assignment.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arguments-exotic-objects-set-p-v-receiver
+    pub(crate) fn set(
+        &self,
+        object: &GcObject,
+        key: &PropertyKey,
+        value: &Value,
+        receiver: &Value,
+        context: &mut Context,
+    ) -> Result<()> {
+        let same_receiver = receiver
+            .as_object()
+            .map_or(false, |receiver| GcObject::equals(&receiver, object));
+        match self.mapped_name(key) {
+            Some(name) if same_receiver => self.set_parameter(&name, value.clone(), context),
+            _ => Ok(()),
+        }
+    }
+
+    /// `[[Delete]]` of a mapped `arguments` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arguments-exotic-objects-delete-p
+    pub(crate) fn delete(&self, object: &mut GcObject, key: &PropertyKey) -> bool {
+        let deleted = object.ordinary_delete(key);
+        if deleted {
+            self.unmap(key);
+        }
+        deleted
+    }
+}
+
+/// Checks if the parameters of a function are all plain names, without default values, rest
+/// parameters or destructuring patterns.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-static-semantics-issimpleparameterlist
+pub(crate) fn is_simple_parameter_list(params: &[FormalParameter]) -> bool {
+    params.iter().all(|param| {
+        !param.is_rest_param()
+            && param.init().is_none()
+            && matches!(param.target(), BindingTarget::Identifier(_))
+    })
+}
+
+/// Creates an `arguments` object with the `length` and the indices shared by both kinds of
+/// objects, and the `@@iterator` method of arrays.
+fn create_arguments_object(arguments: Arguments, args: &[Value], context: &Context) -> GcObject {
+    let prototype = context.standard_objects().object_object().prototype();
+    let mut object = Object::create(prototype.into());
+    object.data = ObjectData::Arguments(arguments);
+
+    let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+    object.insert("length", DataDescriptor::new(args.len(), attribute));
+    for (index, value) in args.iter().enumerate() {
+        object.insert(index, DataDescriptor::new(value.clone(), Attribute::all()));
+    }
+    if let Some(values) = context.array_values_function.clone() {
+        object.insert(
+            WellKnownSymbols::iterator(),
+            DataDescriptor::new(values, attribute),
+        );
+    }
+
+    GcObject::new(object)
+}
+
+/// Creates the `arguments` object of a strict mode function, or of a function whose parameters
+/// are not simple, whose `callee` property throws when it is accessed.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-createunmappedargumentsobject
+pub(crate) fn create_unmapped_arguments_object(args: &[Value], context: &Context) -> Value {
+    let object = create_arguments_object(Arguments::Unmapped, args, context);
+    if let Some(thrower) = context.throw_type_error_function.clone() {
+        object.borrow_mut().insert(
+            "callee",
+            AccessorDescriptor::new(
+                Some(thrower.clone()),
+                Some(thrower),
+                Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+            ),
+        );
+    }
+
+    object.into()
+}
+
+/// Creates the `arguments` object of a sloppy mode function with simple parameters, whose
+/// indices are linked to the bindings of the parameters in the environment of the call.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-createmappedargumentsobject
+pub(crate) fn create_mapped_arguments_object(
+    function: &GcObject,
+    params: &[FormalParameter],
+    args: &[Value],
+    environment: &Environment,
+    context: &Context,
+) -> Value {
+    // Only the indices of the arguments passed to the call are mapped, and when a name is used
+    // by several parameters, the last one takes it.
+    let mut names = vec![None; args.len().min(params.len())];
+    for (index, param) in params.iter().enumerate().take(names.len()).rev() {
+        if let BindingTarget::Identifier(ref identifier) = param.target() {
+            let name = identifier.as_ref();
+            let taken = names
+                .iter()
+                .flatten()
+                .any(|taken: &RcString| taken.as_str() == name);
+            if !taken {
+                names[index] = Some(RcString::from(name));
+            }
+        }
+    }
+
+    let mapped = MappedArguments {
+        environment: environment.clone(),
+        names: Gc::new(GcCell::new(names)),
+    };
+    let object = create_arguments_object(Arguments::Mapped(mapped), args, context);
+    object.borrow_mut().insert(
+        "callee",
+        DataDescriptor::new(
+            function.clone(),
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        ),
+    );
+
+    object.into()
+}
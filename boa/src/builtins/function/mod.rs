@@ -15,15 +15,33 @@ use crate::object::PROTOTYPE;
 use crate::{
     builtins::{Array, BuiltIn},
     environment::lexical_environment::Environment,
-    gc::{empty_trace, Finalize, Trace},
-    object::{ConstructorBuilder, FunctionBuilder, GcObject, Object, ObjectData},
-    property::{Attribute, DataDescriptor},
-    syntax::ast::node::{FormalParameter, RcStatementList},
+    gc::{empty_trace, Finalize, Ref, RefMut, Trace},
+    object::{
+        ConstructorBuilder, FunctionBuilder, GcObject, NativeObject, Object, ObjectData,
+        PrivateElement,
+    },
+    property::{Attribute, DataDescriptor, PropertyKey},
+    symbol::{RcSymbol, WellKnownSymbols},
+    syntax::{
+        ast::node::{
+            declaration::BindingKind, FormalParameter, FunctionExpr, FunctionSource,
+            MethodDefinitionKind, Node, RcStatementList,
+        },
+        Parser,
+    },
     BoaProfiler, Context, Result, Value,
 };
 use bitflags::bitflags;
-use std::fmt::{self, Debug};
+use gc::{Gc, GcCell};
+use std::{
+    fmt::{self, Debug},
+    rc::Rc,
+};
+
+#[cfg(feature = "vm")]
+use crate::vm::CodeBlock;
 
+pub mod arguments;
 #[cfg(test)]
 mod tests;
 
@@ -49,12 +67,100 @@ impl Debug for BuiltInFunction {
     }
 }
 
+/// _fn(this, arguments, captures, context) -> ResultValue_ - The signature of a built-in closure
+pub type ClosureFunction = fn(&Value, &[Value], &Captures, &mut Context) -> Result<Value>;
+
+#[derive(Clone, Copy, Finalize)]
+pub struct BuiltInClosure(pub(crate) ClosureFunction);
+
+unsafe impl Trace for BuiltInClosure {
+    empty_trace!();
+}
+
+impl From<ClosureFunction> for BuiltInClosure {
+    fn from(function: ClosureFunction) -> Self {
+        Self(function)
+    }
+}
+
+impl Debug for BuiltInClosure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[closure]")
+    }
+}
+
+/// The garbage collected state captured by a built-in closure.
+///
+/// This plays the role of the internal slots that the specification attaches to abstract
+/// closures, like the `[[Promise]]` slot of promise resolving functions.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct Captures(Gc<GcCell<Box<dyn NativeObject>>>);
+
+impl Captures {
+    /// Creates a new capture record from a Rust value.
+    pub fn new<T>(captures: T) -> Self
+    where
+        T: NativeObject,
+    {
+        Self(Gc::new(GcCell::new(Box::new(captures))))
+    }
+
+    /// Immutably borrows the captured value, if it is of type `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the captures are currently mutably borrowed.
+    pub fn downcast_ref<T>(&self) -> Option<Ref<'_, T>>
+    where
+        T: NativeObject,
+    {
+        let captures = self.0.borrow();
+        if (**captures).as_any().is::<T>() {
+            Some(Ref::map(captures, |captures| {
+                (**captures)
+                    .as_any()
+                    .downcast_ref::<T>()
+                    .expect("type was checked")
+            }))
+        } else {
+            None
+        }
+    }
+
+    /// Mutably borrows the captured value, if it is of type `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the captures are currently borrowed.
+    pub fn downcast_mut<T>(&self) -> Option<RefMut<'_, Box<dyn NativeObject>, T>>
+    where
+        T: NativeObject,
+    {
+        let captures = self.0.borrow_mut();
+        if (**captures).as_any().is::<T>() {
+            Some(RefMut::map(captures, |captures| {
+                (**captures)
+                    .as_mut_any()
+                    .downcast_mut::<T>()
+                    .expect("type was checked")
+            }))
+        } else {
+            None
+        }
+    }
+}
+
 bitflags! {
     #[derive(Finalize, Default)]
     pub struct FunctionFlags: u8 {
         const CALLABLE = 0b0000_0001;
         const CONSTRUCTABLE = 0b0000_0010;
         const LEXICAL_THIS_MODE = 0b0000_0100;
+        const ASYNC = 0b0000_1000;
+        const CLASS_CONSTRUCTOR = 0b0001_0000;
+        const DERIVED = 0b0010_0000;
+        const GENERATOR = 0b0100_0000;
+        const STRICT = 0b1000_0000;
     }
 }
 
@@ -86,6 +192,31 @@ impl FunctionFlags {
     pub(crate) fn is_lexical_this_mode(&self) -> bool {
         self.contains(Self::LEXICAL_THIS_MODE)
     }
+
+    #[inline]
+    pub(crate) fn is_async(&self) -> bool {
+        self.contains(Self::ASYNC)
+    }
+
+    #[inline]
+    pub(crate) fn is_class_constructor(&self) -> bool {
+        self.contains(Self::CLASS_CONSTRUCTOR)
+    }
+
+    #[inline]
+    pub(crate) fn is_derived(&self) -> bool {
+        self.contains(Self::DERIVED)
+    }
+
+    #[inline]
+    pub(crate) fn is_generator(&self) -> bool {
+        self.contains(Self::GENERATOR)
+    }
+
+    #[inline]
+    pub(crate) fn is_strict(&self) -> bool {
+        self.contains(Self::STRICT)
+    }
 }
 
 unsafe impl Trace for FunctionFlags {
@@ -100,68 +231,88 @@ unsafe impl Trace for FunctionFlags {
 #[derive(Debug, Clone, Finalize, Trace)]
 pub enum Function {
     BuiltIn(BuiltInFunction, FunctionFlags),
+    Closure {
+        function: BuiltInClosure,
+        flags: FunctionFlags,
+        captures: Captures,
+    },
     Ordinary {
         flags: FunctionFlags,
         body: RcStatementList,
-        params: Box<[FormalParameter]>,
+        /// Shared, so the parameters can be bound once the function object is no longer borrowed.
+        #[unsafe_ignore_trace]
+        params: Rc<[FormalParameter]>,
         environment: Environment,
+        /// The object whose prototype is used as the base of `super` property lookups.
+        home_object: Option<GcObject>,
+        /// The instance fields defined by a class constructor.
+        fields: Vec<ClassFieldDefinition>,
+        /// The private methods and accessors a class constructor defines on its instances.
+        private_methods: Vec<(RcSymbol, PrivateElement)>,
+        /// The source text of the function, returned by `Function.prototype.toString`.
+        #[unsafe_ignore_trace]
+        source_text: Option<Rc<str>>,
+    },
+    /// A function compiled to bytecode, which is run by the virtual machine.
+    #[cfg(feature = "vm")]
+    Compiled {
+        flags: FunctionFlags,
+        /// The code block only holds primitive values and nodes, so it does not need tracing.
+        #[unsafe_ignore_trace]
+        code: Rc<CodeBlock>,
+        environment: Environment,
+        /// The object whose prototype is used as the base of `super` property lookups.
+        home_object: Option<GcObject>,
     },
 }
 
-impl Function {
-    // Adds the final rest parameters to the Environment as an array
-    pub(crate) fn add_rest_param(
-        &self,
-        param: &FormalParameter,
-        index: usize,
-        args_list: &[Value],
-        context: &mut Context,
-        local_env: &Environment,
-    ) {
-        // Create array of values
-        let array = Array::new_array(context);
-        Array::add_to_array_object(&array, &args_list[index..], context).unwrap();
-
-        // Create binding
-        local_env
-            .borrow_mut()
-            // Function parameters can share names in JavaScript...
-            .create_mutable_binding(param.name().to_owned(), false, true, context)
-            .expect("Failed to create binding for rest param");
-
-        // Set Binding to value
-        local_env
-            .borrow_mut()
-            .initialize_binding(param.name(), array, context)
-            .expect("Failed to initialize rest param");
-    }
-
-    // Adds an argument to the environment
-    pub(crate) fn add_arguments_to_environment(
-        &self,
-        param: &FormalParameter,
-        value: Value,
-        local_env: &Environment,
-        context: &mut Context,
-    ) {
-        // Create binding
-        local_env
-            .borrow_mut()
-            .create_mutable_binding(param.name().to_owned(), false, true, context)
-            .expect("Failed to create binding");
+/// A field of a class, initialized on every instance by the class constructor.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-classfielddefinition-record-specification-type
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct ClassFieldDefinition {
+    name: ClassFieldName,
+    initializer: Option<GcObject>,
+}
+
+/// The name of a class field, which is either the key of a property or a private name.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub enum ClassFieldName {
+    /// A field defined as a property of the object.
+    Key(PropertyKey),
+    /// A field defined as a private element of the object.
+    Private(RcSymbol),
+}
+
+impl ClassFieldDefinition {
+    /// Creates a new class field definition.
+    pub(crate) fn new(name: ClassFieldName, initializer: Option<GcObject>) -> Self {
+        Self { name, initializer }
+    }
+
+    /// Gets the name of the field.
+    pub fn name(&self) -> &ClassFieldName {
+        &self.name
+    }
 
-        // Set Binding to value
-        local_env
-            .borrow_mut()
-            .initialize_binding(param.name(), value, context)
-            .expect("Failed to intialize binding");
+    /// Gets the function that computes the initial value of the field, if any.
+    pub fn initializer(&self) -> Option<&GcObject> {
+        self.initializer.as_ref()
     }
+}
 
+impl Function {
     /// Returns true if the function object is callable.
     pub fn is_callable(&self) -> bool {
         match self {
             Self::BuiltIn(_, flags) => flags.is_callable(),
+            Self::Closure { flags, .. } => flags.is_callable(),
             Self::Ordinary { flags, .. } => flags.is_callable(),
+            #[cfg(feature = "vm")]
+            Self::Compiled { flags, .. } => flags.is_callable(),
         }
     }
 
@@ -169,37 +320,284 @@ impl Function {
     pub fn is_constructable(&self) -> bool {
         match self {
             Self::BuiltIn(_, flags) => flags.is_constructable(),
+            Self::Closure { flags, .. } => flags.is_constructable(),
             Self::Ordinary { flags, .. } => flags.is_constructable(),
+            #[cfg(feature = "vm")]
+            Self::Compiled { flags, .. } => flags.is_constructable(),
+        }
+    }
+
+    /// Returns true if the function object is a class constructor.
+    pub fn is_class_constructor(&self) -> bool {
+        match self {
+            Self::Ordinary { flags, .. } => flags.is_class_constructor(),
+            _ => false,
+        }
+    }
+
+    /// Sets the `[[HomeObject]]` of an ordinary or compiled function.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-makemethod
+    pub(crate) fn set_home_object(&mut self, object: GcObject) {
+        match self {
+            Self::Ordinary { home_object, .. } => *home_object = Some(object),
+            #[cfg(feature = "vm")]
+            Self::Compiled { home_object, .. } => *home_object = Some(object),
+            _ => {}
+        }
+    }
+
+    /// Adds a field definition to the `[[Fields]]` of a class constructor.
+    pub(crate) fn push_field(&mut self, field: ClassFieldDefinition) {
+        if let Self::Ordinary { fields, .. } = self {
+            fields.push(field);
+        }
+    }
+
+    /// Adds a private method or accessor to the `[[PrivateMethods]]` of a class constructor.
+    ///
+    /// A getter and a setter with the same name are merged into a single accessor.
+    pub(crate) fn push_private_method(&mut self, name: RcSymbol, method: PrivateElement) {
+        if let Self::Ordinary {
+            private_methods, ..
+        } = self
+        {
+            match private_methods.iter_mut().find(|(key, _)| *key == name) {
+                Some((_, existing)) => existing.merge_accessor(method),
+                None => private_methods.push((name, method)),
+            }
+        }
+    }
+
+    /// Sets the `[[SourceText]]` of an ordinary function.
+    pub(crate) fn set_source_text(&mut self, text: Rc<str>) {
+        if let Self::Ordinary { source_text, .. } = self {
+            *source_text = Some(text);
+        }
+    }
+
+    /// Gets the `[[SourceText]]` of a function defined in JavaScript code.
+    ///
+    /// Built-in functions have no source text.
+    pub fn source_text(&self) -> Option<&str> {
+        match self {
+            Self::Ordinary { source_text, .. } => source_text.as_deref(),
+            #[cfg(feature = "vm")]
+            Self::Compiled { code, .. } => code.source_text.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Gets the `[[Fields]]` of a class constructor.
+    pub(crate) fn fields(&self) -> &[ClassFieldDefinition] {
+        match self {
+            Self::Ordinary { fields, .. } => fields,
+            _ => &[],
+        }
+    }
+
+    /// Gets the `[[PrivateMethods]]` of a class constructor.
+    pub(crate) fn private_methods(&self) -> &[(RcSymbol, PrivateElement)] {
+        match self {
+            Self::Ordinary {
+                private_methods, ..
+            } => private_methods,
+            _ => &[],
         }
     }
 }
 
-/// Arguments.
+/// A bound function exotic object, created by `Function.prototype.bind`.
 ///
-/// <https://tc39.es/ecma262/#sec-createunmappedargumentsobject>
-pub fn create_unmapped_arguments_object(arguments_list: &[Value]) -> Value {
-    let len = arguments_list.len();
-    let mut obj = GcObject::new(Object::default());
-    // Set length
-    let length = DataDescriptor::new(
-        len,
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
-    );
-    // Define length as a property
-    obj.ordinary_define_own_property("length", length.into());
-    let mut index: usize = 0;
-    while index < len {
-        let val = arguments_list.get(index).expect("Could not get argument");
-        let prop = DataDescriptor::new(
-            val.clone(),
-            Attribute::WRITABLE | Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
-        );
+/// Calling it calls the target function with the bound `this` value and the bound arguments
+/// prepended to the arguments of the call.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-bound-function-exotic-objects
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct BoundFunction {
+    target_function: GcObject,
+    bound_this: Value,
+    bound_args: Vec<Value>,
+}
 
-        obj.insert(index, prop);
-        index += 1;
+impl BoundFunction {
+    /// Abstract operation `BoundFunctionCreate ( targetFunction, boundThis, boundArgs )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-boundfunctioncreate
+    pub(crate) fn create(
+        target_function: GcObject,
+        bound_this: Value,
+        bound_args: Vec<Value>,
+        context: &mut Context,
+    ) -> Result<GcObject> {
+        // 1. Let proto be ? targetFunction.[[GetPrototypeOf]]().
+        let proto = target_function.get_prototype_of(context)?;
+
+        // 2-9. Create the object with the bound function internal slots.
+        Ok(GcObject::new(Object::with_prototype(
+            proto,
+            ObjectData::BoundFunction(Self {
+                target_function,
+                bound_this,
+                bound_args,
+            }),
+        )))
+    }
+
+    /// The `[[BoundTargetFunction]]` internal slot: the wrapped function object.
+    pub fn target_function(&self) -> &GcObject {
+        &self.target_function
+    }
+
+    /// The `[[BoundThis]]` internal slot: the `this` value passed to the target function.
+    pub fn bound_this(&self) -> &Value {
+        &self.bound_this
+    }
+
+    /// The `[[BoundArguments]]` internal slot: the arguments passed before the call arguments.
+    pub fn bound_args(&self) -> &[Value] {
+        &self.bound_args
+    }
+
+    /// Returns `true` if the bound function has a `[[Construct]]` internal method.
+    pub(crate) fn is_constructable(&self) -> bool {
+        self.target_function.is_constructable()
+    }
+
+    /// `[[Call]] ( thisArgument, argumentsList )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-bound-function-exotic-objects-call-thisargument-argumentslist
+    pub(crate) fn call(&self, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 4. Let args be the list-concatenation of boundArgs and argumentsList.
+        let args = self.concat_args(args);
+        // 5. Return ? Call(target, boundThis, args).
+        self.target_function.call(&self.bound_this, &args, context)
+    }
+
+    /// `[[Construct]] ( argumentsList, newTarget )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-bound-function-exotic-objects-construct-argumentslist-newtarget
+    pub(crate) fn construct(
+        &self,
+        this_function: &GcObject,
+        args: &[Value],
+        new_target: Value,
+        context: &mut Context,
+    ) -> Result<Value> {
+        // 4. Let args be the list-concatenation of boundArgs and argumentsList.
+        let args = self.concat_args(args);
+        // 5. If SameValue(F, newTarget) is true, set newTarget to target.
+        let new_target = match new_target.as_object() {
+            Some(object) if GcObject::equals(&object, this_function) => {
+                self.target_function.clone().into()
+            }
+            _ => new_target,
+        };
+        // 6. Return ? Construct(target, args, newTarget).
+        self.target_function.construct(&args, new_target, context)
     }
 
-    Value::from(obj)
+    fn concat_args(&self, args: &[Value]) -> Vec<Value> {
+        self.bound_args.iter().chain(args).cloned().collect()
+    }
+}
+
+/// Parses the source text of a single function expression, like `(function () {})`.
+fn parse_function_expression(source: &str) -> std::result::Result<FunctionExpr, String> {
+    let statements = Parser::new(source.as_bytes(), false)
+        .parse_all()
+        .map_err(|e| e.to_string())?;
+    match statements.items() {
+        [Node::FunctionExpr(function)] => Ok(function.clone()),
+        _ => Err("the source text is not a single function".to_owned()),
+    }
+}
+
+/// Records the source text of a function object created from the AST, so that
+/// `Function.prototype.toString` can return it.
+///
+/// The text is the slice of the source code the node was parsed from, or the printed node if
+/// it was not parsed.
+pub(crate) fn set_source_text<S>(function: &Value, node: &S)
+where
+    S: FunctionSource,
+{
+    if let Some(object) = function.as_object() {
+        if let Some(function) = object.borrow_mut().as_function_mut() {
+            function.set_source_text(node.source_text());
+        }
+    }
+}
+
+/// Names the function of a method, getter or setter after its property key.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-setfunctionname
+pub(crate) fn set_method_name(function: &Value, kind: MethodDefinitionKind, key: &str) {
+    let name = match kind {
+        MethodDefinitionKind::Get => format!("get {}", key),
+        MethodDefinitionKind::Set => format!("set {}", key),
+        MethodDefinitionKind::Ordinary
+        | MethodDefinitionKind::Generator
+        | MethodDefinitionKind::Async => key.to_owned(),
+    };
+    function.set_property(
+        "name",
+        DataDescriptor::new(
+            name,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        ),
+    );
+}
+
+/// Binds the arguments of a call to the formal parameters of a function.
+///
+/// The bindings are created in the current environment, which must be the environment of the
+/// function being called, as default values and destructuring patterns are evaluated in it.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-functiondeclarationinstantiation
+pub(crate) fn bind_parameters(
+    params: &[FormalParameter],
+    args: &[Value],
+    context: &mut Context,
+) -> Result<()> {
+    for (i, param) in params.iter().enumerate() {
+        // Rest Parameters
+        if param.is_rest_param() {
+            let array = Array::new_array(context);
+            Array::add_to_array_object(&array, args.get(i..).unwrap_or_default(), context)?;
+            param
+                .target()
+                .bind(array, BindingKind::Parameter, context)?;
+            break;
+        }
+
+        let value = args.get(i).cloned().unwrap_or_default();
+        param
+            .element()
+            .bind(value, BindingKind::Parameter, context)?;
+    }
+    Ok(())
 }
 
 /// Creates a new member function of a `Object` or `prototype`.
@@ -257,7 +655,40 @@ pub struct BuiltInFunctionObject;
 impl BuiltInFunctionObject {
     pub const LENGTH: usize = 1;
 
-    fn constructor(new_target: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+    /// `Function ( p1, p2, … , pn, body )`
+    ///
+    /// Creates a function from the source text of its parameters and body. The function is
+    /// created in the global environment, whatever the environment of the caller.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createdynamicfunction
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/Function
+    fn constructor(new_target: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        if !context.eval_enabled() {
+            return context.throw_eval_error("code generation from strings is disabled");
+        }
+
+        let (body, parameters) = match args.split_last() {
+            Some((body, parameters)) => (body.to_string(context)?.to_string(), parameters),
+            None => (String::new(), args),
+        };
+        let parameters = parameters
+            .iter()
+            .map(|parameter| Ok(parameter.to_string(context)?.to_string()))
+            .collect::<Result<Vec<_>>>()?
+            .join(",");
+
+        // The parameters and the body are checked on their own first, so that neither can end
+        // the function early and smuggle code outside of it.
+        let source = format!("function anonymous({}\n) {{\n{}\n}}", parameters, body);
+        let function = parse_function_expression(&format!("(function ({}\n) {{}})", parameters))
+            .and_then(|_| parse_function_expression(&format!("(function () {{\n{}\n}})", body)))
+            .and_then(|_| parse_function_expression(&format!("({})", source)))
+            .map_err(|e| context.construct_syntax_error(e))?;
+
         let prototype = new_target
             .as_object()
             .and_then(|obj| {
@@ -266,17 +697,25 @@ impl BuiltInFunctionObject {
                     .transpose()
             })
             .transpose()?
-            .unwrap_or_else(|| context.standard_objects().object_object().prototype());
-        let this = Value::new_object(context);
+            .unwrap_or_else(|| context.standard_objects().function_object().prototype());
 
+        let mut flags = FunctionFlags::CALLABLE | FunctionFlags::CONSTRUCTABLE;
+        if function.strict() {
+            flags |= FunctionFlags::STRICT;
+        }
+        let this = context.with_global_environment(|context| {
+            context.create_function(
+                function.parameters().to_vec(),
+                function.body().to_vec(),
+                flags,
+            )
+        })?;
         this.as_object()
             .expect("this should be an object")
             .set_prototype_instance(prototype.into());
+        this.set_field("name", "anonymous", context)?;
+        set_source_text(&this, &function);
 
-        this.set_data(ObjectData::Function(Function::BuiltIn(
-            BuiltInFunction(|_, _, _| Ok(Value::undefined())),
-            FunctionFlags::CALLABLE | FunctionFlags::CONSTRUCTABLE,
-        )));
         Ok(this)
     }
 
@@ -332,6 +771,177 @@ impl BuiltInFunctionObject {
         // TODO?: 5. PrepareForTailCall
         context.call(this, &this_arg, &arg_list)
     }
+
+    /// `Function.prototype.bind ( thisArg, ...args )`
+    ///
+    /// The bind() method creates a new function that, when called, calls this function with
+    /// its `this` set to `thisArg` and the given arguments prepended to the call arguments.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-function.prototype.bind
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/bind
+    fn bind(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let Target be the this value.
+        // 2. If IsCallable(Target) is false, throw a TypeError exception.
+        let target = match this.as_object() {
+            Some(object) if object.is_callable() => object,
+            _ => return context.throw_type_error("Bind must be called on a function"),
+        };
+
+        let this_arg = args.get(0).cloned().unwrap_or_default();
+        let bound_args = args.get(1..).unwrap_or_default().to_vec();
+        let arg_count = bound_args.len() as f64;
+
+        // 3. Let F be ? BoundFunctionCreate(Target, thisArg, args).
+        let mut f = BoundFunction::create(target.clone(), this_arg, bound_args, context)?;
+
+        // 4. Let L be 0.
+        let mut length = 0.0;
+
+        // 5. Let targetHasLength be ? HasOwnProperty(Target, "length").
+        // 6. If targetHasLength is true, then
+        if target
+            .get_own_property(&"length".into(), context)?
+            .is_some()
+        {
+            // a. Let targetLen be ? Get(Target, "length").
+            // b. If Type(targetLen) is Number, then
+            if let Some(target_len) = target
+                .get(&"length".into(), target.clone().into(), context)?
+                .as_number()
+            {
+                length = if target_len == f64::INFINITY {
+                    // i. If targetLen is +∞𝔽, set L to +∞.
+                    f64::INFINITY
+                } else if target_len == f64::NEG_INFINITY {
+                    // ii. Else if targetLen is -∞𝔽, set L to 0.
+                    0.0
+                } else {
+                    // iii. Else,
+                    //    1. Let targetLenAsInt be ! ToIntegerOrInfinity(targetLen).
+                    //    2. Set L to max(targetLenAsInt - argCount, 0).
+                    let target_len = if target_len.is_nan() {
+                        0.0
+                    } else {
+                        target_len.trunc()
+                    };
+                    (target_len - arg_count).max(0.0)
+                };
+            }
+        }
+
+        // 7. Perform ! SetFunctionLength(F, L).
+        f.define_property_or_throw(
+            "length",
+            DataDescriptor::new(
+                Value::from(length),
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            ),
+            context,
+        )?;
+
+        // 8. Let targetName be ? Get(Target, "name").
+        // 9. If Type(targetName) is not String, set targetName to the empty String.
+        let target_name = match target.get(&"name".into(), target.clone().into(), context)? {
+            Value::String(ref name) => name.clone(),
+            _ => "".into(),
+        };
+
+        // 10. Perform SetFunctionName(F, targetName, "bound").
+        f.define_property_or_throw(
+            "name",
+            DataDescriptor::new(
+                format!("bound {}", target_name),
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            ),
+            context,
+        )?;
+
+        // 11. Return F.
+        Ok(f.into())
+    }
+
+    /// `Function.prototype.toString ( )`
+    ///
+    /// Returns a string representing the source code of the function. Built-in and bound
+    /// functions are shown with a `[native code]` body.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-function.prototype.tostring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/toString
+    #[allow(clippy::wrong_self_convention)]
+    fn to_string(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let func be the this value.
+        let function = match this.as_object() {
+            Some(object) if object.is_callable() => object,
+            // 5. Throw a TypeError exception.
+            _ => {
+                return context.throw_type_error(
+                    "Function.prototype.toString requires that 'this' be a Function",
+                )
+            }
+        };
+
+        // 2. If func has a [[SourceText]] internal slot, return func.[[SourceText]].
+        let source_text = function.borrow().as_function().and_then(|function| {
+            function
+                .source_text()
+                .map(|text| text.trim_end().to_owned())
+        });
+        if let Some(source_text) = source_text {
+            return Ok(source_text.into());
+        }
+
+        // 3-4. Return an implementation-defined String source code representation of func,
+        //      with the syntax of a NativeFunction.
+        let name = match function.get(&"name".into(), function.clone().into(), context)? {
+            Value::String(ref name) => name.clone(),
+            _ => "".into(),
+        };
+        Ok(format!("function {}() {{ [native code] }}", name).into())
+    }
+
+    /// `%ThrowTypeError%`
+    ///
+    /// Throws a `TypeError`, when the `callee` property of an unmapped `arguments` object is
+    /// accessed.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%throwtypeerror%
+    fn throw_type_error(_: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        context.throw_type_error(
+            "'caller', 'callee' and 'arguments' cannot be accessed in strict mode functions",
+        )
+    }
+
+    /// `Function.prototype [ @@hasInstance ] ( V )`
+    ///
+    /// Determines whether `V` is an instance of this function, following the same algorithm
+    /// as the `instanceof` operator when no custom `@@hasInstance` is defined.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-function.prototype-@@hasinstance
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/@@hasInstance
+    fn has_instance(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let F be the this value.
+        // 2. Return ? OrdinaryHasInstance(F, V).
+        let value = args.get(0).cloned().unwrap_or_default();
+        match this.as_object() {
+            Some(function) => Ok(function.ordinary_has_instance(context, &value)?.into()),
+            None => Ok(false.into()),
+        }
+    }
 }
 
 impl BuiltIn for BuiltInFunctionObject {
@@ -352,6 +962,28 @@ impl BuiltIn for BuiltInFunctionObject {
             .constructable(false)
             .build_function_prototype(&function_prototype);
 
+        let has_instance = FunctionBuilder::new(context, Self::has_instance)
+            .name("[Symbol.hasInstance]")
+            .length(1)
+            .callable(true)
+            .constructable(false)
+            .build();
+
+        // %ThrowTypeError% is a frozen function, shared by all the objects using it
+        // see <https://tc39.es/ecma262/#sec-%throwtypeerror%>
+        let mut throw_type_error = FunctionBuilder::new(context, Self::throw_type_error)
+            .name("")
+            .length(0)
+            .callable(true)
+            .constructable(false)
+            .build();
+        throw_type_error.insert("length", DataDescriptor::new(0, Attribute::empty()));
+        throw_type_error.insert("name", DataDescriptor::new("", Attribute::empty()));
+        throw_type_error
+            .prevent_extensions(context)
+            .expect("ordinary objects can always be made non-extensible");
+        context.throw_type_error_function = Some(throw_type_error);
+
         let function_object = ConstructorBuilder::with_standard_object(
             context,
             Self::constructor,
@@ -361,6 +993,13 @@ impl BuiltIn for BuiltInFunctionObject {
         .length(Self::LENGTH)
         .method(Self::call, "call", 1)
         .method(Self::apply, "apply", 1)
+        .method(Self::bind, "bind", 1)
+        .method(Self::to_string, "toString", 0)
+        .property(
+            WellKnownSymbols::has_instance(),
+            has_instance,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+        )
         .build();
 
         (Self::NAME, function_object.into(), Self::attribute())
@@ -212,3 +212,371 @@ fn function_prototype_apply_on_object() {
         .unwrap();
     assert!(boolean);
 }
+
+#[test]
+fn function_prototype_bind() {
+    let mut context = Context::new();
+    let init = r#"
+        function add(a, b, c) { return this.base + a + b + c; }
+        var bound = add.bind({ base: 100 }, 1, 2);
+        var twice = bound.bind(null, 3);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "bound(3)"), "106");
+    assert_eq!(forward(&mut context, "twice()"), "106");
+    assert_eq!(forward(&mut context, "bound.name"), "\"bound add\"");
+    assert_eq!(forward(&mut context, "twice.name"), "\"bound bound add\"");
+    assert_eq!(forward(&mut context, "bound.length"), "1");
+    assert_eq!(
+        forward(&mut context, "add.bind(null, 1, 2, 3, 4).length"),
+        "0"
+    );
+    assert_eq!(forward(&mut context, "typeof bound"), "\"function\"");
+    assert_eq!(
+        forward(&mut context, "bound.hasOwnProperty('prototype')"),
+        "false"
+    );
+    assert_eq!(forward(&mut context, "bound.call({ base: 0 }, 4)"), "107");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Function.prototype.bind.call({}) } catch (e) { e.constructor.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn function_prototype_bind_new() {
+    let mut context = Context::new();
+    let init = r#"
+        function Point(x, y) { this.x = x; this.y = y; }
+        var XPoint = Point.bind({ ignored: true }, 1);
+        var point = new XPoint(2);
+        var arrow = (() => 1).bind(null);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "point.x + point.y"), "3");
+    assert_eq!(forward(&mut context, "point.ignored === undefined"), "true");
+    assert_eq!(forward(&mut context, "point instanceof Point"), "true");
+    assert_eq!(forward(&mut context, "point instanceof XPoint"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(point) === Point.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(XPoint) === Function.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "arrow()"), "1");
+}
+
+#[test]
+fn function_prototype_to_string() {
+    let mut context = Context::new();
+    let init = r#"
+        function  add ( a, b ) { /* sum */ return a + b; }
+        class Point { constructor(x) { this.x = x; } static  of(x) { return new Point(x); }
+            get x2() { return this.x * 2; } }
+        var o = { * gen() { yield 1 }, m(k) { return k } };
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "add.toString()"),
+        "\"function  add ( a, b ) { /* sum */ return a + b; }\""
+    );
+    assert_eq!(forward(&mut context, "((a) =>a).toString()"), "\"(a) =>a\"");
+    assert_eq!(
+        forward(&mut context, "Point.toString()"),
+        "\"class Point { constructor(x) { this.x = x; } static  of(x) { return new Point(x); }\n            get x2() { return this.x * 2; } }\""
+    );
+    assert_eq!(
+        forward(&mut context, "Point.of.toString()"),
+        "\"of(x) { return new Point(x); }\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(Point.prototype, 'x2').get.toString()"
+        ),
+        "\"get x2() { return this.x * 2; }\""
+    );
+    assert_eq!(
+        forward(&mut context, "o.gen.toString()"),
+        "\"* gen() { yield 1 }\""
+    );
+    assert_eq!(
+        forward(&mut context, "o.m.toString()"),
+        "\"m(k) { return k }\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "(function make() { return { id(k) { return k; } }; }).toString()"
+        ),
+        "\"function make() { return { id(k) { return k; } }; }\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "(function () { return 'é' + /* ü */ `${1 === 1}` }).toString()"
+        ),
+        "\"function () { return 'é' + /* ü */ `${1 === 1}` }\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "(function () {\r\n  return 1\u{2028}+ 2;\r}).toString()"
+        ),
+        "\"function () {\r\n  return 1\u{2028}+ 2;\r}\""
+    );
+    assert_eq!(
+        forward(&mut context, "Math.max.toString()"),
+        "\"function max() { [native code] }\""
+    );
+    assert_eq!(
+        forward(&mut context, "add.bind(null).toString()"),
+        "\"function bound add() { [native code] }\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Function.prototype.toString.call({}) } catch (e) { e.constructor.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn function_prototype_has_instance() {
+    let mut context = Context::new();
+    let init = r#"
+        function Foo() {}
+        var foo = new Foo();
+        var desc = Object.getOwnPropertyDescriptor(Function.prototype, Symbol.hasInstance);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "Foo[Symbol.hasInstance](foo)"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Foo[Symbol.hasInstance]({})"),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Function.prototype[Symbol.hasInstance].call({}, foo)"
+        ),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "desc.writable || desc.enumerable || desc.configurable"
+        ),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "Function.prototype[Symbol.hasInstance].name"),
+        "\"[Symbol.hasInstance]\""
+    );
+}
+
+#[test]
+fn function_constructor() {
+    let mut context = Context::new();
+    let init = r#"
+        var add = new Function("a", "b", "return a + b;");
+        var sum = Function("a, b", "c", "return a + b + c;");
+        var empty = new Function();
+        function scoped() {
+            var x = "local";
+            return Function("try { return x; } catch (e) { return e.name; }")();
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "add(1, 2)"), "3");
+    assert_eq!(forward(&mut context, "add.length"), "2");
+    assert_eq!(forward(&mut context, "add.name"), "\"anonymous\"");
+    assert_eq!(forward(&mut context, "sum(1, 2, 3)"), "6");
+    assert_eq!(forward(&mut context, "empty()"), "undefined");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(add) === Function.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "scoped()"), "\"ReferenceError\"");
+    assert_eq!(
+        forward(&mut context, "add.toString()"),
+        "\"function anonymous(a,b\n) {\nreturn a + b;\n}\""
+    );
+}
+
+#[test]
+fn function_constructor_syntax_error() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Function('a', 'return a +'); } catch (e) { e instanceof SyntaxError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Function('a) {}; (function (', ''); } catch (e) { e instanceof SyntaxError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Function('}); (function () {'); } catch (e) { e instanceof SyntaxError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn mapped_arguments_object() {
+    let mut context = Context::new();
+    let init = r#"
+        function f(a, b, c) {
+            arguments[0] = 10;
+            b = 20;
+            delete arguments[2];
+            arguments[2] = 30;
+            return [a, arguments[1], c, arguments.length, arguments.callee === f];
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "f(1, 2, 3).join()"),
+        "\"10,20,3,3,true\""
+    );
+    assert_eq!(
+        forward(&mut context, "f(1).map(String).join()"),
+        "\"10,undefined,undefined,1,true\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "(function (a, b = 1) { arguments[0] = 2; return a; })(1)"
+        ),
+        "1"
+    );
+}
+
+#[test]
+fn unmapped_arguments_object() {
+    let mut context = Context::new();
+    let init = r#"
+        function f(a) {
+            "use strict";
+            arguments[0] = 10;
+            return a;
+        }
+        function callee() {
+            "use strict";
+            try {
+                arguments.callee;
+            } catch (e) {
+                return e instanceof TypeError;
+            }
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "f(1)"), "1");
+    assert_eq!(forward(&mut context, "callee()"), "true");
+}
+
+#[test]
+fn arguments_object_is_iterable() {
+    let mut context = Context::new();
+    let init = r#"
+        function f() {
+            return [...arguments].join() + Object.prototype.toString.call(arguments);
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "f(1, 2, 3)"),
+        "\"1,2,3[object Arguments]\""
+    );
+}
+
+#[test]
+fn arrow_function_arguments() {
+    let mut context = Context::new();
+    let init = r#"
+        function f() {
+            return (() => arguments[0])(2);
+        }
+        function g(arguments) {
+            return arguments;
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "f(1)"), "1");
+    assert_eq!(forward(&mut context, "g(3)"), "3");
+}
+
+#[test]
+fn arrows_and_methods_are_not_constructors() {
+    let mut context = Context::new();
+    let init = r#"
+        const arrow = () => 1;
+        const obj = { method() {}, get getter() { return 1; } };
+        class C { method() {} static staticMethod() {} }
+        function construct(f) {
+            try {
+                new f();
+                return "constructed";
+            } catch (e) {
+                return e.name;
+            }
+        }
+        "#;
+    forward(&mut context, init);
+    for function in &[
+        "arrow",
+        "arrow.bind(null)",
+        "async () => 1",
+        "obj.method",
+        "Object.getOwnPropertyDescriptor(obj, 'getter').get",
+        "C.prototype.method",
+        "C.staticMethod",
+    ] {
+        assert_eq!(
+            forward(&mut context, format!("construct({})", function)),
+            "\"TypeError\"",
+            "{}",
+            function
+        );
+        assert_eq!(
+            forward(&mut context, format!("'prototype' in ({})", function)),
+            "false",
+            "{}",
+            function
+        );
+    }
+    assert_eq!(
+        forward(&mut context, "construct(function () {})"),
+        "\"constructed\""
+    );
+    assert_eq!(
+        forward(&mut context, "construct(C.bind(null))"),
+        "\"constructed\""
+    );
+}
@@ -0,0 +1,246 @@
+//! This module implements the global `Generator` object.
+//!
+//! Calling a generator function creates a generator object whose body runs in a [`Coroutine`].
+//! Every call to `next`, `return` or `throw` resumes the body until the next `yield`
+//! expression suspends it again, or until it completes.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-generator-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator
+
+use crate::{
+    builtins::{function::make_builtin_fn, iterable::create_iter_result_object},
+    environment::lexical_environment::Environment,
+    exec::coroutine::{Coroutine, CoroutineState, ResumeAction},
+    gc::{empty_trace, Finalize, Trace},
+    object::{GcObject, ObjectData, PROTOTYPE},
+    property::{Attribute, DataDescriptor},
+    symbol::WellKnownSymbols,
+    syntax::ast::node::RcStatementList,
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The `[[GeneratorState]]` internal slot of a generator.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-properties-of-generator-instances
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Finalize)]
+pub(crate) enum GeneratorState {
+    SuspendedStart,
+    SuspendedYield,
+    Executing,
+    Completed,
+}
+
+unsafe impl Trace for GeneratorState {
+    empty_trace!();
+}
+
+/// The internal representation of a `Generator` object.
+#[derive(Debug, Trace, Finalize)]
+pub struct Generator {
+    state: GeneratorState,
+
+    /// The suspended body, `None` while it is running or once it has completed.
+    coroutine: Option<Coroutine>,
+}
+
+impl Generator {
+    /// The name of the object.
+    pub(crate) const NAME: &'static str = "Generator";
+
+    /// Creates the generator object returned by a call to a generator function.
+    ///
+    /// The body is run in the given function environment once the generator is resumed.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator-objects-call-thisargument-argumentslist
+    pub(crate) fn create(
+        function: &GcObject,
+        body: RcStatementList,
+        environment: Environment,
+        context: &mut Context,
+    ) -> Result<Value> {
+        // 1. Let G be ? OrdinaryCreateFromConstructor(functionObject, "%GeneratorFunction.prototype.prototype%", « [[GeneratorState]], [[GeneratorContext]] »).
+        let prototype = function.get(&PROTOTYPE.into(), function.clone().into(), context)?;
+        let prototype = if prototype.is_object() {
+            prototype
+        } else {
+            context.iterator_prototypes().generator().into()
+        };
+
+        // 2. Perform GeneratorStart(G, FunctionBody).
+        let coroutine = Coroutine::new(function.stack_frame_name(), environment, body);
+
+        let generator = Value::new_object(context);
+        generator.set_data(ObjectData::Generator(Self {
+            state: GeneratorState::SuspendedStart,
+            coroutine: Some(coroutine),
+        }));
+        generator
+            .as_object()
+            .expect("generator must be an object")
+            .set_prototype_instance(prototype);
+
+        // 3. Return Completion { [[Type]]: return, [[Value]]: G, [[Target]]: empty }.
+        Ok(generator)
+    }
+
+    /// `Generator.prototype.next( value )`
+    ///
+    /// Resumes the generator, the `yield` expression it is suspended at evaluates to `value`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator.prototype.next
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/next
+    pub(crate) fn next(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let value = args.get(0).cloned().unwrap_or_default();
+        Self::resume(this, ResumeAction::Next(value), context)
+    }
+
+    /// `Generator.prototype.return( value )`
+    ///
+    /// Resumes the generator with a return completion, which runs the enclosing `finally`
+    /// blocks of the body before completing the generator.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator.prototype.return
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/return
+    pub(crate) fn r#return(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let value = args.get(0).cloned().unwrap_or_default();
+        Self::resume(this, ResumeAction::Return(value), context)
+    }
+
+    /// `Generator.prototype.throw( exception )`
+    ///
+    /// Resumes the generator by throwing `exception` at the `yield` expression it is suspended
+    /// at.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator.prototype.throw
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/throw
+    pub(crate) fn throw(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let exception = args.get(0).cloned().unwrap_or_default();
+        Self::resume(this, ResumeAction::Throw(exception), context)
+    }
+
+    /// Resumes the body of the generator with the given completion.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [ECMAScript reference (abrupt completions)][spec_abrupt]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generatorresume
+    /// [spec_abrupt]: https://tc39.es/ecma262/#sec-generatorresumeabrupt
+    fn resume(this: &Value, action: ResumeAction, context: &mut Context) -> Result<Value> {
+        // 1. Let state be ? GeneratorValidate(generator, empty).
+        let object = match this.as_object() {
+            Some(object) => object,
+            None => return context.throw_type_error("`this` is not a generator"),
+        };
+        let mut coroutine = {
+            let mut object = object.borrow_mut();
+            let generator = match object.as_generator_mut() {
+                Some(generator) => generator,
+                None => return context.throw_type_error("`this` is not a generator"),
+            };
+            match generator.state {
+                GeneratorState::Executing => {
+                    return context.throw_type_error("generator is already running")
+                }
+                // GeneratorResumeAbrupt, step 2.a: a generator that did not start yet completes
+                // without running its body.
+                GeneratorState::SuspendedStart if !matches!(action, ResumeAction::Next(_)) => {
+                    generator.state = GeneratorState::Completed;
+                    generator.coroutine = None;
+                }
+                _ => {}
+            }
+            match generator.coroutine.take() {
+                Some(coroutine) => {
+                    generator.state = GeneratorState::Executing;
+                    coroutine
+                }
+                // 2. If state is completed, return CreateIterResultObject(undefined, true).
+                None => {
+                    drop(object);
+                    return match action {
+                        ResumeAction::Next(_) => {
+                            Ok(create_iter_result_object(context, Value::undefined(), true))
+                        }
+                        ResumeAction::Return(value) => {
+                            Ok(create_iter_result_object(context, value, true))
+                        }
+                        ResumeAction::Throw(exception) => Err(exception),
+                    };
+                }
+            }
+        };
+
+        let result = coroutine.resume(action, context);
+
+        let mut object = object.borrow_mut();
+        let generator = object
+            .as_generator_mut()
+            .expect("generator object lost its generator data");
+        match result {
+            // GeneratorYield ( iterNextObj ), the yielded value is already an iterator result.
+            Ok(CoroutineState::Suspended(result)) => {
+                generator.state = GeneratorState::SuspendedYield;
+                generator.coroutine = Some(coroutine);
+                Ok(result)
+            }
+            // GeneratorStart ( generator, generatorBody ), steps 4.e-k.
+            Ok(CoroutineState::Complete(result)) => {
+                generator.state = GeneratorState::Completed;
+                drop(object);
+                let value = result?;
+                Ok(create_iter_result_object(context, value, true))
+            }
+            Err(error) => {
+                generator.state = GeneratorState::Completed;
+                Err(error)
+            }
+        }
+    }
+
+    /// Create the %GeneratorPrototype% object
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-properties-of-generator-prototype
+    pub(crate) fn create_prototype(context: &mut Context, iterator_prototype: Value) -> GcObject {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let mut generator = context.construct_object();
+        make_builtin_fn(Self::next, "next", &generator, 1, context);
+        make_builtin_fn(Self::r#return, "return", &generator, 1, context);
+        make_builtin_fn(Self::throw, "throw", &generator, 1, context);
+        generator.set_prototype_instance(iterator_prototype);
+
+        let to_string_tag = WellKnownSymbols::to_string_tag();
+        let to_string_tag_property = DataDescriptor::new(Self::NAME, Attribute::CONFIGURABLE);
+        generator.insert(to_string_tag, to_string_tag_property);
+        generator
+    }
+}
@@ -0,0 +1,368 @@
+use crate::{forward, Context};
+
+#[test]
+fn next_runs_until_yield() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        function* gen() {
+            log.push("start");
+            const x = yield 1;
+            log.push(x);
+            yield x * 2;
+            return "done";
+        }
+        var g = gen();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "log.length"), "0");
+    assert_eq!(forward(&mut context, "g.next().value"), "1");
+    assert_eq!(forward(&mut context, "log.join()"), "\"start\"");
+    assert_eq!(forward(&mut context, "g.next(5).value"), "10");
+    assert_eq!(forward(&mut context, "log.join()"), "\"start,5\"");
+
+    forward(&mut context, "var last = g.next();");
+    assert_eq!(forward(&mut context, "last.value"), "\"done\"");
+    assert_eq!(forward(&mut context, "last.done"), "true");
+
+    forward(&mut context, "var after = g.next();");
+    assert_eq!(forward(&mut context, "after.value"), "undefined");
+    assert_eq!(forward(&mut context, "after.done"), "true");
+}
+
+#[test]
+fn iteration_protocol() {
+    let mut context = Context::new();
+    let init = r#"
+        function* range(n) {
+            for (let i = 0; i < n; i++) {
+                yield i;
+            }
+        }
+        var values = [];
+        for (const v of range(3)) {
+            values.push(v);
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "values.join()"), "\"0,1,2\"");
+    assert_eq!(forward(&mut context, "[...range(4)].join()"), "\"0,1,2,3\"");
+    assert_eq!(
+        forward(&mut context, "range(1)[Symbol.iterator]() instanceof range"),
+        "true"
+    );
+}
+
+#[test]
+fn return_runs_finally() {
+    let mut context = Context::new();
+    let init = r#"
+        var cleaned = false;
+        function* gen() {
+            try {
+                yield 1;
+                yield 2;
+            } finally {
+                cleaned = true;
+            }
+        }
+        var g = gen();
+        g.next();
+        var result = g.return(42);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "cleaned"), "true");
+    assert_eq!(forward(&mut context, "result.value"), "42");
+    assert_eq!(forward(&mut context, "result.done"), "true");
+    assert_eq!(forward(&mut context, "g.next().done"), "true");
+}
+
+#[test]
+fn break_closes_generator() {
+    let mut context = Context::new();
+    let init = r#"
+        var cleaned = false;
+        function* gen() {
+            try {
+                yield 1;
+                yield 2;
+            } finally {
+                cleaned = true;
+            }
+        }
+        for (const v of gen()) {
+            break;
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "cleaned"), "true");
+}
+
+#[test]
+fn throw_resumes_with_exception() {
+    let mut context = Context::new();
+    let init = r#"
+        function* gen() {
+            try {
+                yield 1;
+            } catch (e) {
+                yield "caught " + e;
+            }
+        }
+        var g = gen();
+        g.next();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "g.throw('x').value"), "\"caught x\"");
+
+    let init = r#"
+        var h = gen();
+        var error;
+        try {
+            h.throw(new Error("not started"));
+        } catch (e) {
+            error = e.message;
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "error"), "\"not started\"");
+    assert_eq!(forward(&mut context, "h.next().done"), "true");
+}
+
+#[test]
+fn delegating_yield() {
+    let mut context = Context::new();
+    let init = r#"
+        function* inner() {
+            const x = yield "a";
+            yield x;
+            return "inner done";
+        }
+        function* outer() {
+            const result = yield* inner();
+            yield result;
+            yield* [1, 2];
+        }
+        var g = outer();
+        var values = [g.next().value, g.next("b").value, g.next().value, g.next().value, g.next().value];
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "values.join()"),
+        "\"a,b,inner done,1,2\""
+    );
+    assert_eq!(forward(&mut context, "g.next().done"), "true");
+}
+
+#[test]
+fn generator_methods() {
+    let mut context = Context::new();
+    let init = r#"
+        var obj = {
+            *values() {
+                yield 1;
+                yield 2;
+            }
+        };
+        class Collection {
+            *[Symbol.iterator]() {
+                yield* obj.values();
+            }
+            static *empty() {}
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "[...obj.values()].join()"), "\"1,2\"");
+    assert_eq!(
+        forward(&mut context, "[...new Collection()].join()"),
+        "\"1,2\""
+    );
+    assert_eq!(
+        forward(&mut context, "Collection.empty().next().done"),
+        "true"
+    );
+}
+
+#[test]
+fn running_generator_throws() {
+    let mut context = Context::new();
+    let init = r#"
+        function* gen() {
+            try {
+                g.next();
+            } catch (e) {
+                yield e instanceof TypeError;
+            }
+        }
+        var g = gen();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "g.next().value"), "true");
+}
+
+#[test]
+fn generator_prototype() {
+    let mut context = Context::new();
+    let init = r#"
+        function* gen() {}
+        var g = gen();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(g)"),
+        "\"[object Generator]\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.getPrototypeOf(g) === gen.prototype"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "gen.prototype.hasOwnProperty('constructor')"),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new gen(); } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn resumed_generator_stack() {
+    let mut context = Context::new();
+    let init = r#"
+        function frames() {
+            return new Error().stack.split("\n").slice(2).map(line => line.trim().split(" ")[1]).join();
+        }
+        function* gen() {
+            yield frames();
+            yield frames();
+        }
+        function resume(g) {
+            return g.next().value;
+        }
+        var g = gen();
+        var first = resume(g);
+        var second = g.next().value;
+        var o = { *m() { yield frames(); } };
+        var method = o.m().next().value;
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "first"), "\"gen,resume,<global>\"");
+    assert_eq!(forward(&mut context, "second"), "\"gen,<global>\"");
+    assert_eq!(forward(&mut context, "method"), "\"m,<global>\"");
+    assert_eq!(forward(&mut context, "frames()"), "\"<global>\"");
+}
+
+#[test]
+fn many_abandoned_generators() {
+    let mut context = Context::new();
+    let init = r#"
+        var s = 0;
+        function* g() { yield 1; }
+        for (var i = 0; i < 60000; i++) {
+            s += g().next().value;
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "s"), "60000");
+}
+
+#[test]
+fn suspended_generator_is_collected() {
+    let mut context = Context::new();
+    let init = r#"
+        var collected = [];
+        var registry = new FinalizationRegistry(held => collected.push(held));
+        (function () {
+            var held = {};
+            registry.register(held, "held");
+            function* g(value) { [value, yield]; }
+            held.generator = g(held);
+            held.generator.next();
+        })();
+        "#;
+    forward(&mut context, init);
+
+    crate::gc::force_collect();
+    context.run_jobs().unwrap();
+    crate::gc::force_collect();
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "collected.join()"), "\"held\"");
+}
+
+#[test]
+fn resume_inside_expressions_and_statements() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        var calls = 0;
+        function count() { return ++calls; }
+        function* g() {
+            log.push([count(), yield "array", count()].join("-"));
+            for (let i of [1, 2]) {
+                try {
+                    log.push(yield i);
+                } finally {
+                    log.push("finally " + i);
+                }
+            }
+            var [a, b = yield "default"] = [yield "element"];
+            log.push(a + b);
+            log.push(count() + (yield "binary"));
+            var i = 0;
+            while (i < 2) {
+                i += yield 1;
+            }
+            log.push(i);
+            return yield* [yield "delegate", "inner"];
+        }
+        var it = g();
+        var yielded = [];
+        var result = it.next("start");
+        while (!result.done) {
+            yielded.push(result.value);
+            result = it.next(typeof result.value === "number" ? result.value : result.value.charAt(0));
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"1-a-2,1,finally 1,2,finally 2,ed,3b,2\""
+    );
+    assert_eq!(
+        forward(&mut context, "yielded.join()"),
+        "\"array,1,2,element,default,binary,1,1,delegate,d,inner\""
+    );
+}
+
+#[test]
+fn closures_keep_environments_across_yields() {
+    let mut context = Context::new();
+    let init = r#"
+        function* g() {
+            var fns = [];
+            for (var i = 0; i < 2; i++) {
+                let j = i;
+                fns.push(() => j);
+                yield;
+            }
+            {
+                let x = 1;
+                fns.push(() => x);
+                yield;
+                x = 2;
+            }
+            return fns.map(f => f()).join();
+        }
+        var it = g();
+        it.next();
+        it.next();
+        it.next();
+        var result = it.next().value;
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result"), "\"0,1,2\"");
+}
@@ -2,6 +2,7 @@ use crate::{
     builtins::string::string_iterator::StringIterator,
     builtins::ArrayIterator,
     builtins::ForInIterator,
+    builtins::Generator,
     builtins::MapIterator,
     builtins::SetIterator,
     object::{GcObject, ObjectInitializer},
@@ -18,6 +19,7 @@ pub struct IteratorPrototypes {
     string_iterator: GcObject,
     map_iterator: GcObject,
     for_in_iterator: GcObject,
+    generator: GcObject,
 }
 
 impl IteratorPrototypes {
@@ -38,6 +40,7 @@ impl IteratorPrototypes {
                 context,
                 iterator_prototype.clone().into(),
             ),
+            generator: Generator::create_prototype(context, iterator_prototype.clone().into()),
             iterator_prototype,
         }
     }
@@ -71,6 +74,11 @@ impl IteratorPrototypes {
     pub fn for_in_iterator(&self) -> GcObject {
         self.for_in_iterator.clone()
     }
+
+    #[inline]
+    pub fn generator(&self) -> GcObject {
+        self.generator.clone()
+    }
 }
 
 /// CreateIterResultObject( value, done )
@@ -134,6 +142,16 @@ impl IteratorRecord {
         }
     }
 
+    /// The `[[Iterator]]` field of the record.
+    pub(crate) fn iterator_object(&self) -> &Value {
+        &self.iterator_object
+    }
+
+    /// The `[[NextMethod]]` field of the record.
+    pub(crate) fn next_function(&self) -> &Value {
+        &self.next_function
+    }
+
     /// Get the next value in the iterator
     ///
     /// More information:
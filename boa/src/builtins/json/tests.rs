@@ -159,6 +159,57 @@ fn json_stringify_function_replacer_propogate_error() {
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn json_stringify_proxy() {
+    let mut context = Context::new();
+
+    let actual = forward(
+        &mut context,
+        r#"
+        const handler = {
+            ownKeys: () => ['a', 'b', 'hidden'],
+            getOwnPropertyDescriptor: (target, key) => ({
+                value: key,
+                enumerable: key !== 'hidden',
+                configurable: true,
+            }),
+            get: (target, key) => key + '!',
+        };
+        JSON.stringify(new Proxy({}, handler)) + JSON.stringify(new Proxy([1, 2], {}))
+        "#,
+    );
+    let expected = r#""{"a":"a!","b":"b!"}[1,2]""#;
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn json_stringify_typed_array() {
+    let mut context = Context::new();
+
+    let actual = forward(&mut context, r#"JSON.stringify(new Uint8Array([1, 2, 3]))"#);
+    let expected = r#""{"0":1,"1":2,"2":3}""#;
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn json_stringify_skips_non_enumerable_properties() {
+    let mut context = Context::new();
+
+    let actual = forward(
+        &mut context,
+        r#"
+        function f() { return JSON.stringify(arguments); }
+        const o = Object.defineProperty({ a: 1 }, 'b', { value: 2, enumerable: false });
+        f(1, 2) + JSON.stringify(o)
+        "#,
+    );
+    let expected = r#""{"0":1,"1":2}{"a":1}""#;
+
+    assert_eq!(actual, expected);
+}
+
 #[test]
 fn json_stringify_function() {
     let mut context = Context::new();
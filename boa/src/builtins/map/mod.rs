@@ -45,6 +45,14 @@ impl BuiltIn for Map {
 
         let iterator_symbol = WellKnownSymbols::iterator();
 
+        let species = WellKnownSymbols::species();
+
+        let species_getter = FunctionBuilder::new(context, Self::species_getter)
+            .callable(true)
+            .constructable(false)
+            .name("get [Symbol.species]")
+            .build();
+
         let entries_function = FunctionBuilder::new(context, Self::entries)
             .name("entries")
             .length(0)
@@ -59,6 +67,7 @@ impl BuiltIn for Map {
         )
         .name(Self::NAME)
         .length(Self::LENGTH)
+        .static_accessor(species, Some(species_getter), None, Attribute::CONFIGURABLE)
         .property(
             "entries",
             entries_function.clone(),
@@ -161,6 +170,18 @@ impl Map {
         Ok(this)
     }
 
+    /// `get Map [ @@species ]`
+    ///
+    /// get accessor for the @@species property of Map
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-map-@@species
+    fn species_getter(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
+        Ok(this.clone())
+    }
+
     /// `Map.prototype.entries()`
     ///
     /// Returns a new Iterator object that contains the [key, value] pairs for each element in the Map object in insertion order.
@@ -351,6 +351,13 @@ fn not_a_function() {
     ";
     assert_eq!(
         forward(&mut context, init),
-        "\"TypeError: calling a builtin Map constructor without new is forbidden\""
+        "\"TypeError: calling a builtin Map constructor without new is forbidden at line 3, col 23\""
     );
 }
+
+#[test]
+fn species() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "Map[Symbol.species] === Map"), "true");
+}
@@ -1,13 +1,20 @@
 //! Builtins live here, such as Object, String, Math, etc.
 
 pub mod array;
+pub mod array_buffer;
+pub mod async_function;
 pub mod bigint;
 pub mod boolean;
 #[cfg(feature = "console")]
 pub mod console;
+pub mod data_view;
 pub mod date;
 pub mod error;
+pub mod escape;
+pub mod eval;
+pub mod finalization_registry;
 pub mod function;
+pub mod generator;
 pub mod global_this;
 pub mod infinity;
 pub mod iterable;
@@ -17,20 +24,36 @@ pub mod math;
 pub mod nan;
 pub mod number;
 pub mod object;
+pub mod promise;
+pub mod proxy;
 pub mod reflect;
 pub mod regexp;
 pub mod set;
 pub mod string;
 pub mod symbol;
+pub mod typed_array;
 pub mod undefined;
+pub mod uri;
+pub mod weak_map;
+pub mod weak_ref;
+pub mod weak_set;
 
 pub(crate) use self::{
     array::{array_iterator::ArrayIterator, Array},
+    array_buffer::ArrayBuffer,
     bigint::BigInt,
     boolean::Boolean,
+    data_view::DataView,
     date::Date,
-    error::{Error, EvalError, RangeError, ReferenceError, SyntaxError, TypeError, UriError},
+    error::{
+        AggregateError, Error, EvalError, RangeError, ReferenceError, SyntaxError, TypeError,
+        UriError,
+    },
+    escape::{Escape, Unescape},
+    eval::Eval,
+    finalization_registry::FinalizationRegistry,
     function::BuiltInFunctionObject,
+    generator::Generator,
     global_this::GlobalThis,
     infinity::Infinity,
     json::Json,
@@ -41,13 +64,23 @@ pub(crate) use self::{
     number::Number,
     object::for_in_iterator::ForInIterator,
     object::Object as BuiltInObjectObject,
+    promise::Promise,
+    proxy::Proxy,
     reflect::Reflect,
     regexp::RegExp,
     set::set_iterator::SetIterator,
     set::Set,
     string::String,
     symbol::Symbol,
+    typed_array::{
+        BigInt64Array, BigUint64Array, Float32Array, Float64Array, Int16Array, Int32Array,
+        Int8Array, TypedArray, Uint16Array, Uint32Array, Uint8Array, Uint8ClampedArray,
+    },
     undefined::Undefined,
+    uri::{DecodeUri, DecodeUriComponent, EncodeUri, EncodeUriComponent},
+    weak_map::WeakMap,
+    weak_ref::WeakRef,
+    weak_set::WeakSet,
 };
 use crate::{
     property::{Attribute, DataDescriptor},
@@ -71,20 +104,45 @@ pub fn init(context: &mut Context) {
         Infinity::init,
         NaN::init,
         GlobalThis::init,
+        Eval::init,
+        EncodeUri::init,
+        EncodeUriComponent::init,
+        DecodeUri::init,
+        DecodeUriComponent::init,
+        Escape::init,
+        Unescape::init,
         BuiltInFunctionObject::init,
         BuiltInObjectObject::init,
         Math::init,
         Json::init,
         Array::init,
+        ArrayBuffer::init,
         BigInt::init,
         Boolean::init,
+        DataView::init,
         Date::init,
         Map::init,
         Number::init,
+        Promise::init,
         Set::init,
         String::init,
         RegExp::init,
         Symbol::init,
+        Int8Array::init,
+        Uint8Array::init,
+        Uint8ClampedArray::init,
+        Int16Array::init,
+        Uint16Array::init,
+        Int32Array::init,
+        Uint32Array::init,
+        Float32Array::init,
+        Float64Array::init,
+        BigInt64Array::init,
+        BigUint64Array::init,
+        WeakMap::init,
+        WeakSet::init,
+        WeakRef::init,
+        FinalizationRegistry::init,
         Error::init,
         RangeError::init,
         ReferenceError::init,
@@ -92,6 +150,8 @@ pub fn init(context: &mut Context) {
         SyntaxError::init,
         EvalError::init,
         UriError::init,
+        AggregateError::init,
+        Proxy::init,
         Reflect::init,
         #[cfg(feature = "console")]
         console::Console::init,
@@ -104,4 +164,7 @@ pub fn init(context: &mut Context) {
         let property = DataDescriptor::new(value, attribute);
         global_object.borrow_mut().insert(name, property);
     }
+
+    // `%TypedArray%` is not a global, and shares its `toString` with `Array.prototype`.
+    TypedArray::init(context);
 }
@@ -70,7 +70,7 @@ impl ForInIterator {
                 let mut object = iterator.object.to_object(context)?;
                 loop {
                     if !iterator.object_was_visited {
-                        let keys = object.own_property_keys();
+                        let keys = object.own_property_keys(context)?;
                         for k in keys {
                             match k {
                                 PropertyKey::String(ref k) => {
@@ -87,7 +87,7 @@ impl ForInIterator {
                     while let Some(r) = iterator.remaining_keys.pop_front() {
                         if !iterator.visited_keys.contains(&r) {
                             if let Some(desc) =
-                                object.get_own_property(&PropertyKey::from(r.clone()))
+                                object.get_own_property(&PropertyKey::from(r.clone()), context)?
                             {
                                 iterator.visited_keys.insert(r.clone());
                                 if desc.enumerable() {
@@ -14,13 +14,15 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object
 
 use crate::{
-    builtins::BuiltIn,
+    builtins::{iterable::get_iterator, Array, BuiltIn},
     object::{
-        ConstructorBuilder, Object as BuiltinObject, ObjectData, ObjectInitializer, PROTOTYPE,
+        ConstructorBuilder, FunctionBuilder, IntegrityLevel, Object as BuiltinObject, ObjectData,
+        ObjectInitializer, PropertyNameKind, PROTOTYPE,
     },
     property::Attribute,
     property::DataDescriptor,
     property::PropertyDescriptor,
+    property::PropertyKey,
     symbol::WellKnownSymbols,
     value::{same_value, Type, Value},
     BoaProfiler, Context, Result,
@@ -44,6 +46,18 @@ impl BuiltIn for Object {
     fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
+        let get_proto = FunctionBuilder::new(context, Self::get_proto)
+            .name("get __proto__")
+            .constructable(false)
+            .callable(true)
+            .build();
+        let set_proto = FunctionBuilder::new(context, Self::set_proto)
+            .name("set __proto__")
+            .length(1)
+            .constructable(false)
+            .callable(true)
+            .build();
+
         let object = ConstructorBuilder::with_standard_object(
             context,
             Self::constructor,
@@ -55,7 +69,15 @@ impl BuiltIn for Object {
         .method(Self::has_own_property, "hasOwnProperty", 0)
         .method(Self::property_is_enumerable, "propertyIsEnumerable", 0)
         .method(Self::to_string, "toString", 0)
+        .method(Self::to_locale_string, "toLocaleString", 0)
+        .method(Self::value_of, "valueOf", 0)
         .method(Self::is_prototype_of, "isPrototypeOf", 0)
+        .accessor(
+            "__proto__",
+            Some(get_proto),
+            Some(set_proto),
+            Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+        )
         .static_method(Self::create, "create", 2)
         .static_method(Self::set_prototype_of, "setPrototypeOf", 2)
         .static_method(Self::get_prototype_of, "getPrototypeOf", 1)
@@ -73,6 +95,18 @@ impl BuiltIn for Object {
             "getOwnPropertyDescriptors",
             1,
         )
+        .static_method(Self::get_own_property_names, "getOwnPropertyNames", 1)
+        .static_method(Self::get_own_property_symbols, "getOwnPropertySymbols", 1)
+        .static_method(Self::keys, "keys", 1)
+        .static_method(Self::values, "values", 1)
+        .static_method(Self::entries, "entries", 1)
+        .static_method(Self::from_entries, "fromEntries", 1)
+        .static_method(Self::freeze, "freeze", 1)
+        .static_method(Self::is_frozen, "isFrozen", 1)
+        .static_method(Self::seal, "seal", 1)
+        .static_method(Self::is_sealed, "isSealed", 1)
+        .static_method(Self::prevent_extensions, "preventExtensions", 1)
+        .static_method(Self::is_extensible, "isExtensible", 1)
         .build();
 
         (Self::NAME, object.into(), Self::attribute())
@@ -165,7 +199,7 @@ impl Object {
         if let Some(key) = args.get(1) {
             let key = key.to_property_key(context)?;
 
-            if let Some(desc) = object.get_own_property(&key) {
+            if let Some(desc) = object.get_own_property(&key, context)? {
                 return Ok(Self::from_property_descriptor(desc, context));
             }
         }
@@ -194,15 +228,9 @@ impl Object {
             .to_object(context)?;
         let descriptors = context.construct_object();
 
-        for key in object.borrow().keys() {
-            let descriptor = {
-                let desc = object
-                    .get_own_property(&key)
-                    .expect("Expected property to be on object.");
-                Self::from_property_descriptor(desc, context)
-            };
-
-            if !descriptor.is_undefined() {
+        for key in object.own_property_keys(context)? {
+            if let Some(desc) = object.get_own_property(&key, context)? {
+                let descriptor = Self::from_property_descriptor(desc, context);
                 descriptors.borrow_mut().insert(
                     key,
                     PropertyDescriptor::from(DataDescriptor::new(descriptor, Attribute::all())),
@@ -218,7 +246,10 @@ impl Object {
     /// [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-frompropertydescriptor
-    fn from_property_descriptor(desc: PropertyDescriptor, context: &mut Context) -> Value {
+    pub(crate) fn from_property_descriptor(
+        desc: PropertyDescriptor,
+        context: &mut Context,
+    ) -> Value {
         let mut descriptor = ObjectInitializer::new(context);
 
         if let PropertyDescriptor::Data(data_desc) = &desc {
@@ -234,14 +265,11 @@ impl Object {
             }
         }
 
-        let writable = if let PropertyDescriptor::Data(data_desc) = &desc {
-            data_desc.writable()
-        } else {
-            false
-        };
+        if let PropertyDescriptor::Data(data_desc) = &desc {
+            descriptor.property("writable", data_desc.writable(), Attribute::all());
+        }
 
         descriptor
-            .property("writable", Value::from(writable), Attribute::all())
             .property(
                 "enumerable",
                 Value::from(desc.enumerable()),
@@ -276,7 +304,7 @@ impl Object {
         let obj = args[0].clone().to_object(ctx)?;
 
         // 2. Return ? obj.[[GetPrototypeOf]]().
-        Ok(obj.prototype_instance())
+        obj.get_prototype_of(ctx)
     }
 
     /// Set the `prototype` of an object.
@@ -318,7 +346,7 @@ impl Object {
         let status = obj
             .as_object()
             .expect("obj was not an object")
-            .set_prototype_of(proto);
+            .set_prototype_of(proto, ctx)?;
 
         // 5. If status is false, throw a TypeError exception.
         if !status {
@@ -417,13 +445,15 @@ impl Object {
             Ok("[object Null]".into())
         } else {
             let o = this.to_object(context)?;
-            let builtin_tag = {
+            let builtin_tag = if this.is_array(context)? {
+                "Array"
+            } else {
                 let o = o.borrow();
                 match &o.data {
-                    ObjectData::Array => "Array",
-                    // TODO: Arguments Exotic Objects are currently not supported
-                    ObjectData::Function(_) => "Function",
-                    ObjectData::Error => "Error",
+                    ObjectData::Arguments(_) => "Arguments",
+                    ObjectData::Function(_) | ObjectData::BoundFunction(_) => "Function",
+                    ObjectData::Proxy(ref proxy) if proxy.is_callable() => "Function",
+                    ObjectData::Error(_) => "Error",
                     ObjectData::Boolean(_) => "Boolean",
                     ObjectData::Number(_) => "Number",
                     ObjectData::String(_) => "String",
@@ -463,7 +493,7 @@ impl Object {
             .to_property_key(context)?;
         let object = this.to_object(context)?;
 
-        Ok(object.has_own_property(key).into())
+        Ok(object.get_own_property(&key, context)?.is_some().into())
     }
 
     /// `Object.prototype.propertyIsEnumerable( property )`
@@ -488,7 +518,7 @@ impl Object {
         };
 
         let key = key.to_property_key(context)?;
-        let own_property = this.to_object(context)?.get_own_property(&key);
+        let own_property = this.to_object(context)?.get_own_property(&key, context)?;
 
         Ok(own_property.map_or(Value::from(false), |own_prop| {
             Value::from(own_prop.enumerable())
@@ -520,9 +550,9 @@ impl Object {
         for source in &args[1..] {
             if !source.is_null_or_undefined() {
                 let from = source.to_object(context).unwrap();
-                let keys = from.own_property_keys();
+                let keys = from.own_property_keys(context)?;
                 for key in keys {
-                    if let Some(desc) = from.get_own_property(&key) {
+                    if let Some(desc) = from.get_own_property(&key, context)? {
                         if desc.enumerable() {
                             let property = from.get(&key, from.clone().into(), context)?;
                             to.set(key, property, to.clone().into(), context)?;
@@ -534,4 +564,370 @@ impl Object {
 
         Ok(to.into())
     }
+
+    /// `Object.prototype.toLocaleString()`
+    ///
+    /// This method returns the result of calling `toString()` on the object, for objects
+    /// overriding it with a locale-specific version.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.prototype.tolocalestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/toLocaleString
+    pub fn to_locale_string(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let to_string = this.get_field("toString", context)?;
+        context.call(&to_string, this, &[])
+    }
+
+    /// `Object.prototype.valueOf()`
+    ///
+    /// This method returns the `this` value converted to an object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.prototype.valueof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/valueOf
+    pub fn value_of(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        Ok(this.to_object(context)?.into())
+    }
+
+    /// `get Object.prototype.__proto__`
+    ///
+    /// Returns the prototype of the object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-object.prototype.__proto__
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/proto
+    pub fn get_proto(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        this.to_object(context)?.get_prototype_of(context)
+    }
+
+    /// `set Object.prototype.__proto__`
+    ///
+    /// Sets the prototype of the object, if the new prototype is an object or `null`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set-object.prototype.__proto__
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/proto
+    pub fn set_proto(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        this.require_object_coercible(context)?;
+
+        let proto = args.get(0).cloned().unwrap_or_default();
+        if !matches!(proto.get_type(), Type::Object | Type::Null) {
+            return Ok(Value::undefined());
+        }
+
+        if let Some(mut object) = this.as_object() {
+            if !object.set_prototype_of(proto, context)? {
+                return context.throw_type_error("can't set prototype of this object");
+            }
+        }
+
+        Ok(Value::undefined())
+    }
+
+    /// `Object.getOwnPropertyNames( object )`
+    ///
+    /// Returns an array of all own string-keyed properties of the object, enumerable or not.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.getownpropertynames
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/getOwnPropertyNames
+    pub fn get_own_property_names(
+        _: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        Self::get_own_property_keys(args.get(0), false, context)
+    }
+
+    /// `Object.getOwnPropertySymbols( object )`
+    ///
+    /// Returns an array of all own symbol-keyed properties of the object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.getownpropertysymbols
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/getOwnPropertySymbols
+    pub fn get_own_property_symbols(
+        _: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        Self::get_own_property_keys(args.get(0), true, context)
+    }
+
+    /// The abstract operation `GetOwnPropertyKeys`.
+    ///
+    /// [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getownpropertykeys
+    fn get_own_property_keys(
+        object: Option<&Value>,
+        symbols: bool,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let object = object.cloned().unwrap_or_default().to_object(context)?;
+        let keys = object
+            .own_property_keys(context)?
+            .into_iter()
+            .filter(|key| matches!(key, PropertyKey::Symbol(_)) == symbols)
+            .map(|key| match key {
+                PropertyKey::Symbol(ref symbol) => Value::from(symbol.clone()),
+                ref key => Value::from(key.to_string()),
+            });
+
+        Ok(Array::create_array_from_list(keys, context))
+    }
+
+    /// `Object.keys( object )`
+    ///
+    /// Returns an array of the own enumerable string-keyed property names of the object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.keys
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/keys
+    pub fn keys(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let object = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_object(context)?;
+        let keys = object.enumerable_own_property_names(PropertyNameKind::Key, context)?;
+
+        Ok(Array::create_array_from_list(keys, context))
+    }
+
+    /// `Object.values( object )`
+    ///
+    /// Returns an array of the own enumerable string-keyed property values of the object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.values
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/values
+    pub fn values(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let object = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_object(context)?;
+        let values = object.enumerable_own_property_names(PropertyNameKind::Value, context)?;
+
+        Ok(Array::create_array_from_list(values, context))
+    }
+
+    /// `Object.entries( object )`
+    ///
+    /// Returns an array of the own enumerable string-keyed `[key, value]` pairs of the object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.entries
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/entries
+    pub fn entries(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let object = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_object(context)?;
+        let entries =
+            object.enumerable_own_property_names(PropertyNameKind::KeyAndValue, context)?;
+
+        Ok(Array::create_array_from_list(entries, context))
+    }
+
+    /// `Object.fromEntries( iterable )`
+    ///
+    /// Creates an object from an iterable of `[key, value]` pairs.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.fromentries
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/fromEntries
+    pub fn from_entries(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let iterable = args.get(0).cloned().unwrap_or_default();
+        iterable.require_object_coercible(context)?;
+
+        let mut object = context.construct_object();
+        let iterator = get_iterator(context, iterable)?;
+        loop {
+            let next = iterator.next(context)?;
+            if next.is_done() {
+                break;
+            }
+            let entry = next.value();
+
+            let result = if entry.is_object() {
+                entry
+                    .get_field(0, context)
+                    .and_then(|key| key.to_property_key(context))
+                    .and_then(|key| {
+                        let value = entry.get_field(1, context)?;
+                        object.define_property_or_throw(
+                            key,
+                            DataDescriptor::new(value, Attribute::all()),
+                            context,
+                        )
+                    })
+            } else {
+                Err(context.construct_type_error(format!(
+                    "Iterator value {} is not an entry object",
+                    entry.display()
+                )))
+            };
+
+            if let Err(error) = result {
+                return iterator.close(Err(error), context);
+            }
+        }
+
+        Ok(object.into())
+    }
+
+    /// `Object.freeze( object )`
+    ///
+    /// Prevents extensions of the object, and makes its properties non-configurable and read-only.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.freeze
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/freeze
+    pub fn freeze(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::set_integrity_level(args.get(0), IntegrityLevel::Frozen, context)
+    }
+
+    /// `Object.isFrozen( object )`
+    ///
+    /// Checks if the object is frozen.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.isfrozen
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/isFrozen
+    pub fn is_frozen(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::test_integrity_level(args.get(0), IntegrityLevel::Frozen, context)
+    }
+
+    /// `Object.seal( object )`
+    ///
+    /// Prevents extensions of the object, and makes its properties non-configurable.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.seal
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/seal
+    pub fn seal(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::set_integrity_level(args.get(0), IntegrityLevel::Sealed, context)
+    }
+
+    /// `Object.isSealed( object )`
+    ///
+    /// Checks if the object is sealed.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.issealed
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/isSealed
+    pub fn is_sealed(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::test_integrity_level(args.get(0), IntegrityLevel::Sealed, context)
+    }
+
+    /// Seals or freezes the argument if it is an object, and returns it.
+    fn set_integrity_level(
+        object: Option<&Value>,
+        level: IntegrityLevel,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let object = object.cloned().unwrap_or_default();
+        if let Some(mut obj) = object.as_object() {
+            if !obj.set_integrity_level(level, context)? {
+                return context.throw_type_error("cannot prevent extensions of this object");
+            }
+        }
+
+        Ok(object)
+    }
+
+    /// Checks if the argument is sealed or frozen. Primitives are always frozen.
+    fn test_integrity_level(
+        object: Option<&Value>,
+        level: IntegrityLevel,
+        context: &mut Context,
+    ) -> Result<Value> {
+        match object.and_then(Value::as_object) {
+            Some(object) => Ok(object.test_integrity_level(level, context)?.into()),
+            None => Ok(true.into()),
+        }
+    }
+
+    /// `Object.preventExtensions( object )`
+    ///
+    /// Prevents new properties from being added to the object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.preventextensions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/preventExtensions
+    pub fn prevent_extensions(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let object = args.get(0).cloned().unwrap_or_default();
+        if let Some(mut obj) = object.as_object() {
+            if !obj.prevent_extensions(context)? {
+                return context.throw_type_error("cannot prevent extensions of this object");
+            }
+        }
+
+        Ok(object)
+    }
+
+    /// `Object.isExtensible( object )`
+    ///
+    /// Checks if new properties can be added to the object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.isextensible
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/isExtensible
+    pub fn is_extensible(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        match args.get(0).and_then(Value::as_object) {
+            Some(object) => Ok(object.is_extensible(context)?.into()),
+            None => Ok(false.into()),
+        }
+    }
 }
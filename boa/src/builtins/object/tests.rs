@@ -30,7 +30,7 @@ fn object_create_with_undefined() {
     let result = forward(&mut context, init);
     assert_eq!(
         result,
-        "\"TypeError: Object prototype may only be an Object or null: undefined\""
+        "\"TypeError: Object prototype may only be an Object or null: undefined at line 3, col 25\""
     );
 }
 
@@ -49,7 +49,7 @@ fn object_create_with_number() {
     let result = forward(&mut context, init);
     assert_eq!(
         result,
-        "\"TypeError: Object prototype may only be an Object or null: 5\""
+        "\"TypeError: Object prototype may only be an Object or null: 5 at line 3, col 25\""
     );
 }
 
@@ -289,3 +289,273 @@ fn object_is_prototype_of() {
 
     assert_eq!(context.eval(init).unwrap(), Value::boolean(true));
 }
+
+#[test]
+fn object_keys_values_entries() {
+    let mut context = Context::new();
+
+    let init = r#"
+        const obj = Object.create({ inherited: 0 }, {
+            hidden: { value: 1, enumerable: false },
+        });
+        obj.b = 2;
+        obj[1] = 3;
+        obj[Symbol.iterator] = 4;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "Object.keys(obj).join()"), "\"1,b\"");
+    assert_eq!(
+        forward(&mut context, "Object.values(obj).join()"),
+        "\"3,2\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.entries(obj).join(';')"),
+        "\"1,3;b,2\""
+    );
+    assert_eq!(
+        forward(&mut context, "typeof Object.keys({0: 1})[0]"),
+        "\"string\""
+    );
+    assert_eq!(
+        forward(&mut context, "typeof Object.entries({0: 1})[0][0]"),
+        "\"string\""
+    );
+    assert_eq!(
+        forward(&mut context, "typeof Object.getOwnPropertyNames([1])[0]"),
+        "\"string\""
+    );
+    assert_eq!(forward(&mut context, "Object.keys(1).length"), "0");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Object.keys(null) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn object_from_entries() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "const o = Object.fromEntries([['a', 1], ['b', 2]]); o.a + o.b"
+        ),
+        "3"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.fromEntries(new Map([['x', 'y']])).x"),
+        "\"y\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Object.fromEntries([1]) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn object_get_own_property_names_and_symbols() {
+    let mut context = Context::new();
+
+    let init = r#"
+        const sym = Symbol("s");
+        const obj = Object.defineProperty({ a: 1 }, "hidden", { value: 2 });
+        obj[sym] = 3;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "Object.getOwnPropertyNames(obj).length"),
+        "2"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyNames(obj).indexOf('hidden') !== -1"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.getOwnPropertySymbols(obj)[0] === sym"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.getOwnPropertySymbols(obj).length"),
+        "1"
+    );
+}
+
+#[test]
+fn object_freeze() {
+    let mut context = Context::new();
+
+    let init = r#"
+        const obj = { a: 1 };
+        const result = Object.freeze(obj);
+        obj.a = 2;
+        obj.b = 3;
+        delete obj.a;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "result === obj"), "true");
+    assert_eq!(forward(&mut context, "obj.a"), "1");
+    assert_eq!(forward(&mut context, "obj.b"), "undefined");
+    assert_eq!(forward(&mut context, "Object.isFrozen(obj)"), "true");
+    assert_eq!(forward(&mut context, "Object.isSealed(obj)"), "true");
+    assert_eq!(forward(&mut context, "Object.isExtensible(obj)"), "false");
+    assert_eq!(forward(&mut context, "Object.isFrozen({})"), "false");
+    assert_eq!(forward(&mut context, "Object.isFrozen(1)"), "true");
+    assert_eq!(forward(&mut context, "Object.freeze(1)"), "1");
+}
+
+#[test]
+fn object_seal() {
+    let mut context = Context::new();
+
+    let init = r#"
+        const obj = { a: 1 };
+        Object.seal(obj);
+        obj.a = 2;
+        obj.b = 3;
+        delete obj.a;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "obj.a"), "2");
+    assert_eq!(forward(&mut context, "obj.b"), "undefined");
+    assert_eq!(forward(&mut context, "Object.isSealed(obj)"), "true");
+    assert_eq!(forward(&mut context, "Object.isFrozen(obj)"), "false");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.isSealed(Object.preventExtensions({}))"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn object_prevent_extensions() {
+    let mut context = Context::new();
+
+    let init = r#"
+        const obj = { a: 1 };
+        Object.preventExtensions(obj);
+        obj.b = 2;
+        obj.a = 3;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "obj.b"), "undefined");
+    assert_eq!(forward(&mut context, "obj.a"), "3");
+    assert_eq!(forward(&mut context, "Object.isExtensible(obj)"), "false");
+    assert_eq!(forward(&mut context, "Object.isExtensible({})"), "true");
+    assert_eq!(forward(&mut context, "Object.isExtensible(1)"), "false");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Object.defineProperty(obj, 'c', { value: 1 }) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn object_value_of_and_to_locale_string() {
+    let mut context = Context::new();
+
+    let init = r#"
+        const obj = { toString() { return "custom"; } };
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "obj.valueOf() === obj"), "true");
+    assert_eq!(
+        forward(&mut context, "typeof Object.prototype.valueOf.call(1)"),
+        "\"object\""
+    );
+    assert_eq!(forward(&mut context, "obj.toLocaleString()"), "\"custom\"");
+    assert_eq!(
+        forward(&mut context, "({}).toLocaleString()"),
+        "\"[object Object]\""
+    );
+}
+
+#[test]
+fn object_proto_accessor() {
+    let mut context = Context::new();
+
+    let init = r#"
+        const proto = { a: 1 };
+        const obj = {};
+        obj.__proto__ = proto;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "obj.a"), "1");
+    assert_eq!(forward(&mut context, "obj.__proto__ === proto"), "true");
+    assert_eq!(
+        forward(&mut context, "Object.getPrototypeOf(obj) === proto"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "obj.__proto__ = 1; obj.a"), "1");
+    assert_eq!(
+        forward(&mut context, "(1).__proto__ === Number.prototype"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { proto.__proto__ = obj } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn string_exotic_own_properties() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "Object.keys('ab').join()"), "\"0,1\"");
+    assert_eq!(
+        forward(&mut context, "Object.getOwnPropertyNames('ab').join()"),
+        "\"0,1,length\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.values('ab').join()"),
+        "\"a,b\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "var desc = Object.getOwnPropertyDescriptor('ab', '1'); \
+             desc.value + desc.writable + desc.enumerable + desc.configurable"
+        ),
+        "\"bfalsetruefalse\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.getOwnPropertyDescriptor('ab', '2')"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "var s = new String('ab'); s[0] = 'x'; s[0] + delete s[0] + ('1' in s)"
+        ),
+        "\"afalsetrue\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { 'use strict'; Object.defineProperty(new String('ab'), '0', { value: 'x' }) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
@@ -0,0 +1,1405 @@
+//! This module implements the global `Promise` object.
+//!
+//! A `Promise` represents the eventual completion (or failure) of an asynchronous operation
+//! and its resulting value.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-promise-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise
+
+use crate::{
+    builtins::{
+        function::Captures,
+        iterable::{get_iterator, IteratorRecord},
+        AggregateError, Array, BuiltIn,
+    },
+    gc::{empty_trace, Finalize, Trace},
+    job::NativeJob,
+    object::{ConstructorBuilder, FunctionBuilder, GcObject, Object, ObjectData, PROTOTYPE},
+    property::{Attribute, DataDescriptor},
+    symbol::WellKnownSymbols,
+    value::same_value,
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The state of a promise.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-properties-of-promise-instances
+#[derive(Debug, Clone, Trace, Finalize)]
+pub enum PromiseState {
+    Pending,
+    Fulfilled(Value),
+    Rejected(Value),
+}
+
+/// The internal representation of a `Promise` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct Promise {
+    state: PromiseState,
+    fulfill_reactions: Vec<ReactionRecord>,
+    reject_reactions: Vec<ReactionRecord>,
+    is_handled: bool,
+}
+
+/// Whether a reaction runs when a promise is fulfilled or when it is rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Finalize)]
+enum ReactionType {
+    Fulfill,
+    Reject,
+}
+
+unsafe impl Trace for ReactionType {
+    empty_trace!();
+}
+
+/// A `PromiseReaction` record.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-promisereaction-records
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct ReactionRecord {
+    capability: Option<PromiseCapability>,
+    reaction_type: ReactionType,
+    handler: Option<GcObject>,
+}
+
+/// A `PromiseCapability` record.
+///
+/// It encapsulates a promise together with the functions that are able to resolve or reject it.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-promisecapability-records
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct PromiseCapability {
+    promise: Value,
+    resolve: Value,
+    reject: Value,
+}
+
+impl PromiseCapability {
+    /// Creates a new promise capability for the given constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromisecapability
+    pub fn new(constructor: &Value, context: &mut Context) -> Result<Self> {
+        #[derive(Debug, Default, Trace, Finalize)]
+        struct Slots {
+            resolve: Value,
+            reject: Value,
+        }
+
+        // 1. If IsConstructor(C) is false, throw a TypeError exception.
+        let constructor_object = match constructor.as_object() {
+            Some(object) if object.is_constructable() => object,
+            _ => {
+                return Err(
+                    context.construct_type_error("promise capability requires a constructor")
+                )
+            }
+        };
+
+        // 3. Let promiseCapability be the PromiseCapability Record { [[Promise]]: undefined, [[Resolve]]: undefined, [[Reject]]: undefined }.
+        // 4. Let executorClosure be a new Abstract Closure with parameters (resolve, reject) that captures promiseCapability.
+        let captures = Captures::new(Slots::default());
+        let executor = FunctionBuilder::closure(
+            context,
+            |_, args, captures, context| {
+                let mut slots = captures
+                    .downcast_mut::<Slots>()
+                    .expect("captures must be capability slots");
+                // a. If promiseCapability.[[Resolve]] is not undefined, throw a TypeError exception.
+                // b. If promiseCapability.[[Reject]] is not undefined, throw a TypeError exception.
+                if !slots.resolve.is_undefined() || !slots.reject.is_undefined() {
+                    drop(slots);
+                    return context.throw_type_error("promise capability is already initialized");
+                }
+                // c. Set promiseCapability.[[Resolve]] to resolve.
+                slots.resolve = args.get(0).cloned().unwrap_or_default();
+                // d. Set promiseCapability.[[Reject]] to reject.
+                slots.reject = args.get(1).cloned().unwrap_or_default();
+                // e. Return undefined.
+                Ok(Value::undefined())
+            },
+            captures.clone(),
+        )
+        .name("")
+        .length(2)
+        .build();
+
+        // 6. Let promise be ? Construct(C, « executor »).
+        let promise =
+            constructor_object.construct(&[executor.into()], constructor.clone(), context)?;
+
+        let slots = captures
+            .downcast_ref::<Slots>()
+            .expect("captures must be capability slots");
+        let resolve = slots.resolve.clone();
+        let reject = slots.reject.clone();
+        drop(slots);
+
+        // 7. If IsCallable(promiseCapability.[[Resolve]]) is false, throw a TypeError exception.
+        // 8. If IsCallable(promiseCapability.[[Reject]]) is false, throw a TypeError exception.
+        if !resolve.is_function() || !reject.is_function() {
+            return Err(
+                context.construct_type_error("promise capability functions must be callable")
+            );
+        }
+
+        // 9. Set promiseCapability.[[Promise]] to promise.
+        // 10. Return promiseCapability.
+        Ok(Self {
+            promise,
+            resolve,
+            reject,
+        })
+    }
+
+    /// Returns the promise of the capability.
+    #[inline]
+    pub fn promise(&self) -> &Value {
+        &self.promise
+    }
+
+    /// Returns the resolve function of the capability.
+    #[inline]
+    pub fn resolve(&self) -> &Value {
+        &self.resolve
+    }
+
+    /// Returns the reject function of the capability.
+    #[inline]
+    pub fn reject(&self) -> &Value {
+        &self.reject
+    }
+
+    /// `IfAbruptRejectPromise ( value, capability )`
+    ///
+    /// Rejects the promise of the capability with `error` and returns the promise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ifabruptrejectpromise
+    fn reject_with(&self, error: Value, context: &mut Context) -> Result<Value> {
+        // a. Perform ? Call(capability.[[Reject]], undefined, « value.[[Value]] »).
+        context.call(&self.reject, &Value::undefined(), &[error])?;
+        // b. Return capability.[[Promise]].
+        Ok(self.promise.clone())
+    }
+}
+
+/// The combinators of promises, like `Promise.all`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CombinatorKind {
+    All,
+    AllSettled,
+    Any,
+    Race,
+}
+
+/// The state shared by the element functions of a promise combinator.
+#[derive(Debug, Trace, Finalize)]
+struct CombinatorState {
+    /// The values (or errors, for `Promise.any`) of the settled elements.
+    values: Vec<Value>,
+
+    /// The number of elements that have not settled yet.
+    remaining: usize,
+
+    #[unsafe_ignore_trace]
+    kind: CombinatorKind,
+
+    capability: PromiseCapability,
+}
+
+/// The captures of the element functions of a promise combinator.
+#[derive(Debug, Trace, Finalize)]
+struct ElementCaptures {
+    state: Captures,
+    index: usize,
+    already_called: bool,
+}
+
+/// The captures shared by the resolving functions of a promise.
+#[derive(Debug, Trace, Finalize)]
+struct ResolvingFunctionsCaptures {
+    promise: GcObject,
+    already_resolved: bool,
+}
+
+impl BuiltIn for Promise {
+    const NAME: &'static str = "Promise";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let species = WellKnownSymbols::species();
+
+        let species_getter = FunctionBuilder::new(context, Self::species_getter)
+            .callable(true)
+            .constructable(false)
+            .name("get [Symbol.species]")
+            .build();
+
+        let promise_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().promise_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .static_accessor(species, Some(species_getter), None, Attribute::CONFIGURABLE)
+        .static_method(Self::all, "all", 1)
+        .static_method(Self::all_settled, "allSettled", 1)
+        .static_method(Self::any, "any", 1)
+        .static_method(Self::race, "race", 1)
+        .static_method(Self::reject, "reject", 1)
+        .static_method(Self::resolve, "resolve", 1)
+        .method(Self::then, "then", 2)
+        .method(Self::catch, "catch", 1)
+        .method(Self::finally, "finally", 1)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build();
+
+        (Self::NAME, promise_object.into(), Self::attribute())
+    }
+}
+
+impl Promise {
+    const LENGTH: usize = 1;
+
+    /// Creates a new pending promise.
+    fn new() -> Self {
+        Self {
+            state: PromiseState::Pending,
+            fulfill_reactions: Vec::new(),
+            reject_reactions: Vec::new(),
+            is_handled: false,
+        }
+    }
+
+    /// Returns the current state of the promise.
+    #[inline]
+    pub fn state(&self) -> &PromiseState {
+        &self.state
+    }
+
+    /// `Promise ( executor )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-executor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/Promise
+    pub(crate) fn constructor(
+        new_target: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error("Promise constructor cannot be invoked without 'new'");
+        }
+
+        // 2. If IsCallable(executor) is false, throw a TypeError exception.
+        let executor = args.get(0).cloned().unwrap_or_default();
+        if !executor.is_function() {
+            return context.throw_type_error("Promise executor is not a function");
+        }
+
+        // 3. Let promise be ? OrdinaryCreateFromConstructor(NewTarget, "%Promise.prototype%", « [[PromiseState]], [[PromiseResult]], [[PromiseFulfillReactions]], [[PromiseRejectReactions]], [[PromiseIsHandled]] »).
+        // 4-7. Set the internal slots of promise.
+        let promise_prototype = context.standard_objects().promise_object().prototype();
+        let prototype = new_target
+            .as_object()
+            .and_then(|obj| {
+                obj.get(&PROTOTYPE.into(), obj.clone().into(), context)
+                    .map(|o| o.as_object())
+                    .transpose()
+            })
+            .transpose()?
+            .unwrap_or(promise_prototype);
+        let promise = GcObject::new(Object::with_prototype(
+            prototype.into(),
+            ObjectData::Promise(Self::new()),
+        ));
+
+        // 8. Let resolvingFunctions be CreateResolvingFunctions(promise).
+        let (resolve, reject) = Self::create_resolving_functions(&promise, context);
+
+        // 9. Let completion be Call(executor, undefined, « resolvingFunctions.[[Resolve]], resolvingFunctions.[[Reject]] »).
+        let completion = context.call(
+            &executor,
+            &Value::undefined(),
+            &[resolve.into(), reject.clone().into()],
+        );
+
+        // 10. If completion is an abrupt completion, then
+        if let Err(error) = completion {
+            // a. Perform ? Call(resolvingFunctions.[[Reject]], undefined, « completion.[[Value]] »).
+            context.call(&reject.into(), &Value::undefined(), &[error])?;
+        }
+
+        // 11. Return promise.
+        Ok(promise.into())
+    }
+
+    /// Creates a new pending `%Promise%` together with its resolving functions.
+    pub(crate) fn new_capability(context: &mut Context) -> PromiseCapability {
+        let promise = GcObject::new(Object::with_prototype(
+            context
+                .standard_objects()
+                .promise_object()
+                .prototype()
+                .into(),
+            ObjectData::Promise(Self::new()),
+        ));
+        let (resolve, reject) = Self::create_resolving_functions(&promise, context);
+        PromiseCapability {
+            promise: promise.into(),
+            resolve: resolve.into(),
+            reject: reject.into(),
+        }
+    }
+
+    /// `CreateResolvingFunctions ( promise )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createresolvingfunctions
+    fn create_resolving_functions(
+        promise: &GcObject,
+        context: &mut Context,
+    ) -> (GcObject, GcObject) {
+        // 1. Let alreadyResolved be the Record { [[Value]]: false }.
+        let captures = Captures::new(ResolvingFunctionsCaptures {
+            promise: promise.clone(),
+            already_resolved: false,
+        });
+
+        // 2. Let stepsResolve be the algorithm steps defined in Promise Resolve Functions.
+        // 3. Let lengthResolve be the number of non-optional parameters of the function definition in Promise Resolve Functions.
+        // 4. Let resolve be ! CreateBuiltinFunction(stepsResolve, lengthResolve, "", « [[Promise]], [[AlreadyResolved]] »).
+        let resolve = FunctionBuilder::closure(
+            context,
+            |_, args, captures, context| {
+                // https://tc39.es/ecma262/#sec-promise-resolve-functions
+                let promise = {
+                    let mut captures = captures
+                        .downcast_mut::<ResolvingFunctionsCaptures>()
+                        .expect("captures must be resolving function captures");
+                    // 5. If alreadyResolved.[[Value]] is true, return undefined.
+                    if captures.already_resolved {
+                        return Ok(Value::undefined());
+                    }
+                    // 6. Set alreadyResolved.[[Value]] to true.
+                    captures.already_resolved = true;
+                    captures.promise.clone()
+                };
+
+                let resolution = args.get(0).cloned().unwrap_or_default();
+                Self::resolve_promise(&promise, resolution, context);
+
+                // 16. Return undefined.
+                Ok(Value::undefined())
+            },
+            captures.clone(),
+        )
+        .name("")
+        .length(1)
+        .build();
+
+        // 7. Let stepsReject be the algorithm steps defined in Promise Reject Functions.
+        // 8. Let lengthReject be the number of non-optional parameters of the function definition in Promise Reject Functions.
+        // 9. Let reject be ! CreateBuiltinFunction(stepsReject, lengthReject, "", « [[Promise]], [[AlreadyResolved]] »).
+        let reject = FunctionBuilder::closure(
+            context,
+            |_, args, captures, context| {
+                // https://tc39.es/ecma262/#sec-promise-reject-functions
+                let promise = {
+                    let mut captures = captures
+                        .downcast_mut::<ResolvingFunctionsCaptures>()
+                        .expect("captures must be resolving function captures");
+                    // 5. If alreadyResolved.[[Value]] is true, return undefined.
+                    if captures.already_resolved {
+                        return Ok(Value::undefined());
+                    }
+                    // 6. Set alreadyResolved.[[Value]] to true.
+                    captures.already_resolved = true;
+                    captures.promise.clone()
+                };
+
+                // 7. Return RejectPromise(promise, reason).
+                let reason = args.get(0).cloned().unwrap_or_default();
+                Self::reject_promise(&promise, reason, context);
+                Ok(Value::undefined())
+            },
+            captures,
+        )
+        .name("")
+        .length(1)
+        .build();
+
+        (resolve, reject)
+    }
+
+    /// Steps 7 to 15 of the promise resolve functions, once `alreadyResolved` has been checked.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-resolve-functions
+    fn resolve_promise(promise: &GcObject, resolution: Value, context: &mut Context) {
+        // 7. If SameValue(resolution, promise) is true, then
+        if same_value(&resolution, &promise.clone().into()) {
+            // a. Let selfResolutionError be a newly created TypeError object.
+            let self_resolution_error =
+                context.construct_type_error("a promise cannot be resolved with itself");
+            // b. Return RejectPromise(promise, selfResolutionError).
+            Self::reject_promise(promise, self_resolution_error, context);
+            return;
+        }
+
+        // 8. If Type(resolution) is not Object, then
+        let thenable = match resolution.as_object() {
+            Some(object) => object,
+            None => {
+                // a. Return FulfillPromise(promise, resolution).
+                Self::fulfill_promise(promise, resolution, context);
+                return;
+            }
+        };
+
+        // 9. Let then be Get(resolution, "then").
+        let then = match thenable.get(&"then".into(), resolution.clone(), context) {
+            Ok(then) => then,
+            // 10. If then is an abrupt completion, then
+            Err(error) => {
+                // a. Return RejectPromise(promise, then.[[Value]]).
+                Self::reject_promise(promise, error, context);
+                return;
+            }
+        };
+
+        // 11. Let thenAction be then.[[Value]].
+        // 12. If IsCallable(thenAction) is false, then
+        if !then.is_function() {
+            // a. Return FulfillPromise(promise, resolution).
+            Self::fulfill_promise(promise, resolution, context);
+            return;
+        }
+
+        // 13. Let thenJobCallback be HostMakeJobCallback(thenAction).
+        // 14. Let job be NewPromiseResolveThenableJob(promise, resolution, thenJobCallback).
+        let job = Self::new_promise_resolve_thenable_job(promise.clone(), resolution, then);
+
+        // 15. Perform HostEnqueuePromiseJob(job.[[Job]], job.[[Realm]]).
+        context.enqueue_job(job);
+    }
+
+    /// `FulfillPromise ( promise, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-fulfillpromise
+    fn fulfill_promise(promise: &GcObject, value: Value, context: &mut Context) {
+        let reactions = {
+            let mut object = promise.borrow_mut();
+            let promise = object.as_promise_mut().expect("expected a promise object");
+            // 1. Assert: The value of promise.[[PromiseState]] is pending.
+            debug_assert!(matches!(promise.state, PromiseState::Pending));
+
+            // 2. Let reactions be promise.[[PromiseFulfillReactions]].
+            let reactions = std::mem::take(&mut promise.fulfill_reactions);
+
+            // 3. Set promise.[[PromiseResult]] to value.
+            // 4. Set promise.[[PromiseFulfillReactions]] to undefined.
+            // 5. Set promise.[[PromiseRejectReactions]] to undefined.
+            // 6. Set promise.[[PromiseState]] to fulfilled.
+            promise.reject_reactions.clear();
+            promise.state = PromiseState::Fulfilled(value.clone());
+            reactions
+        };
+
+        // 7. Return TriggerPromiseReactions(reactions, value).
+        Self::trigger_promise_reactions(reactions, value, context);
+    }
+
+    /// `RejectPromise ( promise, reason )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-rejectpromise
+    fn reject_promise(promise: &GcObject, reason: Value, context: &mut Context) {
+        let reactions = {
+            let mut object = promise.borrow_mut();
+            let promise = object.as_promise_mut().expect("expected a promise object");
+            // 1. Assert: The value of promise.[[PromiseState]] is pending.
+            debug_assert!(matches!(promise.state, PromiseState::Pending));
+
+            // 2. Let reactions be promise.[[PromiseRejectReactions]].
+            let reactions = std::mem::take(&mut promise.reject_reactions);
+
+            // 3. Set promise.[[PromiseResult]] to reason.
+            // 4. Set promise.[[PromiseFulfillReactions]] to undefined.
+            // 5. Set promise.[[PromiseRejectReactions]] to undefined.
+            // 6. Set promise.[[PromiseState]] to rejected.
+            promise.fulfill_reactions.clear();
+            promise.state = PromiseState::Rejected(reason.clone());
+            reactions
+        };
+
+        // 7. If promise.[[PromiseIsHandled]] is false, perform HostPromiseRejectionTracker(promise, "reject").
+        // 8. Return TriggerPromiseReactions(reactions, reason).
+        Self::trigger_promise_reactions(reactions, reason, context);
+    }
+
+    /// `TriggerPromiseReactions ( reactions, argument )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-triggerpromisereactions
+    fn trigger_promise_reactions(
+        reactions: Vec<ReactionRecord>,
+        argument: Value,
+        context: &mut Context,
+    ) {
+        // 1. For each element reaction of reactions, do
+        for reaction in reactions {
+            // a. Let job be NewPromiseReactionJob(reaction, argument).
+            let job = Self::new_promise_reaction_job(reaction, argument.clone());
+            // b. Perform HostEnqueuePromiseJob(job.[[Job]], job.[[Realm]]).
+            context.enqueue_job(job);
+        }
+    }
+
+    /// `NewPromiseReactionJob ( reaction, argument )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromisereactionjob
+    fn new_promise_reaction_job(reaction: ReactionRecord, argument: Value) -> NativeJob {
+        NativeJob::new(move |context| {
+            // a. Let promiseCapability be reaction.[[Capability]].
+            // b. Let type be reaction.[[Type]].
+            // c. Let handler be reaction.[[Handler]].
+            let handler_result = match &reaction.handler {
+                // d. If handler is empty, then
+                None => match reaction.reaction_type {
+                    // i. If type is Fulfill, let handlerResult be NormalCompletion(argument).
+                    ReactionType::Fulfill => Ok(argument),
+                    // ii. Else, let handlerResult be ThrowCompletion(argument).
+                    ReactionType::Reject => Err(argument),
+                },
+                // e. Else, let handlerResult be HostCallJobCallback(handler, undefined, « argument »).
+                Some(handler) => handler.call(&Value::undefined(), &[argument], context),
+            };
+
+            match &reaction.capability {
+                // f. If promiseCapability is undefined, then
+                None => {
+                    // i. Assert: handlerResult is not an abrupt completion.
+                    // ii. Return NormalCompletion(empty).
+                    handler_result.map(|_| Value::undefined())
+                }
+                // g. Assert: promiseCapability is a PromiseCapability Record.
+                Some(capability) => match handler_result {
+                    // h. If handlerResult is an abrupt completion, then
+                    // i. Return ? Call(promiseCapability.[[Reject]], undefined, « handlerResult.[[Value]] »).
+                    Err(error) => context.call(&capability.reject, &Value::undefined(), &[error]),
+                    // i. Else,
+                    // i. Return ? Call(promiseCapability.[[Resolve]], undefined, « handlerResult.[[Value]] »).
+                    Ok(value) => context.call(&capability.resolve, &Value::undefined(), &[value]),
+                },
+            }
+        })
+    }
+
+    /// `NewPromiseResolveThenableJob ( promiseToResolve, thenable, then )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromiseresolvethenablejob
+    fn new_promise_resolve_thenable_job(
+        promise_to_resolve: GcObject,
+        thenable: Value,
+        then: Value,
+    ) -> NativeJob {
+        NativeJob::new(move |context| {
+            // a. Let resolvingFunctions be CreateResolvingFunctions(promiseToResolve).
+            let (resolve, reject) = Self::create_resolving_functions(&promise_to_resolve, context);
+
+            // b. Let thenCallResult be HostCallJobCallback(then, thenable, « resolvingFunctions.[[Resolve]], resolvingFunctions.[[Reject]] »).
+            let then_call_result =
+                context.call(&then, &thenable, &[resolve.into(), reject.clone().into()]);
+
+            // c. If thenCallResult is an abrupt completion, then
+            match then_call_result {
+                // i. Return ? Call(resolvingFunctions.[[Reject]], undefined, « thenCallResult.[[Value]] »).
+                Err(error) => context.call(&reject.into(), &Value::undefined(), &[error]),
+                // d. Return Completion(thenCallResult).
+                Ok(value) => Ok(value),
+            }
+        })
+    }
+
+    /// `PromiseResolve ( C, x )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-resolve
+    pub(crate) fn promise_resolve(
+        constructor: &Value,
+        value: Value,
+        context: &mut Context,
+    ) -> Result<Value> {
+        // 1. If IsPromise(x) is true, then
+        if let Some(object) = value.as_object() {
+            if object.borrow().is_promise() {
+                // a. Let xConstructor be ? Get(x, "constructor").
+                let value_constructor =
+                    object.get(&"constructor".into(), value.clone(), context)?;
+                // b. If SameValue(xConstructor, C) is true, return x.
+                if same_value(&value_constructor, constructor) {
+                    return Ok(value);
+                }
+            }
+        }
+
+        // 2. Let promiseCapability be ? NewPromiseCapability(C).
+        let capability = PromiseCapability::new(constructor, context)?;
+
+        // 3. Perform ? Call(promiseCapability.[[Resolve]], undefined, « x »).
+        context.call(&capability.resolve, &Value::undefined(), &[value])?;
+
+        // 4. Return promiseCapability.[[Promise]].
+        Ok(capability.promise.clone())
+    }
+
+    /// `Promise.prototype.then ( onFulfilled, onRejected )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.then
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/then
+    pub(crate) fn then(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let promise be the this value.
+        // 2. If IsPromise(promise) is false, throw a TypeError exception.
+        let promise = match this.as_object() {
+            Some(object) if object.borrow().is_promise() => object,
+            _ => {
+                return context
+                    .throw_type_error("Promise.prototype.then called on a non-promise object")
+            }
+        };
+
+        // 3. Let C be ? SpeciesConstructor(promise, %Promise%).
+        let default_constructor = context.standard_objects().promise_object().constructor();
+        let constructor = promise.species_constructor(default_constructor.into(), context)?;
+
+        // 4. Let resultCapability be ? NewPromiseCapability(C).
+        let result_capability = PromiseCapability::new(&constructor, context)?;
+
+        // 5. Return PerformPromiseThen(promise, onFulfilled, onRejected, resultCapability).
+        let on_fulfilled = args.get(0).cloned().unwrap_or_default();
+        let on_rejected = args.get(1).cloned().unwrap_or_default();
+        Ok(Self::perform_promise_then(
+            &promise,
+            &on_fulfilled,
+            &on_rejected,
+            Some(result_capability),
+            context,
+        ))
+    }
+
+    /// `PerformPromiseThen ( promise, onFulfilled, onRejected [ , resultCapability ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromisethen
+    pub(crate) fn perform_promise_then(
+        promise: &GcObject,
+        on_fulfilled: &Value,
+        on_rejected: &Value,
+        result_capability: Option<PromiseCapability>,
+        context: &mut Context,
+    ) -> Value {
+        // 3. If IsCallable(onFulfilled) is false, then
+        //     a. Let onFulfilledJobCallback be empty.
+        // 4. Else,
+        //     a. Let onFulfilledJobCallback be HostMakeJobCallback(onFulfilled).
+        let on_fulfilled_job_callback = on_fulfilled.as_object().filter(GcObject::is_callable);
+
+        // 5. If IsCallable(onRejected) is false, then
+        //     a. Let onRejectedJobCallback be empty.
+        // 6. Else,
+        //     a. Let onRejectedJobCallback be HostMakeJobCallback(onRejected).
+        let on_rejected_job_callback = on_rejected.as_object().filter(GcObject::is_callable);
+
+        // 7. Let fulfillReaction be the PromiseReaction { [[Capability]]: resultCapability, [[Type]]: Fulfill, [[Handler]]: onFulfilledJobCallback }.
+        let fulfill_reaction = ReactionRecord {
+            capability: result_capability.clone(),
+            reaction_type: ReactionType::Fulfill,
+            handler: on_fulfilled_job_callback,
+        };
+
+        // 8. Let rejectReaction be the PromiseReaction { [[Capability]]: resultCapability, [[Type]]: Reject, [[Handler]]: onRejectedJobCallback }.
+        let reject_reaction = ReactionRecord {
+            capability: result_capability.clone(),
+            reaction_type: ReactionType::Reject,
+            handler: on_rejected_job_callback,
+        };
+
+        let job = {
+            let mut object = promise.borrow_mut();
+            let promise = object.as_promise_mut().expect("expected a promise object");
+
+            let job = match promise.state {
+                // 9. If promise.[[PromiseState]] is pending, then
+                PromiseState::Pending => {
+                    // a. Append fulfillReaction as the last element of the List that is promise.[[PromiseFulfillReactions]].
+                    promise.fulfill_reactions.push(fulfill_reaction);
+                    // b. Append rejectReaction as the last element of the List that is promise.[[PromiseRejectReactions]].
+                    promise.reject_reactions.push(reject_reaction);
+                    None
+                }
+                // 10. Else if promise.[[PromiseState]] is fulfilled, then
+                PromiseState::Fulfilled(ref value) => {
+                    // a. Let value be promise.[[PromiseResult]].
+                    // b. Let fulfillJob be NewPromiseReactionJob(fulfillReaction, value).
+                    Some(Self::new_promise_reaction_job(
+                        fulfill_reaction,
+                        value.clone(),
+                    ))
+                }
+                // 11. Else,
+                PromiseState::Rejected(ref reason) => {
+                    // a. Assert: The value of promise.[[PromiseState]] is rejected.
+                    // b. Let reason be promise.[[PromiseResult]].
+                    // c. If promise.[[PromiseIsHandled]] is false, perform HostPromiseRejectionTracker(promise, "handle").
+                    // d. Let rejectJob be NewPromiseReactionJob(rejectReaction, reason).
+                    Some(Self::new_promise_reaction_job(
+                        reject_reaction,
+                        reason.clone(),
+                    ))
+                }
+            };
+
+            // 12. Set promise.[[PromiseIsHandled]] to true.
+            promise.is_handled = true;
+            job
+        };
+
+        // 10.c / 11.e. Perform HostEnqueuePromiseJob(job.[[Job]], job.[[Realm]]).
+        if let Some(job) = job {
+            context.enqueue_job(job);
+        }
+
+        // 13. If resultCapability is undefined, then
+        //     a. Return undefined.
+        // 14. Else,
+        //     a. Return resultCapability.[[Promise]].
+        result_capability
+            .map(|capability| capability.promise.clone())
+            .unwrap_or_default()
+    }
+
+    /// `get Promise [ @@species ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-promise-@@species
+    fn species_getter(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
+        Ok(this.clone())
+    }
+
+    /// `Promise.prototype.catch ( onRejected )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.catch
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/catch
+    pub(crate) fn catch(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let promise be the this value.
+        // 2. Return ? Invoke(promise, "then", « undefined, onRejected »).
+        let on_rejected = args.get(0).cloned().unwrap_or_default();
+        let then = this.get_field("then", context)?;
+        context.call(&then, this, &[Value::undefined(), on_rejected])
+    }
+
+    /// `Promise.prototype.finally ( onFinally )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.finally
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/finally
+    pub(crate) fn finally(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        #[derive(Debug, Trace, Finalize)]
+        struct FinallyCaptures {
+            on_finally: Value,
+            constructor: Value,
+        }
+
+        // 1. Let promise be the this value.
+        // 2. If Type(promise) is not Object, throw a TypeError exception.
+        let promise = match this.as_object() {
+            Some(object) => object,
+            None => {
+                return context
+                    .throw_type_error("Promise.prototype.finally called on a non-object value")
+            }
+        };
+
+        // 3. Let C be ? SpeciesConstructor(promise, %Promise%).
+        // 4. Assert: IsConstructor(C) is true.
+        let default_constructor = context.standard_objects().promise_object().constructor();
+        let constructor = promise.species_constructor(default_constructor.into(), context)?;
+
+        let on_finally = args.get(0).cloned().unwrap_or_default();
+        let (then_finally, catch_finally) = if on_finally.is_function() {
+            // 6. Else,
+            let captures = Captures::new(FinallyCaptures {
+                on_finally,
+                constructor,
+            });
+
+            // a. Let thenFinallyClosure be a new Abstract Closure with parameters (value) that captures onFinally and C and performs the following steps when called:
+            let then_finally = FunctionBuilder::closure(
+                context,
+                |_, args, captures, context| {
+                    let (on_finally, constructor) = {
+                        let captures = captures
+                            .downcast_ref::<FinallyCaptures>()
+                            .expect("captures must be finally captures");
+                        (captures.on_finally.clone(), captures.constructor.clone())
+                    };
+                    let value = args.get(0).cloned().unwrap_or_default();
+
+                    // i. Let result be ? Call(onFinally, undefined).
+                    let result = context.call(&on_finally, &Value::undefined(), &[])?;
+                    // ii. Let promise be ? PromiseResolve(C, result).
+                    let promise = Self::promise_resolve(&constructor, result, context)?;
+                    // iii. Let returnValue be a new Abstract Closure with no parameters that captures value and performs the following steps when called:
+                    //     1. Return value.
+                    // iv. Let valueThunk be ! CreateBuiltinFunction(returnValue, 0, "", « »).
+                    let value_thunk = FunctionBuilder::closure(
+                        context,
+                        |_, _, value, _| {
+                            Ok(value
+                                .downcast_ref::<Value>()
+                                .expect("captures must be a value")
+                                .clone())
+                        },
+                        Captures::new(value),
+                    )
+                    .name("")
+                    .length(0)
+                    .build();
+                    // v. Return ? Invoke(promise, "then", « valueThunk »).
+                    let then = promise.get_field("then", context)?;
+                    context.call(&then, &promise, &[value_thunk.into()])
+                },
+                captures.clone(),
+            )
+            .name("")
+            .length(1)
+            .build();
+
+            // c. Let catchFinallyClosure be a new Abstract Closure with parameters (reason) that captures onFinally and C and performs the following steps when called:
+            let catch_finally = FunctionBuilder::closure(
+                context,
+                |_, args, captures, context| {
+                    let (on_finally, constructor) = {
+                        let captures = captures
+                            .downcast_ref::<FinallyCaptures>()
+                            .expect("captures must be finally captures");
+                        (captures.on_finally.clone(), captures.constructor.clone())
+                    };
+                    let reason = args.get(0).cloned().unwrap_or_default();
+
+                    // i. Let result be ? Call(onFinally, undefined).
+                    let result = context.call(&on_finally, &Value::undefined(), &[])?;
+                    // ii. Let promise be ? PromiseResolve(C, result).
+                    let promise = Self::promise_resolve(&constructor, result, context)?;
+                    // iii. Let throwReason be a new Abstract Closure with no parameters that captures reason and performs the following steps when called:
+                    //     1. Return ThrowCompletion(reason).
+                    // iv. Let thrower be ! CreateBuiltinFunction(throwReason, 0, "", « »).
+                    let thrower = FunctionBuilder::closure(
+                        context,
+                        |_, _, reason, _| {
+                            Err(reason
+                                .downcast_ref::<Value>()
+                                .expect("captures must be a value")
+                                .clone())
+                        },
+                        Captures::new(reason),
+                    )
+                    .name("")
+                    .length(0)
+                    .build();
+                    // v. Return ? Invoke(promise, "then", « thrower »).
+                    let then = promise.get_field("then", context)?;
+                    context.call(&then, &promise, &[thrower.into()])
+                },
+                captures,
+            )
+            .name("")
+            .length(1)
+            .build();
+
+            (then_finally.into(), catch_finally.into())
+        } else {
+            // 5. If IsCallable(onFinally) is false, then
+            //     a. Let thenFinally be onFinally.
+            //     b. Let catchFinally be onFinally.
+            (on_finally.clone(), on_finally)
+        };
+
+        // 7. Return ? Invoke(promise, "then", « thenFinally, catchFinally »).
+        let then = this.get_field("then", context)?;
+        context.call(&then, this, &[then_finally, catch_finally])
+    }
+
+    /// `Promise.resolve ( x )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.resolve
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/resolve
+    pub(crate) fn resolve(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let C be the this value.
+        // 2. If Type(C) is not Object, throw a TypeError exception.
+        if !this.is_object() {
+            return context.throw_type_error("Promise.resolve called on a non-object value");
+        }
+
+        // 3. Return ? PromiseResolve(C, x).
+        let value = args.get(0).cloned().unwrap_or_default();
+        Self::promise_resolve(this, value, context)
+    }
+
+    /// `Promise.reject ( r )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.reject
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/reject
+    pub(crate) fn reject(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let C be the this value.
+        // 2. Let promiseCapability be ? NewPromiseCapability(C).
+        let capability = PromiseCapability::new(this, context)?;
+
+        // 3. Perform ? Call(promiseCapability.[[Reject]], undefined, « r »).
+        let reason = args.get(0).cloned().unwrap_or_default();
+        context.call(&capability.reject, &Value::undefined(), &[reason])?;
+
+        // 4. Return promiseCapability.[[Promise]].
+        Ok(capability.promise.clone())
+    }
+
+    /// `Promise.all ( iterable )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.all
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/all
+    pub(crate) fn all(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::combine(this, args, CombinatorKind::All, context)
+    }
+
+    /// `Promise.allSettled ( iterable )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.allsettled
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/allSettled
+    pub(crate) fn all_settled(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        Self::combine(this, args, CombinatorKind::AllSettled, context)
+    }
+
+    /// `Promise.any ( iterable )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.any
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/any
+    pub(crate) fn any(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::combine(this, args, CombinatorKind::Any, context)
+    }
+
+    /// `Promise.race ( iterable )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.race
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/race
+    pub(crate) fn race(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::combine(this, args, CombinatorKind::Race, context)
+    }
+
+    /// The steps shared by `Promise.all`, `Promise.allSettled`, `Promise.any` and `Promise.race`.
+    fn combine(
+        this: &Value,
+        args: &[Value],
+        kind: CombinatorKind,
+        context: &mut Context,
+    ) -> Result<Value> {
+        // 1. Let C be the this value.
+        // 2. Let promiseCapability be ? NewPromiseCapability(C).
+        let capability = PromiseCapability::new(this, context)?;
+
+        // 3. Let promiseResolve be GetPromiseResolve(C).
+        // 4. IfAbruptRejectPromise(promiseResolve, promiseCapability).
+        let promise_resolve = match Self::get_promise_resolve(this, context) {
+            Ok(promise_resolve) => promise_resolve,
+            Err(error) => return capability.reject_with(error, context),
+        };
+
+        // 5. Let iteratorRecord be GetIterator(iterable).
+        // 6. IfAbruptRejectPromise(iteratorRecord, promiseCapability).
+        let iterable = args.get(0).cloned().unwrap_or_default();
+        let iterator_record = match get_iterator(context, iterable) {
+            Ok(iterator_record) => iterator_record,
+            Err(error) => return capability.reject_with(error, context),
+        };
+
+        // 7. Let result be PerformPromiseAll(iteratorRecord, C, promiseCapability, promiseResolve).
+        let mut iterator_done = false;
+        let result = Self::perform_combinator(
+            kind,
+            &iterator_record,
+            &mut iterator_done,
+            this,
+            &capability,
+            &promise_resolve,
+            context,
+        );
+
+        // 8. If result is an abrupt completion, then
+        match result {
+            Ok(promise) => Ok(promise),
+            Err(error) => {
+                // a. If iteratorRecord.[[Done]] is false, set result to IteratorClose(iteratorRecord, result).
+                let error = if iterator_done {
+                    error
+                } else {
+                    match iterator_record.close(Err(error), context) {
+                        Ok(_) => unreachable!("closing an iterator with an error must fail"),
+                        Err(error) => error,
+                    }
+                };
+                // b. IfAbruptRejectPromise(result, promiseCapability).
+                capability.reject_with(error, context)
+            }
+        }
+    }
+
+    /// `GetPromiseResolve ( promiseConstructor )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getpromiseresolve
+    fn get_promise_resolve(constructor: &Value, context: &mut Context) -> Result<Value> {
+        // 1. Assert: IsConstructor(promiseConstructor) is true.
+        // 2. Let promiseResolve be ? Get(promiseConstructor, "resolve").
+        let promise_resolve = constructor.get_field("resolve", context)?;
+
+        // 3. If IsCallable(promiseResolve) is false, throw a TypeError exception.
+        if !promise_resolve.is_function() {
+            return context.throw_type_error("Promise resolve is not a function");
+        }
+
+        // 4. Return promiseResolve.
+        Ok(promise_resolve)
+    }
+
+    /// `PerformPromiseAll`, `PerformPromiseAllSettled`, `PerformPromiseAny` and
+    /// `PerformPromiseRace`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromiseall
+    fn perform_combinator(
+        kind: CombinatorKind,
+        iterator_record: &IteratorRecord,
+        iterator_done: &mut bool,
+        constructor: &Value,
+        capability: &PromiseCapability,
+        promise_resolve: &Value,
+        context: &mut Context,
+    ) -> Result<Value> {
+        // 1. Let values be a new empty List.
+        // 2. Let remainingElementsCount be the Record { [[Value]]: 1 }.
+        let state = Captures::new(CombinatorState {
+            values: Vec::new(),
+            remaining: 1,
+            kind,
+            capability: capability.clone(),
+        });
+
+        // 3. Let index be 0.
+        let mut index = 0;
+
+        // 4. Repeat,
+        loop {
+            // a. Let next be IteratorStep(iteratorRecord).
+            // b. If next is an abrupt completion, set iteratorRecord.[[Done]] to true.
+            // c. ReturnIfAbrupt(next).
+            // e. Let nextValue be IteratorValue(next).
+            // f. If nextValue is an abrupt completion, set iteratorRecord.[[Done]] to true.
+            // g. ReturnIfAbrupt(nextValue).
+            let next = iterator_record.next(context).map_err(|error| {
+                *iterator_done = true;
+                error
+            })?;
+
+            // d. If next is false, then
+            if next.is_done() {
+                // i. Set iteratorRecord.[[Done]] to true.
+                *iterator_done = true;
+
+                if kind == CombinatorKind::Race {
+                    // ii. Return promiseCapability.[[Promise]].
+                    return Ok(capability.promise.clone());
+                }
+
+                // ii. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
+                // iii. If remainingElementsCount.[[Value]] is 0, then
+                //     1. Let valuesArray be ! CreateArrayFromList(values).
+                //     2. Perform ? Call(resultCapability.[[Resolve]], undefined, « valuesArray »).
+                Self::finish_element(&state, context)?;
+
+                // iv. Return resultCapability.[[Promise]].
+                return Ok(capability.promise.clone());
+            }
+            let next_value = next.value();
+
+            // i. Let nextPromise be ? Call(promiseResolve, constructor, « nextValue »).
+            let next_promise = context.call(promise_resolve, constructor, &[next_value])?;
+
+            let (on_fulfilled, on_rejected) = if kind == CombinatorKind::Race {
+                (capability.resolve.clone(), capability.reject.clone())
+            } else {
+                // h. Append undefined to values.
+                // l. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] + 1.
+                {
+                    let mut state = state
+                        .downcast_mut::<CombinatorState>()
+                        .expect("captures must be a combinator state");
+                    state.values.push(Value::undefined());
+                    state.remaining += 1;
+                }
+
+                // j-k. Let onFulfilled be ! CreateBuiltinFunction(steps, length, "", « [[AlreadyCalled]], [[Index]], [[Values]], [[Capability]], [[RemainingElements]] »).
+                let element = Captures::new(ElementCaptures {
+                    state: state.clone(),
+                    index,
+                    already_called: false,
+                });
+                let resolve_element = Self::element_function(element.clone(), false, context);
+                let reject_element = Self::element_function(element, true, context);
+
+                match kind {
+                    CombinatorKind::All => (resolve_element.into(), capability.reject.clone()),
+                    CombinatorKind::AllSettled => (resolve_element.into(), reject_element.into()),
+                    _ => (capability.resolve.clone(), reject_element.into()),
+                }
+            };
+
+            // m. Perform ? Invoke(nextPromise, "then", « onFulfilled, resultCapability.[[Reject]] »).
+            let then = next_promise.get_field("then", context)?;
+            context.call(&then, &next_promise, &[on_fulfilled, on_rejected])?;
+
+            // n. Set index to index + 1.
+            index += 1;
+        }
+    }
+
+    /// Creates the function that stores the result of a single element of a combinator.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.all-resolve-element-functions
+    fn element_function(element: Captures, rejected: bool, context: &mut Context) -> GcObject {
+        let function = if rejected {
+            Self::reject_element
+        } else {
+            Self::resolve_element
+        };
+        FunctionBuilder::closure(context, function, element)
+            .name("")
+            .length(1)
+            .build()
+    }
+
+    /// `Promise.all` and `Promise.allSettled` resolve element functions.
+    fn resolve_element(
+        _: &Value,
+        args: &[Value],
+        element: &Captures,
+        context: &mut Context,
+    ) -> Result<Value> {
+        Self::settle_element(args, element, false, context)
+    }
+
+    /// `Promise.allSettled` and `Promise.any` reject element functions.
+    fn reject_element(
+        _: &Value,
+        args: &[Value],
+        element: &Captures,
+        context: &mut Context,
+    ) -> Result<Value> {
+        Self::settle_element(args, element, true, context)
+    }
+
+    /// The steps shared by all the element functions of the combinators.
+    fn settle_element(
+        args: &[Value],
+        element: &Captures,
+        rejected: bool,
+        context: &mut Context,
+    ) -> Result<Value> {
+        // 1. Let F be the active function object.
+        // 2. Let alreadyCalled be F.[[AlreadyCalled]].
+        // 3. If alreadyCalled.[[Value]] is true, return undefined.
+        // 4. Set alreadyCalled.[[Value]] to true.
+        let (state, index) = {
+            let mut element = element
+                .downcast_mut::<ElementCaptures>()
+                .expect("captures must be element captures");
+            if element.already_called {
+                return Ok(Value::undefined());
+            }
+            element.already_called = true;
+            (element.state.clone(), element.index)
+        };
+
+        let kind = state
+            .downcast_ref::<CombinatorState>()
+            .expect("captures must be a combinator state")
+            .kind;
+
+        let x = args.get(0).cloned().unwrap_or_default();
+        let value = if kind == CombinatorKind::AllSettled {
+            // 9. Let obj be ! OrdinaryObjectCreate(%Object.prototype%).
+            let obj = Value::from(context.construct_object());
+            let (status, key) = if rejected {
+                ("rejected", "reason")
+            } else {
+                ("fulfilled", "value")
+            };
+            // 10. Perform ! CreateDataPropertyOrThrow(obj, "status", status).
+            obj.set_property("status", DataDescriptor::new(status, Attribute::all()));
+            // 11. Perform ! CreateDataPropertyOrThrow(obj, key, x).
+            obj.set_property(key, DataDescriptor::new(x, Attribute::all()));
+            obj
+        } else {
+            x
+        };
+
+        // 9. Set values[index] to x.
+        state
+            .downcast_mut::<CombinatorState>()
+            .expect("captures must be a combinator state")
+            .values[index] = value;
+
+        // 10. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
+        // 11. If remainingElementsCount.[[Value]] is 0, then
+        Self::finish_element(&state, context)
+    }
+
+    /// Counts down the remaining elements of a combinator, and settles its promise with the
+    /// collected values once there are no elements left.
+    fn finish_element(state: &Captures, context: &mut Context) -> Result<Value> {
+        let (values, capability, kind) = {
+            let mut state = state
+                .downcast_mut::<CombinatorState>()
+                .expect("captures must be a combinator state");
+            state.remaining -= 1;
+            if state.remaining != 0 {
+                return Ok(Value::undefined());
+            }
+            (
+                std::mem::take(&mut state.values),
+                state.capability.clone(),
+                state.kind,
+            )
+        };
+
+        if kind == CombinatorKind::Any {
+            // a. Let error be a newly created AggregateError object.
+            // b. Perform ! DefinePropertyOrThrow(error, "errors", PropertyDescriptor { [[Configurable]]: true, [[Enumerable]]: false, [[Writable]]: true, [[Value]]: ! CreateArrayFromList(errors) }).
+            let error = AggregateError::create(
+                &values,
+                "no promise in Promise.any was fulfilled",
+                context,
+            )?;
+            // c. Return ? Call(promiseCapability.[[Reject]], undefined, « error »).
+            context.call(&capability.reject, &Value::undefined(), &[error])
+        } else {
+            // a. Let valuesArray be ! CreateArrayFromList(values).
+            let array = Array::new_array(context);
+            let values_array = Array::construct_array(&array, &values, context)?;
+            // b. Return ? Call(promiseCapability.[[Resolve]], undefined, « valuesArray »).
+            context.call(&capability.resolve, &Value::undefined(), &[values_array])
+        }
+    }
+}
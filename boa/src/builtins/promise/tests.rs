@@ -0,0 +1,278 @@
+use super::PromiseState;
+use crate::{forward, job::NativeJob, Context};
+
+#[test]
+fn executor_resolves() {
+    let mut context = Context::new();
+    let init = r#"
+        var result;
+        var promise = new Promise(resolve => resolve(10));
+        promise.then(value => { result = value; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result"), "10");
+}
+
+#[test]
+fn executor_throws() {
+    let mut context = Context::new();
+    let init = r#"
+        var result;
+        new Promise(() => { throw "error"; }).then(undefined, reason => { result = reason; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result"), "\"error\"");
+}
+
+#[test]
+fn then_is_asynchronous() {
+    let mut context = Context::new();
+    let init = r#"
+        var order = [];
+        new Promise(resolve => resolve()).then(() => order.push("then"));
+        order.push("sync");
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "order.join()"), "\"sync,then\"");
+}
+
+#[test]
+fn then_chains() {
+    let mut context = Context::new();
+    let init = r#"
+        var result;
+        new Promise(resolve => resolve(1))
+            .then(value => value + 1)
+            .then(value => { throw value + 1; })
+            .then(undefined, value => { result = value; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result"), "3");
+}
+
+#[test]
+fn resolve_with_thenable() {
+    let mut context = Context::new();
+    let init = r#"
+        var result;
+        var thenable = { then(resolve) { resolve("thenable"); } };
+        new Promise(resolve => resolve(thenable)).then(value => { result = value; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result"), "\"thenable\"");
+}
+
+#[test]
+fn resolve_with_itself() {
+    let mut context = Context::new();
+    let init = r#"
+        var result;
+        var resolvePromise;
+        var promise = new Promise(resolve => { resolvePromise = resolve; });
+        resolvePromise(promise);
+        promise.then(undefined, reason => { result = reason instanceof TypeError; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result"), "true");
+}
+
+#[test]
+fn constructor_requires_new() {
+    let mut context = Context::new();
+    let result = forward(
+        &mut context,
+        "try { Promise(() => {}) } catch (e) { e.name }",
+    );
+    assert_eq!(result, "\"TypeError\"");
+}
+
+#[test]
+fn catch() {
+    let mut context = Context::new();
+    let init = r#"
+        var result;
+        Promise.reject("error").catch(reason => { result = reason; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result"), "\"error\"");
+}
+
+#[test]
+fn finally() {
+    let mut context = Context::new();
+    let init = r#"
+        var calls = 0;
+        var fulfilled;
+        var rejected;
+        Promise.resolve(1)
+            .finally(() => { calls++; return 2; })
+            .then(value => { fulfilled = value; });
+        Promise.reject("error")
+            .finally(() => { calls++; })
+            .catch(reason => { rejected = reason; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "calls"), "2");
+    assert_eq!(forward(&mut context, "fulfilled"), "1");
+    assert_eq!(forward(&mut context, "rejected"), "\"error\"");
+}
+
+#[test]
+fn finally_throws() {
+    let mut context = Context::new();
+    let init = r#"
+        var result;
+        Promise.resolve(1)
+            .finally(() => { throw "finally"; })
+            .catch(reason => { result = reason; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result"), "\"finally\"");
+}
+
+#[test]
+fn resolve() {
+    let mut context = Context::new();
+    let init = r#"
+        var promise = Promise.resolve(1);
+        var result;
+        Promise.resolve(promise).then(value => { result = value; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "Promise.resolve(promise) === promise"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "result"), "1");
+}
+
+#[test]
+fn all() {
+    let mut context = Context::new();
+    let init = r#"
+        var result;
+        Promise.all([1, Promise.resolve(2), new Promise(resolve => resolve(3))])
+            .then(values => { result = values.join(); });
+        var empty;
+        Promise.all([]).then(values => { empty = values.length; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result"), "\"1,2,3\"");
+    assert_eq!(forward(&mut context, "empty"), "0");
+}
+
+#[test]
+fn all_rejects() {
+    let mut context = Context::new();
+    let init = r#"
+        var result;
+        Promise.all([Promise.resolve(1), Promise.reject("error")])
+            .then(undefined, reason => { result = reason; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result"), "\"error\"");
+}
+
+#[test]
+fn all_not_iterable() {
+    let mut context = Context::new();
+    let init = r#"
+        var result;
+        Promise.all(1).catch(reason => { result = reason instanceof TypeError; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result"), "true");
+}
+
+#[test]
+fn all_settled() {
+    let mut context = Context::new();
+    let init = r#"
+        var result;
+        Promise.allSettled([Promise.resolve(1), Promise.reject(2)])
+            .then(results => {
+                result = results.map(r => r.status + ":" + (r.value || r.reason)).join();
+            });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "result"),
+        "\"fulfilled:1,rejected:2\""
+    );
+}
+
+#[test]
+fn any() {
+    let mut context = Context::new();
+    let init = r#"
+        var result;
+        Promise.any([Promise.reject(1), Promise.resolve(2), Promise.resolve(3)])
+            .then(value => { result = value; });
+        var error;
+        Promise.any([Promise.reject(1), Promise.reject(2)])
+            .catch(reason => { error = reason; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result"), "2");
+    assert_eq!(
+        forward(&mut context, "error instanceof AggregateError"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "error.errors.join()"), "\"1,2\"");
+}
+
+#[test]
+fn race() {
+    let mut context = Context::new();
+    let init = r#"
+        var result;
+        var pending = new Promise(() => {});
+        Promise.race([pending, Promise.resolve("first"), Promise.resolve("second")])
+            .then(value => { result = value; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result"), "\"first\"");
+}
+
+#[test]
+fn jobs_run_by_host() {
+    let mut context = Context::new();
+    context
+        .eval("var result; Promise.resolve(1).then(value => { result = value; });")
+        .unwrap();
+    assert!(context.eval("result").unwrap().is_undefined());
+    context.run_jobs().unwrap();
+    assert_eq!(context.eval("result").unwrap().as_number(), Some(1.0));
+}
+
+#[test]
+fn job_errors_returned_to_host() {
+    let mut context = Context::new();
+    context.eval("var result;").unwrap();
+    context.enqueue_job(NativeJob::new(|context| {
+        context.throw_type_error("job failed")
+    }));
+    context.enqueue_job(NativeJob::new(|context| context.eval("result = 1")));
+
+    let error = context.run_jobs().unwrap_err();
+    assert_eq!(
+        error.to_string(&mut context).unwrap().as_str(),
+        "TypeError: job failed"
+    );
+    assert!(context.eval("result").unwrap().is_undefined());
+
+    context.run_jobs().unwrap();
+    assert_eq!(context.eval("result").unwrap().as_number(), Some(1.0));
+}
+
+#[test]
+fn settled_state() {
+    let mut context = Context::new();
+    let promise = context.eval("Promise.resolve(42)").unwrap();
+    let object = promise.as_object().unwrap();
+    let state = object.borrow().as_promise().unwrap().state().clone();
+    match &state {
+        PromiseState::Fulfilled(value) => assert_eq!(value.as_number(), Some(42.0)),
+        state => panic!("unexpected promise state {:?}", state),
+    }
+}
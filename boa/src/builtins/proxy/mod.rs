@@ -0,0 +1,802 @@
+//! This module implements the global `Proxy` object.
+//!
+//! The `Proxy` object enables you to create a proxy for another object,
+//! which can intercept and redefine fundamental operations for that object.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-proxy-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy
+
+use crate::{
+    builtins::{
+        function::{make_builtin_fn, Captures},
+        Array, BuiltIn, BuiltInObjectObject,
+    },
+    gc::{Finalize, Trace},
+    object::{FunctionBuilder, GcObject, Object, ObjectData},
+    property::{Attribute, PropertyDescriptor, PropertyKey},
+    value::{same_value, Type},
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The internal slots of a proxy object.
+///
+/// The target and the handler are `None` once the proxy has been revoked.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct Proxy {
+    target: Option<GcObject>,
+    handler: Option<GcObject>,
+    callable: bool,
+    constructable: bool,
+}
+
+impl BuiltIn for Proxy {
+    const NAME: &'static str = "Proxy";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let proxy = FunctionBuilder::new(context, Self::constructor)
+            .name(Self::NAME)
+            .length(Self::LENGTH)
+            .constructable(true)
+            .build();
+        make_builtin_fn(Self::revocable, "revocable", &proxy, 2, context);
+
+        (Self::NAME, proxy.into(), Self::attribute())
+    }
+}
+
+impl Proxy {
+    pub(crate) const LENGTH: usize = 2;
+
+    /// `Proxy ( target, handler )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-target-handler
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy/Proxy
+    pub(crate) fn constructor(
+        new_target: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        if new_target.is_undefined() {
+            return context
+                .throw_type_error("calling a builtin Proxy constructor without new is forbidden");
+        }
+
+        let target = args.get(0).cloned().unwrap_or_default();
+        let handler = args.get(1).cloned().unwrap_or_default();
+        Self::create(&target, &handler, context).map(Value::from)
+    }
+
+    /// `Proxy.revocable ( target, handler )`
+    ///
+    /// Creates a proxy along with a function that revokes it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy.revocable
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy/revocable
+    pub(crate) fn revocable(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let target = args.get(0).cloned().unwrap_or_default();
+        let handler = args.get(1).cloned().unwrap_or_default();
+        let proxy = Self::create(&target, &handler, context)?;
+
+        let revoke = FunctionBuilder::closure(
+            context,
+            |_, _, captures, _| {
+                // The proxy is dropped from the captures, so revoking it again does nothing.
+                let proxy = captures
+                    .downcast_mut::<Option<GcObject>>()
+                    .expect("captures must be the revocable proxy")
+                    .take();
+                if let Some(proxy) = proxy {
+                    if let ObjectData::Proxy(ref mut proxy) = proxy.borrow_mut().data {
+                        proxy.target = None;
+                        proxy.handler = None;
+                    }
+                }
+                Ok(Value::undefined())
+            },
+            Captures::new(Some(proxy.clone())),
+        )
+        .name("")
+        .length(0)
+        .build();
+
+        let mut result = context.construct_object();
+        result.insert_property("proxy", proxy, Attribute::all());
+        result.insert_property("revoke", revoke, Attribute::all());
+        Ok(result.into())
+    }
+
+    /// The abstract operation `ProxyCreate`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxycreate
+    pub(crate) fn create(
+        target: &Value,
+        handler: &Value,
+        context: &mut Context,
+    ) -> Result<GcObject> {
+        let target = target.as_object().ok_or_else(|| {
+            context.construct_type_error("cannot create proxy with a non-object as target")
+        })?;
+        let handler = handler.as_object().ok_or_else(|| {
+            context.construct_type_error("cannot create proxy with a non-object as handler")
+        })?;
+
+        let proxy = Self {
+            callable: target.is_callable(),
+            constructable: target.is_constructable(),
+            target: Some(target),
+            handler: Some(handler),
+        };
+        Ok(GcObject::new(Object::with_prototype(
+            Value::null(),
+            ObjectData::Proxy(proxy),
+        )))
+    }
+
+    /// Returns `true` if the proxy has a `[[Call]]` internal method.
+    #[inline]
+    pub(crate) fn is_callable(&self) -> bool {
+        self.callable
+    }
+
+    /// Returns `true` if the proxy has a `[[Construct]]` internal method.
+    #[inline]
+    pub(crate) fn is_constructable(&self) -> bool {
+        self.constructable
+    }
+
+    /// Gets the target and the handler of the proxy, or throws a `TypeError` if it was revoked.
+    fn target_and_handler(&self, context: &mut Context) -> Result<(GcObject, GcObject)> {
+        match (&self.target, &self.handler) {
+            (Some(target), Some(handler)) => Ok((target.clone(), handler.clone())),
+            _ => Err(context.construct_type_error("cannot perform operation on a revoked proxy")),
+        }
+    }
+
+    /// Gets the target of the proxy, looking through nested proxies, or throws a `TypeError` if
+    /// one of them was revoked.
+    pub(crate) fn unwrap_target(&self, context: &mut Context) -> Result<GcObject> {
+        let (target, _) = self.target_and_handler(context)?;
+        if let Some(proxy) = target.as_proxy() {
+            proxy.unwrap_target(context)
+        } else {
+            Ok(target)
+        }
+    }
+
+    /// `[[GetPrototypeOf]] ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-getprototypeof
+    pub(crate) fn get_prototype_of(&self, context: &mut Context) -> Result<Value> {
+        let (target, handler) = self.target_and_handler(context)?;
+        let trap = if let Some(trap) = handler.get_method(context, "getPrototypeOf")? {
+            trap
+        } else {
+            return target.get_prototype_of(context);
+        };
+
+        let handler_proto = trap.call(&handler.into(), &[target.clone().into()], context)?;
+        if !handler_proto.is_object() && !handler_proto.is_null() {
+            return context.throw_type_error(
+                "'getPrototypeOf' on proxy: trap returned neither object nor null",
+            );
+        }
+        if target.is_extensible(context)? {
+            return Ok(handler_proto);
+        }
+        if !same_value(&handler_proto, &target.get_prototype_of(context)?) {
+            return context.throw_type_error(
+                "'getPrototypeOf' on proxy: trap did not return the prototype of the non-extensible target",
+            );
+        }
+        Ok(handler_proto)
+    }
+
+    /// `[[SetPrototypeOf]] ( V )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-setprototypeof-v
+    pub(crate) fn set_prototype_of(&self, val: Value, context: &mut Context) -> Result<bool> {
+        let (mut target, handler) = self.target_and_handler(context)?;
+        let trap = if let Some(trap) = handler.get_method(context, "setPrototypeOf")? {
+            trap
+        } else {
+            return target.set_prototype_of(val, context);
+        };
+
+        let boolean_trap_result = trap
+            .call(
+                &handler.into(),
+                &[target.clone().into(), val.clone()],
+                context,
+            )?
+            .to_boolean();
+        if !boolean_trap_result || target.is_extensible(context)? {
+            return Ok(boolean_trap_result);
+        }
+        if !same_value(&val, &target.get_prototype_of(context)?) {
+            return Err(context.construct_type_error(
+                "'setPrototypeOf' on proxy: trap returned truish for setting a new prototype on the non-extensible target",
+            ));
+        }
+        Ok(true)
+    }
+
+    /// `[[IsExtensible]] ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-isextensible
+    pub(crate) fn is_extensible(&self, context: &mut Context) -> Result<bool> {
+        let (target, handler) = self.target_and_handler(context)?;
+        let trap = if let Some(trap) = handler.get_method(context, "isExtensible")? {
+            trap
+        } else {
+            return target.is_extensible(context);
+        };
+
+        let boolean_trap_result = trap
+            .call(&handler.into(), &[target.clone().into()], context)?
+            .to_boolean();
+        if boolean_trap_result != target.is_extensible(context)? {
+            return Err(context.construct_type_error(
+                "'isExtensible' on proxy: trap result does not reflect the extensibility of the target",
+            ));
+        }
+        Ok(boolean_trap_result)
+    }
+
+    /// `[[PreventExtensions]] ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-preventextensions
+    pub(crate) fn prevent_extensions(&self, context: &mut Context) -> Result<bool> {
+        let (mut target, handler) = self.target_and_handler(context)?;
+        let trap = if let Some(trap) = handler.get_method(context, "preventExtensions")? {
+            trap
+        } else {
+            return target.prevent_extensions(context);
+        };
+
+        let boolean_trap_result = trap
+            .call(&handler.into(), &[target.clone().into()], context)?
+            .to_boolean();
+        if boolean_trap_result && target.is_extensible(context)? {
+            return Err(context.construct_type_error(
+                "'preventExtensions' on proxy: trap returned truish but the target is extensible",
+            ));
+        }
+        Ok(boolean_trap_result)
+    }
+
+    /// `[[GetOwnProperty]] ( P )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-getownproperty-p
+    pub(crate) fn get_own_property(
+        &self,
+        key: &PropertyKey,
+        context: &mut Context,
+    ) -> Result<Option<PropertyDescriptor>> {
+        let (target, handler) = self.target_and_handler(context)?;
+        let trap = if let Some(trap) = handler.get_method(context, "getOwnPropertyDescriptor")? {
+            trap
+        } else {
+            return target.get_own_property(key, context);
+        };
+
+        let trap_result_obj = trap.call(
+            &handler.into(),
+            &[target.clone().into(), key.into()],
+            context,
+        )?;
+        let target_desc = target.get_own_property(key, context)?;
+        let trap_result_obj = match trap_result_obj {
+            Value::Object(ref object) => object.clone(),
+            Value::Undefined => {
+                return match target_desc {
+                    Some(desc) if !desc.configurable() => Err(context.construct_type_error(format!(
+                        "'getOwnPropertyDescriptor' on proxy: trap returned undefined for non-configurable property '{}'",
+                        key
+                    ))),
+                    Some(_) if !target.is_extensible(context)? => Err(context.construct_type_error(format!(
+                        "'getOwnPropertyDescriptor' on proxy: trap returned undefined for property '{}' of the non-extensible target",
+                        key
+                    ))),
+                    _ => Ok(None),
+                };
+            }
+            _ => return Err(context.construct_type_error(
+                "'getOwnPropertyDescriptor' on proxy: trap returned neither object nor undefined",
+            )),
+        };
+
+        let extensible_target = target.is_extensible(context)?;
+        let result_desc = trap_result_obj.to_property_descriptor(context)?;
+        if !is_compatible_property_descriptor(extensible_target, &result_desc, target_desc.as_ref())
+        {
+            return Err(context.construct_type_error(format!(
+                "'getOwnPropertyDescriptor' on proxy: trap returned a descriptor for property '{}' that is incompatible with the target",
+                key
+            )));
+        }
+        if !result_desc.configurable() {
+            match (&result_desc, &target_desc) {
+                (
+                    PropertyDescriptor::Data(result_desc),
+                    Some(PropertyDescriptor::Data(target_desc)),
+                ) if !target_desc.configurable()
+                    && target_desc.writable()
+                    && !result_desc.writable() =>
+                {
+                    return Err(context.construct_type_error(format!(
+                        "'getOwnPropertyDescriptor' on proxy: trap reported property '{}' as non-writable, but it is writable in the target",
+                        key
+                    )));
+                }
+                (_, Some(target_desc)) if !target_desc.configurable() => {}
+                _ => {
+                    return Err(context.construct_type_error(format!(
+                        "'getOwnPropertyDescriptor' on proxy: trap reported property '{}' as non-configurable, but it is not in the target",
+                        key
+                    )));
+                }
+            }
+        }
+        Ok(Some(result_desc))
+    }
+
+    /// `[[DefineOwnProperty]] ( P, Desc )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-defineownproperty-p-desc
+    pub(crate) fn define_own_property(
+        &self,
+        key: PropertyKey,
+        desc: PropertyDescriptor,
+        context: &mut Context,
+    ) -> Result<bool> {
+        let (mut target, handler) = self.target_and_handler(context)?;
+        let trap = if let Some(trap) = handler.get_method(context, "defineProperty")? {
+            trap
+        } else {
+            return target.define_own_property(key, desc, context);
+        };
+
+        let desc_obj = BuiltInObjectObject::from_property_descriptor(desc.clone(), context);
+        let boolean_trap_result = trap
+            .call(
+                &handler.into(),
+                &[target.clone().into(), (&key).into(), desc_obj],
+                context,
+            )?
+            .to_boolean();
+        if !boolean_trap_result {
+            return Ok(false);
+        }
+
+        let target_desc = target.get_own_property(&key, context)?;
+        let extensible_target = target.is_extensible(context)?;
+        let setting_config_false = !desc.configurable();
+        let message = match target_desc {
+            None if !extensible_target => "adding a property to the non-extensible target",
+            None if setting_config_false => {
+                "defining a non-configurable property which does not exist in the target"
+            }
+            None => return Ok(true),
+            Some(ref target_desc)
+                if !is_compatible_property_descriptor(
+                    extensible_target,
+                    &desc,
+                    Some(target_desc),
+                ) =>
+            {
+                "defining a property that is incompatible with the target"
+            }
+            Some(ref target_desc) if setting_config_false && target_desc.configurable() => {
+                "defining a non-configurable property which is configurable in the target"
+            }
+            Some(PropertyDescriptor::Data(ref target_desc))
+                if !target_desc.configurable()
+                    && target_desc.writable()
+                    && matches!(desc, PropertyDescriptor::Data(ref desc) if !desc.writable()) =>
+            {
+                "defining a non-writable property which is writable in the target"
+            }
+            Some(_) => return Ok(true),
+        };
+        Err(context.construct_type_error(format!(
+            "'defineProperty' on proxy: trap returned truish for {} ('{}')",
+            message, key
+        )))
+    }
+
+    /// `[[HasProperty]] ( P )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-hasproperty-p
+    pub(crate) fn has(&self, key: &PropertyKey, context: &mut Context) -> Result<bool> {
+        let (target, handler) = self.target_and_handler(context)?;
+        let trap = if let Some(trap) = handler.get_method(context, "has")? {
+            trap
+        } else {
+            return target.has_property(key, context);
+        };
+
+        let boolean_trap_result = trap
+            .call(
+                &handler.into(),
+                &[target.clone().into(), key.into()],
+                context,
+            )?
+            .to_boolean();
+        if !boolean_trap_result {
+            if let Some(target_desc) = target.get_own_property(key, context)? {
+                if !target_desc.configurable() {
+                    return Err(context.construct_type_error(format!(
+                        "'has' on proxy: trap returned falsish for non-configurable property '{}'",
+                        key
+                    )));
+                }
+                if !target.is_extensible(context)? {
+                    return Err(context.construct_type_error(format!(
+                        "'has' on proxy: trap returned falsish for property '{}' of the non-extensible target",
+                        key
+                    )));
+                }
+            }
+        }
+        Ok(boolean_trap_result)
+    }
+
+    /// `[[Get]] ( P, Receiver )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-get-p-receiver
+    pub(crate) fn get(
+        &self,
+        key: &PropertyKey,
+        receiver: Value,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let (target, handler) = self.target_and_handler(context)?;
+        let trap = if let Some(trap) = handler.get_method(context, "get")? {
+            trap
+        } else {
+            return target.get(key, receiver, context);
+        };
+
+        let trap_result = trap.call(
+            &handler.into(),
+            &[target.clone().into(), key.into(), receiver],
+            context,
+        )?;
+
+        match target.get_own_property(key, context)? {
+            Some(PropertyDescriptor::Data(ref desc))
+                if !desc.configurable()
+                    && !desc.writable()
+                    && !same_value(&trap_result, &desc.value()) =>
+            {
+                context.throw_type_error(format!(
+                    "'get' on proxy: trap did not return the value of the non-configurable, non-writable property '{}'",
+                    key
+                ))
+            }
+            Some(PropertyDescriptor::Accessor(ref desc))
+                if !desc.configurable()
+                    && desc.getter().is_none()
+                    && !trap_result.is_undefined() =>
+            {
+                context.throw_type_error(format!(
+                    "'get' on proxy: trap did not return undefined for the non-configurable property '{}' without a getter",
+                    key
+                ))
+            }
+            _ => Ok(trap_result),
+        }
+    }
+
+    /// `[[Set]] ( P, V, Receiver )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-set-p-v-receiver
+    pub(crate) fn set(
+        &self,
+        key: PropertyKey,
+        val: Value,
+        receiver: Value,
+        context: &mut Context,
+    ) -> Result<bool> {
+        let (mut target, handler) = self.target_and_handler(context)?;
+        let trap = if let Some(trap) = handler.get_method(context, "set")? {
+            trap
+        } else {
+            return target.set(key, val, receiver, context);
+        };
+
+        let boolean_trap_result = trap
+            .call(
+                &handler.into(),
+                &[target.clone().into(), (&key).into(), val.clone(), receiver],
+                context,
+            )?
+            .to_boolean();
+        if !boolean_trap_result {
+            return Ok(false);
+        }
+
+        match target.get_own_property(&key, context)? {
+            Some(PropertyDescriptor::Data(ref desc))
+                if !desc.configurable() && !desc.writable() && !same_value(&val, &desc.value()) =>
+            {
+                Err(context.construct_type_error(format!(
+                    "'set' on proxy: trap returned truish for changing the non-configurable, non-writable property '{}'",
+                    key
+                )))
+            }
+            Some(PropertyDescriptor::Accessor(ref desc))
+                if !desc.configurable() && desc.setter().is_none() =>
+            {
+                Err(context.construct_type_error(format!(
+                    "'set' on proxy: trap returned truish for the non-configurable property '{}' without a setter",
+                    key
+                )))
+            }
+            _ => Ok(true),
+        }
+    }
+
+    /// `[[Delete]] ( P )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-delete-p
+    pub(crate) fn delete(&self, key: &PropertyKey, context: &mut Context) -> Result<bool> {
+        let (mut target, handler) = self.target_and_handler(context)?;
+        let trap = if let Some(trap) = handler.get_method(context, "deleteProperty")? {
+            trap
+        } else {
+            return target.delete(key, context);
+        };
+
+        let boolean_trap_result = trap
+            .call(
+                &handler.into(),
+                &[target.clone().into(), key.into()],
+                context,
+            )?
+            .to_boolean();
+        if !boolean_trap_result {
+            return Ok(false);
+        }
+
+        match target.get_own_property(key, context)? {
+            Some(desc) if !desc.configurable() => Err(context.construct_type_error(format!(
+                "'deleteProperty' on proxy: trap returned truish for non-configurable property '{}'",
+                key
+            ))),
+            Some(_) if !target.is_extensible(context)? => Err(context.construct_type_error(format!(
+                "'deleteProperty' on proxy: trap returned truish for property '{}' of the non-extensible target",
+                key
+            ))),
+            _ => Ok(true),
+        }
+    }
+
+    /// `[[OwnPropertyKeys]] ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-ownpropertykeys
+    pub(crate) fn own_property_keys(&self, context: &mut Context) -> Result<Vec<PropertyKey>> {
+        let (target, handler) = self.target_and_handler(context)?;
+        let trap = if let Some(trap) = handler.get_method(context, "ownKeys")? {
+            trap
+        } else {
+            return target.own_property_keys(context);
+        };
+
+        let trap_result_array = trap.call(&handler.into(), &[target.clone().into()], context)?;
+        let trap_result_array = trap_result_array.as_object().ok_or_else(|| {
+            context.construct_type_error("'ownKeys' on proxy: trap returned a non-object")
+        })?;
+        let mut trap_result = Vec::new();
+        for value in
+            trap_result_array.create_list_from_array_like(&[Type::String, Type::Symbol], context)?
+        {
+            let key = value.to_property_key(context)?;
+            if trap_result.contains(&key) {
+                return Err(context.construct_type_error(format!(
+                    "'ownKeys' on proxy: trap returned duplicate entries for '{}'",
+                    key
+                )));
+            }
+            trap_result.push(key);
+        }
+
+        let extensible_target = target.is_extensible(context)?;
+        let mut target_configurable_keys = Vec::new();
+        let mut target_nonconfigurable_keys = Vec::new();
+        for key in target.own_property_keys(context)? {
+            match target.get_own_property(&key, context)? {
+                Some(desc) if !desc.configurable() => target_nonconfigurable_keys.push(key),
+                _ => target_configurable_keys.push(key),
+            }
+        }
+        if extensible_target && target_nonconfigurable_keys.is_empty() {
+            return Ok(trap_result);
+        }
+
+        let mut unchecked_result_keys = trap_result.clone();
+        let mut check_keys = |keys: Vec<PropertyKey>, context: &mut Context| {
+            for key in keys {
+                if let Some(index) = unchecked_result_keys.iter().position(|k| *k == key) {
+                    unchecked_result_keys.swap_remove(index);
+                } else {
+                    return Err(context.construct_type_error(format!(
+                        "'ownKeys' on proxy: trap result did not include '{}'",
+                        key
+                    )));
+                }
+            }
+            Ok(())
+        };
+        check_keys(target_nonconfigurable_keys, context)?;
+        if extensible_target {
+            return Ok(trap_result);
+        }
+        check_keys(target_configurable_keys, context)?;
+        if !unchecked_result_keys.is_empty() {
+            return Err(context.construct_type_error(
+                "'ownKeys' on proxy: trap returned extra keys but the target is non-extensible",
+            ));
+        }
+        Ok(trap_result)
+    }
+
+    /// `[[Call]] ( thisArgument, argumentsList )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-call-thisargument-argumentslist
+    pub(crate) fn call(
+        &self,
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let (target, handler) = self.target_and_handler(context)?;
+        let trap = if let Some(trap) = handler.get_method(context, "apply")? {
+            trap
+        } else {
+            return target.call(this, args, context);
+        };
+
+        let arg_array = Array::construct_array(&Array::new_array(context), args, context)?;
+        trap.call(
+            &handler.into(),
+            &[target.into(), this.clone(), arg_array],
+            context,
+        )
+    }
+
+    /// `[[Construct]] ( argumentsList, newTarget )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-construct-argumentslist-newtarget
+    pub(crate) fn construct(
+        &self,
+        args: &[Value],
+        new_target: Value,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let (target, handler) = self.target_and_handler(context)?;
+        let trap = if let Some(trap) = handler.get_method(context, "construct")? {
+            trap
+        } else {
+            return target.construct(args, new_target, context);
+        };
+
+        let arg_array = Array::construct_array(&Array::new_array(context), args, context)?;
+        let new_obj = trap.call(
+            &handler.into(),
+            &[target.into(), arg_array, new_target],
+            context,
+        )?;
+        if !new_obj.is_object() {
+            return context.throw_type_error("'construct' on proxy: trap returned a non-object");
+        }
+        Ok(new_obj)
+    }
+}
+
+/// The abstract operation `IsCompatiblePropertyDescriptor`.
+///
+/// Checks if `desc` could be defined over the `current` property without breaking the invariants
+/// of the object. Property descriptors are always complete here, so no field is ever absent.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-iscompatiblepropertydescriptor
+fn is_compatible_property_descriptor(
+    extensible: bool,
+    desc: &PropertyDescriptor,
+    current: Option<&PropertyDescriptor>,
+) -> bool {
+    let current = if let Some(current) = current {
+        current
+    } else {
+        return extensible;
+    };
+
+    if current.configurable() {
+        return true;
+    }
+    if desc.configurable() || desc.enumerable() != current.enumerable() {
+        return false;
+    }
+
+    match (current, desc) {
+        (PropertyDescriptor::Data(current), PropertyDescriptor::Data(desc)) => {
+            current.writable() || (!desc.writable() && same_value(&desc.value(), &current.value()))
+        }
+        (PropertyDescriptor::Accessor(current), PropertyDescriptor::Accessor(desc)) => {
+            let same_function = |a: Option<&GcObject>, b: Option<&GcObject>| match (a, b) {
+                (Some(a), Some(b)) => GcObject::equals(a, b),
+                (None, None) => true,
+                _ => false,
+            };
+            same_function(current.getter(), desc.getter())
+                && same_function(current.setter(), desc.setter())
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,321 @@
+use crate::{forward, Context};
+
+#[test]
+fn constructor_requires_new() {
+    let mut context = Context::new();
+
+    let result = forward(
+        &mut context,
+        "try { Proxy({}, {}) } catch (e) { e instanceof TypeError }",
+    );
+    assert_eq!(result, "true");
+
+    let result = forward(
+        &mut context,
+        "try { new Proxy(1, {}) } catch (e) { e instanceof TypeError }",
+    );
+    assert_eq!(result, "true");
+}
+
+#[test]
+fn forwards_without_traps() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var target = { a: 1 };
+        var proxy = new Proxy(target, {});
+        proxy.b = 2;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "proxy.a"), "1");
+    assert_eq!(forward(&mut context, "target.b"), "2");
+    assert_eq!(forward(&mut context, "'a' in proxy"), "true");
+    assert_eq!(forward(&mut context, "delete proxy.a"), "true");
+    assert_eq!(forward(&mut context, "target.a"), "undefined");
+    assert_eq!(
+        forward(&mut context, "Reflect.ownKeys(proxy).join()"),
+        "\"b\""
+    );
+}
+
+#[test]
+fn get_set_traps() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var log = [];
+        var proxy = new Proxy({}, {
+            get: function (target, key, receiver) {
+                log.push('get ' + key);
+                return key in target ? target[key] : 42;
+            },
+            set: function (target, key, value, receiver) {
+                log.push('set ' + key);
+                target[key] = value * 2;
+                return true;
+            }
+        });
+        proxy.x = 5;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "proxy.x"), "10");
+    assert_eq!(forward(&mut context, "proxy.y"), "42");
+    assert_eq!(forward(&mut context, "log.join()"), "\"set x,get x,get y\"");
+}
+
+#[test]
+fn has_and_delete_property_traps() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var proxy = new Proxy({ hidden: 1, shown: 2 }, {
+            has(target, key) {
+                return key !== 'hidden' && key in target;
+            },
+            deleteProperty(target, key) {
+                return false;
+            }
+        });
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "'hidden' in proxy"), "false");
+    assert_eq!(forward(&mut context, "'shown' in proxy"), "true");
+    assert_eq!(forward(&mut context, "delete proxy.shown"), "false");
+    assert_eq!(
+        forward(&mut context, "Reflect.deleteProperty(proxy, 'shown')"),
+        "false"
+    );
+}
+
+#[test]
+fn own_keys_and_descriptor_traps() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var proxy = new Proxy({}, {
+            ownKeys(target) {
+                return ['a', 'b'];
+            },
+            getOwnPropertyDescriptor(target, key) {
+                return { value: key.toUpperCase(), enumerable: true, configurable: true };
+            }
+        });
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "Reflect.ownKeys(proxy).join()"),
+        "\"a,b\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "var keys = []; for (var k in proxy) { keys.push(k); } keys.join()"
+        ),
+        "\"a,b\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(proxy, 'a').value"
+        ),
+        "\"A\""
+    );
+    assert_eq!(forward(&mut context, "proxy.hasOwnProperty('b')"), "true");
+}
+
+#[test]
+fn define_property_trap() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var defined = [];
+        var target = {};
+        var proxy = new Proxy(target, {
+            defineProperty(target, key, desc) {
+                defined.push(key);
+                return Reflect.defineProperty(target, key, desc);
+            }
+        });
+        Object.defineProperty(proxy, 'x', { value: 1, configurable: true });
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "defined.join()"), "\"x\"");
+    assert_eq!(forward(&mut context, "target.x"), "1");
+}
+
+#[test]
+fn prototype_traps() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var proto = { inherited: true };
+        var proxy = new Proxy({}, {
+            getPrototypeOf(target) {
+                return proto;
+            },
+            setPrototypeOf(target, value) {
+                return false;
+            }
+        });
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "Object.getPrototypeOf(proxy) === proto"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Reflect.setPrototypeOf(proxy, null)"),
+        "false"
+    );
+}
+
+#[test]
+fn extensibility_traps() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var target = {};
+        var proxy = new Proxy(target, {
+            isExtensible(target) {
+                return Reflect.isExtensible(target);
+            },
+            preventExtensions(target) {
+                return Reflect.preventExtensions(target);
+            }
+        });
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "Reflect.isExtensible(proxy)"), "true");
+    assert_eq!(
+        forward(&mut context, "Reflect.preventExtensions(proxy)"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Reflect.isExtensible(target)"),
+        "false"
+    );
+}
+
+#[test]
+fn apply_and_construct_traps() {
+    let mut context = Context::new();
+
+    let init = r#"
+        function target(a, b) { this.sum = a + b; return a + b; }
+        var proxy = new Proxy(target, {
+            apply(target, thisArg, args) {
+                return target.apply(thisArg, args) * 10;
+            },
+            construct(target, args, newTarget) {
+                return { constructed: args.length };
+            }
+        });
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "proxy(1, 2)"), "30");
+    assert_eq!(forward(&mut context, "new proxy(1, 2, 3).constructed"), "3");
+    assert_eq!(forward(&mut context, "typeof proxy"), "\"function\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Proxy({}, {})() } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn invariant_violations() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var target = {};
+        Object.defineProperty(target, 'fixed', { value: 1 });
+        var proxy = new Proxy(target, {
+            get: function (target, key) { return 2; },
+            has(target, key) { return false; },
+            ownKeys(target) { return []; }
+        });
+        function throwsTypeError(f) {
+            try { f(); return false; } catch (e) { return e instanceof TypeError; }
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "throwsTypeError(() => proxy.fixed)"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "throwsTypeError(() => 'fixed' in proxy)"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "throwsTypeError(() => Reflect.ownKeys(proxy))"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "throwsTypeError(() => Reflect.ownKeys(new Proxy({}, { ownKeys: () => ['a', 'a'] })))"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn revocable() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var { proxy, revoke } = Proxy.revocable({ a: 1 }, {});
+        var before = proxy.a;
+        revoke();
+        revoke();
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "before"), "1");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { proxy.a } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Array.isArray(proxy) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn is_array_sees_through_proxies() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "Array.isArray(new Proxy([], {}))"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.prototype.toString.call(new Proxy([], {}))"
+        ),
+        "\"[object Array]\""
+    );
+}
@@ -178,7 +178,7 @@ impl Reflect {
             .ok_or_else(|| context.construct_type_error("target must be an object"))?;
         let key = args.get(1).unwrap_or(&undefined).to_property_key(context)?;
 
-        Ok(target.delete(&key).into())
+        Ok(target.delete(&key, context)?.into())
     }
 
     /// Gets a property of an object.
@@ -243,7 +243,7 @@ impl Reflect {
             .get(0)
             .and_then(|v| v.as_object())
             .ok_or_else(|| context.construct_type_error("target must be an object"))?;
-        Ok(target.get_prototype_of())
+        target.get_prototype_of(context)
     }
 
     /// Returns `true` if the object has the property, `false` otherwise.
@@ -263,7 +263,7 @@ impl Reflect {
             .get(1)
             .unwrap_or(&Value::undefined())
             .to_property_key(context)?;
-        Ok(target.has_property(&key).into())
+        Ok(target.has_property(&key, context)?.into())
     }
 
     /// Returns `true` if the object is extensible, `false` otherwise.
@@ -279,7 +279,7 @@ impl Reflect {
             .get(0)
             .and_then(|v| v.as_object())
             .ok_or_else(|| context.construct_type_error("target must be an object"))?;
-        Ok(target.is_extensible().into())
+        Ok(target.is_extensible(context)?.into())
     }
 
     /// Returns an array of object own property keys.
@@ -306,7 +306,7 @@ impl Reflect {
             ),
         );
 
-        let keys = target.own_property_keys();
+        let keys = target.own_property_keys(context)?;
         for (i, k) in keys.iter().enumerate() {
             result.set_field(i, k, context)?;
         }
@@ -332,7 +332,7 @@ impl Reflect {
             .and_then(|v| v.as_object())
             .ok_or_else(|| context.construct_type_error("target must be an object"))?;
 
-        Ok(target.prevent_extensions().into())
+        Ok(target.prevent_extensions(context)?.into())
     }
 
     /// Sets a property of an object.
@@ -381,6 +381,6 @@ impl Reflect {
         if !proto.is_null() && !proto.is_object() {
             return context.throw_type_error("proto must be an object or null");
         }
-        Ok(target.set_prototype_of(proto.clone()).into())
+        Ok(target.set_prototype_of(proto.clone(), context)?.into())
     }
 }
@@ -10,11 +10,12 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp
 
 use crate::{
-    builtins::BuiltIn,
+    builtins::{Array, BuiltIn},
     gc::{empty_trace, Finalize, Trace},
     object::{ConstructorBuilder, FunctionBuilder, GcObject, ObjectData, PROTOTYPE},
     property::{Attribute, DataDescriptor},
-    value::{RcString, Value},
+    symbol::WellKnownSymbols,
+    value::{same_value, Value},
     BoaProfiler, Context, Result,
 };
 use regress::Regex;
@@ -109,6 +110,14 @@ impl BuiltIn for RegExp {
             .callable(true)
             .build();
 
+        let species = WellKnownSymbols::species();
+
+        let species_getter = FunctionBuilder::new(context, Self::species_getter)
+            .callable(true)
+            .constructable(false)
+            .name("get [Symbol.species]")
+            .build();
+
         let regexp_object = ConstructorBuilder::with_standard_object(
             context,
             Self::constructor,
@@ -116,10 +125,36 @@ impl BuiltIn for RegExp {
         )
         .name(Self::NAME)
         .length(Self::LENGTH)
+        .static_accessor(species, Some(species_getter), None, Attribute::CONFIGURABLE)
         .property("lastIndex", 0, Attribute::all())
         .method(Self::test, "test", 1)
         .method(Self::exec, "exec", 1)
         .method(Self::to_string, "toString", 0)
+        .method(
+            Self::r#match,
+            (WellKnownSymbols::match_(), "[Symbol.match]"),
+            1,
+        )
+        .method(
+            Self::match_all,
+            (WellKnownSymbols::match_all(), "[Symbol.matchAll]"),
+            1,
+        )
+        .method(
+            Self::replace,
+            (WellKnownSymbols::replace(), "[Symbol.replace]"),
+            2,
+        )
+        .method(
+            Self::search,
+            (WellKnownSymbols::search(), "[Symbol.search]"),
+            1,
+        )
+        .method(
+            Self::split,
+            (WellKnownSymbols::split(), "[Symbol.split]"),
+            2,
+        )
         .accessor("global", Some(get_global), None, flag_attributes)
         .accessor("ignoreCase", Some(get_ignore_case), None, flag_attributes)
         .accessor("multiline", Some(get_multiline), None, flag_attributes)
@@ -252,6 +287,18 @@ impl RegExp {
         Ok(this)
     }
 
+    /// `get RegExp [ @@species ]`
+    ///
+    /// get accessor for the @@species property of RegExp
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-regexp-@@species
+    fn species_getter(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
+        Ok(this.clone())
+    }
+
     #[inline]
     fn regexp_has_flag(this: &Value, flag: char, context: &mut Context) -> Result<Value> {
         if let Some(object) = this.as_object() {
@@ -588,7 +635,12 @@ impl RegExp {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-regexp.prototype-@@match
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/@@match
-    pub(crate) fn r#match(this: &Value, arg: RcString, context: &mut Context) -> Result<Value> {
+    pub(crate) fn r#match(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let arg = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_string(context)?;
         let (matcher, flags) = if let Some(object) = this.as_object() {
             let object = object.borrow();
             if let Some(regex) = object.as_regexp() {
@@ -609,7 +661,7 @@ impl RegExp {
             if matches.is_empty() {
                 return Ok(Value::null());
             }
-            Ok(Value::from(matches))
+            Ok(Array::create_array_from_list(matches, context))
         } else {
             Self::exec(this, &[Value::from(arg)], context)
         }
@@ -656,7 +708,13 @@ impl RegExp {
     /// [spec]: https://tc39.es/ecma262/#sec-regexp-prototype-matchall
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/@@matchAll
     // TODO: it's returning an array, it should return an iterator
-    pub(crate) fn match_all(this: &Value, arg_str: String, context: &mut Context) -> Result<Value> {
+    pub(crate) fn match_all(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let arg_str = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_string(context)?
+            .to_string();
         let matches = if let Some(object) = this.as_object() {
             let object = object.borrow();
             if let Some(regex) = object.as_regexp() {
@@ -705,4 +763,156 @@ impl RegExp {
 
         Ok(result)
     }
+
+    /// `RegExp.prototype[ @@replace ]( string, replaceValue )`
+    ///
+    /// This method replaces the first match of the regular expression in a string.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-regexp.prototype-@@replace
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/@@replace
+    pub(crate) fn replace(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        if !matches!(this.as_object(), Some(object) if object.borrow().is_regexp()) {
+            return context
+                .throw_type_error("RegExp.prototype[Symbol.replace] called on incompatible value");
+        }
+        let string = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_string(context)?;
+
+        let mut pattern_args = vec![this.clone()];
+        pattern_args.extend(args.get(1).cloned());
+        super::String::replace_pattern(string, &pattern_args, context)
+    }
+
+    /// `RegExp.prototype[ @@search ]( string )`
+    ///
+    /// This method returns the index of the first match of the regular expression in a string,
+    /// or `-1` if there is none.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-regexp.prototype-@@search
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/@@search
+    pub(crate) fn search(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        if !this.is_object() {
+            return context
+                .throw_type_error("RegExp.prototype[Symbol.search] called on incompatible value");
+        }
+        let string = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_string(context)?;
+
+        // The search always starts at the beginning of the string, and leaves `lastIndex` as it was.
+        let previous_last_index = this.get_field("lastIndex", context)?;
+        if !same_value(&previous_last_index, &Value::from(0)) {
+            this.set_field("lastIndex", 0, context)?;
+        }
+        let result = Self::exec(this, &[string.into()], context)?;
+        let current_last_index = this.get_field("lastIndex", context)?;
+        if !same_value(&current_last_index, &previous_last_index) {
+            this.set_field("lastIndex", previous_last_index, context)?;
+        }
+
+        if result.is_null() {
+            Ok(Value::from(-1))
+        } else {
+            result.get_field("index", context)
+        }
+    }
+
+    /// `RegExp.prototype[ @@split ]( string, limit )`
+    ///
+    /// This method splits a string at the matches of the regular expression, keeping the
+    /// captured groups in the result.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-regexp.prototype-@@split
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/@@split
+    pub(crate) fn split(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let matcher = match this.as_object() {
+            Some(object) => match object.borrow().as_regexp() {
+                Some(regexp) => regexp.matcher.clone(),
+                None => {
+                    return context.throw_type_error(
+                        "RegExp.prototype[Symbol.split] called on incompatible value",
+                    )
+                }
+            },
+            None => {
+                return context.throw_type_error(
+                    "RegExp.prototype[Symbol.split] called on incompatible value",
+                )
+            }
+        };
+        let string = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_string(context)?;
+        let limit = match args.get(1) {
+            Some(limit) if !limit.is_undefined() => limit.to_u32(context)? as usize,
+            _ => u32::MAX as usize,
+        };
+
+        let mut values = Vec::new();
+        if limit == 0 {
+            return Ok(Array::create_array_from_list(values, context));
+        }
+        if string.is_empty() {
+            if matcher.find(&string).is_none() {
+                values.push(Value::from(string));
+            }
+            return Ok(Array::create_array_from_list(values, context));
+        }
+
+        // The start of the next substring, and the position to search the next match from.
+        let mut p = 0;
+        let mut q = 0;
+        while q < string.len() {
+            let mat = match matcher.find_from(&string, q).next() {
+                Some(mat) if mat.start() < string.len() => mat,
+                _ => break,
+            };
+            if mat.end() == p {
+                // An empty match at the start of the substring doesn't split it.
+                q = mat.start()
+                    + string[mat.start()..]
+                        .chars()
+                        .next()
+                        .map_or(1, char::len_utf8);
+                continue;
+            }
+
+            values.push(Value::from(&string[p..mat.start()]));
+            if values.len() == limit {
+                return Ok(Array::create_array_from_list(values, context));
+            }
+            for group in mat.groups().skip(1) {
+                values
+                    .push(group.map_or_else(Value::undefined, |range| Value::from(&string[range])));
+                if values.len() == limit {
+                    return Ok(Array::create_array_from_list(values, context));
+                }
+            }
+
+            p = mat.end();
+            q = p;
+        }
+        values.push(Value::from(&string[p..]));
+
+        Ok(Array::create_array_from_list(values, context))
+    }
 }
@@ -107,3 +107,35 @@ fn no_panic_on_invalid_character_escape() {
     // The line below should not cause Boa to panic
     forward(&mut context, r"const a = /,\;/");
 }
+
+#[test]
+fn well_known_symbols() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "RegExp[Symbol.species] === RegExp"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "/c/[Symbol.search]('abcabc')"), "2");
+    assert_eq!(forward(&mut context, "/z/[Symbol.search]('abc')"), "-1");
+    assert_eq!(
+        forward(&mut context, "/\\d/[Symbol.split]('a1b2c3').join('|')"),
+        "\"a|b|c|\""
+    );
+    assert_eq!(
+        forward(&mut context, "/(\\d)/[Symbol.split]('a1b2', 3).join('|')"),
+        "\"a|1|b\""
+    );
+    assert_eq!(
+        forward(&mut context, "/(?:)/[Symbol.split]('abc').join('|')"),
+        "\"a|b|c\""
+    );
+    assert_eq!(
+        forward(&mut context, "/X/[Symbol.replace]('aXbX', '-')"),
+        "\"a-bX\""
+    );
+    assert_eq!(
+        forward(&mut context, "/a/g[Symbol.match]('abab').join()"),
+        "\"a,a\""
+    );
+}
@@ -310,12 +310,6 @@ impl Set {
 
         let callback_arg = &args[0];
         let this_arg = args.get(1).cloned().unwrap_or_else(Value::undefined);
-        // TODO: if condition should also check that we are not in strict mode
-        let this_arg = if this_arg.is_undefined() {
-            Value::Object(context.global_object())
-        } else {
-            this_arg
-        };
 
         let mut index = 0;
 
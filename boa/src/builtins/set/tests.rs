@@ -216,6 +216,20 @@ fn for_each() {
     assert_eq!(forward(&mut context, "sizeSum"), "9");
 }
 
+#[test]
+fn for_each_this_arg() {
+    let mut context = Context::new();
+    let init = r#"
+        let set = new Set([1]);
+        let values = [];
+        set.forEach(function () { values.push(this === globalThis); });
+        set.forEach(function () { "use strict"; values.push(this === undefined); });
+        set.forEach(function () { "use strict"; values.push(this); }, 5);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "values.join()"), "\"true,true,5\"");
+}
+
 #[test]
 fn recursive_display() {
     let mut context = Context::new();
@@ -243,6 +257,6 @@ fn not_a_function() {
     ";
     assert_eq!(
         forward(&mut context, init),
-        "\"TypeError: calling a builtin Set constructor without new is forbidden\""
+        "\"TypeError: calling a builtin Set constructor without new is forbidden at line 3, col 23\""
     );
 }
@@ -119,6 +119,7 @@ impl BuiltIn for String {
         .method(Self::index_of, "indexOf", 1)
         .method(Self::last_index_of, "lastIndexOf", 1)
         .method(Self::r#match, "match", 1)
+        .method(Self::search, "search", 1)
         .method(Self::pad_end, "padEnd", 1)
         .method(Self::pad_start, "padStart", 1)
         .method(Self::trim, "trim", 0)
@@ -646,12 +647,33 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.replace
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/replace
     pub(crate) fn replace(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        // TODO: Support Symbol replacer
+        let this = this.require_object_coercible(context)?;
+
+        let search_value = args.get(0).filter(|value| !value.is_null_or_undefined());
+        if let Some(search_value) = search_value {
+            if let Some(object) = search_value.as_object() {
+                if let Some(replacer) = object.get_method(context, WellKnownSymbols::replace())? {
+                    let replace_value = args.get(1).cloned().unwrap_or_default();
+                    return replacer.call(search_value, &[this.clone(), replace_value], context);
+                }
+            }
+        }
+
         let primitive_val = this.to_string(context)?;
         if args.is_empty() {
             return Ok(Value::from(primitive_val));
         }
+        Self::replace_pattern(primitive_val, args, context)
+    }
 
+    /// Replaces the first match of the pattern, a string or a regular expression, in the string.
+    ///
+    /// The arguments are the pattern and the replacement of `String.prototype.replace`.
+    pub(crate) fn replace_pattern(
+        primitive_val: RcString,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
         let regex_body = Self::get_regex_string(args.get(0).expect("Value needed"));
         let re = Regex::new(&regex_body).expect("unable to convert regex to regex object");
         let mat = match re.find(&primitive_val) {
@@ -774,7 +796,7 @@ impl String {
                     // Push the whole string being examined
                     results.push(Value::from(primitive_val.to_string()));
 
-                    let result = context.call(&replace_object, this, &results)?;
+                    let result = context.call(&replace_object, &Value::undefined(), &results)?;
 
                     result.to_string(context)?.to_string()
                 }
@@ -893,12 +915,44 @@ impl String {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/match
     /// [regex]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Regular_Expressions
     pub(crate) fn r#match(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        let re = RegExp::constructor(
-            &Value::from(Object::default()),
-            &[args.get(0).cloned().unwrap_or_default()],
-            context,
-        )?;
-        RegExp::r#match(&re, this.to_string(context)?, context)
+        let this = this.require_object_coercible(context)?;
+
+        let regexp = args.get(0).cloned().unwrap_or_default();
+        if let Some(object) = regexp.as_object() {
+            if let Some(matcher) = object.get_method(context, WellKnownSymbols::match_())? {
+                return matcher.call(&regexp, &[this.clone()], context);
+            }
+        }
+
+        let string = this.to_string(context)?;
+        let re = RegExp::constructor(&Value::from(Object::default()), &[regexp], context)?;
+        RegExp::r#match(&re, &[string.into()], context)
+    }
+
+    /// `String.prototype.search( regexp )`
+    ///
+    /// The `search()` method returns the index of the first match of a regular expression in the
+    /// string, or `-1` if there is none.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.search
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/search
+    pub(crate) fn search(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let this = this.require_object_coercible(context)?;
+
+        let regexp = args.get(0).cloned().unwrap_or_default();
+        if let Some(object) = regexp.as_object() {
+            if let Some(searcher) = object.get_method(context, WellKnownSymbols::search())? {
+                return searcher.call(&regexp, &[this.clone()], context);
+            }
+        }
+
+        let string = this.to_string(context)?;
+        let re = RegExp::constructor(&Value::from(Object::default()), &[regexp], context)?;
+        RegExp::search(&re, &[string.into()], context)
     }
 
     /// Abstract method `StringPad`.
@@ -1214,11 +1268,11 @@ impl String {
         let separator = args.get(0).filter(|value| !value.is_null_or_undefined());
 
         if let Some(result) = separator
-            .and_then(|separator| separator.as_object())
-            .and_then(|separator| {
+            .and_then(|separator| separator.as_object().map(|object| (separator, object)))
+            .and_then(|(separator, object)| {
                 let key = WellKnownSymbols::split();
 
-                match separator.get_method(context, key) {
+                match object.get_method(context, key) {
                     Ok(splitter) => splitter.map(|splitter| {
                         let arguments = &[
                             Value::from(string.clone()),
@@ -1226,7 +1280,7 @@ impl String {
                                 .map(|x| x.to_owned())
                                 .unwrap_or(Value::Undefined),
                         ];
-                        splitter.call(this, arguments, context)
+                        splitter.call(separator, arguments, context)
                     }),
                     Err(_) => Some(Err(
                         context.construct_type_error("separator[Symbol.split] is not a function")
@@ -1296,6 +1350,16 @@ impl String {
     /// [cg]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Regular_Expressions/Groups_and_Ranges
     // TODO: update this method to return iterator
     pub(crate) fn match_all(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let this = this.require_object_coercible(context)?;
+
+        if let Some(regexp) = args.get(0) {
+            if let Some(object) = regexp.as_object() {
+                if let Some(matcher) = object.get_method(context, WellKnownSymbols::match_all())? {
+                    return matcher.call(regexp, &[this.clone()], context);
+                }
+            }
+        }
+
         let re: Value = match args.get(0) {
             Some(arg) => {
                 if arg.is_null() {
@@ -1321,7 +1385,7 @@ impl String {
             ),
         }?;
 
-        RegExp::match_all(&re, this.to_string(context)?.to_string(), context)
+        RegExp::match_all(&re, &[this.to_string(context)?.into()], context)
     }
 
     pub(crate) fn iterator(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
@@ -352,7 +352,7 @@ fn starts_with_with_regex_arg() {
         forward(
             &mut context, scenario
         ),
-        "\"TypeError: First argument to String.prototype.startsWith must not be a regular expression\""
+        "\"TypeError: First argument to String.prototype.startsWith must not be a regular expression at line 3, col 13\""
     )
 }
 
@@ -396,7 +396,7 @@ fn ends_with_with_regex_arg() {
         forward(
             &mut context, scenario
         ),
-        "\"TypeError: First argument to String.prototype.endsWith must not be a regular expression\""
+        "\"TypeError: First argument to String.prototype.endsWith must not be a regular expression at line 3, col 13\""
     )
 }
 
@@ -440,7 +440,7 @@ fn includes_with_regex_arg() {
         forward(
             &mut context, scenario
         ),
-        "\"TypeError: First argument to String.prototype.includes must not be a regular expression\""
+        "\"TypeError: First argument to String.prototype.includes must not be a regular expression at line 3, col 13\""
     )
 }
 
@@ -694,7 +694,7 @@ fn split_with_symbol_split_method() {
             }
         "#
         ),
-        "\"TypeError: separator[Symbol.split] is not a function\""
+        "\"TypeError: separator[Symbol.split] is not a function at line 5, col 17\""
     );
 }
 
@@ -1093,3 +1093,43 @@ fn unicode_iter() {
     assert_eq!(forward(&mut context, "next.value"), "undefined");
     assert_eq!(forward(&mut context, "next.done"), "true");
 }
+
+#[test]
+fn search() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "'abcabc'.search(/c/)"), "2");
+    assert_eq!(forward(&mut context, "'abc'.search('b')"), "1");
+    assert_eq!(forward(&mut context, "'abc'.search('z')"), "-1");
+}
+
+#[test]
+fn symbol_methods_of_patterns() {
+    let mut context = Context::new();
+    let init = r#"
+        var pattern = {};
+        pattern[Symbol.match] = function (string) { return "match " + string; };
+        pattern[Symbol.replace] = function (string, replacement) { return string + replacement; };
+        pattern[Symbol.search] = function (string) { return 42; };
+        pattern[Symbol.split] = function (string, limit) { return [string, limit]; };
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "'foo'.match(pattern)"),
+        "\"match foo\""
+    );
+    assert_eq!(
+        forward(&mut context, "'foo'.replace(pattern, '!')"),
+        "\"foo!\""
+    );
+    assert_eq!(forward(&mut context, "'foo'.search(pattern)"), "42");
+    assert_eq!(
+        forward(&mut context, "'foo'.split(pattern, 2).join()"),
+        "\"foo,2\""
+    );
+    assert_eq!(
+        forward(&mut context, "'a1b2'.split(/\\d/).join('|')"),
+        "\"a|b|\""
+    );
+}
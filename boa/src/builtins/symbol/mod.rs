@@ -62,6 +62,13 @@ impl BuiltIn for Symbol {
             .callable(true)
             .build();
 
+        let to_primitive = FunctionBuilder::new(context, Self::to_primitive)
+            .name("[Symbol.toPrimitive]")
+            .length(1)
+            .constructable(false)
+            .callable(true)
+            .build();
+
         let symbol_object = ConstructorBuilder::with_standard_object(
             context,
             Self::constructor,
@@ -79,16 +86,29 @@ impl BuiltIn for Symbol {
         .static_property("search", symbol_search, attribute)
         .static_property("species", symbol_species, attribute)
         .static_property("split", symbol_split, attribute)
-        .static_property("toPrimitive", symbol_to_primitive, attribute)
-        .static_property("toStringTag", symbol_to_string_tag, attribute)
+        .static_property("toPrimitive", symbol_to_primitive.clone(), attribute)
+        .static_property("toStringTag", symbol_to_string_tag.clone(), attribute)
         .static_property("unscopables", symbol_unscopables, attribute)
+        .static_method(Self::for_, "for", 1)
+        .static_method(Self::key_for, "keyFor", 1)
         .method(Self::to_string, "toString", 0)
+        .method(Self::value_of, "valueOf", 0)
         .accessor(
             "description",
             Some(get_description),
             None,
             Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
         )
+        .property(
+            symbol_to_primitive,
+            to_primitive,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .property(
+            symbol_to_string_tag,
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
         .callable(true)
         .constructable(false)
         .build();
@@ -112,14 +132,8 @@ impl Symbol {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-symbol-description
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/Symbol
-    pub(crate) fn constructor(
-        new_target: &Value,
-        args: &[Value],
-        context: &mut Context,
-    ) -> Result<Value> {
-        if new_target.is_undefined() {
-            return context.throw_type_error("Symbol is not a constructor");
-        }
+    pub(crate) fn constructor(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // `new Symbol()` is already rejected, as `Symbol` is not constructable.
         let description = match args.get(0) {
             Some(ref value) if !value.is_undefined() => Some(value.to_string(context)?),
             _ => None,
@@ -181,4 +195,76 @@ impl Symbol {
             Ok(Value::undefined())
         }
     }
+
+    /// `Symbol.prototype.valueOf()`
+    ///
+    /// This method returns the primitive value of a `Symbol` object.
+    ///
+    /// More information:
+    /// - [MDN documentation][mdn]
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-symbol.prototype.valueof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/valueOf
+    pub(crate) fn value_of(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        Ok(Self::this_symbol_value(this, context)?.into())
+    }
+
+    /// `Symbol.prototype [ @@toPrimitive ] ( hint )`
+    ///
+    /// This method converts a `Symbol` object to its primitive value, whatever the hint.
+    ///
+    /// More information:
+    /// - [MDN documentation][mdn]
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-symbol.prototype-@@toprimitive
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/@@toPrimitive
+    pub(crate) fn to_primitive(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        Ok(Self::this_symbol_value(this, context)?.into())
+    }
+
+    /// `Symbol.for( key )`
+    ///
+    /// This method returns the symbol registered with the given key in the global symbol
+    /// registry, creating and registering a new one if there is none.
+    ///
+    /// More information:
+    /// - [MDN documentation][mdn]
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-symbol.for
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/for
+    pub(crate) fn for_(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let key = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_string(context)?;
+        Ok(context.realm.symbol_registry.get_or_insert(key).into())
+    }
+
+    /// `Symbol.keyFor( sym )`
+    ///
+    /// This method returns the key of a symbol in the global symbol registry, or `undefined` if
+    /// it is not registered.
+    ///
+    /// More information:
+    /// - [MDN documentation][mdn]
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-symbol.keyfor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/keyFor
+    pub(crate) fn key_for(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let symbol = match args.get(0) {
+            Some(Value::Symbol(ref symbol)) => symbol,
+            _ => return context.throw_type_error("Symbol.keyFor requires a symbol"),
+        };
+
+        Ok(context
+            .realm
+            .symbol_registry
+            .key_for(symbol)
+            .map_or_else(Value::undefined, Value::from))
+    }
 }
@@ -37,3 +37,62 @@ fn symbol_access() {
     assert_eq!(forward(&mut context, "x[sym2]"), "20");
     assert_eq!(forward(&mut context, "x['Symbol(Hello)']"), "undefined");
 }
+
+#[test]
+fn symbol_registry() {
+    let mut context = Context::new();
+    let init = r#"
+        var registered = Symbol.for("app");
+        "#;
+    forward_val(&mut context, init).unwrap();
+    assert_eq!(
+        forward(&mut context, "registered === Symbol.for('app')"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Symbol.keyFor(registered)"),
+        "\"app\""
+    );
+    assert_eq!(
+        forward(&mut context, "Symbol.keyFor(Symbol('app'))"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(&mut context, "Symbol.keyFor(Symbol.iterator)"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Symbol.keyFor('app') } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn symbol_prototype() {
+    let mut context = Context::new();
+    let init = r#"
+        var sym = Symbol("Hello");
+        var wrapper = Object(sym);
+        "#;
+    forward_val(&mut context, init).unwrap();
+    assert_eq!(forward(&mut context, "wrapper.valueOf() === sym"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "wrapper[Symbol.toPrimitive]('number') === sym"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "wrapper.description"), "\"Hello\"");
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(sym)"),
+        "\"[object Symbol]\""
+    );
+    assert_eq!(
+        forward(&mut context, "Symbol.call(undefined, 'a').toString()"),
+        "\"Symbol(a)\""
+    );
+}
@@ -0,0 +1,1735 @@
+//! This module implements the `%TypedArray%` intrinsic and the typed array constructors.
+//!
+//! A typed array is an array-like view of the bytes of an `ArrayBuffer`, interpreted as numbers
+//! of a single element type, such as `Int8Array` or `Float64Array`. Typed arrays are integer-indexed
+//! exotic objects: their numeric properties are stored in the buffer instead of the object.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-typedarray-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray
+
+use crate::{
+    builtins::{
+        array::array_iterator::{ArrayIterationKind, ArrayIterator},
+        iterable::get_iterator,
+        number::{f64_to_int32, f64_to_uint32},
+        ArrayBuffer, BigInt, BuiltIn, Number,
+    },
+    context::StandardConstructor,
+    gc::{empty_trace, Finalize, Trace},
+    object::{ConstructorBuilder, FunctionBuilder, GcObject, Object, ObjectData, PROTOTYPE},
+    property::{Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
+    symbol::WellKnownSymbols,
+    value::{same_value_zero, IntegerOrInfinity, Numeric, Value},
+    BoaProfiler, Context, Result,
+};
+use std::{cmp::Ordering, convert::TryFrom};
+
+#[cfg(test)]
+mod tests;
+
+/// Whether the elements of typed arrays are stored in little endian order.
+///
+/// Typed arrays use the byte order of the platform, so a host sharing a buffer with scripts can
+/// reinterpret its bytes directly.
+const LITTLE_ENDIAN: bool = cfg!(target_endian = "little");
+
+/// The element type of a typed array.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TypedArrayKind {
+    Int8,
+    Uint8,
+    Uint8Clamped,
+    Int16,
+    Uint16,
+    Int32,
+    Uint32,
+    Float32,
+    Float64,
+    BigInt64,
+    BigUint64,
+}
+
+unsafe impl Trace for TypedArrayKind {
+    empty_trace!();
+}
+
+impl Finalize for TypedArrayKind {}
+
+impl TypedArrayKind {
+    /// Returns the name of the constructor of typed arrays of this kind.
+    #[inline]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Int8 => "Int8Array",
+            Self::Uint8 => "Uint8Array",
+            Self::Uint8Clamped => "Uint8ClampedArray",
+            Self::Int16 => "Int16Array",
+            Self::Uint16 => "Uint16Array",
+            Self::Int32 => "Int32Array",
+            Self::Uint32 => "Uint32Array",
+            Self::Float32 => "Float32Array",
+            Self::Float64 => "Float64Array",
+            Self::BigInt64 => "BigInt64Array",
+            Self::BigUint64 => "BigUint64Array",
+        }
+    }
+
+    /// Returns the size of an element, in bytes.
+    #[inline]
+    pub fn element_size(self) -> usize {
+        match self {
+            Self::Int8 | Self::Uint8 | Self::Uint8Clamped => 1,
+            Self::Int16 | Self::Uint16 => 2,
+            Self::Int32 | Self::Uint32 | Self::Float32 => 4,
+            Self::Float64 | Self::BigInt64 | Self::BigUint64 => 8,
+        }
+    }
+
+    /// Returns `true` if the elements are bigints rather than numbers.
+    #[inline]
+    pub fn is_bigint(self) -> bool {
+        matches!(self, Self::BigInt64 | Self::BigUint64)
+    }
+
+    /// Returns the standard constructor of typed arrays of this kind.
+    pub(crate) fn standard_constructor(self, context: &Context) -> StandardConstructor {
+        let objects = context.standard_objects();
+        match self {
+            Self::Int8 => objects.int8_array_object(),
+            Self::Uint8 => objects.uint8_array_object(),
+            Self::Uint8Clamped => objects.uint8_clamped_array_object(),
+            Self::Int16 => objects.int16_array_object(),
+            Self::Uint16 => objects.uint16_array_object(),
+            Self::Int32 => objects.int32_array_object(),
+            Self::Uint32 => objects.uint32_array_object(),
+            Self::Float32 => objects.float32_array_object(),
+            Self::Float64 => objects.float64_array_object(),
+            Self::BigInt64 => objects.big_int64_array_object(),
+            Self::BigUint64 => objects.big_uint64_array_object(),
+        }
+        .clone()
+    }
+
+    /// Converts a value to the numeric type of the elements, with `ToBigInt` or `ToNumber`.
+    pub(crate) fn to_numeric(self, value: &Value, context: &mut Context) -> Result<Numeric> {
+        if self.is_bigint() {
+            Ok(Numeric::BigInt(value.to_bigint(context)?))
+        } else {
+            Ok(Numeric::Number(value.to_number(context)?))
+        }
+    }
+
+    /// Decodes an element from its bytes.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-rawbytestonumeric
+    pub(crate) fn read(self, bytes: &[u8], little_endian: bool) -> Value {
+        macro_rules! read {
+            ($ty:ty) => {{
+                let mut raw = [0; std::mem::size_of::<$ty>()];
+                raw.copy_from_slice(&bytes[..std::mem::size_of::<$ty>()]);
+                if little_endian {
+                    <$ty>::from_le_bytes(raw)
+                } else {
+                    <$ty>::from_be_bytes(raw)
+                }
+            }};
+        }
+
+        match self {
+            Self::Int8 => Value::integer(i32::from(read!(i8))),
+            Self::Uint8 | Self::Uint8Clamped => Value::integer(i32::from(read!(u8))),
+            Self::Int16 => Value::integer(i32::from(read!(i16))),
+            Self::Uint16 => Value::integer(i32::from(read!(u16))),
+            Self::Int32 => Value::integer(read!(i32)),
+            Self::Uint32 => Value::from(read!(u32)),
+            Self::Float32 => Value::rational(f64::from(read!(f32))),
+            Self::Float64 => Value::rational(read!(f64)),
+            Self::BigInt64 => Value::from(BigInt::from(read!(i64))),
+            Self::BigUint64 => Value::from(BigInt::from(read!(u64))),
+        }
+    }
+
+    /// Encodes an element to its bytes.
+    ///
+    /// The value must have been converted with [`TypedArrayKind::to_numeric`] first.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-numerictorawbytes
+    pub(crate) fn write(self, value: &Numeric, bytes: &mut [u8], little_endian: bool) {
+        macro_rules! write {
+            ($value:expr) => {{
+                let value = $value;
+                let raw = if little_endian {
+                    value.to_le_bytes()
+                } else {
+                    value.to_be_bytes()
+                };
+                bytes[..raw.len()].copy_from_slice(&raw);
+            }};
+        }
+
+        match *value {
+            Numeric::Number(number) => match self {
+                Self::Int8 => write!(f64_to_int32(number) as i8),
+                Self::Uint8 => write!(f64_to_int32(number) as u8),
+                Self::Uint8Clamped => write!(to_uint8_clamp(number)),
+                Self::Int16 => write!(f64_to_int32(number) as i16),
+                Self::Uint16 => write!(f64_to_int32(number) as u16),
+                Self::Int32 => write!(f64_to_int32(number)),
+                Self::Uint32 => write!(f64_to_uint32(number)),
+                Self::Float32 => write!(number as f32),
+                Self::Float64 => write!(number),
+                Self::BigInt64 | Self::BigUint64 => unreachable!("bigint element set to a number"),
+            },
+            Numeric::BigInt(ref bigint) => match self {
+                Self::BigInt64 => write!(bigint.to_big_int64()),
+                Self::BigUint64 => write!(bigint.to_big_uint64()),
+                _ => unreachable!("number element set to a bigint"),
+            },
+        }
+    }
+}
+
+/// The abstract operation `ToUint8Clamp`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-touint8clamp
+fn to_uint8_clamp(number: f64) -> u8 {
+    if number.is_nan() || number <= 0.0 {
+        return 0;
+    }
+    if number >= 255.0 {
+        return 255;
+    }
+    let floor = number.floor();
+    let floor_int = floor as u8;
+    match number.partial_cmp(&(floor + 0.5)) {
+        Some(Ordering::Less) => floor_int,
+        Some(Ordering::Greater) => floor_int + 1,
+        _ if floor_int % 2 == 0 => floor_int,
+        _ => floor_int + 1,
+    }
+}
+
+/// The abstract operation `CanonicalNumericIndexString`, applied to a property key.
+///
+/// Returns the number a key stands for, if the key is the canonical string of a number.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-canonicalnumericindexstring
+pub(crate) fn canonical_numeric_index(key: &PropertyKey) -> Option<f64> {
+    match key {
+        PropertyKey::Index(index) => Some(f64::from(*index)),
+        PropertyKey::String(string) if string == "-0" => Some(-0.0),
+        PropertyKey::String(string) => {
+            let first = string.chars().next()?;
+            if !(first.is_ascii_digit() || first == '-' || first == 'I' || first == 'N') {
+                return None;
+            }
+            string
+                .parse::<f64>()
+                .ok()
+                .filter(|number| Number::to_native_string(*number) == string.as_str())
+        }
+        PropertyKey::Symbol(_) => None,
+    }
+}
+
+/// The data of a typed array object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct TypedArray {
+    kind: TypedArrayKind,
+    buffer: GcObject,
+    byte_offset: usize,
+    array_length: usize,
+}
+
+impl TypedArray {
+    /// The name of the `%TypedArray%` intrinsic.
+    const NAME: &'static str = "TypedArray";
+
+    /// The length of the typed array constructors.
+    const CONSTRUCTOR_LENGTH: usize = 3;
+
+    /// Returns the element type of the typed array.
+    #[inline]
+    pub fn kind(&self) -> TypedArrayKind {
+        self.kind
+    }
+
+    /// Returns the `ArrayBuffer` object viewed by the typed array.
+    #[inline]
+    pub fn buffer(&self) -> &GcObject {
+        &self.buffer
+    }
+
+    /// Returns `true` if the buffer viewed by the typed array has been detached.
+    #[inline]
+    pub fn is_detached(&self) -> bool {
+        self.buffer
+            .borrow()
+            .as_array_buffer()
+            .map_or(true, ArrayBuffer::is_detached)
+    }
+
+    /// Returns the offset of the typed array in its buffer, which is `0` if the buffer is
+    /// detached.
+    #[inline]
+    pub fn byte_offset(&self) -> usize {
+        if self.is_detached() {
+            0
+        } else {
+            self.byte_offset
+        }
+    }
+
+    /// Returns the number of elements of the typed array, which is `0` if the buffer is detached.
+    #[inline]
+    pub fn length(&self) -> usize {
+        if self.is_detached() {
+            0
+        } else {
+            self.array_length
+        }
+    }
+
+    /// Returns the length of the typed array in bytes, which is `0` if the buffer is detached.
+    #[inline]
+    pub fn byte_length(&self) -> usize {
+        self.length() * self.kind.element_size()
+    }
+
+    /// The abstract operation `IsValidIntegerIndex`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isvalidintegerindex
+    pub(crate) fn is_valid_integer_index(&self, index: f64) -> bool {
+        index.fract() == 0.0
+            && !(index == 0.0 && index.is_sign_negative())
+            && index >= 0.0
+            && index < self.length() as f64
+    }
+
+    /// Gets the element at the given index, or `None` if the index is out of bounds or the buffer
+    /// is detached.
+    pub(crate) fn get_element(&self, index: usize) -> Option<Value> {
+        if index >= self.array_length {
+            return None;
+        }
+        let size = self.kind.element_size();
+        let start = self.byte_offset + index * size;
+        let buffer = self.buffer.borrow();
+        let bytes = buffer.as_array_buffer()?.bytes()?;
+        Some(self.kind.read(&bytes[start..start + size], LITTLE_ENDIAN))
+    }
+
+    /// Sets the element at the given index, doing nothing if the index is out of bounds or the
+    /// buffer is detached.
+    pub(crate) fn set_element(&self, index: usize, value: &Numeric) {
+        if index >= self.array_length {
+            return;
+        }
+        let size = self.kind.element_size();
+        let start = self.byte_offset + index * size;
+        let mut buffer = self.buffer.borrow_mut();
+        if let Some(bytes) = buffer
+            .as_array_buffer_mut()
+            .and_then(ArrayBuffer::bytes_mut)
+        {
+            self.kind
+                .write(value, &mut bytes[start..start + size], LITTLE_ENDIAN);
+        }
+    }
+
+    /// The abstract operation `IntegerIndexedElementGet`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-integerindexedelementget
+    pub(crate) fn integer_indexed_element_get(&self, index: f64) -> Option<Value> {
+        if self.is_valid_integer_index(index) {
+            self.get_element(index as usize)
+        } else {
+            None
+        }
+    }
+
+    /// The abstract operation `IntegerIndexedElementSet`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-integerindexedelementset
+    pub(crate) fn integer_indexed_element_set(
+        &self,
+        index: f64,
+        value: &Value,
+        context: &mut Context,
+    ) -> Result<()> {
+        let value = self.kind.to_numeric(value, context)?;
+        if self.is_valid_integer_index(index) {
+            self.set_element(index as usize, &value);
+        }
+        Ok(())
+    }
+
+    /// The `[[GetOwnProperty]]` internal method of integer-indexed exotic objects, for a numeric
+    /// key.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-integer-indexed-exotic-objects-getownproperty-p
+    pub(crate) fn get_own_property(&self, index: f64) -> Option<PropertyDescriptor> {
+        self.integer_indexed_element_get(index)
+            .map(|value| DataDescriptor::new(value, Attribute::all()).into())
+    }
+
+    /// The `[[DefineOwnProperty]]` internal method of integer-indexed exotic objects, for a
+    /// numeric key.
+    ///
+    /// Property descriptors are always complete in this engine, so only accessor descriptors are
+    /// rejected: a data descriptor just sets the element.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-integer-indexed-exotic-objects-defineownproperty-p-desc
+    pub(crate) fn define_own_property(
+        &self,
+        index: f64,
+        desc: PropertyDescriptor,
+        context: &mut Context,
+    ) -> Result<bool> {
+        if !self.is_valid_integer_index(index) {
+            return Ok(false);
+        }
+        match desc {
+            PropertyDescriptor::Accessor(_) => Ok(false),
+            PropertyDescriptor::Data(ref desc) => {
+                self.integer_indexed_element_set(index, &desc.value(), context)?;
+                Ok(true)
+            }
+        }
+    }
+
+    /// The `[[OwnPropertyKeys]]` internal method of integer-indexed exotic objects.
+    ///
+    /// The keys of the elements come first, followed by the keys of the ordinary properties of
+    /// the typed array object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-integer-indexed-exotic-objects-ownpropertykeys
+    pub(crate) fn own_property_keys(&self, object: &GcObject) -> Vec<PropertyKey> {
+        let length = u32::try_from(self.length()).unwrap_or(u32::MAX);
+        (0..length)
+            .map(PropertyKey::from)
+            .chain(object.ordinary_own_property_keys())
+            .collect()
+    }
+
+    /// Initializes the `%TypedArray%` intrinsic object, which is the prototype of the typed array
+    /// constructors.
+    ///
+    /// It must be called after `Array` is initialized, since `%TypedArray%.prototype.toString` is
+    /// `Array.prototype.toString`.
+    pub(crate) fn init(context: &mut Context) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let get_species = FunctionBuilder::new(context, Self::get_species)
+            .name("get [Symbol.species]")
+            .constructable(false)
+            .build();
+        let get_buffer = FunctionBuilder::new(context, Self::get_buffer)
+            .name("get buffer")
+            .constructable(false)
+            .build();
+        let get_byte_length = FunctionBuilder::new(context, Self::get_byte_length)
+            .name("get byteLength")
+            .constructable(false)
+            .build();
+        let get_byte_offset = FunctionBuilder::new(context, Self::get_byte_offset)
+            .name("get byteOffset")
+            .constructable(false)
+            .build();
+        let get_length = FunctionBuilder::new(context, Self::get_length)
+            .name("get length")
+            .constructable(false)
+            .build();
+        let get_to_string_tag = FunctionBuilder::new(context, Self::get_to_string_tag)
+            .name("get [Symbol.toStringTag]")
+            .constructable(false)
+            .build();
+        let values = FunctionBuilder::new(context, Self::values)
+            .name("values")
+            .length(0)
+            .constructable(false)
+            .build();
+
+        let array_to_string = context
+            .standard_objects()
+            .array_object()
+            .prototype()
+            .ordinary_get_own_property(&"toString".into())
+            .and_then(|property| property.as_data_descriptor().map(DataDescriptor::value))
+            .unwrap_or_default();
+
+        ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().typed_array_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(0)
+        .static_method(Self::from, "from", 1)
+        .static_method(Self::of, "of", 0)
+        .static_accessor(
+            WellKnownSymbols::species(),
+            Some(get_species),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .accessor("buffer", Some(get_buffer), None, Attribute::CONFIGURABLE)
+        .accessor(
+            "byteLength",
+            Some(get_byte_length),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .accessor(
+            "byteOffset",
+            Some(get_byte_offset),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .accessor("length", Some(get_length), None, Attribute::CONFIGURABLE)
+        .accessor(
+            WellKnownSymbols::to_string_tag(),
+            Some(get_to_string_tag),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .method(Self::entries, "entries", 0)
+        .method(Self::every, "every", 1)
+        .method(Self::fill, "fill", 1)
+        .method(Self::filter, "filter", 1)
+        .method(Self::find, "find", 1)
+        .method(Self::find_index, "findIndex", 1)
+        .method(Self::for_each, "forEach", 1)
+        .method(Self::includes, "includes", 1)
+        .method(Self::index_of, "indexOf", 1)
+        .method(Self::join, "join", 1)
+        .method(Self::keys, "keys", 0)
+        .method(Self::last_index_of, "lastIndexOf", 1)
+        .method(Self::map, "map", 1)
+        .method(Self::reduce, "reduce", 1)
+        .method(Self::reverse, "reverse", 0)
+        .method(Self::set, "set", 1)
+        .method(Self::slice, "slice", 2)
+        .method(Self::some, "some", 1)
+        .method(Self::sort, "sort", 1)
+        .method(Self::subarray, "subarray", 2)
+        .property(
+            "values",
+            values.clone(),
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .property(
+            WellKnownSymbols::iterator(),
+            values,
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .property(
+            "toString",
+            array_to_string,
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build();
+    }
+
+    /// Initializes the constructor of typed arrays of the given kind.
+    fn init_kind(
+        context: &mut Context,
+        kind: TypedArrayKind,
+        constructor: crate::builtins::function::NativeFunction,
+    ) -> GcObject {
+        let _timer = BoaProfiler::global().start_event(kind.name(), "init");
+
+        let typed_array = context.standard_objects().typed_array_object().clone();
+        let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT;
+
+        let mut object = ConstructorBuilder::with_standard_object(
+            context,
+            constructor,
+            kind.standard_constructor(context),
+        )
+        .name(kind.name())
+        .length(Self::CONSTRUCTOR_LENGTH)
+        .static_property("BYTES_PER_ELEMENT", kind.element_size(), attribute)
+        .property("BYTES_PER_ELEMENT", kind.element_size(), attribute)
+        .inherit(typed_array.prototype().into())
+        .build();
+
+        object.set_prototype_instance(typed_array.constructor().into());
+        object
+    }
+
+    /// `%TypedArray%`
+    ///
+    /// The intrinsic cannot be called nor constructed directly.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%
+    fn constructor(_: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        context.throw_type_error("the TypedArray constructor cannot be invoked directly")
+    }
+
+    /// `new TypedArray( ...args )`
+    ///
+    /// Constructs a typed array from a length, another typed array, an `ArrayBuffer` or an
+    /// iterable or array-like object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-typedarray
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/TypedArray
+    fn construct(
+        kind: TypedArrayKind,
+        new_target: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        if new_target.is_undefined() {
+            return context.throw_type_error(format!(
+                "calling a builtin {} constructor without new is forbidden",
+                kind.name()
+            ));
+        }
+
+        let prototype = Self::prototype_from_constructor(kind, new_target, context)?;
+        let first = args.get(0).cloned().unwrap_or_default();
+
+        let object = match first.as_object() {
+            None => {
+                let length = first.to_index(context)?;
+                Self::allocate(kind, prototype, length, context)?
+            }
+            Some(source) => {
+                let source_typed_array = source.borrow().as_typed_array().cloned();
+                let is_buffer = source.borrow().as_array_buffer().is_some();
+                if let Some(source) = source_typed_array {
+                    Self::from_typed_array(kind, prototype, &source, context)?
+                } else if is_buffer {
+                    Self::from_array_buffer(kind, prototype, source, args, context)?
+                } else {
+                    let values = Self::iterable_or_array_like_to_list(&first, context)?;
+                    let object = Self::allocate(kind, prototype, values.len(), context)?;
+                    let typed_array = Self::this_typed_array(&object.clone().into(), context)?;
+                    for (index, value) in values.iter().enumerate() {
+                        typed_array.integer_indexed_element_set(index as f64, value, context)?;
+                    }
+                    object
+                }
+            }
+        };
+
+        Ok(object.into())
+    }
+
+    /// Gets the prototype of a typed array created by the given constructor.
+    fn prototype_from_constructor(
+        kind: TypedArrayKind,
+        new_target: &Value,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let prototype = match new_target.as_object() {
+            Some(object) => object.get(&PROTOTYPE.into(), new_target.clone(), context)?,
+            None => Value::undefined(),
+        };
+        if prototype.is_object() {
+            Ok(prototype)
+        } else {
+            Ok(kind.standard_constructor(context).prototype().into())
+        }
+    }
+
+    /// Creates a typed array object viewing the given buffer.
+    fn create(
+        kind: TypedArrayKind,
+        prototype: Value,
+        buffer: GcObject,
+        byte_offset: usize,
+        array_length: usize,
+    ) -> GcObject {
+        GcObject::new(Object::with_prototype(
+            prototype,
+            ObjectData::TypedArray(Self {
+                kind,
+                buffer,
+                byte_offset,
+                array_length,
+            }),
+        ))
+    }
+
+    /// The abstract operation `AllocateTypedArray`, with a length.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-allocatetypedarray
+    pub(crate) fn allocate(
+        kind: TypedArrayKind,
+        prototype: Value,
+        length: usize,
+        context: &mut Context,
+    ) -> Result<GcObject> {
+        let byte_length = match length.checked_mul(kind.element_size()) {
+            Some(byte_length) => byte_length,
+            None => return Err(context.construct_range_error("invalid typed array length")),
+        };
+        let constructor = context
+            .standard_objects()
+            .array_buffer_object()
+            .constructor();
+        let buffer = ArrayBuffer::allocate(&constructor.into(), byte_length, context)?;
+        Ok(Self::create(kind, prototype, buffer, 0, length))
+    }
+
+    /// The abstract operation `InitializeTypedArrayFromTypedArray`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-initializetypedarrayfromtypedarray
+    fn from_typed_array(
+        kind: TypedArrayKind,
+        prototype: Value,
+        source: &TypedArray,
+        context: &mut Context,
+    ) -> Result<GcObject> {
+        if source.is_detached() {
+            return Err(
+                context.construct_type_error("cannot construct from a detached typed array")
+            );
+        }
+        if source.kind.is_bigint() != kind.is_bigint() {
+            return Err(context.construct_type_error(
+                "cannot mix BigInt and other types, use explicit conversions",
+            ));
+        }
+
+        let length = source.length();
+        let object = Self::allocate(kind, prototype, length, context)?;
+        let target = Self::this_typed_array(&object.clone().into(), context)?;
+        for index in 0..length {
+            let value = source.get_element(index).unwrap_or_default();
+            let value = kind.to_numeric(&value, context)?;
+            target.set_element(index, &value);
+        }
+        Ok(object)
+    }
+
+    /// The abstract operation `InitializeTypedArrayFromArrayBuffer`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-initializetypedarrayfromarraybuffer
+    fn from_array_buffer(
+        kind: TypedArrayKind,
+        prototype: Value,
+        buffer: GcObject,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<GcObject> {
+        let element_size = kind.element_size();
+        let offset = args.get(1).cloned().unwrap_or_default().to_index(context)?;
+        if offset % element_size != 0 {
+            return Err(context.construct_range_error(format!(
+                "start offset of {} should be a multiple of {}",
+                kind.name(),
+                element_size
+            )));
+        }
+        let length = match args.get(2) {
+            Some(length) if !length.is_undefined() => Some(length.to_index(context)?),
+            _ => None,
+        };
+
+        let buffer_byte_length = match buffer.borrow().as_array_buffer() {
+            Some(data) if !data.is_detached() => data.byte_length(),
+            _ => {
+                return Err(
+                    context.construct_type_error("cannot construct from a detached ArrayBuffer")
+                )
+            }
+        };
+
+        let byte_length = if let Some(length) = length {
+            let byte_length = length.checked_mul(element_size);
+            match byte_length {
+                Some(byte_length) if offset + byte_length <= buffer_byte_length => byte_length,
+                _ => {
+                    return Err(context
+                        .construct_range_error(format!("invalid typed array length: {}", length)))
+                }
+            }
+        } else {
+            if buffer_byte_length % element_size != 0 {
+                return Err(context.construct_range_error(format!(
+                    "byte length of {} should be a multiple of {}",
+                    kind.name(),
+                    element_size
+                )));
+            }
+            if offset > buffer_byte_length {
+                return Err(context.construct_range_error(format!(
+                    "start offset {} is outside the bounds of the buffer",
+                    offset
+                )));
+            }
+            buffer_byte_length - offset
+        };
+
+        Ok(Self::create(
+            kind,
+            prototype,
+            buffer,
+            offset,
+            byte_length / element_size,
+        ))
+    }
+
+    /// Collects the values of an iterable object, or of an array-like object if it is not
+    /// iterable.
+    fn iterable_or_array_like_to_list(source: &Value, context: &mut Context) -> Result<Vec<Value>> {
+        let object = source.to_object(context)?;
+        let iterator = object.get(
+            &WellKnownSymbols::iterator().into(),
+            source.clone(),
+            context,
+        )?;
+
+        if !iterator.is_null_or_undefined() {
+            let iterator = get_iterator(context, source.clone())?;
+            let mut values = Vec::new();
+            loop {
+                let next = iterator.next(context)?;
+                if next.is_done() {
+                    return Ok(values);
+                }
+                values.push(next.value());
+            }
+        }
+
+        let length = object
+            .get(&"length".into(), source.clone(), context)?
+            .to_length(context)?;
+        let mut values = Vec::with_capacity(length);
+        for index in 0..length {
+            values.push(object.get(&index.into(), source.clone(), context)?);
+        }
+        Ok(values)
+    }
+
+    /// The abstract operation `ValidateTypedArray`.
+    ///
+    /// Returns the data of the typed array, throwing a `TypeError` if the value is not a typed
+    /// array or if its buffer is detached.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-validatetypedarray
+    fn this_typed_array(this: &Value, context: &mut Context) -> Result<TypedArray> {
+        let typed_array = this
+            .as_object()
+            .and_then(|object| object.borrow().as_typed_array().cloned());
+        match typed_array {
+            Some(typed_array) if !typed_array.is_detached() => Ok(typed_array),
+            Some(_) => Err(context.construct_type_error("typed array is detached")),
+            None => Err(context.construct_type_error("value is not a typed array")),
+        }
+    }
+
+    /// Gets the data of a typed array for an accessor, which does not throw on detached buffers.
+    fn this_typed_array_or_detached(
+        this: &Value,
+        name: &str,
+        context: &mut Context,
+    ) -> Result<TypedArray> {
+        let typed_array = this
+            .as_object()
+            .and_then(|object| object.borrow().as_typed_array().cloned());
+        match typed_array {
+            Some(typed_array) => Ok(typed_array),
+            None => Err(context.construct_type_error(format!(
+                "%TypedArray%.prototype.{} called on incompatible receiver",
+                name
+            ))),
+        }
+    }
+
+    /// The abstract operation `TypedArrayCreate`.
+    ///
+    /// Constructs a typed array with the given constructor, checking that the result is a typed
+    /// array with at least `length` elements.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#typedarray-create
+    fn typed_array_create(
+        constructor: &Value,
+        args: &[Value],
+        length: Option<usize>,
+        context: &mut Context,
+    ) -> Result<(GcObject, TypedArray)> {
+        let new = match constructor.as_object() {
+            Some(object) if object.is_constructable() => {
+                object.construct(args, constructor.clone(), context)?
+            }
+            _ => return Err(context.construct_type_error("value is not a constructor")),
+        };
+        let typed_array = Self::this_typed_array(&new, context)?;
+        if let Some(length) = length {
+            if typed_array.length() < length {
+                return Err(context.construct_type_error("derived typed array is too small"));
+            }
+        }
+        let object = new.as_object().expect("typed arrays are objects");
+        Ok((object, typed_array))
+    }
+
+    /// The abstract operation `TypedArraySpeciesCreate`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#typedarray-species-create
+    fn species_create(
+        exemplar: &Value,
+        kind: TypedArrayKind,
+        args: &[Value],
+        length: Option<usize>,
+        context: &mut Context,
+    ) -> Result<(GcObject, TypedArray)> {
+        let default_constructor = kind.standard_constructor(context).constructor();
+        let constructor = exemplar
+            .as_object()
+            .expect("exemplar must be a typed array")
+            .species_constructor(default_constructor.into(), context)?;
+        let result = Self::typed_array_create(&constructor, args, length, context)?;
+        if result.1.kind.is_bigint() != kind.is_bigint() {
+            return Err(context.construct_type_error(
+                "species constructor returned a typed array of another content type",
+            ));
+        }
+        Ok(result)
+    }
+
+    /// Gets the callback function argument of an iteration method.
+    fn callback(args: &[Value], name: &str, context: &mut Context) -> Result<GcObject> {
+        match args.get(0).and_then(Value::as_object) {
+            Some(callback) if callback.is_callable() => Ok(callback),
+            _ => Err(context.construct_type_error(format!(
+                "%TypedArray%.prototype.{}: callback is not callable",
+                name
+            ))),
+        }
+    }
+
+    /// `TypedArray.from( source [ , mapfn [ , thisArg ] ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.from
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/from
+    fn from(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        if !this
+            .as_object()
+            .map_or(false, |this| this.is_constructable())
+        {
+            return context.throw_type_error("TypedArray.from: this is not a constructor");
+        }
+        let map_fn = match args.get(1) {
+            Some(map_fn) if !map_fn.is_undefined() => match map_fn.as_object() {
+                Some(map_fn) if map_fn.is_callable() => Some(map_fn),
+                _ => return context.throw_type_error("TypedArray.from: mapper is not callable"),
+            },
+            _ => None,
+        };
+        let this_arg = args.get(2).cloned().unwrap_or_default();
+
+        let source = args.get(0).cloned().unwrap_or_default();
+        let values = Self::iterable_or_array_like_to_list(&source, context)?;
+        let (object, typed_array) =
+            Self::typed_array_create(this, &[values.len().into()], Some(values.len()), context)?;
+
+        for (index, value) in values.into_iter().enumerate() {
+            let value = match map_fn {
+                Some(ref map_fn) => map_fn.call(&this_arg, &[value, index.into()], context)?,
+                None => value,
+            };
+            typed_array.integer_indexed_element_set(index as f64, &value, context)?;
+        }
+
+        Ok(object.into())
+    }
+
+    /// `TypedArray.of( ...items )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.of
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/of
+    fn of(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        if !this
+            .as_object()
+            .map_or(false, |this| this.is_constructable())
+        {
+            return context.throw_type_error("TypedArray.of: this is not a constructor");
+        }
+        let (object, typed_array) =
+            Self::typed_array_create(this, &[args.len().into()], Some(args.len()), context)?;
+        for (index, value) in args.iter().enumerate() {
+            typed_array.integer_indexed_element_set(index as f64, value, context)?;
+        }
+        Ok(object.into())
+    }
+
+    /// `get %TypedArray% [ @@species ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%-@@species
+    fn get_species(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
+        Ok(this.clone())
+    }
+
+    /// `get %TypedArray%.prototype.buffer`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.buffer
+    fn get_buffer(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let typed_array = Self::this_typed_array_or_detached(this, "buffer", context)?;
+        Ok(typed_array.buffer.clone().into())
+    }
+
+    /// `get %TypedArray%.prototype.byteLength`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.bytelength
+    fn get_byte_length(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let typed_array = Self::this_typed_array_or_detached(this, "byteLength", context)?;
+        Ok(typed_array.byte_length().into())
+    }
+
+    /// `get %TypedArray%.prototype.byteOffset`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.byteoffset
+    fn get_byte_offset(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let typed_array = Self::this_typed_array_or_detached(this, "byteOffset", context)?;
+        Ok(typed_array.byte_offset().into())
+    }
+
+    /// `get %TypedArray%.prototype.length`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.length
+    fn get_length(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let typed_array = Self::this_typed_array_or_detached(this, "length", context)?;
+        Ok(typed_array.length().into())
+    }
+
+    /// `get %TypedArray%.prototype [ @@toStringTag ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype-@@tostringtag
+    fn get_to_string_tag(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
+        Ok(this
+            .as_object()
+            .and_then(|object| object.borrow().as_typed_array().map(|t| t.kind.name()))
+            .map(Value::from)
+            .unwrap_or_default())
+    }
+
+    /// `%TypedArray%.prototype.entries( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.entries
+    fn entries(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        Self::this_typed_array(this, context)?;
+        Ok(ArrayIterator::create_array_iterator(
+            context,
+            this.clone(),
+            ArrayIterationKind::KeyAndValue,
+        ))
+    }
+
+    /// `%TypedArray%.prototype.keys( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.keys
+    fn keys(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        Self::this_typed_array(this, context)?;
+        Ok(ArrayIterator::create_array_iterator(
+            context,
+            this.clone(),
+            ArrayIterationKind::Key,
+        ))
+    }
+
+    /// `%TypedArray%.prototype.values( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.values
+    fn values(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        Self::this_typed_array(this, context)?;
+        Ok(ArrayIterator::create_array_iterator(
+            context,
+            this.clone(),
+            ArrayIterationKind::Value,
+        ))
+    }
+
+    /// `%TypedArray%.prototype.every( callbackfn [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.every
+    fn every(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let typed_array = Self::this_typed_array(this, context)?;
+        let callback = Self::callback(args, "every", context)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+        for index in 0..typed_array.array_length {
+            let value = typed_array.get_element(index).unwrap_or_default();
+            let result = callback.call(&this_arg, &[value, index.into(), this.clone()], context)?;
+            if !result.to_boolean() {
+                return Ok(false.into());
+            }
+        }
+        Ok(true.into())
+    }
+
+    /// `%TypedArray%.prototype.fill( value [ , start [ , end ] ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.fill
+    fn fill(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let typed_array = Self::this_typed_array(this, context)?;
+        let length = typed_array.array_length;
+        let value = args.get(0).cloned().unwrap_or_default();
+        let value = typed_array.kind.to_numeric(&value, context)?;
+        let start = ArrayBuffer::relative_index(args.get(1), length, 0, context)?;
+        let end = ArrayBuffer::relative_index(args.get(2), length, length, context)?;
+        Self::this_typed_array(this, context)?;
+        for index in start..end {
+            typed_array.set_element(index, &value);
+        }
+        Ok(this.clone())
+    }
+
+    /// `%TypedArray%.prototype.filter( callbackfn [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.filter
+    fn filter(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let typed_array = Self::this_typed_array(this, context)?;
+        let callback = Self::callback(args, "filter", context)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+        let mut kept = Vec::new();
+        for index in 0..typed_array.array_length {
+            let value = typed_array.get_element(index).unwrap_or_default();
+            let selected = callback
+                .call(
+                    &this_arg,
+                    &[value.clone(), index.into(), this.clone()],
+                    context,
+                )?
+                .to_boolean();
+            if selected {
+                kept.push(value);
+            }
+        }
+
+        let (object, result) = Self::species_create(
+            this,
+            typed_array.kind,
+            &[kept.len().into()],
+            Some(kept.len()),
+            context,
+        )?;
+        for (index, value) in kept.iter().enumerate() {
+            result.integer_indexed_element_set(index as f64, value, context)?;
+        }
+        Ok(object.into())
+    }
+
+    /// `%TypedArray%.prototype.find( predicate [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.find
+    fn find(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let typed_array = Self::this_typed_array(this, context)?;
+        let predicate = Self::callback(args, "find", context)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+        for index in 0..typed_array.array_length {
+            let value = typed_array.get_element(index).unwrap_or_default();
+            let found = predicate
+                .call(
+                    &this_arg,
+                    &[value.clone(), index.into(), this.clone()],
+                    context,
+                )?
+                .to_boolean();
+            if found {
+                return Ok(value);
+            }
+        }
+        Ok(Value::undefined())
+    }
+
+    /// `%TypedArray%.prototype.findIndex( predicate [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.findindex
+    fn find_index(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let typed_array = Self::this_typed_array(this, context)?;
+        let predicate = Self::callback(args, "findIndex", context)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+        for index in 0..typed_array.array_length {
+            let value = typed_array.get_element(index).unwrap_or_default();
+            let found = predicate
+                .call(&this_arg, &[value, index.into(), this.clone()], context)?
+                .to_boolean();
+            if found {
+                return Ok(index.into());
+            }
+        }
+        Ok((-1).into())
+    }
+
+    /// `%TypedArray%.prototype.forEach( callbackfn [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.foreach
+    fn for_each(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let typed_array = Self::this_typed_array(this, context)?;
+        let callback = Self::callback(args, "forEach", context)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+        for index in 0..typed_array.array_length {
+            let value = typed_array.get_element(index).unwrap_or_default();
+            callback.call(&this_arg, &[value, index.into(), this.clone()], context)?;
+        }
+        Ok(Value::undefined())
+    }
+
+    /// `%TypedArray%.prototype.includes( searchElement [ , fromIndex ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.includes
+    fn includes(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let typed_array = Self::this_typed_array(this, context)?;
+        let length = typed_array.array_length;
+        if length == 0 {
+            return Ok(false.into());
+        }
+        let search = args.get(0).cloned().unwrap_or_default();
+        let start = ArrayBuffer::relative_index(args.get(1), length, 0, context)?;
+        let found = (start..length).any(|index| {
+            same_value_zero(&typed_array.get_element(index).unwrap_or_default(), &search)
+        });
+        Ok(found.into())
+    }
+
+    /// `%TypedArray%.prototype.indexOf( searchElement [ , fromIndex ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.indexof
+    fn index_of(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let typed_array = Self::this_typed_array(this, context)?;
+        let length = typed_array.array_length;
+        if length == 0 {
+            return Ok((-1).into());
+        }
+        let search = args.get(0).cloned().unwrap_or_default();
+        let start = ArrayBuffer::relative_index(args.get(1), length, 0, context)?;
+        let found = (start..length).find(|&index| {
+            typed_array
+                .get_element(index)
+                .map_or(false, |value| value.strict_equals(&search))
+        });
+        Ok(found.map_or_else(|| (-1).into(), Value::from))
+    }
+
+    /// `%TypedArray%.prototype.join( separator )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.join
+    fn join(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let typed_array = Self::this_typed_array(this, context)?;
+        let separator = match args.get(0) {
+            Some(separator) if !separator.is_undefined() => separator.to_string(context)?,
+            _ => ",".into(),
+        };
+        let mut elements = Vec::with_capacity(typed_array.array_length);
+        for index in 0..typed_array.array_length {
+            elements.push(match typed_array.get_element(index) {
+                Some(value) => value.to_string(context)?.to_string(),
+                None => String::new(),
+            });
+        }
+        Ok(elements.join(&separator).into())
+    }
+
+    /// `%TypedArray%.prototype.lastIndexOf( searchElement [ , fromIndex ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.lastindexof
+    fn last_index_of(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let typed_array = Self::this_typed_array(this, context)?;
+        let length = typed_array.array_length;
+        if length == 0 {
+            return Ok((-1).into());
+        }
+        let search = args.get(0).cloned().unwrap_or_default();
+        let end = match args.get(1) {
+            Some(from) => match from.to_integer_or_infinity(context)? {
+                IntegerOrInfinity::NegativeInfinity => return Ok((-1).into()),
+                IntegerOrInfinity::Integer(i) if i < 0 => {
+                    let i = length as i64 + i;
+                    if i < 0 {
+                        return Ok((-1).into());
+                    }
+                    i as usize
+                }
+                IntegerOrInfinity::Integer(i) => (i as usize).min(length - 1),
+                IntegerOrInfinity::PositiveInfinity => length - 1,
+            },
+            None => length - 1,
+        };
+        let found = (0..=end).rev().find(|&index| {
+            typed_array
+                .get_element(index)
+                .map_or(false, |value| value.strict_equals(&search))
+        });
+        Ok(found.map_or_else(|| (-1).into(), Value::from))
+    }
+
+    /// `%TypedArray%.prototype.map( callbackfn [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.map
+    fn map(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let typed_array = Self::this_typed_array(this, context)?;
+        let callback = Self::callback(args, "map", context)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+        let length = typed_array.array_length;
+        let (object, result) = Self::species_create(
+            this,
+            typed_array.kind,
+            &[length.into()],
+            Some(length),
+            context,
+        )?;
+        for index in 0..length {
+            let value = typed_array.get_element(index).unwrap_or_default();
+            let mapped = callback.call(&this_arg, &[value, index.into(), this.clone()], context)?;
+            result.integer_indexed_element_set(index as f64, &mapped, context)?;
+        }
+        Ok(object.into())
+    }
+
+    /// `%TypedArray%.prototype.reduce( callbackfn [ , initialValue ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.reduce
+    fn reduce(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let typed_array = Self::this_typed_array(this, context)?;
+        let callback = Self::callback(args, "reduce", context)?;
+        let length = typed_array.array_length;
+        let (mut accumulator, start) = match args.get(1) {
+            Some(initial) => (initial.clone(), 0),
+            None if length == 0 => {
+                return context
+                    .throw_type_error("reduce of an empty typed array with no initial value")
+            }
+            None => (typed_array.get_element(0).unwrap_or_default(), 1),
+        };
+        for index in start..length {
+            let value = typed_array.get_element(index).unwrap_or_default();
+            accumulator = callback.call(
+                &Value::undefined(),
+                &[accumulator, value, index.into(), this.clone()],
+                context,
+            )?;
+        }
+        Ok(accumulator)
+    }
+
+    /// `%TypedArray%.prototype.reverse( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.reverse
+    fn reverse(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let typed_array = Self::this_typed_array(this, context)?;
+        let size = typed_array.kind.element_size();
+        let start = typed_array.byte_offset;
+        let end = start + typed_array.array_length * size;
+        let mut buffer = typed_array.buffer.borrow_mut();
+        if let Some(bytes) = buffer
+            .as_array_buffer_mut()
+            .and_then(ArrayBuffer::bytes_mut)
+        {
+            let bytes = &mut bytes[start..end];
+            bytes.reverse();
+            // Reversing the bytes reversed the elements, and the bytes of each element.
+            for element in bytes.chunks_exact_mut(size) {
+                element.reverse();
+            }
+        }
+        Ok(this.clone())
+    }
+
+    /// `%TypedArray%.prototype.set( source [ , offset ] )`
+    ///
+    /// Copies the elements of a typed array or an array-like object into the typed array,
+    /// starting at `offset`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.set
+    fn set(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let target = Self::this_typed_array_or_detached(this, "set", context)?;
+        let offset = match args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .to_integer_or_infinity(context)?
+        {
+            IntegerOrInfinity::Integer(offset) if offset >= 0 => offset as usize,
+            IntegerOrInfinity::PositiveInfinity => usize::MAX,
+            _ => return context.throw_range_error("offset is out of bounds"),
+        };
+        if target.is_detached() {
+            return context.throw_type_error("typed array is detached");
+        }
+        let target_length = target.array_length;
+
+        let source = args.get(0).cloned().unwrap_or_default();
+        let source_typed_array = source
+            .as_object()
+            .and_then(|object| object.borrow().as_typed_array().cloned());
+
+        if let Some(source) = source_typed_array {
+            if source.is_detached() {
+                return context.throw_type_error("source typed array is detached");
+            }
+            if source.kind.is_bigint() != target.kind.is_bigint() {
+                return context.throw_type_error(
+                    "cannot mix BigInt and other types, use explicit conversions",
+                );
+            }
+            let source_length = source.array_length;
+            if source_length > target_length.saturating_sub(offset) || offset > target_length {
+                return context.throw_range_error("source is too large");
+            }
+            // Read every element first, since both arrays may view the same buffer.
+            let values = (0..source_length)
+                .map(|index| source.get_element(index).unwrap_or_default())
+                .collect::<Vec<_>>();
+            for (index, value) in values.iter().enumerate() {
+                let value = target.kind.to_numeric(value, context)?;
+                target.set_element(offset + index, &value);
+            }
+        } else {
+            let source = source.to_object(context)?;
+            let source_length = source
+                .get(&"length".into(), source.clone().into(), context)?
+                .to_length(context)?;
+            if source_length > target_length.saturating_sub(offset) || offset > target_length {
+                return context.throw_range_error("source is too large");
+            }
+            for index in 0..source_length {
+                let value = source.get(&index.into(), source.clone().into(), context)?;
+                target.integer_indexed_element_set((offset + index) as f64, &value, context)?;
+            }
+        }
+
+        Ok(Value::undefined())
+    }
+
+    /// `%TypedArray%.prototype.slice( start, end )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.slice
+    fn slice(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let typed_array = Self::this_typed_array(this, context)?;
+        let length = typed_array.array_length;
+        let start = ArrayBuffer::relative_index(args.get(0), length, 0, context)?;
+        let end = ArrayBuffer::relative_index(args.get(1), length, length, context)?;
+        let count = end.saturating_sub(start);
+
+        let (object, result) = Self::species_create(
+            this,
+            typed_array.kind,
+            &[count.into()],
+            Some(count),
+            context,
+        )?;
+        if count > 0 {
+            Self::this_typed_array(this, context)?;
+            for index in 0..count {
+                let value = typed_array.get_element(start + index).unwrap_or_default();
+                let value = result.kind.to_numeric(&value, context)?;
+                result.set_element(index, &value);
+            }
+        }
+        Ok(object.into())
+    }
+
+    /// `%TypedArray%.prototype.some( callbackfn [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.some
+    fn some(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let typed_array = Self::this_typed_array(this, context)?;
+        let callback = Self::callback(args, "some", context)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+        for index in 0..typed_array.array_length {
+            let value = typed_array.get_element(index).unwrap_or_default();
+            let result = callback.call(&this_arg, &[value, index.into(), this.clone()], context)?;
+            if result.to_boolean() {
+                return Ok(true.into());
+            }
+        }
+        Ok(false.into())
+    }
+
+    /// `%TypedArray%.prototype.sort( comparefn )`
+    ///
+    /// Sorts the elements numerically, or with the given comparison function.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.sort
+    fn sort(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let compare_fn = match args.get(0) {
+            Some(compare_fn) if !compare_fn.is_undefined() => match compare_fn.as_object() {
+                Some(compare_fn) if compare_fn.is_callable() => Some(compare_fn),
+                _ => {
+                    return context.throw_type_error(
+                        "%TypedArray%.prototype.sort: comparator is not callable",
+                    )
+                }
+            },
+            _ => None,
+        };
+        let typed_array = Self::this_typed_array(this, context)?;
+
+        let mut values = (0..typed_array.array_length)
+            .map(|index| typed_array.get_element(index).unwrap_or_default())
+            .collect::<Vec<_>>();
+
+        let mut error = None;
+        values.sort_by(|x, y| {
+            if error.is_some() {
+                return Ordering::Equal;
+            }
+            match compare_fn {
+                Some(ref compare_fn) => {
+                    let result = compare_fn
+                        .call(&Value::undefined(), &[x.clone(), y.clone()], context)
+                        .and_then(|result| result.to_number(context));
+                    match result {
+                        Ok(result) => result.partial_cmp(&0.0).unwrap_or(Ordering::Equal),
+                        Err(err) => {
+                            error = Some(err);
+                            Ordering::Equal
+                        }
+                    }
+                }
+                None => Self::compare_elements(x, y),
+            }
+        });
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        for (index, value) in values.iter().enumerate() {
+            let value = typed_array.kind.to_numeric(value, context)?;
+            typed_array.set_element(index, &value);
+        }
+        Ok(this.clone())
+    }
+
+    /// The default comparison of typed array elements, which sorts `NaN` last and `-0` before `+0`.
+    fn compare_elements(x: &Value, y: &Value) -> Ordering {
+        if let (Value::BigInt(x), Value::BigInt(y)) = (x, y) {
+            return x.as_inner().cmp(y.as_inner());
+        }
+
+        let x = x.as_number().unwrap_or(f64::NAN);
+        let y = y.as_number().unwrap_or(f64::NAN);
+        match (x.is_nan(), y.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) if x == 0.0 && y == 0.0 => {
+                y.is_sign_negative().cmp(&x.is_sign_negative())
+            }
+            (false, false) => x.partial_cmp(&y).unwrap_or(Ordering::Equal),
+        }
+    }
+
+    /// `%TypedArray%.prototype.subarray( begin, end )`
+    ///
+    /// Returns a new typed array viewing the same buffer, from `begin` up to `end`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.subarray
+    fn subarray(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let typed_array = Self::this_typed_array_or_detached(this, "subarray", context)?;
+        let length = typed_array.length();
+        let begin = ArrayBuffer::relative_index(args.get(0), length, 0, context)?;
+        let end = ArrayBuffer::relative_index(args.get(1), length, length, context)?;
+        let new_length = end.saturating_sub(begin);
+        let begin_byte_offset = typed_array.byte_offset + begin * typed_array.kind.element_size();
+
+        let (object, _) = Self::species_create(
+            this,
+            typed_array.kind,
+            &[
+                typed_array.buffer.clone().into(),
+                begin_byte_offset.into(),
+                new_length.into(),
+            ],
+            None,
+            context,
+        )?;
+        Ok(object.into())
+    }
+}
+
+macro_rules! typed_array_constructor {
+    ($ty:ident, $kind:ident) => {
+        #[doc = concat!("The `", stringify!($ty), "` constructor.")]
+        #[derive(Debug, Clone, Copy)]
+        pub(crate) struct $ty;
+
+        impl BuiltIn for $ty {
+            const NAME: &'static str = stringify!($ty);
+
+            fn attribute() -> Attribute {
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+            }
+
+            fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+                let constructor =
+                    TypedArray::init_kind(context, TypedArrayKind::$kind, Self::constructor);
+                (Self::NAME, constructor.into(), Self::attribute())
+            }
+        }
+
+        impl $ty {
+            fn constructor(
+                new_target: &Value,
+                args: &[Value],
+                context: &mut Context,
+            ) -> Result<Value> {
+                TypedArray::construct(TypedArrayKind::$kind, new_target, args, context)
+            }
+        }
+    };
+}
+
+typed_array_constructor!(Int8Array, Int8);
+typed_array_constructor!(Uint8Array, Uint8);
+typed_array_constructor!(Uint8ClampedArray, Uint8Clamped);
+typed_array_constructor!(Int16Array, Int16);
+typed_array_constructor!(Uint16Array, Uint16);
+typed_array_constructor!(Int32Array, Int32);
+typed_array_constructor!(Uint32Array, Uint32);
+typed_array_constructor!(Float32Array, Float32);
+typed_array_constructor!(Float64Array, Float64);
+typed_array_constructor!(BigInt64Array, BigInt64);
+typed_array_constructor!(BigUint64Array, BigUint64);
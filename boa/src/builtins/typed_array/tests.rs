@@ -0,0 +1,296 @@
+use crate::{forward, Context};
+
+#[test]
+fn constructors() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "new Int16Array(3).length"), "3");
+    assert_eq!(forward(&mut context, "new Int16Array(3).byteLength"), "6");
+    assert_eq!(
+        forward(&mut context, "new Float64Array([1, 2.5]).join()"),
+        "\"1,2.5\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Uint8Array(new Set([3, 4])).join()"),
+        "\"3,4\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Int8Array({ length: 2, 0: 7 }).join()"),
+        "\"7,0\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Uint16Array(new Int8Array([-1, 2])).join()"
+        ),
+        "\"65535,2\""
+    );
+    assert_eq!(forward(&mut context, "Int32Array.BYTES_PER_ELEMENT"), "4");
+    assert_eq!(
+        forward(&mut context, "new BigInt64Array(1).BYTES_PER_ELEMENT"),
+        "8"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Int8Array(1) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(Int8Array) === Object.getPrototypeOf(Float32Array)"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new (Object.getPrototypeOf(Int8Array))() } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn buffer_views() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var buffer = new ArrayBuffer(8);
+        var bytes = new Uint8Array(buffer);
+        var words = new Uint16Array(buffer, 2, 2);
+        words[0] = 0xffff;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "words.byteOffset"), "2");
+    assert_eq!(forward(&mut context, "bytes[2] + bytes[3]"), "510");
+    assert_eq!(forward(&mut context, "words.buffer === buffer"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Uint16Array(buffer, 1) } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Uint32Array(buffer, 4, 2) } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn element_conversions() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "new Int8Array([127, 128, -129, 1.9]).join()"),
+        "\"127,-128,127,1\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Uint8Array([256, -1, NaN]).join()"),
+        "\"0,255,0\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Uint8ClampedArray([300, -5, 1.5, 2.5, 0.5]).join()"
+        ),
+        "\"255,0,2,2,0\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Float32Array([0.1])[0] === Math.fround(0.1)"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "new BigUint64Array([-1n])[0]"),
+        "18446744073709551615n"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new BigInt64Array([undefined]) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Int8Array(new BigInt64Array(1)) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn integer_indexed_properties() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var array = new Int8Array(2);
+        array[5] = 1;
+        array["-0"] = 1;
+        array.foo = "bar";
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "array[5]"), "undefined");
+    assert_eq!(forward(&mut context, "5 in array"), "false");
+    assert_eq!(forward(&mut context, "1 in array"), "true");
+    assert_eq!(forward(&mut context, "array['-0']"), "undefined");
+    assert_eq!(forward(&mut context, "array[1.5]"), "undefined");
+    assert_eq!(forward(&mut context, "delete array[0]"), "false");
+    assert_eq!(forward(&mut context, "delete array[2]"), "true");
+    assert_eq!(
+        forward(&mut context, "Reflect.ownKeys(array).join()"),
+        "\"0,1,foo\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Reflect.defineProperty(array, 0, { value: 3, writable: true, enumerable: true, configurable: true })"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "array[0]"), "3");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Reflect.defineProperty(array, 0, { get: function () { return 1; } })"
+        ),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(array)"),
+        "\"[object Int8Array]\""
+    );
+}
+
+#[test]
+fn from_of_and_set() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "Int16Array.from([1, 2], x => x * 10).join()"),
+        "\"10,20\""
+    );
+    assert_eq!(
+        forward(&mut context, "Uint8Array.of(1, 2, 3).join()"),
+        "\"1,2,3\""
+    );
+
+    let init = r#"
+        var array = new Int8Array(5);
+        array.set([1, 2]);
+        array.set(new Int8Array([3, 4]), 3);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "array.join()"), "\"1,2,0,3,4\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { array.set([1, 2], 4) } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "array.set(array.subarray(0, 2), 1); array.join()"
+        ),
+        "\"1,1,2,3,4\""
+    );
+}
+
+#[test]
+fn subarray_and_slice() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var array = new Int8Array([1, 2, 3, 4]);
+        var sub = array.subarray(1, 3);
+        var copy = array.slice(1, 3);
+        array[1] = 9;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "sub.join()"), "\"9,3\"");
+    assert_eq!(forward(&mut context, "sub.byteOffset"), "1");
+    assert_eq!(forward(&mut context, "copy.join()"), "\"2,3\"");
+    assert_eq!(forward(&mut context, "sub.buffer === array.buffer"), "true");
+    assert_eq!(
+        forward(&mut context, "copy.buffer === array.buffer"),
+        "false"
+    );
+}
+
+#[test]
+fn prototype_methods() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var array = new Float64Array([3, NaN, -0, 1, 0]);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "array.includes(NaN)"), "true");
+    assert_eq!(forward(&mut context, "array.indexOf(NaN)"), "-1");
+    assert_eq!(forward(&mut context, "array.lastIndexOf(0)"), "4");
+    assert_eq!(
+        forward(&mut context, "array.map(x => x * 2).join()"),
+        "\"6,NaN,0,2,0\""
+    );
+    assert_eq!(
+        forward(&mut context, "array.filter(x => x > 0).join()"),
+        "\"3,1\""
+    );
+    assert_eq!(
+        forward(&mut context, "array.reduce((a, b) => a + (b || 0), 0)"),
+        "4"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[...array.slice().sort()].map(x => Object.is(x, -0) ? '-0' : String(x)).join()"
+        ),
+        "\"-0,0,1,3,NaN\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Int8Array([1, 2, 3]).sort((a, b) => b - a).join()"
+        ),
+        "\"3,2,1\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Int16Array([1, 2, 3]).reverse().join()"),
+        "\"3,2,1\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Int8Array(3).fill(7, 1).toString()"),
+        "\"0,7,7\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[...new Int8Array([5, 6]).entries()].join(';')"
+        ),
+        "\"0,5;1,6\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Int8Array.prototype.toString === Array.prototype.toString"
+        ),
+        "true"
+    );
+}
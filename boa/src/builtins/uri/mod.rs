@@ -0,0 +1,355 @@
+//! This module implements the global URI handling functions.
+//!
+//! `encodeURI` and `encodeURIComponent` replace the characters of a string that are not allowed
+//! in a URI by escape sequences representing their UTF-8 encoding, while `decodeURI` and
+//! `decodeURIComponent` replace the escape sequences by the characters they represent.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-uri-handling-functions
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/encodeURI
+
+use crate::{
+    builtins::BuiltIn, object::FunctionBuilder, property::Attribute, BoaProfiler, Context, Result,
+    Value,
+};
+use std::char::decode_utf16;
+
+#[cfg(test)]
+mod tests;
+
+/// The `uriReserved` characters, which have a special meaning in a URI.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-uriReserved
+const URI_RESERVED: &str = ";/?:@&=+$,";
+
+/// The `uriMark` characters, which are never escaped.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-uriMark
+const URI_MARK: &str = "-_.!~*'()";
+
+/// The JavaScript `encodeURI` function.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct EncodeUri;
+
+impl BuiltIn for EncodeUri {
+    const NAME: &'static str = "encodeURI";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let function = FunctionBuilder::new(context, Self::encode_uri)
+            .name(Self::NAME)
+            .length(1)
+            .callable(true)
+            .constructable(false)
+            .build();
+
+        (Self::NAME, function.into(), Self::attribute())
+    }
+}
+
+impl EncodeUri {
+    /// `encodeURI( uri )`
+    ///
+    /// Escapes the characters of a complete URI, keeping the characters that separate its parts,
+    /// like `/`, `?` and `#`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-encodeuri-uri
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/encodeURI
+    fn encode_uri(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let uri = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_string(context)?;
+        let unescaped_set = |c: char| URI_RESERVED.contains(c) || c == '#';
+
+        let units: Vec<u16> = uri.encode_utf16().collect();
+        encode(&units, unescaped_set, context)
+    }
+}
+
+/// The JavaScript `encodeURIComponent` function.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct EncodeUriComponent;
+
+impl BuiltIn for EncodeUriComponent {
+    const NAME: &'static str = "encodeURIComponent";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let function = FunctionBuilder::new(context, Self::encode_uri_component)
+            .name(Self::NAME)
+            .length(1)
+            .callable(true)
+            .constructable(false)
+            .build();
+
+        (Self::NAME, function.into(), Self::attribute())
+    }
+}
+
+impl EncodeUriComponent {
+    /// `encodeURIComponent( uriComponent )`
+    ///
+    /// Escapes the characters of a part of a URI, including the characters that separate the
+    /// parts of a URI.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-encodeuricomponent-uricomponent
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/encodeURIComponent
+    fn encode_uri_component(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let component = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_string(context)?;
+
+        let units: Vec<u16> = component.encode_utf16().collect();
+        encode(&units, |_| false, context)
+    }
+}
+
+/// The JavaScript `decodeURI` function.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DecodeUri;
+
+impl BuiltIn for DecodeUri {
+    const NAME: &'static str = "decodeURI";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let function = FunctionBuilder::new(context, Self::decode_uri)
+            .name(Self::NAME)
+            .length(1)
+            .callable(true)
+            .constructable(false)
+            .build();
+
+        (Self::NAME, function.into(), Self::attribute())
+    }
+}
+
+impl DecodeUri {
+    /// `decodeURI( encodedURI )`
+    ///
+    /// Decodes the escape sequences of a complete URI, except the ones representing the
+    /// characters that separate its parts, which `encodeURI` would not have escaped.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-decodeuri-encodeduri
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/decodeURI
+    fn decode_uri(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let uri = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_string(context)?;
+        let reserved_set = |c: char| URI_RESERVED.contains(c) || c == '#';
+
+        decode(&uri, reserved_set, context)
+    }
+}
+
+/// The JavaScript `decodeURIComponent` function.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DecodeUriComponent;
+
+impl BuiltIn for DecodeUriComponent {
+    const NAME: &'static str = "decodeURIComponent";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let function = FunctionBuilder::new(context, Self::decode_uri_component)
+            .name(Self::NAME)
+            .length(1)
+            .callable(true)
+            .constructable(false)
+            .build();
+
+        (Self::NAME, function.into(), Self::attribute())
+    }
+}
+
+impl DecodeUriComponent {
+    /// `decodeURIComponent( encodedURIComponent )`
+    ///
+    /// Decodes all the escape sequences of a part of a URI.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-decodeuricomponent-encodeduricomponent
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/decodeURIComponent
+    fn decode_uri_component(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let component = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_string(context)?;
+
+        decode(&component, |_| false, context)
+    }
+}
+
+/// Checks if the character is never escaped by the URI encoding functions.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-uriUnescaped
+fn is_uri_unescaped(c: char) -> bool {
+    c.is_ascii_alphanumeric() || URI_MARK.contains(c)
+}
+
+/// `Encode ( string, extraUnescaped )`
+///
+/// Escapes the UTF-8 encoding of every code point of the UTF-16 code units of a string, except
+/// the ones that are always unescaped or in the extra unescaped set. Unpaired surrogates cannot
+/// be encoded, and throw a `URIError`.
+///
+/// String values of the engine are stored as UTF-8, so the lone surrogates of string literals
+/// are already replaced by U+FFFD when the literals are parsed, and never reach this function.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-encode
+fn encode<F>(units: &[u16], extra_unescaped: F, context: &mut Context) -> Result<Value>
+where
+    F: Fn(char) -> bool,
+{
+    let mut result = String::with_capacity(units.len());
+    let mut buffer = [0; 4];
+    for c in decode_utf16(units.iter().copied()) {
+        let c = match c {
+            Ok(c) => c,
+            Err(_) => return context.throw_uri_error("URI malformed"),
+        };
+        if is_uri_unescaped(c) || extra_unescaped(c) {
+            result.push(c);
+        } else {
+            for octet in c.encode_utf8(&mut buffer).bytes() {
+                result.push_str(&format!("%{:02X}", octet));
+            }
+        }
+    }
+
+    Ok(result.into())
+}
+
+/// `Decode ( string, reservedSet )`
+///
+/// Replaces the escape sequences of the string by the code points whose UTF-8 encoding they
+/// represent, except the single octet escape sequences of the characters in the reserved set.
+/// Malformed escape sequences and invalid UTF-8 encodings, like the ones of surrogates, throw a
+/// `URIError`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-decode
+fn decode<F>(string: &str, reserved_set: F, context: &mut Context) -> Result<Value>
+where
+    F: Fn(char) -> bool,
+{
+    let units: Vec<u16> = string.encode_utf16().collect();
+    let mut result = Vec::with_capacity(units.len());
+    let mut k = 0;
+    while k < units.len() {
+        if units[k] != u16::from(b'%') {
+            result.push(units[k]);
+            k += 1;
+            continue;
+        }
+
+        let start = k;
+        let octet = match decode_hex_octet(&units, k) {
+            Some(octet) => octet,
+            None => return context.throw_uri_error("URI malformed"),
+        };
+        k += 3;
+
+        if octet < 0x80 {
+            let c = char::from(octet);
+            if reserved_set(c) {
+                result.extend_from_slice(&units[start..k]);
+            } else {
+                result.push(u16::from(octet));
+            }
+            continue;
+        }
+
+        // The number of leading one bits of the first octet is the length of the encoding.
+        let n = octet.leading_ones() as usize;
+        if n == 1 || n > 4 {
+            return context.throw_uri_error("URI malformed");
+        }
+        let mut octets = vec![octet];
+        for _ in 1..n {
+            match decode_hex_octet(&units, k) {
+                Some(octet) if octet & 0xC0 == 0x80 => octets.push(octet),
+                _ => return context.throw_uri_error("URI malformed"),
+            }
+            k += 3;
+        }
+
+        // Overlong encodings and encodings of surrogates are not valid UTF-8.
+        match std::str::from_utf8(&octets) {
+            Ok(decoded) => result.extend(decoded.encode_utf16()),
+            Err(_) => return context.throw_uri_error("URI malformed"),
+        }
+    }
+
+    Ok(String::from_utf16_lossy(&result).into())
+}
+
+/// Decodes the escape sequence `%XX` of an octet, at the given index of the code units.
+fn decode_hex_octet(units: &[u16], index: usize) -> Option<u8> {
+    let sequence = units.get(index..index + 3)?;
+    if sequence[0] != u16::from(b'%') {
+        return None;
+    }
+    let high = char::from_u32(u32::from(sequence[1]))?.to_digit(16)?;
+    let low = char::from_u32(u32::from(sequence[2]))?.to_digit(16)?;
+    Some((high * 16 + low) as u8)
+}
@@ -0,0 +1,134 @@
+use super::encode;
+use crate::{forward, Context};
+
+#[test]
+fn encode_uri() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(
+            &mut context,
+            "encodeURI('http://example.com/a b?q=ü&r=€#frag')"
+        ),
+        "\"http://example.com/a%20b?q=%C3%BC&r=%E2%82%AC#frag\""
+    );
+    assert_eq!(forward(&mut context, "encodeURI('😀')"), "\"%F0%9F%98%80\"");
+    assert_eq!(
+        forward(&mut context, "encodeURI(\"-_.!~*'()\")"),
+        "\"-_.!~*'()\""
+    );
+    assert_eq!(forward(&mut context, "encodeURI.length"), "1");
+}
+
+#[test]
+fn encode_uri_component() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "encodeURIComponent('a b&c=d/e?f#g')"),
+        "\"a%20b%26c%3Dd%2Fe%3Ff%23g\""
+    );
+    assert_eq!(
+        forward(&mut context, "encodeURIComponent(';/?:@&=+$,#')"),
+        "\"%3B%2F%3F%3A%40%26%3D%2B%24%2C%23\""
+    );
+    assert_eq!(forward(&mut context, "encodeURIComponent(1.5)"), "\"1.5\"");
+}
+
+#[test]
+fn decode_uri() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(
+            &mut context,
+            "decodeURI('a%20b%C3%BC%E2%82%AC%F0%9F%98%80')"
+        ),
+        "\"a bü€😀\""
+    );
+    // The escape sequences of reserved characters are kept.
+    assert_eq!(
+        forward(&mut context, "decodeURI('%3B%2F%23%41')"),
+        "\"%3B%2F%23A\""
+    );
+}
+
+#[test]
+fn decode_uri_component() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "decodeURIComponent('%3B%2F%23%41%c3%bc')"),
+        "\";/#Aü\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "decodeURIComponent(encodeURIComponent('key=välue & more'))"
+        ),
+        "\"key=välue & more\""
+    );
+}
+
+#[test]
+fn encode_uri_malformed() {
+    let mut context = Context::new();
+    let unpaired = [
+        &[0xD800][..],
+        &[0xDFFF],
+        &[0x61, 0xDBFF],
+        &[0xDC00, 0xD800],
+        &[0xD800, 0x62],
+    ];
+    for units in &unpaired {
+        let error = encode(units, |_| false, &mut context)
+            .expect_err("unpaired surrogates cannot be encoded");
+        assert_eq!(
+            error
+                .get_field("name", &mut context)
+                .unwrap()
+                .display()
+                .to_string(),
+            "\"URIError\"",
+            "encode({:X?})",
+            units
+        );
+    }
+
+    let pair = encode(&[0xD83D, 0xDE00], |_| false, &mut context).unwrap();
+    assert_eq!(pair.display().to_string(), "\"%F0%9F%98%80\"");
+}
+
+#[test]
+fn decode_uri_malformed() {
+    let mut context = Context::new();
+    let malformed = [
+        "'%'",
+        "'%4'",
+        "'%G0'",
+        "'%80'",
+        "'%C3'",
+        "'%C3%28'",
+        "'%F8%80%80%80'",
+        "'%C0%80'",
+        // The UTF-8 encoding of a surrogate.
+        "'%ED%A0%80'",
+    ];
+    for input in &malformed {
+        assert_eq!(
+            forward(
+                &mut context,
+                format!(
+                    "try {{ decodeURIComponent({}); }} catch (e) {{ e instanceof URIError }}",
+                    input
+                )
+            ),
+            "true",
+            "decodeURIComponent({})",
+            input
+        );
+    }
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { decodeURI('%E2%82'); } catch (e) { e.name }"
+        ),
+        "\"URIError\""
+    );
+}
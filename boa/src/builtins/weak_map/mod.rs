@@ -0,0 +1,223 @@
+//! This module implements the global `WeakMap` object.
+//!
+//! A `WeakMap` is a collection of key/value pairs whose keys are objects, and which does not keep
+//! its keys alive: an entry is removed once its key is collected, and its value is only kept alive
+//! while both the map and the key are.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-weakmap-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap
+
+use crate::{
+    builtins::{iterable::get_iterator, BuiltIn},
+    gc::EphemeronOwner,
+    object::{ConstructorBuilder, GcObject, Object, ObjectData, PROTOTYPE},
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WeakMap;
+
+impl BuiltIn for WeakMap {
+    const NAME: &'static str = "WeakMap";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let weak_map_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().weak_map_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .method(Self::delete, "delete", 1)
+        .method(Self::get, "get", 1)
+        .method(Self::has, "has", 1)
+        .method(Self::set, "set", 2)
+        .build();
+
+        (Self::NAME, weak_map_object.into(), Self::attribute())
+    }
+}
+
+impl WeakMap {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `new WeakMap( [ iterable ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap-iterable
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/WeakMap
+    pub(crate) fn constructor(
+        new_target: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin WeakMap constructor without new is forbidden",
+            );
+        }
+
+        let prototype = new_target
+            .as_object()
+            .map(|object| object.get(&PROTOTYPE.into(), new_target.clone(), context))
+            .transpose()?
+            .filter(Value::is_object)
+            .unwrap_or_else(|| {
+                context
+                    .standard_objects()
+                    .weak_map_object()
+                    .prototype()
+                    .into()
+            });
+        let map: Value = GcObject::new(Object::with_prototype(
+            prototype,
+            ObjectData::WeakMap(EphemeronOwner::default()),
+        ))
+        .into();
+
+        let iterable = args.get(0).cloned().unwrap_or_default();
+        if iterable.is_null_or_undefined() {
+            return Ok(map);
+        }
+
+        let adder = map.get_field("set", context)?;
+        let adder = match adder.as_object() {
+            Some(adder) if adder.is_callable() => adder,
+            _ => return context.throw_type_error("WeakMap.prototype.set is not a function"),
+        };
+
+        let iterator = get_iterator(context, iterable)?;
+        loop {
+            let next = iterator.next(context)?;
+            if next.is_done() {
+                return Ok(map);
+            }
+
+            let item = next.value();
+            let status = if item.is_object() {
+                item.get_field(0, context).and_then(|key| {
+                    let value = item.get_field(1, context)?;
+                    adder.call(&map, &[key, value], context)
+                })
+            } else {
+                Err(context.construct_type_error("WeakMap entries must be objects"))
+            };
+            if status.is_err() {
+                return iterator.close(status, context);
+            }
+        }
+    }
+
+    /// Returns the owner of the entries of the `WeakMap`, throwing if `this` is not one.
+    fn owner(this: &Value, method: &str, context: &mut Context) -> Result<EphemeronOwner> {
+        if let Some(object) = this.as_object() {
+            if let Some(owner) = object.borrow().as_weak_map() {
+                return Ok(owner.clone());
+            }
+        }
+        Err(context.construct_type_error(format!(
+            "WeakMap.prototype.{} called on incompatible receiver",
+            method
+        )))
+    }
+
+    /// `WeakMap.prototype.delete( key )`
+    ///
+    /// Removes the entry of the key, returning `true` if there was one.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.delete
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/delete
+    pub(crate) fn delete(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let owner = Self::owner(this, "delete", context)?;
+        let deleted = args.get(0).and_then(Value::as_object).map_or(false, |key| {
+            key.borrow_mut().ephemerons_mut().remove(&owner)
+        });
+        Ok(deleted.into())
+    }
+
+    /// `WeakMap.prototype.get( key )`
+    ///
+    /// Returns the value of the key, or `undefined` if the map has no entry for it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.get
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/get
+    pub(crate) fn get(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let owner = Self::owner(this, "get", context)?;
+        Ok(args
+            .get(0)
+            .and_then(Value::as_object)
+            .and_then(|key| key.borrow().ephemerons().get(&owner).cloned())
+            .unwrap_or_default())
+    }
+
+    /// `WeakMap.prototype.has( key )`
+    ///
+    /// Returns `true` if the map has an entry for the key.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/has
+    pub(crate) fn has(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let owner = Self::owner(this, "has", context)?;
+        let has = args
+            .get(0)
+            .and_then(Value::as_object)
+            .map_or(false, |key| key.borrow().ephemerons().get(&owner).is_some());
+        Ok(has.into())
+    }
+
+    /// `WeakMap.prototype.set( key, value )`
+    ///
+    /// Sets the value of the key, and returns the map. The key must be an object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.set
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/set
+    pub(crate) fn set(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let owner = Self::owner(this, "set", context)?;
+        let key = match args.get(0).and_then(Value::as_object) {
+            Some(key) => key,
+            None => return context.throw_type_error("WeakMap keys must be objects"),
+        };
+        let value = args.get(1).cloned().unwrap_or_default();
+        key.borrow_mut().ephemerons_mut().insert(&owner, value);
+        Ok(this.clone())
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{forward, Context};
+
+#[test]
+fn construct() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var first = {};
+        var second = {};
+        var map = new WeakMap([[first, 1], [second, 2]]);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "map.get(first)"), "1");
+    assert_eq!(forward(&mut context, "map.get(second)"), "2");
+    assert_eq!(forward(&mut context, "map.size"), "undefined");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { WeakMap() } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new WeakMap([[1, 2]]) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(map)"),
+        "\"[object WeakMap]\""
+    );
+}
+
+#[test]
+fn get_set_has_delete() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var key = {};
+        var map = new WeakMap();
+        var other = new WeakMap();
+        var returned = map.set(key, "value");
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "returned === map"), "true");
+    assert_eq!(forward(&mut context, "map.get(key)"), "\"value\"");
+    assert_eq!(forward(&mut context, "map.has(key)"), "true");
+    assert_eq!(forward(&mut context, "other.has(key)"), "false");
+    assert_eq!(forward(&mut context, "map.get({})"), "undefined");
+    assert_eq!(forward(&mut context, "map.has(1)"), "false");
+    assert_eq!(forward(&mut context, "map.set(key, 2); map.get(key)"), "2");
+    assert_eq!(forward(&mut context, "map.delete(key)"), "true");
+    assert_eq!(forward(&mut context, "map.delete(key)"), "false");
+    assert_eq!(forward(&mut context, "map.has(key)"), "false");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { map.set(1, 2) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { WeakMap.prototype.get.call(new Map(), key) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "map.set(map, map); map.get(map) === map"),
+        "true"
+    );
+}
+
+#[test]
+fn entries_do_not_keep_keys_alive() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var map = new WeakMap();
+        var collected = [];
+        var registry = new FinalizationRegistry(held => collected.push(held));
+        (function () {
+            var key = {};
+            var value = { key: key };
+            map.set(key, value);
+            registry.register(key, "key");
+            registry.register(value, "value");
+        })();
+        "#;
+    forward(&mut context, init);
+
+    crate::gc::force_collect();
+    context.run_jobs().unwrap();
+
+    assert_eq!(forward(&mut context, "collected.join()"), "\"key,value\"");
+}
+
+#[test]
+fn entries_keep_values_alive() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var key = {};
+        var map = new WeakMap();
+        var collected = [];
+        var registry = new FinalizationRegistry(held => collected.push(held));
+        (function () {
+            var value = {};
+            map.set(key, value);
+            registry.register(value, "value");
+        })();
+        "#;
+    forward(&mut context, init);
+
+    crate::gc::force_collect();
+    context.run_jobs().unwrap();
+
+    assert_eq!(forward(&mut context, "collected.length"), "0");
+    assert_eq!(forward(&mut context, "typeof map.get(key)"), "\"object\"");
+}
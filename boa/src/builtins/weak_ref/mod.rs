@@ -0,0 +1,132 @@
+//! This module implements the global `WeakRef` object.
+//!
+//! A `WeakRef` holds a weak reference to an object, which does not keep the object alive.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-weak-ref-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakRef
+
+use crate::{
+    builtins::BuiltIn,
+    object::{ConstructorBuilder, GcObject, Object, ObjectData, PROTOTYPE},
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WeakRef;
+
+impl BuiltIn for WeakRef {
+    const NAME: &'static str = "WeakRef";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let weak_ref_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().weak_ref_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .method(Self::deref, "deref", 0)
+        .build();
+
+        (Self::NAME, weak_ref_object.into(), Self::attribute())
+    }
+}
+
+impl WeakRef {
+    pub(crate) const LENGTH: usize = 1;
+
+    /// `new WeakRef( target )`
+    ///
+    /// The target is kept alive until the jobs that follow the current script are run.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weak-ref-target
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakRef/WeakRef
+    pub(crate) fn constructor(
+        new_target: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin WeakRef constructor without new is forbidden",
+            );
+        }
+
+        let target = match args.get(0).and_then(Value::as_object) {
+            Some(target) => target,
+            None => return context.throw_type_error("WeakRef target must be an object"),
+        };
+
+        let prototype = new_target
+            .as_object()
+            .map(|object| object.get(&PROTOTYPE.into(), new_target.clone(), context))
+            .transpose()?
+            .filter(Value::is_object)
+            .unwrap_or_else(|| {
+                context
+                    .standard_objects()
+                    .weak_ref_object()
+                    .prototype()
+                    .into()
+            });
+        let weak_ref = GcObject::new(Object::with_prototype(
+            prototype,
+            ObjectData::WeakRef(target.downgrade()),
+        ));
+        context.add_to_kept_objects(target);
+
+        Ok(weak_ref.into())
+    }
+
+    /// `WeakRef.prototype.deref()`
+    ///
+    /// Returns the target of the `WeakRef`, or `undefined` if it has been collected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weak-ref.prototype.deref
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakRef/deref
+    pub(crate) fn deref(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let target = match this.as_object() {
+            Some(object) => match object.borrow().as_weak_ref() {
+                Some(target) => target.upgrade(),
+                None => return context.throw_type_error("'this' is not a WeakRef"),
+            },
+            None => return context.throw_type_error("'this' is not a WeakRef"),
+        };
+
+        match target {
+            Some(target) => {
+                context.add_to_kept_objects(target.clone());
+                Ok(target.into())
+            }
+            None => Ok(Value::undefined()),
+        }
+    }
+}
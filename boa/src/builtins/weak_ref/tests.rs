@@ -0,0 +1,55 @@
+use crate::{forward, Context};
+
+#[test]
+fn deref() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var target = { a: 1 };
+        var ref = new WeakRef(target);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "ref.deref() === target"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new WeakRef(1) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { WeakRef({}) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { WeakRef.prototype.deref.call({}) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(ref)"),
+        "\"[object WeakRef]\""
+    );
+}
+
+#[test]
+fn target_is_kept_alive_until_jobs_run() {
+    let mut context = Context::new();
+
+    // `forward` runs the jobs, so `eval` is used while the target is kept alive.
+    context.eval("var ref = new WeakRef({ a: 1 });").unwrap();
+
+    crate::gc::force_collect();
+    let a = context.eval("ref.deref().a").unwrap();
+    assert_eq!(a.as_number(), Some(1.0));
+
+    context.run_jobs().unwrap();
+    crate::gc::force_collect();
+    assert_eq!(forward(&mut context, "ref.deref()"), "undefined");
+}
@@ -0,0 +1,201 @@
+//! This module implements the global `WeakSet` object.
+//!
+//! A `WeakSet` is a collection of objects which does not keep its elements alive: an element is
+//! removed from the set once it is collected.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-weakset-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet
+
+use crate::{
+    builtins::{iterable::get_iterator, BuiltIn},
+    gc::EphemeronOwner,
+    object::{ConstructorBuilder, GcObject, Object, ObjectData, PROTOTYPE},
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WeakSet;
+
+impl BuiltIn for WeakSet {
+    const NAME: &'static str = "WeakSet";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let weak_set_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().weak_set_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .method(Self::add, "add", 1)
+        .method(Self::delete, "delete", 1)
+        .method(Self::has, "has", 1)
+        .build();
+
+        (Self::NAME, weak_set_object.into(), Self::attribute())
+    }
+}
+
+impl WeakSet {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `new WeakSet( [ iterable ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset-iterable
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/WeakSet
+    pub(crate) fn constructor(
+        new_target: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin WeakSet constructor without new is forbidden",
+            );
+        }
+
+        let prototype = new_target
+            .as_object()
+            .map(|object| object.get(&PROTOTYPE.into(), new_target.clone(), context))
+            .transpose()?
+            .filter(Value::is_object)
+            .unwrap_or_else(|| {
+                context
+                    .standard_objects()
+                    .weak_set_object()
+                    .prototype()
+                    .into()
+            });
+        let set: Value = GcObject::new(Object::with_prototype(
+            prototype,
+            ObjectData::WeakSet(EphemeronOwner::default()),
+        ))
+        .into();
+
+        let iterable = args.get(0).cloned().unwrap_or_default();
+        if iterable.is_null_or_undefined() {
+            return Ok(set);
+        }
+
+        let adder = set.get_field("add", context)?;
+        let adder = match adder.as_object() {
+            Some(adder) if adder.is_callable() => adder,
+            _ => return context.throw_type_error("WeakSet.prototype.add is not a function"),
+        };
+
+        let iterator = get_iterator(context, iterable)?;
+        loop {
+            let next = iterator.next(context)?;
+            if next.is_done() {
+                return Ok(set);
+            }
+
+            let status = adder.call(&set, &[next.value()], context);
+            if status.is_err() {
+                return iterator.close(status, context);
+            }
+        }
+    }
+
+    /// Returns the owner of the elements of the `WeakSet`, throwing if `this` is not one.
+    fn owner(this: &Value, method: &str, context: &mut Context) -> Result<EphemeronOwner> {
+        if let Some(object) = this.as_object() {
+            if let Some(owner) = object.borrow().as_weak_set() {
+                return Ok(owner.clone());
+            }
+        }
+        Err(context.construct_type_error(format!(
+            "WeakSet.prototype.{} called on incompatible receiver",
+            method
+        )))
+    }
+
+    /// `WeakSet.prototype.add( value )`
+    ///
+    /// Adds the value to the set, and returns the set. The value must be an object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.add
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/add
+    pub(crate) fn add(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let owner = Self::owner(this, "add", context)?;
+        let value = match args.get(0).and_then(Value::as_object) {
+            Some(value) => value,
+            None => return context.throw_type_error("WeakSet values must be objects"),
+        };
+        value
+            .borrow_mut()
+            .ephemerons_mut()
+            .insert(&owner, Value::undefined());
+        Ok(this.clone())
+    }
+
+    /// `WeakSet.prototype.delete( value )`
+    ///
+    /// Removes the value from the set, returning `true` if it was in the set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.delete
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/delete
+    pub(crate) fn delete(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let owner = Self::owner(this, "delete", context)?;
+        let deleted = args
+            .get(0)
+            .and_then(Value::as_object)
+            .map_or(false, |value| {
+                value.borrow_mut().ephemerons_mut().remove(&owner)
+            });
+        Ok(deleted.into())
+    }
+
+    /// `WeakSet.prototype.has( value )`
+    ///
+    /// Returns `true` if the value is in the set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/has
+    pub(crate) fn has(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let owner = Self::owner(this, "has", context)?;
+        let has = args
+            .get(0)
+            .and_then(Value::as_object)
+            .map_or(false, |value| {
+                value.borrow().ephemerons().get(&owner).is_some()
+            });
+        Ok(has.into())
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{forward, Context};
+
+#[test]
+fn add_has_delete() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var first = {};
+        var second = {};
+        var set = new WeakSet([first]);
+        var returned = set.add(second);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "returned === set"), "true");
+    assert_eq!(forward(&mut context, "set.has(first)"), "true");
+    assert_eq!(forward(&mut context, "set.has(second)"), "true");
+    assert_eq!(forward(&mut context, "set.has({})"), "false");
+    assert_eq!(forward(&mut context, "set.has(1)"), "false");
+    assert_eq!(forward(&mut context, "set.delete(first)"), "true");
+    assert_eq!(forward(&mut context, "set.delete(first)"), "false");
+    assert_eq!(forward(&mut context, "set.has(first)"), "false");
+    assert_eq!(forward(&mut context, "new WeakSet().has(second)"), "false");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { set.add(1) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new WeakSet([1]) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { WeakSet() } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(set)"),
+        "\"[object WeakSet]\""
+    );
+}
+
+#[test]
+fn elements_are_not_kept_alive() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var set = new WeakSet();
+        var collected = [];
+        var registry = new FinalizationRegistry(held => collected.push(held));
+        (function () {
+            var element = {};
+            set.add(element);
+            registry.register(element, "element");
+        })();
+        "#;
+    forward(&mut context, init);
+
+    crate::gc::force_collect();
+    context.run_jobs().unwrap();
+
+    assert_eq!(forward(&mut context, "collected.join()"), "\"element\"");
+}
@@ -3,12 +3,15 @@
 use crate::{
     builtins::{
         self,
+        date::TimeZone,
         function::{Function, FunctionFlags, NativeFunction},
         iterable::IteratorPrototypes,
     },
     class::{Class, ClassBuilder},
     exec::Interpreter,
-    object::{GcObject, Object, PROTOTYPE},
+    job::NativeJob,
+    module::{Module, ModuleLoader},
+    object::{GcObject, Object, WeakGcObject, PROTOTYPE},
     property::{Attribute, DataDescriptor, PropertyKey},
     realm::Realm,
     symbol::{RcSymbol, Symbol},
@@ -26,13 +29,16 @@ use crate::{
     BoaProfiler, Executable, Result,
 };
 
+use rustc_hash::FxHashMap;
+use std::{collections::VecDeque, rc::Rc};
+
 #[cfg(feature = "console")]
 use crate::builtins::console::Console;
 
 #[cfg(feature = "vm")]
 use crate::vm::{
     compilation::{CodeGen, Compiler},
-    VM,
+    CodeBlock, CodeCache, VM,
 };
 
 /// Store a builtin constructor (such as `Object`) and its corresponding prototype.
@@ -96,8 +102,28 @@ pub struct StandardObjects {
     syntax_error: StandardConstructor,
     eval_error: StandardConstructor,
     uri_error: StandardConstructor,
+    aggregate_error: StandardConstructor,
     map: StandardConstructor,
     set: StandardConstructor,
+    promise: StandardConstructor,
+    array_buffer: StandardConstructor,
+    data_view: StandardConstructor,
+    typed_array: StandardConstructor,
+    int8_array: StandardConstructor,
+    uint8_array: StandardConstructor,
+    uint8_clamped_array: StandardConstructor,
+    int16_array: StandardConstructor,
+    uint16_array: StandardConstructor,
+    int32_array: StandardConstructor,
+    uint32_array: StandardConstructor,
+    float32_array: StandardConstructor,
+    float64_array: StandardConstructor,
+    big_int64_array: StandardConstructor,
+    big_uint64_array: StandardConstructor,
+    weak_map: StandardConstructor,
+    weak_set: StandardConstructor,
+    weak_ref: StandardConstructor,
+    finalization_registry: StandardConstructor,
 }
 
 impl Default for StandardObjects {
@@ -119,8 +145,28 @@ impl Default for StandardObjects {
             syntax_error: StandardConstructor::default(),
             eval_error: StandardConstructor::default(),
             uri_error: StandardConstructor::default(),
+            aggregate_error: StandardConstructor::default(),
             map: StandardConstructor::default(),
             set: StandardConstructor::default(),
+            promise: StandardConstructor::default(),
+            array_buffer: StandardConstructor::default(),
+            data_view: StandardConstructor::default(),
+            typed_array: StandardConstructor::default(),
+            int8_array: StandardConstructor::default(),
+            uint8_array: StandardConstructor::default(),
+            uint8_clamped_array: StandardConstructor::default(),
+            int16_array: StandardConstructor::default(),
+            uint16_array: StandardConstructor::default(),
+            int32_array: StandardConstructor::default(),
+            uint32_array: StandardConstructor::default(),
+            float32_array: StandardConstructor::default(),
+            float64_array: StandardConstructor::default(),
+            big_int64_array: StandardConstructor::default(),
+            big_uint64_array: StandardConstructor::default(),
+            weak_map: StandardConstructor::default(),
+            weak_set: StandardConstructor::default(),
+            weak_ref: StandardConstructor::default(),
+            finalization_registry: StandardConstructor::default(),
         }
     }
 }
@@ -206,6 +252,11 @@ impl StandardObjects {
         &self.uri_error
     }
 
+    #[inline]
+    pub fn aggregate_error_object(&self) -> &StandardConstructor {
+        &self.aggregate_error
+    }
+
     #[inline]
     pub fn map_object(&self) -> &StandardConstructor {
         &self.map
@@ -215,6 +266,101 @@ impl StandardObjects {
     pub fn set_object(&self) -> &StandardConstructor {
         &self.set
     }
+
+    #[inline]
+    pub fn promise_object(&self) -> &StandardConstructor {
+        &self.promise
+    }
+
+    #[inline]
+    pub fn array_buffer_object(&self) -> &StandardConstructor {
+        &self.array_buffer
+    }
+
+    #[inline]
+    pub fn data_view_object(&self) -> &StandardConstructor {
+        &self.data_view
+    }
+
+    #[inline]
+    pub fn typed_array_object(&self) -> &StandardConstructor {
+        &self.typed_array
+    }
+
+    #[inline]
+    pub fn int8_array_object(&self) -> &StandardConstructor {
+        &self.int8_array
+    }
+
+    #[inline]
+    pub fn uint8_array_object(&self) -> &StandardConstructor {
+        &self.uint8_array
+    }
+
+    #[inline]
+    pub fn uint8_clamped_array_object(&self) -> &StandardConstructor {
+        &self.uint8_clamped_array
+    }
+
+    #[inline]
+    pub fn int16_array_object(&self) -> &StandardConstructor {
+        &self.int16_array
+    }
+
+    #[inline]
+    pub fn uint16_array_object(&self) -> &StandardConstructor {
+        &self.uint16_array
+    }
+
+    #[inline]
+    pub fn int32_array_object(&self) -> &StandardConstructor {
+        &self.int32_array
+    }
+
+    #[inline]
+    pub fn uint32_array_object(&self) -> &StandardConstructor {
+        &self.uint32_array
+    }
+
+    #[inline]
+    pub fn float32_array_object(&self) -> &StandardConstructor {
+        &self.float32_array
+    }
+
+    #[inline]
+    pub fn float64_array_object(&self) -> &StandardConstructor {
+        &self.float64_array
+    }
+
+    #[inline]
+    pub fn big_int64_array_object(&self) -> &StandardConstructor {
+        &self.big_int64_array
+    }
+
+    #[inline]
+    pub fn big_uint64_array_object(&self) -> &StandardConstructor {
+        &self.big_uint64_array
+    }
+
+    #[inline]
+    pub fn weak_map_object(&self) -> &StandardConstructor {
+        &self.weak_map
+    }
+
+    #[inline]
+    pub fn weak_set_object(&self) -> &StandardConstructor {
+        &self.weak_set
+    }
+
+    #[inline]
+    pub fn weak_ref_object(&self) -> &StandardConstructor {
+        &self.weak_ref
+    }
+
+    #[inline]
+    pub fn finalization_registry_object(&self) -> &StandardConstructor {
+        &self.finalization_registry
+    }
 }
 
 /// Javascript context. It is the primary way to interact with the runtime.
@@ -240,8 +386,45 @@ pub struct Context {
     /// Cached standard objects and their prototypes.
     standard_objects: StandardObjects,
 
+    /// The jobs waiting to be run, like promise reactions.
+    job_queue: VecDeque<NativeJob>,
+
+    /// The objects kept alive until the end of the current job, like the targets of new `WeakRef`s.
+    kept_alive: Vec<GcObject>,
+
+    /// The `FinalizationRegistry` objects, checked for collected targets between jobs.
+    finalization_registries: Vec<WeakGcObject>,
+
+    /// The host hook resolving and loading the modules imported by modules.
+    module_loader: Option<Rc<dyn ModuleLoader>>,
+
+    /// The modules loaded so far, by key.
+    modules: FxHashMap<Box<str>, Module>,
+
     /// Whether or not to show trace of instructions being ran
     pub trace: bool,
+
+    /// Whether code can be generated from strings, with `eval` and the `Function` constructor.
+    eval_enabled: bool,
+
+    /// The `%eval%` intrinsic, used to recognize direct calls to `eval`.
+    pub(crate) eval_function: Option<GcObject>,
+
+    /// The `%Array.prototype.values%` intrinsic, the `@@iterator` method of `arguments` objects.
+    pub(crate) array_values_function: Option<GcObject>,
+
+    /// The `%ThrowTypeError%` intrinsic, the `callee` accessor of unmapped `arguments` objects.
+    pub(crate) throw_type_error_function: Option<GcObject>,
+
+    /// Whether the code being evaluated is strict mode code.
+    strict: bool,
+
+    /// The time zone `Date` objects use for local time.
+    time_zone: TimeZone,
+
+    /// The code blocks compiled from the sources evaluated so far.
+    #[cfg(feature = "vm")]
+    code_cache: CodeCache,
 }
 
 impl Default for Context {
@@ -255,7 +438,20 @@ impl Default for Context {
             console: Console::default(),
             iterator_prototypes: IteratorPrototypes::default(),
             standard_objects: Default::default(),
+            job_queue: VecDeque::new(),
+            kept_alive: Vec::new(),
+            finalization_registries: Vec::new(),
+            module_loader: None,
+            modules: FxHashMap::default(),
             trace: false,
+            eval_enabled: true,
+            eval_function: None,
+            array_values_function: None,
+            throw_type_error_function: None,
+            strict: false,
+            time_zone: TimeZone::local(),
+            #[cfg(feature = "vm")]
+            code_cache: CodeCache::default(),
         };
 
         // Add new builtIns to Context Realm
@@ -313,6 +509,28 @@ impl Context {
         GcObject::new(Object::create(object_prototype))
     }
 
+    /// Construct an `ArrayBuffer` object holding the given bytes.
+    ///
+    /// The bytes are moved into the buffer without being copied, so scripts read and write the
+    /// same memory. The host can get them back, also without copying, by detaching the buffer:
+    ///
+    /// ```
+    /// # use boa::{property::Attribute, Context};
+    /// let mut context = Context::new();
+    ///
+    /// let buffer = context.create_array_buffer(vec![1, 2, 3]);
+    /// context.register_global_property("buffer", buffer.clone(), Attribute::all());
+    /// context.eval("new Uint8Array(buffer)[0] = 42").unwrap();
+    ///
+    /// let bytes = buffer.borrow_mut().as_array_buffer_mut().and_then(|b| b.detach());
+    /// assert_eq!(bytes, Some(vec![42, 2, 3]));
+    /// ```
+    #[inline]
+    pub fn create_array_buffer(&self, bytes: Vec<u8>) -> GcObject {
+        let prototype = self.standard_objects().array_buffer_object().prototype();
+        builtins::ArrayBuffer::create(bytes, prototype.into())
+    }
+
     /// <https://tc39.es/ecma262/#sec-call>
     #[inline]
     pub(crate) fn call(&mut self, f: &Value, this: &Value, args: &[Value]) -> Result<Value> {
@@ -328,6 +546,20 @@ impl Context {
         self.realm.global_object.clone()
     }
 
+    /// Appends the position in the source code of the node being evaluated to an error message.
+    fn with_source_position(&self, message: Box<str>) -> Box<str> {
+        match self.executor.call_stack.current_span() {
+            Some(span) => format!(
+                "{} at line {}, col {}",
+                message,
+                span.start().line_number(),
+                span.start().column_number()
+            )
+            .into(),
+            None => message,
+        }
+    }
+
     /// Constructs a `RangeError` with the specified message.
     #[inline]
     pub fn construct_range_error<M>(&mut self, message: M) -> Value
@@ -359,9 +591,10 @@ impl Context {
         M: Into<Box<str>>,
     {
         // Runs a `new TypeError(message)`.
+        let message = self.with_source_position(message.into());
         New::from(Call::new(
             Identifier::from("TypeError"),
-            vec![Const::from(message.into()).into()],
+            vec![Const::from(message).into()],
         ))
         .run(self)
         .expect("Into<String> used as message")
@@ -382,9 +615,10 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
+        let message = self.with_source_position(message.into());
         New::from(Call::new(
             Identifier::from("ReferenceError"),
-            vec![Const::from(message.into()).into()],
+            vec![Const::from(message).into()],
         ))
         .run(self)
         .expect("Into<String> used as message")
@@ -465,40 +699,98 @@ impl Context {
     }
 
     /// Utility to create a function Value for Function Declarations, Arrow Functions or Function Expressions
+    ///
+    /// The function is strict mode code if its body is, or if `flags` says so.
     pub(crate) fn create_function<P, B>(
         &mut self,
         params: P,
         body: B,
-        flags: FunctionFlags,
+        mut flags: FunctionFlags,
     ) -> Result<Value>
     where
         P: Into<Box<[FormalParameter]>>,
         B: Into<StatementList>,
     {
-        let function_prototype: Value =
-            self.standard_objects().function_object().prototype().into();
+        let params: Box<[FormalParameter]> = params.into();
+        let body = body.into();
+        if body.strict() {
+            flags |= FunctionFlags::STRICT;
+        }
+        // The length of a function only counts the parameters before the first default value
+        // see <https://tc39.es/ecma262/#sec-function-definitions-static-semantics-expectedargumentcount>
+        let params_len = params
+            .iter()
+            .take_while(|param| param.init().is_none() && !param.is_rest_param())
+            .count();
+        let func = Function::Ordinary {
+            flags,
+            body: RcStatementList::from(body),
+            params: params.into(),
+            environment: self.get_current_environment().clone(),
+            home_object: None,
+            fields: Vec::new(),
+            private_methods: Vec::new(),
+            source_text: None,
+        };
 
-        // Every new function has a prototype property pre-made
-        let proto = Value::new_object(self);
+        self.create_function_object(func, flags, params_len)
+    }
 
-        let params = params.into();
-        let params_len = params.len();
-        let func = Function::Ordinary {
+    /// Utility to create a function Value for a function compiled to bytecode.
+    #[cfg(feature = "vm")]
+    pub(crate) fn create_compiled_function(&mut self, code: Rc<CodeBlock>) -> Result<Value> {
+        let flags = code.flags;
+        let length = code.length;
+        let name = code.name.clone();
+        let func = Function::Compiled {
             flags,
-            body: RcStatementList::from(body.into()),
-            params,
+            code,
             environment: self.get_current_environment().clone(),
+            home_object: None,
         };
 
+        let val = self.create_function_object(func, flags, length)?;
+        if let Some(name) = name {
+            val.set_field("name", Value::from(name.as_ref()), self)?;
+        }
+
+        Ok(val)
+    }
+
+    /// Creates the object of a function, with its `length` property, and its `prototype`
+    /// property if it is a constructor or a generator.
+    fn create_function_object(
+        &mut self,
+        func: Function,
+        flags: FunctionFlags,
+        length: usize,
+    ) -> Result<Value> {
+        let function_prototype: Value =
+            self.standard_objects().function_object().prototype().into();
+
         let new_func = Object::function(func, function_prototype);
 
         let val = Value::from(new_func);
 
-        // Set constructor field to the newly created Value (function object)
-        proto.set_field("constructor", val.clone(), self)?;
+        // Arrow functions, methods and async functions don't have a prototype property
+        // see <https://tc39.es/ecma262/#sec-makeconstructor>
+        if flags.is_constructable() || flags.is_generator() {
+            let proto = Value::new_object(self);
+            if flags.is_generator() {
+                // The instances of a generator function inherit from %GeneratorPrototype%
+                // see <https://tc39.es/ecma262/#sec-runtime-semantics-instantiategeneratorfunctionobject>
+                proto
+                    .as_object()
+                    .expect("prototype must be an object")
+                    .set_prototype_instance(self.iterator_prototypes().generator().into());
+            } else {
+                // Set constructor field to the newly created Value (function object)
+                proto.set_field("constructor", val.clone(), self)?;
+            }
 
-        val.set_field(PROTOTYPE, proto, self)?;
-        val.set_field("length", Value::from(params_len), self)?;
+            val.set_field(PROTOTYPE, proto, self)?;
+        }
+        val.set_field("length", Value::from(length), self)?;
 
         Ok(val)
     }
@@ -546,11 +838,11 @@ impl Context {
 
     /// <https://tc39.es/ecma262/#sec-hasproperty>
     #[inline]
-    pub(crate) fn has_property(&self, obj: &Value, key: &PropertyKey) -> bool {
+    pub(crate) fn has_property(&mut self, obj: &Value, key: &PropertyKey) -> Result<bool> {
         if let Some(obj) = obj.as_object() {
-            obj.has_property(key)
+            obj.has_property(key, self)
         } else {
-            false
+            Ok(false)
         }
     }
 
@@ -558,17 +850,23 @@ impl Context {
     pub(crate) fn set_value(&mut self, node: &Node, value: Value) -> Result<Value> {
         match node {
             Node::Identifier(ref name) => {
-                self.set_mutable_binding(name.as_ref(), value.clone(), true)?;
+                let strict = self.strict();
+                self.set_mutable_binding(name.as_ref(), value.clone(), strict)?;
                 Ok(value)
             }
             Node::GetConstField(ref get_const_field_node) => Ok(get_const_field_node
                 .obj()
                 .run(self)?
-                .set_field(get_const_field_node.field(), value, self)?),
+                .put_value(get_const_field_node.field(), value, self)?),
             Node::GetField(ref get_field) => {
                 let field = get_field.field().run(self)?;
                 let key = field.to_property_key(self)?;
-                Ok(get_field.obj().run(self)?.set_field(key, value, self)?)
+                Ok(get_field.obj().run(self)?.put_value(key, value, self)?)
+            }
+            Node::GetPrivateField(ref get_private_field) => {
+                let obj = get_private_field.obj().run(self)?;
+                get_private_field.assign(&obj, value.clone(), self)?;
+                Ok(value)
             }
             _ => self.throw_type_error(format!("invalid assignment to {}", node)),
         }
@@ -651,7 +949,9 @@ impl Context {
             .map_err(|e| e.to_string());
 
         let execution_result = match parsing_result {
-            Ok(statement_list) => statement_list.run(self),
+            Ok(statement_list) => self.with_strict(statement_list.strict(), |context| {
+                statement_list.run(context)
+            }),
             Err(e) => self.throw_syntax_error(e),
         };
 
@@ -678,24 +978,150 @@ impl Context {
     #[allow(clippy::unit_arg, clippy::drop_copy)]
     pub fn eval<T: AsRef<[u8]>>(&mut self, src: T) -> Result<Value> {
         let main_timer = BoaProfiler::global().start_event("Main", "Main");
-        let src_bytes: &[u8] = src.as_ref();
 
-        let parsing_result = Parser::new(src_bytes, false)
-            .parse_all()
-            .map_err(|e| e.to_string());
+        let result = self.compile(src).and_then(|code| self.execute(&code));
+
+        // The main_timer needs to be dropped before the BoaProfiler is.
+        drop(main_timer);
+        BoaProfiler::global().drop();
+
+        result
+    }
 
-        let statement_list = match parsing_result {
+    /// Compiles the given code to bytecode, without running it.
+    ///
+    /// The code block is looked up in the [code cache](Context::code_cache) first, so the source
+    /// is only parsed and compiled if it was not compiled recently.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    ///
+    /// let code = context.compile("1 + 3").unwrap();
+    /// let value = context.execute(&code).unwrap();
+    ///
+    /// assert_eq!(value.as_number().unwrap(), 4.0);
+    /// ```
+    #[cfg(feature = "vm")]
+    pub fn compile<T: AsRef<[u8]>>(&mut self, src: T) -> Result<Rc<CodeBlock>> {
+        let src_bytes: &[u8] = src.as_ref();
+        if let Some(code) = self.code_cache.get(src_bytes) {
+            return Ok(code);
+        }
+
+        let statement_list = match Parser::new(src_bytes, false).parse_all() {
             Ok(statement_list) => statement_list,
-            Err(e) => return self.throw_syntax_error(e),
+            Err(e) => return Err(self.construct_syntax_error(e.to_string())),
         };
 
         let mut compiler = Compiler::default();
         statement_list.compile(&mut compiler);
+        let mut code = compiler.finish();
+        if statement_list.strict() {
+            code.flags |= FunctionFlags::STRICT;
+        }
+        let code = Rc::new(code);
+
+        self.code_cache.insert(src_bytes, code.clone());
+        Ok(code)
+    }
+
+    /// Runs the given bytecode, returning the value of the last expression statement.
+    ///
+    /// The code block can come from [`Context::compile`], or be loaded with
+    /// [`CodeBlock::from_bytes`].
+    #[cfg(feature = "vm")]
+    pub fn execute(&mut self, code: &CodeBlock) -> Result<Value> {
+        self.with_strict(code.flags.is_strict(), |context| {
+            VM::new(code, context).run()
+        })
+    }
+
+    /// Gets the cache of the code blocks compiled by [`Context::compile`] and [`Context::eval`].
+    #[cfg(feature = "vm")]
+    #[inline]
+    pub fn code_cache(&self) -> &CodeCache {
+        &self.code_cache
+    }
+
+    /// Gets a mutable reference to the cache of compiled code blocks.
+    ///
+    /// Hosts can use it to preload code blocks, to share them between contexts, or to change
+    /// the capacity of the cache, which is disabled by a capacity of zero.
+    #[cfg(feature = "vm")]
+    #[inline]
+    pub fn code_cache_mut(&mut self) -> &mut CodeCache {
+        &mut self.code_cache
+    }
+
+    /// Sets the module loader, which resolves and loads the modules imported by modules.
+    ///
+    /// See [`ModuleLoader`] for more information.
+    #[inline]
+    pub fn set_module_loader<L>(&mut self, loader: L)
+    where
+        L: ModuleLoader + 'static,
+    {
+        self.module_loader = Some(Rc::new(loader));
+    }
+
+    /// Gets the module loader, if one is set.
+    #[inline]
+    pub(crate) fn module_loader(&self) -> Option<Rc<dyn ModuleLoader>> {
+        self.module_loader.clone()
+    }
+
+    /// Gets the map of the modules loaded so far, by key.
+    #[inline]
+    pub(crate) fn modules(&mut self) -> &mut FxHashMap<Box<str>, Module> {
+        &mut self.modules
+    }
+
+    /// Loads the module with the given specifier with the module loader, then links and evaluates
+    /// it with the modules it imports.
+    ///
+    /// Returns the namespace object of the module, whose properties are its exports.
+    #[allow(clippy::drop_copy)]
+    pub fn eval_module(&mut self, specifier: &str) -> Result<Value> {
+        let main_timer = BoaProfiler::global().start_event("Main", "Main");
+
+        let result = Module::load(specifier, None, self)
+            .and_then(|module| module.load_link_and_evaluate(self));
+
+        // The main_timer needs to be dropped before the BoaProfiler is.
+        drop(main_timer);
+        BoaProfiler::global().drop();
+
+        result
+    }
+
+    /// Evaluates the given module source, registered under the given key.
+    ///
+    /// The modules it imports are loaded with the module loader, relatively to the key. Returns
+    /// the namespace object of the module, whose properties are its exports.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    ///
+    /// let namespace = context
+    ///     .eval_module_source("main.js", "export default 1 + 3;")
+    ///     .unwrap();
+    /// let value = namespace.get_field("default", &mut context).unwrap();
+    ///
+    /// assert_eq!(value.as_number().unwrap(), 4.0);
+    /// ```
+    #[allow(clippy::drop_copy)]
+    pub fn eval_module_source<T: AsRef<[u8]>>(&mut self, key: &str, src: T) -> Result<Value> {
+        let main_timer = BoaProfiler::global().start_event("Main", "Main");
+
+        let result = Module::parse(key, src.as_ref(), self).and_then(|module| {
+            self.modules.insert(key.into(), module.clone());
+            module.load_link_and_evaluate(self)
+        });
 
-        let mut vm = VM::new(compiler, self);
-        // Generate Bytecode and place it into instruction_stack
-        // Interpret the Bytecode
-        let result = vm.run();
         // The main_timer needs to be dropped before the BoaProfiler is.
         drop(main_timer);
         BoaProfiler::global().drop();
@@ -719,4 +1145,187 @@ impl Context {
     pub fn set_trace(&mut self, trace: bool) {
         self.trace = trace;
     }
+
+    /// Enables or disables the generation of code from strings.
+    ///
+    /// When disabled, `eval` and the `Function` constructor throw an `EvalError` instead of
+    /// running the given code, so hosts can keep sandboxed scripts to the code they were given.
+    /// Calling `eval` with a value that is not a string still returns it as it is.
+    ///
+    /// # Examples
+    /// ```
+    /// # use boa::Context;
+    /// let mut context = Context::new();
+    /// context.set_eval_enabled(false);
+    ///
+    /// assert!(context.eval("eval('1 + 3')").is_err());
+    /// assert!(context.eval("new Function('return 1 + 3')").is_err());
+    /// assert_eq!(context.eval("1 + 3").unwrap().as_number(), Some(4.0));
+    /// ```
+    #[inline]
+    pub fn set_eval_enabled(&mut self, enabled: bool) {
+        self.eval_enabled = enabled;
+    }
+
+    /// Returns whether code can be generated from strings, with `eval` and the `Function`
+    /// constructor.
+    #[inline]
+    pub fn eval_enabled(&self) -> bool {
+        self.eval_enabled
+    }
+
+    /// Returns whether the code being evaluated is strict mode code.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-strict-mode-code
+    #[inline]
+    pub(crate) fn strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Runs `f` as strict mode code if `strict` is true, or as sloppy mode code otherwise.
+    ///
+    /// The strictness of the caller is restored afterwards.
+    pub(crate) fn with_strict<T, F>(&mut self, strict: bool, f: F) -> T
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        let caller_strict = std::mem::replace(&mut self.strict, strict);
+        let result = f(self);
+        self.strict = caller_strict;
+        result
+    }
+
+    /// Sets the time zone `Date` objects use for local time.
+    ///
+    /// It defaults to the time zone of the host.
+    ///
+    /// # Examples
+    /// ```
+    /// # use boa::{builtins::date::TimeZone, Context};
+    /// let mut context = Context::new();
+    /// context.set_time_zone(TimeZone::from_name("UTC").unwrap());
+    ///
+    /// let hours = context.eval("new Date(Date.UTC(2021, 0, 1, 12)).getHours()");
+    /// assert_eq!(hours.unwrap().as_number(), Some(12.0));
+    /// ```
+    #[inline]
+    pub fn set_time_zone(&mut self, time_zone: TimeZone) {
+        self.time_zone = time_zone;
+    }
+
+    /// Returns the time zone `Date` objects use for local time.
+    #[inline]
+    pub fn time_zone(&self) -> &TimeZone {
+        &self.time_zone
+    }
+
+    /// Returns whether the value is the `%eval%` intrinsic of the realm.
+    #[inline]
+    pub(crate) fn is_intrinsic_eval(&self, value: &Value) -> bool {
+        match (value.as_object(), &self.eval_function) {
+            (Some(function), Some(eval)) => GcObject::equals(&function, eval),
+            _ => false,
+        }
+    }
+
+    /// Adds a job to the end of the job queue.
+    ///
+    /// The job runs the next time the queue is drained with [`Context::run_jobs`].
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-hostenqueuepromisejob
+    #[inline]
+    pub fn enqueue_job(&mut self, job: NativeJob) {
+        self.job_queue.push_back(job);
+    }
+
+    /// Runs the queued jobs, in order, until the queue is empty.
+    ///
+    /// Jobs are never run by `eval`, so hosts should call this once the script has finished to
+    /// settle the pending promises. Jobs enqueued while running are run as well.
+    ///
+    /// If a job throws, the error is returned and the remaining jobs are left in the queue, so the
+    /// host can report the error and call this again to keep draining the queue.
+    ///
+    /// Between jobs, the objects kept alive by `WeakRef`s are released, and the cleanup callbacks
+    /// of the `FinalizationRegistry` objects are queued for the targets that have been collected.
+    /// The stacks of the async functions and generators that were collected before finishing
+    /// are unwound as well.
+    ///
+    /// # Examples
+    /// ```
+    /// # use boa::Context;
+    /// let mut context = Context::new();
+    /// context.eval("var result; Promise.resolve(1).then(value => { result = value; });").unwrap();
+    /// assert!(context.eval("result").unwrap().is_undefined());
+    ///
+    /// context.run_jobs().unwrap();
+    /// assert_eq!(context.eval("result").unwrap().as_number(), Some(1.0));
+    /// ```
+    pub fn run_jobs(&mut self) -> Result<()> {
+        loop {
+            self.kept_alive.clear();
+            self.cleanup_finalization_registries();
+
+            match self.job_queue.pop_front() {
+                Some(job) => {
+                    job.call(self)?;
+                }
+                None => return Ok(()),
+            }
+        }
+    }
+
+    /// Keeps the object alive until the end of the current job.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-addtokeptobjects
+    #[inline]
+    pub(crate) fn add_to_kept_objects(&mut self, object: GcObject) {
+        self.kept_alive.push(object);
+    }
+
+    /// Adds a `FinalizationRegistry` object to the registries checked between jobs.
+    #[inline]
+    pub(crate) fn add_finalization_registry(&mut self, registry: &GcObject) {
+        self.finalization_registries.push(registry.downgrade());
+    }
+
+    /// Queues a call to the cleanup callback of the `FinalizationRegistry` objects for each of
+    /// their targets that has been collected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-host-cleanup-finalization-registry
+    fn cleanup_finalization_registries(&mut self) {
+        self.finalization_registries.retain(WeakGcObject::is_alive);
+
+        let registries: Vec<_> = self
+            .finalization_registries
+            .iter()
+            .filter_map(WeakGcObject::upgrade)
+            .collect();
+        for registry in registries {
+            let cleared = registry
+                .borrow_mut()
+                .as_finalization_registry_mut()
+                .map(|registry| registry.take_cleared_cells());
+            if let Some((cleanup, held_values)) = cleared {
+                for held_value in held_values {
+                    let cleanup = cleanup.clone();
+                    self.enqueue_job(NativeJob::new(move |context| {
+                        cleanup.call(&Value::undefined(), &[held_value], context)
+                    }));
+                }
+            }
+        }
+    }
 }
@@ -36,6 +36,9 @@ pub struct DeclarativeEnvironmentRecordBinding {
 pub struct DeclarativeEnvironmentRecord {
     pub env_rec: FxHashMap<Box<str>, DeclarativeEnvironmentRecordBinding>,
     pub outer_env: Option<Environment>,
+    /// Whether `var` and function declarations are created in this record instead of an outer
+    /// one.
+    pub var_scope: bool,
 }
 
 impl DeclarativeEnvironmentRecord {
@@ -45,6 +48,26 @@ impl DeclarativeEnvironmentRecord {
         let boxed_env = Box::new(DeclarativeEnvironmentRecord {
             env_rec: FxHashMap::default(),
             outer_env: env,
+            var_scope: false,
+        });
+
+        Gc::new(GcCell::new(boxed_env))
+    }
+
+    /// Creates a declarative environment which is also the variable scope of the code running in
+    /// it, like the environment of a strict mode eval, whose declarations are not hoisted to the
+    /// caller.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performeval
+    pub fn new_var_scope(env: Option<Environment>) -> Environment {
+        let _timer = BoaProfiler::global().start_event("new_declarative_environment", "env");
+        let boxed_env = Box::new(DeclarativeEnvironmentRecord {
+            env_rec: FxHashMap::default(),
+            outer_env: env,
+            var_scope: true,
         });
 
         Gc::new(GcCell::new(boxed_env))
@@ -144,18 +167,30 @@ impl EnvironmentRecordTrait for DeclarativeEnvironmentRecord {
         if record.strict {
             strict = true
         }
+        // The errors are constructed directly, since looking up their constructors would borrow
+        // this environment again.
         if record.value.is_none() {
-            return Err(
-                context.construct_reference_error(format!("{} has not been initialized", name))
-            );
+            let constructor = context
+                .standard_objects()
+                .reference_error_object()
+                .constructor();
+            let message = format!("{} has not been initialized", name);
+            return Err(constructor.construct(
+                &[message.into()],
+                constructor.clone().into(),
+                context,
+            )?);
         }
         if record.mutable {
             record.value = Some(value);
         } else if strict {
-            return Err(context.construct_reference_error(format!(
-                "Cannot mutate an immutable binding {}",
-                name
-            )));
+            let constructor = context.standard_objects().type_error_object().constructor();
+            let message = format!("Cannot mutate an immutable binding {}", name);
+            return Err(constructor.construct(
+                &[message.into()],
+                constructor.clone().into(),
+                context,
+            )?);
         }
 
         Ok(())
@@ -214,4 +249,8 @@ impl EnvironmentRecordTrait for DeclarativeEnvironmentRecord {
     fn get_environment_type(&self) -> EnvironmentType {
         EnvironmentType::Declarative
     }
+
+    fn is_var_scope(&self) -> bool {
+        self.var_scope
+    }
 }
@@ -11,7 +11,12 @@
 
 use crate::{environment::lexical_environment::VariableScope, object::GcObject};
 use crate::{
-    environment::lexical_environment::{Environment, EnvironmentType},
+    environment::{
+        function_environment_record::FunctionEnvironmentRecord,
+        global_environment_record::GlobalEnvironmentRecord,
+        lexical_environment::{Environment, EnvironmentType},
+        module_environment_record::ModuleEnvironmentRecord,
+    },
     gc::{Finalize, Trace},
     Context, Result, Value,
 };
@@ -97,6 +102,12 @@ pub trait EnvironmentRecordTrait: Debug + Trace + Finalize {
     /// Otherwise, return None.
     fn with_base_object(&self) -> Option<GcObject>;
 
+    /// If the binding `name` is a property of the binding object of this Environment Record,
+    /// return that object. Otherwise, return None.
+    fn binding_object(&self, _name: &str) -> Option<GcObject> {
+        None
+    }
+
     /// Get the next environment up
     fn get_outer_environment_ref(&self) -> Option<&Environment>;
     fn get_outer_environment(&self) -> Option<Environment> {
@@ -109,6 +120,35 @@ pub trait EnvironmentRecordTrait: Debug + Trace + Finalize {
     /// Get the type of environment this is
     fn get_environment_type(&self) -> EnvironmentType;
 
+    /// Checks if `var` and function declarations are created in this Environment Record, rather
+    /// than in an outer one.
+    fn is_var_scope(&self) -> bool {
+        matches!(
+            self.get_environment_type(),
+            EnvironmentType::Function | EnvironmentType::Module | EnvironmentType::Global
+        )
+    }
+
+    /// Returns the function Environment Record, if this is one.
+    fn as_function_environment(&self) -> Option<&FunctionEnvironmentRecord> {
+        None
+    }
+
+    /// Returns the function Environment Record mutably, if this is one.
+    fn as_function_environment_mut(&mut self) -> Option<&mut FunctionEnvironmentRecord> {
+        None
+    }
+
+    /// Returns the global Environment Record, if this is one.
+    fn as_global_environment(&self) -> Option<&GlobalEnvironmentRecord> {
+        None
+    }
+
+    /// Returns the module Environment Record mutably, if this is one.
+    fn as_module_environment_mut(&mut self) -> Option<&mut ModuleEnvironmentRecord> {
+        None
+    }
+
     /// Return the `this` binding from the environment or try to get it from outer environments
     fn recursive_get_this_binding(&self, context: &mut Context) -> Result<Value> {
         if self.has_this_binding() {
@@ -130,12 +170,12 @@ pub trait EnvironmentRecordTrait: Debug + Trace + Finalize {
         context: &mut Context,
     ) -> Result<()> {
         match scope {
-            VariableScope::Block => self.create_mutable_binding(name, deletion, false, context),
-            VariableScope::Function => self
+            VariableScope::Function if !self.is_var_scope() => self
                 .get_outer_environment_ref()
                 .expect("No function or global environment")
                 .borrow_mut()
                 .recursive_create_mutable_binding(name, deletion, scope, context),
+            _ => self.create_mutable_binding(name, deletion, false, context),
         }
     }
 
@@ -148,12 +188,12 @@ pub trait EnvironmentRecordTrait: Debug + Trace + Finalize {
         context: &mut Context,
     ) -> Result<()> {
         match scope {
-            VariableScope::Block => self.create_immutable_binding(name, deletion, context),
-            VariableScope::Function => self
+            VariableScope::Function if !self.is_var_scope() => self
                 .get_outer_environment_ref()
                 .expect("No function or global environment")
                 .borrow_mut()
                 .recursive_create_immutable_binding(name, deletion, scope, context),
+            _ => self.create_immutable_binding(name, deletion, context),
         }
     }
 
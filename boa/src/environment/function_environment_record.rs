@@ -9,7 +9,7 @@
 //! More info: <https://tc39.es/ecma262/#sec-function-environment-records>
 
 use gc::{Gc, GcCell};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
     environment::{
@@ -56,6 +56,9 @@ pub struct FunctionEnvironmentRecord {
     /// `[[NewTarget]]` is the value of the `[[Construct]]` newTarget parameter.
     /// Otherwise, its value is undefined.
     pub new_target: Value,
+    /// The names of the lexical declarations of the function body, which share this record
+    /// with its parameters and `var` declarations.
+    pub lexical_names: FxHashSet<Box<str>>,
 }
 
 impl FunctionEnvironmentRecord {
@@ -65,27 +68,37 @@ impl FunctionEnvironmentRecord {
         this: Option<Value>,
         outer: Option<Environment>,
         binding_status: BindingStatus,
+        home_object: Value,
         new_target: Value,
     ) -> Environment {
         let mut func_env = FunctionEnvironmentRecord {
             declarative_record: DeclarativeEnvironmentRecord {
                 env_rec: FxHashMap::default(),
                 outer_env: outer, // this will come from Environment set as a private property of F - https://tc39.es/ecma262/#sec-ecmascript-function-objects
+                var_scope: false,
             },
             function: f,
             this_binding_status: binding_status,
-            home_object: Value::undefined(),
+            home_object,
             new_target,
             this_value: Value::undefined(),
+            lexical_names: FxHashSet::default(),
         };
         // If a `this` value has been passed, bind it to the environment
         if let Some(v) = this {
-            func_env.bind_this_value(v).unwrap();
+            func_env.this_value = v;
+            func_env.this_binding_status = BindingStatus::Initialized;
         }
         Gc::new(GcCell::new(Box::new(func_env)))
     }
 
-    pub fn bind_this_value(&mut self, value: Value) -> Result<Value> {
+    /// Checks if the function body has a lexical declaration of the name, like a `let` or a
+    /// `const` declaration, rather than a parameter or a `var` declaration.
+    pub fn has_lexical_declaration(&self, name: &str) -> bool {
+        self.lexical_names.contains(name)
+    }
+
+    pub fn bind_this_value(&mut self, value: Value, context: &mut Context) -> Result<Value> {
         match self.this_binding_status {
             // You can not bind an arrow function, their `this` value comes from the lexical scope above
             BindingStatus::Lexical => {
@@ -93,8 +106,7 @@ impl FunctionEnvironmentRecord {
             }
             // You can not bind a function twice
             BindingStatus::Initialized => {
-                todo!();
-                // context.throw_reference_error("Cannot bind to an initialised function!")
+                context.throw_reference_error("Cannot bind to an initialised function!")
             }
             BindingStatus::Uninitialized => {
                 self.this_value = value.clone();
@@ -213,13 +225,24 @@ impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
         EnvironmentType::Function
     }
 
+    fn as_function_environment(&self) -> Option<&FunctionEnvironmentRecord> {
+        Some(self)
+    }
+
+    fn as_function_environment_mut(&mut self) -> Option<&mut FunctionEnvironmentRecord> {
+        Some(self)
+    }
+
     fn recursive_create_mutable_binding(
         &mut self,
         name: String,
         deletion: bool,
-        _scope: VariableScope,
+        scope: VariableScope,
         context: &mut Context,
     ) -> Result<()> {
+        if scope == VariableScope::Block {
+            self.lexical_names.insert(name.clone().into_boxed_str());
+        }
         self.create_mutable_binding(name, deletion, false, context)
     }
 
@@ -227,9 +250,12 @@ impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
         &mut self,
         name: String,
         deletion: bool,
-        _scope: VariableScope,
+        scope: VariableScope,
         context: &mut Context,
     ) -> Result<()> {
+        if scope == VariableScope::Block {
+            self.lexical_names.insert(name.clone().into_boxed_str());
+        }
         self.create_immutable_binding(name, deletion, context)
     }
 }
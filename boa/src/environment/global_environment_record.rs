@@ -47,6 +47,7 @@ impl GlobalEnvironmentRecord {
         let dcl_rec = DeclarativeEnvironmentRecord {
             env_rec: FxHashMap::default(),
             outer_env: None,
+            var_scope: false,
         };
 
         Gc::new(GcCell::new(Box::new(GlobalEnvironmentRecord {
@@ -267,6 +268,14 @@ impl EnvironmentRecordTrait for GlobalEnvironmentRecord {
         None
     }
 
+    fn binding_object(&self, name: &str) -> Option<GcObject> {
+        if self.declarative_record.has_binding(name) {
+            None
+        } else {
+            self.object_record.binding_object(name)
+        }
+    }
+
     fn get_outer_environment(&self) -> Option<Environment> {
         None
     }
@@ -284,6 +293,10 @@ impl EnvironmentRecordTrait for GlobalEnvironmentRecord {
         EnvironmentType::Global
     }
 
+    fn as_global_environment(&self) -> Option<&GlobalEnvironmentRecord> {
+        Some(self)
+    }
+
     fn recursive_create_mutable_binding(
         &mut self,
         name: String,
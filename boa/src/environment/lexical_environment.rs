@@ -5,10 +5,13 @@
 //! The following operations are used to operate upon lexical environments
 //! This is the entrypoint to lexical environments.
 
-use super::global_environment_record::GlobalEnvironmentRecord;
+use super::{
+    global_environment_record::GlobalEnvironmentRecord,
+    object_environment_record::ObjectEnvironmentRecord,
+};
 use crate::{
-    environment::environment_record_trait::EnvironmentRecordTrait, object::GcObject, BoaProfiler,
-    Context, Result, Value,
+    environment::environment_record_trait::EnvironmentRecordTrait, object::GcObject,
+    symbol::RcSymbol, BoaProfiler, Context, Result, Value,
 };
 use gc::{Gc, GcCell};
 use std::{collections::VecDeque, error, fmt};
@@ -23,6 +26,7 @@ pub enum EnvironmentType {
     Declarative,
     Function,
     Global,
+    Module,
     Object,
 }
 
@@ -37,7 +41,7 @@ pub enum VariableScope {
 
 #[derive(Debug, Clone)]
 pub struct LexicalEnvironment {
-    environment_stack: VecDeque<Environment>,
+    pub(crate) environment_stack: VecDeque<Environment>,
 }
 
 /// An error that occurred during lexing or compiling of the source input.
@@ -85,12 +89,59 @@ impl Context {
         self.realm.environment.environment_stack.pop_back()
     }
 
+    /// Runs `f` with the global environment as the only environment, like the code of a script.
+    ///
+    /// The environments of the caller are restored afterwards.
+    pub(crate) fn with_global_environment<T, F>(&mut self, f: F) -> T
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        let global_env = self
+            .realm
+            .environment
+            .environment_stack
+            .front()
+            .expect("No global environment")
+            .clone();
+        let environment_stack = std::mem::replace(
+            &mut self.realm.environment.environment_stack,
+            VecDeque::from(vec![global_env]),
+        );
+        let result = f(self);
+        self.realm.environment.environment_stack = environment_stack;
+        result
+    }
+
     pub(crate) fn get_this_binding(&mut self) -> Result<Value> {
         self.get_current_environment()
             .borrow()
             .recursive_get_this_binding(self)
     }
 
+    /// Returns the nearest environment that provides a `this` binding.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getthisenvironment
+    pub(crate) fn get_this_environment(&mut self) -> Environment {
+        let mut env = self.get_current_environment();
+        loop {
+            let outer = {
+                let record = env.borrow();
+                if record.has_this_binding() {
+                    break;
+                }
+                record.get_outer_environment()
+            };
+            match outer {
+                Some(outer) => env = outer,
+                None => break,
+            }
+        }
+        env
+    }
+
     pub(crate) fn create_mutable_binding(
         &mut self,
         name: String,
@@ -113,21 +164,104 @@ impl Context {
             .recursive_create_immutable_binding(name, deletion, scope, self)
     }
 
+    /// Assigns to the binding the name resolves to.
+    ///
+    /// Assignments to unresolvable names create properties of the global object, which strict
+    /// mode code is not allowed to do.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-putvalue
     pub(crate) fn set_mutable_binding(
         &mut self,
         name: &str,
         value: Value,
         strict: bool,
     ) -> Result<()> {
-        self.get_current_environment()
-            .borrow_mut()
-            .recursive_set_mutable_binding(name, value, strict, self)
+        let mut env = self.get_current_environment();
+        loop {
+            let has_binding = self.environment_has_binding(&env, name)?;
+            let outer = {
+                let mut record = env.borrow_mut();
+                if has_binding {
+                    let object = record
+                        .with_base_object()
+                        .or_else(|| record.binding_object(name));
+                    if let Some(object) = object {
+                        // The setters of the object can run any code, so the environment must
+                        // not stay borrowed.
+                        drop(record);
+                        return Self::set_object_binding(object, name, value, strict, self);
+                    }
+                }
+                match record.get_outer_environment() {
+                    Some(outer) if !has_binding => outer,
+                    _ if has_binding => {
+                        return record.recursive_set_mutable_binding(name, value, strict, self)
+                    }
+                    _ => break,
+                }
+            };
+            env = outer;
+        }
+
+        if strict {
+            return Err(self.construct_reference_error(format!("{} is not defined", name)));
+        }
+        let mut global = self.global_object();
+        global.set(name.into(), value, global.clone().into(), self)?;
+        Ok(())
+    }
+
+    /// Sets a binding of the object of a `with` statement or of the global object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object-environment-records-setmutablebinding-n-v-s
+    fn set_object_binding(
+        mut object: GcObject,
+        name: &str,
+        value: Value,
+        strict: bool,
+        context: &mut Self,
+    ) -> Result<()> {
+        // 1. Let stillExists be ? HasProperty(bindingObject, N).
+        // 2. If stillExists is false and S is true, throw a ReferenceError exception.
+        if strict && !object.has_property(&name.into(), context)? {
+            return Err(context.construct_reference_error(format!("{} is not defined", name)));
+        }
+
+        // 3. Return ? Set(bindingObject, N, V, S).
+        let receiver = object.clone().into();
+        if !object.set(name.into(), value, receiver, context)? && strict {
+            return Err(
+                context.construct_type_error(format!("cannot assign to read-only {}", name))
+            );
+        }
+        Ok(())
     }
 
+    /// Initializes a binding declared in the current environment or in its outer environments.
+    ///
+    /// Declarations never create bindings in the objects of `with` statements, so their
+    /// environments are skipped.
     pub(crate) fn initialize_binding(&mut self, name: &str, value: Value) -> Result<()> {
-        self.get_current_environment()
-            .borrow_mut()
-            .recursive_initialize_binding(name, value, self)
+        let mut env = self.get_current_environment();
+        loop {
+            let outer = {
+                let mut record = env.borrow_mut();
+                if record.with_base_object().is_none() && record.has_binding(name) {
+                    return record.initialize_binding(name, value, self);
+                }
+                match record.get_outer_environment() {
+                    Some(outer) => outer,
+                    None => return record.initialize_binding(name, value, self),
+                }
+            };
+            env = outer;
+        }
     }
 
     /// When neededing to clone an environment (linking it with another environnment)
@@ -141,16 +275,131 @@ impl Context {
             .clone()
     }
 
-    pub(crate) fn has_binding(&mut self, name: &str) -> bool {
-        self.get_current_environment()
-            .borrow()
-            .recursive_has_binding(name)
+    /// Checks if the name resolves to a binding of the current environment or of its outer
+    /// environments.
+    pub(crate) fn has_binding(&mut self, name: &str) -> Result<bool> {
+        let mut env = Some(self.get_current_environment());
+        while let Some(current) = env {
+            if self.environment_has_binding(&current, name)? {
+                return Ok(true);
+            }
+            env = current.borrow().get_outer_environment();
+        }
+        Ok(false)
+    }
+
+    /// Checks if an environment has a binding for the name, like identifier resolution does.
+    ///
+    /// The object of a `with` statement is asked through its internal methods, which can run any
+    /// code, so the environment must not stay borrowed.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getidentifierreference
+    fn environment_has_binding(&mut self, env: &Environment, name: &str) -> Result<bool> {
+        let object = {
+            let record = env.borrow();
+            match record.with_base_object() {
+                Some(object) => object,
+                None => return Ok(record.has_binding(name)),
+            }
+        };
+        ObjectEnvironmentRecord::has_with_binding(&object, name, self)
+    }
+
+    /// Checks if a binding exists in the current variable scope, that is in the current
+    /// environment or in the outer environments up to the nearest one where `var` declarations
+    /// are created.
+    ///
+    /// `var` and function declarations use this to redeclare the bindings of their own scope,
+    /// without assigning to the bindings they shadow.
+    pub(crate) fn has_var_scope_binding(&mut self, name: &str) -> bool {
+        let mut env = Some(self.get_current_environment());
+        while let Some(current) = env {
+            let record = current.borrow();
+            // The properties of the objects of `with` statements are not declarations.
+            if record.with_base_object().is_none() && record.has_binding(name) {
+                return true;
+            }
+            env = if record.is_var_scope() {
+                None
+            } else {
+                record.get_outer_environment()
+            };
+        }
+        false
     }
 
     pub(crate) fn get_binding_value(&mut self, name: &str) -> Result<Value> {
-        self.get_current_environment()
-            .borrow()
-            .recursive_get_binding_value(name, self)
+        let mut env = self.get_current_environment();
+        loop {
+            let has_binding = self.environment_has_binding(&env, name)?;
+            let outer = {
+                let record = env.borrow();
+                if has_binding {
+                    return match record.with_base_object() {
+                        Some(object) => {
+                            // The getters of the object can run any code, so the environment
+                            // must not stay borrowed.
+                            drop(record);
+                            object.get(&name.into(), object.clone().into(), self)
+                        }
+                        None => record.get_binding_value(name, false, self),
+                    };
+                }
+                record.get_outer_environment()
+            };
+            match outer {
+                Some(outer) => env = outer,
+                None => return self.throw_reference_error(format!("{} is not defined", name)),
+            }
+        }
+    }
+
+    /// Returns the object of the `with` statement binding the name, if any, which is the `this`
+    /// value of the calls of the functions it holds.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-evaluatecall
+    pub(crate) fn get_with_base_object(&mut self, name: &str) -> Result<Option<GcObject>> {
+        let mut env = Some(self.get_current_environment());
+        while let Some(current) = env {
+            if self.environment_has_binding(&current, name)? {
+                return Ok(current.borrow().with_base_object());
+            }
+            env = current.borrow().get_outer_environment();
+        }
+        Ok(None)
+    }
+
+    /// Resolves a private name, like `#x`, in the private environments of the classes around
+    /// the running code.
+    ///
+    /// The private environment of a class is a declarative environment around its body,
+    /// binding each private name to a unique symbol. Names starting with `#` cannot be declared
+    /// any other way, and the objects of `with` statements are skipped.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-resolve-private-identifier
+    pub(crate) fn resolve_private_identifier(&mut self, name: &str) -> Result<RcSymbol> {
+        let mut env = Some(self.get_current_environment());
+        while let Some(current) = env {
+            let record = current.borrow();
+            if record.get_environment_type() == EnvironmentType::Declarative
+                && record.has_binding(name)
+            {
+                if let Value::Symbol(ref symbol) = record.get_binding_value(name, true, self)? {
+                    return Ok(symbol.clone());
+                }
+            }
+            env = record.get_outer_environment();
+        }
+        Err(self.construct_syntax_error(format!("undeclared private name {}", name)))
     }
 }
 
@@ -172,7 +421,7 @@ mod tests {
           }
         "#;
 
-        assert_eq!(&exec(scenario), "\"bar is not defined\"");
+        assert_eq!(&exec(scenario), "\"bar is not defined at line 7, col 13\"");
     }
 
     #[test]
@@ -189,7 +438,7 @@ mod tests {
           }
         "#;
 
-        assert_eq!(&exec(scenario), "\"bar is not defined\"");
+        assert_eq!(&exec(scenario), "\"bar is not defined at line 7, col 13\"");
     }
 
     #[test]
@@ -220,7 +469,7 @@ mod tests {
           }
         "#;
 
-        assert_eq!(&exec(scenario), "\"bar is not defined\"");
+        assert_eq!(&exec(scenario), "\"bar is not defined at line 4, col 17\"");
     }
 
     #[test]
@@ -0,0 +1,207 @@
+//! # Module Environment Records
+//!
+//! A module Environment Record is a declarative Environment Record that is used to represent
+//! the outer scope of an ECMAScript Module.
+//! In addition to normal mutable and immutable bindings, module Environment Records also provide
+//! immutable import bindings which are bindings that provide indirect access to a target binding
+//! that exists in another Environment Record.
+//! More info: <https://tc39.es/ecma262/#sec-module-environment-records>
+
+use gc::{Gc, GcCell};
+use rustc_hash::FxHashMap;
+
+use crate::{
+    environment::{
+        declarative_environment_record::DeclarativeEnvironmentRecord,
+        environment_record_trait::EnvironmentRecordTrait,
+        lexical_environment::{Environment, EnvironmentType, VariableScope},
+    },
+    gc::{Finalize, Trace},
+    module::Module,
+    object::GcObject,
+    BoaProfiler, Context, Result, Value,
+};
+
+/// An import binding, which gives access to a binding of another module.
+///
+/// The environment of the target module is looked up when the binding is accessed, since it may
+/// not have been created yet when the import binding is.
+#[derive(Debug, Trace, Finalize, Clone)]
+pub struct ImportBinding {
+    module: Module,
+    binding_name: Box<str>,
+}
+
+/// <https://tc39.es/ecma262/#sec-module-environment-records>
+#[derive(Debug, Trace, Finalize, Clone)]
+pub struct ModuleEnvironmentRecord {
+    pub declarative_record: DeclarativeEnvironmentRecord,
+    pub import_bindings: FxHashMap<Box<str>, ImportBinding>,
+}
+
+impl ModuleEnvironmentRecord {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(outer: Option<Environment>) -> Environment {
+        let _timer = BoaProfiler::global().start_event("new_module_environment", "env");
+        let module_env = ModuleEnvironmentRecord {
+            declarative_record: DeclarativeEnvironmentRecord {
+                env_rec: FxHashMap::default(),
+                outer_env: outer,
+                var_scope: false,
+            },
+            import_bindings: FxHashMap::default(),
+        };
+
+        Gc::new(GcCell::new(Box::new(module_env)))
+    }
+
+    /// Creates an immutable indirect binding to the binding `binding_name` of `module`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createimportbinding
+    pub fn create_import_binding(&mut self, name: String, module: Module, binding_name: &str) {
+        assert!(
+            !self.has_binding(&name),
+            "Identifier {} has already been declared",
+            name
+        );
+
+        self.import_bindings.insert(
+            name.into_boxed_str(),
+            ImportBinding {
+                module,
+                binding_name: binding_name.into(),
+            },
+        );
+    }
+}
+
+impl EnvironmentRecordTrait for ModuleEnvironmentRecord {
+    fn has_binding(&self, name: &str) -> bool {
+        self.import_bindings.contains_key(name) || self.declarative_record.has_binding(name)
+    }
+
+    fn create_mutable_binding(
+        &mut self,
+        name: String,
+        deletion: bool,
+        allow_name_reuse: bool,
+        context: &mut Context,
+    ) -> Result<()> {
+        self.declarative_record
+            .create_mutable_binding(name, deletion, allow_name_reuse, context)
+    }
+
+    fn create_immutable_binding(
+        &mut self,
+        name: String,
+        strict: bool,
+        context: &mut Context,
+    ) -> Result<()> {
+        self.declarative_record
+            .create_immutable_binding(name, strict, context)
+    }
+
+    fn initialize_binding(
+        &mut self,
+        name: &str,
+        value: Value,
+        context: &mut Context,
+    ) -> Result<()> {
+        self.declarative_record
+            .initialize_binding(name, value, context)
+    }
+
+    fn set_mutable_binding(
+        &mut self,
+        name: &str,
+        value: Value,
+        strict: bool,
+        context: &mut Context,
+    ) -> Result<()> {
+        if self.import_bindings.contains_key(name) {
+            // The error is constructed directly, since looking up `TypeError` would borrow this
+            // environment again.
+            let constructor = context.standard_objects().type_error_object().constructor();
+            let message = format!("Cannot assign to the import binding {}", name);
+            return Err(constructor.construct(
+                &[message.into()],
+                constructor.clone().into(),
+                context,
+            )?);
+        }
+        self.declarative_record
+            .set_mutable_binding(name, value, strict, context)
+    }
+
+    fn get_binding_value(&self, name: &str, strict: bool, context: &mut Context) -> Result<Value> {
+        if let Some(binding) = self.import_bindings.get(name) {
+            return binding
+                .module
+                .get_binding_value(&binding.binding_name, context);
+        }
+        self.declarative_record
+            .get_binding_value(name, strict, context)
+    }
+
+    fn delete_binding(&mut self, name: &str) -> bool {
+        if self.import_bindings.contains_key(name) {
+            return false;
+        }
+        self.declarative_record.delete_binding(name)
+    }
+
+    fn has_this_binding(&self) -> bool {
+        true
+    }
+
+    fn get_this_binding(&self, _context: &mut Context) -> Result<Value> {
+        Ok(Value::undefined())
+    }
+
+    fn has_super_binding(&self) -> bool {
+        false
+    }
+
+    fn with_base_object(&self) -> Option<GcObject> {
+        None
+    }
+
+    fn get_outer_environment_ref(&self) -> Option<&Environment> {
+        self.declarative_record.get_outer_environment_ref()
+    }
+
+    fn set_outer_environment(&mut self, env: Environment) {
+        self.declarative_record.set_outer_environment(env)
+    }
+
+    fn get_environment_type(&self) -> EnvironmentType {
+        EnvironmentType::Module
+    }
+
+    fn as_module_environment_mut(&mut self) -> Option<&mut ModuleEnvironmentRecord> {
+        Some(self)
+    }
+
+    fn recursive_create_mutable_binding(
+        &mut self,
+        name: String,
+        deletion: bool,
+        _scope: VariableScope,
+        context: &mut Context,
+    ) -> Result<()> {
+        self.create_mutable_binding(name, deletion, false, context)
+    }
+
+    fn recursive_create_immutable_binding(
+        &mut self,
+        name: String,
+        deletion: bool,
+        _scope: VariableScope,
+        context: &mut Context,
+    ) -> Result<()> {
+        self.create_immutable_binding(name, deletion, context)
+    }
+}
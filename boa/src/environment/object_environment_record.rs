@@ -17,6 +17,7 @@ use crate::{
     object::GcObject,
     property::PropertyDescriptor,
     property::{Attribute, DataDescriptor},
+    symbol::WellKnownSymbols,
     Context, Result, Value,
 };
 
@@ -24,36 +25,100 @@ use crate::{
 pub struct ObjectEnvironmentRecord {
     // TODO: bindings should be an object.
     pub bindings: Value,
+    /// Object Environment Records created for with statements (13.11)
+    /// can provide their binding object as an implicit this value for use in function calls.
+    /// The capability is controlled by a withEnvironment Boolean value that is associated
+    /// with each object Environment Record. By default, the value of withEnvironment is false
+    /// for any object Environment Record.
     pub with_environment: bool,
     pub outer_env: Option<Environment>,
 }
 
 impl ObjectEnvironmentRecord {
+    /// Creates a new object environment record binding the properties of `object`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newobjectenvironment
     #[allow(clippy::new_ret_no_self)]
-    pub fn new(object: Value, environment: Option<Environment>) -> Environment {
+    pub fn new(
+        object: Value,
+        with_environment: bool,
+        environment: Option<Environment>,
+    ) -> Environment {
         Gc::new(GcCell::new(Box::new(ObjectEnvironmentRecord {
             bindings: object,
             outer_env: environment,
-            /// Object Environment Records created for with statements (13.11)
-            /// can provide their binding object as an implicit this value for use in function calls.
-            /// The capability is controlled by a withEnvironment Boolean value that is associated
-            /// with each object Environment Record. By default, the value of withEnvironment is false
-            /// for any object Environment Record.
-            with_environment: false,
+            with_environment,
         })))
     }
+
+    /// `HasBinding ( N )` of the object Environment Record of a `with` statement.
+    ///
+    /// Unlike `EnvironmentRecordTrait::has_binding`, this runs the internal methods of the
+    /// object, like the `has` trap of a proxy and the getter of `@@unscopables`, so identifier
+    /// resolution uses it without keeping the environment borrowed.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object-environment-records-hasbinding-n
+    pub(crate) fn has_with_binding(
+        object: &GcObject,
+        name: &str,
+        context: &mut Context,
+    ) -> Result<bool> {
+        // 2. Let foundBinding be ? HasProperty(bindingObject, N).
+        // 3. If foundBinding is false, return false.
+        if !object.has_property(&name.into(), context)? {
+            return Ok(false);
+        }
+
+        // 5. Let unscopables be ? Get(bindingObject, @@unscopables).
+        let unscopables = object.get(
+            &WellKnownSymbols::unscopables().into(),
+            object.clone().into(),
+            context,
+        )?;
+
+        // 6. If Type(unscopables) is Object, then
+        if let Some(unscopables) = unscopables.as_object() {
+            // a. Let blocked be ! ToBoolean(? Get(unscopables, N)).
+            // b. If blocked is true, return false.
+            let blocked = unscopables.get(&name.into(), unscopables.clone().into(), context)?;
+            if blocked.to_boolean() {
+                return Ok(false);
+            }
+        }
+
+        // 7. Return true.
+        Ok(true)
+    }
 }
 
 impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
     fn has_binding(&self, name: &str) -> bool {
-        if self.bindings.has_field(name) {
-            if self.with_environment {
-                // TODO: implement unscopables
-            }
-            true
-        } else {
-            false
+        if !self.bindings.has_field(name) {
+            return false;
+        }
+        if !self.with_environment {
+            return true;
         }
+
+        // The properties listed in `@@unscopables` are hidden from `with` statements. Like
+        // `has_field`, this only looks at ordinary properties, so it does not run getters.
+        // Identifier resolution uses `has_with_binding` instead, which runs them.
+        let blocked = match self.bindings.get_property(WellKnownSymbols::unscopables()) {
+            Some(PropertyDescriptor::Data(ref unscopables)) => {
+                match unscopables.value().get_property(name) {
+                    Some(PropertyDescriptor::Data(ref blocked)) => blocked.value().to_boolean(),
+                    _ => false,
+                }
+            }
+            _ => false,
+        };
+        !blocked
     }
 
     fn create_mutable_binding(
@@ -102,16 +167,17 @@ impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
         &mut self,
         name: &str,
         value: Value,
-        strict: bool,
+        _strict: bool,
         _context: &mut Context,
     ) -> Result<()> {
-        debug_assert!(value.is_object() || value.is_function());
-        let mut property = DataDescriptor::new(value, Attribute::ENUMERABLE);
-        property.set_configurable(strict);
-        self.bindings
-            .as_object()
-            .expect("binding object")
-            .insert(name, property);
+        // Assignments to the existing properties go through `Context::set_mutable_binding`, which
+        // can run their setters, so only the bindings being created or initialized end up here.
+        let mut bindings = self.bindings.as_object().expect("binding object");
+        let attributes = match bindings.ordinary_get_own_property(&name.into()) {
+            Some(PropertyDescriptor::Data(ref data)) => data.attributes(),
+            _ => Attribute::all(),
+        };
+        bindings.insert(name, DataDescriptor::new(value, attributes));
         Ok(())
     }
 
@@ -155,6 +221,14 @@ impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
         None
     }
 
+    fn binding_object(&self, name: &str) -> Option<GcObject> {
+        if self.has_binding(name) {
+            self.bindings.as_object()
+        } else {
+            None
+        }
+    }
+
     fn get_outer_environment_ref(&self) -> Option<&Environment> {
         self.outer_env.as_ref()
     }
@@ -164,6 +238,6 @@ impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
     }
 
     fn get_environment_type(&self) -> EnvironmentType {
-        EnvironmentType::Function
+        EnvironmentType::Object
     }
 }
@@ -0,0 +1,198 @@
+//! The call stack of the interpreter, and the stack traces captured from it.
+//!
+//! Each function called from JavaScript code adds a frame to the stack, holding the name of the
+//! function and the spans of the nodes its code is evaluating. `Error` objects capture the stack
+//! when they are created, which can be read from Rust with [`Value::stack_trace`][stack_trace]
+//! and from JavaScript with the non-standard `stack` property of the error.
+//!
+//! [stack_trace]: crate::Value::stack_trace
+
+use crate::{
+    gc::{empty_trace, Finalize, Trace},
+    syntax::ast::Span,
+};
+use std::fmt;
+
+/// The name of the frame of the code of scripts, which is not in a function.
+const GLOBAL_FRAME_NAME: &str = "<global>";
+
+/// A frame of the call stack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StackFrame {
+    function_name: Box<str>,
+    span: Option<Span>,
+}
+
+impl StackFrame {
+    /// Gets the name of the function, which is `<global>` for the code of scripts and
+    /// `<anonymous>` for functions without a name.
+    #[inline]
+    pub fn function_name(&self) -> &str {
+        &self.function_name
+    }
+
+    /// Gets the span of the innermost node being evaluated in the frame, if any.
+    #[inline]
+    pub fn span(&self) -> Option<Span> {
+        self.span
+    }
+}
+
+impl fmt::Display for StackFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "at {}", self.function_name)?;
+        if let Some(span) = self.span {
+            let start = span.start();
+            write!(
+                f,
+                " (line {}, col {})",
+                start.line_number(),
+                start.column_number()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The frames of the call stack captured at some point of the execution, from the innermost.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Finalize)]
+pub struct StackTrace {
+    frames: Box<[StackFrame]>,
+}
+
+impl StackTrace {
+    /// Gets the frames of the stack trace, from the innermost.
+    #[inline]
+    pub fn frames(&self) -> &[StackFrame] {
+        &self.frames
+    }
+}
+
+// SAFETY: `StackTrace` does not contain any garbage collected values.
+unsafe impl Trace for StackTrace {
+    empty_trace!();
+}
+
+impl fmt::Display for StackTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, frame) in self.frames.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "    {}", frame)?;
+        }
+        Ok(())
+    }
+}
+
+/// A frame of the call stack of the interpreter.
+#[derive(Debug, Clone)]
+pub(crate) struct Frame {
+    function_name: Box<str>,
+
+    /// The spans of the nodes being evaluated in the frame, from the outermost.
+    spans: Vec<Span>,
+}
+
+impl Frame {
+    /// Creates a new frame for the function with the given name.
+    fn new(function_name: Box<str>) -> Self {
+        Self {
+            function_name,
+            spans: Vec::new(),
+        }
+    }
+
+    /// Gets the frame of the stack trace for this frame, which has the span of the innermost
+    /// node being evaluated.
+    fn capture(&self) -> StackFrame {
+        StackFrame {
+            function_name: self.function_name.clone(),
+            span: self.spans.last().copied(),
+        }
+    }
+}
+
+/// The call stack of the interpreter, with the frame of the code of scripts at the bottom.
+#[derive(Debug, Clone)]
+pub(crate) struct CallStack {
+    frames: Vec<Frame>,
+}
+
+impl Default for CallStack {
+    fn default() -> Self {
+        Self {
+            frames: vec![Frame::new(GLOBAL_FRAME_NAME.into())],
+        }
+    }
+}
+
+impl CallStack {
+    /// Adds a frame for a call to the function with the given name.
+    #[inline]
+    pub(crate) fn push(&mut self, function_name: Box<str>) {
+        self.frames.push(Frame::new(function_name));
+    }
+
+    /// Removes the frame of the function that returned.
+    ///
+    /// The frame of the code of scripts is never removed.
+    #[inline]
+    pub(crate) fn pop(&mut self) {
+        if self.frames.len() > 1 {
+            self.frames.pop();
+        }
+    }
+
+    /// Gets the current frame.
+    #[inline]
+    fn current(&mut self) -> &mut Frame {
+        self.frames
+            .last_mut()
+            .expect("the frame of the code of scripts is never removed")
+    }
+
+    /// Records that the node with the given span is being evaluated in the current frame.
+    #[inline]
+    pub(crate) fn enter(&mut self, span: Span) {
+        self.current().spans.push(span);
+    }
+
+    /// Records that the innermost node being evaluated in the current frame has finished.
+    #[inline]
+    pub(crate) fn exit(&mut self) {
+        self.current().spans.pop();
+    }
+
+    /// Gets the span of the innermost node being evaluated, if any.
+    ///
+    /// Frames of native functions evaluate no nodes, so in them this is the span of the call.
+    #[inline]
+    pub(crate) fn current_span(&self) -> Option<Span> {
+        self.frames
+            .iter()
+            .rev()
+            .find_map(|frame| frame.spans.last().copied())
+    }
+
+    /// Gets the number of nodes being evaluated in the current frame.
+    #[cfg(feature = "vm")]
+    #[inline]
+    pub(crate) fn span_depth(&self) -> usize {
+        self.frames.last().map_or(0, |frame| frame.spans.len())
+    }
+
+    /// Forgets the nodes being evaluated in the current frame above the given depth.
+    #[cfg(feature = "vm")]
+    #[inline]
+    pub(crate) fn truncate_spans(&mut self, depth: usize) {
+        self.current().spans.truncate(depth);
+    }
+
+    /// Captures the current frames, from the innermost.
+    pub(crate) fn capture(&self) -> StackTrace {
+        StackTrace {
+            frames: self.frames.iter().rev().map(Frame::capture).collect(),
+        }
+    }
+}
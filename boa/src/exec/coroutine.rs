@@ -0,0 +1,427 @@
+//! Suspendable execution of function bodies.
+//!
+//! The tree walking interpreter evaluates the AST recursively, so the state of a running function
+//! lives on the native stack. To suspend a function in the middle of an expression (for `yield`
+//! and `await`), the suspension point returns an error that unwinds the native stack up to the
+//! [`Coroutine`] running the body. On the way, every node that already did part of its work
+//! saves what it did in a [`ResumeFrame`].
+//!
+//! Resuming the coroutine runs the body again from the top. The nodes on the path to the
+//! suspension point take their frame back and replay the work they saved instead of doing it
+//! again, until the suspension point is reached and evaluates to the value the coroutine was
+//! resumed with.
+//!
+//! The frames of a suspended coroutine are owned by the object that resumes it, so the values
+//! they hold are traced by the garbage collector like any other value, and no native stack has
+//! to be kept around.
+
+use crate::{
+    builtins::iterable::IteratorRecord,
+    environment::lexical_environment::Environment,
+    exec::{Executable, InterpreterState},
+    gc::{empty_trace, Finalize, Trace},
+    syntax::ast::node::{Node, RcStatementList},
+    Context, Result, Value,
+};
+use std::mem;
+
+/// How a suspended coroutine should continue its execution.
+#[derive(Debug, Clone)]
+pub(crate) enum ResumeAction {
+    /// Continue normally, the suspension point evaluates to the value.
+    Next(Value),
+
+    /// Throw the value at the suspension point.
+    Throw(Value),
+
+    /// Return the value from the suspended function, as if a `return` statement was evaluated
+    /// at the suspension point.
+    Return(Value),
+}
+
+/// The reason why the execution of a coroutine came back to the caller of `resume`.
+#[derive(Debug)]
+pub(crate) enum CoroutineState {
+    /// The coroutine suspended itself with the given value.
+    Suspended(Value),
+
+    /// The body of the coroutine finished.
+    Complete(Result<Value>),
+}
+
+/// Something a node did before its coroutine was suspended.
+#[derive(Debug, Clone, Trace, Finalize)]
+enum Record {
+    /// A value the node evaluated.
+    Value(Value),
+
+    /// An environment the node created.
+    Environment(Environment),
+
+    /// A state of the interpreter the node saved.
+    State(InterpreterState),
+
+    /// The suspension point of the coroutine.
+    Suspension,
+}
+
+/// The work a node did before the coroutine running it was suspended.
+///
+/// Nodes record every value they need to continue in their frame while they run inside of a
+/// coroutine. When the coroutine is resumed, the recorded values are handed back in the same
+/// order instead of being evaluated again, up to the point where the node was interrupted.
+#[derive(Debug, Default, Trace, Finalize)]
+pub(crate) struct ResumeFrame {
+    records: Vec<Record>,
+
+    /// The number of records handed back or recorded so far by the current run of the node.
+    #[unsafe_ignore_trace]
+    position: usize,
+
+    /// Whether the node runs inside of a coroutine, nothing has to be recorded otherwise.
+    #[unsafe_ignore_trace]
+    recording: bool,
+}
+
+impl ResumeFrame {
+    /// Returns `true` if the frame still has records to hand back.
+    pub(crate) fn is_replaying(&self) -> bool {
+        self.position < self.records.len()
+    }
+
+    /// Hands back the next record, or records the one created by `f`.
+    fn record<F>(&mut self, context: &mut Context, f: F) -> Result<Record>
+    where
+        F: FnOnce(&mut Context) -> Result<Record>,
+    {
+        if let Some(record) = self.records.get(self.position) {
+            self.position += 1;
+            return Ok(record.clone());
+        }
+        let record = f(context)?;
+        if self.recording {
+            self.records.push(record.clone());
+            self.position += 1;
+        }
+        Ok(record)
+    }
+
+    /// Hands back the next recorded value, if the frame has records left.
+    pub(crate) fn replay(&mut self) -> Option<Value> {
+        let record = self.records.get(self.position)?;
+        self.position += 1;
+        match record {
+            Record::Value(value) => Some(value.clone()),
+            record => panic!("expected a value to be recorded, found {:?}", record),
+        }
+    }
+
+    /// Records a value evaluated by the node.
+    pub(crate) fn save(&mut self, value: Value) {
+        debug_assert!(!self.is_replaying(), "values are recorded after the replay");
+        if self.recording {
+            self.records.push(Record::Value(value));
+            self.position += 1;
+        }
+    }
+
+    /// Evaluates `f`, or hands back the value it evaluated to before the coroutine was
+    /// suspended.
+    pub(crate) fn eval<F>(&mut self, context: &mut Context, f: F) -> Result<Value>
+    where
+        F: FnOnce(&mut Context) -> Result<Value>,
+    {
+        if let Some(value) = self.replay() {
+            return Ok(value);
+        }
+        let value = f(context)?;
+        self.save(value.clone());
+        Ok(value)
+    }
+
+    /// Gets an iterator with `f`, or hands back the one it got before the coroutine was
+    /// suspended.
+    pub(crate) fn iterator<F>(&mut self, context: &mut Context, f: F) -> Result<IteratorRecord>
+    where
+        F: FnOnce(&mut Context) -> Result<IteratorRecord>,
+    {
+        if let Some(iterator) = self.replay() {
+            let next_function = self.replay().expect("the next method must be recorded");
+            return Ok(IteratorRecord::new(iterator, next_function));
+        }
+        let iterator_record = f(context)?;
+        self.save(iterator_record.iterator_object().clone());
+        self.save(iterator_record.next_function().clone());
+        Ok(iterator_record)
+    }
+
+    /// Runs `node`, or hands back the value it evaluated to before the coroutine was suspended.
+    pub(crate) fn run(&mut self, node: &Node, context: &mut Context) -> Result<Value> {
+        self.eval(context, |context| node.run(context))
+    }
+
+    /// Creates an environment with `f`, or hands back the one created before the coroutine was
+    /// suspended.
+    pub(crate) fn environment<F>(&mut self, context: &mut Context, f: F) -> Environment
+    where
+        F: FnOnce(&mut Context) -> Environment,
+    {
+        match self.record(context, |context| Ok(Record::Environment(f(context)))) {
+            Ok(Record::Environment(ref environment)) => environment.clone(),
+            record => panic!("expected an environment to be recorded, found {:?}", record),
+        }
+    }
+
+    /// Gets the current state of the interpreter, or hands back the one that was current before
+    /// the coroutine was suspended.
+    pub(crate) fn state(&mut self, context: &mut Context) -> InterpreterState {
+        let record = self.record(context, |context| {
+            Ok(Record::State(
+                context.executor().get_current_state().clone(),
+            ))
+        });
+        match record {
+            Ok(Record::State(ref state)) => state.clone(),
+            record => panic!("expected a state to be recorded, found {:?}", record),
+        }
+    }
+
+    /// Returns the position of the next record, to be passed to `restart` by loops.
+    pub(crate) fn checkpoint(&self) -> usize {
+        self.position
+    }
+
+    /// Forgets the records made after the given checkpoint, so they can be made again by the
+    /// next iteration of a loop.
+    ///
+    /// While replaying, the records after the checkpoint belong to the iteration that was
+    /// interrupted, so they are kept.
+    pub(crate) fn restart(&mut self, checkpoint: usize) {
+        if !self.is_replaying() {
+            self.records.truncate(checkpoint);
+            self.position = checkpoint;
+        }
+    }
+}
+
+/// A coroutine that is currently running.
+#[derive(Debug)]
+pub(crate) struct ActiveCoroutine {
+    /// The depth of the environment stack when the coroutine was entered.
+    environment_base: usize,
+
+    /// The frames of the suspended nodes, innermost first.
+    ///
+    /// While the coroutine is resumed, the frames are taken back from the outermost, until the
+    /// suspension point takes the resume action.
+    frames: Vec<ResumeFrame>,
+
+    /// The action the coroutine was resumed with, `None` once the suspension point took it.
+    action: Option<ResumeAction>,
+
+    /// The value the coroutine suspended itself with, `Some` while it unwinds.
+    suspended: Option<Value>,
+}
+
+/// A function body that can be suspended and resumed.
+#[derive(Debug, Trace, Finalize)]
+pub(crate) struct Coroutine {
+    /// The name of the frame of the function on the call stack.
+    function_name: Box<str>,
+
+    /// The function environment the body runs in.
+    environment: Environment,
+
+    /// The body of the function.
+    body: RcStatementList,
+
+    /// The frames of the nodes that were interrupted by the last suspension.
+    frames: Vec<ResumeFrame>,
+
+    /// Whether the body started running.
+    started: bool,
+
+    /// Whether the body finished running.
+    done: bool,
+}
+
+impl Coroutine {
+    /// Creates a new coroutine that runs `body` with the given environment as its current
+    /// environment, in a frame of the call stack for the function with the given name.
+    ///
+    /// The body only starts running on the first call to `resume`, the action passed on that
+    /// first call is ignored.
+    pub(crate) fn new(
+        function_name: Box<str>,
+        environment: Environment,
+        body: RcStatementList,
+    ) -> Self {
+        Self {
+            function_name,
+            environment,
+            body,
+            frames: Vec::new(),
+            started: false,
+            done: false,
+        }
+    }
+
+    /// Returns `true` if the body of the coroutine has finished.
+    pub(crate) fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /// Runs the coroutine until it suspends itself or completes.
+    pub(crate) fn resume(
+        &mut self,
+        action: ResumeAction,
+        context: &mut Context,
+    ) -> Result<CoroutineState> {
+        if self.done {
+            return Err(context.construct_type_error("cannot resume a function that has finished"));
+        }
+
+        let environment_base = context.realm.environment.environment_stack.len();
+        let action = if mem::replace(&mut self.started, true) {
+            Some(action)
+        } else {
+            None
+        };
+        context.executor().coroutines.push(ActiveCoroutine {
+            environment_base,
+            frames: mem::take(&mut self.frames),
+            action,
+            suspended: None,
+        });
+        context.push_environment(self.environment.clone());
+        context
+            .executor()
+            .call_stack
+            .push(self.function_name.clone());
+
+        let body = self.body.clone();
+        let result = context.with_strict(body.strict(), |context| body.run(context));
+        let returned = *context.executor().get_current_state() == InterpreterState::Return;
+
+        context.executor().call_stack.pop();
+        let active = context
+            .executor()
+            .coroutines
+            .pop()
+            .expect("the running coroutine must be active");
+        // The body leaves its environments behind when it is suspended.
+        context
+            .realm
+            .environment
+            .environment_stack
+            .truncate(active.environment_base);
+
+        // Resumptions only happen while evaluating an expression.
+        context
+            .executor()
+            .set_current_state(InterpreterState::Executing);
+
+        if let Some(value) = active.suspended {
+            self.frames = active.frames;
+            return Ok(CoroutineState::Suspended(value));
+        }
+
+        self.done = true;
+        Ok(CoroutineState::Complete(result.map(|value| {
+            if returned {
+                value
+            } else {
+                Value::undefined()
+            }
+        })))
+    }
+}
+
+impl Context {
+    /// Runs the evaluation of a node that can be interrupted by the suspension of the coroutine
+    /// running it.
+    ///
+    /// `f` gets the frame of the node, which holds the work saved before the suspension when the
+    /// coroutine is resumed. The frame is saved if the coroutine is suspended while `f` runs.
+    pub(crate) fn resumable<T, F>(&mut self, f: F) -> Result<T>
+    where
+        F: FnOnce(&mut ResumeFrame, &mut Self) -> Result<T>,
+    {
+        let mut frame = match self.executor().coroutines.last_mut() {
+            Some(active) if active.action.is_some() => active
+                .frames
+                .pop()
+                .expect("a resumed node must have saved a frame"),
+            Some(_) => {
+                let mut frame = ResumeFrame::default();
+                frame.recording = true;
+                frame
+            }
+            None => ResumeFrame::default(),
+        };
+        frame.position = 0;
+
+        let result = f(&mut frame, self);
+        if result.is_err() {
+            if let Some(active) = self.executor().coroutines.last_mut() {
+                if active.suspended.is_some() {
+                    active.frames.push(frame);
+                }
+            }
+        }
+        result
+    }
+
+    /// Returns `true` if the coroutine that is currently running is being suspended.
+    ///
+    /// The error that unwinds the suspended coroutine must not be handled as an exception.
+    pub(crate) fn is_suspending(&mut self) -> bool {
+        matches!(
+            self.executor().coroutines.last(),
+            Some(active) if active.suspended.is_some()
+        )
+    }
+
+    /// Suspends the coroutine that is currently running, handing `value` to its resumer.
+    ///
+    /// This returns the error that unwinds the coroutine, and once it is resumed, how the
+    /// execution should continue.
+    pub(crate) fn suspend(
+        &mut self,
+        frame: &mut ResumeFrame,
+        value: Value,
+    ) -> Result<ResumeAction> {
+        if self.executor().coroutines.is_empty() {
+            return Err(self.construct_syntax_error("cannot suspend outside of a function body"));
+        }
+
+        if frame.is_replaying() {
+            frame.position += 1;
+            let active = self
+                .executor()
+                .coroutines
+                .last_mut()
+                .expect("the resumed coroutine must be active");
+            debug_assert!(active.frames.is_empty(), "all frames must be resumed");
+            return Ok(active
+                .action
+                .take()
+                .expect("the resumed coroutine must have an action"));
+        }
+
+        frame.records.push(Record::Suspension);
+        frame.position += 1;
+        self.executor()
+            .coroutines
+            .last_mut()
+            .expect("the running coroutine must be active")
+            .suspended = Some(value);
+        Err(Value::undefined())
+    }
+}
+
+unsafe impl Trace for InterpreterState {
+    empty_trace!();
+}
+
+impl Finalize for InterpreterState {}
@@ -1,8 +1,11 @@
 //! Execution of the AST, this is where the interpreter actually runs
 
+pub mod call_stack;
+pub(crate) mod coroutine;
 #[cfg(test)]
 mod tests;
 
+use self::{call_stack::CallStack, coroutine::ActiveCoroutine};
 use crate::{Context, Result, Value};
 
 pub trait Executable {
@@ -10,7 +13,7 @@ pub trait Executable {
     fn run(&self, context: &mut Context) -> Result<Value>;
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub(crate) enum InterpreterState {
     Executing,
     Return,
@@ -23,6 +26,12 @@ pub(crate) enum InterpreterState {
 pub struct Interpreter {
     /// the current state of the interpreter.
     state: InterpreterState,
+
+    /// The coroutines that are currently running, innermost last.
+    pub(crate) coroutines: Vec<ActiveCoroutine>,
+
+    /// The frames of the functions that are currently running.
+    pub(crate) call_stack: CallStack,
 }
 
 impl Default for Interpreter {
@@ -36,6 +45,8 @@ impl Interpreter {
     pub fn new() -> Self {
         Self {
             state: InterpreterState::Executing,
+            coroutines: Vec::new(),
+            call_stack: CallStack::default(),
         }
     }
 
@@ -103,7 +103,7 @@ fn identifier_on_global_object_undefined() {
         }
         "#;
 
-    assert_eq!(&exec(scenario), "\"bar is not defined\"");
+    assert_eq!(&exec(scenario), "\"bar is not defined at line 3, col 13\"");
 }
 
 #[test]
@@ -416,7 +416,7 @@ fn for_loop_iteration_variable_does_not_leak() {
         }
         "#;
 
-    assert_eq!(&exec(inner_scope), "\"i is not defined\"");
+    assert_eq!(&exec(inner_scope), "\"i is not defined at line 5, col 13\"");
 }
 
 #[test]
@@ -471,6 +471,14 @@ fn typeof_string() {
     assert_eq!(&exec(typeof_string), "\"string\"");
 }
 
+#[test]
+fn typeof_unresolvable_reference() {
+    let typeof_undeclared = r#"
+        typeof notDeclared;
+    "#;
+    assert_eq!(&exec(typeof_undeclared), "\"undefined\"");
+}
+
 #[test]
 fn typeof_int() {
     let typeof_int = r#"
@@ -764,7 +772,10 @@ mod in_operator {
         "#;
 
         let result = forward(&mut context, scenario);
-        assert_eq!(result, "Uncaught \"TypeError\": \"a is not a constructor\"");
+        assert_eq!(
+            result,
+            "Uncaught \"TypeError\": \"a is not a constructor at line 3, col 13\""
+        );
     }
 
     #[test]
@@ -1240,7 +1251,7 @@ fn not_a_function() {
     "#;
     assert_eq!(
         forward(&mut context, scenario),
-        "\"TypeError: not a function\""
+        "\"TypeError: not a function at line 3, col 13\""
     );
     let scenario = r#"
         try {
@@ -1251,7 +1262,7 @@ fn not_a_function() {
     "#;
     assert_eq!(
         forward(&mut context, scenario),
-        "\"TypeError: not a function\""
+        "\"TypeError: not a function at line 3, col 13\""
     );
     let scenario = r#"
         try {
@@ -1262,7 +1273,7 @@ fn not_a_function() {
     "#;
     assert_eq!(
         forward(&mut context, scenario),
-        "\"TypeError: not a function\""
+        "\"TypeError: not a function at line 3, col 13\""
     );
 }
 
@@ -1529,3 +1540,74 @@ fn test_empty_statement() {
     "#;
     assert_eq!(&exec(src), "10");
 }
+
+#[test]
+fn var_decl_shadows_outer_binding() {
+    let scenario = r#"
+        var a = "outer";
+        function f() {
+            var a = "inner";
+            function g() {}
+            return a;
+        }
+        function g() { return "outer"; }
+        f() + " " + a + " " + g();
+    "#;
+
+    assert_eq!(&exec(scenario), "\"inner outer outer\"");
+}
+
+#[test]
+fn strict_mode_this_is_not_coerced() {
+    let scenario = r#"
+        function sloppy() { return this; }
+        function strict() { "use strict"; return this; }
+        [
+            sloppy() === globalThis,
+            typeof sloppy.call(1),
+            strict() === undefined,
+            strict.call(1) === 1,
+            (() => { "use strict"; return typeof this; })(),
+        ].join();
+    "#;
+
+    assert_eq!(&exec(scenario), "\"true,object,true,true,object\"");
+}
+
+#[test]
+fn strict_mode_implicit_globals() {
+    let scenario = r#"
+        function sloppy() { created = 1; }
+        function strict() { "use strict"; notCreated = 1; }
+        sloppy();
+        var error;
+        try { strict(); } catch (e) { error = e; }
+        [created, error instanceof ReferenceError, "notCreated" in globalThis].join();
+    "#;
+
+    assert_eq!(&exec(scenario), "\"1,true,false\"");
+}
+
+#[test]
+fn strict_mode_failed_assignments_throw() {
+    let scenario = r#"
+        var frozen = Object.freeze({ a: 1 });
+        var errors = [];
+        function check(f) {
+            try { f(); errors.push("none"); } catch (e) { errors.push(e.constructor.name); }
+        }
+        check(function () { frozen.a = 2; });
+        check(function () { "use strict"; frozen.a = 2; });
+        check(function () { "use strict"; frozen["a"] += 1; });
+        check(function () { "use strict"; "abc".length = 1; });
+        check(function () { "use strict"; undefined = 1; });
+        check(function () { "use strict"; delete Object.prototype; });
+        check(function () { const c = 1; c = 2; });
+        errors.join() + " " + frozen.a;
+    "#;
+
+    assert_eq!(
+        &exec(scenario),
+        "\"none,TypeError,TypeError,TypeError,TypeError,TypeError,TypeError 1\""
+    );
+}
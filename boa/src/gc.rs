@@ -1,4 +1,8 @@
 //! This module represents the main way to interact with the garbage collector.
+//!
+//! Besides re-exporting the collector, it implements the weak references that the collector
+//! lacks: [`WeakGc`] pointers, which do not keep their target alive, and [`Ephemerons`], whose
+//! values are only kept alive by their key.
 
 // This is because `rust-gc` unsafe_empty_trace has a `unsafe_`
 // when it should be `empty_trace`.
@@ -8,3 +12,230 @@ pub use gc::{
     custom_trace, force_collect, unsafe_empty_trace as empty_trace, Finalize, GcCellRef as Ref,
     GcCellRefMut as RefMut, Trace,
 };
+
+use gc::Gc;
+use std::{
+    cell::{Cell, RefCell},
+    fmt,
+    mem::ManuallyDrop,
+    ptr::{self, NonNull},
+    rc::{Rc, Weak},
+};
+
+/// Tracks whether a garbage collected value is still alive, for the weak references to it.
+///
+/// A `WeakTarget` must be embedded in the value, and traced with it: the collector finalizes every
+/// value it is about to free, which marks the value as dead before it is freed.
+#[derive(Debug, Default)]
+pub struct WeakTarget {
+    liveness: RefCell<Option<Liveness>>,
+}
+
+impl WeakTarget {
+    /// Returns the liveness shared by the weak references to the value.
+    pub fn liveness(&self) -> Liveness {
+        self.liveness
+            .borrow_mut()
+            .get_or_insert_with(|| Liveness(Rc::new(Cell::new(true))))
+            .clone()
+    }
+}
+
+impl Finalize for WeakTarget {
+    fn finalize(&self) {
+        if let Some(liveness) = self.liveness.borrow_mut().take() {
+            liveness.0.set(false);
+        }
+    }
+}
+
+unsafe impl Trace for WeakTarget {
+    empty_trace!();
+}
+
+/// Whether a value with a [`WeakTarget`] is still alive.
+#[derive(Debug, Clone)]
+pub struct Liveness(Rc<Cell<bool>>);
+
+impl Liveness {
+    /// Returns `true` if the value has not been collected.
+    #[inline]
+    pub fn is_alive(&self) -> bool {
+        self.0.get()
+    }
+}
+
+/// A garbage collected type which can be the target of [`WeakGc`] pointers.
+pub trait WeakReferent: Trace {
+    /// Returns the liveness of the value, from the [`WeakTarget`] it embeds.
+    fn liveness(&self) -> Liveness;
+}
+
+/// A weak pointer to a garbage collected value.
+///
+/// A `WeakGc` is not traced, so it does not keep its target alive. Once the target is collected,
+/// [`WeakGc::upgrade`] returns `None`.
+pub struct WeakGc<T: WeakReferent + ?Sized + 'static> {
+    ptr: NonNull<T>,
+    liveness: Liveness,
+}
+
+impl<T: WeakReferent + ?Sized> WeakGc<T> {
+    /// Creates a weak pointer to the value of a `Gc`.
+    #[inline]
+    pub fn new(value: &Gc<T>) -> Self {
+        Self {
+            ptr: NonNull::from(&**value),
+            liveness: value.liveness(),
+        }
+    }
+
+    /// Returns `true` if the target has not been collected.
+    #[inline]
+    pub fn is_alive(&self) -> bool {
+        self.liveness.is_alive()
+    }
+
+    /// Returns a strong pointer to the target, or `None` if it has been collected.
+    #[inline]
+    pub fn upgrade(&self) -> Option<Gc<T>> {
+        if !self.is_alive() {
+            return None;
+        }
+
+        // SAFETY: The target is alive, so the pointer is valid. The `Gc` rebuilt from the pointer
+        // does not own a root, so it must not be dropped: cloning it roots the returned `Gc`.
+        unsafe {
+            let gc = ManuallyDrop::new(Gc::from_raw(self.ptr.as_ptr()));
+            Some(Gc::clone(&gc))
+        }
+    }
+
+    /// Returns `true` if the target is the value of the given `Gc`.
+    #[inline]
+    pub fn ptr_eq(&self, other: &Gc<T>) -> bool {
+        self.is_alive()
+            && ptr::eq(
+                self.ptr.as_ptr() as *const u8,
+                &**other as *const T as *const u8,
+            )
+    }
+}
+
+impl<T: WeakReferent + ?Sized> Clone for WeakGc<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            ptr: self.ptr,
+            liveness: self.liveness.clone(),
+        }
+    }
+}
+
+impl<T: WeakReferent + ?Sized> fmt::Debug for WeakGc<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WeakGc")
+            .field("ptr", &self.ptr)
+            .field("alive", &self.is_alive())
+            .finish()
+    }
+}
+
+impl<T: WeakReferent + ?Sized> Finalize for WeakGc<T> {}
+
+unsafe impl<T: WeakReferent + ?Sized> Trace for WeakGc<T> {
+    empty_trace!();
+}
+
+/// The owner of ephemeron entries, such as a `WeakMap`.
+///
+/// The entries of an owner are dropped when it, and all its clones, are dropped.
+#[derive(Debug, Clone, Default)]
+pub struct EphemeronOwner(Rc<()>);
+
+impl Finalize for EphemeronOwner {}
+
+unsafe impl Trace for EphemeronOwner {
+    empty_trace!();
+}
+
+/// The values attached to a key by the owners of ephemeron entries.
+///
+/// Ephemerons are stored in the key, so their values are only traced while the key is reachable,
+/// and while their owner is alive. A value referencing its own key does not keep the key alive.
+pub struct Ephemerons<V: Trace> {
+    entries: Vec<(Weak<()>, V)>,
+}
+
+impl<V: Trace> Ephemerons<V> {
+    /// Returns the value the owner attached to the key.
+    #[inline]
+    pub fn get(&self, owner: &EphemeronOwner) -> Option<&V> {
+        self.entries
+            .iter()
+            .find(|(entry_owner, _)| ptr::eq(entry_owner.as_ptr(), Rc::as_ptr(&owner.0)))
+            .map(|(_, value)| value)
+    }
+
+    /// Attaches a value to the key, replacing the previous value of the owner.
+    pub fn insert(&mut self, owner: &EphemeronOwner, value: V) {
+        self.remove_dead();
+        let existing = self
+            .entries
+            .iter_mut()
+            .find(|(entry_owner, _)| ptr::eq(entry_owner.as_ptr(), Rc::as_ptr(&owner.0)));
+        match existing {
+            Some((_, existing)) => *existing = value,
+            None => self.entries.push((Rc::downgrade(&owner.0), value)),
+        }
+    }
+
+    /// Removes the value the owner attached to the key, returning `true` if there was one.
+    pub fn remove(&mut self, owner: &EphemeronOwner) -> bool {
+        self.remove_dead();
+        let len = self.entries.len();
+        self.entries
+            .retain(|(entry_owner, _)| !ptr::eq(entry_owner.as_ptr(), Rc::as_ptr(&owner.0)));
+        self.entries.len() != len
+    }
+
+    /// Removes the entries whose owner was dropped.
+    ///
+    /// The values of these entries are not traced anymore, so they may point to freed values. They
+    /// are never accessed, only dropped, which does not dereference an unrooted `Gc`.
+    fn remove_dead(&mut self) {
+        self.entries
+            .retain(|(entry_owner, _)| entry_owner.strong_count() > 0);
+    }
+}
+
+impl<V: Trace> Default for Ephemerons<V> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl<V: Trace> fmt::Debug for Ephemerons<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Ephemerons")
+            .field("len", &self.entries.len())
+            .finish()
+    }
+}
+
+impl<V: Trace> Finalize for Ephemerons<V> {}
+
+unsafe impl<V: Trace> Trace for Ephemerons<V> {
+    custom_trace!(this, {
+        for (owner, value) in &this.entries {
+            // The entries of a dropped owner are skipped consistently, so they are never rooted
+            // again once their value may have been freed.
+            if owner.strong_count() > 0 {
+                mark(value);
+            }
+        }
+    });
+}
@@ -0,0 +1,39 @@
+//! Jobs and the job queue.
+//!
+//! A job is an abstract closure that runs when no other ECMAScript code is running, like the
+//! reactions of a promise. Jobs are queued in the [`Context`] and only run when the host drains
+//! the queue with [`Context::run_jobs`].
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-jobs
+
+use crate::{Context, Result, Value};
+use std::fmt;
+
+/// A job that runs native code.
+///
+/// The values captured by the job are kept alive until the job is run.
+pub struct NativeJob(Box<dyn FnOnce(&mut Context) -> Result<Value>>);
+
+impl NativeJob {
+    /// Creates a new job from a closure.
+    pub fn new<F>(job: F) -> Self
+    where
+        F: FnOnce(&mut Context) -> Result<Value> + 'static,
+    {
+        Self(Box::new(job))
+    }
+
+    /// Runs the job.
+    pub fn call(self, context: &mut Context) -> Result<Value> {
+        (self.0)(context)
+    }
+}
+
+impl fmt::Debug for NativeJob {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("NativeJob")
+    }
+}
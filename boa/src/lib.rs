@@ -49,6 +49,8 @@ pub mod class;
 pub mod environment;
 pub mod exec;
 pub mod gc;
+pub mod job;
+pub mod module;
 pub mod object;
 pub mod profiler;
 pub mod property;
@@ -72,7 +74,7 @@ pub(crate) use crate::{exec::Executable, profiler::BoaProfiler};
 pub use crate::{context::Context, value::Value};
 
 use crate::syntax::{
-    ast::node::StatementList,
+    ast::node::{Module, StatementList},
     parser::{ParseError, Parser},
 };
 
@@ -90,6 +92,16 @@ pub fn parse<T: AsRef<[u8]>>(src: T, strict_mode: bool) -> StdResult<StatementLi
     Parser::new(src_bytes, strict_mode).parse_all()
 }
 
+/// Parses the given source code as a module.
+///
+/// It will return either the module AST node for the code, or a parsing error if something goes
+/// wrong.
+#[inline]
+pub fn parse_module<T: AsRef<[u8]>>(src: T) -> StdResult<Module, ParseError> {
+    let src_bytes: &[u8] = src.as_ref();
+    Parser::new(src_bytes, true).parse_module()
+}
+
 /// Execute the code using an existing Context
 /// The str is consumed and the state of the Context is changed
 #[cfg(test)]
@@ -109,7 +121,9 @@ pub(crate) fn forward<T: AsRef<[u8]>>(context: &mut Context, src: T) -> String {
             );
         }
     };
-    expr.run(context).map_or_else(
+    let result = expr.run(context);
+    let jobs = context.run_jobs();
+    result.and_then(|v| jobs.map(|_| v)).map_or_else(
         |e| format!("Uncaught {}", e.display()),
         |v| v.display().to_string(),
     )
@@ -133,6 +147,8 @@ pub(crate) fn forward_val<T: AsRef<[u8]>>(context: &mut Context, src: T) -> Resu
                 .expect_err("interpreter.throw_syntax_error() did not return an error")
         })
         .and_then(|expr| expr.run(context));
+    let jobs = context.run_jobs();
+    let result = result.and_then(|v| jobs.map(|_| v));
 
     // The main_timer needs to be dropped before the BoaProfiler is.
     drop(main_timer);
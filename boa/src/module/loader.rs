@@ -0,0 +1,186 @@
+//! Module loaders, which let the host resolve module specifiers and provide module sources.
+
+use crate::{Context, Result};
+use rustc_hash::FxHashMap;
+use std::{
+    fmt::Debug,
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+/// A host hook resolving module specifiers and loading the source of modules.
+///
+/// A module is identified by the key its specifier resolves to: modules whose specifiers resolve to
+/// the same key are only loaded and evaluated once.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-HostLoadImportedModule
+pub trait ModuleLoader: Debug {
+    /// Resolves the specifier of a module to its key.
+    ///
+    /// The referrer is the key of the importing module, or `None` for the module evaluated by the
+    /// host. By default, specifiers starting with `./` or `../` are resolved relatively to the
+    /// referrer, and other specifiers are used as keys.
+    fn resolve(
+        &self,
+        specifier: &str,
+        referrer: Option<&str>,
+        _context: &mut Context,
+    ) -> Result<String> {
+        Ok(resolve_relative(specifier, referrer))
+    }
+
+    /// Loads the source of the module with the given key.
+    fn load(&self, key: &str, context: &mut Context) -> Result<String>;
+}
+
+/// Returns `true` if the specifier is relative to the module importing it.
+fn is_relative(specifier: &str) -> bool {
+    specifier.starts_with("./") || specifier.starts_with("../")
+}
+
+/// Resolves a `/` separated specifier relatively to the directory of the referrer.
+fn resolve_relative(specifier: &str, referrer: Option<&str>) -> String {
+    let directory = match referrer {
+        Some(referrer) if is_relative(specifier) => {
+            referrer.rfind('/').map_or("", |index| &referrer[..index])
+        }
+        _ => "",
+    };
+
+    let mut segments: Vec<&str> = Vec::new();
+    for segment in directory.split('/').chain(specifier.split('/')) {
+        match segment {
+            "" | "." => {}
+            ".." if matches!(segments.last(), Some(last) if *last != "..") => {
+                segments.pop();
+            }
+            _ => segments.push(segment),
+        }
+    }
+    segments.join("/")
+}
+
+/// A module loader serving the sources of modules from memory.
+///
+/// # Examples
+/// ```
+///# use boa::{module::MapModuleLoader, Context};
+/// let mut loader = MapModuleLoader::new();
+/// loader.insert("lib.js", "export const answer = 42;");
+///
+/// let mut context = Context::new();
+/// context.set_module_loader(loader);
+///
+/// let namespace = context
+///     .eval_module_source("main.js", r#"export { answer } from "./lib.js";"#)
+///     .unwrap();
+/// let answer = namespace.get_field("answer", &mut context).unwrap();
+///
+/// assert_eq!(answer.as_number(), Some(42.0));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct MapModuleLoader {
+    modules: FxHashMap<String, String>,
+}
+
+impl MapModuleLoader {
+    /// Creates a new, empty, loader.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the source of the module with the given key.
+    pub fn insert<K, S>(&mut self, key: K, source: S)
+    where
+        K: Into<String>,
+        S: Into<String>,
+    {
+        self.modules.insert(key.into(), source.into());
+    }
+}
+
+impl ModuleLoader for MapModuleLoader {
+    fn load(&self, key: &str, context: &mut Context) -> Result<String> {
+        self.modules
+            .get(key)
+            .cloned()
+            .ok_or_else(|| context.construct_type_error(format!("Cannot find module {}", key)))
+    }
+}
+
+/// A module loader reading the sources of modules from the filesystem.
+///
+/// Relative specifiers are resolved relatively to the importing module, and other specifiers
+/// relatively to the root directory of the loader. Modules must be inside the root directory:
+/// specifiers are resolved to canonical paths, following `..` components and symbolic links,
+/// and the paths outside of the root directory are rejected with a `TypeError`.
+#[derive(Debug, Clone)]
+pub struct FsModuleLoader {
+    root: PathBuf,
+}
+
+impl FsModuleLoader {
+    /// Creates a new loader, resolving non-relative specifiers from the given directory.
+    pub fn new<P>(root: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self { root: root.into() }
+    }
+}
+
+impl ModuleLoader for FsModuleLoader {
+    fn resolve(
+        &self,
+        specifier: &str,
+        referrer: Option<&str>,
+        context: &mut Context,
+    ) -> Result<String> {
+        let directory = match referrer.and_then(|referrer| Path::new(referrer).parent()) {
+            Some(directory) if is_relative(specifier) => directory.to_path_buf(),
+            _ => self.root.clone(),
+        };
+
+        let mut path = PathBuf::new();
+        for component in directory
+            .join(specifier.trim_start_matches('/'))
+            .components()
+        {
+            match component {
+                Component::CurDir => {}
+                Component::ParentDir => {
+                    path.pop();
+                }
+                component => path.push(component),
+            }
+        }
+
+        let root = fs::canonicalize(&self.root).map_err(|error| {
+            context.construct_type_error(format!(
+                "Cannot read the module root {}: {}",
+                self.root.display(),
+                error
+            ))
+        })?;
+        let path = fs::canonicalize(&path).map_err(|error| {
+            context.construct_type_error(format!("Cannot find module {}: {}", specifier, error))
+        })?;
+        if !path.starts_with(&root) {
+            return Err(context.construct_type_error(format!(
+                "Cannot import module {} from outside of {}",
+                specifier,
+                root.display()
+            )));
+        }
+        Ok(path.to_string_lossy().into_owned())
+    }
+
+    fn load(&self, key: &str, context: &mut Context) -> Result<String> {
+        fs::read_to_string(key).map_err(|error| {
+            context.construct_type_error(format!("Cannot load module {}: {}", key, error))
+        })
+    }
+}
@@ -0,0 +1,937 @@
+//! This module implements ECMAScript modules.
+//!
+//! The modules imported by a module are resolved and loaded by the [`ModuleLoader`] of the
+//! [`Context`], which lets the host decide where modules come from, like a directory or an
+//! in-memory map. A module and the modules it imports form a graph, which may contain cycles. The
+//! graph is loaded, then linked, which creates the environments of the modules and binds their
+//! imports, then evaluated, dependencies first.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-cyclic-module-records
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Modules
+
+mod loader;
+mod namespace;
+#[cfg(test)]
+mod tests;
+
+pub use self::{
+    loader::{FsModuleLoader, MapModuleLoader, ModuleLoader},
+    namespace::ModuleNamespace,
+};
+
+use crate::{
+    environment::{
+        lexical_environment::Environment, module_environment_record::ModuleEnvironmentRecord,
+    },
+    gc::{empty_trace, Finalize, Trace},
+    object::GcObject,
+    property::{Attribute, DataDescriptor},
+    syntax::{
+        ast::node::{
+            self,
+            module::{declared_names, var_declared_names},
+            ExportDecl, ModuleItem, Node,
+        },
+        parser::Parser,
+    },
+    BoaProfiler, Context, Executable, Result, Value,
+};
+use gc::{Gc, GcCell};
+use rustc_hash::FxHashMap;
+use std::fmt;
+
+/// The name of the binding holding the value of `export default expression;`.
+const DEFAULT_BINDING: &str = "*default*";
+
+/// The status of a module in the linking and evaluation algorithms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Finalize)]
+enum ModuleStatus {
+    Unlinked,
+    Linking,
+    Linked,
+    Evaluating,
+    Evaluated,
+}
+
+unsafe impl Trace for ModuleStatus {
+    empty_trace!();
+}
+
+/// An export of a binding of the module, as in `export { local as exported };`.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct LocalExport {
+    export_name: Box<str>,
+    local_name: Box<str>,
+}
+
+/// An export of a binding of another module, as in `export { imported as exported } from "module";`.
+///
+/// The imported name is `None` when the namespace of the other module is exported.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct IndirectExport {
+    export_name: Box<str>,
+    request: Box<str>,
+    import_name: Option<Box<str>>,
+}
+
+/// An import of a binding of another module, as in `import { imported as local } from "module";`.
+///
+/// The imported name is `None` when the namespace of the other module is imported.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct ImportEntry {
+    request: Box<str>,
+    import_name: Option<Box<str>>,
+    local_name: Box<str>,
+}
+
+/// The binding an export resolves to.
+#[derive(Debug, Clone)]
+enum ResolvedBinding {
+    /// A binding of the environment of a module.
+    Binding { module: Module, name: Box<str> },
+    /// The namespace object of a module.
+    Namespace(Module),
+}
+
+impl ResolvedBinding {
+    /// Returns `true` if both resolutions are the same binding.
+    fn is_same(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Self::Binding { module, name },
+                Self::Binding {
+                    module: other_module,
+                    name: other_name,
+                },
+            ) => module.ptr_eq(other_module) && name == other_name,
+            (Self::Namespace(module), Self::Namespace(other_module)) => module.ptr_eq(other_module),
+            _ => false,
+        }
+    }
+}
+
+/// The result of resolving an export.
+#[derive(Debug, Clone)]
+enum ExportResolution {
+    Resolved(ResolvedBinding),
+    /// The export does not exist, or is part of a cycle of re-exports.
+    NotFound,
+    /// The export is provided by several `export *` declarations.
+    Ambiguous,
+}
+
+/// A source text module record.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-source-text-module-records
+#[derive(Debug, Trace, Finalize)]
+struct SourceTextModule {
+    /// The key the specifier of the module resolved to.
+    key: Box<str>,
+    /// The parsed source of the module.
+    body: Gc<node::Module>,
+    /// The specifiers of the modules imported by the module.
+    requests: Box<[Box<str>]>,
+    /// The modules imported by the module, by specifier.
+    loaded: FxHashMap<Box<str>, Module>,
+    status: ModuleStatus,
+    environment: Option<Environment>,
+    namespace: Option<GcObject>,
+    import_entries: Box<[ImportEntry]>,
+    local_exports: Box<[LocalExport]>,
+    indirect_exports: Box<[IndirectExport]>,
+    star_exports: Box<[Box<str>]>,
+    dfs_index: usize,
+    dfs_ancestor_index: usize,
+    evaluation_error: Option<Value>,
+}
+
+/// A module, shared by the modules importing it.
+#[derive(Clone, Trace, Finalize)]
+pub struct Module(Gc<GcCell<SourceTextModule>>);
+
+impl fmt::Debug for Module {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The modules imported by a module may import it back, so they are not printed.
+        f.debug_struct("Module")
+            .field("key", &self.0.borrow().key)
+            .finish()
+    }
+}
+
+impl Module {
+    /// Creates a new unlinked module from its parsed source.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-parsemodule
+    fn new(key: Box<str>, body: node::Module) -> Self {
+        let mut import_entries = Vec::new();
+        let mut exports = Vec::new();
+        let mut indirect_exports = Vec::new();
+        let mut star_exports = Vec::new();
+
+        for item in body.items() {
+            match item {
+                ModuleItem::ImportDecl(import) => {
+                    let request: Box<str> = import.from().into();
+                    let default = import.default().map(|local| (Some("default"), local));
+                    let namespace = import.namespace().map(|local| (None, local));
+                    let specifiers = import
+                        .specifiers()
+                        .iter()
+                        .map(|specifier| (Some(specifier.imported()), specifier.local()));
+                    for (import_name, local_name) in
+                        default.into_iter().chain(namespace).chain(specifiers)
+                    {
+                        import_entries.push(ImportEntry {
+                            request: request.clone(),
+                            import_name: import_name.map(Into::into),
+                            local_name: local_name.into(),
+                        });
+                    }
+                }
+                ModuleItem::ExportDecl(ExportDecl::Declaration(node)) => {
+                    for name in declared_names(node) {
+                        exports.push(LocalExport {
+                            export_name: name.into(),
+                            local_name: name.into(),
+                        });
+                    }
+                }
+                ModuleItem::ExportDecl(ExportDecl::Default(node)) => {
+                    exports.push(LocalExport {
+                        export_name: "default".into(),
+                        local_name: declared_names(node)
+                            .first()
+                            .copied()
+                            .unwrap_or(DEFAULT_BINDING)
+                            .into(),
+                    });
+                }
+                ModuleItem::ExportDecl(ExportDecl::Named { specifiers, from }) => {
+                    for specifier in specifiers.iter() {
+                        match from {
+                            Some(from) => indirect_exports.push(IndirectExport {
+                                export_name: specifier.exported().into(),
+                                request: from.clone(),
+                                import_name: Some(specifier.local().into()),
+                            }),
+                            None => exports.push(LocalExport {
+                                export_name: specifier.exported().into(),
+                                local_name: specifier.local().into(),
+                            }),
+                        }
+                    }
+                }
+                ModuleItem::ExportDecl(ExportDecl::All { alias, from }) => match alias {
+                    Some(alias) => indirect_exports.push(IndirectExport {
+                        export_name: alias.clone(),
+                        request: from.clone(),
+                        import_name: None,
+                    }),
+                    None => star_exports.push(from.clone()),
+                },
+                ModuleItem::Statement(_) => {}
+            }
+        }
+
+        // Exporting an imported binding re-exports the binding of the imported module, except for
+        // an imported namespace, which is a binding of the module.
+        let mut local_exports = Vec::new();
+        for export in exports {
+            let import = import_entries
+                .iter()
+                .find(|import: &&ImportEntry| import.local_name == export.local_name);
+            match import {
+                Some(ImportEntry {
+                    request,
+                    import_name: Some(import_name),
+                    ..
+                }) => indirect_exports.push(IndirectExport {
+                    export_name: export.export_name.clone(),
+                    request: request.clone(),
+                    import_name: Some(import_name.clone()),
+                }),
+                _ => local_exports.push(export),
+            }
+        }
+
+        let requests = body.requests().into_iter().map(Into::into).collect();
+
+        Self(Gc::new(GcCell::new(SourceTextModule {
+            key,
+            body: Gc::new(body),
+            requests,
+            loaded: FxHashMap::default(),
+            status: ModuleStatus::Unlinked,
+            environment: None,
+            namespace: None,
+            import_entries: import_entries.into(),
+            local_exports: local_exports.into(),
+            indirect_exports: indirect_exports.into(),
+            star_exports: star_exports.into(),
+            dfs_index: 0,
+            dfs_ancestor_index: 0,
+            evaluation_error: None,
+        })))
+    }
+
+    /// Parses the source of a module, throwing a `SyntaxError` if it is not valid.
+    pub(crate) fn parse(key: &str, src: &[u8], context: &mut Context) -> Result<Self> {
+        let _timer = BoaProfiler::global().start_event("Module::parse", "module");
+        let body = Parser::new(src, true)
+            .parse_module()
+            .map_err(|e| context.construct_syntax_error(e.to_string()))?;
+
+        Ok(Self::new(key.into(), body))
+    }
+
+    /// Resolves and loads a module with the module loader of the context.
+    ///
+    /// A module is only loaded once per key: the modules already loaded are taken from the module
+    /// map of the context.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-HostLoadImportedModule
+    pub(crate) fn load(
+        specifier: &str,
+        referrer: Option<&Module>,
+        context: &mut Context,
+    ) -> Result<Self> {
+        let loader = match context.module_loader() {
+            Some(loader) => loader,
+            None => {
+                return Err(context.construct_type_error(format!(
+                    "Cannot load module {}: no module loader is set",
+                    specifier
+                )))
+            }
+        };
+
+        let referrer = referrer.map(|referrer| referrer.0.borrow().key.clone());
+        let key = loader.resolve(specifier, referrer.as_deref(), context)?;
+        if let Some(module) = context.modules().get(key.as_str()) {
+            return Ok(module.clone());
+        }
+
+        let source = loader.load(&key, context)?;
+        let module = Self::parse(&key, source.as_bytes(), context)?;
+        context.modules().insert(key.into(), module.clone());
+
+        Ok(module)
+    }
+
+    /// Returns `true` if both modules are the same module.
+    #[inline]
+    fn ptr_eq(&self, other: &Self) -> bool {
+        Gc::ptr_eq(&self.0, &other.0)
+    }
+
+    /// Returns the module imported with the given specifier.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the requested modules have not been loaded.
+    fn requested_module(&self, request: &str) -> Self {
+        self.0
+            .borrow()
+            .loaded
+            .get(request)
+            .cloned()
+            .expect("the requested modules must be loaded before linking")
+    }
+
+    /// Loads the modules imported by the module, and the modules they import, recursively.
+    ///
+    /// The modules that were loaded before an error are kept, so loading the graph again only
+    /// loads the remaining modules.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-LoadRequestedModules
+    pub(crate) fn load_requested_modules(&self, context: &mut Context) -> Result<()> {
+        let _timer = BoaProfiler::global().start_event("Module::load_requested_modules", "module");
+        let mut visited = vec![self.clone()];
+        let mut pending = vec![self.clone()];
+
+        while let Some(module) = pending.pop() {
+            let requests = module.0.borrow().requests.clone();
+            for request in requests.iter() {
+                let loaded = module.0.borrow().loaded.get(request).cloned();
+                let required = if let Some(required) = loaded {
+                    required
+                } else {
+                    let required = Self::load(request, Some(&module), context)?;
+                    module
+                        .0
+                        .borrow_mut()
+                        .loaded
+                        .insert(request.clone(), required.clone());
+                    required
+                };
+                if !visited.iter().any(|visited| visited.ptr_eq(&required)) {
+                    visited.push(required.clone());
+                    pending.push(required);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Links the module and the modules it imports.
+    ///
+    /// If linking fails, the modules that were being linked are unlinked.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-moduledeclarationlinking
+    pub(crate) fn link(&self, context: &mut Context) -> Result<()> {
+        let _timer = BoaProfiler::global().start_event("Module::link", "module");
+        let mut stack = Vec::new();
+
+        if let Err(error) = self.inner_link(&mut stack, 0, context) {
+            for module in stack {
+                let mut module = module.0.borrow_mut();
+                module.status = ModuleStatus::Unlinked;
+                module.environment = None;
+            }
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
+    /// <https://tc39.es/ecma262/#sec-InnerModuleLinking>
+    fn inner_link(
+        &self,
+        stack: &mut Vec<Self>,
+        index: usize,
+        context: &mut Context,
+    ) -> Result<usize> {
+        {
+            let mut module = self.0.borrow_mut();
+            if module.status != ModuleStatus::Unlinked {
+                return Ok(index);
+            }
+            module.status = ModuleStatus::Linking;
+            module.dfs_index = index;
+            module.dfs_ancestor_index = index;
+        }
+        let mut index = index + 1;
+        stack.push(self.clone());
+
+        let requests = self.0.borrow().requests.clone();
+        for request in requests.iter() {
+            let required = self.requested_module(request);
+            index = required.inner_link(stack, index, context)?;
+
+            let (status, ancestor_index) = {
+                let required = required.0.borrow();
+                (required.status, required.dfs_ancestor_index)
+            };
+            if status == ModuleStatus::Linking {
+                let mut module = self.0.borrow_mut();
+                module.dfs_ancestor_index = module.dfs_ancestor_index.min(ancestor_index);
+            }
+        }
+
+        self.initialize_environment(context)?;
+
+        let is_cycle_root = {
+            let module = self.0.borrow();
+            module.dfs_index == module.dfs_ancestor_index
+        };
+        if is_cycle_root {
+            while let Some(module) = stack.pop() {
+                module.0.borrow_mut().status = ModuleStatus::Linked;
+                if module.ptr_eq(self) {
+                    break;
+                }
+            }
+        }
+
+        Ok(index)
+    }
+
+    /// Creates the environment of the module, binds its imports, and instantiates its function
+    /// declarations.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-source-text-module-record-initialize-environment
+    fn initialize_environment(&self, context: &mut Context) -> Result<()> {
+        let (key, body, import_entries, indirect_exports) = {
+            let module = self.0.borrow();
+            (
+                module.key.clone(),
+                module.body.clone(),
+                module.import_entries.clone(),
+                module.indirect_exports.clone(),
+            )
+        };
+
+        for export in indirect_exports.iter() {
+            if let ExportResolution::Resolved(_) =
+                self.resolve_export(&export.export_name, &mut Vec::new())
+            {
+                continue;
+            }
+            return Err(context.construct_syntax_error(format!(
+                "The export {} of module {} cannot be resolved",
+                export.export_name, key
+            )));
+        }
+
+        let global_env = context.realm.environment.environment_stack.front().cloned();
+        let env = ModuleEnvironmentRecord::new(global_env);
+
+        for import in import_entries.iter() {
+            let imported = self.requested_module(&import.request);
+            let resolution = match import.import_name {
+                Some(ref import_name) => imported.resolve_export(import_name, &mut Vec::new()),
+                None => ExportResolution::Resolved(ResolvedBinding::Namespace(imported)),
+            };
+
+            let local_name = import.local_name.to_string();
+            match resolution {
+                ExportResolution::Resolved(ResolvedBinding::Binding { module, name }) => env
+                    .borrow_mut()
+                    .as_module_environment_mut()
+                    .expect("the environment of a module must be a module environment")
+                    .create_import_binding(local_name, module, &name),
+                ExportResolution::Resolved(ResolvedBinding::Namespace(module)) => {
+                    let namespace = module.namespace();
+                    let mut env = env.borrow_mut();
+                    env.create_immutable_binding(local_name, true, context)?;
+                    env.initialize_binding(&import.local_name, namespace.into(), context)?;
+                }
+                ExportResolution::NotFound | ExportResolution::Ambiguous => {
+                    return Err(context.construct_syntax_error(format!(
+                        "The module {} does not provide a single export named {}",
+                        import.request,
+                        import.import_name.as_deref().unwrap_or_default()
+                    )))
+                }
+            }
+        }
+
+        self.0.borrow_mut().environment = Some(env.clone());
+
+        let mut var_names = Vec::new();
+        let mut functions = Vec::new();
+        let mut default_function = None;
+        let mut has_default_binding = false;
+        for item in body.items() {
+            match item {
+                ModuleItem::Statement(node)
+                | ModuleItem::ExportDecl(ExportDecl::Declaration(node)) => {
+                    if is_function_declaration(node) {
+                        functions.push(node);
+                    } else {
+                        var_names.extend(var_declared_names(node));
+                    }
+                }
+                ModuleItem::ExportDecl(ExportDecl::Default(node)) => {
+                    if is_function_declaration(node) {
+                        functions.push(node);
+                    } else if declared_names(node).is_empty() {
+                        has_default_binding = true;
+                        if is_anonymous_function(node) {
+                            default_function = Some(node);
+                        }
+                    }
+                }
+                ModuleItem::ImportDecl(_) | ModuleItem::ExportDecl(_) => {}
+            }
+        }
+
+        {
+            let mut env = env.borrow_mut();
+            let function_names = functions.iter().flat_map(|node| declared_names(node));
+            for name in var_names.into_iter().chain(function_names) {
+                if !env.has_binding(name) {
+                    env.create_mutable_binding(name.to_owned(), false, false, context)?;
+                    env.initialize_binding(name, Value::undefined(), context)?;
+                }
+            }
+            if has_default_binding {
+                env.create_mutable_binding(DEFAULT_BINDING.to_owned(), false, false, context)?;
+            }
+        }
+
+        run_in_environment(&env, context, |context| {
+            for function in functions {
+                function.run(context)?;
+            }
+            if let Some(function) = default_function {
+                let function = function.run(context)?;
+                set_default_name(&function);
+                env.borrow_mut()
+                    .initialize_binding(DEFAULT_BINDING, function, context)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Evaluates the module, after the modules it imports.
+    ///
+    /// A module is only evaluated once: evaluating it again returns the error thrown by its first
+    /// evaluation, if any.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-moduleevaluation
+    pub(crate) fn evaluate(&self, context: &mut Context) -> Result<()> {
+        let _timer = BoaProfiler::global().start_event("Module::evaluate", "module");
+        let mut stack = Vec::new();
+
+        if let Err(error) = self.inner_evaluate(&mut stack, 0, context) {
+            for module in stack {
+                let mut module = module.0.borrow_mut();
+                module.status = ModuleStatus::Evaluated;
+                module.evaluation_error = Some(error.clone());
+            }
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
+    /// <https://tc39.es/ecma262/#sec-innermoduleevaluation>
+    fn inner_evaluate(
+        &self,
+        stack: &mut Vec<Self>,
+        index: usize,
+        context: &mut Context,
+    ) -> Result<usize> {
+        {
+            let mut module = self.0.borrow_mut();
+            match module.status {
+                ModuleStatus::Evaluated => {
+                    return match module.evaluation_error {
+                        Some(ref error) => Err(error.clone()),
+                        None => Ok(index),
+                    }
+                }
+                ModuleStatus::Evaluating => return Ok(index),
+                _ => {}
+            }
+            debug_assert_eq!(module.status, ModuleStatus::Linked);
+            module.status = ModuleStatus::Evaluating;
+            module.dfs_index = index;
+            module.dfs_ancestor_index = index;
+        }
+        let mut index = index + 1;
+        stack.push(self.clone());
+
+        let requests = self.0.borrow().requests.clone();
+        for request in requests.iter() {
+            let required = self.requested_module(request);
+            index = required.inner_evaluate(stack, index, context)?;
+
+            let (status, ancestor_index) = {
+                let required = required.0.borrow();
+                (required.status, required.dfs_ancestor_index)
+            };
+            if status == ModuleStatus::Evaluating {
+                let mut module = self.0.borrow_mut();
+                module.dfs_ancestor_index = module.dfs_ancestor_index.min(ancestor_index);
+            }
+        }
+
+        self.execute(context)?;
+
+        let is_cycle_root = {
+            let module = self.0.borrow();
+            module.dfs_index == module.dfs_ancestor_index
+        };
+        if is_cycle_root {
+            while let Some(module) = stack.pop() {
+                module.0.borrow_mut().status = ModuleStatus::Evaluated;
+                if module.ptr_eq(self) {
+                    break;
+                }
+            }
+        }
+
+        Ok(index)
+    }
+
+    /// Runs the statements of the module in its environment.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-source-text-module-record-execute-module
+    fn execute(&self, context: &mut Context) -> Result<()> {
+        let (body, env) = {
+            let module = self.0.borrow();
+            (
+                module.body.clone(),
+                module
+                    .environment
+                    .clone()
+                    .expect("a linked module must have an environment"),
+            )
+        };
+
+        run_in_environment(&env, context, |context| {
+            for item in body.items() {
+                match item {
+                    ModuleItem::Statement(node)
+                    | ModuleItem::ExportDecl(ExportDecl::Declaration(node)) => {
+                        if !is_function_declaration(node) {
+                            node.run(context)?;
+                        }
+                    }
+                    ModuleItem::ExportDecl(ExportDecl::Default(node)) => {
+                        if is_function_declaration(node) || is_anonymous_function(node) {
+                            continue;
+                        }
+                        if declared_names(node).is_empty() {
+                            let value = node.run(context)?;
+                            if is_anonymous_function_definition(node) {
+                                set_default_name(&value);
+                            }
+                            env.borrow_mut()
+                                .initialize_binding(DEFAULT_BINDING, value, context)?;
+                        } else {
+                            node.run(context)?;
+                        }
+                    }
+                    ModuleItem::ImportDecl(_) | ModuleItem::ExportDecl(_) => {}
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Loads, links and evaluates the module, and returns its namespace object.
+    pub(crate) fn load_link_and_evaluate(&self, context: &mut Context) -> Result<Value> {
+        self.load_requested_modules(context)?;
+        self.link(context)?;
+        self.evaluate(context)?;
+        Ok(self.namespace().into())
+    }
+
+    /// Resolves an exported name to the binding it exports.
+    ///
+    /// The resolve set holds the exports being resolved, to detect cycles of re-exports.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-resolveexport
+    fn resolve_export(
+        &self,
+        export_name: &str,
+        resolve_set: &mut Vec<(Self, Box<str>)>,
+    ) -> ExportResolution {
+        let is_circular = resolve_set
+            .iter()
+            .any(|(module, name)| module.ptr_eq(self) && name.as_ref() == export_name);
+        if is_circular {
+            return ExportResolution::NotFound;
+        }
+        resolve_set.push((self.clone(), export_name.into()));
+
+        let module = self.0.borrow();
+        if let Some(export) = module
+            .local_exports
+            .iter()
+            .find(|export| export.export_name.as_ref() == export_name)
+        {
+            return ExportResolution::Resolved(ResolvedBinding::Binding {
+                module: self.clone(),
+                name: export.local_name.clone(),
+            });
+        }
+
+        if let Some(export) = module
+            .indirect_exports
+            .iter()
+            .find(|export| export.export_name.as_ref() == export_name)
+        {
+            let imported = self.requested_module(&export.request);
+            return match export.import_name {
+                Some(ref import_name) => imported.resolve_export(import_name, resolve_set),
+                None => ExportResolution::Resolved(ResolvedBinding::Namespace(imported)),
+            };
+        }
+
+        // A default export cannot be provided by `export * from "module";`.
+        if export_name == "default" {
+            return ExportResolution::NotFound;
+        }
+
+        let mut star_resolution: Option<ResolvedBinding> = None;
+        for request in module.star_exports.iter() {
+            let imported = self.requested_module(request);
+            match imported.resolve_export(export_name, resolve_set) {
+                ExportResolution::Ambiguous => return ExportResolution::Ambiguous,
+                ExportResolution::NotFound => {}
+                ExportResolution::Resolved(resolution) => match star_resolution {
+                    None => star_resolution = Some(resolution),
+                    Some(ref existing) if existing.is_same(&resolution) => {}
+                    Some(_) => return ExportResolution::Ambiguous,
+                },
+            }
+        }
+
+        star_resolution.map_or(ExportResolution::NotFound, ExportResolution::Resolved)
+    }
+
+    /// Returns the names exported by the module, including the ones exported with
+    /// `export * from "module";`.
+    ///
+    /// The export star set holds the modules already visited, to handle cycles.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getexportednames
+    fn get_exported_names(&self, export_star_set: &mut Vec<Self>) -> Vec<Box<str>> {
+        if export_star_set.iter().any(|module| module.ptr_eq(self)) {
+            return Vec::new();
+        }
+        export_star_set.push(self.clone());
+
+        let module = self.0.borrow();
+        let mut names: Vec<Box<str>> = module
+            .local_exports
+            .iter()
+            .map(|export| export.export_name.clone())
+            .chain(
+                module
+                    .indirect_exports
+                    .iter()
+                    .map(|export| export.export_name.clone()),
+            )
+            .collect();
+
+        for request in module.star_exports.iter() {
+            let requested = self.requested_module(request);
+            for name in requested.get_exported_names(export_star_set) {
+                if name.as_ref() != "default" && !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+
+        names
+    }
+
+    /// Returns the namespace object of the module, creating it on first use.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getmodulenamespace
+    pub(crate) fn namespace(&self) -> GcObject {
+        if let Some(ref namespace) = self.0.borrow().namespace {
+            return namespace.clone();
+        }
+
+        let exports = self
+            .get_exported_names(&mut Vec::new())
+            .into_iter()
+            .filter(|name| {
+                matches!(
+                    self.resolve_export(name, &mut Vec::new()),
+                    ExportResolution::Resolved(_)
+                )
+            })
+            .collect();
+        let namespace = ModuleNamespace::create(self.clone(), exports);
+        self.0.borrow_mut().namespace = Some(namespace.clone());
+
+        namespace
+    }
+
+    /// Gets the value of a binding of the environment of the module.
+    ///
+    /// Throws a `ReferenceError` if the binding is not initialized yet.
+    pub(crate) fn get_binding_value(&self, name: &str, context: &mut Context) -> Result<Value> {
+        let env = self.0.borrow().environment.clone();
+        match env {
+            Some(env) if env.borrow().has_binding(name) => {
+                env.borrow().get_binding_value(name, true, context)
+            }
+            _ => context.throw_reference_error(format!("{} is an uninitialized binding", name)),
+        }
+    }
+}
+
+/// Runs a closure with the given environment as the current environment.
+///
+/// The code of modules is always strict mode code.
+fn run_in_environment<F>(env: &Environment, context: &mut Context, f: F) -> Result<()>
+where
+    F: FnOnce(&mut Context) -> Result<()>,
+{
+    context.push_environment(env.clone());
+    let result = context.with_strict(true, f);
+    context.pop_environment();
+    result
+}
+
+/// Returns `true` if the node is a function declaration, which is instantiated when the module
+/// is linked.
+fn is_function_declaration(node: &Node) -> bool {
+    matches!(
+        node,
+        Node::FunctionDecl(_) | Node::GeneratorDecl(_) | Node::AsyncFunctionDecl(_)
+    )
+}
+
+/// Returns `true` if the node is an anonymous function, as in `export default function () {}`,
+/// which is instantiated when the module is linked.
+fn is_anonymous_function(node: &Node) -> bool {
+    match node {
+        Node::FunctionExpr(function) => function.name().is_none(),
+        Node::GeneratorExpr(generator) => generator.name().is_none(),
+        Node::AsyncFunctionExpr(function) => function.name().is_none(),
+        _ => false,
+    }
+}
+
+/// Returns `true` if the node is an anonymous function or class, which is named after the binding
+/// it is assigned to.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-isanonymousfunctiondefinition
+fn is_anonymous_function_definition(node: &Node) -> bool {
+    match node {
+        Node::ArrowFunctionDecl(_) => true,
+        Node::ClassExpr(class) => class.name().is_none(),
+        _ => is_anonymous_function(node),
+    }
+}
+
+/// Names an anonymous default export `default`.
+fn set_default_name(value: &Value) {
+    value.set_property(
+        "name",
+        DataDescriptor::new(
+            "default",
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        ),
+    );
+}
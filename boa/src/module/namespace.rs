@@ -0,0 +1,187 @@
+//! This module implements the module namespace exotic object.
+//!
+//! A module namespace object exposes the bindings exported by a module as its properties. The
+//! properties are live: reading one reads the current value of the exported binding.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects
+
+use super::{ExportResolution, Module, ResolvedBinding};
+use crate::{
+    gc::{Finalize, Trace},
+    object::{GcObject, Object},
+    property::{Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
+    symbol::WellKnownSymbols,
+    value::{same_value, Value},
+    Context, Result,
+};
+
+/// The internal slots of a module namespace object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct ModuleNamespace {
+    /// The module whose exports the namespace provides.
+    module: Module,
+    /// The names exported by the module, sorted by code unit order.
+    exports: Box<[Box<str>]>,
+}
+
+impl ModuleNamespace {
+    /// Creates the namespace object of a module.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-modulenamespacecreate
+    pub(super) fn create(module: Module, mut exports: Vec<Box<str>>) -> GcObject {
+        exports.sort();
+        let namespace = Self {
+            module,
+            exports: exports.into(),
+        };
+
+        let mut object = Object::module_namespace(namespace);
+        object.insert(
+            WellKnownSymbols::to_string_tag(),
+            DataDescriptor::new("Module", Attribute::READONLY | Attribute::NON_ENUMERABLE),
+        );
+
+        GcObject::new(object)
+    }
+
+    /// Returns the name exported by the module for the given key, if any.
+    fn export_name(&self, key: &PropertyKey) -> Option<&str> {
+        let name = match key {
+            PropertyKey::Symbol(_) => return None,
+            PropertyKey::String(ref string) => string.to_string(),
+            PropertyKey::Index(index) => index.to_string(),
+        };
+        self.exports
+            .iter()
+            .find(|export| export.as_ref() == name)
+            .map(AsRef::as_ref)
+    }
+
+    /// `[[HasProperty]]` for a string key.
+    ///
+    /// Returns `None` for symbol keys, which are ordinary properties.
+    pub(crate) fn has(&self, key: &PropertyKey) -> Option<bool> {
+        match key {
+            PropertyKey::Symbol(_) => None,
+            _ => Some(self.export_name(key).is_some()),
+        }
+    }
+
+    /// `[[Get]]` for a string key.
+    ///
+    /// Returns `None` for symbol keys, which are ordinary properties.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects-get-p-receiver
+    pub(crate) fn get(&self, key: &PropertyKey, context: &mut Context) -> Option<Result<Value>> {
+        if let PropertyKey::Symbol(_) = key {
+            return None;
+        }
+        let name = match self.export_name(key) {
+            Some(name) => name,
+            None => return Some(Ok(Value::undefined())),
+        };
+
+        Some(match self.module.resolve_export(name, &mut Vec::new()) {
+            ExportResolution::Resolved(ResolvedBinding::Binding { module, name }) => {
+                module.get_binding_value(&name, context)
+            }
+            ExportResolution::Resolved(ResolvedBinding::Namespace(module)) => {
+                Ok(module.namespace().into())
+            }
+            ExportResolution::NotFound | ExportResolution::Ambiguous => {
+                unreachable!("the exports of a namespace are resolvable")
+            }
+        })
+    }
+
+    /// `[[GetOwnProperty]]` for a string key.
+    ///
+    /// Returns `None` for symbol keys, which are ordinary properties.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects-getownproperty-p
+    pub(crate) fn get_own_property(
+        &self,
+        key: &PropertyKey,
+        context: &mut Context,
+    ) -> Option<Result<Option<PropertyDescriptor>>> {
+        if self.export_name(key).is_none() {
+            return self.has(key).map(|_| Ok(None));
+        }
+
+        self.get(key, context).map(|value| {
+            value.map(|value| {
+                Some(
+                    DataDescriptor::new(
+                        value,
+                        Attribute::WRITABLE | Attribute::ENUMERABLE | Attribute::PERMANENT,
+                    )
+                    .into(),
+                )
+            })
+        })
+    }
+
+    /// `[[DefineOwnProperty]]` for a string key.
+    ///
+    /// Returns `None` for symbol keys, which are ordinary properties.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects-defineownproperty-p-desc
+    pub(crate) fn define_own_property(
+        &self,
+        key: &PropertyKey,
+        desc: &PropertyDescriptor,
+        context: &mut Context,
+    ) -> Option<Result<bool>> {
+        let current = match self.get_own_property(key, context)? {
+            Ok(Some(current)) => current,
+            Ok(None) => return Some(Ok(false)),
+            Err(error) => return Some(Err(error)),
+        };
+
+        Some(Ok(match (desc, &current) {
+            (PropertyDescriptor::Data(desc), PropertyDescriptor::Data(current)) => {
+                !desc.configurable()
+                    && desc.enumerable()
+                    && desc.writable()
+                    && same_value(&desc.value(), &current.value())
+            }
+            _ => false,
+        }))
+    }
+
+    /// `[[Delete]]` for a string key.
+    ///
+    /// Returns `None` for symbol keys, which are ordinary properties.
+    pub(crate) fn delete(&self, key: &PropertyKey) -> Option<bool> {
+        self.has(key).map(|has| !has)
+    }
+
+    /// `[[OwnPropertyKeys]]`: the exported names, followed by the symbol keys.
+    pub(crate) fn own_property_keys(&self, object: &GcObject) -> Vec<PropertyKey> {
+        self.exports
+            .iter()
+            .map(|export| PropertyKey::from(export.as_ref()))
+            .chain(
+                object
+                    .ordinary_own_property_keys()
+                    .into_iter()
+                    .filter(|key| matches!(key, PropertyKey::Symbol(_))),
+            )
+            .collect()
+    }
+}
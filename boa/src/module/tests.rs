@@ -0,0 +1,344 @@
+use super::{FsModuleLoader, MapModuleLoader};
+use crate::{forward, property::PropertyKey, Context, Value};
+use std::fs;
+
+/// Creates a context loading the given modules from memory.
+fn context_with_modules(modules: &[(&str, &str)]) -> Context {
+    let mut loader = MapModuleLoader::new();
+    for (key, source) in modules {
+        loader.insert(*key, *source);
+    }
+
+    let mut context = Context::new();
+    context.set_module_loader(loader);
+    context
+}
+
+/// Returns the name of the constructor of the given error.
+fn error_name(error: Value, context: &mut Context) -> String {
+    error
+        .get_field("name", context)
+        .unwrap()
+        .to_string(context)
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn named_and_default_imports() {
+    let mut context = context_with_modules(&[
+        (
+            "lib.js",
+            r#"
+            export const a = 1;
+            export let b = 2;
+            export function f() { return a + b; }
+            export default class Point {}
+            "#,
+        ),
+        (
+            "main.js",
+            r#"
+            import Point, { a, b as c, f } from "./lib.js";
+            globalThis.result = [a, c, f(), Point.name].join();
+            "#,
+        ),
+    ]);
+
+    context.eval_module("main.js").unwrap();
+    assert_eq!(forward(&mut context, "result"), "\"1,2,3,Point\"");
+}
+
+#[test]
+fn default_exports() {
+    let mut context = context_with_modules(&[
+        ("expression.js", "export default 20 + 22;"),
+        ("function.js", "export default function () { return 1; }"),
+        ("arrow.js", "export default () => 2;"),
+        (
+            "main.js",
+            r#"
+            import answer from "./expression.js";
+            import f from "./function.js";
+            import g from "./arrow.js";
+            globalThis.result = [answer, f(), f.name, g(), g.name].join();
+            "#,
+        ),
+    ]);
+
+    context.eval_module("main.js").unwrap();
+    assert_eq!(
+        forward(&mut context, "result"),
+        "\"42,1,default,2,default\""
+    );
+}
+
+#[test]
+fn namespace_imports() {
+    let mut context = context_with_modules(&[
+        ("lib.js", "export var b = 2; export var a = 1;"),
+        (
+            "main.js",
+            r#"
+            import * as ns from "./lib.js";
+            globalThis.result = [
+                Reflect.ownKeys(ns)[0],
+                Reflect.ownKeys(ns)[1],
+                ns.a,
+                ns[Symbol.toStringTag],
+                Object.getPrototypeOf(ns) === null,
+                Reflect.isExtensible(ns),
+                Reflect.set(ns, "a", 3),
+                Reflect.deleteProperty(ns, "a"),
+                "c" in ns,
+            ].join();
+            "#,
+        ),
+    ]);
+
+    context.eval_module("main.js").unwrap();
+    assert_eq!(
+        forward(&mut context, "result"),
+        "\"a,b,1,Module,true,false,false,false,false\""
+    );
+}
+
+#[test]
+fn re_exports() {
+    let mut context = context_with_modules(&[
+        ("a.js", "export const x = 1; export default 'a';"),
+        ("b.js", "export const y = 2;"),
+        (
+            "lib.js",
+            r#"
+            export * from "./a.js";
+            export * as b from "./b.js";
+            export { x as z } from "./a.js";
+            import { y } from "./b.js";
+            export { y };
+            "#,
+        ),
+    ]);
+
+    let namespace = context
+        .eval_module_source("main.js", r#"export * from "./lib.js";"#)
+        .unwrap();
+    let keys = namespace
+        .as_object()
+        .unwrap()
+        .own_property_keys(&mut context)
+        .unwrap()
+        .into_iter()
+        .filter(|key| !matches!(key, PropertyKey::Symbol(_)))
+        .map(|key| key.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(keys, ["b", "x", "y", "z"]);
+
+    let b = namespace.get_field("b", &mut context).unwrap();
+    assert_eq!(b.get_field("y", &mut context).unwrap(), Value::from(2));
+    assert_eq!(
+        namespace.get_field("default", &mut context).unwrap(),
+        Value::undefined()
+    );
+}
+
+#[test]
+fn ambiguous_star_exports_are_hidden() {
+    let mut context = context_with_modules(&[
+        ("a.js", "export const x = 1;"),
+        ("b.js", "export const x = 2;"),
+        (
+            "lib.js",
+            r#"export * from "./a.js"; export * from "./b.js";"#,
+        ),
+        ("main.js", r#"import { x } from "./lib.js";"#),
+    ]);
+
+    let namespace = context
+        .eval_module_source("star.js", r#"export * from "./lib.js";"#)
+        .unwrap();
+    assert_eq!(
+        namespace.get_field("x", &mut context).unwrap(),
+        Value::undefined()
+    );
+
+    let error = context.eval_module("main.js").unwrap_err();
+    assert_eq!(error_name(error, &mut context), "SyntaxError");
+}
+
+#[test]
+fn live_bindings() {
+    let mut context = context_with_modules(&[
+        (
+            "counter.js",
+            "export let count = 0; export function increment() { count++; }",
+        ),
+        (
+            "main.js",
+            r#"
+            import { count, increment } from "./counter.js";
+            increment();
+            increment();
+            globalThis.result = count;
+            "#,
+        ),
+    ]);
+
+    context.eval_module("main.js").unwrap();
+    assert_eq!(forward(&mut context, "result"), "2");
+}
+
+#[test]
+fn cyclic_imports() {
+    let mut context = context_with_modules(&[
+        (
+            "even.js",
+            r#"
+            import { odd } from "./odd.js";
+            export function even(n) { return n === 0 ? true : odd(n - 1); }
+            "#,
+        ),
+        (
+            "odd.js",
+            r#"
+            import { even } from "./even.js";
+            export function odd(n) { return n === 0 ? false : even(n - 1); }
+            order.push("odd");
+            "#,
+        ),
+        (
+            "main.js",
+            r#"
+            import { even } from "./even.js";
+            order.push("main");
+            globalThis.result = even(10);
+            "#,
+        ),
+    ]);
+
+    forward(&mut context, "var order = [];");
+    context.eval_module("main.js").unwrap();
+    assert_eq!(forward(&mut context, "result"), "true");
+    assert_eq!(forward(&mut context, "order.join()"), "\"odd,main\"");
+}
+
+#[test]
+fn modules_are_evaluated_once() {
+    let mut context = context_with_modules(&[
+        ("lib.js", "count++; export default count;"),
+        ("a.js", r#"import "./lib.js";"#),
+        ("b.js", r#"import "./lib.js"; export * from "./lib.js";"#),
+        ("main.js", r#"import "./a.js"; import "./dir/../b.js";"#),
+    ]);
+
+    forward(&mut context, "var count = 0;");
+    context.eval_module("main.js").unwrap();
+    context.eval_module("./lib.js").unwrap();
+    assert_eq!(forward(&mut context, "count"), "1");
+}
+
+#[test]
+fn module_scope() {
+    let mut context = context_with_modules(&[(
+        "main.js",
+        r#"
+        var local = 1;
+        export const self = this;
+        export function f() { return local; }
+        "#,
+    )]);
+
+    let namespace = context.eval_module("main.js").unwrap();
+    assert_eq!(
+        namespace.get_field("self", &mut context).unwrap(),
+        Value::undefined()
+    );
+    assert_eq!(forward(&mut context, "'local' in globalThis"), "false");
+    assert_eq!(forward(&mut context, "'f' in globalThis"), "false");
+}
+
+#[test]
+fn assigning_an_import_throws() {
+    let mut context = context_with_modules(&[
+        ("lib.js", "export let a = 1;"),
+        ("main.js", r#"import { a } from "./lib.js"; a = 2;"#),
+    ]);
+
+    let error = context.eval_module("main.js").unwrap_err();
+    assert_eq!(error_name(error, &mut context), "TypeError");
+}
+
+#[test]
+fn resolution_errors() {
+    let mut context = context_with_modules(&[
+        ("lib.js", "export const a = 1;"),
+        ("missing_export.js", r#"import { b } from "./lib.js";"#),
+        ("missing_module.js", r#"import { a } from "./missing.js";"#),
+        ("invalid.js", "export const a = 1; export { a };"),
+        ("importer.js", r#"import "./invalid.js";"#),
+    ]);
+
+    let error = context.eval_module("missing_export.js").unwrap_err();
+    assert_eq!(error_name(error, &mut context), "SyntaxError");
+
+    let error = context.eval_module("missing_module.js").unwrap_err();
+    assert_eq!(error_name(error, &mut context), "TypeError");
+
+    let error = context.eval_module("importer.js").unwrap_err();
+    assert_eq!(error_name(error, &mut context), "SyntaxError");
+}
+
+#[test]
+fn evaluation_errors_are_cached() {
+    let mut context = context_with_modules(&[
+        ("lib.js", "count++; throw new RangeError('lib');"),
+        ("main.js", r#"import "./lib.js";"#),
+    ]);
+
+    forward(&mut context, "var count = 0;");
+    let error = context.eval_module("main.js").unwrap_err();
+    assert_eq!(error_name(error, &mut context), "RangeError");
+    let error = context.eval_module("lib.js").unwrap_err();
+    assert_eq!(error_name(error, &mut context), "RangeError");
+    assert_eq!(forward(&mut context, "count"), "1");
+}
+
+#[test]
+fn no_module_loader() {
+    let mut context = Context::new();
+
+    let error = context
+        .eval_module_source("main.js", r#"import "./lib.js";"#)
+        .unwrap_err();
+    assert_eq!(error_name(error, &mut context), "TypeError");
+}
+
+#[test]
+fn fs_module_loader_stays_in_root() {
+    let directory = std::env::temp_dir().join(format!("boa-modules-{}", std::process::id()));
+    let root = directory.join("root");
+    fs::create_dir_all(root.join("lib")).unwrap();
+    fs::write(root.join("lib/a.js"), "export const a = 1;").unwrap();
+    fs::write(root.join("main.js"), r#"export { a } from "./lib/a.js";"#).unwrap();
+    fs::write(directory.join("secret.js"), "export const secret = 2;").unwrap();
+
+    let mut context = Context::new();
+    context.set_module_loader(FsModuleLoader::new(&root));
+    let namespace = context.eval_module("main.js").unwrap();
+    let a = namespace.get_field("a", &mut context).unwrap();
+    assert_eq!(a.as_number(), Some(1.0));
+
+    let escapes = [
+        r#"import { secret } from "../secret.js";"#,
+        r#"import { secret } from "./lib/../../secret.js";"#,
+        r#"import { secret } from "/../secret.js";"#,
+    ];
+    for source in escapes.iter() {
+        let error = context
+            .eval_module_source(root.join("main2.js").to_str().unwrap(), *source)
+            .unwrap_err();
+        assert_eq!(error_name(error, &mut context), "TypeError");
+    }
+
+    fs::remove_dir_all(&directory).unwrap();
+}
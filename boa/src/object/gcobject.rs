@@ -2,14 +2,33 @@
 //!
 //! The `GcObject` is a garbage collected Object.
 
-use super::{NativeObject, Object, PROTOTYPE};
+use super::{NativeObject, Object, PrivateElement, PROTOTYPE};
 use crate::{
-    builtins::function::{
-        create_unmapped_arguments_object, BuiltInFunction, Function, NativeFunction,
+    builtins::{
+        async_function::AsyncFunction,
+        function::{
+            arguments::{
+                create_mapped_arguments_object, create_unmapped_arguments_object,
+                is_simple_parameter_list, MappedArguments,
+            },
+            bind_parameters, BoundFunction, BuiltInClosure, BuiltInFunction, Captures,
+            ClassFieldDefinition, ClassFieldName, ClosureFunction, Function, FunctionFlags,
+            NativeFunction,
+        },
+        generator::Generator,
+        proxy::Proxy,
+        typed_array::TypedArray,
     },
-    environment::function_environment_record::{BindingStatus, FunctionEnvironmentRecord},
+    environment::{
+        function_environment_record::{BindingStatus, FunctionEnvironmentRecord},
+        lexical_environment::Environment,
+    },
+    exec::InterpreterState,
+    gc::{Liveness, WeakGc, WeakReferent},
+    module::ModuleNamespace,
     property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
-    syntax::ast::node::RcStatementList,
+    symbol::{RcSymbol, WellKnownSymbols},
+    syntax::ast::node::{FormalParameter, RcStatementList},
     value::PreferredType,
     Context, Executable, Result, Value,
 };
@@ -20,9 +39,13 @@ use std::{
     collections::HashMap,
     error::Error,
     fmt::{self, Debug, Display},
+    rc::Rc,
     result::Result as StdResult,
 };
 
+#[cfg(feature = "vm")]
+use crate::vm::{CodeBlock, VM};
+
 /// A wrapper type for an immutably borrowed type T.
 pub type Ref<'a, T> = GcCellRef<'a, T>;
 
@@ -33,6 +56,37 @@ pub type RefMut<'a, T, U> = GcCellRefMut<'a, T, U>;
 #[derive(Trace, Finalize, Clone, Default)]
 pub struct GcObject(Gc<GcCell<Object>>);
 
+impl WeakReferent for GcCell<Object> {
+    #[inline]
+    fn liveness(&self) -> Liveness {
+        self.borrow().weak_target.liveness()
+    }
+}
+
+/// A weak reference to a `GcObject`, which does not keep the object alive.
+#[derive(Debug, Trace, Finalize, Clone)]
+pub struct WeakGcObject(WeakGc<GcCell<Object>>);
+
+impl WeakGcObject {
+    /// Returns the object, or `None` if it has been collected.
+    #[inline]
+    pub fn upgrade(&self) -> Option<GcObject> {
+        self.0.upgrade().map(GcObject)
+    }
+
+    /// Returns `true` if the object has not been collected.
+    #[inline]
+    pub fn is_alive(&self) -> bool {
+        self.0.is_alive()
+    }
+
+    /// Checks if the reference points to the given object.
+    #[inline]
+    pub fn ptr_eq(&self, object: &GcObject) -> bool {
+        self.0.ptr_eq(&object.0)
+    }
+}
+
 /// The body of a JavaScript function.
 ///
 /// This is needed for the call method since we cannot mutate the function itself since we
@@ -40,7 +94,119 @@ pub struct GcObject(Gc<GcCell<Object>>);
 enum FunctionBody {
     BuiltInFunction(NativeFunction),
     BuiltInConstructor(NativeFunction),
-    Ordinary(RcStatementList),
+    Closure(ClosureFunction, Captures),
+    Ordinary(RcStatementList, Rc<[FormalParameter]>),
+    Async(RcStatementList, Rc<[FormalParameter]>, Environment),
+    Generator(RcStatementList, Rc<[FormalParameter]>, Environment),
+    #[cfg(feature = "vm")]
+    Compiled(Rc<CodeBlock>),
+}
+
+/// Creates the environment of a call to an ordinary or compiled function, binding its
+/// `arguments` object.
+///
+/// The parent of the environment is the scope of the function declaration.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-prepareforordinarycall
+#[allow(clippy::too_many_arguments)]
+fn create_function_environment(
+    function: GcObject,
+    this: Option<Value>,
+    environment: &Environment,
+    flags: FunctionFlags,
+    home_object: Option<&GcObject>,
+    new_target: Value,
+    params: &[FormalParameter],
+    args: &[Value],
+    context: &mut Context,
+) -> Result<Environment> {
+    // The `this` value of a sloppy mode function is always an object, the global object taking
+    // the place of `undefined` and `null`
+    // see <https://tc39.es/ecma262/#sec-ordinarycallbindthis>
+    let this = match this {
+        Some(Value::Undefined) | Some(Value::Null) if !flags.is_strict() => {
+            Some(context.global_object().into())
+        }
+        Some(this) if !flags.is_strict() => Some(this.to_object(context)?.into()),
+        this => this,
+    };
+
+    let local_env = FunctionEnvironmentRecord::new(
+        function.clone(),
+        this,
+        Some(environment.clone()),
+        // Arrow functions do not have a this binding https://tc39.es/ecma262/#sec-function-environment-records
+        if flags.is_lexical_this_mode() {
+            BindingStatus::Lexical
+        } else {
+            BindingStatus::Uninitialized
+        },
+        home_object.cloned().map(Value::from).unwrap_or_default(),
+        new_target,
+    );
+
+    // Arrow functions see the `arguments` of their enclosing function, and a parameter named
+    // `arguments` takes the place of the object
+    // see <https://tc39.es/ecma262/#sec-functiondeclarationinstantiation>
+    let arguments_object_needed = !flags.is_lexical_this_mode()
+        && !params
+            .iter()
+            .any(|param| param.names().contains(&"arguments"));
+    if arguments_object_needed {
+        let arguments_obj = if flags.is_strict() || !is_simple_parameter_list(params) {
+            create_unmapped_arguments_object(args, context)
+        } else {
+            create_mapped_arguments_object(&function, params, args, &local_env, context)
+        };
+        if flags.is_strict() {
+            local_env.borrow_mut().create_immutable_binding(
+                "arguments".to_string(),
+                false,
+                context,
+            )?;
+        } else {
+            local_env.borrow_mut().create_mutable_binding(
+                "arguments".to_string(),
+                false,
+                true,
+                context,
+            )?;
+        }
+        local_env
+            .borrow_mut()
+            .initialize_binding("arguments", arguments_obj, context)?;
+    }
+
+    Ok(local_env)
+}
+
+/// Gets the prototype of the objects created by a constructor.
+///
+/// If the prototype of the constructor is not an object, then the default object prototype is
+/// used as prototype for the new object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getprototypefromconstructor
+fn get_prototype_from_constructor(new_target: &Value, context: &mut Context) -> Result<Value> {
+    let proto =
+        new_target
+            .as_object()
+            .unwrap()
+            .get(&PROTOTYPE.into(), new_target.clone(), context)?;
+    Ok(if proto.is_object() {
+        proto
+    } else {
+        context
+            .standard_objects()
+            .object_object()
+            .prototype()
+            .into()
+    })
 }
 
 impl GcObject {
@@ -105,6 +271,12 @@ impl GcObject {
         std::ptr::eq(lhs.as_ref(), rhs.as_ref())
     }
 
+    /// Creates a weak reference to the object.
+    #[inline]
+    pub fn downgrade(&self) -> WeakGcObject {
+        WeakGcObject(WeakGc::new(&self.0))
+    }
+
     /// Call this object.
     ///
     /// # Panics
@@ -114,6 +286,15 @@ impl GcObject {
     // <https://tc39.es/ecma262/#sec-ecmascript-function-objects-call-thisargument-argumentslist>
     #[track_caller]
     pub fn call(&self, this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        if let Some(proxy) = self.as_proxy() {
+            if proxy.is_callable() {
+                return proxy.call(this, args, context);
+            }
+        }
+        if let Some(bound_function) = self.as_bound_function() {
+            return bound_function.call(args, context);
+        }
+
         let this_function_object = self.clone();
         let f_body = if let Some(function) = self.borrow().as_function() {
             if function.is_callable() {
@@ -125,61 +306,79 @@ impl GcObject {
                             FunctionBody::BuiltInFunction(*function)
                         }
                     }
+                    Function::Closure {
+                        function: BuiltInClosure(function),
+                        captures,
+                        ..
+                    } => FunctionBody::Closure(*function, captures.clone()),
                     Function::Ordinary {
                         body,
                         params,
                         environment,
                         flags,
+                        home_object,
+                        ..
                     } => {
-                        // Create a new Function environment whose parent is set to the scope of the function declaration (self.environment)
-                        // <https://tc39.es/ecma262/#sec-prepareforordinarycall>
-                        let local_env = FunctionEnvironmentRecord::new(
+                        // <https://tc39.es/ecma262/#sec-ecmascript-function-objects-call-thisargument-argumentslist>
+                        if flags.is_class_constructor() {
+                            return context.throw_type_error(
+                                "class constructors cannot be invoked without 'new'",
+                            );
+                        }
+
+                        let local_env = create_function_environment(
                             this_function_object,
                             if flags.is_lexical_this_mode() {
                                 None
                             } else {
                                 Some(this.clone())
                             },
-                            Some(environment.clone()),
-                            // Arrow functions do not have a this binding https://tc39.es/ecma262/#sec-function-environment-records
+                            environment,
+                            *flags,
+                            home_object.as_ref(),
+                            Value::undefined(),
+                            params,
+                            args,
+                            context,
+                        )?;
+
+                        // The parameters are bound once the function is no longer borrowed, as
+                        // default values and destructuring patterns can run arbitrary code
+                        if flags.is_async() {
+                            FunctionBody::Async(body.clone(), params.clone(), local_env)
+                        } else if flags.is_generator() {
+                            FunctionBody::Generator(body.clone(), params.clone(), local_env)
+                        } else {
+                            context.push_environment(local_env);
+
+                            FunctionBody::Ordinary(body.clone(), params.clone())
+                        }
+                    }
+                    #[cfg(feature = "vm")]
+                    Function::Compiled {
+                        code,
+                        environment,
+                        flags,
+                        home_object,
+                    } => {
+                        let local_env = create_function_environment(
+                            this_function_object,
                             if flags.is_lexical_this_mode() {
-                                BindingStatus::Lexical
+                                None
                             } else {
-                                BindingStatus::Uninitialized
+                                Some(this.clone())
                             },
+                            environment,
+                            *flags,
+                            home_object.as_ref(),
                             Value::undefined(),
-                        );
-
-                        // Add argument bindings to the function environment
-                        for (i, param) in params.iter().enumerate() {
-                            // Rest Parameters
-                            if param.is_rest_param() {
-                                function.add_rest_param(param, i, args, context, &local_env);
-                                break;
-                            }
-
-                            let value = args.get(i).cloned().unwrap_or_else(Value::undefined);
-                            function
-                                .add_arguments_to_environment(param, value, &local_env, context);
-                        }
-
-                        // Add arguments object
-                        let arguments_obj = create_unmapped_arguments_object(args);
-                        local_env.borrow_mut().create_mutable_binding(
-                            "arguments".to_string(),
-                            false,
-                            true,
-                            context,
-                        )?;
-                        local_env.borrow_mut().initialize_binding(
-                            "arguments",
-                            arguments_obj,
+                            &code.params,
+                            args,
                             context,
                         )?;
-
                         context.push_environment(local_env);
 
-                        FunctionBody::Ordinary(body.clone())
+                        FunctionBody::Compiled(code.clone())
                     }
                 }
             } else {
@@ -192,12 +391,62 @@ impl GcObject {
         match f_body {
             FunctionBody::BuiltInFunction(func) => func(this, args, context),
             FunctionBody::BuiltInConstructor(func) => func(&Value::undefined(), args, context),
-            FunctionBody::Ordinary(body) => {
-                let result = body.run(context);
+            FunctionBody::Closure(func, captures) => func(this, args, &captures, context),
+            FunctionBody::Ordinary(body, params) => {
+                context.executor().call_stack.push(self.stack_frame_name());
+                let result = context.with_strict(body.strict(), |context| {
+                    bind_parameters(&params, args, context).and_then(|_| body.run(context))
+                });
+                context.executor().call_stack.pop();
                 context.pop_environment();
 
+                // unset the early return flag, natives can call functions outside of a `Call` node
+                context
+                    .executor()
+                    .set_current_state(InterpreterState::Executing);
+
                 result
             }
+            #[cfg(feature = "vm")]
+            FunctionBody::Compiled(code) => {
+                context.executor().call_stack.push(self.stack_frame_name());
+                let result = context.with_strict(code.flags.is_strict(), |context| {
+                    bind_parameters(&code.params, args, context)
+                        .and_then(|_| VM::new(&code, context).run())
+                });
+                context.executor().call_stack.pop();
+                context.pop_environment();
+                result
+            }
+            FunctionBody::Async(body, params, environment) => Ok(AsyncFunction::start(
+                self.stack_frame_name(),
+                body,
+                params,
+                args,
+                environment,
+                context,
+            )),
+            FunctionBody::Generator(body, params, environment) => {
+                context.push_environment(environment.clone());
+                let result = context.with_strict(body.strict(), |context| {
+                    bind_parameters(&params, args, context)
+                });
+                context.pop_environment();
+                result?;
+
+                Generator::create(self, body, environment, context)
+            }
+        }
+    }
+
+    /// Gets the name of the function shown in its frames of the call stack.
+    pub(crate) fn stack_frame_name(&self) -> Box<str> {
+        match self.ordinary_get_own_property(&"name".into()) {
+            Some(PropertyDescriptor::Data(ref name)) => match name.value() {
+                Value::String(ref name) if !name.is_empty() => name.as_str().into(),
+                _ => "<anonymous>".into(),
+            },
+            _ => "<anonymous>".into(),
         }
     }
 
@@ -214,83 +463,94 @@ impl GcObject {
         new_target: Value,
         context: &mut Context,
     ) -> Result<Value> {
+        if let Some(proxy) = self.as_proxy() {
+            if proxy.is_constructable() {
+                return proxy.construct(args, new_target, context);
+            }
+        }
+        if let Some(bound_function) = self.as_bound_function() {
+            if bound_function.is_constructable() {
+                return bound_function.construct(self, args, new_target, context);
+            }
+        }
+
         let this_function_object = self.clone();
+        let mut is_derived = false;
+        let mut fields_target = None;
         let body = if let Some(function) = self.borrow().as_function() {
             if function.is_constructable() {
                 match function {
                     Function::BuiltIn(BuiltInFunction(function), _) => {
                         FunctionBody::BuiltInConstructor(*function)
                     }
+                    Function::Closure {
+                        function: BuiltInClosure(function),
+                        captures,
+                        ..
+                    } => FunctionBody::Closure(*function, captures.clone()),
                     Function::Ordinary {
                         body,
                         params,
                         environment,
                         flags,
+                        home_object,
+                        ..
                     } => {
-                        // If the prototype of the constructor is not an object, then use the default object
-                        // prototype as prototype for the new object
-                        // see <https://tc39.es/ecma262/#sec-ordinarycreatefromconstructor>
-                        // see <https://tc39.es/ecma262/#sec-getprototypefromconstructor>
-                        let proto = new_target.as_object().unwrap().get(
-                            &PROTOTYPE.into(),
-                            new_target.clone(),
-                            context,
-                        )?;
-                        let proto = if proto.is_object() {
-                            proto
+                        // A derived constructor binds `this` when it calls `super(...)`
+                        // see <https://tc39.es/ecma262/#sec-ecmascript-function-objects-construct-argumentslist-newtarget>
+                        let this = if flags.is_derived() {
+                            is_derived = true;
+                            None
                         } else {
-                            context
-                                .standard_objects()
-                                .object_object()
-                                .prototype()
-                                .into()
+                            let this = GcObject::new(Object::create(
+                                get_prototype_from_constructor(&new_target, context)?,
+                            ));
+                            if flags.is_class_constructor() {
+                                fields_target = Some(this.clone());
+                            }
+                            Some(this.into())
                         };
-                        let this = Value::from(Object::create(proto));
 
-                        // Create a new Function environment who's parent is set to the scope of the function declaration (self.environment)
-                        // <https://tc39.es/ecma262/#sec-prepareforordinarycall>
-                        let local_env = FunctionEnvironmentRecord::new(
+                        let local_env = create_function_environment(
                             this_function_object,
-                            Some(this),
-                            Some(environment.clone()),
-                            // Arrow functions do not have a this binding https://tc39.es/ecma262/#sec-function-environment-records
-                            if flags.is_lexical_this_mode() {
-                                BindingStatus::Lexical
-                            } else {
-                                BindingStatus::Uninitialized
-                            },
+                            this,
+                            environment,
+                            *flags,
+                            home_object.as_ref(),
                             new_target.clone(),
-                        );
-
-                        // Add argument bindings to the function environment
-                        for (i, param) in params.iter().enumerate() {
-                            // Rest Parameters
-                            if param.is_rest_param() {
-                                function.add_rest_param(param, i, args, context, &local_env);
-                                break;
-                            }
-
-                            let value = args.get(i).cloned().unwrap_or_else(Value::undefined);
-                            function
-                                .add_arguments_to_environment(param, value, &local_env, context);
-                        }
-
-                        // Add arguments object
-                        let arguments_obj = create_unmapped_arguments_object(args);
-                        local_env.borrow_mut().create_mutable_binding(
-                            "arguments".to_string(),
-                            false,
-                            true,
+                            params,
+                            args,
                             context,
                         )?;
-                        local_env.borrow_mut().initialize_binding(
-                            "arguments",
-                            arguments_obj,
+                        context.push_environment(local_env);
+
+                        FunctionBody::Ordinary(body.clone(), params.clone())
+                    }
+                    #[cfg(feature = "vm")]
+                    Function::Compiled {
+                        code,
+                        environment,
+                        flags,
+                        home_object,
+                    } => {
+                        let this = GcObject::new(Object::create(get_prototype_from_constructor(
+                            &new_target,
+                            context,
+                        )?));
+                        let local_env = create_function_environment(
+                            this_function_object,
+                            Some(this.into()),
+                            environment,
+                            *flags,
+                            home_object.as_ref(),
+                            new_target.clone(),
+                            &code.params,
+                            args,
                             context,
                         )?;
                         context.push_environment(local_env);
 
-                        FunctionBody::Ordinary(body.clone())
+                        FunctionBody::Compiled(code.clone())
                     }
                 }
             } else {
@@ -306,15 +566,64 @@ impl GcObject {
 
         match body {
             FunctionBody::BuiltInConstructor(function) => function(&new_target, args, context),
-            FunctionBody::Ordinary(body) => {
-                let _ = body.run(context);
-
-                // local_env gets dropped here, its no longer needed
-                let result = context.get_this_binding();
+            FunctionBody::Closure(function, captures) => {
+                function(&new_target, args, &captures, context)
+            }
+            FunctionBody::Ordinary(body, params) => {
+                context.executor().call_stack.push(self.stack_frame_name());
+                // Base class constructors initialize the fields before running their body
+                // see <https://tc39.es/ecma262/#sec-ordinarycallbindthis>
+                let result = match fields_target {
+                    Some(this) => this.initialize_instance_elements(self, context),
+                    None => Ok(()),
+                }
+                .and_then(|_| {
+                    context.with_strict(body.strict(), |context| {
+                        bind_parameters(&params, args, context).and_then(|_| body.run(context))
+                    })
+                });
+                context.executor().call_stack.pop();
+                let returned = context.executor().get_current_state() == &InterpreterState::Return;
+                context
+                    .executor()
+                    .set_current_state(InterpreterState::Executing);
+
+                let result = match result {
+                    Ok(value) if returned && value.is_object() => Ok(value),
+                    Ok(value) if returned && is_derived && !value.is_undefined() => context
+                        .throw_type_error(
+                            "derived constructors may only return an object or undefined",
+                        ),
+                    // local_env gets dropped here, its no longer needed
+                    Ok(_) => context.get_this_binding(),
+                    Err(error) => Err(error),
+                };
+                context.pop_environment();
+                result
+            }
+            #[cfg(feature = "vm")]
+            FunctionBody::Compiled(code) => {
+                context.executor().call_stack.push(self.stack_frame_name());
+                let result = context.with_strict(code.flags.is_strict(), |context| {
+                    bind_parameters(&code.params, args, context)
+                        .and_then(|_| VM::new(&code, context).run())
+                });
+                context.executor().call_stack.pop();
+                let result = result.and_then(|value| {
+                    if value.is_object() {
+                        Ok(value)
+                    } else {
+                        context.get_this_binding()
+                    }
+                });
                 context.pop_environment();
                 result
             }
-            FunctionBody::BuiltInFunction(_) => unreachable!("Cannot have a function in construct"),
+            FunctionBody::BuiltInFunction(_)
+            | FunctionBody::Async(..)
+            | FunctionBody::Generator(..) => {
+                unreachable!("Cannot have a function in construct")
+            }
         }
     }
 
@@ -391,30 +700,47 @@ impl GcObject {
     }
 
     /// Converts an object to JSON, checking for reference cycles and throwing a TypeError if one is found
+    ///
+    /// The properties are read with the internal methods of the object, so proxies and exotic
+    /// objects like typed arrays are serialized through their own keys and values.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-serializejsonobject
     pub(crate) fn to_json(&self, context: &mut Context) -> Result<Option<JSONValue>> {
         let rec_limiter = RecursionLimiter::new(self);
         if rec_limiter.live {
-            Err(context.construct_type_error("cyclic object value"))
-        } else if self.is_array() {
-            let mut keys: Vec<u32> = self.borrow().index_property_keys().cloned().collect();
-            keys.sort_unstable();
-            let mut arr: Vec<JSONValue> = Vec::with_capacity(keys.len());
-            let this = Value::from(self.clone());
-            for key in keys {
-                let value = this.get_field(key, context)?;
-                if let Some(value) = value.to_json(context)? {
-                    arr.push(value);
-                } else {
-                    arr.push(JSONValue::Null);
-                }
+            return Err(context.construct_type_error("cyclic object value"));
+        }
+
+        let this = Value::from(self.clone());
+        if this.is_array(context)? {
+            // SerializeJSONArray ( state, value )
+            let length = self
+                .get(&"length".into(), this.clone(), context)?
+                .to_length(context)?;
+            let mut arr: Vec<JSONValue> = Vec::with_capacity(length);
+            for index in 0..length {
+                let value = self.get(&index.into(), this.clone(), context)?;
+                arr.push(value.to_json(context)?.unwrap_or(JSONValue::Null));
             }
             Ok(Some(JSONValue::Array(arr)))
         } else {
+            // SerializeJSONObject ( state, value ), with the keys of
+            // EnumerableOwnPropertyNames ( value, key ).
             let mut new_obj = Map::new();
-            let this = Value::from(self.clone());
-            for k in self.borrow().keys() {
-                let key = k.clone();
-                let value = this.get_field(k.to_string(), context)?;
+            for key in self.own_property_keys(context)? {
+                if let PropertyKey::Symbol(_) = key {
+                    continue;
+                }
+                let enumerable = self
+                    .get_own_property(&key, context)?
+                    .map_or(false, |desc| desc.enumerable());
+                if !enumerable {
+                    continue;
+                }
+                let value = self.get(&key, this.clone(), context)?;
                 if let Some(value) = value.to_json(context)? {
                     new_obj.insert(key.to_string(), value);
                 }
@@ -432,7 +758,7 @@ impl GcObject {
         let mut attribute = Attribute::empty();
 
         let enumerable_key = PropertyKey::from("enumerable");
-        if self.has_property(&enumerable_key)
+        if self.has_property(&enumerable_key, context)?
             && self
                 .get(&enumerable_key, self.clone().into(), context)?
                 .to_boolean()
@@ -441,7 +767,7 @@ impl GcObject {
         }
 
         let configurable_key = PropertyKey::from("configurable");
-        if self.has_property(&configurable_key)
+        if self.has_property(&configurable_key, context)?
             && self
                 .get(&configurable_key, self.clone().into(), context)?
                 .to_boolean()
@@ -451,13 +777,13 @@ impl GcObject {
 
         let mut value = None;
         let value_key = PropertyKey::from("value");
-        if self.has_property(&value_key) {
+        if self.has_property(&value_key, context)? {
             value = Some(self.get(&value_key, self.clone().into(), context)?);
         }
 
         let mut has_writable = false;
         let writable_key = PropertyKey::from("writable");
-        if self.has_property(&writable_key) {
+        if self.has_property(&writable_key, context)? {
             has_writable = true;
             if self
                 .get(&writable_key, self.clone().into(), context)?
@@ -469,7 +795,7 @@ impl GcObject {
 
         let mut get = None;
         let get_key = PropertyKey::from("get");
-        if self.has_property(&get_key) {
+        if self.has_property(&get_key, context)? {
             let getter = self.get(&get_key, self.clone().into(), context)?;
             match getter {
                 Value::Object(ref object) if object.is_callable() => {
@@ -485,7 +811,7 @@ impl GcObject {
 
         let mut set = None;
         let set_key = PropertyKey::from("set");
-        if self.has_property(&set_key) {
+        if self.has_property(&set_key, context)? {
             let setter = self.get(&set_key, self.clone().into(), context)?;
             match setter {
                 Value::Object(ref object) if object.is_callable() => {
@@ -609,6 +935,85 @@ impl GcObject {
         self.borrow().is_array_iterator()
     }
 
+    /// Checks if it is a `Proxy` object.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    #[inline]
+    #[track_caller]
+    pub fn is_proxy(&self) -> bool {
+        self.borrow().is_proxy()
+    }
+
+    /// Checks if it is a module namespace object.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    #[inline]
+    #[track_caller]
+    pub fn is_module_namespace(&self) -> bool {
+        self.borrow().is_module_namespace()
+    }
+
+    /// Gets a copy of the internal slots of a `Proxy` object.
+    ///
+    /// The copy is returned so that the object is not borrowed while the traps run.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    #[inline]
+    #[track_caller]
+    pub(crate) fn as_proxy(&self) -> Option<Proxy> {
+        self.borrow().as_proxy().cloned()
+    }
+
+    /// Returns a copy of the internal slots of the object if it is a bound function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    #[inline]
+    #[track_caller]
+    pub(crate) fn as_bound_function(&self) -> Option<BoundFunction> {
+        self.borrow().as_bound_function().cloned()
+    }
+
+    /// Returns a copy of the data of the object if it is a typed array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    #[inline]
+    #[track_caller]
+    pub(crate) fn as_typed_array(&self) -> Option<TypedArray> {
+        self.borrow().as_typed_array().cloned()
+    }
+
+    /// Returns a copy of the internal slots of a module namespace object.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    #[inline]
+    #[track_caller]
+    pub(crate) fn as_module_namespace(&self) -> Option<ModuleNamespace> {
+        self.borrow().as_module_namespace().cloned()
+    }
+
+    /// Returns a copy of the internal slots of a mapped `arguments` object.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    #[inline]
+    #[track_caller]
+    pub(crate) fn as_mapped_arguments(&self) -> Option<MappedArguments> {
+        self.borrow().as_mapped_arguments().cloned()
+    }
+
     /// Checks if it is a `Map` object.pub
     ///
     /// # Panics
@@ -755,6 +1160,232 @@ impl GcObject {
         }
     }
 
+    /// Returns the constructor that should be used to create objects derived from this object.
+    ///
+    /// More information:
+    /// - [EcmaScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-speciesconstructor
+    pub(crate) fn species_constructor(
+        &self,
+        default_constructor: Value,
+        context: &mut Context,
+    ) -> Result<Value> {
+        // 1. Assert: Type(O) is Object.
+
+        // 2. Let C be ? Get(O, "constructor").
+        let c = self.get(&"constructor".into(), self.clone().into(), context)?;
+
+        // 3. If C is undefined, return defaultConstructor.
+        if c.is_undefined() {
+            return Ok(default_constructor);
+        }
+
+        // 4. If Type(C) is not Object, throw a TypeError exception.
+        let c = match c.as_object() {
+            Some(c) => c,
+            None => return context.throw_type_error("property 'constructor' is not an object"),
+        };
+
+        // 5. Let S be ? Get(C, @@species).
+        let s = c.get(
+            &WellKnownSymbols::species().into(),
+            c.clone().into(),
+            context,
+        )?;
+
+        // 6. If S is either undefined or null, return defaultConstructor.
+        if s.is_null_or_undefined() {
+            return Ok(default_constructor);
+        }
+
+        // 7. If IsConstructor(S) is true, return S.
+        // 8. Throw a TypeError exception.
+        match s.as_object() {
+            Some(constructor) if constructor.is_constructable() => Ok(s),
+            _ => context.throw_type_error("property 'constructor' is not a constructor"),
+        }
+    }
+
+    /// Defines the private methods and the fields of a class constructor on a newly created
+    /// instance.
+    ///
+    /// More information:
+    /// - [EcmaScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-initializeinstanceelements
+    pub(crate) fn initialize_instance_elements(
+        &self,
+        constructor: &GcObject,
+        context: &mut Context,
+    ) -> Result<()> {
+        let (methods, fields) = constructor
+            .borrow()
+            .as_function()
+            .map(|function| {
+                (
+                    function.private_methods().to_vec(),
+                    function.fields().to_vec(),
+                )
+            })
+            .unwrap_or_default();
+
+        for (name, method) in methods {
+            self.private_method_or_accessor_add(name, method, context)?;
+        }
+        for field in fields {
+            self.define_field(&field, context)?;
+        }
+
+        Ok(())
+    }
+
+    /// Defines a field of a class on the object, with the value computed by its initializer.
+    ///
+    /// More information:
+    /// - [EcmaScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-definefield
+    pub(crate) fn define_field(
+        &self,
+        field: &ClassFieldDefinition,
+        context: &mut Context,
+    ) -> Result<()> {
+        let value = match field.initializer() {
+            Some(initializer) => initializer.call(&self.clone().into(), &[], context)?,
+            None => Value::undefined(),
+        };
+
+        match field.name() {
+            ClassFieldName::Private(name) => self.private_field_add(name.clone(), value, context),
+            // CreateDataPropertyOrThrow ( O, P, V )
+            ClassFieldName::Key(key) => {
+                let desc = DataDescriptor::new(value, Attribute::all());
+                if self
+                    .clone()
+                    .define_own_property(key.clone(), desc.into(), context)?
+                {
+                    Ok(())
+                } else {
+                    Err(context.construct_type_error(format!(
+                        "cannot define field '{}' on the instance",
+                        key
+                    )))
+                }
+            }
+        }
+    }
+
+    /// Adds a private field to the object.
+    ///
+    /// More information:
+    /// - [EcmaScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-privatefieldadd
+    pub(crate) fn private_field_add(
+        &self,
+        name: RcSymbol,
+        value: Value,
+        context: &mut Context,
+    ) -> Result<()> {
+        self.private_method_or_accessor_add(name, PrivateElement::Field(value), context)
+    }
+
+    /// Adds a private method or accessor to the object.
+    ///
+    /// An object can only be initialized once by a class, so adding an element twice is an error.
+    ///
+    /// More information:
+    /// - [EcmaScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-privatemethodoraccessoradd
+    pub(crate) fn private_method_or_accessor_add(
+        &self,
+        name: RcSymbol,
+        element: PrivateElement,
+        context: &mut Context,
+    ) -> Result<()> {
+        let mut object = self.borrow_mut();
+        if object.private_elements().contains_key(&name) {
+            drop(object);
+            return Err(context.construct_type_error(format!(
+                "cannot initialize {} twice on the same object",
+                private_name_description(&name)
+            )));
+        }
+        object.private_elements_mut().insert(name, element);
+        Ok(())
+    }
+
+    /// Gets the value of a private element of the object.
+    ///
+    /// More information:
+    /// - [EcmaScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-privateget
+    pub(crate) fn private_get(&self, name: &RcSymbol, context: &mut Context) -> Result<Value> {
+        let element = self.borrow().private_elements().get(name).cloned();
+        match element {
+            Some(PrivateElement::Field(ref value)) => Ok(value.clone()),
+            Some(PrivateElement::Method(ref method)) => Ok(method.clone().into()),
+            Some(PrivateElement::Accessor {
+                getter: Some(ref getter),
+                ..
+            }) => getter.call(&self.clone().into(), &[], context),
+            Some(PrivateElement::Accessor { getter: None, .. }) => Err(context
+                .construct_type_error(format!(
+                    "private accessor {} was defined without a getter",
+                    private_name_description(name)
+                ))),
+            None => Err(context.construct_type_error(format!(
+                "cannot read private member {} from an object whose class did not declare it",
+                private_name_description(name)
+            ))),
+        }
+    }
+
+    /// Sets the value of a private element of the object.
+    ///
+    /// More information:
+    /// - [EcmaScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-privateset
+    pub(crate) fn private_set(
+        &self,
+        name: &RcSymbol,
+        value: Value,
+        context: &mut Context,
+    ) -> Result<()> {
+        let element = self.borrow().private_elements().get(name).cloned();
+        match element {
+            Some(PrivateElement::Field(_)) => {
+                self.borrow_mut()
+                    .private_elements_mut()
+                    .insert(name.clone(), PrivateElement::Field(value));
+                Ok(())
+            }
+            Some(PrivateElement::Method(_)) => Err(context.construct_type_error(format!(
+                "private method {} is not writable",
+                private_name_description(name)
+            ))),
+            Some(PrivateElement::Accessor {
+                setter: Some(ref setter),
+                ..
+            }) => setter
+                .call(&self.clone().into(), &[value], context)
+                .map(|_| ()),
+            Some(PrivateElement::Accessor { setter: None, .. }) => Err(context
+                .construct_type_error(format!(
+                    "private accessor {} was defined without a setter",
+                    private_name_description(name)
+                ))),
+            None => Err(context.construct_type_error(format!(
+                "cannot write private member {} to an object whose class did not declare it",
+                private_name_description(name)
+            ))),
+        }
+    }
+
     /// Determines if `value` inherits from the instance object inheritance path.
     ///
     /// More information:
@@ -771,21 +1402,24 @@ impl GcObject {
             return Ok(false);
         }
 
-        // TODO: If C has a [[BoundTargetFunction]] internal slot, then
-        //           Let BC be C.[[BoundTargetFunction]].
-        //           Return ? InstanceofOperator(O, BC).
+        // 2. If C has a [[BoundTargetFunction]] internal slot, then
+        if let Some(bound_function) = self.as_bound_function() {
+            // a. Let BC be C.[[BoundTargetFunction]].
+            // b. Return ? InstanceofOperator(O, BC).
+            return value.instance_of(&bound_function.target_function().clone().into(), context);
+        }
 
         if let Some(object) = value.as_object() {
             if let Some(prototype) = self
                 .get(&"prototype".into(), self.clone().into(), context)?
                 .as_object()
             {
-                let mut object = object.get_prototype_of();
+                let mut object = object.get_prototype_of(context)?;
                 while let Some(object_prototype) = object.as_object() {
                     if GcObject::equals(&prototype, &object_prototype) {
                         return Ok(true);
                     }
-                    object = object_prototype.get_prototype_of();
+                    object = object_prototype.get_prototype_of(context)?;
                 }
 
                 Ok(false)
@@ -804,7 +1438,7 @@ impl GcObject {
         K: Into<PropertyKey>,
     {
         let key = key.into();
-        self.get_own_property(&key).is_some()
+        self.ordinary_get_own_property(&key).is_some()
     }
 
     /// Defines the property or throws a `TypeError` if the operation fails.
@@ -834,6 +1468,62 @@ impl GcObject {
             Ok(())
         }
     }
+
+    /// Sets the property or throws a `TypeError` if it cannot be set, like a read-only
+    /// property or a new property of a non-extensible object.
+    ///
+    /// More information:
+    /// - [EcmaScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set-o-p-v-throw
+    #[inline]
+    pub(crate) fn set_or_throw<K, V>(
+        &mut self,
+        key: K,
+        value: V,
+        context: &mut Context,
+    ) -> Result<()>
+    where
+        K: Into<PropertyKey>,
+        V: Into<Value>,
+    {
+        let key = key.into();
+        let receiver = self.clone().into();
+        if self.set(key.clone(), value.into(), receiver, context)? {
+            Ok(())
+        } else {
+            Err(context
+                .construct_type_error(format!("Cannot assign to read-only property: {}", key)))
+        }
+    }
+
+    /// Deletes the property or throws a `TypeError` if it is not configurable.
+    ///
+    /// More information:
+    /// - [EcmaScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-deletepropertyorthrow
+    #[inline]
+    pub(crate) fn delete_property_or_throw<K>(
+        &mut self,
+        key: K,
+        context: &mut Context,
+    ) -> Result<()>
+    where
+        K: Into<PropertyKey>,
+    {
+        let key = key.into();
+        if self.delete(&key, context)? {
+            Ok(())
+        } else {
+            Err(context.construct_type_error(format!("Cannot delete property: {}", key)))
+        }
+    }
+}
+
+/// Returns the description of a private name, like `#x`, for error messages.
+fn private_name_description(name: &RcSymbol) -> &str {
+    name.description().unwrap_or_default()
 }
 
 impl AsRef<GcCell<Object>> for GcObject {
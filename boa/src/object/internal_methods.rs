@@ -6,11 +6,13 @@
 //! [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots
 
 use crate::{
+    builtins::{typed_array::canonical_numeric_index, Array},
     object::{GcObject, Object, ObjectData},
     property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
     value::{same_value, Type, Value},
     BoaProfiler, Context, Result,
 };
+use std::convert::TryFrom;
 
 impl GcObject {
     /// Check if object has property.
@@ -20,17 +22,29 @@ impl GcObject {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-hasproperty-p
     #[inline]
-    pub fn has_property(&self, key: &PropertyKey) -> bool {
-        let prop = self.get_own_property(key);
-        if prop.is_none() {
-            let parent = self.get_prototype_of();
-            return if let Value::Object(ref object) = parent {
-                object.has_property(key)
-            } else {
-                false
-            };
+    pub fn has_property(&self, key: &PropertyKey, context: &mut Context) -> Result<bool> {
+        if let Some(proxy) = self.as_proxy() {
+            return proxy.has(key, context);
+        }
+        if let Some(has) = self.as_module_namespace().and_then(|ns| ns.has(key)) {
+            return Ok(has);
+        }
+        if let Some(typed_array) = self.as_typed_array() {
+            if let Some(index) = canonical_numeric_index(key) {
+                return Ok(typed_array.is_valid_integer_index(index));
+            }
+        }
+
+        if self.ordinary_get_own_property(key).is_some()
+            || self.string_get_own_property(key).is_some()
+        {
+            return Ok(true);
+        }
+        if let Some(parent) = self.prototype_instance().as_object() {
+            parent.has_property(key, context)
+        } else {
+            Ok(false)
         }
-        true
     }
 
     /// Check if it is extensible.
@@ -40,8 +54,12 @@ impl GcObject {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-isextensible
     #[inline]
-    pub fn is_extensible(&self) -> bool {
-        self.borrow().extensible
+    pub fn is_extensible(&self, context: &mut Context) -> Result<bool> {
+        if let Some(proxy) = self.as_proxy() {
+            return proxy.is_extensible(context);
+        }
+
+        Ok(self.borrow().extensible)
     }
 
     /// Disable extensibility.
@@ -51,17 +69,55 @@ impl GcObject {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-preventextensions
     #[inline]
-    pub fn prevent_extensions(&mut self) -> bool {
+    pub fn prevent_extensions(&mut self, context: &mut Context) -> Result<bool> {
+        if let Some(proxy) = self.as_proxy() {
+            return proxy.prevent_extensions(context);
+        }
+
         self.borrow_mut().extensible = false;
-        true
+        Ok(true)
     }
 
     /// Delete property.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-delete-p
     #[inline]
-    pub fn delete(&mut self, key: &PropertyKey) -> bool {
-        match self.get_own_property(key) {
+    pub fn delete(&mut self, key: &PropertyKey, context: &mut Context) -> Result<bool> {
+        if let Some(proxy) = self.as_proxy() {
+            return proxy.delete(key, context);
+        }
+        if let Some(deleted) = self.as_module_namespace().and_then(|ns| ns.delete(key)) {
+            return Ok(deleted);
+        }
+        if let Some(arguments) = self.as_mapped_arguments() {
+            return Ok(arguments.delete(self, key));
+        }
+        if let Some(typed_array) = self.as_typed_array() {
+            if let Some(index) = canonical_numeric_index(key) {
+                return Ok(!typed_array.is_valid_integer_index(index));
+            }
+        }
+        if self.string_get_own_property(key).is_some() {
+            return Ok(false);
+        }
+
+        Ok(self.ordinary_delete(key))
+    }
+
+    /// Delete a property of an ordinary object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinarydelete
+    #[inline]
+    pub fn ordinary_delete(&mut self, key: &PropertyKey) -> bool {
+        match self.ordinary_get_own_property(key) {
             Some(desc) if desc.configurable() => {
-                self.remove(&key);
+                self.remove(key);
                 true
             }
             Some(_) => false,
@@ -72,10 +128,36 @@ impl GcObject {
     /// `[[Get]]`
     /// <https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-get-p-receiver>
     pub fn get(&self, key: &PropertyKey, receiver: Value, context: &mut Context) -> Result<Value> {
-        match self.get_own_property(key) {
+        if let Some(proxy) = self.as_proxy() {
+            return proxy.get(key, receiver, context);
+        }
+        if let Some(value) = self
+            .as_module_namespace()
+            .and_then(|ns| ns.get(key, context))
+        {
+            return value;
+        }
+        if let Some(value) = self
+            .as_mapped_arguments()
+            .and_then(|arguments| arguments.get(key, context))
+        {
+            return value;
+        }
+        if let Some(typed_array) = self.as_typed_array() {
+            if let Some(index) = canonical_numeric_index(key) {
+                return Ok(typed_array
+                    .integer_indexed_element_get(index)
+                    .unwrap_or_default());
+            }
+        }
+
+        match self
+            .ordinary_get_own_property(key)
+            .or_else(|| self.string_get_own_property(key))
+        {
             None => {
                 // parent will either be null or an Object
-                if let Some(parent) = self.get_prototype_of().as_object() {
+                if let Some(parent) = self.prototype_instance().as_object() {
                     Ok(parent.get(key, receiver, context)?)
                 } else {
                     Ok(Value::undefined())
@@ -102,10 +184,34 @@ impl GcObject {
     ) -> Result<bool> {
         let _timer = BoaProfiler::global().start_event("Object::set", "object");
 
+        if let Some(proxy) = self.as_proxy() {
+            return proxy.set(key, val, receiver, context);
+        }
+        if self.is_module_namespace() {
+            return Ok(false);
+        }
+        if let Some(arguments) = self.as_mapped_arguments() {
+            arguments.set(self, &key, &val, &receiver, context)?;
+        }
+        if let Some(typed_array) = self.as_typed_array() {
+            if let Some(index) = canonical_numeric_index(&key) {
+                let same_receiver = receiver
+                    .as_object()
+                    .map_or(false, |receiver| GcObject::equals(&receiver, self));
+                if same_receiver {
+                    typed_array.integer_indexed_element_set(index, &val, context)?;
+                    return Ok(true);
+                }
+            }
+        }
+
         // Fetch property key
-        let own_desc = if let Some(desc) = self.get_own_property(&key) {
+        let own_desc = if let Some(desc) = self
+            .ordinary_get_own_property(&key)
+            .or_else(|| self.string_get_own_property(&key))
+        {
             desc
-        } else if let Some(ref mut parent) = self.get_prototype_of().as_object() {
+        } else if let Some(ref mut parent) = self.prototype_instance().as_object() {
             return parent.set(key, val, receiver, context);
         } else {
             DataDescriptor::new(Value::undefined(), Attribute::all()).into()
@@ -117,7 +223,7 @@ impl GcObject {
                     return Ok(false);
                 }
                 if let Some(ref mut receiver) = receiver.as_object() {
-                    if let Some(ref existing_desc) = receiver.get_own_property(&key) {
+                    if let Some(ref existing_desc) = receiver.get_own_property(&key, context)? {
                         match existing_desc {
                             PropertyDescriptor::Accessor(_) => Ok(false),
                             PropertyDescriptor::Data(existing_data_desc) => {
@@ -166,6 +272,37 @@ impl GcObject {
     where
         K: Into<PropertyKey>,
     {
+        if let Some(proxy) = self.as_proxy() {
+            return proxy.define_own_property(key.into(), desc, context);
+        }
+        let key = key.into();
+        if let Some(defined) = self
+            .as_module_namespace()
+            .and_then(|ns| ns.define_own_property(&key, &desc, context))
+        {
+            return defined;
+        }
+        if let Some(arguments) = self.as_mapped_arguments() {
+            return arguments.define_own_property(self, key, desc, context);
+        }
+        if let Some(typed_array) = self.as_typed_array() {
+            if let Some(index) = canonical_numeric_index(&key) {
+                return typed_array.define_own_property(index, desc, context);
+            }
+        }
+        if let Some(current) = self.string_get_own_property(&key) {
+            // The code units of a string can only be "redefined" to themselves.
+            return Ok(match (&current, &desc) {
+                (PropertyDescriptor::Data(current), PropertyDescriptor::Data(desc)) => {
+                    !desc.configurable()
+                        && !desc.writable()
+                        && desc.enumerable() == current.enumerable()
+                        && same_value(&desc.value(), &current.value())
+                }
+                _ => false,
+            });
+        }
+
         if self.is_array() {
             self.array_define_own_property(key, desc, context)
         } else {
@@ -186,9 +323,9 @@ impl GcObject {
         let _timer = BoaProfiler::global().start_event("Object::define_own_property", "object");
 
         let key = key.into();
-        let extensible = self.is_extensible();
+        let extensible = self.borrow().extensible;
 
-        let current = if let Some(desc) = self.get_own_property(&key) {
+        let current = if let Some(desc) = self.ordinary_get_own_property(&key) {
             desc
         } else {
             if !extensible {
@@ -306,7 +443,8 @@ impl GcObject {
                         }
                         let mut new_len_desc =
                             PropertyDescriptor::Data(DataDescriptor::new(new_len, d.attributes()));
-                        let old_len_desc = self.get_own_property(&"length".into()).unwrap();
+                        let old_len_desc =
+                            self.ordinary_get_own_property(&"length".into()).unwrap();
                         let old_len_desc = old_len_desc.as_data_descriptor().unwrap();
                         let old_len = old_len_desc.value();
                         if new_len >= old_len.to_u32(context)? {
@@ -340,7 +478,7 @@ impl GcObject {
                             keys
                         };
                         for key in keys_to_delete.into_iter().rev() {
-                            if !self.delete(&key.into()) {
+                            if !self.ordinary_delete(&key.into()) {
                                 let mut new_len_desc_attribute = new_len_desc.attributes();
                                 if !new_writable {
                                     new_len_desc_attribute.set_writable(false);
@@ -367,7 +505,7 @@ impl GcObject {
                 Ok(true)
             }
             PropertyKey::Index(index) => {
-                let old_len_desc = self.get_own_property(&"length".into()).unwrap();
+                let old_len_desc = self.ordinary_get_own_property(&"length".into()).unwrap();
                 let old_len_data_desc = old_len_desc.as_data_descriptor().unwrap();
                 let old_len = old_len_data_desc.value().to_u32(context)?;
                 if index >= old_len && !old_len_data_desc.writable() {
@@ -390,6 +528,71 @@ impl GcObject {
         }
     }
 
+    /// Gets an own property of the object, or `None` if it does not exist.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-getownproperty-p
+    #[inline]
+    pub fn get_own_property(
+        &self,
+        key: &PropertyKey,
+        context: &mut Context,
+    ) -> Result<Option<PropertyDescriptor>> {
+        if let Some(proxy) = self.as_proxy() {
+            return proxy.get_own_property(key, context);
+        }
+        if let Some(desc) = self
+            .as_module_namespace()
+            .and_then(|ns| ns.get_own_property(key, context))
+        {
+            return desc;
+        }
+        if let Some(desc) = self
+            .as_mapped_arguments()
+            .and_then(|arguments| arguments.get_own_property(self, key, context))
+        {
+            return desc;
+        }
+        if let Some(typed_array) = self.as_typed_array() {
+            if let Some(index) = canonical_numeric_index(key) {
+                return Ok(typed_array.get_own_property(index));
+            }
+        }
+
+        Ok(self
+            .ordinary_get_own_property(key)
+            .or_else(|| self.string_get_own_property(key)))
+    }
+
+    /// Gets an own index property of a String exotic object, which is the code unit of its
+    /// string value at that index, or `None` if the object isn't a `String` object or the index
+    /// is out of bounds.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-stringgetownproperty
+    fn string_get_own_property(&self, key: &PropertyKey) -> Option<PropertyDescriptor> {
+        let index = match *key {
+            PropertyKey::Index(index) => index as usize,
+            _ => return None,
+        };
+        let string = self.borrow().as_string()?;
+        let unit = string.encode_utf16().nth(index)?;
+
+        Some(
+            DataDescriptor::new(
+                String::from_utf16_lossy(&[unit]),
+                Attribute::READONLY | Attribute::ENUMERABLE | Attribute::PERMANENT,
+            )
+            .into(),
+        )
+    }
+
+    /// Gets an own property of an ordinary object.
+    ///
     /// The specification returns a Property Descriptor or Undefined.
     ///
     /// These are 2 separate types and we can't do that here.
@@ -397,9 +600,9 @@ impl GcObject {
     /// More information:
     ///  - [ECMAScript reference][spec]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-getownproperty-p
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinarygetownproperty
     #[inline]
-    pub fn get_own_property(&self, key: &PropertyKey) -> Option<PropertyDescriptor> {
+    pub fn ordinary_get_own_property(&self, key: &PropertyKey) -> Option<PropertyDescriptor> {
         let _timer = BoaProfiler::global().start_event("Object::get_own_property", "object");
 
         let object = self.borrow();
@@ -420,7 +623,38 @@ impl GcObject {
     /// [spec]: https://tc39.es/ecma262/#table-essential-internal-methods
     #[inline]
     #[track_caller]
-    pub fn own_property_keys(&self) -> Vec<PropertyKey> {
+    pub fn own_property_keys(&self, context: &mut Context) -> Result<Vec<PropertyKey>> {
+        if let Some(proxy) = self.as_proxy() {
+            return proxy.own_property_keys(context);
+        }
+        if let Some(namespace) = self.as_module_namespace() {
+            return Ok(namespace.own_property_keys(self));
+        }
+        if let Some(typed_array) = self.as_typed_array() {
+            return Ok(typed_array.own_property_keys(self));
+        }
+        let string = self.borrow().as_string();
+        if let Some(string) = string {
+            // The indices of the code units come first, followed by the ordinary keys.
+            let length = u32::try_from(string.encode_utf16().count()).unwrap_or(u32::MAX);
+            return Ok((0..length)
+                .map(PropertyKey::from)
+                .chain(self.ordinary_own_property_keys())
+                .collect());
+        }
+
+        Ok(self.ordinary_own_property_keys())
+    }
+
+    /// Returns the own property keys of an ordinary object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinaryownpropertykeys
+    #[inline]
+    #[track_caller]
+    pub fn ordinary_own_property_keys(&self) -> Vec<PropertyKey> {
         self.borrow().keys().collect()
     }
 
@@ -433,11 +667,11 @@ impl GcObject {
     #[inline]
     pub fn define_properties(&mut self, props: Value, context: &mut Context) -> Result<()> {
         let props = &props.to_object(context)?;
-        let keys = props.own_property_keys();
+        let keys = props.own_property_keys(context)?;
         let mut descriptors: Vec<(PropertyKey, PropertyDescriptor)> = Vec::new();
 
         for next_key in keys {
-            if let Some(prop_desc) = props.get_own_property(&next_key) {
+            if let Some(prop_desc) = props.get_own_property(&next_key, context)? {
                 if prop_desc.enumerable() {
                     let desc_obj = props.get(&next_key, props.clone().into(), context)?;
                     let desc = desc_obj.to_property_descriptor(context)?;
@@ -465,29 +699,43 @@ impl GcObject {
     /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-setprototypeof-v
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/setPrototypeOf
     #[inline]
-    pub fn set_prototype_of(&mut self, val: Value) -> bool {
+    pub fn set_prototype_of(&mut self, val: Value, context: &mut Context) -> Result<bool> {
+        if let Some(proxy) = self.as_proxy() {
+            return proxy.set_prototype_of(val, context);
+        }
+        if self.is_module_namespace() {
+            return Ok(val.is_null());
+        }
+
+        Ok(self.ordinary_set_prototype_of(val))
+    }
+
+    /// Sets the prototype of an ordinary object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinarysetprototypeof
+    #[inline]
+    pub fn ordinary_set_prototype_of(&mut self, val: Value) -> bool {
         debug_assert!(val.is_object() || val.is_null());
-        let current = self.get_prototype_of();
+        let current = self.prototype_instance();
         if same_value(&current, &val) {
             return true;
         }
-        if !self.is_extensible() {
+        if !self.borrow().extensible {
             return false;
         }
         let mut p = val.clone();
-        let mut done = false;
-        while !done {
-            if p.is_null() {
-                done = true
-            } else if same_value(&Value::from(self.clone()), &p) {
+        while let Some(object) = p.as_object() {
+            if GcObject::equals(self, &object) {
                 return false;
-            } else {
-                let prototype = p
-                    .as_object()
-                    .expect("prototype should be null or object")
-                    .get_prototype_of();
-                p = prototype;
             }
+            // The prototype chain of a proxy is not known without running its traps.
+            if object.is_proxy() {
+                break;
+            }
+            p = object.prototype_instance();
         }
         self.set_prototype_instance(val);
         true
@@ -503,8 +751,12 @@ impl GcObject {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/getPrototypeOf
     #[inline]
     #[track_caller]
-    pub fn get_prototype_of(&self) -> Value {
-        self.borrow().prototype.clone()
+    pub fn get_prototype_of(&self, context: &mut Context) -> Result<Value> {
+        if let Some(proxy) = self.as_proxy() {
+            return proxy.get_prototype_of(context);
+        }
+
+        Ok(self.prototype_instance())
     }
 
     /// Helper function for property insertion.
@@ -611,6 +863,132 @@ impl GcObject {
         }
         Ok(list)
     }
+
+    /// Prevents extensions and makes all own properties non-configurable, and, for the frozen
+    /// level, makes all own data properties read-only.
+    ///
+    /// Returns `false` if extensions could not be prevented.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-setintegritylevel
+    pub fn set_integrity_level(
+        &mut self,
+        level: IntegrityLevel,
+        context: &mut Context,
+    ) -> Result<bool> {
+        if !self.prevent_extensions(context)? {
+            return Ok(false);
+        }
+
+        for key in self.own_property_keys(context)? {
+            let mut desc = match self.get_own_property(&key, context)? {
+                Some(desc) => desc,
+                None => continue,
+            };
+            match desc {
+                PropertyDescriptor::Data(ref mut data) => {
+                    data.set_configurable(false);
+                    if level == IntegrityLevel::Frozen {
+                        data.set_writable(false);
+                    }
+                }
+                PropertyDescriptor::Accessor(ref mut accessor) => accessor.set_configurable(false),
+            }
+            self.define_property_or_throw(key, desc, context)?;
+        }
+
+        Ok(true)
+    }
+
+    /// Checks if the object is not extensible and all its own properties are non-configurable,
+    /// and, for the frozen level, if all its own data properties are read-only.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-testintegritylevel
+    pub fn test_integrity_level(
+        &self,
+        level: IntegrityLevel,
+        context: &mut Context,
+    ) -> Result<bool> {
+        if self.is_extensible(context)? {
+            return Ok(false);
+        }
+
+        for key in self.own_property_keys(context)? {
+            match self.get_own_property(&key, context)? {
+                Some(desc) if desc.configurable() => return Ok(false),
+                Some(PropertyDescriptor::Data(ref data))
+                    if level == IntegrityLevel::Frozen && data.writable() =>
+                {
+                    return Ok(false)
+                }
+                _ => {}
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Returns the keys, values or `[key, value]` entries of the enumerable own string-keyed
+    /// properties of the object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-enumerableownpropertynames
+    pub fn enumerable_own_property_names(
+        &self,
+        kind: PropertyNameKind,
+        context: &mut Context,
+    ) -> Result<Vec<Value>> {
+        let mut properties = Vec::new();
+        for key in self.own_property_keys(context)? {
+            if let PropertyKey::Symbol(_) = key {
+                continue;
+            }
+            match self.get_own_property(&key, context)? {
+                Some(desc) if desc.enumerable() => {}
+                _ => continue,
+            }
+
+            // Integer keys are returned as strings, like every other string key.
+            let name = Value::from(key.to_string());
+            if kind == PropertyNameKind::Key {
+                properties.push(name);
+                continue;
+            }
+            let value = self.get(&key, self.clone().into(), context)?;
+            if kind == PropertyNameKind::Value {
+                properties.push(value);
+            } else {
+                properties.push(Array::create_array_from_list(vec![name, value], context));
+            }
+        }
+
+        Ok(properties)
+    }
+}
+
+/// The level passed to [`GcObject::set_integrity_level`] and
+/// [`GcObject::test_integrity_level`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegrityLevel {
+    /// The properties can no longer be added, removed or reconfigured.
+    Sealed,
+    /// The properties can no longer be added, removed, reconfigured or written to.
+    Frozen,
+}
+
+/// What [`GcObject::enumerable_own_property_names`] returns for each property.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PropertyNameKind {
+    Key,
+    Value,
+    KeyAndValue,
 }
 
 impl Object {
@@ -3,16 +3,29 @@
 use crate::{
     builtins::{
         array::array_iterator::ArrayIterator,
-        function::{BuiltInFunction, Function, FunctionFlags, NativeFunction},
+        array_buffer::ArrayBuffer,
+        data_view::DataView,
+        finalization_registry::FinalizationRegistry,
+        function::{
+            arguments::{Arguments, MappedArguments},
+            BoundFunction, BuiltInClosure, BuiltInFunction, Captures, ClosureFunction, Function,
+            FunctionFlags, NativeFunction,
+        },
+        generator::Generator,
         map::map_iterator::MapIterator,
         map::ordered_map::OrderedMap,
+        promise::Promise,
+        proxy::Proxy,
         set::ordered_set::OrderedSet,
         set::set_iterator::SetIterator,
         string::string_iterator::StringIterator,
+        typed_array::TypedArray,
         BigInt, Date, RegExp,
     },
     context::StandardConstructor,
-    gc::{Finalize, Trace},
+    exec::call_stack::StackTrace,
+    gc::{EphemeronOwner, Ephemerons, Finalize, Trace, WeakTarget},
+    module::ModuleNamespace,
     property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
     symbol::RcSymbol,
     value::{same_value, RcBigInt, RcString, Value},
@@ -33,7 +46,8 @@ mod internal_methods;
 mod iter;
 
 use crate::builtins::object::for_in_iterator::ForInIterator;
-pub use gcobject::{GcObject, RecursionLimiter, Ref, RefMut};
+pub use gcobject::{GcObject, RecursionLimiter, Ref, RefMut, WeakGcObject};
+pub use internal_methods::{IntegrityLevel, PropertyNameKind};
 pub use iter::*;
 
 /// Static `prototype`, usually set on constructors as a key to point to their respective prototype object.
@@ -76,27 +90,93 @@ pub struct Object {
     prototype: Value,
     /// Whether it can have new properties added to it.
     extensible: bool,
+    /// The liveness of the object, for the weak references to it.
+    weak_target: WeakTarget,
+    /// The values attached to the object by the `WeakMap`s and `WeakSet`s it is a key of.
+    ephemerons: Ephemerons<Value>,
+    /// The `[[PrivateElements]]`, defined by the classes the object is an instance of.
+    private_elements: FxHashMap<RcSymbol, PrivateElement>,
+}
+
+/// A field, method or accessor of an object, defined by a class with a private name.
+///
+/// Private elements are not properties: they can only be reached through the private names of
+/// the class, so they are invisible to reflection and to proxies.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-privateelement-specification-type
+#[derive(Debug, Clone, Trace, Finalize)]
+pub enum PrivateElement {
+    /// A private field, holding a value that can be changed.
+    Field(Value),
+    /// A private method, which cannot be assigned.
+    Method(GcObject),
+    /// A private getter and setter pair.
+    Accessor {
+        getter: Option<GcObject>,
+        setter: Option<GcObject>,
+    },
+}
+
+impl PrivateElement {
+    /// Adds the getter or the setter of another accessor to this accessor.
+    ///
+    /// Other elements are replaced, which only happens for elements of different classes.
+    pub(crate) fn merge_accessor(&mut self, other: PrivateElement) {
+        if let (
+            PrivateElement::Accessor { getter, setter },
+            PrivateElement::Accessor {
+                getter: ref other_getter,
+                setter: ref other_setter,
+            },
+        ) = (&mut *self, &other)
+        {
+            if other_getter.is_some() {
+                *getter = other_getter.clone();
+            }
+            if other_setter.is_some() {
+                *setter = other_setter.clone();
+            }
+        } else {
+            *self = other;
+        }
+    }
 }
 
 /// Defines the different types of objects.
 #[derive(Debug, Trace, Finalize)]
 pub enum ObjectData {
+    Arguments(Arguments),
     Array,
+    ArrayBuffer(ArrayBuffer),
     ArrayIterator(ArrayIterator),
+    DataView(DataView),
     Map(OrderedMap<Value, Value>),
     MapIterator(MapIterator),
+    ModuleNamespace(ModuleNamespace),
     RegExp(Box<RegExp>),
     BigInt(RcBigInt),
     Boolean(bool),
+    BoundFunction(BoundFunction),
+    FinalizationRegistry(FinalizationRegistry),
     ForInIterator(ForInIterator),
     Function(Function),
+    Generator(Generator),
     Set(OrderedSet<Value>),
     SetIterator(SetIterator),
     String(RcString),
     StringIterator(StringIterator),
     Number(f64),
+    Promise(Promise),
+    Proxy(Proxy),
     Symbol(RcSymbol),
-    Error,
+    TypedArray(TypedArray),
+    WeakMap(EphemeronOwner),
+    WeakRef(WeakGcObject),
+    WeakSet(EphemeronOwner),
+    Error(StackTrace),
     Ordinary,
     Date(Date),
     Global,
@@ -109,22 +189,35 @@ impl Display for ObjectData {
             f,
             "{}",
             match self {
+                Self::Arguments(_) => "Arguments",
                 Self::Array => "Array",
+                Self::ArrayBuffer(_) => "ArrayBuffer",
                 Self::ArrayIterator(_) => "ArrayIterator",
+                Self::BoundFunction(_) => "BoundFunction",
+                Self::DataView(_) => "DataView",
+                Self::FinalizationRegistry(_) => "FinalizationRegistry",
                 Self::ForInIterator(_) => "ForInIterator",
                 Self::Function(_) => "Function",
+                Self::Generator(_) => "Generator",
                 Self::RegExp(_) => "RegExp",
                 Self::Map(_) => "Map",
                 Self::MapIterator(_) => "MapIterator",
+                Self::ModuleNamespace(_) => "ModuleNamespace",
                 Self::Set(_) => "Set",
                 Self::SetIterator(_) => "SetIterator",
                 Self::String(_) => "String",
                 Self::StringIterator(_) => "StringIterator",
                 Self::Symbol(_) => "Symbol",
-                Self::Error => "Error",
+                Self::TypedArray(_) => "TypedArray",
+                Self::WeakMap(_) => "WeakMap",
+                Self::WeakRef(_) => "WeakRef",
+                Self::WeakSet(_) => "WeakSet",
+                Self::Error(_) => "Error",
                 Self::Ordinary => "Ordinary",
                 Self::Boolean(_) => "Boolean",
                 Self::Number(_) => "Number",
+                Self::Promise(_) => "Promise",
+                Self::Proxy(_) => "Proxy",
                 Self::BigInt(_) => "BigInt",
                 Self::Date(_) => "Date",
                 Self::Global => "Global",
@@ -145,6 +238,9 @@ impl Default for Object {
             symbol_properties: FxHashMap::default(),
             prototype: Value::null(),
             extensible: true,
+            weak_target: WeakTarget::default(),
+            ephemerons: Ephemerons::default(),
+            private_elements: FxHashMap::default(),
         }
     }
 }
@@ -167,6 +263,9 @@ impl Object {
             symbol_properties: FxHashMap::default(),
             prototype,
             extensible: true,
+            weak_target: WeakTarget::default(),
+            ephemerons: Ephemerons::default(),
+            private_elements: FxHashMap::default(),
         }
     }
 
@@ -194,6 +293,25 @@ impl Object {
             symbol_properties: FxHashMap::default(),
             prototype: Value::null(),
             extensible: true,
+            weak_target: WeakTarget::default(),
+            ephemerons: Ephemerons::default(),
+            private_elements: FxHashMap::default(),
+        }
+    }
+
+    /// Return a new module namespace object, which has no prototype and is not extensible.
+    #[inline]
+    pub fn module_namespace(namespace: ModuleNamespace) -> Self {
+        Self {
+            data: ObjectData::ModuleNamespace(namespace),
+            indexed_properties: FxHashMap::default(),
+            string_properties: FxHashMap::default(),
+            symbol_properties: FxHashMap::default(),
+            prototype: Value::null(),
+            extensible: false,
+            weak_target: WeakTarget::default(),
+            ephemerons: Ephemerons::default(),
+            private_elements: FxHashMap::default(),
         }
     }
 
@@ -207,6 +325,9 @@ impl Object {
             symbol_properties: FxHashMap::default(),
             prototype: Value::null(),
             extensible: true,
+            weak_target: WeakTarget::default(),
+            ephemerons: Ephemerons::default(),
+            private_elements: FxHashMap::default(),
         }
     }
 
@@ -223,6 +344,9 @@ impl Object {
             symbol_properties: FxHashMap::default(),
             prototype: Value::null(),
             extensible: true,
+            weak_target: WeakTarget::default(),
+            ephemerons: Ephemerons::default(),
+            private_elements: FxHashMap::default(),
         }
     }
 
@@ -236,6 +360,9 @@ impl Object {
             symbol_properties: FxHashMap::default(),
             prototype: Value::null(),
             extensible: true,
+            weak_target: WeakTarget::default(),
+            ephemerons: Ephemerons::default(),
+            private_elements: FxHashMap::default(),
         }
     }
 
@@ -252,6 +379,9 @@ impl Object {
             symbol_properties: FxHashMap::default(),
             prototype: Value::null(),
             extensible: true,
+            weak_target: WeakTarget::default(),
+            ephemerons: Ephemerons::default(),
+            private_elements: FxHashMap::default(),
         }
     }
 
@@ -263,7 +393,12 @@ impl Object {
     /// [spec]: https://tc39.es/ecma262/#sec-iscallable
     #[inline]
     pub fn is_callable(&self) -> bool {
-        matches!(self.data, ObjectData::Function(ref f) if f.is_callable())
+        match self.data {
+            ObjectData::Function(ref function) => function.is_callable(),
+            ObjectData::BoundFunction(_) => true,
+            ObjectData::Proxy(ref proxy) => proxy.is_callable(),
+            _ => false,
+        }
     }
 
     /// It determines if Object is a function object with a `[[Construct]]` internal method.
@@ -274,7 +409,12 @@ impl Object {
     /// [spec]: https://tc39.es/ecma262/#sec-isconstructor
     #[inline]
     pub fn is_constructable(&self) -> bool {
-        matches!(self.data, ObjectData::Function(ref f) if f.is_constructable())
+        match self.data {
+            ObjectData::Function(ref function) => function.is_constructable(),
+            ObjectData::BoundFunction(ref bound_function) => bound_function.is_constructable(),
+            ObjectData::Proxy(ref proxy) => proxy.is_constructable(),
+            _ => false,
+        }
     }
 
     /// Checks if it an `Array` object.
@@ -413,7 +553,10 @@ impl Object {
     /// Checks if it a `Function` object.
     #[inline]
     pub fn is_function(&self) -> bool {
-        matches!(self.data, ObjectData::Function(_))
+        matches!(
+            self.data,
+            ObjectData::Function(_) | ObjectData::BoundFunction(_)
+        )
     }
 
     #[inline]
@@ -424,6 +567,14 @@ impl Object {
         }
     }
 
+    #[inline]
+    pub fn as_function_mut(&mut self) -> Option<&mut Function> {
+        match self.data {
+            ObjectData::Function(ref mut function) => Some(function),
+            _ => None,
+        }
+    }
+
     /// Checks if it a Symbol object.
     #[inline]
     pub fn is_symbol(&self) -> bool {
@@ -441,13 +592,14 @@ impl Object {
     /// Checks if it an Error object.
     #[inline]
     pub fn is_error(&self) -> bool {
-        matches!(self.data, ObjectData::Error)
+        matches!(self.data, ObjectData::Error(_))
     }
 
+    /// Gets the stack trace captured when the error was created.
     #[inline]
-    pub fn as_error(&self) -> Option<()> {
+    pub fn as_error(&self) -> Option<&StackTrace> {
         match self.data {
-            ObjectData::Error => Some(()),
+            ObjectData::Error(ref stack_trace) => Some(stack_trace),
             _ => None,
         }
     }
@@ -480,6 +632,234 @@ impl Object {
         }
     }
 
+    /// Checks if it is a bound function exotic object.
+    #[inline]
+    pub fn is_bound_function(&self) -> bool {
+        matches!(self.data, ObjectData::BoundFunction(_))
+    }
+
+    #[inline]
+    pub fn as_bound_function(&self) -> Option<&BoundFunction> {
+        match self.data {
+            ObjectData::BoundFunction(ref bound_function) => Some(bound_function),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a `Proxy` object.
+    #[inline]
+    pub fn is_proxy(&self) -> bool {
+        matches!(self.data, ObjectData::Proxy(_))
+    }
+
+    #[inline]
+    pub fn as_proxy(&self) -> Option<&Proxy> {
+        match self.data {
+            ObjectData::Proxy(ref proxy) => Some(proxy),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a module namespace object.
+    #[inline]
+    pub fn is_module_namespace(&self) -> bool {
+        matches!(self.data, ObjectData::ModuleNamespace(_))
+    }
+
+    #[inline]
+    pub fn as_module_namespace(&self) -> Option<&ModuleNamespace> {
+        match self.data {
+            ObjectData::ModuleNamespace(ref namespace) => Some(namespace),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is an `arguments` object.
+    #[inline]
+    pub fn is_arguments(&self) -> bool {
+        matches!(self.data, ObjectData::Arguments(_))
+    }
+
+    #[inline]
+    pub fn as_mapped_arguments(&self) -> Option<&MappedArguments> {
+        match self.data {
+            ObjectData::Arguments(Arguments::Mapped(ref arguments)) => Some(arguments),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is an `ArrayBuffer` object.
+    #[inline]
+    pub fn is_array_buffer(&self) -> bool {
+        matches!(self.data, ObjectData::ArrayBuffer(_))
+    }
+
+    #[inline]
+    pub fn as_array_buffer(&self) -> Option<&ArrayBuffer> {
+        match self.data {
+            ObjectData::ArrayBuffer(ref buffer) => Some(buffer),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_array_buffer_mut(&mut self) -> Option<&mut ArrayBuffer> {
+        match &mut self.data {
+            ObjectData::ArrayBuffer(buffer) => Some(buffer),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a `DataView` object.
+    #[inline]
+    pub fn is_data_view(&self) -> bool {
+        matches!(self.data, ObjectData::DataView(_))
+    }
+
+    #[inline]
+    pub fn as_data_view(&self) -> Option<&DataView> {
+        match self.data {
+            ObjectData::DataView(ref data_view) => Some(data_view),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a typed array object.
+    #[inline]
+    pub fn is_typed_array(&self) -> bool {
+        matches!(self.data, ObjectData::TypedArray(_))
+    }
+
+    #[inline]
+    pub fn as_typed_array(&self) -> Option<&TypedArray> {
+        match self.data {
+            ObjectData::TypedArray(ref typed_array) => Some(typed_array),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a `WeakMap` object.
+    #[inline]
+    pub fn is_weak_map(&self) -> bool {
+        matches!(self.data, ObjectData::WeakMap(_))
+    }
+
+    #[inline]
+    pub fn as_weak_map(&self) -> Option<&EphemeronOwner> {
+        match self.data {
+            ObjectData::WeakMap(ref owner) => Some(owner),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a `WeakSet` object.
+    #[inline]
+    pub fn is_weak_set(&self) -> bool {
+        matches!(self.data, ObjectData::WeakSet(_))
+    }
+
+    #[inline]
+    pub fn as_weak_set(&self) -> Option<&EphemeronOwner> {
+        match self.data {
+            ObjectData::WeakSet(ref owner) => Some(owner),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a `WeakRef` object.
+    #[inline]
+    pub fn is_weak_ref(&self) -> bool {
+        matches!(self.data, ObjectData::WeakRef(_))
+    }
+
+    #[inline]
+    pub fn as_weak_ref(&self) -> Option<&WeakGcObject> {
+        match self.data {
+            ObjectData::WeakRef(ref target) => Some(target),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a `FinalizationRegistry` object.
+    #[inline]
+    pub fn is_finalization_registry(&self) -> bool {
+        matches!(self.data, ObjectData::FinalizationRegistry(_))
+    }
+
+    #[inline]
+    pub fn as_finalization_registry(&self) -> Option<&FinalizationRegistry> {
+        match self.data {
+            ObjectData::FinalizationRegistry(ref registry) => Some(registry),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_finalization_registry_mut(&mut self) -> Option<&mut FinalizationRegistry> {
+        match &mut self.data {
+            ObjectData::FinalizationRegistry(registry) => Some(registry),
+            _ => None,
+        }
+    }
+
+    /// Returns the values attached to the object by the weak collections it is a key of.
+    #[inline]
+    pub(crate) fn ephemerons(&self) -> &Ephemerons<Value> {
+        &self.ephemerons
+    }
+
+    #[inline]
+    pub(crate) fn ephemerons_mut(&mut self) -> &mut Ephemerons<Value> {
+        &mut self.ephemerons
+    }
+
+    /// Returns the elements defined on the object by classes with private names.
+    #[inline]
+    pub(crate) fn private_elements(&self) -> &FxHashMap<RcSymbol, PrivateElement> {
+        &self.private_elements
+    }
+
+    #[inline]
+    pub(crate) fn private_elements_mut(&mut self) -> &mut FxHashMap<RcSymbol, PrivateElement> {
+        &mut self.private_elements
+    }
+
+    /// Checks if it a `Promise` object.
+    #[inline]
+    pub fn is_promise(&self) -> bool {
+        matches!(self.data, ObjectData::Promise(_))
+    }
+
+    #[inline]
+    pub fn as_promise(&self) -> Option<&Promise> {
+        match self.data {
+            ObjectData::Promise(ref promise) => Some(promise),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_promise_mut(&mut self) -> Option<&mut Promise> {
+        match &mut self.data {
+            ObjectData::Promise(promise) => Some(promise),
+            _ => None,
+        }
+    }
+
+    /// Checks if it a `Generator` object.
+    #[inline]
+    pub fn is_generator(&self) -> bool {
+        matches!(self.data, ObjectData::Generator(_))
+    }
+
+    #[inline]
+    pub fn as_generator_mut(&mut self) -> Option<&mut Generator> {
+        match &mut self.data {
+            ObjectData::Generator(generator) => Some(generator),
+            _ => None,
+        }
+    }
+
     /// Checks if it a `BigInt` object.
     #[inline]
     pub fn is_bigint(&self) -> bool {
@@ -669,11 +1049,18 @@ where
     }
 }
 
+/// The native code wrapped by a `FunctionBuilder`.
+#[derive(Debug)]
+enum NativeCode {
+    Function(BuiltInFunction),
+    Closure(BuiltInClosure, Captures),
+}
+
 /// Builder for creating native function objects
 #[derive(Debug)]
 pub struct FunctionBuilder<'context> {
     context: &'context mut Context,
-    function: BuiltInFunction,
+    function: NativeCode,
     name: Option<String>,
     length: usize,
     callable: bool,
@@ -686,7 +1073,7 @@ impl<'context> FunctionBuilder<'context> {
     pub fn new(context: &'context mut Context, function: NativeFunction) -> Self {
         Self {
             context,
-            function: function.into(),
+            function: NativeCode::Function(function.into()),
             name: None,
             length: 0,
             callable: true,
@@ -694,6 +1081,36 @@ impl<'context> FunctionBuilder<'context> {
         }
     }
 
+    /// Create a new `FunctionBuilder` for a closure that carries the given captures.
+    #[inline]
+    pub fn closure(
+        context: &'context mut Context,
+        function: ClosureFunction,
+        captures: Captures,
+    ) -> Self {
+        Self {
+            context,
+            function: NativeCode::Closure(function.into(), captures),
+            name: None,
+            length: 0,
+            callable: true,
+            constructable: false,
+        }
+    }
+
+    /// Create the `Function` that is stored in the function object.
+    fn function(&self) -> Function {
+        let flags = FunctionFlags::from_parameters(self.callable, self.constructable);
+        match self.function {
+            NativeCode::Function(function) => Function::BuiltIn(function, flags),
+            NativeCode::Closure(function, ref captures) => Function::Closure {
+                function,
+                flags,
+                captures: captures.clone(),
+            },
+        }
+    }
+
     /// Specify the name property of object function object.
     ///
     /// The default is `""` (empty string).
@@ -739,10 +1156,7 @@ impl<'context> FunctionBuilder<'context> {
     #[inline]
     pub fn build(&mut self) -> GcObject {
         let mut function = Object::function(
-            Function::BuiltIn(
-                self.function,
-                FunctionFlags::from_parameters(self.callable, self.constructable),
-            ),
+            self.function(),
             self.context
                 .standard_objects()
                 .function_object()
@@ -763,10 +1177,7 @@ impl<'context> FunctionBuilder<'context> {
     /// Initializes the `Function.prototype` function object.
     pub(crate) fn build_function_prototype(&mut self, object: &GcObject) {
         let mut object = object.borrow_mut();
-        object.data = ObjectData::Function(Function::BuiltIn(
-            self.function,
-            FunctionFlags::from_parameters(self.callable, self.constructable),
-        ));
+        object.data = ObjectData::Function(self.function());
         object.set_prototype_instance(
             self.context
                 .standard_objects()
@@ -14,6 +14,6 @@ fn ordinary_has_instance_nonobject_prototype() {
 
     assert_eq!(
         &exec(scenario),
-        "\"TypeError: function has non-object prototype in instanceof check\""
+        "\"TypeError: function has non-object prototype in instanceof check at line 5, col 11\""
     );
 }
@@ -12,6 +12,7 @@ use crate::{
         object_environment_record::ObjectEnvironmentRecord,
     },
     object::{GcObject, Object, ObjectData},
+    symbol::GlobalSymbolRegistry,
     BoaProfiler,
 };
 use gc::{Gc, GcCell};
@@ -25,6 +26,7 @@ pub struct Realm {
     pub global_object: GcObject,
     pub global_env: Gc<GcCell<GlobalEnvironmentRecord>>,
     pub environment: LexicalEnvironment,
+    pub(crate) symbol_registry: GlobalSymbolRegistry,
 }
 
 impl Realm {
@@ -47,6 +49,7 @@ impl Realm {
             global_object: gc_global.clone(),
             global_env,
             environment: LexicalEnvironment::new(gc_global),
+            symbol_registry: GlobalSymbolRegistry::default(),
         }
     }
 }
@@ -70,6 +73,7 @@ fn new_global_environment(
     let dcl_rec = DeclarativeEnvironmentRecord {
         env_rec: FxHashMap::default(),
         outer_env: None,
+        var_scope: false,
     };
 
     Gc::new(GcCell::new(GlobalEnvironmentRecord {
@@ -21,6 +21,7 @@ use crate::{
     gc::{Finalize, Trace},
     value::RcString,
 };
+use rustc_hash::FxHashMap;
 use std::{
     cell::Cell,
     hash::{Hash, Hasher},
@@ -295,3 +296,34 @@ impl Hash for Symbol {
         self.hash.hash(state);
     }
 }
+
+/// The global symbol registry of a realm, which holds the symbols created by `Symbol.for`.
+///
+/// More information:
+/// - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-symbol.for
+#[derive(Debug, Default)]
+pub(crate) struct GlobalSymbolRegistry {
+    symbols: FxHashMap<RcString, RcSymbol>,
+    keys: FxHashMap<RcSymbol, RcString>,
+}
+
+impl GlobalSymbolRegistry {
+    /// Gets the symbol registered with the given key, registering a new symbol if there is none.
+    pub(crate) fn get_or_insert(&mut self, key: RcString) -> RcSymbol {
+        if let Some(symbol) = self.symbols.get(&key) {
+            return symbol.clone();
+        }
+
+        let symbol = RcSymbol::from(Symbol::new(Some(key.clone())));
+        self.keys.insert(symbol.clone(), key.clone());
+        self.symbols.insert(key, symbol.clone());
+        symbol
+    }
+
+    /// Gets the key the given symbol is registered with, if it is registered.
+    pub(crate) fn key_for(&self, symbol: &RcSymbol) -> Option<RcString> {
+        self.keys.get(symbol).cloned()
+    }
+}
@@ -1,10 +1,11 @@
 //! Array declaration node.
 
-use super::{join_nodes, Node};
+use super::{call::evaluate_arguments, join_nodes, Node};
 use crate::{
-    builtins::{iterable, Array},
+    builtins::Array,
     exec::Executable,
     gc::{Finalize, Trace},
+    syntax::ast::{node::NodeSpan, Const},
     BoaProfiler, Context, Result, Value,
 };
 use std::fmt;
@@ -12,6 +13,9 @@ use std::fmt;
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "vm")]
+use crate::vm::{compilation::CodeGen, Compiler, Instruction};
+
 /// An array is an ordered collection of data (either primitive or object depending upon the
 /// language).
 ///
@@ -31,41 +35,58 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct ArrayDecl {
-    #[cfg_attr(feature = "deser", serde(flatten))]
     arr: Box<[Node]>,
+    span: NodeSpan,
 }
 
+impl_span!(ArrayDecl);
+
 impl Executable for ArrayDecl {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("ArrayDecl", "exec");
+        // Spread elements are expanded like spread arguments, and the elements between the
+        // elisions are evaluated in groups.
+        let groups = context.resumable(|frame, context| {
+            self.as_ref()
+                .split(is_elision)
+                .map(|group| evaluate_arguments(group, frame, context))
+                .collect::<Result<Vec<_>>>()
+        })?;
+
         let array = Array::new_array(context);
-        let mut elements = Vec::new();
-        for elem in self.as_ref() {
-            if let Node::Spread(ref x) = elem {
-                let val = x.run(context)?;
-                let iterator_record = iterable::get_iterator(context, val)?;
-                // TODO after proper internal Array representation as per https://github.com/boa-dev/boa/pull/811#discussion_r502460858
-                // next_index variable should be utilized here as per https://tc39.es/ecma262/#sec-runtime-semantics-arrayaccumulation
-                // let mut next_index = 0;
-                loop {
-                    let next = iterator_record.next(context)?;
-                    if next.is_done() {
-                        break;
-                    }
-                    let next_value = next.value();
-                    //next_index += 1;
-                    elements.push(next_value.clone());
-                }
-            } else {
-                elements.push(elem.run(context)?);
+        for (i, group) in groups.iter().enumerate() {
+            if i > 0 {
+                Array::add_hole_to_array_object(&array, context)?;
             }
+            Array::add_to_array_object(&array, group, context)?;
         }
-
-        Array::add_to_array_object(&array, &elements, context)?;
         Ok(array)
     }
 }
 
+#[cfg(feature = "vm")]
+impl CodeGen for ArrayDecl {
+    fn compile(&self, compiler: &mut Compiler) {
+        let _timer = BoaProfiler::global().start_event("ArrayDecl", "codeGen");
+        compiler.add_instruction(Instruction::NewArray);
+        for (i, group) in self.as_ref().split(is_elision).enumerate() {
+            if i > 0 {
+                compiler.add_instruction(Instruction::PushHoleToArray);
+            }
+            compiler.compile_elements(group);
+        }
+    }
+}
+
+/// Returns `true` if the element is an elision, like the one of `[1, , 3]`, which leaves a hole in
+/// the array.
+///
+/// The parser represents elisions as `undefined` constants, since the `undefined` value of the
+/// source is an identifier.
+fn is_elision(element: &Node) -> bool {
+    matches!(element, Node::Const(Const::Undefined))
+}
+
 impl AsRef<[Node]> for ArrayDecl {
     fn as_ref(&self) -> &[Node] {
         &self.arr
@@ -77,7 +98,10 @@ where
     T: Into<Box<[Node]>>,
 {
     fn from(decl: T) -> Self {
-        Self { arr: decl.into() }
+        Self {
+            arr: decl.into(),
+            span: NodeSpan::default(),
+        }
     }
 }
 
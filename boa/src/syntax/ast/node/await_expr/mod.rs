@@ -1,7 +1,12 @@
 //! Await expression node.
 
 use super::Node;
-use crate::{exec::Executable, BoaProfiler, Context, Result, Value};
+use crate::{
+    builtins::promise::Promise,
+    exec::{coroutine::ResumeAction, Executable},
+    syntax::ast::node::NodeSpan,
+    BoaProfiler, Context, Result, Value,
+};
 use gc::{Finalize, Trace};
 use std::fmt;
 
@@ -21,30 +26,52 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct AwaitExpr {
     expr: Box<Node>,
+    span: NodeSpan,
 }
 
 impl Executable for AwaitExpr {
-    fn run(&self, _: &mut Context) -> Result<Value> {
+    fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("AwaitExpression", "exec");
-        // TODO: Implement AwaitExpr
-        Ok(Value::Undefined)
+        context.resumable(|frame, context| {
+            let value = frame.run(&self.expr, context)?;
+
+            // 2. Let promise be ? PromiseResolve(%Promise%, value).
+            let promise = frame.eval(context, |context| {
+                let constructor = context.standard_objects().promise_object().constructor();
+                Promise::promise_resolve(&constructor.into(), value, context)
+            })?;
+
+            // 3-9. Suspend the running function until the promise settles.
+            match context.suspend(frame, promise)? {
+                ResumeAction::Next(value) => Ok(value),
+                ResumeAction::Throw(error) => Err(error),
+                ResumeAction::Return(_) => {
+                    unreachable!("async functions cannot be resumed with a return")
+                }
+            }
+        })
     }
 }
 
 impl AwaitExpr {
     /// Implements the display formatting with indentation.
     pub(super) fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
-        writeln!(f, "await ")?;
+        f.write_str("await ")?;
         self.expr.display(f, indentation)
     }
 }
 
+impl_span!(AwaitExpr);
+
 impl<T> From<T> for AwaitExpr
 where
     T: Into<Box<Node>>,
 {
     fn from(e: T) -> Self {
-        Self { expr: e.into() }
+        Self {
+            expr: e.into(),
+            span: NodeSpan::default(),
+        }
     }
 }
 
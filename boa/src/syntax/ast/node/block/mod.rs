@@ -6,6 +6,7 @@ use crate::{
     exec::Executable,
     exec::InterpreterState,
     gc::{Finalize, Trace},
+    syntax::ast::node::NodeSpan,
     BoaProfiler, Context, Result, Value,
 };
 use std::fmt;
@@ -13,6 +14,9 @@ use std::fmt;
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "vm")]
+use crate::vm::{compilation::CodeGen, Compiler};
+
 /// A `block` statement (or compound statement in other languages) is used to group zero or
 /// more statements.
 ///
@@ -32,8 +36,9 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "deser", serde(transparent))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct Block {
-    #[cfg_attr(feature = "deser", serde(flatten))]
     statements: StatementList,
+    #[cfg_attr(feature = "deser", serde(skip))]
+    span: NodeSpan,
 }
 
 impl Block {
@@ -50,50 +55,49 @@ impl Block {
     }
 }
 
+impl_span!(Block);
+
 impl Executable for Block {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("Block", "exec");
-        {
-            let env = context.get_current_environment();
-            context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
-        }
+        context.resumable(|frame, context| {
+            let env = frame.environment(context, |context| {
+                let env = context.get_current_environment();
+                DeclarativeEnvironmentRecord::new(Some(env))
+            });
+            context.push_environment(env);
 
-        // https://tc39.es/ecma262/#sec-block-runtime-semantics-evaluation
-        // The return value is uninitialized, which means it defaults to Value::Undefined
-        let mut obj = Value::default();
-        for statement in self.items() {
-            obj = statement.run(context).map_err(|e| {
-                // No matter how control leaves the Block the LexicalEnvironment is always
-                // restored to its former state.
-                context.pop_environment();
-                e
-            })?;
-
-            match context.executor().get_current_state() {
-                InterpreterState::Return => {
-                    // Early return.
-                    break;
-                }
-                InterpreterState::Break(_label) => {
-                    // TODO, break to a label.
+            // https://tc39.es/ecma262/#sec-block-runtime-semantics-evaluation
+            // The return value is uninitialized, which means it defaults to Value::Undefined
+            let mut obj = Value::default();
+            for statement in self.items() {
+                obj = frame.run(statement, context).map_err(|e| {
+                    // No matter how control leaves the Block the LexicalEnvironment is always
+                    // restored to its former state.
+                    context.pop_environment();
+                    e
+                })?;
 
-                    // Early break.
-                    break;
-                }
-                InterpreterState::Continue(_label) => {
-                    // TODO, continue to a label
-                    break;
-                }
-                InterpreterState::Executing => {
-                    // Continue execution
+                match context.executor().get_current_state() {
+                    InterpreterState::Return => {
+                        // Early return.
+                        break;
+                    }
+                    InterpreterState::Break(_) | InterpreterState::Continue(_) => {
+                        // Early break, the enclosing loop or labelled statement handles the label.
+                        break;
+                    }
+                    InterpreterState::Executing => {
+                        // Continue execution
+                    }
                 }
             }
-        }
 
-        // pop the block env
-        let _ = context.pop_environment();
+            // pop the block env
+            let _ = context.pop_environment();
 
-        Ok(obj)
+            Ok(obj)
+        })
     }
 }
 
@@ -104,10 +108,19 @@ where
     fn from(list: T) -> Self {
         Self {
             statements: list.into(),
+            span: NodeSpan::default(),
         }
     }
 }
 
+#[cfg(feature = "vm")]
+impl CodeGen for Block {
+    fn compile(&self, compiler: &mut Compiler) {
+        let _timer = BoaProfiler::global().start_event("Block", "codeGen");
+        compiler.compile_block(self.items());
+    }
+}
+
 impl fmt::Display for Block {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.display(f, 0)
@@ -3,6 +3,7 @@ use crate::{
     exec::Executable,
     exec::InterpreterState,
     gc::{Finalize, Trace},
+    syntax::ast::node::NodeSpan,
     Context, Result, Value,
 };
 use std::fmt;
@@ -10,6 +11,9 @@ use std::fmt;
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "vm")]
+use crate::vm::{compilation::CodeGen, Compiler};
+
 #[cfg(test)]
 mod tests;
 
@@ -31,6 +35,7 @@ mod tests;
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct Break {
     label: Option<Box<str>>,
+    span: NodeSpan,
 }
 
 impl Break {
@@ -42,6 +47,7 @@ impl Break {
     {
         Self {
             label: label.into().map(L::into),
+            span: NodeSpan::default(),
         }
     }
 
@@ -51,6 +57,8 @@ impl Break {
     }
 }
 
+impl_span!(Break);
+
 impl Executable for Break {
     fn run(&self, context: &mut Context) -> Result<Value> {
         context
@@ -61,6 +69,13 @@ impl Executable for Break {
     }
 }
 
+#[cfg(feature = "vm")]
+impl CodeGen for Break {
+    fn compile(&self, compiler: &mut Compiler) {
+        compiler.compile_break(self.label());
+    }
+}
+
 impl fmt::Display for Break {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
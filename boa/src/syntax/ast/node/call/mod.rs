@@ -1,10 +1,9 @@
 use crate::{
-    builtins::iterable,
-    exec::Executable,
-    exec::InterpreterState,
+    builtins::{iterable, Eval},
+    exec::{coroutine::ResumeFrame, Executable, InterpreterState},
     gc::{Finalize, Trace},
-    syntax::ast::node::{join_nodes, Node},
-    value::{Type, Value},
+    syntax::ast::node::{join_nodes, Node, NodeSpan},
+    value::Value,
     BoaProfiler, Context, Result,
 };
 use std::fmt;
@@ -12,6 +11,9 @@ use std::fmt;
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "vm")]
+use crate::vm::{compilation::CodeGen, Compiler, Instruction};
+
 /// Calling the function actually performs the specified actions with the indicated parameters.
 ///
 /// Defining a function does not execute it. Defining it simply names the function and
@@ -31,6 +33,7 @@ use serde::{Deserialize, Serialize};
 pub struct Call {
     expr: Box<Node>,
     args: Box<[Node]>,
+    span: NodeSpan,
 }
 
 impl Call {
@@ -43,6 +46,7 @@ impl Call {
         Self {
             expr: Box::new(expr.into()),
             args: args.into(),
+            span: NodeSpan::default(),
         }
     }
 
@@ -57,65 +61,164 @@ impl Call {
     }
 }
 
+impl_span!(Call);
+
 impl Executable for Call {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("Call", "exec");
-        let (this, func) = match self.expr() {
-            Node::GetConstField(ref get_const_field) => {
-                let mut obj = get_const_field.obj().run(context)?;
-                if obj.get_type() != Type::Object {
-                    obj = Value::Object(obj.to_object(context)?);
+        context.resumable(|frame, context| {
+            let (this, func) = match self.expr() {
+                // Methods of primitives are called with the primitive itself as their `this` value.
+                Node::GetConstField(ref get_const_field) => {
+                    let obj = frame.run(get_const_field.obj(), context)?;
+                    let func = frame.eval(context, |context| {
+                        obj.to_object(context)?.get(
+                            &get_const_field.field().into(),
+                            obj.clone(),
+                            context,
+                        )
+                    })?;
+                    (obj, func)
+                }
+                Node::GetField(ref get_field) => {
+                    let obj = frame.run(get_field.obj(), context)?;
+                    let func = frame.eval(context, |context| {
+                        let field = get_field.field().run(context)?;
+                        let key = field.to_property_key(context)?;
+                        obj.to_object(context)?.get(&key, obj.clone(), context)
+                    })?;
+                    (obj, func)
+                }
+                Node::GetPrivateField(ref get_private_field) => {
+                    let obj = frame.run(get_private_field.obj(), context)?;
+                    let func =
+                        frame.eval(context, |context| get_private_field.get(&obj, context))?;
+                    (obj, func)
+                }
+                Node::GetSuperField(ref get_super_field) => {
+                    let func = frame.eval(context, |context| {
+                        let (base, key, this) = get_super_field.reference(context)?;
+                        base.to_object(context)?.get(&key, this, context)
+                    })?;
+                    (context.get_this_binding()?, func)
+                }
+                // Functions found in the object of a `with` statement are called with the object.
+                Node::Identifier(ref name) => (
+                    frame.eval(context, |context| {
+                        Ok(context
+                            .get_with_base_object(name.as_ref())?
+                            .map_or_else(Value::undefined, Value::from))
+                    })?,
+                    frame.run(self.expr(), context)?,
+                ),
+                // Functions called without a base object get `undefined` as their `this` value,
+                // which sloppy mode functions replace with the global object.
+                _ => (Value::undefined(), frame.run(self.expr(), context)?),
+            };
+            let v_args = evaluate_arguments(self.args(), frame, context)?;
+
+            // A call to the `%eval%` intrinsic through the name `eval` is a direct eval, which
+            // runs the code in the environment of the caller.
+            if let Node::Identifier(ref name) = self.expr() {
+                if name.as_ref() == "eval" && context.is_intrinsic_eval(&func) {
+                    let x = v_args.get(0).cloned().unwrap_or_default();
+                    return Eval::perform_eval(&x, true, context);
                 }
-                (
-                    obj.clone(),
-                    obj.get_field(get_const_field.field(), context)?,
-                )
             }
-            Node::GetField(ref get_field) => {
-                let mut obj = get_field.obj().run(context)?;
-                if obj.get_type() != Type::Object {
-                    obj = Value::Object(obj.to_object(context)?);
+
+            // execute the function call itself
+            let fnct_result = context.call(&func, &this, &v_args);
+
+            // unset the early return flag
+            context
+                .executor()
+                .set_current_state(InterpreterState::Executing);
+
+            fnct_result
+        })
+    }
+}
+
+/// Evaluates the arguments of a call, spreading the iterables of spread arguments.
+///
+/// The arguments evaluated before a suspension are handed back by the frame of the call.
+pub(crate) fn evaluate_arguments(
+    args: &[Node],
+    frame: &mut ResumeFrame,
+    context: &mut Context,
+) -> Result<Vec<Value>> {
+    let mut values = Vec::with_capacity(args.len());
+    for arg in args {
+        if let Node::Spread(ref spread) = arg {
+            // A spread argument is recorded as the number of values it spread, then the values.
+            if let Some(count) = frame.replay() {
+                for _ in 0..count.as_number().expect("the count must be a number") as usize {
+                    values.push(frame.replay().expect("the spread value must be recorded"));
                 }
-                let field = get_field.field().run(context)?;
-                (
-                    obj.clone(),
-                    obj.get_field(field.to_property_key(context)?, context)?,
-                )
+                continue;
             }
-            _ => (
-                // 'this' binding should come from the function's self-contained environment
-                context.global_object().into(),
-                self.expr().run(context)?,
-            ),
-        };
-        let mut v_args = Vec::with_capacity(self.args().len());
-        for arg in self.args() {
-            if let Node::Spread(ref x) = arg {
-                let val = x.run(context)?;
-                let iterator_record = iterable::get_iterator(context, val)?;
-                loop {
-                    let next = iterator_record.next(context)?;
-                    if next.is_done() {
-                        break;
-                    }
-                    let next_value = next.value();
-                    v_args.push(next_value.clone());
+            let start = values.len();
+            let iterable = spread.run(context)?;
+            let iterator_record = iterable::get_iterator(context, iterable)?;
+            loop {
+                let next = iterator_record.next(context)?;
+                if next.is_done() {
+                    break;
                 }
-                break; // after spread we don't accept any new arguments
-            } else {
-                v_args.push(arg.run(context)?);
+                values.push(next.value().clone());
             }
+            frame.save((values.len() - start).into());
+            for value in &values[start..] {
+                frame.save(value.clone());
+            }
+        } else {
+            values.push(frame.run(arg, context)?);
         }
+    }
+    Ok(values)
+}
 
-        // execute the function call itself
-        let fnct_result = context.call(&func, &this, &v_args);
-
-        // unset the early return flag
-        context
-            .executor()
-            .set_current_state(InterpreterState::Executing);
-
-        fnct_result
+#[cfg(feature = "vm")]
+impl CodeGen for Call {
+    fn compile(&self, compiler: &mut Compiler) {
+        let _timer = BoaProfiler::global().start_event("Call", "codeGen");
+        match self.expr() {
+            Node::GetConstField(ref get_const_field) => {
+                get_const_field.obj().compile(compiler);
+                compiler.add_instruction(Instruction::Dup);
+                compiler
+                    .add_name_instruction(get_const_field.field(), Instruction::GetPropertyByName);
+            }
+            Node::GetField(ref get_field) => {
+                get_field.obj().compile(compiler);
+                compiler.add_instruction(Instruction::Dup);
+                get_field.field().compile(compiler);
+                compiler.add_instruction(Instruction::GetPropertyByValue);
+            }
+            // Calls to `super` methods need the home object of the function, and private names
+            // are resolved by the interpreter.
+            Node::GetSuperField(_) | Node::GetPrivateField(_) => {
+                return compiler.add_node_instruction(&self.clone().into())
+            }
+            // Direct calls to `eval` are recognized by the interpreter.
+            Node::Identifier(ref name) if name.as_ref() == "eval" => {
+                return compiler.add_node_instruction(&self.clone().into())
+            }
+            // Functions found in the object of a `with` statement are called with the object.
+            Node::Identifier(ref name) => {
+                compiler.add_name_instruction(name.as_ref(), Instruction::GetWithBaseObject);
+                compiler.add_name_instruction(name.as_ref(), Instruction::GetName);
+            }
+            expr => {
+                compiler.add_instruction(Instruction::Undefined);
+                expr.compile(compiler);
+            }
+        }
+        if compiler.compile_arguments(self.args()) {
+            compiler.add_instruction(Instruction::CallWithArray);
+        } else {
+            compiler.add_instruction(Instruction::Call(self.args().len()));
+        }
     }
 }
 
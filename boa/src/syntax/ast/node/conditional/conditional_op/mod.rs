@@ -1,7 +1,7 @@
 use crate::{
     exec::Executable,
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::node::{Node, NodeSpan},
     Context, Result, Value,
 };
 use std::fmt;
@@ -9,6 +9,9 @@ use std::fmt;
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "vm")]
+use crate::vm::{compilation::CodeGen, Compiler, Instruction};
+
 /// The `conditional` (ternary) operator is the only JavaScript operator that takes three
 /// operands.
 ///
@@ -29,6 +32,7 @@ pub struct ConditionalOp {
     condition: Box<Node>,
     if_true: Box<Node>,
     if_false: Box<Node>,
+    span: NodeSpan,
 }
 
 impl ConditionalOp {
@@ -55,20 +59,38 @@ impl ConditionalOp {
             condition: Box::new(condition.into()),
             if_true: Box::new(if_true.into()),
             if_false: Box::new(if_false.into()),
+            span: NodeSpan::default(),
         }
     }
 }
 
+impl_span!(ConditionalOp);
+
 impl Executable for ConditionalOp {
     fn run(&self, context: &mut Context) -> Result<Value> {
-        Ok(if self.cond().run(context)?.to_boolean() {
-            self.if_true().run(context)?
-        } else {
-            self.if_false().run(context)?
+        context.resumable(|frame, context| {
+            Ok(if frame.run(self.cond(), context)?.to_boolean() {
+                self.if_true().run(context)?
+            } else {
+                self.if_false().run(context)?
+            })
         })
     }
 }
 
+#[cfg(feature = "vm")]
+impl CodeGen for ConditionalOp {
+    fn compile(&self, compiler: &mut Compiler) {
+        self.cond().compile(compiler);
+        let if_false = compiler.jump(Instruction::JumpIfFalse);
+        self.if_true().compile(compiler);
+        let end = compiler.jump(Instruction::Jump);
+        compiler.patch_jump(if_false);
+        self.if_false().compile(compiler);
+        compiler.patch_jump(end);
+    }
+}
+
 impl fmt::Display for ConditionalOp {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -1,7 +1,7 @@
 use crate::{
     exec::Executable,
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::node::{Node, NodeSpan},
     Context, Result, Value,
 };
 use std::fmt;
@@ -9,6 +9,9 @@ use std::fmt;
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "vm")]
+use crate::vm::{compilation::CodeGen, Compiler, Instruction};
+
 /// The `if` statement executes a statement if a specified condition is [`truthy`][truthy]. If
 /// the condition is [`falsy`][falsy], another statement can be executed.
 ///
@@ -31,6 +34,7 @@ pub struct If {
     cond: Box<Node>,
     body: Box<Node>,
     else_node: Option<Box<Node>>,
+    span: NodeSpan,
 }
 
 impl If {
@@ -58,6 +62,7 @@ impl If {
             cond: Box::new(condition.into()),
             body: Box::new(body.into()),
             else_node: else_node.into().map(E::into).map(Box::new),
+            span: NodeSpan::default(),
         }
     }
 
@@ -78,18 +83,39 @@ impl If {
     }
 }
 
+impl_span!(If);
+
 impl Executable for If {
     fn run(&self, context: &mut Context) -> Result<Value> {
-        Ok(if self.cond().run(context)?.to_boolean() {
-            self.body().run(context)?
-        } else if let Some(ref else_e) = self.else_node() {
-            else_e.run(context)?
-        } else {
-            Value::undefined()
+        context.resumable(|frame, context| {
+            Ok(if frame.run(self.cond(), context)?.to_boolean() {
+                self.body().run(context)?
+            } else if let Some(ref else_e) = self.else_node() {
+                else_e.run(context)?
+            } else {
+                Value::undefined()
+            })
         })
     }
 }
 
+#[cfg(feature = "vm")]
+impl CodeGen for If {
+    fn compile(&self, compiler: &mut Compiler) {
+        self.cond().compile(compiler);
+        let if_false = compiler.jump(Instruction::JumpIfFalse);
+        compiler.compile_statement(self.body());
+        if let Some(else_node) = self.else_node() {
+            let end = compiler.jump(Instruction::Jump);
+            compiler.patch_jump(if_false);
+            compiler.compile_statement(else_node);
+            compiler.patch_jump(end);
+        } else {
+            compiler.patch_jump(if_false);
+        }
+    }
+}
+
 impl fmt::Display for If {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.display(f, 0)
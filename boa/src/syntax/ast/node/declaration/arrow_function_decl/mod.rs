@@ -1,8 +1,10 @@
 use crate::{
-    builtins::function::FunctionFlags,
+    builtins::function::{set_source_text, FunctionFlags},
     exec::Executable,
     gc::{Finalize, Trace},
-    syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
+    syntax::ast::node::{
+        join_nodes, FormalParameter, FunctionSource, Node, NodeSourceText, NodeSpan, StatementList,
+    },
     Context, Result, Value,
 };
 use std::fmt;
@@ -10,6 +12,9 @@ use std::fmt;
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "vm")]
+use crate::vm::{compilation::CodeGen, Compiler};
+
 /// An arrow function expression is a syntactically compact alternative to a regular function
 /// expression.
 ///
@@ -28,6 +33,10 @@ use serde::{Deserialize, Serialize};
 pub struct ArrowFunctionDecl {
     params: Box<[FormalParameter]>,
     body: StatementList,
+    is_async: bool,
+    span: NodeSpan,
+    #[cfg_attr(feature = "deser", serde(skip))]
+    source_text: NodeSourceText,
 }
 
 impl ArrowFunctionDecl {
@@ -40,17 +49,47 @@ impl ArrowFunctionDecl {
         Self {
             params: params.into(),
             body: body.into(),
+            is_async: false,
+            span: NodeSpan::default(),
+            source_text: NodeSourceText::default(),
         }
     }
 
+    /// Creates a new async `ArrowFunctionDecl` AST node, like `async (a) => await a`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-AsyncArrowFunction
+    pub(in crate::syntax) fn new_async<P, B>(params: P, body: B) -> Self
+    where
+        P: Into<Box<[FormalParameter]>>,
+        B: Into<StatementList>,
+    {
+        let mut decl = Self::new(params, body);
+        decl.is_async = true;
+        decl
+    }
+
     /// Gets the list of parameters of the arrow function.
     pub(crate) fn params(&self) -> &[FormalParameter] {
         &self.params
     }
 
-    /// Gets the body of the arrow function.
-    pub(crate) fn body(&self) -> &[Node] {
-        &self.body.items()
+    /// Returns `true` if this is an async arrow function.
+    pub fn is_async(&self) -> bool {
+        self.is_async
+    }
+
+    /// Gets the flags of the functions created by the arrow function.
+    fn flags(&self) -> FunctionFlags {
+        // Arrow functions are not constructors
+        // see <https://tc39.es/ecma262/#sec-arrow-function-definitions-runtime-semantics-evaluation>
+        if self.is_async {
+            FunctionFlags::CALLABLE | FunctionFlags::ASYNC | FunctionFlags::LEXICAL_THIS_MODE
+        } else {
+            FunctionFlags::CALLABLE | FunctionFlags::LEXICAL_THIS_MODE
+        }
     }
 
     /// Implements the display formatting with indentation.
@@ -59,22 +98,53 @@ impl ArrowFunctionDecl {
         f: &mut fmt::Formatter<'_>,
         indentation: usize,
     ) -> fmt::Result {
+        if self.is_async {
+            f.write_str("async ")?;
+        }
         write!(f, "(")?;
         join_nodes(f, &self.params)?;
-        f.write_str(") => ")?;
-        self.body.display(f, indentation)
+        writeln!(f, ") => {{")?;
+        self.body.display(f, indentation + 1)?;
+        write!(f, "{}}}", "    ".repeat(indentation))
     }
 }
 
+impl_span!(ArrowFunctionDecl);
+
 impl Executable for ArrowFunctionDecl {
     fn run(&self, context: &mut Context) -> Result<Value> {
-        context.create_function(
-            self.params().to_vec(),
-            self.body().to_vec(),
-            FunctionFlags::CALLABLE
-                | FunctionFlags::CONSTRUCTABLE
-                | FunctionFlags::LEXICAL_THIS_MODE,
-        )
+        let val =
+            context.create_function(self.params().to_vec(), self.body.clone(), self.flags())?;
+        set_source_text(&val, self);
+
+        Ok(val)
+    }
+}
+
+#[cfg(feature = "vm")]
+impl CodeGen for ArrowFunctionDecl {
+    fn compile(&self, compiler: &mut Compiler) {
+        // Async functions suspend their execution, so they are run by the interpreter.
+        if self.is_async {
+            return compiler.add_node_instruction(&self.clone().into());
+        }
+        compiler.add_function_instruction(
+            None,
+            self.params(),
+            &self.body,
+            self.flags(),
+            &self.source_text(),
+        );
+    }
+}
+
+impl FunctionSource for ArrowFunctionDecl {
+    fn node_source_text(&self) -> &NodeSourceText {
+        &self.source_text
+    }
+
+    fn node_source_text_mut(&mut self) -> &mut NodeSourceText {
+        &mut self.source_text
     }
 }
 
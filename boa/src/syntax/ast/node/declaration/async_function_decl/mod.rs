@@ -1,8 +1,12 @@
 //! Async Function Declaration.
 
 use crate::{
+    builtins::function::{set_source_text, FunctionFlags},
+    environment::lexical_environment::VariableScope,
     exec::Executable,
-    syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
+    syntax::ast::node::{
+        join_nodes, FormalParameter, FunctionSource, Node, NodeSourceText, NodeSpan, StatementList,
+    },
     BoaProfiler, Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -25,6 +29,9 @@ pub struct AsyncFunctionDecl {
     name: Option<Box<str>>,
     parameters: Box<[FormalParameter]>,
     body: StatementList,
+    span: NodeSpan,
+    #[cfg_attr(feature = "deser", serde(skip))]
+    source_text: NodeSourceText,
 }
 
 impl AsyncFunctionDecl {
@@ -39,6 +46,8 @@ impl AsyncFunctionDecl {
             name: name.into(),
             parameters: parameters.into(),
             body: body.into(),
+            span: NodeSpan::default(),
+            source_text: NodeSourceText::default(),
         }
     }
 
@@ -72,18 +81,41 @@ impl AsyncFunctionDecl {
             }
         }
         join_nodes(f, &self.parameters)?;
-        f.write_str(") {{")?;
+        writeln!(f, ") {{")?;
 
         self.body.display(f, indentation + 1)?;
 
-        writeln!(f, "}}")
+        write!(f, "{}}}", "    ".repeat(indentation))
     }
 }
 
+impl_span!(AsyncFunctionDecl);
+
 impl Executable for AsyncFunctionDecl {
-    fn run(&self, _: &mut Context) -> Result<Value> {
+    fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("AsyncFunctionDecl", "exec");
-        // TODO: Implement AsyncFunctionDecl
+        let val = context.create_function(
+            self.parameters().to_vec(),
+            self.body.clone(),
+            FunctionFlags::CALLABLE | FunctionFlags::ASYNC,
+        )?;
+        set_source_text(&val, self);
+
+        let name = match self.name() {
+            Some(name) => name,
+            None => return Ok(val),
+        };
+
+        // Set the name and assign it in the current environment
+        val.set_field("name", name, context)?;
+
+        if context.has_var_scope_binding(name) {
+            context.set_mutable_binding(name, val, true)?;
+        } else {
+            context.create_mutable_binding(name.to_owned(), false, VariableScope::Function)?;
+
+            context.initialize_binding(name, val)?;
+        }
         Ok(Value::undefined())
     }
 }
@@ -94,6 +126,16 @@ impl From<AsyncFunctionDecl> for Node {
     }
 }
 
+impl FunctionSource for AsyncFunctionDecl {
+    fn node_source_text(&self) -> &NodeSourceText {
+        &self.source_text
+    }
+
+    fn node_source_text_mut(&mut self) -> &mut NodeSourceText {
+        &mut self.source_text
+    }
+}
+
 impl fmt::Display for AsyncFunctionDecl {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.display(f, 0)
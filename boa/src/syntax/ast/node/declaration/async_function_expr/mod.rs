@@ -1,8 +1,11 @@
 //! Async Function Expression.
 
 use crate::{
+    builtins::function::{set_source_text, FunctionFlags},
     exec::Executable,
-    syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
+    syntax::ast::node::{
+        join_nodes, FormalParameter, FunctionSource, Node, NodeSourceText, NodeSpan, StatementList,
+    },
     Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -26,6 +29,9 @@ pub struct AsyncFunctionExpr {
     name: Option<Box<str>>,
     parameters: Box<[FormalParameter]>,
     body: StatementList,
+    span: NodeSpan,
+    #[cfg_attr(feature = "deser", serde(skip))]
+    source_text: NodeSourceText,
 }
 
 impl AsyncFunctionExpr {
@@ -40,6 +46,8 @@ impl AsyncFunctionExpr {
             name: name.into(),
             parameters: parameters.into(),
             body: body.into(),
+            span: NodeSpan::default(),
+            source_text: NodeSourceText::default(),
         }
     }
 
@@ -64,24 +72,46 @@ impl AsyncFunctionExpr {
         f: &mut fmt::Formatter<'_>,
         indentation: usize,
     ) -> fmt::Result {
-        f.write_str("function")?;
+        f.write_str("async function")?;
         if let Some(ref name) = self.name {
             write!(f, " {}", name)?;
         }
         f.write_str("(")?;
         join_nodes(f, &self.parameters)?;
-        f.write_str(") {{")?;
+        writeln!(f, ") {{")?;
 
         self.body.display(f, indentation + 1)?;
 
-        writeln!(f, "}}")
+        write!(f, "{}}}", "    ".repeat(indentation))
     }
 }
 
+impl_span!(AsyncFunctionExpr);
+
 impl Executable for AsyncFunctionExpr {
-    fn run(&self, _: &mut Context) -> Result<Value> {
-        // TODO: Implement AsyncFunctionExpr
-        Ok(Value::Undefined)
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        let val = context.create_function(
+            self.parameters().to_vec(),
+            self.body.clone(),
+            FunctionFlags::CALLABLE | FunctionFlags::ASYNC,
+        )?;
+        set_source_text(&val, self);
+
+        if let Some(name) = self.name() {
+            val.set_field("name", Value::from(name), context)?;
+        }
+
+        Ok(val)
+    }
+}
+
+impl FunctionSource for AsyncFunctionExpr {
+    fn node_source_text(&self) -> &NodeSourceText {
+        &self.source_text
+    }
+
+    fn node_source_text_mut(&mut self) -> &mut NodeSourceText {
+        &mut self.source_text
     }
 }
 
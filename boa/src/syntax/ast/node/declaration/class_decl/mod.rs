@@ -0,0 +1,715 @@
+use crate::{
+    builtins::function::{
+        set_method_name, set_source_text, ClassFieldDefinition, ClassFieldName, FunctionFlags,
+    },
+    environment::{
+        declarative_environment_record::DeclarativeEnvironmentRecord,
+        lexical_environment::VariableScope,
+    },
+    exec::coroutine::ResumeFrame,
+    gc::{Finalize, Trace},
+    object::{GcObject, Object, PrivateElement, PROTOTYPE},
+    property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
+    symbol::RcSymbol,
+    syntax::ast::node::{
+        join_nodes, FormalParameter, FunctionExpr, FunctionSource, Identifier,
+        MethodDefinitionKind, Node, NodeSourceText, NodeSpan, PropertyName, Return, Spread,
+        StatementList, SuperCall,
+    },
+    BoaProfiler, Context, Result, Value,
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+/// Classes are templates for creating objects, built on top of prototypes.
+///
+/// A class has a constructor, which is called when the class is instantiated with `new`, and a
+/// list of methods, accessors and fields. A class can extend another constructor, in which case
+/// its constructor must call `super(...)` before accessing `this`.
+///
+/// The same node is used for class declarations and class expressions. Calling a class
+/// constructor without `new` throws a `TypeError`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-class-definitions
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct Class {
+    name: Option<Box<str>>,
+    super_ref: Option<Box<Node>>,
+    constructor: Option<FunctionExpr>,
+    elements: Box<[ClassElement]>,
+    span: NodeSpan,
+    #[cfg_attr(feature = "deser", serde(skip))]
+    source_text: NodeSourceText,
+}
+
+impl Class {
+    /// Creates a new class node.
+    pub(in crate::syntax) fn new<N, S, C, E>(
+        name: N,
+        super_ref: S,
+        constructor: C,
+        elements: E,
+    ) -> Self
+    where
+        N: Into<Option<Box<str>>>,
+        S: Into<Option<Node>>,
+        C: Into<Option<FunctionExpr>>,
+        E: Into<Box<[ClassElement]>>,
+    {
+        Self {
+            name: name.into(),
+            super_ref: super_ref.into().map(Box::new),
+            constructor: constructor.into(),
+            elements: elements.into(),
+            span: NodeSpan::default(),
+            source_text: NodeSourceText::default(),
+        }
+    }
+
+    /// Gets the name of the class.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_ref().map(Box::as_ref)
+    }
+
+    /// Gets the expression of the `extends` clause of the class, if any.
+    pub fn super_ref(&self) -> Option<&Node> {
+        self.super_ref.as_ref().map(Box::as_ref)
+    }
+
+    /// Gets the explicit constructor of the class, if any.
+    pub fn constructor(&self) -> Option<&FunctionExpr> {
+        self.constructor.as_ref()
+    }
+
+    /// Gets the methods, accessors and fields of the class.
+    pub fn elements(&self) -> &[ClassElement] {
+        &self.elements
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        f.write_str("class")?;
+        if let Some(ref name) = self.name {
+            write!(f, " {}", name)?;
+        }
+        if let Some(ref super_ref) = self.super_ref {
+            write!(f, " extends {}", super_ref)?;
+        }
+        f.write_str(" {\n")?;
+
+        let indent = "    ".repeat(indentation + 1);
+        if let Some(ref constructor) = self.constructor {
+            f.write_str(&indent)?;
+            display_method(f, "constructor", constructor, indentation + 1)?;
+        }
+        for element in self.elements.iter() {
+            f.write_str(&indent)?;
+            match element {
+                ClassElement::MethodDefinition(kind, name, function) => {
+                    display_method(f, &method_name(*kind, name), function, indentation + 1)?
+                }
+                ClassElement::StaticMethodDefinition(kind, name, function) => {
+                    f.write_str("static ")?;
+                    display_method(f, &method_name(*kind, name), function, indentation + 1)?
+                }
+                ClassElement::FieldDefinition(name, init) => display_field(f, name, init)?,
+                ClassElement::StaticFieldDefinition(name, init) => {
+                    f.write_str("static ")?;
+                    display_field(f, name, init)?
+                }
+            }
+        }
+
+        write!(f, "{}}}", "    ".repeat(indentation))
+    }
+
+    /// Evaluates the class definition and returns the class constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-classdefinitionevaluation
+    pub(crate) fn create(&self, context: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("Class", "exec");
+
+        context.resumable(|frame, context| {
+            // The name of the class is bound in its own scope, so that methods can refer to the
+            // class even if the outer binding is reassigned.
+            if let Some(name) = self.name() {
+                let env = frame.environment(context, |context| {
+                    let env = context.get_current_environment();
+                    DeclarativeEnvironmentRecord::new(Some(env))
+                });
+                context.push_environment(env);
+                if let Err(e) = frame.eval(context, |context| {
+                    context.create_immutable_binding(
+                        name.to_owned(),
+                        true,
+                        VariableScope::Block,
+                    )?;
+                    Ok(Value::undefined())
+                }) {
+                    context.pop_environment();
+                    return Err(e);
+                }
+            }
+
+            let result = self.define(frame, context);
+
+            if self.name().is_some() {
+                context.pop_environment();
+            }
+
+            result
+        })
+    }
+
+    /// Runs a class declaration, binding the class constructor in the current scope.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-bindingclassdeclarationevaluation
+    pub(crate) fn run_declaration(&self, context: &mut Context) -> Result<Value> {
+        let class = self.create(context)?;
+        let name = self.name().expect("class declarations must have a name");
+
+        context.create_mutable_binding(name.to_owned(), false, VariableScope::Block)?;
+        context.initialize_binding(name, class)?;
+
+        Ok(Value::undefined())
+    }
+
+    /// Creates the constructor and the prototype of the class and defines the class elements.
+    ///
+    /// Only the `extends` expression and the computed keys of the elements can suspend the
+    /// function running the class definition. The objects of the class are not reachable until
+    /// the definition completes, so they are created again when it is resumed.
+    fn define(&self, frame: &mut ResumeFrame, context: &mut Context) -> Result<Value> {
+        let function_prototype: Value = context
+            .standard_objects()
+            .function_object()
+            .prototype()
+            .into();
+        let (proto_parent, constructor_parent) = match self.super_ref() {
+            None => (
+                context
+                    .standard_objects()
+                    .object_object()
+                    .prototype()
+                    .into(),
+                function_prototype,
+            ),
+            Some(super_ref) => match frame.run(super_ref, context)? {
+                Value::Null => (Value::null(), function_prototype),
+                Value::Object(ref superclass) if superclass.is_constructable() => {
+                    let proto_parent = frame.eval(context, |context| {
+                        superclass.get(&PROTOTYPE.into(), superclass.clone().into(), context)
+                    })?;
+                    if !proto_parent.is_object() && !proto_parent.is_null() {
+                        return context.throw_type_error(
+                            "class extends value does not have a valid prototype property",
+                        );
+                    }
+                    (proto_parent, superclass.clone().into())
+                }
+                superclass => {
+                    return context.throw_type_error(format!(
+                        "class extends value {} is not a constructor or null",
+                        superclass.display()
+                    ))
+                }
+            },
+        };
+
+        // The private names of the class are bound in a new environment, which is captured by
+        // the functions of the class but not by the `extends` expression.
+        let private_names = self.private_names();
+        if private_names.is_empty() {
+            return self.define_elements(proto_parent, constructor_parent, frame, context);
+        }
+        let env = frame.environment(context, |context| {
+            let env = context.get_current_environment();
+            DeclarativeEnvironmentRecord::new(Some(env))
+        });
+        context.push_environment(env);
+        let result = frame
+            .eval(context, |context| {
+                bind_private_names(&private_names, context)?;
+                Ok(Value::undefined())
+            })
+            .and_then(|_| self.define_elements(proto_parent, constructor_parent, frame, context));
+        context.pop_environment();
+
+        result
+    }
+
+    /// Returns the private names declared by the elements of the class, in order and without
+    /// duplicates.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-privateboundidentifiers
+    fn private_names(&self) -> Vec<&str> {
+        let mut names = Vec::new();
+        for element in self.elements() {
+            let name = match element {
+                ClassElement::MethodDefinition(_, name, _)
+                | ClassElement::StaticMethodDefinition(_, name, _)
+                | ClassElement::FieldDefinition(name, _)
+                | ClassElement::StaticFieldDefinition(name, _) => name.private_name(),
+            };
+            if let Some(name) = name {
+                if !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+        names
+    }
+
+    /// Creates the constructor and the prototype of the class, once the parents of both are
+    /// known, and defines the class elements.
+    fn define_elements(
+        &self,
+        proto_parent: Value,
+        constructor_parent: Value,
+        frame: &mut ResumeFrame,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let prototype = GcObject::new(Object::create(proto_parent));
+
+        // All parts of a class are strict mode code.
+        let mut flags = FunctionFlags::CALLABLE
+            | FunctionFlags::CONSTRUCTABLE
+            | FunctionFlags::CLASS_CONSTRUCTOR
+            | FunctionFlags::STRICT;
+        let (parameters, body) = match self.constructor() {
+            Some(constructor) => (
+                constructor.parameters().to_vec(),
+                constructor.body().to_vec(),
+            ),
+            // `constructor(...args) { super(...args); }`
+            None if self.super_ref.is_some() => (
+                vec![FormalParameter::new("args", None, true)],
+                vec![SuperCall::new(vec![Spread::new(Identifier::from("args")).into()]).into()],
+            ),
+            // `constructor() {}`
+            None => (Vec::new(), Vec::new()),
+        };
+        if self.super_ref.is_some() {
+            flags |= FunctionFlags::DERIVED;
+        }
+
+        let constructor = context.create_function(parameters, body, flags)?;
+        set_source_text(&constructor, self);
+        let mut constructor_object = constructor
+            .as_object()
+            .expect("class constructor must be an object");
+        constructor_object.set_prototype_instance(constructor_parent);
+        constructor_object
+            .borrow_mut()
+            .as_function_mut()
+            .expect("class constructor must be a function")
+            .set_home_object(prototype.clone());
+
+        constructor.set_property(
+            PROTOTYPE,
+            DataDescriptor::new(
+                prototype.clone(),
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+            ),
+        );
+        Value::from(prototype.clone()).set_property(
+            "constructor",
+            DataDescriptor::new(
+                constructor.clone(),
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            ),
+        );
+        if let Some(name) = self.name() {
+            constructor.set_property(
+                "name",
+                DataDescriptor::new(
+                    name,
+                    Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                ),
+            );
+        }
+
+        let mut static_private_methods: Vec<(RcSymbol, PrivateElement)> = Vec::new();
+        let mut static_fields = Vec::new();
+        for element in self.elements() {
+            match element {
+                ClassElement::MethodDefinition(kind, PropertyName::Private(name), function) => {
+                    let method = private_method(&prototype, *kind, name, function, context)?;
+                    constructor_object
+                        .borrow_mut()
+                        .as_function_mut()
+                        .expect("class constructor must be a function")
+                        .push_private_method(context.resolve_private_identifier(name)?, method);
+                }
+                ClassElement::StaticMethodDefinition(
+                    kind,
+                    PropertyName::Private(name),
+                    function,
+                ) => {
+                    let method =
+                        private_method(&constructor_object, *kind, name, function, context)?;
+                    let name = context.resolve_private_identifier(name)?;
+                    match static_private_methods
+                        .iter_mut()
+                        .find(|(key, _)| *key == name)
+                    {
+                        Some((_, existing)) => existing.merge_accessor(method),
+                        None => static_private_methods.push((name, method)),
+                    }
+                }
+                ClassElement::MethodDefinition(kind, name, function) => {
+                    let key = element_key(name, frame, context)?;
+                    define_method(&prototype, *kind, name, key, function, context)?
+                }
+                ClassElement::StaticMethodDefinition(kind, name, function) => {
+                    let key = element_key(name, frame, context)?;
+                    define_method(&constructor_object, *kind, name, key, function, context)?
+                }
+                ClassElement::FieldDefinition(name, init) => {
+                    let name = field_name(name, frame, context)?;
+                    let field = field_definition(&prototype, name, init.as_ref(), context)?;
+                    constructor_object
+                        .borrow_mut()
+                        .as_function_mut()
+                        .expect("class constructor must be a function")
+                        .push_field(field);
+                }
+                ClassElement::StaticFieldDefinition(name, init) => {
+                    let name = field_name(name, frame, context)?;
+                    static_fields.push(field_definition(
+                        &constructor_object,
+                        name,
+                        init.as_ref(),
+                        context,
+                    )?);
+                }
+            }
+        }
+
+        if let Some(name) = self.name() {
+            context.initialize_binding(name, constructor.clone())?;
+        }
+
+        for (name, method) in static_private_methods {
+            constructor_object.private_method_or_accessor_add(name, method, context)?;
+        }
+        for field in static_fields {
+            constructor_object.define_field(&field, context)?;
+        }
+
+        Ok(constructor)
+    }
+}
+
+impl_span!(Class);
+
+/// Evaluates the key of a class element, or returns the key evaluated before the function running
+/// the class definition was suspended.
+fn element_key(
+    name: &PropertyName,
+    frame: &mut ResumeFrame,
+    context: &mut Context,
+) -> Result<PropertyKey> {
+    frame
+        .eval(context, |context| Ok(name.to_property_key(context)?.into()))?
+        .to_property_key(context)
+}
+
+/// Defines a method, getter or setter on the prototype or the constructor of a class.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-classelementevaluation
+fn define_method(
+    home_object: &GcObject,
+    kind: MethodDefinitionKind,
+    name: &PropertyName,
+    key: PropertyKey,
+    function: &FunctionExpr,
+    context: &mut Context,
+) -> Result<()> {
+    let method = create_method(home_object, kind, name, &key.to_string(), function, context)?;
+
+    let existing = home_object
+        .get_own_property(&key, context)?
+        .and_then(|desc| desc.as_accessor_descriptor().cloned());
+    let desc = match kind {
+        MethodDefinitionKind::Ordinary
+        | MethodDefinitionKind::Generator
+        | MethodDefinitionKind::Async => PropertyDescriptor::Data(DataDescriptor::new(
+            method,
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )),
+        MethodDefinitionKind::Get => PropertyDescriptor::Accessor(AccessorDescriptor::new(
+            method.as_object(),
+            existing.and_then(|desc| desc.setter().cloned()),
+            Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )),
+        MethodDefinitionKind::Set => PropertyDescriptor::Accessor(AccessorDescriptor::new(
+            existing.and_then(|desc| desc.getter().cloned()),
+            method.as_object(),
+            Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )),
+    };
+    home_object
+        .clone()
+        .define_own_property(key, desc, context)?;
+
+    Ok(())
+}
+
+/// Creates the function of a method, getter or setter of a class, named after the method.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-definemethod
+fn create_method(
+    home_object: &GcObject,
+    kind: MethodDefinitionKind,
+    name: &PropertyName,
+    key: &str,
+    function: &FunctionExpr,
+    context: &mut Context,
+) -> Result<Value> {
+    let flags = match kind {
+        MethodDefinitionKind::Generator => {
+            FunctionFlags::CALLABLE | FunctionFlags::GENERATOR | FunctionFlags::STRICT
+        }
+        MethodDefinitionKind::Async => {
+            FunctionFlags::CALLABLE | FunctionFlags::ASYNC | FunctionFlags::STRICT
+        }
+        _ => FunctionFlags::CALLABLE | FunctionFlags::STRICT,
+    };
+    let method = context.create_function(
+        function.parameters().to_vec(),
+        function.body().to_vec(),
+        flags,
+    )?;
+    {
+        let method = method.as_object().expect("method must be an object");
+        let mut method = method.borrow_mut();
+        let method = method.as_function_mut().expect("method must be a function");
+        method.set_home_object(home_object.clone());
+        let source_text = function
+            .node_source_text()
+            .get()
+            .unwrap_or_else(|| MethodSource(kind, name, function).to_string().into());
+        method.set_source_text(source_text);
+    }
+
+    set_method_name(&method, kind, key);
+
+    Ok(method)
+}
+
+/// Creates a private method, getter or setter of a class, to be added to the instances or to
+/// the constructor.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-classelementevaluation
+fn private_method(
+    home_object: &GcObject,
+    kind: MethodDefinitionKind,
+    name: &str,
+    function: &FunctionExpr,
+    context: &mut Context,
+) -> Result<PrivateElement> {
+    let private_name = PropertyName::Private(name.into());
+    let method = create_method(home_object, kind, &private_name, name, function, context)?
+        .as_object()
+        .expect("method must be an object");
+    Ok(match kind {
+        MethodDefinitionKind::Get => PrivateElement::Accessor {
+            getter: Some(method),
+            setter: None,
+        },
+        MethodDefinitionKind::Set => PrivateElement::Accessor {
+            getter: None,
+            setter: Some(method),
+        },
+        MethodDefinitionKind::Ordinary
+        | MethodDefinitionKind::Generator
+        | MethodDefinitionKind::Async => PrivateElement::Method(method),
+    })
+}
+
+/// Binds each private name of a class to a new unique symbol in the current environment.
+fn bind_private_names(names: &[&str], context: &mut Context) -> Result<()> {
+    for name in names {
+        let symbol = context.construct_symbol(Some((*name).into()));
+        context.create_immutable_binding((*name).to_owned(), false, VariableScope::Block)?;
+        context.initialize_binding(name, Value::Symbol(symbol))?;
+    }
+    Ok(())
+}
+
+/// Resolves the name of a class field, which is either a private name or a property key.
+fn field_name(
+    name: &PropertyName,
+    frame: &mut ResumeFrame,
+    context: &mut Context,
+) -> Result<ClassFieldName> {
+    Ok(match name {
+        PropertyName::Private(name) => {
+            ClassFieldName::Private(context.resolve_private_identifier(name)?)
+        }
+        name => ClassFieldName::Key(element_key(name, frame, context)?),
+    })
+}
+
+/// Creates the definition of a class field, with a method computing its initial value.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-classfielddefinitionevaluation
+fn field_definition(
+    home_object: &GcObject,
+    name: ClassFieldName,
+    init: Option<&Node>,
+    context: &mut Context,
+) -> Result<ClassFieldDefinition> {
+    let initializer = match init {
+        Some(init) => {
+            let initializer = context
+                .create_function(
+                    Vec::new(),
+                    vec![Return::new(init.clone(), None).into()],
+                    FunctionFlags::CALLABLE | FunctionFlags::STRICT,
+                )?
+                .as_object()
+                .expect("field initializer must be an object");
+            initializer
+                .borrow_mut()
+                .as_function_mut()
+                .expect("field initializer must be a function")
+                .set_home_object(home_object.clone());
+            Some(initializer)
+        }
+        None => None,
+    };
+
+    Ok(ClassFieldDefinition::new(name, initializer))
+}
+
+/// Returns the name of a method as written in the class body.
+fn method_name(kind: MethodDefinitionKind, name: &PropertyName) -> String {
+    match kind {
+        MethodDefinitionKind::Get => format!("get {}", name),
+        MethodDefinitionKind::Set => format!("set {}", name),
+        MethodDefinitionKind::Ordinary => name.to_string(),
+        MethodDefinitionKind::Generator => format!("*{}", name),
+        MethodDefinitionKind::Async => format!("async {}", name),
+    }
+}
+
+/// Displays a method of a class body.
+fn display_method(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    function: &FunctionExpr,
+    indentation: usize,
+) -> fmt::Result {
+    write!(f, "{}(", name)?;
+    join_nodes(f, function.parameters())?;
+    f.write_str(") {\n")?;
+    StatementList::from(function.body()).display(f, indentation + 1)?;
+    writeln!(f, "{}}}", "    ".repeat(indentation))
+}
+
+impl FunctionSource for Class {
+    fn node_source_text(&self) -> &NodeSourceText {
+        &self.source_text
+    }
+
+    fn node_source_text_mut(&mut self) -> &mut NodeSourceText {
+        &mut self.source_text
+    }
+}
+
+impl fmt::Display for Class {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+/// Displays a method of a class body on its own, as the source text of the method's function.
+struct MethodSource<'a>(MethodDefinitionKind, &'a PropertyName, &'a FunctionExpr);
+
+impl fmt::Display for MethodSource<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display_method(f, &method_name(self.0, self.1), self.2, 0)
+    }
+}
+
+/// Displays a field of a class body.
+fn display_field(
+    f: &mut fmt::Formatter<'_>,
+    name: &PropertyName,
+    init: &Option<Node>,
+) -> fmt::Result {
+    write!(f, "{}", name)?;
+    if let Some(init) = init {
+        write!(f, " = {}", init)?;
+    }
+    writeln!(f, ";")
+}
+
+/// An element of the body of a class.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassElement
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes#class_body_and_method_definitions
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum ClassElement {
+    /// A method, getter or setter defined on the prototype of the class.
+    MethodDefinition(MethodDefinitionKind, PropertyName, FunctionExpr),
+
+    /// A method, getter or setter defined on the class constructor.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes/static
+    StaticMethodDefinition(MethodDefinitionKind, PropertyName, FunctionExpr),
+
+    /// A field defined on every instance of the class.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes/Public_class_fields
+    FieldDefinition(PropertyName, Option<Node>),
+
+    /// A field defined on the class constructor.
+    StaticFieldDefinition(PropertyName, Option<Node>),
+}
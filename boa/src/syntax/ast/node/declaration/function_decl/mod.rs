@@ -1,9 +1,11 @@
 use crate::{
-    builtins::function::FunctionFlags,
+    builtins::function::{set_source_text, FunctionFlags},
     environment::lexical_environment::VariableScope,
     exec::Executable,
     gc::{Finalize, Trace},
-    syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
+    syntax::ast::node::{
+        join_nodes, FormalParameter, FunctionSource, Node, NodeSourceText, NodeSpan, StatementList,
+    },
     BoaProfiler, Context, Result, Value,
 };
 use std::fmt;
@@ -11,6 +13,9 @@ use std::fmt;
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "vm")]
+use crate::vm::{compilation::CodeGen, Compiler, Instruction};
+
 /// The `function` declaration (function statement) defines a function with the specified
 /// parameters.
 ///
@@ -35,6 +40,9 @@ pub struct FunctionDecl {
     name: Box<str>,
     parameters: Box<[FormalParameter]>,
     body: StatementList,
+    span: NodeSpan,
+    #[cfg_attr(feature = "deser", serde(skip))]
+    source_text: NodeSourceText,
 }
 
 impl FunctionDecl {
@@ -49,6 +57,8 @@ impl FunctionDecl {
             name: name.into(),
             parameters: parameters.into(),
             body: body.into(),
+            span: NodeSpan::default(),
+            source_text: NodeSourceText::default(),
         }
     }
 
@@ -75,27 +85,30 @@ impl FunctionDecl {
     ) -> fmt::Result {
         write!(f, "function {}(", self.name)?;
         join_nodes(f, &self.parameters)?;
-        f.write_str(") {{")?;
+        writeln!(f, ") {{")?;
 
         self.body.display(f, indentation + 1)?;
 
-        writeln!(f, "}}")
+        write!(f, "{}}}", "    ".repeat(indentation))
     }
 }
 
+impl_span!(FunctionDecl);
+
 impl Executable for FunctionDecl {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("FunctionDecl", "exec");
         let val = context.create_function(
             self.parameters().to_vec(),
-            self.body().to_vec(),
+            self.body.clone(),
             FunctionFlags::CALLABLE | FunctionFlags::CONSTRUCTABLE,
         )?;
+        set_source_text(&val, self);
 
         // Set the name and assign it in the current environment
         val.set_field("name", self.name(), context)?;
 
-        if context.has_binding(self.name()) {
+        if context.has_var_scope_binding(self.name()) {
             context.set_mutable_binding(self.name(), val, true)?;
         } else {
             context.create_mutable_binding(
@@ -110,12 +123,39 @@ impl Executable for FunctionDecl {
     }
 }
 
+#[cfg(feature = "vm")]
+impl CodeGen for FunctionDecl {
+    fn compile(&self, compiler: &mut Compiler) {
+        let _timer = BoaProfiler::global().start_event("FunctionDecl", "codeGen");
+        compiler.add_name_instruction(self.name(), Instruction::DefVar);
+        compiler.add_function_instruction(
+            Some(self.name()),
+            self.parameters(),
+            &self.body,
+            FunctionFlags::CALLABLE | FunctionFlags::CONSTRUCTABLE,
+            &self.source_text(),
+        );
+        compiler.add_name_instruction(self.name(), Instruction::SetName);
+        compiler.add_instruction(Instruction::Pop);
+    }
+}
+
 impl From<FunctionDecl> for Node {
     fn from(decl: FunctionDecl) -> Self {
         Self::FunctionDecl(decl)
     }
 }
 
+impl FunctionSource for FunctionDecl {
+    fn node_source_text(&self) -> &NodeSourceText {
+        &self.source_text
+    }
+
+    fn node_source_text_mut(&mut self) -> &mut NodeSourceText {
+        &mut self.source_text
+    }
+}
+
 impl fmt::Display for FunctionDecl {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.display(f, 0)
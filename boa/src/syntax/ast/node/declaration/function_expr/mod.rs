@@ -1,8 +1,10 @@
 use crate::{
-    builtins::function::FunctionFlags,
+    builtins::function::{set_source_text, FunctionFlags},
     exec::Executable,
     gc::{Finalize, Trace},
-    syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
+    syntax::ast::node::{
+        join_nodes, FormalParameter, FunctionSource, Node, NodeSourceText, NodeSpan, StatementList,
+    },
     Context, Result, Value,
 };
 use std::fmt;
@@ -10,6 +12,9 @@ use std::fmt;
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "vm")]
+use crate::vm::{compilation::CodeGen, Compiler};
+
 /// The `function` expression defines a function with the specified parameters.
 ///
 /// A function created with a function expression is a `Function` object and has all the
@@ -32,6 +37,9 @@ pub struct FunctionExpr {
     name: Option<Box<str>>,
     parameters: Box<[FormalParameter]>,
     body: StatementList,
+    span: NodeSpan,
+    #[cfg_attr(feature = "deser", serde(skip))]
+    source_text: NodeSourceText,
 }
 
 impl FunctionExpr {
@@ -46,6 +54,8 @@ impl FunctionExpr {
             name: name.into(),
             parameters: parameters.into(),
             body: body.into(),
+            span: NodeSpan::default(),
+            source_text: NodeSourceText::default(),
         }
     }
 
@@ -64,6 +74,11 @@ impl FunctionExpr {
         self.body.items()
     }
 
+    /// Returns `true` if the function is strict mode code.
+    pub fn strict(&self) -> bool {
+        self.body.strict()
+    }
+
     /// Implements the display formatting with indentation.
     pub(in crate::syntax::ast::node) fn display(
         &self,
@@ -76,21 +91,24 @@ impl FunctionExpr {
         }
         f.write_str("(")?;
         join_nodes(f, &self.parameters)?;
-        f.write_str(") {{")?;
+        writeln!(f, ") {{")?;
 
         self.body.display(f, indentation + 1)?;
 
-        writeln!(f, "}}")
+        write!(f, "{}}}", "    ".repeat(indentation))
     }
 }
 
+impl_span!(FunctionExpr);
+
 impl Executable for FunctionExpr {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let val = context.create_function(
             self.parameters().to_vec(),
-            self.body().to_vec(),
+            self.body.clone(),
             FunctionFlags::CALLABLE | FunctionFlags::CONSTRUCTABLE,
         )?;
+        set_source_text(&val, self);
 
         if let Some(name) = self.name() {
             val.set_field("name", Value::from(name), context)?;
@@ -100,6 +118,41 @@ impl Executable for FunctionExpr {
     }
 }
 
+#[cfg(feature = "vm")]
+impl CodeGen for FunctionExpr {
+    fn compile(&self, compiler: &mut Compiler) {
+        self.compile_with_flags(
+            FunctionFlags::CALLABLE | FunctionFlags::CONSTRUCTABLE,
+            compiler,
+        );
+    }
+}
+
+#[cfg(feature = "vm")]
+impl FunctionExpr {
+    /// Compiles the function expression into a function with the given flags, like the flags of
+    /// methods, which are not constructors.
+    pub(crate) fn compile_with_flags(&self, flags: FunctionFlags, compiler: &mut Compiler) {
+        compiler.add_function_instruction(
+            self.name(),
+            self.parameters(),
+            &self.body,
+            flags,
+            &self.source_text(),
+        );
+    }
+}
+
+impl FunctionSource for FunctionExpr {
+    fn node_source_text(&self) -> &NodeSourceText {
+        &self.source_text
+    }
+
+    fn node_source_text_mut(&mut self) -> &mut NodeSourceText {
+        &mut self.source_text
+    }
+}
+
 impl fmt::Display for FunctionExpr {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.display(f, 0)
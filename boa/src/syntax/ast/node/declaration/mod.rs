@@ -1,9 +1,11 @@
 //! Declaration nodes
 use crate::{
-    environment::lexical_environment::VariableScope,
     exec::Executable,
     gc::{Finalize, Trace},
-    syntax::ast::node::{join_nodes, Identifier, Node},
+    syntax::ast::{
+        node::{join_nodes, Node, NodeSpan},
+        Span,
+    },
     Context, Result, Value,
 };
 use std::fmt;
@@ -11,18 +13,36 @@ use std::fmt;
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "vm")]
+use crate::vm::{compilation::CodeGen, Compiler, Instruction};
+
 pub mod arrow_function_decl;
 pub mod async_function_decl;
 pub mod async_function_expr;
+pub mod class_decl;
 pub mod function_decl;
 pub mod function_expr;
+pub mod generator_decl;
+pub mod generator_expr;
+pub mod pattern;
 
 pub use self::{
-    arrow_function_decl::ArrowFunctionDecl, async_function_decl::AsyncFunctionDecl,
-    async_function_expr::AsyncFunctionExpr, function_decl::FunctionDecl,
+    arrow_function_decl::ArrowFunctionDecl,
+    async_function_decl::AsyncFunctionDecl,
+    async_function_expr::AsyncFunctionExpr,
+    class_decl::{Class, ClassElement},
+    function_decl::FunctionDecl,
     function_expr::FunctionExpr,
+    generator_decl::GeneratorDecl,
+    generator_expr::GeneratorExpr,
+    pattern::{
+        ArrayPattern, BindingElement, BindingProperty, BindingTarget, DeclarationPattern,
+        ObjectPattern,
+    },
 };
 
+pub(crate) use self::pattern::BindingKind;
+
 #[cfg(test)]
 mod tests;
 
@@ -91,45 +111,97 @@ pub enum DeclarationList {
 
 impl Executable for DeclarationList {
     fn run(&self, context: &mut Context) -> Result<Value> {
-        for decl in self.as_ref() {
-            use DeclarationList::*;
-            let val = match decl.init() {
-                None if self.is_const() => {
-                    return context.throw_syntax_error("missing = in const declaration")
-                }
-                Some(init) => init.run(context)?,
-                None => Value::undefined(),
-            };
+        context.resumable(|frame, context| {
+            for decl in self.as_ref() {
+                use DeclarationList::*;
+                let val = match decl.init() {
+                    None if self.is_const() => {
+                        return context.throw_syntax_error("missing = in const declaration")
+                    }
+                    None if decl.pattern().is_some() => {
+                        return context.throw_syntax_error("missing = in destructuring declaration")
+                    }
+                    Some(init) => frame.run(init, context)?,
+                    None => Value::undefined(),
+                };
 
-            if self.is_var() && context.has_binding(decl.name()) {
-                if decl.init().is_some() {
-                    context.set_mutable_binding(decl.name(), val, true)?;
-                }
-                continue;
-            }
+                frame.eval(context, |context| {
+                    if let Some(name) = decl.name() {
+                        if self.is_var() && context.has_var_scope_binding(name) {
+                            if decl.init().is_some() {
+                                let strict = context.strict();
+                                context.set_mutable_binding(name, val, strict)?;
+                            }
+                            return Ok(Value::undefined());
+                        }
+                    }
 
-            match &self {
-                Const(_) => context.create_immutable_binding(
-                    decl.name().to_owned(),
-                    false,
-                    VariableScope::Block,
-                )?,
-                Let(_) => context.create_mutable_binding(
-                    decl.name().to_owned(),
-                    false,
-                    VariableScope::Block,
-                )?,
-                Var(_) => context.create_mutable_binding(
-                    decl.name().to_owned(),
-                    false,
-                    VariableScope::Function,
-                )?,
+                    let kind = match &self {
+                        Const(_) => BindingKind::Const,
+                        Let(_) => BindingKind::Let,
+                        Var(_) => BindingKind::Var,
+                    };
+                    decl.target().bind(val, kind, context)?;
+                    Ok(Value::undefined())
+                })?;
             }
 
-            context.initialize_binding(decl.name(), val)?;
+            Ok(Value::undefined())
+        })
+    }
+}
+
+#[cfg(feature = "vm")]
+impl CodeGen for DeclarationList {
+    fn compile(&self, compiler: &mut Compiler) {
+        // Missing initializers throw the interpreter's error.
+        let is_invalid = self
+            .as_ref()
+            .iter()
+            .any(|decl| decl.init().is_none() && (self.is_const() || decl.pattern().is_some()));
+        if is_invalid {
+            return compiler.add_node_instruction(&self.clone().into());
         }
 
-        Ok(Value::undefined())
+        for decl in self.as_ref() {
+            match decl.target() {
+                BindingTarget::Identifier(name) if self.is_var() => {
+                    compiler.add_name_instruction(name.as_ref(), Instruction::DefVar);
+                    if let Some(init) = decl.init() {
+                        init.compile(compiler);
+                        compiler.add_name_instruction(name.as_ref(), Instruction::SetName);
+                        compiler.add_instruction(Instruction::Pop);
+                    }
+                }
+                BindingTarget::Identifier(name) => {
+                    match decl.init() {
+                        Some(init) => init.compile(compiler),
+                        None => compiler.add_instruction(Instruction::Undefined),
+                    }
+                    if self.is_const() {
+                        compiler.add_name_instruction(name.as_ref(), Instruction::DefConst);
+                    } else {
+                        compiler.add_name_instruction(name.as_ref(), Instruction::DefLet);
+                    }
+                    compiler.add_name_instruction(name.as_ref(), Instruction::InitLexical);
+                    compiler.add_instruction(Instruction::Pop);
+                }
+                BindingTarget::Pattern(pattern) => {
+                    if let Some(init) = decl.init() {
+                        init.compile(compiler);
+                    }
+                    let instr = match self {
+                        DeclarationList::Const(_) => Instruction::BindConst,
+                        DeclarationList::Let(_) => Instruction::BindLet,
+                        DeclarationList::Var(_) => Instruction::BindVar,
+                    };
+                    compiler.add_pattern_instruction(pattern, instr);
+                }
+                BindingTarget::Property(_) => {
+                    unreachable!("property references are only assignment targets")
+                }
+            }
+        }
     }
 }
 
@@ -144,6 +216,13 @@ impl DeclarationList {
     pub(in crate::syntax) fn is_var(&self) -> bool {
         matches!(self, Self::Var(_))
     }
+
+    /// Gets the span of the declarations in the source code, if they were parsed.
+    pub fn span(&self) -> Option<Span> {
+        let start = self.as_ref().first()?.span()?.start();
+        let end = self.as_ref().last()?.span()?.end();
+        Some(Span::new(start, end))
+    }
 }
 
 impl AsRef<[Declaration]> for DeclarationList {
@@ -192,13 +271,14 @@ impl From<Declaration> for Box<[Declaration]> {
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct Declaration {
-    name: Identifier,
+    target: BindingTarget,
     init: Option<Node>,
+    span: NodeSpan,
 }
 
 impl fmt::Display for Declaration {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Display::fmt(&self.name, f)?;
+        fmt::Display::fmt(&self.target, f)?;
         if let Some(ref init) = self.init {
             write!(f, " = {}", init)?;
         }
@@ -207,21 +287,43 @@ impl fmt::Display for Declaration {
 }
 
 impl Declaration {
-    /// Creates a new variable declaration.
-    pub(in crate::syntax) fn new<N, I>(name: N, init: I) -> Self
+    /// Creates a new variable declaration, binding either a name or a destructuring pattern.
+    pub(in crate::syntax) fn new<T, I>(target: T, init: I) -> Self
     where
-        N: Into<Identifier>,
+        T: Into<BindingTarget>,
         I: Into<Option<Node>>,
     {
         Self {
-            name: name.into(),
+            target: target.into(),
             init: init.into(),
+            span: NodeSpan::default(),
+        }
+    }
+
+    /// Gets the target of the declaration.
+    pub fn target(&self) -> &BindingTarget {
+        &self.target
+    }
+
+    /// Gets the name of the variable, if the declaration binds a single name.
+    pub fn name(&self) -> Option<&str> {
+        match &self.target {
+            BindingTarget::Identifier(ident) => Some(ident.as_ref()),
+            _ => None,
         }
     }
 
-    /// Gets the name of the variable.
-    pub fn name(&self) -> &str {
-        self.name.as_ref()
+    /// Gets the destructuring pattern of the declaration, if any.
+    pub fn pattern(&self) -> Option<&DeclarationPattern> {
+        match &self.target {
+            BindingTarget::Pattern(pattern) => Some(pattern),
+            _ => None,
+        }
+    }
+
+    /// Gets the names of all the variables declared.
+    pub fn names(&self) -> Vec<&str> {
+        self.target.names()
     }
 
     /// Gets the initialization node for the variable, if any.
@@ -229,3 +331,5 @@ impl Declaration {
         self.init.as_ref()
     }
 }
+
+impl_span!(Declaration);
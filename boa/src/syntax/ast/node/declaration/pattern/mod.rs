@@ -0,0 +1,668 @@
+//! Destructuring pattern nodes.
+
+use crate::{
+    builtins::{
+        iterable::{get_iterator, IteratorRecord},
+        Array,
+    },
+    environment::lexical_environment::VariableScope,
+    exec::coroutine::ResumeFrame,
+    gc::{Finalize, Trace},
+    syntax::ast::{
+        node::{Assign, Identifier, Node, NodeSpan, PropertyName},
+        Span,
+    },
+    Context, Result, Value,
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+/// A destructuring pattern unpacks the values of an array, or the properties of an object,
+/// into distinct bindings.
+///
+/// Patterns can appear in variable declarations, function parameters, `catch` clauses and the
+/// heads of `for...of` loops, where they declare new bindings, and on the left hand side of an
+/// assignment, where they assign to existing references.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-BindingPattern
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Destructuring_assignment
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum DeclarationPattern {
+    /// An object pattern, like `{ a, b: c = 1, ...rest }`.
+    Object(ObjectPattern),
+
+    /// An array pattern, like `[a, , b = 1, ...rest]`.
+    Array(ArrayPattern),
+}
+
+impl DeclarationPattern {
+    /// Gets the span of the object or array pattern.
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            Self::Object(pattern) => pattern.span(),
+            Self::Array(pattern) => pattern.span(),
+        }
+    }
+
+    /// Sets the span of the object or array pattern.
+    pub(in crate::syntax) fn set_span(&mut self, span: Span) {
+        match self {
+            Self::Object(pattern) => pattern.set_span(span),
+            Self::Array(pattern) => pattern.set_span(span),
+        }
+    }
+
+    /// Gets the names of all the bindings created by the pattern.
+    pub fn names(&self) -> Vec<&str> {
+        let mut names = Vec::new();
+        self.collect_names(&mut names);
+        names
+    }
+
+    fn collect_names<'a>(&'a self, names: &mut Vec<&'a str>) {
+        match self {
+            Self::Object(pattern) => {
+                for property in pattern.properties() {
+                    property.element().target().collect_names(names);
+                }
+                if let Some(rest) = pattern.rest() {
+                    rest.collect_names(names);
+                }
+            }
+            Self::Array(pattern) => {
+                for element in pattern.elements().iter().flatten() {
+                    element.target().collect_names(names);
+                }
+                if let Some(rest) = pattern.rest() {
+                    rest.collect_names(names);
+                }
+            }
+        }
+    }
+
+    /// Destructures `value` into the bindings of the pattern.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-bindinginitialization
+    pub(crate) fn bind(
+        &self,
+        value: Value,
+        kind: BindingKind,
+        context: &mut Context,
+    ) -> Result<()> {
+        match self {
+            Self::Object(pattern) => pattern.bind(value, kind, context),
+            Self::Array(pattern) => pattern.bind(value, kind, context),
+        }
+    }
+}
+
+impl fmt::Display for DeclarationPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Object(pattern) => fmt::Display::fmt(pattern, f),
+            Self::Array(pattern) => fmt::Display::fmt(pattern, f),
+        }
+    }
+}
+
+impl From<ObjectPattern> for DeclarationPattern {
+    fn from(pattern: ObjectPattern) -> Self {
+        Self::Object(pattern)
+    }
+}
+
+impl From<ArrayPattern> for DeclarationPattern {
+    fn from(pattern: ArrayPattern) -> Self {
+        Self::Array(pattern)
+    }
+}
+
+impl From<DeclarationPattern> for Node {
+    fn from(pattern: DeclarationPattern) -> Self {
+        Self::DeclarationPattern(pattern)
+    }
+}
+
+/// An object destructuring pattern.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ObjectBindingPattern
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ObjectPattern {
+    properties: Box<[BindingProperty]>,
+    rest: Option<Box<BindingTarget>>,
+    span: NodeSpan,
+}
+
+impl ObjectPattern {
+    /// Creates a new object pattern.
+    pub(in crate::syntax) fn new<P>(properties: P, rest: Option<BindingTarget>) -> Self
+    where
+        P: Into<Box<[BindingProperty]>>,
+    {
+        Self {
+            properties: properties.into(),
+            rest: rest.map(Box::new),
+            span: NodeSpan::default(),
+        }
+    }
+
+    /// Gets the properties unpacked by the pattern.
+    pub fn properties(&self) -> &[BindingProperty] {
+        &self.properties
+    }
+
+    /// Gets the target that receives the remaining properties, if any.
+    pub fn rest(&self) -> Option<&BindingTarget> {
+        self.rest.as_deref()
+    }
+
+    fn bind(&self, value: Value, kind: BindingKind, context: &mut Context) -> Result<()> {
+        if value.is_null_or_undefined() {
+            return Err(
+                context.construct_type_error(format!("{} cannot be destructured", value.display()))
+            );
+        }
+
+        context.resumable(|frame, context| {
+            let object = frame.eval(context, |context| Ok(value.to_object(context)?.into()))?;
+            let object = object
+                .as_object()
+                .expect("the destructured value must be an object");
+
+            let mut excluded = Vec::with_capacity(self.properties.len());
+            for property in self.properties() {
+                let key = frame
+                    .eval(context, |context| {
+                        Ok(property.key().to_property_key(context)?.into())
+                    })?
+                    .to_property_key(context)?;
+                let property_value =
+                    frame.eval(context, |context| object.get(&key, value.clone(), context))?;
+                frame.eval(context, |context| {
+                    property.element().bind(property_value, kind, context)?;
+                    Ok(Value::undefined())
+                })?;
+                excluded.push(key);
+            }
+
+            if let Some(rest) = self.rest() {
+                let rest_object = frame.eval(context, |context| {
+                    let rest_object = Value::new_object(context);
+                    for key in object.own_property_keys(context)? {
+                        if excluded.contains(&key) {
+                            continue;
+                        }
+                        let enumerable = object
+                            .get_own_property(&key, context)?
+                            .map_or(false, |desc| desc.enumerable());
+                        if enumerable {
+                            let property_value = object.get(&key, value.clone(), context)?;
+                            rest_object.set_field(key, property_value, context)?;
+                        }
+                    }
+                    Ok(rest_object)
+                })?;
+                rest.bind(rest_object, kind, context)?;
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl_span!(ObjectPattern);
+
+impl fmt::Display for ObjectPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("{")?;
+        let mut first = true;
+        for property in self.properties() {
+            if !first {
+                f.write_str(",")?;
+            }
+            first = false;
+            write!(f, " {}", property)?;
+        }
+        if let Some(rest) = self.rest() {
+            if !first {
+                f.write_str(",")?;
+            }
+            first = false;
+            write!(f, " ...{}", rest)?;
+        }
+        if !first {
+            f.write_str(" ")?;
+        }
+        f.write_str("}")
+    }
+}
+
+/// A single property of an object destructuring pattern, like `a`, `a = 1` or `a: [b, c]`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-BindingProperty
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct BindingProperty {
+    key: PropertyName,
+    element: BindingElement,
+}
+
+impl BindingProperty {
+    /// Creates a new binding property.
+    pub(in crate::syntax) fn new<K>(key: K, element: BindingElement) -> Self
+    where
+        K: Into<PropertyName>,
+    {
+        Self {
+            key: key.into(),
+            element,
+        }
+    }
+
+    /// Gets the key of the property.
+    pub fn key(&self) -> &PropertyName {
+        &self.key
+    }
+
+    /// Gets the element the property value is bound to.
+    pub fn element(&self) -> &BindingElement {
+        &self.element
+    }
+}
+
+impl fmt::Display for BindingProperty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.key, self.element.target()) {
+            (PropertyName::Literal(key), BindingTarget::Identifier(ident))
+                if key.as_ref() == ident.as_ref() =>
+            {
+                fmt::Display::fmt(&self.element, f)
+            }
+            _ => write!(f, "{}: {}", self.key, self.element),
+        }
+    }
+}
+
+/// An array destructuring pattern.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ArrayBindingPattern
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ArrayPattern {
+    elements: Box<[Option<BindingElement>]>,
+    rest: Option<Box<BindingTarget>>,
+    span: NodeSpan,
+}
+
+impl ArrayPattern {
+    /// Creates a new array pattern.
+    ///
+    /// Elisions, like the hole in `[a, , b]`, are represented by `None`.
+    pub(in crate::syntax) fn new<E>(elements: E, rest: Option<BindingTarget>) -> Self
+    where
+        E: Into<Box<[Option<BindingElement>]>>,
+    {
+        Self {
+            elements: elements.into(),
+            rest: rest.map(Box::new),
+            span: NodeSpan::default(),
+        }
+    }
+
+    /// Gets the elements of the pattern, with `None` for elisions.
+    pub fn elements(&self) -> &[Option<BindingElement>] {
+        &self.elements
+    }
+
+    /// Gets the target that receives the remaining elements, if any.
+    pub fn rest(&self) -> Option<&BindingTarget> {
+        self.rest.as_deref()
+    }
+
+    fn bind(&self, value: Value, kind: BindingKind, context: &mut Context) -> Result<()> {
+        context.resumable(|frame, context| {
+            let iterator = frame.iterator(context, |context| get_iterator(context, value))?;
+            let mut done = false;
+
+            let result = self.bind_elements(&iterator, &mut done, kind, frame, context);
+
+            // The iterator is closed unless it was exhausted, or it threw an error itself. It
+            // stays open while the function is suspended.
+            if done || context.is_suspending() {
+                result
+            } else {
+                iterator
+                    .close(result.map(|_| Value::undefined()), context)
+                    .map(|_| ())
+            }
+        })
+    }
+
+    fn bind_elements(
+        &self,
+        iterator: &IteratorRecord,
+        done: &mut bool,
+        kind: BindingKind,
+        frame: &mut ResumeFrame,
+        context: &mut Context,
+    ) -> Result<()> {
+        for element in self.elements() {
+            let value = if let Some(value) = frame.replay() {
+                *done = frame
+                    .replay()
+                    .expect("the state of the iterator must be recorded")
+                    .to_boolean();
+                value
+            } else {
+                let value = step(iterator, done, context)?.unwrap_or_default();
+                frame.save(value.clone());
+                frame.save((*done).into());
+                value
+            };
+            if let Some(element) = element {
+                frame.eval(context, |context| {
+                    element.bind(value, kind, context)?;
+                    Ok(Value::undefined())
+                })?;
+            }
+        }
+
+        if let Some(rest) = self.rest() {
+            let array = frame.eval(context, |context| {
+                let mut values = Vec::new();
+                while let Some(value) = step(iterator, done, context)? {
+                    values.push(value);
+                }
+                let array = Array::new_array(context);
+                Array::add_to_array_object(&array, &values, context)?;
+                Ok(array)
+            })?;
+            // The rest of the iterator was exhausted before the function was suspended.
+            *done = true;
+            rest.bind(array, kind, context)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl_span!(ArrayPattern);
+
+/// Gets the next value of an iterator that is being destructured.
+///
+/// Returns `None` once the iterator is exhausted, and marks it as done if it is exhausted or
+/// throws.
+fn step(
+    iterator: &IteratorRecord,
+    done: &mut bool,
+    context: &mut Context,
+) -> Result<Option<Value>> {
+    if *done {
+        return Ok(None);
+    }
+    match iterator.next(context) {
+        Ok(result) if result.is_done() => {
+            *done = true;
+            Ok(None)
+        }
+        Ok(result) => Ok(Some(result.value())),
+        Err(error) => {
+            *done = true;
+            Err(error)
+        }
+    }
+}
+
+impl fmt::Display for ArrayPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+        let mut first = true;
+        for element in self.elements() {
+            if !first {
+                f.write_str(", ")?;
+            }
+            first = false;
+            if let Some(element) = element {
+                fmt::Display::fmt(element, f)?;
+            }
+        }
+        if let Some(rest) = self.rest() {
+            if !first {
+                f.write_str(", ")?;
+            }
+            write!(f, "...{}", rest)?;
+        } else if matches!(self.elements.last(), Some(None)) {
+            // A trailing elision needs an extra comma to be preserved.
+            f.write_str(",")?;
+        }
+        f.write_str("]")
+    }
+}
+
+/// An element of a destructuring pattern: a target, with an optional default value that is used
+/// when the destructured value is `undefined`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-BindingElement
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct BindingElement {
+    target: BindingTarget,
+    init: Option<Node>,
+}
+
+impl BindingElement {
+    /// Creates a new binding element.
+    pub(in crate::syntax) fn new<T>(target: T, init: Option<Node>) -> Self
+    where
+        T: Into<BindingTarget>,
+    {
+        Self {
+            target: target.into(),
+            init,
+        }
+    }
+
+    /// Gets the target of the element.
+    pub fn target(&self) -> &BindingTarget {
+        &self.target
+    }
+
+    /// Gets the default value of the element, if any.
+    pub fn init(&self) -> Option<&Node> {
+        self.init.as_ref()
+    }
+
+    /// Binds `value` to the target of the element, using the default value if `value` is
+    /// `undefined`.
+    pub(crate) fn bind(
+        &self,
+        value: Value,
+        kind: BindingKind,
+        context: &mut Context,
+    ) -> Result<()> {
+        match self.init() {
+            Some(init) if value.is_undefined() => context.resumable(|frame, context| {
+                let value = frame.run(init, context)?;
+                self.target.bind(value, kind, context)
+            }),
+            _ => self.target.bind(value, kind, context),
+        }
+    }
+}
+
+impl fmt::Display for BindingElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.target, f)?;
+        if let Some(ref init) = self.init {
+            write!(f, " = {}", init)?;
+        }
+        Ok(())
+    }
+}
+
+/// The target of a binding: a name, or a nested pattern.
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum BindingTarget {
+    /// A single name, like `a`.
+    Identifier(Identifier),
+
+    /// A nested destructuring pattern.
+    Pattern(DeclarationPattern),
+
+    /// A property reference, like `obj.a` or `obj[key]`.
+    ///
+    /// These targets are only valid in destructuring assignments.
+    Property(Box<Node>),
+}
+
+impl BindingTarget {
+    /// Gets the names of all the bindings created by the target.
+    pub fn names(&self) -> Vec<&str> {
+        let mut names = Vec::new();
+        self.collect_names(&mut names);
+        names
+    }
+
+    fn collect_names<'a>(&'a self, names: &mut Vec<&'a str>) {
+        match self {
+            Self::Identifier(ident) => names.push(ident.as_ref()),
+            Self::Pattern(pattern) => pattern.collect_names(names),
+            Self::Property(_) => {}
+        }
+    }
+
+    /// Binds `value` to the target.
+    pub(crate) fn bind(
+        &self,
+        value: Value,
+        kind: BindingKind,
+        context: &mut Context,
+    ) -> Result<()> {
+        match self {
+            Self::Identifier(ident) => kind.initialize(ident.as_ref(), value, context),
+            Self::Pattern(pattern) => pattern.bind(value, kind, context),
+            Self::Property(reference) => Assign::assign_to(reference, value, context),
+        }
+    }
+}
+
+impl fmt::Display for BindingTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Identifier(ident) => fmt::Display::fmt(ident, f),
+            Self::Pattern(pattern) => fmt::Display::fmt(pattern, f),
+            Self::Property(reference) => fmt::Display::fmt(reference, f),
+        }
+    }
+}
+
+impl From<&str> for BindingTarget {
+    fn from(name: &str) -> Self {
+        Self::Identifier(name.into())
+    }
+}
+
+impl From<Box<str>> for BindingTarget {
+    fn from(name: Box<str>) -> Self {
+        Self::Identifier(name.into())
+    }
+}
+
+impl From<Identifier> for BindingTarget {
+    fn from(ident: Identifier) -> Self {
+        Self::Identifier(ident)
+    }
+}
+
+impl From<DeclarationPattern> for BindingTarget {
+    fn from(pattern: DeclarationPattern) -> Self {
+        Self::Pattern(pattern)
+    }
+}
+
+/// The kind of binding that a pattern initializes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BindingKind {
+    /// A `var` declaration.
+    Var,
+
+    /// A `let` declaration or a `catch` parameter.
+    Let,
+
+    /// A `const` declaration.
+    Const,
+
+    /// A function parameter, bound in the function environment being set up.
+    Parameter,
+
+    /// A destructuring assignment to existing bindings.
+    Assignment,
+}
+
+impl BindingKind {
+    /// Initializes the binding `name` with `value` in the current environment.
+    pub(crate) fn initialize(self, name: &str, value: Value, context: &mut Context) -> Result<()> {
+        match self {
+            Self::Var if context.has_var_scope_binding(name) => {
+                let strict = context.strict();
+                context.set_mutable_binding(name, value, strict)
+            }
+            Self::Assignment => {
+                let strict = context.strict();
+                context.set_mutable_binding(name, value, strict)
+            }
+            Self::Var | Self::Let | Self::Const => {
+                let scope = match self {
+                    Self::Let | Self::Const => VariableScope::Block,
+                    _ => VariableScope::Function,
+                };
+                // Assignments to constants always throw, even in sloppy mode code.
+                if self == Self::Const {
+                    context.create_immutable_binding(name.to_owned(), true, scope)?;
+                } else {
+                    context.create_mutable_binding(name.to_owned(), false, scope)?;
+                }
+                // A `var` declaration in a `with` statement is initialized to `undefined`, and
+                // its value is assigned to the property of the object with the same name.
+                if self == Self::Var && context.get_with_base_object(name)?.is_some() {
+                    context.initialize_binding(name, Value::undefined())?;
+                    let strict = context.strict();
+                    return context.set_mutable_binding(name, value, strict);
+                }
+                context.initialize_binding(name, value)
+            }
+            Self::Parameter => {
+                let env = context.get_current_environment();
+                // Function parameters can share names in JavaScript...
+                env.borrow_mut()
+                    .create_mutable_binding(name.to_owned(), false, true, context)?;
+                let result = env.borrow_mut().initialize_binding(name, value, context);
+                result
+            }
+        }
+    }
+}
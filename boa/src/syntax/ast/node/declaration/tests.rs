@@ -10,3 +10,307 @@ fn duplicate_function_name() {
 
     assert_eq!(&exec(scenario), "12");
 }
+
+#[test]
+fn class_methods_and_accessors() {
+    let scenario = r#"
+    class A {
+        constructor(x) { this.x = x; }
+        double() { return this.x * 2; }
+        get value() { return this.x; }
+        set value(v) { this.x = v; }
+        static create() { return new A(3); }
+    }
+    let a = A.create();
+    a.value = a.double();
+    a.value
+    "#;
+
+    assert_eq!(&exec(scenario), "6");
+}
+
+#[test]
+fn class_fields() {
+    let scenario = r#"
+    let counter = 0;
+    class A {
+        x = ++counter;
+        y = this.x + 1;
+        static z = 'static';
+    }
+    let a = new A();
+    let b = new A();
+    `${a.x} ${a.y} ${b.x} ${b.y} ${A.z}`
+    "#;
+
+    assert_eq!(&exec(scenario), "\"1 2 2 3 static\"");
+}
+
+#[test]
+fn class_extends() {
+    let scenario = r#"
+    class A {
+        constructor(x) { this.x = x; }
+        name() { return 'A' + this.x; }
+        static kind() { return 'base'; }
+    }
+    class B extends A {
+        y = 2;
+        constructor(x) { super(x + 1); }
+        name() { return 'B:' + super.name(); }
+        static kind() { return 'derived>' + super.kind(); }
+    }
+    class C extends B {}
+    let c = new C(4);
+    `${c.name()} ${c.y} ${c instanceof A} ${C.kind()}`
+    "#;
+
+    assert_eq!(&exec(scenario), "\"B:A5 2 true derived>base\"");
+}
+
+#[test]
+fn class_constructor_without_new() {
+    let scenario = r#"
+    class A {}
+    try {
+        A();
+    } catch (e) {
+        e instanceof TypeError
+    }
+    "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn class_this_before_super() {
+    let scenario = r#"
+    class A {}
+    class B extends A {
+        constructor() {
+            this.x = 1;
+            super();
+        }
+    }
+    try {
+        new B();
+    } catch (e) {
+        e instanceof ReferenceError
+    }
+    "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn class_expression_name_binding() {
+    let scenario = r#"
+    let A = class Named {
+        who() { return Named.name; }
+    };
+    new A().who()
+    "#;
+
+    assert_eq!(&exec(scenario), "\"Named\"");
+}
+
+#[test]
+fn class_constructor_returning_object() {
+    let scenario = r#"
+    let obj = { marker: true };
+    class A {
+        constructor() { return obj; }
+    }
+    new A() === obj
+    "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn class_private_elements() {
+    let scenario = r#"
+    class Counter {
+        #count = 1;
+        static #instances = 0;
+        constructor() { Counter.#instances++; }
+        #step() { return 2; }
+        get #double() { return this.#count * 2; }
+        set #double(v) { this.#count = v / 2; }
+        increment() {
+            this.#count += this.#step();
+            return this.#count;
+        }
+        swap() {
+            this.#double = 10;
+            return this.#double;
+        }
+        static instances() { return Counter.#instances; }
+    }
+    let c = new Counter();
+    new Counter();
+    `${c.increment()} ${c.swap()} ${Counter.instances()} ${Object.keys(c).length}`
+    "#;
+
+    assert_eq!(&exec(scenario), "\"3 10 2 0\"");
+}
+
+#[test]
+fn class_private_brand_check() {
+    let scenario = r#"
+    class A {
+        #x = 1;
+        static read(o) { return o.#x; }
+    }
+    class B {
+        #x = 2;
+    }
+    let results = [A.read(new A())];
+    for (let o of [new B(), {}, new Proxy(new A(), {})]) {
+        try {
+            A.read(o);
+            results.push('read');
+        } catch (e) {
+            results.push(e instanceof TypeError);
+        }
+    }
+    results.join()
+    "#;
+
+    assert_eq!(&exec(scenario), "\"1,true,true,true\"");
+}
+
+#[test]
+fn class_private_method_not_writable() {
+    let scenario = r#"
+    class A {
+        #m() {}
+        write() { this.#m = 1; }
+    }
+    try {
+        new A().write();
+    } catch (e) {
+        e instanceof TypeError
+    }
+    "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn object_destructuring() {
+    let scenario = r#"
+    const { a, b: { c }, d = 4, ...rest } = { a: 1, b: { c: 2 }, e: 5, f: 6 };
+    [a, c, d, rest.e, rest.f, "a" in rest].join()
+    "#;
+
+    assert_eq!(&exec(scenario), "\"1,2,4,5,6,false\"");
+}
+
+#[test]
+fn array_destructuring() {
+    let scenario = r#"
+    let [a, , b = 3, [c] = [4], ...rest] = [1, 2, undefined, undefined, 5, 6];
+    [a, b, c, rest.length, rest[1]].join()
+    "#;
+
+    assert_eq!(&exec(scenario), "\"1,3,4,2,6\"");
+}
+
+#[test]
+fn array_destructuring_closes_iterator() {
+    let scenario = r#"
+    let closed = false;
+    const iterable = {};
+    iterable[Symbol.iterator] = function () {
+        return {
+            next() { return { value: 1, done: false }; },
+            return() { closed = true; return {}; }
+        };
+    };
+    const [a] = iterable;
+    closed
+    "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn destructuring_null_throws() {
+    let scenario = r#"
+    try {
+        const { a } = null;
+    } catch (e) {
+        e.name
+    }
+    "#;
+
+    assert_eq!(&exec(scenario), "\"TypeError\"");
+}
+
+#[test]
+fn destructured_parameters() {
+    let scenario = r#"
+    function f({ a = 0, b = 2 } = {}, [c] = [3], ...[d = 0, e = 0]) {
+        return [a, b, c, d, e].join();
+    }
+    const g = ({ a }, [b]) => a + b;
+    [f({ a: 1 }, undefined, 4, 5), f(), g({ a: 1 }, [2]), f.length].join(";")
+    "#;
+
+    assert_eq!(&exec(scenario), "\"1,2,3,4,5;0,2,3,0,0;3;0\"");
+}
+
+#[test]
+fn parameter_defaults() {
+    let scenario = r#"
+    function f(a, b = a + 1) { return a + b; }
+    const g = (a = 2) => a;
+    [f(1), f(1, 5), g(), f.length].join()
+    "#;
+
+    assert_eq!(&exec(scenario), "\"3,6,2,1\"");
+}
+
+#[test]
+fn destructured_catch_parameter() {
+    let scenario = r#"
+    try {
+        throw new TypeError("message");
+    } catch ({ name, message }) {
+        name + ": " + message
+    }
+    "#;
+
+    assert_eq!(&exec(scenario), "\"TypeError: message\"");
+}
+
+#[test]
+fn destructuring_assignment() {
+    let scenario = r#"
+    let a = 1, b = 2;
+    const o = {};
+    [a, b] = [b, a];
+    ({ x: o.x, y: o["y"], z = 3 } = { x: a, y: b });
+    [a, b, o.x, o.y, z].join()
+    "#;
+
+    assert_eq!(&exec(scenario), "\"2,1,2,1,3\"");
+}
+
+#[test]
+fn destructuring_for_of() {
+    let scenario = r#"
+    let result = "";
+    for (const [k, { v }] of [["a", { v: 1 }], ["b", { v: 2 }]]) {
+        result += k + v;
+    }
+    let k, v;
+    for ([k, v] of [["c", 3]]) {
+        result += k + v;
+    }
+    result
+    "#;
+
+    assert_eq!(&exec(scenario), "\"a1b2c3\"");
+}
@@ -1,7 +1,7 @@
 use crate::{
     exec::Executable,
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::node::{Node, NodeSpan},
     value::{Type, Value},
     Context, Result,
 };
@@ -10,6 +10,9 @@ use std::fmt;
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "vm")]
+use crate::vm::{compilation::CodeGen, Compiler, Instruction};
+
 /// This property accessor provides access to an object's properties by using the
 /// [dot notation][mdn].
 ///
@@ -36,6 +39,7 @@ use serde::{Deserialize, Serialize};
 pub struct GetConstField {
     obj: Box<Node>,
     field: Box<str>,
+    span: NodeSpan,
 }
 
 impl GetConstField {
@@ -48,6 +52,7 @@ impl GetConstField {
         Self {
             obj: Box::new(value.into()),
             field: label.into(),
+            span: NodeSpan::default(),
         }
     }
 
@@ -62,6 +67,8 @@ impl GetConstField {
     }
 }
 
+impl_span!(GetConstField);
+
 impl Executable for GetConstField {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let mut obj = self.obj().run(context)?;
@@ -73,6 +80,14 @@ impl Executable for GetConstField {
     }
 }
 
+#[cfg(feature = "vm")]
+impl CodeGen for GetConstField {
+    fn compile(&self, compiler: &mut Compiler) {
+        self.obj().compile(compiler);
+        compiler.add_name_instruction(self.field(), Instruction::GetPropertyByName);
+    }
+}
+
 impl fmt::Display for GetConstField {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}.{}", self.obj(), self.field())
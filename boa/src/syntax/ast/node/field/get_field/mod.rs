@@ -1,7 +1,7 @@
 use crate::{
     exec::Executable,
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::node::{Node, NodeSpan},
     value::{Type, Value},
     Context, Result,
 };
@@ -10,6 +10,9 @@ use std::fmt;
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "vm")]
+use crate::vm::{compilation::CodeGen, Compiler, Instruction};
+
 /// This property accessor provides access to an object's properties by using the
 /// [bracket notation][mdn].
 ///
@@ -37,6 +40,7 @@ use serde::{Deserialize, Serialize};
 pub struct GetField {
     obj: Box<Node>,
     field: Box<Node>,
+    span: NodeSpan,
 }
 
 impl GetField {
@@ -57,19 +61,33 @@ impl GetField {
         Self {
             obj: Box::new(value.into()),
             field: Box::new(field.into()),
+            span: NodeSpan::default(),
         }
     }
 }
 
+impl_span!(GetField);
+
 impl Executable for GetField {
     fn run(&self, context: &mut Context) -> Result<Value> {
-        let mut obj = self.obj().run(context)?;
-        if obj.get_type() != Type::Object {
-            obj = Value::Object(obj.to_object(context)?);
-        }
-        let field = self.field().run(context)?;
+        context.resumable(|frame, context| {
+            let mut obj = frame.run(self.obj(), context)?;
+            if obj.get_type() != Type::Object {
+                obj = Value::Object(obj.to_object(context)?);
+            }
+            let field = self.field().run(context)?;
+
+            obj.get_field(field.to_property_key(context)?, context)
+        })
+    }
+}
 
-        obj.get_field(field.to_property_key(context)?, context)
+#[cfg(feature = "vm")]
+impl CodeGen for GetField {
+    fn compile(&self, compiler: &mut Compiler) {
+        self.obj().compile(compiler);
+        self.field().compile(compiler);
+        compiler.add_instruction(Instruction::GetPropertyByValue);
     }
 }
 
@@ -0,0 +1,122 @@
+use crate::{
+    exec::Executable,
+    gc::{Finalize, Trace},
+    syntax::ast::node::{Node, NodeSpan},
+    Context, Result, Value,
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "vm")]
+use crate::vm::{compilation::CodeGen, Compiler};
+
+/// This property accessor provides access to the private elements of an object, like
+/// `this.#field`.
+///
+/// Private names are only visible inside the body of the class that declares them, and they
+/// are resolved in the private environment of the class. Accessing a private element of an
+/// object that was not initialized by the class throws a `TypeError`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-makeprivatereference
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes/Private_class_fields
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct GetPrivateField {
+    obj: Box<Node>,
+    field: Box<str>,
+    span: NodeSpan,
+}
+
+impl GetPrivateField {
+    /// Creates a `GetPrivateField` AST node.
+    pub fn new<V, L>(value: V, label: L) -> Self
+    where
+        V: Into<Node>,
+        L: Into<Box<str>>,
+    {
+        Self {
+            obj: Box::new(value.into()),
+            field: label.into(),
+            span: NodeSpan::default(),
+        }
+    }
+
+    /// Gets the original object from where to get the field from.
+    pub fn obj(&self) -> &Node {
+        &self.obj
+    }
+
+    /// Gets the private name of the field, including the `#`.
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    /// Assigns a value to the private element of the object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-privateset
+    pub(crate) fn assign(&self, obj: &Value, value: Value, context: &mut Context) -> Result<()> {
+        let name = context.resolve_private_identifier(self.field())?;
+        match obj.as_object() {
+            Some(object) => object.private_set(&name, value, context),
+            None => Err(context.construct_type_error(format!(
+                "cannot write private member {} to a primitive value",
+                self.field()
+            ))),
+        }
+    }
+
+    /// Gets the value of the private element of the object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-privateget
+    pub(crate) fn get(&self, obj: &Value, context: &mut Context) -> Result<Value> {
+        let name = context.resolve_private_identifier(self.field())?;
+        match obj.as_object() {
+            Some(object) => object.private_get(&name, context),
+            None => Err(context.construct_type_error(format!(
+                "cannot read private member {} from a primitive value",
+                self.field()
+            ))),
+        }
+    }
+}
+
+impl_span!(GetPrivateField);
+
+impl Executable for GetPrivateField {
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        let obj = self.obj().run(context)?;
+        self.get(&obj, context)
+    }
+}
+
+/// Private names are resolved in the environments of the interpreter.
+#[cfg(feature = "vm")]
+impl CodeGen for GetPrivateField {
+    fn compile(&self, compiler: &mut Compiler) {
+        compiler.add_node_instruction(&self.clone().into());
+    }
+}
+
+impl fmt::Display for GetPrivateField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.obj(), self.field())
+    }
+}
+
+impl From<GetPrivateField> for Node {
+    fn from(get_private_field: GetPrivateField) -> Self {
+        Self::GetPrivateField(get_private_field)
+    }
+}
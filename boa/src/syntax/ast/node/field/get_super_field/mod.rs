@@ -0,0 +1,104 @@
+use crate::{
+    exec::Executable,
+    gc::{Finalize, Trace},
+    property::PropertyKey,
+    syntax::ast::{
+        node::{Node, NodeSpan},
+        Const,
+    },
+    Context, Result, Value,
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+/// The `super` property accessor provides access to the properties of the prototype of the
+/// object a method was defined on.
+///
+/// Both the dot notation (`super.prop`) and the bracket notation (`super[expr]`) are supported.
+/// The properties are looked up with the current `this` value as the receiver, so getters defined
+/// on the parent class operate on the instance.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-SuperProperty
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/super
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct GetSuperField {
+    field: Box<Node>,
+    span: NodeSpan,
+}
+
+impl GetSuperField {
+    /// Creates a `GetSuperField` AST node.
+    pub fn new<F>(field: F) -> Self
+    where
+        F: Into<Node>,
+    {
+        Self {
+            field: Box::new(field.into()),
+            span: NodeSpan::default(),
+        }
+    }
+
+    /// Gets the expression that computes the name of the field.
+    pub fn field(&self) -> &Node {
+        &self.field
+    }
+
+    /// Resolves the `super` property reference.
+    ///
+    /// Returns the object where the lookup starts, the property key and the `this` value.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-makesuperpropertyreference
+    pub(crate) fn reference(&self, context: &mut Context) -> Result<(Value, PropertyKey, Value)> {
+        let env = context.get_this_environment();
+        let this = context.get_this_binding()?;
+        let key = self.field().run(context)?.to_property_key(context)?;
+
+        let base = match env.borrow().as_function_environment() {
+            Some(env) if !env.home_object.is_undefined() => env.get_super_base(),
+            _ => return Err(context.construct_syntax_error("'super' keyword unexpected here")),
+        };
+        if base.is_null_or_undefined() {
+            return Err(context.construct_type_error(format!(
+                "cannot access property '{}' of {}",
+                key,
+                base.display()
+            )));
+        }
+
+        Ok((base, key, this))
+    }
+}
+
+impl_span!(GetSuperField);
+
+impl Executable for GetSuperField {
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        let (base, key, this) = self.reference(context)?;
+        base.to_object(context)?.get(&key, this, context)
+    }
+}
+
+impl fmt::Display for GetSuperField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.field() {
+            Node::Const(Const::String(field)) => write!(f, "super.{}", field),
+            field => write!(f, "super[{}]", field),
+        }
+    }
+}
+
+impl From<GetSuperField> for Node {
+    fn from(get_super_field: GetSuperField) -> Self {
+        Self::GetSuperField(get_super_field)
+    }
+}
@@ -3,7 +3,7 @@
 use crate::{
     exec::Executable,
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::node::{Node, NodeSpan},
     Context, Result, Value,
 };
 use std::fmt;
@@ -32,8 +32,12 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct Identifier {
     ident: Box<str>,
+    #[cfg_attr(feature = "deser", serde(skip))]
+    span: NodeSpan,
 }
 
+impl_span!(Identifier);
+
 impl Executable for Identifier {
     fn run(&self, context: &mut Context) -> Result<Value> {
         context.get_binding_value(self.as_ref())
@@ -57,7 +61,10 @@ where
     T: Into<Box<str>>,
 {
     fn from(stm: T) -> Self {
-        Self { ident: stm.into() }
+        Self {
+            ident: stm.into(),
+            span: NodeSpan::default(),
+        }
     }
 }
 
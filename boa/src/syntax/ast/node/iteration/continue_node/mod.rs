@@ -1,7 +1,7 @@
 use crate::{
     exec::{Executable, InterpreterState},
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::node::{Node, NodeSpan},
     Context, Result, Value,
 };
 use std::fmt;
@@ -9,6 +9,9 @@ use std::fmt;
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "vm")]
+use crate::vm::{compilation::CodeGen, Compiler};
+
 /// The `continue` statement terminates execution of the statements in the current iteration of
 /// the current or labeled loop, and continues execution of the loop with the next iteration.
 ///
@@ -26,6 +29,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct Continue {
     label: Option<Box<str>>,
+    span: NodeSpan,
 }
 
 impl Continue {
@@ -41,10 +45,13 @@ impl Continue {
     {
         Self {
             label: label.into().map(L::into),
+            span: NodeSpan::default(),
         }
     }
 }
 
+impl_span!(Continue);
+
 impl Executable for Continue {
     fn run(&self, context: &mut Context) -> Result<Value> {
         context
@@ -55,6 +62,13 @@ impl Executable for Continue {
     }
 }
 
+#[cfg(feature = "vm")]
+impl CodeGen for Continue {
+    fn compile(&self, compiler: &mut Compiler) {
+        compiler.compile_continue(self.label());
+    }
+}
+
 impl fmt::Display for Continue {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -1,14 +1,18 @@
+use super::display_labels;
 use crate::{
     exec::{Executable, InterpreterState},
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::node::{Node, NodeSpan},
     Context, Result, Value,
 };
-use std::fmt;
+use std::{fmt, iter};
 
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "vm")]
+use crate::vm::{compilation::CodeGen, Compiler, Instruction};
+
 /// The `do...while` statement creates a loop that executes a specified statement until the
 /// test condition evaluates to false.
 ///
@@ -26,7 +30,8 @@ use serde::{Deserialize, Serialize};
 pub struct DoWhileLoop {
     body: Box<Node>,
     cond: Box<Node>,
-    label: Option<Box<str>>,
+    labels: Box<[Box<str>]>,
+    span: NodeSpan,
 }
 
 impl DoWhileLoop {
@@ -38,12 +43,16 @@ impl DoWhileLoop {
         &self.cond
     }
 
-    pub fn label(&self) -> Option<&str> {
-        self.label.as_ref().map(Box::as_ref)
+    /// Gets the labels of the loop, from the outermost one.
+    pub fn labels(&self) -> &[Box<str>] {
+        &self.labels
     }
 
-    pub fn set_label(&mut self, label: Box<str>) {
-        self.label = Some(label);
+    /// Adds a label to the loop, outside of the labels it already has.
+    pub fn add_label(&mut self, label: Box<str>) {
+        self.labels = iter::once(label)
+            .chain(self.labels.iter().cloned())
+            .collect();
     }
 
     /// Creates a `DoWhileLoop` AST node.
@@ -55,7 +64,8 @@ impl DoWhileLoop {
         Self {
             body: Box::new(body.into()),
             cond: Box::new(condition.into()),
-            label: None,
+            labels: Box::new([]),
+            span: NodeSpan::default(),
         }
     }
 
@@ -64,37 +74,57 @@ impl DoWhileLoop {
         f: &mut fmt::Formatter<'_>,
         indentation: usize,
     ) -> fmt::Result {
+        display_labels(f, self.labels())?;
         write!(f, "do")?;
         self.body().display(f, indentation)?;
         write!(f, "while ({})", self.cond())
     }
 }
 
+impl_span!(DoWhileLoop);
+
 impl Executable for DoWhileLoop {
     fn run(&self, context: &mut Context) -> Result<Value> {
-        let mut result;
-        loop {
-            result = self.body().run(context)?;
-            match context.executor().get_current_state() {
-                InterpreterState::Break(label) => {
-                    handle_state_with_labels!(self, label, context, break);
-                    break;
+        context.resumable(|frame, context| {
+            let mut result;
+            let checkpoint = frame.checkpoint();
+            loop {
+                frame.restart(checkpoint);
+                result = frame.run(self.body(), context)?;
+                match context.executor().get_current_state() {
+                    InterpreterState::Break(label) => {
+                        handle_state_with_labels!(self, label, context, break);
+                        break;
+                    }
+                    InterpreterState::Continue(label) => {
+                        handle_state_with_labels!(self, label, context, continue);
+                    }
+                    InterpreterState::Return => {
+                        return Ok(result);
+                    }
+                    InterpreterState::Executing => {
+                        // Continue execution.
+                    }
                 }
-                InterpreterState::Continue(label) => {
-                    handle_state_with_labels!(self, label, context, continue);
-                }
-                InterpreterState::Return => {
-                    return Ok(result);
-                }
-                InterpreterState::Executing => {
-                    // Continue execution.
+                if !self.cond().run(context)?.to_boolean() {
+                    break;
                 }
             }
-            if !self.cond().run(context)?.to_boolean() {
-                break;
-            }
-        }
-        Ok(result)
+            Ok(result)
+        })
+    }
+}
+
+#[cfg(feature = "vm")]
+impl CodeGen for DoWhileLoop {
+    fn compile(&self, compiler: &mut Compiler) {
+        compiler.push_jumpable(self.labels(), true);
+        let start = compiler.next_address();
+        compiler.compile_statement(self.body());
+        let next = compiler.next_address();
+        self.cond().compile(compiler);
+        compiler.add_instruction(Instruction::JumpIfTrue(start));
+        compiler.pop_jumpable(next);
     }
 }
 
@@ -1,26 +1,30 @@
+use super::{bind_loop_variable, display_labels, next_loop_value};
+#[cfg(feature = "vm")]
+use super::{compile_loop_variable, is_compilable_loop_variable};
 use crate::{
     builtins::{iterable::IteratorRecord, ForInIterator},
-    environment::{
-        declarative_environment_record::DeclarativeEnvironmentRecord,
-        lexical_environment::VariableScope,
-    },
+    environment::declarative_environment_record::DeclarativeEnvironmentRecord,
     exec::{Executable, InterpreterState},
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::node::{Node, NodeSpan},
     BoaProfiler, Context, Result, Value,
 };
-use std::fmt;
+use std::{fmt, iter};
 
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "vm")]
+use crate::vm::{compilation::CodeGen, Compiler, Instruction};
+
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct ForInLoop {
     variable: Box<Node>,
     expr: Box<Node>,
     body: Box<Node>,
-    label: Option<Box<str>>,
+    labels: Box<[Box<str>]>,
+    span: NodeSpan,
 }
 
 impl ForInLoop {
@@ -34,7 +38,8 @@ impl ForInLoop {
             variable: Box::new(variable.into()),
             expr: Box::new(expr.into()),
             body: Box::new(body.into()),
-            label: None,
+            labels: Box::new([]),
+            span: NodeSpan::default(),
         }
     }
 
@@ -50,21 +55,28 @@ impl ForInLoop {
         &self.body
     }
 
-    pub fn label(&self) -> Option<&str> {
-        self.label.as_ref().map(Box::as_ref)
+    /// Gets the labels of the loop, from the outermost one.
+    pub fn labels(&self) -> &[Box<str>] {
+        &self.labels
     }
 
-    pub fn set_label(&mut self, label: Box<str>) {
-        self.label = Some(label);
+    /// Adds a label to the loop, outside of the labels it already has.
+    pub fn add_label(&mut self, label: Box<str>) {
+        self.labels = iter::once(label)
+            .chain(self.labels.iter().cloned())
+            .collect();
     }
 
     pub fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
+        display_labels(f, self.labels())?;
         write!(f, "for ({} in {}) {{", self.variable, self.expr,)?;
         self.body().display(f, indentation + 1)?;
         f.write_str("}")
     }
 }
 
+impl_span!(ForInLoop);
+
 impl fmt::Display for ForInLoop {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.display(f, 0)
@@ -80,134 +92,97 @@ impl From<ForInLoop> for Node {
 impl Executable for ForInLoop {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("ForIn", "exec");
-        let object = self.expr().run(context)?;
-        let mut result = Value::undefined();
+        context.resumable(|frame, context| {
+            let object = frame.run(self.expr(), context)?;
+            let mut result = Value::undefined();
 
-        if object.is_null_or_undefined() {
-            return Ok(result);
-        }
-        let object = object.to_object(context)?;
-        let for_in_iterator = ForInIterator::create_for_in_iterator(context, Value::from(object));
-        let next_function = for_in_iterator
-            .get_property("next")
-            .map(|p| p.as_data_descriptor().unwrap().value())
-            .ok_or_else(|| context.construct_type_error("Could not find property `next`"))?;
-        let iterator = IteratorRecord::new(for_in_iterator, next_function);
-
-        loop {
-            {
-                let env = context.get_current_environment();
-                context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
+            if object.is_null_or_undefined() {
+                return Ok(result);
             }
-            let iterator_result = iterator.next(context)?;
-            if iterator_result.is_done() {
-                context.pop_environment();
-                break;
-            }
-            let next_result = iterator_result.value();
-
-            match self.variable() {
-                Node::Identifier(ref name) => {
-                    if context.has_binding(name.as_ref()) {
-                        // Binding already exists
-                        context.set_mutable_binding(name.as_ref(), next_result.clone(), true)?;
-                    } else {
-                        context.create_mutable_binding(
-                            name.as_ref().to_owned(),
-                            true,
-                            VariableScope::Function,
-                        )?;
-                        context.initialize_binding(name.as_ref(), next_result.clone())?;
-                    }
+            let iterator = frame.iterator(context, |context| {
+                let object = object.to_object(context)?;
+                let for_in_iterator =
+                    ForInIterator::create_for_in_iterator(context, Value::from(object));
+                let next_function = for_in_iterator
+                    .get_property("next")
+                    .map(|p| p.as_data_descriptor().unwrap().value())
+                    .ok_or_else(|| {
+                        context.construct_type_error("Could not find property `next`")
+                    })?;
+                Ok(IteratorRecord::new(for_in_iterator, next_function))
+            })?;
+
+            let checkpoint = frame.checkpoint();
+            loop {
+                frame.restart(checkpoint);
+                let previous = result;
+                result = frame.eval(context, |_| Ok(previous))?;
+                let env = frame.environment(context, |context| {
+                    let env = context.get_current_environment();
+                    DeclarativeEnvironmentRecord::new(Some(env))
+                });
+                context.push_environment(env);
+                let next_result = if let Some(value) = next_loop_value(&iterator, frame, context)? {
+                    value
+                } else {
+                    context.pop_environment();
+                    break;
+                };
+
+                if let Err(error) = frame.eval(context, |context| {
+                    bind_loop_variable(self.variable(), next_result, "for-in", context)?;
+                    Ok(Value::undefined())
+                }) {
+                    let _ = context.pop_environment();
+                    return Err(error);
                 }
-                Node::VarDeclList(ref list) => match list.as_ref() {
-                    [var] => {
-                        if var.init().is_some() {
-                            return context.throw_syntax_error("a declaration in the head of a for-in loop can't have an initializer");
-                        }
-
-                        if context.has_binding(var.name()) {
-                            context.set_mutable_binding(var.name(), next_result, true)?;
-                        } else {
-                            context.create_mutable_binding(
-                                var.name().to_owned(),
-                                false,
-                                VariableScope::Function,
-                            )?;
-                            context.initialize_binding(var.name(), next_result)?;
-                        }
-                    }
-                    _ => {
-                        return context.throw_syntax_error(
-                            "only one variable can be declared in the head of a for-in loop",
-                        )
-                    }
-                },
-                Node::LetDeclList(ref list) => match list.as_ref() {
-                    [var] => {
-                        if var.init().is_some() {
-                            return context.throw_syntax_error("a declaration in the head of a for-in loop can't have an initializer");
-                        }
-
-                        context.create_mutable_binding(
-                            var.name().to_owned(),
-                            false,
-                            VariableScope::Block,
-                        )?;
-                        context.initialize_binding(var.name(), next_result)?;
-                    }
-                    _ => {
-                        return context.throw_syntax_error(
-                            "only one variable can be declared in the head of a for-in loop",
-                        )
+
+                result = self.body().run(context)?;
+                match context.executor().get_current_state() {
+                    InterpreterState::Break(label) => {
+                        handle_state_with_labels!(self, label, context, break);
+                        break;
                     }
-                },
-                Node::ConstDeclList(ref list) => match list.as_ref() {
-                    [var] => {
-                        if var.init().is_some() {
-                            return context.throw_syntax_error("a declaration in the head of a for-in loop can't have an initializer");
-                        }
-
-                        context.create_immutable_binding(
-                            var.name().to_owned(),
-                            false,
-                            VariableScope::Block,
-                        )?;
-                        context.initialize_binding(var.name(), next_result)?;
+                    InterpreterState::Continue(label) => {
+                        handle_state_with_labels!(self, label, context, continue);
                     }
-                    _ => {
-                        return context.throw_syntax_error(
-                            "only one variable can be declared in the head of a for-in loop",
-                        )
+                    InterpreterState::Return => return Ok(result),
+                    InterpreterState::Executing => {
+                        // Continue execution.
                     }
-                },
-                Node::Assign(_) => {
-                    return context.throw_syntax_error(
-                        "a declaration in the head of a for-in loop can't have an initializer",
-                    );
-                }
-                _ => {
-                    return context
-                        .throw_syntax_error("unknown left hand side in head of for-in loop")
                 }
+                let _ = context.pop_environment();
             }
+            Ok(result)
+        })
+    }
+}
 
-            result = self.body().run(context)?;
-            match context.executor().get_current_state() {
-                InterpreterState::Break(label) => {
-                    handle_state_with_labels!(self, label, context, break);
-                    break;
-                }
-                InterpreterState::Continue(label) => {
-                    handle_state_with_labels!(self, label, context, continue);
-                }
-                InterpreterState::Return => return Ok(result),
-                InterpreterState::Executing => {
-                    // Continue execution.
-                }
-            }
-            let _ = context.pop_environment();
+#[cfg(feature = "vm")]
+impl CodeGen for ForInLoop {
+    fn compile(&self, compiler: &mut Compiler) {
+        let _timer = BoaProfiler::global().start_event("ForIn", "codeGen");
+        if !is_compilable_loop_variable(self.variable()) {
+            return compiler.add_node_instruction(&self.clone().into());
         }
-        Ok(result)
+
+        self.expr().compile(compiler);
+        let skip = compiler.jump(Instruction::ForInIterator);
+        compiler.push_iterator();
+        compiler.push_jumpable(self.labels(), true);
+
+        let start = compiler.next_address();
+        let exit = compiler.jump(Instruction::IteratorNext);
+        compiler.push_environment();
+        compile_loop_variable(self.variable(), compiler);
+        compiler.compile_statement(self.body());
+        compiler.pop_environment();
+        compiler.add_instruction(Instruction::Jump(start));
+
+        compiler.pop_jumpable(start);
+        compiler.add_instruction(Instruction::IteratorClose);
+        compiler.patch_jump(exit);
+        compiler.patch_jump(skip);
+        compiler.pop_control();
     }
 }
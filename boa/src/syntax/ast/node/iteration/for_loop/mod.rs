@@ -1,15 +1,19 @@
+use super::display_labels;
 use crate::{
     environment::declarative_environment_record::DeclarativeEnvironmentRecord,
     exec::{Executable, InterpreterState},
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::node::{Node, NodeSpan},
     BoaProfiler, Context, Result, Value,
 };
-use std::fmt;
+use std::{fmt, iter};
 
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "vm")]
+use crate::vm::{compilation::CodeGen, Compiler, Instruction};
+
 /// The `for` statement creates a loop that consists of three optional expressions.
 ///
 /// A `for` loop repeats until a specified condition evaluates to `false`.
@@ -24,9 +28,9 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct ForLoop {
-    #[cfg_attr(feature = "deser", serde(flatten))]
     inner: Box<InnerForLoop>,
-    label: Option<Box<str>>,
+    labels: Box<[Box<str>]>,
+    span: NodeSpan,
 }
 
 impl ForLoop {
@@ -40,7 +44,8 @@ impl ForLoop {
     {
         Self {
             inner: Box::new(InnerForLoop::new(init, condition, final_expr, body)),
-            label: None,
+            labels: Box::new([]),
+            span: NodeSpan::default(),
         }
     }
 
@@ -69,6 +74,7 @@ impl ForLoop {
         f: &mut fmt::Formatter<'_>,
         indentation: usize,
     ) -> fmt::Result {
+        display_labels(f, self.labels())?;
         f.write_str("for (")?;
         if let Some(init) = self.init() {
             fmt::Display::fmt(init, f)?;
@@ -88,62 +94,104 @@ impl ForLoop {
         write!(f, "}}")
     }
 
-    pub fn label(&self) -> Option<&str> {
-        self.label.as_ref().map(Box::as_ref)
+    /// Gets the labels of the loop, from the outermost one.
+    pub fn labels(&self) -> &[Box<str>] {
+        &self.labels
     }
 
-    pub fn set_label(&mut self, label: Box<str>) {
-        self.label = Some(label);
+    /// Adds a label to the loop, outside of the labels it already has.
+    pub fn add_label(&mut self, label: Box<str>) {
+        self.labels = iter::once(label)
+            .chain(self.labels.iter().cloned())
+            .collect();
     }
 }
 
+impl_span!(ForLoop);
+
 impl Executable for ForLoop {
     fn run(&self, context: &mut Context) -> Result<Value> {
         // Create the block environment.
         let _timer = BoaProfiler::global().start_event("ForLoop", "exec");
-        {
-            let env = context.get_current_environment();
-            context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
-        }
+        context.resumable(|frame, context| {
+            let env = frame.environment(context, |context| {
+                let env = context.get_current_environment();
+                DeclarativeEnvironmentRecord::new(Some(env))
+            });
+            context.push_environment(env);
 
-        if let Some(init) = self.init() {
-            init.run(context)?;
-        }
+            if let Some(init) = self.init() {
+                frame.run(init, context)?;
+            }
 
-        while self
-            .condition()
-            .map(|cond| cond.run(context).map(|v| v.to_boolean()))
-            .transpose()?
-            .unwrap_or(true)
-        {
-            let result = self.body().run(context)?;
-
-            match context.executor().get_current_state() {
-                InterpreterState::Break(label) => {
-                    handle_state_with_labels!(self, label, context, break);
-                    break;
-                }
-                InterpreterState::Continue(label) => {
-                    handle_state_with_labels!(self, label, context, continue);
+            let checkpoint = frame.checkpoint();
+            loop {
+                frame.restart(checkpoint);
+                if let Some(cond) = self.condition() {
+                    if !frame.run(cond, context)?.to_boolean() {
+                        break;
+                    }
                 }
 
-                InterpreterState::Return => {
-                    return Ok(result);
+                let result = frame.run(self.body(), context)?;
+
+                match context.executor().get_current_state() {
+                    InterpreterState::Break(label) => {
+                        handle_state_with_labels!(self, label, context, break);
+                        break;
+                    }
+                    InterpreterState::Continue(label) => {
+                        handle_state_with_labels!(self, label, context, continue);
+                    }
+
+                    InterpreterState::Return => {
+                        return Ok(result);
+                    }
+                    InterpreterState::Executing => {
+                        // Continue execution.
+                    }
                 }
-                InterpreterState::Executing => {
-                    // Continue execution.
+
+                if let Some(final_expr) = self.final_expr() {
+                    final_expr.run(context)?;
                 }
             }
 
-            if let Some(final_expr) = self.final_expr() {
-                final_expr.run(context)?;
-            }
+            // pop the block env
+            let _ = context.pop_environment();
+
+            Ok(Value::undefined())
+        })
+    }
+}
+
+#[cfg(feature = "vm")]
+impl CodeGen for ForLoop {
+    fn compile(&self, compiler: &mut Compiler) {
+        let _timer = BoaProfiler::global().start_event("ForLoop", "codeGen");
+        compiler.push_environment();
+        if let Some(init) = self.init() {
+            compiler.compile_discarded(init);
         }
+        compiler.push_jumpable(self.labels(), true);
 
-        // pop the block env
-        let _ = context.pop_environment();
+        let start = compiler.next_address();
+        let exit = self.condition().map(|cond| {
+            cond.compile(compiler);
+            compiler.jump(Instruction::JumpIfFalse)
+        });
+        compiler.compile_statement(self.body());
+        let next = compiler.next_address();
+        if let Some(final_expr) = self.final_expr() {
+            compiler.compile_discarded(final_expr);
+        }
+        compiler.add_instruction(Instruction::Jump(start));
 
-        Ok(Value::undefined())
+        if let Some(exit) = exit {
+            compiler.patch_jump(exit);
+        }
+        compiler.pop_jumpable(next);
+        compiler.pop_environment();
     }
 }
 
@@ -1,26 +1,30 @@
+use super::{bind_loop_variable, display_labels, next_loop_value};
+#[cfg(feature = "vm")]
+use super::{compile_loop_variable, is_compilable_loop_variable};
 use crate::{
-    builtins::iterable::get_iterator,
-    environment::{
-        declarative_environment_record::DeclarativeEnvironmentRecord,
-        lexical_environment::VariableScope,
-    },
+    builtins::iterable::{get_iterator, IteratorRecord},
+    environment::declarative_environment_record::DeclarativeEnvironmentRecord,
     exec::{Executable, InterpreterState},
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::node::{Node, NodeSpan},
     BoaProfiler, Context, Result, Value,
 };
-use std::fmt;
+use std::{fmt, iter};
 
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "vm")]
+use crate::vm::{compilation::CodeGen, Compiler, Instruction};
+
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct ForOfLoop {
     variable: Box<Node>,
     iterable: Box<Node>,
     body: Box<Node>,
-    label: Option<Box<str>>,
+    labels: Box<[Box<str>]>,
+    span: NodeSpan,
 }
 
 impl ForOfLoop {
@@ -34,7 +38,8 @@ impl ForOfLoop {
             variable: Box::new(variable.into()),
             iterable: Box::new(iterable.into()),
             body: Box::new(body.into()),
-            label: None,
+            labels: Box::new([]),
+            span: NodeSpan::default(),
         }
     }
 
@@ -50,21 +55,28 @@ impl ForOfLoop {
         &self.body
     }
 
-    pub fn label(&self) -> Option<&str> {
-        self.label.as_ref().map(Box::as_ref)
+    /// Gets the labels of the loop, from the outermost one.
+    pub fn labels(&self) -> &[Box<str>] {
+        &self.labels
     }
 
-    pub fn set_label(&mut self, label: Box<str>) {
-        self.label = Some(label);
+    /// Adds a label to the loop, outside of the labels it already has.
+    pub fn add_label(&mut self, label: Box<str>) {
+        self.labels = iter::once(label)
+            .chain(self.labels.iter().cloned())
+            .collect();
     }
 
     pub fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
+        display_labels(f, self.labels())?;
         write!(f, "for ({} of {}) {{", self.variable, self.iterable)?;
         self.body().display(f, indentation + 1)?;
         f.write_str("}")
     }
 }
 
+impl_span!(ForOfLoop);
+
 impl fmt::Display for ForOfLoop {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.display(f, 0)
@@ -80,125 +92,113 @@ impl From<ForOfLoop> for Node {
 impl Executable for ForOfLoop {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("ForOf", "exec");
-        let iterable = self.iterable().run(context)?;
-        let iterator = get_iterator(context, iterable)?;
-        let mut result = Value::undefined();
-
-        loop {
-            {
-                let env = context.get_current_environment();
-                context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
-            }
-            let iterator_result = iterator.next(context)?;
-            if iterator_result.is_done() {
-                context.pop_environment();
-                break;
-            }
-            let next_result = iterator_result.value();
-
-            match self.variable() {
-                Node::Identifier(ref name) => {
-                    if context.has_binding(name.as_ref()) {
-                        // Binding already exists
-                        context.set_mutable_binding(name.as_ref(), next_result.clone(), true)?;
-                    } else {
-                        context.create_mutable_binding(
-                            name.as_ref().to_owned(),
-                            true,
-                            VariableScope::Function,
-                        )?;
-                        context.initialize_binding(name.as_ref(), next_result.clone())?;
+        context.resumable(|frame, context| {
+            let iterable = frame.run(self.iterable(), context)?;
+            let iterator = frame.iterator(context, |context| get_iterator(context, iterable))?;
+            let mut result = Value::undefined();
+            let mut exhausted = false;
+
+            let checkpoint = frame.checkpoint();
+            loop {
+                frame.restart(checkpoint);
+                let previous = result;
+                result = frame.eval(context, |_| Ok(previous))?;
+                let env = frame.environment(context, |context| {
+                    let env = context.get_current_environment();
+                    DeclarativeEnvironmentRecord::new(Some(env))
+                });
+                context.push_environment(env);
+                let next_result = if let Some(value) = next_loop_value(&iterator, frame, context)? {
+                    value
+                } else {
+                    context.pop_environment();
+                    exhausted = true;
+                    break;
+                };
+
+                if let Err(error) = frame.eval(context, |context| {
+                    bind_loop_variable(self.variable(), next_result, "for-of", context)?;
+                    Ok(Value::undefined())
+                }) {
+                    let _ = context.pop_environment();
+                    // The iterator stays open while the function is suspended.
+                    if context.is_suspending() {
+                        return Err(error);
                     }
+                    return iterator.close(Err(error), context);
                 }
-                Node::VarDeclList(ref list) => match list.as_ref() {
-                    [var] => {
-                        if var.init().is_some() {
-                            return context.throw_syntax_error("a declaration in the head of a for-of loop can't have an initializer");
-                        }
-
-                        if context.has_binding(var.name()) {
-                            context.set_mutable_binding(var.name(), next_result, true)?;
-                        } else {
-                            context.create_mutable_binding(
-                                var.name().to_owned(),
-                                false,
-                                VariableScope::Function,
-                            )?;
-                            context.initialize_binding(var.name(), next_result)?;
-                        }
-                    }
-                    _ => {
-                        return context.throw_syntax_error(
-                            "only one variable can be declared in the head of a for-of loop",
-                        )
-                    }
-                },
-                Node::LetDeclList(ref list) => match list.as_ref() {
-                    [var] => {
-                        if var.init().is_some() {
-                            return context.throw_syntax_error("a declaration in the head of a for-of loop can't have an initializer");
-                        }
-
-                        context.create_mutable_binding(
-                            var.name().to_owned(),
-                            false,
-                            VariableScope::Block,
-                        )?;
-
-                        context.initialize_binding(var.name(), next_result)?;
+
+                let body_result = self.body().run(context);
+                let _ = context.pop_environment();
+                result = match body_result {
+                    Ok(result) => result,
+                    Err(error) if context.is_suspending() => return Err(error),
+                    Err(error) => return iterator.close(Err(error), context),
+                };
+                match context.executor().get_current_state() {
+                    InterpreterState::Break(label) => {
+                        handle_state_with_labels!(self, label, context, break);
+                        break;
                     }
-                    _ => {
-                        return context.throw_syntax_error(
-                            "only one variable can be declared in the head of a for-of loop",
-                        )
+                    InterpreterState::Continue(label) => {
+                        handle_state_with_labels!(self, label, context, continue);
                     }
-                },
-                Node::ConstDeclList(ref list) => match list.as_ref() {
-                    [var] => {
-                        if var.init().is_some() {
-                            return context.throw_syntax_error("a declaration in the head of a for-of loop can't have an initializer");
-                        }
-
-                        context.create_immutable_binding(
-                            var.name().to_owned(),
-                            false,
-                            VariableScope::Block,
-                        )?;
-                        context.initialize_binding(var.name(), next_result)?;
+                    InterpreterState::Return => {
+                        close_iterator(&iterator, context)?;
+                        return Ok(result);
                     }
-                    _ => {
-                        return context.throw_syntax_error(
-                            "only one variable can be declared in the head of a for-of loop",
-                        )
+                    InterpreterState::Executing => {
+                        // Continue execution.
                     }
-                },
-                Node::Assign(_) => {
-                    return context.throw_syntax_error(
-                        "a declaration in the head of a for-of loop can't have an initializer",
-                    );
-                }
-                _ => {
-                    return context
-                        .throw_syntax_error("unknown left hand side in head of for-of loop")
                 }
             }
 
-            result = self.body().run(context)?;
-            match context.executor().get_current_state() {
-                InterpreterState::Break(label) => {
-                    handle_state_with_labels!(self, label, context, break);
-                    break;
-                }
-                InterpreterState::Continue(label) => {
-                    handle_state_with_labels!(self, label, context, continue);
-                }
-                InterpreterState::Return => return Ok(result),
-                InterpreterState::Executing => {
-                    // Continue execution.
-                }
+            if !exhausted {
+                close_iterator(&iterator, context)?;
             }
-            let _ = context.pop_environment();
+            Ok(result)
+        })
+    }
+}
+
+#[cfg(feature = "vm")]
+impl CodeGen for ForOfLoop {
+    fn compile(&self, compiler: &mut Compiler) {
+        let _timer = BoaProfiler::global().start_event("ForOf", "codeGen");
+        if !is_compilable_loop_variable(self.variable()) {
+            return compiler.add_node_instruction(&self.clone().into());
         }
-        Ok(result)
+
+        self.iterable().compile(compiler);
+        compiler.add_instruction(Instruction::GetIterator);
+        compiler.push_iterator();
+        compiler.push_jumpable(self.labels(), true);
+
+        let start = compiler.next_address();
+        let exit = compiler.jump(Instruction::IteratorNext);
+        compiler.push_environment();
+        compile_loop_variable(self.variable(), compiler);
+        compiler.compile_statement(self.body());
+        compiler.pop_environment();
+        compiler.add_instruction(Instruction::Jump(start));
+
+        // A `break` closes the iterator, while the iterator is already removed when it is done.
+        compiler.pop_jumpable(start);
+        compiler.add_instruction(Instruction::IteratorClose);
+        compiler.patch_jump(exit);
+        compiler.pop_control();
     }
 }
+
+/// Closes the iterator of a loop that is exited early, keeping the completion of the loop body.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-iteratorclose
+fn close_iterator(iterator: &IteratorRecord, context: &mut Context) -> Result<()> {
+    let state = context.executor().get_current_state().clone();
+    iterator.close(Ok(Value::undefined()), context)?;
+    context.executor().set_current_state(state);
+    Ok(())
+}
@@ -1,25 +1,31 @@
 //! Iteration nodes
 
+use crate::{
+    builtins::iterable::IteratorRecord,
+    exec::coroutine::ResumeFrame,
+    syntax::ast::node::{declaration::BindingKind, Assign, Node},
+    Context, Result, Value,
+};
+use std::fmt;
+
 pub use self::{
     continue_node::Continue, do_while_loop::DoWhileLoop, for_in_loop::ForInLoop, for_loop::ForLoop,
     for_of_loop::ForOfLoop, while_loop::WhileLoop,
 };
 
+#[cfg(feature = "vm")]
+use crate::vm::{Compiler, Instruction};
+
 #[cfg(test)]
 mod tests;
 
-// Checking labels for break and continue is the same operation for `ForLoop`, `While` and `DoWhile`
+// Checking labels for break and continue is the same operation for every loop
 #[macro_use]
 macro_rules! handle_state_with_labels {
     ($self:ident, $label:ident, $interpreter:ident, $state:tt) => {{
         if let Some(brk_label) = $label {
-            if let Some(stmt_label) = $self.label() {
-                // Break from where we are, keeping "continue" set as the state
-                if stmt_label != brk_label.as_ref() {
-                    break;
-                }
-            } else {
-                // if a label is set but the current block has no label, break
+            // Break from where we are, keeping the state set, if the label is not one of ours
+            if !$self.labels().iter().any(|label| label == brk_label) {
                 break;
             }
         }
@@ -36,3 +42,151 @@ pub mod for_in_loop;
 pub mod for_loop;
 pub mod for_of_loop;
 pub mod while_loop;
+
+/// Writes the labels of a loop before it, like `outer: `.
+fn display_labels(f: &mut fmt::Formatter<'_>, labels: &[Box<str>]) -> fmt::Result {
+    for label in labels {
+        write!(f, "{}: ", label)?;
+    }
+    Ok(())
+}
+
+/// Binds the value of the current iteration to the variable in the head of a `for...in` or
+/// `for...of` loop.
+/// Gets the next value of the iterator of a loop, or `None` once the iterator is done.
+///
+/// The value of the iteration the loop was suspended in is handed back by its frame.
+fn next_loop_value(
+    iterator: &IteratorRecord,
+    frame: &mut ResumeFrame,
+    context: &mut Context,
+) -> Result<Option<Value>> {
+    if let Some(value) = frame.replay() {
+        return Ok(Some(value));
+    }
+    let iterator_result = iterator.next(context)?;
+    if iterator_result.is_done() {
+        return Ok(None);
+    }
+    let value = iterator_result.value();
+    frame.save(value.clone());
+    Ok(Some(value))
+}
+
+fn bind_loop_variable(
+    variable: &Node,
+    value: Value,
+    loop_name: &str,
+    context: &mut Context,
+) -> Result<()> {
+    let (list, kind) = match variable {
+        Node::Identifier(_)
+        | Node::GetConstField(_)
+        | Node::GetField(_)
+        | Node::GetPrivateField(_)
+        | Node::DeclarationPattern(_) => return Assign::assign_to(variable, value, context),
+        Node::VarDeclList(ref list) => (list, BindingKind::Var),
+        Node::LetDeclList(ref list) => (list, BindingKind::Let),
+        Node::ConstDeclList(ref list) => (list, BindingKind::Const),
+        Node::Assign(_) => {
+            return Err(context.construct_syntax_error(format!(
+                "a declaration in the head of a {} loop can't have an initializer",
+                loop_name
+            )))
+        }
+        _ => {
+            return Err(context.construct_syntax_error(format!(
+                "unknown left hand side in head of {} loop",
+                loop_name
+            )))
+        }
+    };
+
+    match list.as_ref() {
+        [var] if var.init().is_some() => Err(context.construct_syntax_error(format!(
+            "a declaration in the head of a {} loop can't have an initializer",
+            loop_name
+        ))),
+        [var] => var.target().bind(value, kind, context),
+        _ => Err(context.construct_syntax_error(format!(
+            "only one variable can be declared in the head of a {} loop",
+            loop_name
+        ))),
+    }
+}
+
+/// Returns true if the variable in the head of a `for...in` or `for...of` loop can be compiled
+/// by [`compile_loop_variable`].
+///
+/// Other variables are invalid, so their loop is evaluated by the interpreter, which throws the
+/// error.
+#[cfg(feature = "vm")]
+fn is_compilable_loop_variable(variable: &Node) -> bool {
+    match variable {
+        Node::Identifier(_)
+        | Node::GetConstField(_)
+        | Node::GetField(_)
+        | Node::DeclarationPattern(_) => true,
+        Node::VarDeclList(ref list)
+        | Node::LetDeclList(ref list)
+        | Node::ConstDeclList(ref list) => matches!(list.as_ref(), [var] if var.init().is_none()),
+        _ => false,
+    }
+}
+
+/// Compiles the binding of the value on top of the stack to the variable in the head of a
+/// `for...in` or `for...of` loop, popping the value.
+#[cfg(feature = "vm")]
+fn compile_loop_variable(variable: &Node, compiler: &mut Compiler) {
+    use crate::{syntax::ast::node::BindingTarget, vm::compilation::CodeGen};
+
+    match variable {
+        Node::Identifier(ref name) => {
+            compiler.add_name_instruction(name.as_ref(), Instruction::SetName);
+        }
+        Node::GetConstField(ref get_const_field) => {
+            get_const_field.obj().compile(compiler);
+            compiler.add_instruction(Instruction::Swap);
+            compiler.add_name_instruction(get_const_field.field(), Instruction::SetPropertyByName);
+        }
+        Node::GetField(ref get_field) => {
+            get_field.obj().compile(compiler);
+            get_field.field().compile(compiler);
+            compiler.add_instruction(Instruction::RotateUp(3));
+            compiler.add_instruction(Instruction::SetPropertyByValue);
+        }
+        Node::DeclarationPattern(ref pattern) => {
+            return compiler.add_pattern_instruction(pattern, Instruction::BindAssign);
+        }
+        Node::VarDeclList(ref list)
+        | Node::LetDeclList(ref list)
+        | Node::ConstDeclList(ref list) => match list.as_ref()[0].target() {
+            BindingTarget::Identifier(name) => {
+                let def = match variable {
+                    Node::VarDeclList(_) => Instruction::DefVar,
+                    Node::LetDeclList(_) => Instruction::DefLet,
+                    _ => Instruction::DefConst,
+                };
+                compiler.add_name_instruction(name.as_ref(), def);
+                if let Node::VarDeclList(_) = variable {
+                    compiler.add_name_instruction(name.as_ref(), Instruction::SetName);
+                } else {
+                    compiler.add_name_instruction(name.as_ref(), Instruction::InitLexical);
+                }
+            }
+            BindingTarget::Pattern(pattern) => {
+                let bind = match variable {
+                    Node::VarDeclList(_) => Instruction::BindVar,
+                    Node::LetDeclList(_) => Instruction::BindLet,
+                    _ => Instruction::BindConst,
+                };
+                return compiler.add_pattern_instruction(pattern, bind);
+            }
+            BindingTarget::Property(_) => {
+                unreachable!("property references are only assignment targets")
+            }
+        },
+        _ => unreachable!("the loop variable is not compilable"),
+    }
+    compiler.add_instruction(Instruction::Pop);
+}
@@ -242,7 +242,7 @@ fn for_of_loop_let() {
         }
     "#
         ),
-        "\"ReferenceError: i is not defined\""
+        "\"ReferenceError: i is not defined at line 3, col 13\""
     );
 }
 
@@ -268,7 +268,7 @@ fn for_of_loop_const() {
         }
     "#
         ),
-        "\"ReferenceError: i is not defined\""
+        "\"ReferenceError: i is not defined at line 3, col 13\""
     );
 }
 
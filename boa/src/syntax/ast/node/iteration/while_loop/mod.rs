@@ -1,14 +1,18 @@
+use super::display_labels;
 use crate::{
     exec::{Executable, InterpreterState},
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::node::{Node, NodeSpan},
     Context, Result, Value,
 };
-use std::fmt;
+use std::{fmt, iter};
 
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "vm")]
+use crate::vm::{compilation::CodeGen, Compiler, Instruction};
+
 /// The `while` statement creates a loop that executes a specified statement as long as the
 /// test condition evaluates to `true`.
 ///
@@ -25,7 +29,8 @@ use serde::{Deserialize, Serialize};
 pub struct WhileLoop {
     cond: Box<Node>,
     expr: Box<Node>,
-    label: Option<Box<str>>,
+    labels: Box<[Box<str>]>,
+    span: NodeSpan,
 }
 
 impl WhileLoop {
@@ -37,12 +42,16 @@ impl WhileLoop {
         &self.expr
     }
 
-    pub fn label(&self) -> Option<&str> {
-        self.label.as_ref().map(Box::as_ref)
+    /// Gets the labels of the loop, from the outermost one.
+    pub fn labels(&self) -> &[Box<str>] {
+        &self.labels
     }
 
-    pub fn set_label(&mut self, label: Box<str>) {
-        self.label = Some(label);
+    /// Adds a label to the loop, outside of the labels it already has.
+    pub fn add_label(&mut self, label: Box<str>) {
+        self.labels = iter::once(label)
+            .chain(self.labels.iter().cloned())
+            .collect();
     }
 
     /// Creates a `WhileLoop` AST node.
@@ -54,7 +63,8 @@ impl WhileLoop {
         Self {
             cond: Box::new(condition.into()),
             expr: Box::new(body.into()),
-            label: None,
+            labels: Box::new([]),
+            span: NodeSpan::default(),
         }
     }
 
@@ -63,33 +73,60 @@ impl WhileLoop {
         f: &mut fmt::Formatter<'_>,
         indentation: usize,
     ) -> fmt::Result {
+        display_labels(f, self.labels())?;
         write!(f, "while ({}) ", self.cond())?;
         self.expr().display(f, indentation)
     }
 }
 
+impl_span!(WhileLoop);
+
 impl Executable for WhileLoop {
     fn run(&self, context: &mut Context) -> Result<Value> {
-        let mut result = Value::undefined();
-        while self.cond().run(context)?.to_boolean() {
-            result = self.expr().run(context)?;
-            match context.executor().get_current_state() {
-                InterpreterState::Break(label) => {
-                    handle_state_with_labels!(self, label, context, break);
+        context.resumable(|frame, context| {
+            let mut result = Value::undefined();
+            let checkpoint = frame.checkpoint();
+            loop {
+                frame.restart(checkpoint);
+                let previous = result;
+                result = frame.eval(context, |_| Ok(previous))?;
+                if !frame.run(self.cond(), context)?.to_boolean() {
                     break;
                 }
-                InterpreterState::Continue(label) => {
-                    handle_state_with_labels!(self, label, context, continue)
-                }
-                InterpreterState::Return => {
-                    return Ok(result);
-                }
-                InterpreterState::Executing => {
-                    // Continue execution.
+
+                result = self.expr().run(context)?;
+                match context.executor().get_current_state() {
+                    InterpreterState::Break(label) => {
+                        handle_state_with_labels!(self, label, context, break);
+                        break;
+                    }
+                    InterpreterState::Continue(label) => {
+                        handle_state_with_labels!(self, label, context, continue)
+                    }
+                    InterpreterState::Return => {
+                        return Ok(result);
+                    }
+                    InterpreterState::Executing => {
+                        // Continue execution.
+                    }
                 }
             }
-        }
-        Ok(result)
+            Ok(result)
+        })
+    }
+}
+
+#[cfg(feature = "vm")]
+impl CodeGen for WhileLoop {
+    fn compile(&self, compiler: &mut Compiler) {
+        compiler.push_jumpable(self.labels(), true);
+        let start = compiler.next_address();
+        self.cond().compile(compiler);
+        let exit = compiler.jump(Instruction::JumpIfFalse);
+        compiler.compile_statement(self.expr());
+        compiler.add_instruction(Instruction::Jump(start));
+        compiler.patch_jump(exit);
+        compiler.pop_jumpable(start);
     }
 }
 
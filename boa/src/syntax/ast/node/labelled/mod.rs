@@ -0,0 +1,114 @@
+use super::Node;
+use crate::{
+    exec::{Executable, InterpreterState},
+    gc::{Finalize, Trace},
+    syntax::ast::node::NodeSpan,
+    BoaProfiler, Context, Result, Value,
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "vm")]
+use crate::vm::{compilation::CodeGen, Compiler};
+
+#[cfg(test)]
+mod tests;
+
+/// A labelled statement is a statement prefixed with a label, which `break` statements can
+/// refer to in order to leave it.
+///
+/// Loops keep their labels themselves, since `continue` statements can also refer to them, so
+/// this node only holds the labelled statements that are not loops.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-LabelledStatement
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/label
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct Labelled {
+    label: Box<str>,
+    item: Box<Node>,
+    span: NodeSpan,
+}
+
+impl Labelled {
+    /// Creates a `Labelled` AST node.
+    pub fn new<L, I>(label: L, item: I) -> Self
+    where
+        L: Into<Box<str>>,
+        I: Into<Node>,
+    {
+        Self {
+            label: label.into(),
+            item: Box::new(item.into()),
+            span: NodeSpan::default(),
+        }
+    }
+
+    /// Gets the label of the statement.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Gets the labelled statement.
+    pub fn item(&self) -> &Node {
+        &self.item
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        write!(f, "{}: ", self.label)?;
+        self.item.display(f, indentation)
+    }
+}
+
+impl_span!(Labelled);
+
+impl Executable for Labelled {
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("Labelled", "exec");
+        let result = self.item.run(context)?;
+
+        // A `break` with our label stops here, any other one keeps leaving the statements.
+        if let InterpreterState::Break(Some(label)) = context.executor().get_current_state() {
+            if *label == self.label {
+                context
+                    .executor()
+                    .set_current_state(InterpreterState::Executing);
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(feature = "vm")]
+impl CodeGen for Labelled {
+    fn compile(&self, compiler: &mut Compiler) {
+        let _timer = BoaProfiler::global().start_event("Labelled", "codeGen");
+        compiler.push_label(self.label());
+        compiler.compile_statement(self.item());
+        compiler.pop_label();
+    }
+}
+
+impl fmt::Display for Labelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<Labelled> for Node {
+    fn from(labelled: Labelled) -> Self {
+        Self::Labelled(labelled)
+    }
+}
@@ -0,0 +1,121 @@
+use crate::exec;
+
+#[test]
+fn break_labelled_block() {
+    let scenario = r#"
+        let result = [];
+        block: {
+            result.push(1);
+            if (true) {
+                break block;
+            }
+            result.push(2);
+        }
+        result.push(3);
+        result.join()
+    "#;
+    assert_eq!(&exec(scenario), r#""1,3""#);
+}
+
+#[test]
+fn break_labelled_statement_from_loop() {
+    let scenario = r#"
+        let result = [];
+        outer: if (true) {
+            for (let i = 0; i < 3; i++) {
+                result.push(i);
+                if (i == 1) {
+                    break outer;
+                }
+            }
+            result.push("unreachable");
+        }
+        result.join()
+    "#;
+    assert_eq!(&exec(scenario), r#""0,1""#);
+}
+
+#[test]
+fn continue_outer_loop() {
+    let scenario = r#"
+        let result = [];
+        outer: for (let i = 0; i < 3; i++) {
+            for (let j = 0; j < 3; j++) {
+                if (j == 1) {
+                    continue outer;
+                }
+                result.push(i + "" + j);
+            }
+        }
+        result.join()
+    "#;
+    assert_eq!(&exec(scenario), r#""00,10,20""#);
+}
+
+#[test]
+fn loop_with_several_labels() {
+    let scenario = r#"
+        let result = [];
+        let i = 0;
+        first: second: while (i < 4) {
+            i++;
+            if (i == 2) {
+                continue first;
+            }
+            if (i == 3) {
+                continue second;
+            }
+            result.push(i);
+        }
+        result.join()
+    "#;
+    assert_eq!(&exec(scenario), r#""1,4""#);
+}
+
+#[test]
+fn labels_through_switch() {
+    let scenario = r#"
+        let result = [];
+        outer: for (let i of [1, 2, 3]) {
+            switch (i) {
+                case 1:
+                    continue outer;
+                case 2:
+                    result.push("two");
+                    break;
+                default:
+                    break outer;
+            }
+            result.push(i);
+        }
+        sw: switch (1) {
+            case 1:
+                result.push("one");
+                break sw;
+            case 2:
+                result.push("unreachable");
+        }
+        switch (0) {
+            default:
+                break;
+        }
+        result.push("end");
+        result.join()
+    "#;
+    assert_eq!(&exec(scenario), r#""two,2,one,end""#);
+}
+
+#[test]
+fn break_labelled_try() {
+    let scenario = r#"
+        let result = [];
+        outer: try {
+            result.push("try");
+            break outer;
+        } finally {
+            result.push("finally");
+        }
+        result.join()
+    "#;
+    assert_eq!(&exec(scenario), r#""try,finally""#);
+}
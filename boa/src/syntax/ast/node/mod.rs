@@ -1,5 +1,24 @@
 //! This module implements the `Node` structure, which composes the AST.
 
+// Every node parsed from the source code keeps its span in a `span: NodeSpan` field.
+macro_rules! impl_span {
+    ($node:ty) => {
+        impl $node {
+            /// Gets the span of the node in the source code, if it was parsed.
+            #[inline]
+            pub fn span(&self) -> Option<$crate::syntax::ast::Span> {
+                self.span.get()
+            }
+
+            /// Sets the span of the node in the source code.
+            #[inline]
+            pub(in crate::syntax) fn set_span(&mut self, span: $crate::syntax::ast::Span) {
+                self.span.set(span)
+            }
+        }
+    };
+}
+
 pub mod array;
 pub mod await_expr;
 pub mod block;
@@ -10,16 +29,22 @@ pub mod declaration;
 pub mod field;
 pub mod identifier;
 pub mod iteration;
+pub mod labelled;
+pub mod module;
 pub mod new;
 pub mod object;
 pub mod operator;
+pub mod optional;
 pub mod return_smt;
 pub mod spread;
 pub mod statement_list;
+pub mod super_call;
 pub mod switch;
 pub mod template;
 pub mod throw;
 pub mod try_node;
+pub mod with;
+pub mod yield_expr;
 
 pub use self::{
     array::ArrayDecl,
@@ -29,32 +54,42 @@ pub use self::{
     call::Call,
     conditional::{ConditionalOp, If},
     declaration::{
-        ArrowFunctionDecl, AsyncFunctionDecl, AsyncFunctionExpr, Declaration, DeclarationList,
-        FunctionDecl, FunctionExpr,
+        ArrayPattern, ArrowFunctionDecl, AsyncFunctionDecl, AsyncFunctionExpr, BindingElement,
+        BindingProperty, BindingTarget, Class, ClassElement, Declaration, DeclarationList,
+        DeclarationPattern, FunctionDecl, FunctionExpr, GeneratorDecl, GeneratorExpr,
+        ObjectPattern,
     },
-    field::{GetConstField, GetField},
+    field::{GetConstField, GetField, GetPrivateField, GetSuperField},
     identifier::Identifier,
     iteration::{Continue, DoWhileLoop, ForInLoop, ForLoop, ForOfLoop, WhileLoop},
+    labelled::Labelled,
+    module::{ExportDecl, ExportSpecifier, ImportDecl, ImportSpecifier, Module, ModuleItem},
     new::New,
     object::Object,
     operator::{Assign, BinOp, UnaryOp},
+    optional::{OptionalChain, OptionalOperation, OptionalOperationKind},
     return_smt::Return,
     spread::Spread,
     statement_list::{RcStatementList, StatementList},
+    super_call::SuperCall,
     switch::{Case, Switch},
     template::{TaggedTemplate, TemplateLit},
     throw::Throw,
     try_node::{Catch, Finally, Try},
+    with::With,
+    yield_expr::YieldExpr,
 };
-use super::Const;
+use super::{Const, Span};
 use crate::{
     exec::Executable,
     gc::{empty_trace, Finalize, Trace},
+    property::PropertyKey,
     BoaProfiler, Context, Result, Value,
 };
 use std::{
     cmp::Ordering,
     fmt::{self, Display},
+    rc::Rc,
 };
 
 #[cfg(feature = "deser")]
@@ -93,6 +128,12 @@ pub enum Node {
     /// A function call. [More information](./expression/struct.Call.html).
     Call(Call),
 
+    /// A class declaration node. [More information](./declaration/struct.Class.html).
+    ClassDecl(Class),
+
+    /// A class expression node. [More information](./declaration/struct.Class.html).
+    ClassExpr(Class),
+
     /// A javascript conditional operand ( x ? y : z ). [More information](./conditional/struct.ConditionalOp.html).
     ConditionalOp(ConditionalOp),
 
@@ -114,6 +155,9 @@ pub enum Node {
     /// A continue statement. [More information](./iteration/struct.Continue.html).
     Continue(Continue),
 
+    /// A destructuring pattern used as the target of an assignment. [More information](./declaration/enum.DeclarationPattern.html).
+    DeclarationPattern(DeclarationPattern),
+
     /// A do ... while statement. [More information](./iteration/struct.DoWhileLoop.html).
     DoWhileLoop(DoWhileLoop),
 
@@ -129,6 +173,18 @@ pub enum Node {
     /// Provides access to object fields. [More information](./declaration/struct.GetField.html).
     GetField(GetField),
 
+    /// Provides access to the private elements of an object. [More information](./field/struct.GetPrivateField.html).
+    GetPrivateField(GetPrivateField),
+
+    /// A generator function declaration node. [More information](./declaration/struct.GeneratorDecl.html).
+    GeneratorDecl(GeneratorDecl),
+
+    /// A generator function expression node. [More information](./declaration/struct.GeneratorExpr.html).
+    GeneratorExpr(GeneratorExpr),
+
+    /// Provides access to the fields of the parent class. [More information](./field/struct.GetSuperField.html).
+    GetSuperField(GetSuperField),
+
     /// A `for` statement. [More information](./iteration/struct.ForLoop.html).
     ForLoop(ForLoop),
 
@@ -141,6 +197,9 @@ pub enum Node {
     /// An 'if' statement. [More information](./conditional/struct.If.html).
     If(If),
 
+    /// A labelled statement. [More information](./labelled/struct.Labelled.html).
+    Labelled(Labelled),
+
     /// A `let` declaration list. [More information](./declaration/enum.DeclarationList.html#variant.Let).
     LetDeclList(DeclarationList),
 
@@ -153,9 +212,15 @@ pub enum Node {
     /// An object. [More information](./object/struct.Object.html).
     Object(Object),
 
+    /// An optional chain. [More information](./optional/struct.OptionalChain.html).
+    OptionalChain(OptionalChain),
+
     /// A return statement. [More information](./object/struct.Return.html).
     Return(Return),
 
+    /// A call to the parent class constructor. [More information](./super_call/struct.SuperCall.html).
+    SuperCall(SuperCall),
+
     /// A switch {case} statement. [More information](./switch/struct.Switch.html).
     Switch(Switch),
 
@@ -197,6 +262,12 @@ pub enum Node {
     /// A 'while {...}' node. [More information](./iteration/struct.WhileLoop.html).
     WhileLoop(WhileLoop),
 
+    /// A `with` statement. [More information](./with/struct.With.html).
+    With(With),
+
+    /// A yield expression node. [More information](./yield_expr/struct.YieldExpr.html).
+    YieldExpr(YieldExpr),
+
     /// A empty node.
     ///
     /// Empty statement do nothing, just return undefined.
@@ -225,12 +296,17 @@ impl From<Const> for Node {
 impl Node {
     /// Returns a node ordering based on the hoistability of each node.
     pub(crate) fn hoistable_order(a: &Node, b: &Node) -> Ordering {
-        match (a, b) {
-            (Node::FunctionDecl(_), Node::FunctionDecl(_)) => Ordering::Equal,
-            (_, Node::FunctionDecl(_)) => Ordering::Greater,
-            (Node::FunctionDecl(_), _) => Ordering::Less,
-
-            (_, _) => Ordering::Equal,
+        let is_function_decl = |node: &Node| {
+            matches!(
+                node,
+                Node::FunctionDecl(_) | Node::AsyncFunctionDecl(_) | Node::GeneratorDecl(_)
+            )
+        };
+
+        match (is_function_decl(a), is_function_decl(b)) {
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            _ => Ordering::Equal,
         }
     }
 
@@ -239,6 +315,139 @@ impl Node {
         Self::This
     }
 
+    /// Gets the span of the node in the source code, if it was parsed.
+    ///
+    /// Literals, `this` and empty statements have no span.
+    pub fn span(&self) -> Option<Span> {
+        match *self {
+            Self::ArrayDecl(ref node) => node.span(),
+            Self::ArrowFunctionDecl(ref node) => node.span(),
+            Self::Assign(ref node) => node.span(),
+            Self::AsyncFunctionDecl(ref node) => node.span(),
+            Self::AsyncFunctionExpr(ref node) => node.span(),
+            Self::AwaitExpr(ref node) => node.span(),
+            Self::BinOp(ref node) => node.span(),
+            Self::Block(ref node) => node.span(),
+            Self::Break(ref node) => node.span(),
+            Self::Call(ref node) => node.span(),
+            Self::ClassDecl(ref node) | Self::ClassExpr(ref node) => node.span(),
+            Self::ConditionalOp(ref node) => node.span(),
+            Self::Continue(ref node) => node.span(),
+            Self::DeclarationPattern(ref node) => node.span(),
+            Self::DoWhileLoop(ref node) => node.span(),
+            Self::FunctionDecl(ref node) => node.span(),
+            Self::FunctionExpr(ref node) => node.span(),
+            Self::GetConstField(ref node) => node.span(),
+            Self::GetField(ref node) => node.span(),
+            Self::GetPrivateField(ref node) => node.span(),
+            Self::GeneratorDecl(ref node) => node.span(),
+            Self::GeneratorExpr(ref node) => node.span(),
+            Self::GetSuperField(ref node) => node.span(),
+            Self::ForLoop(ref node) => node.span(),
+            Self::ForInLoop(ref node) => node.span(),
+            Self::ForOfLoop(ref node) => node.span(),
+            Self::If(ref node) => node.span(),
+            Self::Labelled(ref node) => node.span(),
+            Self::Identifier(ref node) => node.span(),
+            Self::New(ref node) => node.span(),
+            Self::Object(ref node) => node.span(),
+            Self::OptionalChain(ref node) => node.span(),
+            Self::Return(ref node) => node.span(),
+            Self::SuperCall(ref node) => node.span(),
+            Self::Switch(ref node) => node.span(),
+            Self::Spread(ref node) => node.span(),
+            Self::TaggedTemplate(ref node) => node.span(),
+            Self::TemplateLit(ref node) => node.span(),
+            Self::Throw(ref node) => node.span(),
+            Self::Try(ref node) => node.span(),
+            Self::UnaryOp(ref node) => node.span(),
+            Self::WhileLoop(ref node) => node.span(),
+            Self::With(ref node) => node.span(),
+            Self::YieldExpr(ref node) => node.span(),
+            Self::LetDeclList(ref list)
+            | Self::ConstDeclList(ref list)
+            | Self::VarDeclList(ref list) => list.span(),
+            Self::Const(_) | Self::This | Self::Empty => None,
+        }
+    }
+
+    /// Sets the span of the node in the source code.
+    ///
+    /// The span of declaration lists comes from their declarations, and literals, `this` and
+    /// empty statements have no span, so it is not set for them.
+    pub(in crate::syntax) fn set_span(&mut self, span: Span) {
+        match *self {
+            Self::ArrayDecl(ref mut node) => node.set_span(span),
+            Self::ArrowFunctionDecl(ref mut node) => node.set_span(span),
+            Self::Assign(ref mut node) => node.set_span(span),
+            Self::AsyncFunctionDecl(ref mut node) => node.set_span(span),
+            Self::AsyncFunctionExpr(ref mut node) => node.set_span(span),
+            Self::AwaitExpr(ref mut node) => node.set_span(span),
+            Self::BinOp(ref mut node) => node.set_span(span),
+            Self::Block(ref mut node) => node.set_span(span),
+            Self::Break(ref mut node) => node.set_span(span),
+            Self::Call(ref mut node) => node.set_span(span),
+            Self::ClassDecl(ref mut node) | Self::ClassExpr(ref mut node) => node.set_span(span),
+            Self::ConditionalOp(ref mut node) => node.set_span(span),
+            Self::Continue(ref mut node) => node.set_span(span),
+            Self::DeclarationPattern(ref mut node) => node.set_span(span),
+            Self::DoWhileLoop(ref mut node) => node.set_span(span),
+            Self::FunctionDecl(ref mut node) => node.set_span(span),
+            Self::FunctionExpr(ref mut node) => node.set_span(span),
+            Self::GetConstField(ref mut node) => node.set_span(span),
+            Self::GetField(ref mut node) => node.set_span(span),
+            Self::GetPrivateField(ref mut node) => node.set_span(span),
+            Self::GeneratorDecl(ref mut node) => node.set_span(span),
+            Self::GeneratorExpr(ref mut node) => node.set_span(span),
+            Self::GetSuperField(ref mut node) => node.set_span(span),
+            Self::ForLoop(ref mut node) => node.set_span(span),
+            Self::ForInLoop(ref mut node) => node.set_span(span),
+            Self::ForOfLoop(ref mut node) => node.set_span(span),
+            Self::If(ref mut node) => node.set_span(span),
+            Self::Labelled(ref mut node) => node.set_span(span),
+            Self::Identifier(ref mut node) => node.set_span(span),
+            Self::New(ref mut node) => node.set_span(span),
+            Self::Object(ref mut node) => node.set_span(span),
+            Self::OptionalChain(ref mut node) => node.set_span(span),
+            Self::Return(ref mut node) => node.set_span(span),
+            Self::SuperCall(ref mut node) => node.set_span(span),
+            Self::Switch(ref mut node) => node.set_span(span),
+            Self::Spread(ref mut node) => node.set_span(span),
+            Self::TaggedTemplate(ref mut node) => node.set_span(span),
+            Self::TemplateLit(ref mut node) => node.set_span(span),
+            Self::Throw(ref mut node) => node.set_span(span),
+            Self::Try(ref mut node) => node.set_span(span),
+            Self::UnaryOp(ref mut node) => node.set_span(span),
+            Self::WhileLoop(ref mut node) => node.set_span(span),
+            Self::With(ref mut node) => node.set_span(span),
+            Self::YieldExpr(ref mut node) => node.set_span(span),
+            Self::LetDeclList(_)
+            | Self::ConstDeclList(_)
+            | Self::VarDeclList(_)
+            | Self::Const(_)
+            | Self::This
+            | Self::Empty => {}
+        }
+    }
+
+    /// Gets the source text of the node if it creates a function or a class, so that the parser
+    /// can record it.
+    pub(in crate::syntax) fn source_text_mut(&mut self) -> Option<&mut NodeSourceText> {
+        match *self {
+            Self::ArrowFunctionDecl(ref mut node) => Some(node.node_source_text_mut()),
+            Self::AsyncFunctionDecl(ref mut node) => Some(node.node_source_text_mut()),
+            Self::AsyncFunctionExpr(ref mut node) => Some(node.node_source_text_mut()),
+            Self::ClassDecl(ref mut node) | Self::ClassExpr(ref mut node) => {
+                Some(node.node_source_text_mut())
+            }
+            Self::FunctionDecl(ref mut node) => Some(node.node_source_text_mut()),
+            Self::FunctionExpr(ref mut node) => Some(node.node_source_text_mut()),
+            Self::GeneratorDecl(ref mut node) => Some(node.node_source_text_mut()),
+            Self::GeneratorExpr(ref mut node) => Some(node.node_source_text_mut()),
+            _ => None,
+        }
+    }
+
     /// Implements the display formatting with indentation.
     fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
         let indent = "    ".repeat(indentation);
@@ -264,15 +473,27 @@ impl Node {
             Self::New(ref expr) => Display::fmt(expr, f),
             Self::GetConstField(ref get_const_field) => Display::fmt(get_const_field, f),
             Self::GetField(ref get_field) => Display::fmt(get_field, f),
+            Self::GetPrivateField(ref get_private_field) => Display::fmt(get_private_field, f),
+            Self::OptionalChain(ref chain) => Display::fmt(chain, f),
+            Self::GetSuperField(ref get_super_field) => Display::fmt(get_super_field, f),
+            Self::SuperCall(ref call) => Display::fmt(call, f),
+            Self::ClassDecl(ref class) | Self::ClassExpr(ref class) => {
+                class.display(f, indentation)
+            }
             Self::WhileLoop(ref while_loop) => while_loop.display(f, indentation),
             Self::DoWhileLoop(ref do_while) => do_while.display(f, indentation),
+            Self::With(ref with) => with.display(f, indentation),
             Self::If(ref if_smt) => if_smt.display(f, indentation),
+            Self::Labelled(ref labelled) => labelled.display(f, indentation),
             Self::Switch(ref switch) => switch.display(f, indentation),
             Self::Object(ref obj) => obj.display(f, indentation),
             Self::ArrayDecl(ref arr) => Display::fmt(arr, f),
+            Self::DeclarationPattern(ref pattern) => Display::fmt(pattern, f),
             Self::VarDeclList(ref list) => Display::fmt(list, f),
             Self::FunctionDecl(ref decl) => decl.display(f, indentation),
             Self::FunctionExpr(ref expr) => expr.display(f, indentation),
+            Self::GeneratorDecl(ref decl) => decl.display(f, indentation),
+            Self::GeneratorExpr(ref expr) => expr.display(f, indentation),
             Self::ArrowFunctionDecl(ref decl) => decl.display(f, indentation),
             Self::BinOp(ref op) => Display::fmt(op, f),
             Self::UnaryOp(ref op) => Display::fmt(op, f),
@@ -286,6 +507,7 @@ impl Node {
             Self::AsyncFunctionDecl(ref decl) => decl.display(f, indentation),
             Self::AsyncFunctionExpr(ref expr) => expr.display(f, indentation),
             Self::AwaitExpr(ref expr) => expr.display(f, indentation),
+            Self::YieldExpr(ref expr) => expr.display(f, indentation),
             Self::Empty => write!(f, ";"),
         }
     }
@@ -294,6 +516,24 @@ impl Node {
 impl Executable for Node {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("Executable", "exec");
+
+        // Keep track of the node being evaluated, for the stack traces and error messages.
+        let span = self.span();
+        if let Some(span) = span {
+            context.executor().call_stack.enter(span);
+        }
+        let result = self.run_node(context);
+        if span.is_some() {
+            context.executor().call_stack.exit();
+        }
+
+        result
+    }
+}
+
+impl Node {
+    /// Evaluates the node.
+    fn run_node(&self, context: &mut Context) -> Result<Value> {
         match *self {
             Node::AsyncFunctionDecl(ref decl) => decl.run(context),
             Node::AsyncFunctionExpr(ref function_expr) => function_expr.run(context),
@@ -313,12 +553,20 @@ impl Executable for Node {
             Node::Identifier(ref identifier) => identifier.run(context),
             Node::GetConstField(ref get_const_field_node) => get_const_field_node.run(context),
             Node::GetField(ref get_field) => get_field.run(context),
+            Node::GetPrivateField(ref get_private_field) => get_private_field.run(context),
+            Node::OptionalChain(ref chain) => chain.run(context),
+            Node::GetSuperField(ref get_super_field) => get_super_field.run(context),
+            Node::SuperCall(ref call) => call.run(context),
+            Node::ClassDecl(ref class) => class.run_declaration(context),
+            Node::ClassExpr(ref class) => class.create(context),
             Node::WhileLoop(ref while_loop) => while_loop.run(context),
             Node::DoWhileLoop(ref do_while) => do_while.run(context),
+            Node::With(ref with) => with.run(context),
             Node::ForLoop(ref for_loop) => for_loop.run(context),
             Node::ForOfLoop(ref for_of_loop) => for_of_loop.run(context),
             Node::ForInLoop(ref for_in_loop) => for_in_loop.run(context),
             Node::If(ref if_smt) => if_smt.run(context),
+            Node::Labelled(ref labelled) => labelled.run(context),
             Node::ConditionalOp(ref op) => op.run(context),
             Node::Switch(ref switch) => switch.run(context),
             Node::Object(ref obj) => obj.run(context),
@@ -327,6 +575,8 @@ impl Executable for Node {
             Node::FunctionDecl(ref decl) => decl.run(context),
             // <https://tc39.es/ecma262/#sec-createdynamicfunction>
             Node::FunctionExpr(ref function_expr) => function_expr.run(context),
+            Node::GeneratorDecl(ref decl) => decl.run(context),
+            Node::GeneratorExpr(ref expr) => expr.run(context),
             Node::ArrowFunctionDecl(ref decl) => decl.run(context),
             Node::BinOp(ref op) => op.run(context),
             Node::UnaryOp(ref op) => op.run(context),
@@ -347,11 +597,107 @@ impl Executable for Node {
             Node::Try(ref try_node) => try_node.run(context),
             Node::Break(ref break_node) => break_node.run(context),
             Node::Continue(ref continue_node) => continue_node.run(context),
+            // Patterns are only valid as assignment targets, where they are bound by `Assign`.
+            Node::DeclarationPattern(_) => {
+                context.throw_syntax_error("invalid destructuring assignment target")
+            }
+            Node::YieldExpr(ref expr) => expr.run(context),
             Node::Empty => Ok(Value::Undefined),
         }
     }
 }
 
+/// The span of a node in the source code.
+///
+/// Nodes created by the parser know the span of the code they were parsed from, while nodes
+/// created by hand have no span. Spans are ignored when comparing nodes, so the same code compares
+/// equal wherever it was written.
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "deser", serde(transparent))]
+#[derive(Clone, Copy, Debug, Default, Finalize)]
+pub(crate) struct NodeSpan(Option<Span>);
+
+impl NodeSpan {
+    /// Gets the span, if any.
+    #[inline]
+    pub(crate) fn get(self) -> Option<Span> {
+        self.0
+    }
+
+    /// Sets the span.
+    #[inline]
+    pub(crate) fn set(&mut self, span: Span) {
+        self.0 = Some(span);
+    }
+}
+
+impl PartialEq for NodeSpan {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+// SAFETY: `NodeSpan` does not contain any garbage collected values.
+unsafe impl Trace for NodeSpan {
+    empty_trace!();
+}
+
+/// The source text of a node that creates a function, as it was written in the source code.
+///
+/// Like spans, the source text is only known for parsed nodes, and it is ignored when comparing
+/// nodes.
+#[derive(Clone, Debug, Default, Finalize)]
+pub(crate) struct NodeSourceText(Option<Rc<str>>);
+
+impl NodeSourceText {
+    /// Gets the source text, if any.
+    #[inline]
+    pub(crate) fn get(&self) -> Option<Rc<str>> {
+        self.0.clone()
+    }
+
+    /// Sets the source text.
+    #[inline]
+    pub(crate) fn set(&mut self, source_text: &str) {
+        self.0 = Some(source_text.into());
+    }
+}
+
+impl PartialEq for NodeSourceText {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+// SAFETY: `NodeSourceText` does not contain any garbage collected values.
+unsafe impl Trace for NodeSourceText {
+    empty_trace!();
+}
+
+/// A node that creates a function, whose source text is returned by
+/// `Function.prototype.toString`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-function.prototype.tostring
+pub(crate) trait FunctionSource: Display {
+    /// Gets the source text the parser recorded for the node.
+    fn node_source_text(&self) -> &NodeSourceText;
+
+    /// Gets a mutable reference to the source text the parser recorded for the node.
+    fn node_source_text_mut(&mut self) -> &mut NodeSourceText;
+
+    /// Gets the source text of the function.
+    ///
+    /// Nodes that were not parsed from source code are printed instead.
+    fn source_text(&self) -> Rc<str> {
+        self.node_source_text()
+            .get()
+            .unwrap_or_else(|| self.to_string().into())
+    }
+}
+
 /// Utility to join multiple Nodes into a single string.
 fn join_nodes<N>(f: &mut fmt::Formatter<'_>, nodes: &[N]) -> fmt::Result
 where
@@ -386,32 +732,40 @@ where
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, PartialEq, Trace, Finalize)]
 pub struct FormalParameter {
-    name: Box<str>,
-    init: Option<Node>,
+    element: BindingElement,
     is_rest_param: bool,
 }
 
 impl FormalParameter {
-    /// Creates a new formal parameter.
-    pub(in crate::syntax) fn new<N>(name: N, init: Option<Node>, is_rest_param: bool) -> Self
+    /// Creates a new formal parameter, binding either a name or a destructuring pattern.
+    pub(in crate::syntax) fn new<T>(target: T, init: Option<Node>, is_rest_param: bool) -> Self
     where
-        N: Into<Box<str>>,
+        T: Into<BindingTarget>,
     {
         Self {
-            name: name.into(),
-            init,
+            element: BindingElement::new(target, init),
             is_rest_param,
         }
     }
 
-    /// Gets the name of the formal parameter.
-    pub fn name(&self) -> &str {
-        &self.name
+    /// Gets the binding element of the formal parameter.
+    pub fn element(&self) -> &BindingElement {
+        &self.element
+    }
+
+    /// Gets the target the parameter is bound to.
+    pub fn target(&self) -> &BindingTarget {
+        self.element.target()
+    }
+
+    /// Gets the names of all the bindings created by the parameter.
+    pub fn names(&self) -> Vec<&str> {
+        self.element.target().names()
     }
 
     /// Gets the initialization node of the formal parameter, if any.
     pub fn init(&self) -> Option<&Node> {
-        self.init.as_ref()
+        self.element.init()
     }
 
     /// Gets wether the parameter is a rest parameter.
@@ -425,11 +779,16 @@ impl Display for FormalParameter {
         if self.is_rest_param {
             write!(f, "...")?;
         }
-        write!(f, "{}", self.name)?;
-        if let Some(n) = self.init.as_ref() {
-            write!(f, " = {}", n)?;
+        Display::fmt(&self.element, f)
+    }
+}
+
+impl From<BindingElement> for FormalParameter {
+    fn from(element: BindingElement) -> Self {
+        Self {
+            element,
+            is_rest_param: false,
         }
-        Ok(())
     }
 }
 
@@ -491,6 +850,17 @@ pub enum PropertyDefinition {
     /// [spec]: https://tc39.es/ecma262/#prod-PropertyDefinition
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Object_initializer#Spread_properties
     SpreadObject(Node),
+
+    /// A shorthand property with an initializer, like `{ a = 1 }`.
+    ///
+    /// This is only valid in an object literal that is reinterpreted as a destructuring
+    /// pattern, where it defines a default value for the binding.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-CoverInitializedName
+    CoverInitializedName(Box<str>, Node),
 }
 
 impl PropertyDefinition {
@@ -526,6 +896,88 @@ impl PropertyDefinition {
     {
         Self::SpreadObject(obj.into())
     }
+
+    /// Creates a `CoverInitializedName` definition.
+    pub fn cover_initialized_name<N, I>(name: N, init: I) -> Self
+    where
+        N: Into<Box<str>>,
+        I: Into<Node>,
+    {
+        Self::CoverInitializedName(name.into(), init.into())
+    }
+}
+
+/// The name of a property defined by a class element.
+///
+/// A property name is either a literal name, like `foo` or `"bar"`, an expression
+/// computed at runtime, like `[Symbol.iterator]`, or a private name, like `#foo`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassElementName
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq, Trace, Finalize)]
+pub enum PropertyName {
+    /// A `LiteralPropertyName`, like an identifier, a string or a number.
+    Literal(Box<str>),
+
+    /// A `ComputedPropertyName`, an expression between brackets.
+    Computed(Node),
+
+    /// A `PrivateIdentifier`, like `#foo`, which names a private element only visible inside
+    /// the class body.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes/Private_class_fields
+    Private(Box<str>),
+}
+
+impl PropertyName {
+    /// Evaluates the property name into a property key.
+    ///
+    /// Private names are not property keys, they are resolved in the private environment of
+    /// the class instead.
+    pub(crate) fn to_property_key(&self, context: &mut Context) -> Result<PropertyKey> {
+        match self {
+            Self::Literal(name) => Ok(name.as_ref().into()),
+            Self::Computed(node) => node.run(context)?.to_property_key(context),
+            Self::Private(name) => {
+                Err(context.construct_syntax_error(format!("unexpected private name {}", name)))
+            }
+        }
+    }
+
+    /// Gets the private name, like `#foo`, if this is a private name.
+    pub fn private_name(&self) -> Option<&str> {
+        match self {
+            Self::Private(name) => Some(name),
+            _ => None,
+        }
+    }
+}
+
+impl Display for PropertyName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Literal(name) | Self::Private(name) => f.write_str(name),
+            Self::Computed(node) => write!(f, "[{}]", node),
+        }
+    }
+}
+
+impl From<&str> for PropertyName {
+    fn from(name: &str) -> Self {
+        Self::Literal(name.into())
+    }
+}
+
+impl From<Node> for PropertyName {
+    fn from(node: Node) -> Self {
+        Self::Computed(node)
+    }
 }
 
 /// Method definition kinds.
@@ -582,7 +1034,26 @@ pub enum MethodDefinitionKind {
     /// [spec]: https://tc39.es/ecma262/#prod-MethodDefinition
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions#Method_definition_syntax
     Ordinary,
-    // TODO: support other method definition kinds, like `Generator`.
+
+    /// A generator method, like `*foo() {}`, defines a method which returns a generator.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-GeneratorMethod
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/Method_definitions#generator_methods
+    Generator,
+
+    /// An async method, like `async foo() {}`, defines a method which returns a promise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-AsyncMethod
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/Method_definitions#async_methods
+    Async,
 }
 
 unsafe impl Trace for MethodDefinitionKind {
@@ -0,0 +1,467 @@
+//! Module nodes.
+
+use crate::{
+    gc::{Finalize, Trace},
+    syntax::ast::node::{Declaration, Node},
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+/// The body of a module, parsed with the `Module` goal symbol.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-Module
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Modules
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct Module {
+    items: Box<[ModuleItem]>,
+}
+
+impl Module {
+    /// Gets the list of items of the module.
+    pub fn items(&self) -> &[ModuleItem] {
+        &self.items
+    }
+
+    /// Gets the specifiers of the modules imported by the module, in source order and without
+    /// duplicates.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-modulerequests
+    pub fn requests(&self) -> Vec<&str> {
+        let mut requests = Vec::new();
+        for item in self.items.iter() {
+            let request = match item {
+                ModuleItem::ImportDecl(import) => Some(import.from()),
+                ModuleItem::ExportDecl(export) => export.from(),
+                ModuleItem::Statement(_) => None,
+            };
+            if let Some(request) = request {
+                if !requests.contains(&request) {
+                    requests.push(request);
+                }
+            }
+        }
+        requests
+    }
+}
+
+impl<T> From<T> for Module
+where
+    T: Into<Box<[ModuleItem]>>,
+{
+    fn from(items: T) -> Self {
+        Self {
+            items: items.into(),
+        }
+    }
+}
+
+impl fmt::Display for Module {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for item in self.items.iter() {
+            match item {
+                ModuleItem::ImportDecl(import) => writeln!(f, "{};", import)?,
+                ModuleItem::ExportDecl(export) => writeln!(f, "{};", export)?,
+                ModuleItem::Statement(node) => writeln!(f, "{};", node)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An item of a module: an import declaration, an export declaration or a statement.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleItem
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum ModuleItem {
+    /// An `import` declaration.
+    ImportDecl(ImportDecl),
+
+    /// An `export` declaration.
+    ExportDecl(ExportDecl),
+
+    /// A statement or a declaration.
+    Statement(Node),
+}
+
+/// The `import` declaration binds the values exported by another module.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ImportDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ImportDecl {
+    default: Option<Box<str>>,
+    namespace: Option<Box<str>>,
+    specifiers: Box<[ImportSpecifier]>,
+    from: Box<str>,
+}
+
+impl ImportDecl {
+    /// Creates a new import declaration.
+    pub(in crate::syntax) fn new<S, F>(
+        default: Option<Box<str>>,
+        namespace: Option<Box<str>>,
+        specifiers: S,
+        from: F,
+    ) -> Self
+    where
+        S: Into<Box<[ImportSpecifier]>>,
+        F: Into<Box<str>>,
+    {
+        Self {
+            default,
+            namespace,
+            specifiers: specifiers.into(),
+            from: from.into(),
+        }
+    }
+
+    /// Gets the binding of the default export, as in `import name from "module"`.
+    pub fn default(&self) -> Option<&str> {
+        self.default.as_deref()
+    }
+
+    /// Gets the binding of the module namespace, as in `import * as name from "module"`.
+    pub fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+
+    /// Gets the named imports, as in `import { a, b as c } from "module"`.
+    pub fn specifiers(&self) -> &[ImportSpecifier] {
+        &self.specifiers
+    }
+
+    /// Gets the specifier of the imported module.
+    pub fn from(&self) -> &str {
+        &self.from
+    }
+
+    /// Gets the names bound by the declaration.
+    pub fn names(&self) -> Vec<&str> {
+        self.default
+            .as_deref()
+            .into_iter()
+            .chain(self.namespace.as_deref())
+            .chain(self.specifiers.iter().map(ImportSpecifier::local))
+            .collect()
+    }
+}
+
+impl fmt::Display for ImportDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("import ")?;
+        let mut clauses = Vec::new();
+        if let Some(default) = &self.default {
+            clauses.push(default.to_string());
+        }
+        if let Some(namespace) = &self.namespace {
+            clauses.push(format!("* as {}", namespace));
+        }
+        if !self.specifiers.is_empty() {
+            let specifiers: Vec<_> = self.specifiers.iter().map(ToString::to_string).collect();
+            clauses.push(format!("{{ {} }}", specifiers.join(", ")));
+        }
+        if !clauses.is_empty() {
+            write!(f, "{} from ", clauses.join(", "))?;
+        }
+        write!(f, "\"{}\"", self.from)
+    }
+}
+
+impl From<ImportDecl> for ModuleItem {
+    fn from(import: ImportDecl) -> Self {
+        Self::ImportDecl(import)
+    }
+}
+
+/// A named import, as in `import { imported as local } from "module"`.
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ImportSpecifier {
+    imported: Box<str>,
+    local: Box<str>,
+}
+
+impl ImportSpecifier {
+    /// Creates a new import specifier.
+    pub(in crate::syntax) fn new<I, L>(imported: I, local: L) -> Self
+    where
+        I: Into<Box<str>>,
+        L: Into<Box<str>>,
+    {
+        Self {
+            imported: imported.into(),
+            local: local.into(),
+        }
+    }
+
+    /// Gets the name exported by the imported module.
+    pub fn imported(&self) -> &str {
+        &self.imported
+    }
+
+    /// Gets the name of the local binding.
+    pub fn local(&self) -> &str {
+        &self.local
+    }
+}
+
+impl fmt::Display for ImportSpecifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.imported == self.local {
+            write!(f, "{}", self.local)
+        } else {
+            write!(f, "{} as {}", self.imported, self.local)
+        }
+    }
+}
+
+/// The `export` declaration exports values from the module.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ExportDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/export
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum ExportDecl {
+    /// A declaration whose bindings are exported with their own names, as in `export let a;`.
+    Declaration(Node),
+
+    /// The default export, as in `export default expression;`.
+    ///
+    /// The node is a function or class declaration if it has a name, and an expression
+    /// otherwise.
+    Default(Node),
+
+    /// Named exports, as in `export { a, b as c };`, or re-exports of another module, as in
+    /// `export { a } from "module";`.
+    Named {
+        specifiers: Box<[ExportSpecifier]>,
+        from: Option<Box<str>>,
+    },
+
+    /// A re-export of all the exports of another module, as in `export * from "module";`, or of
+    /// its namespace, as in `export * as name from "module";`.
+    All {
+        alias: Option<Box<str>>,
+        from: Box<str>,
+    },
+}
+
+impl ExportDecl {
+    /// Gets the specifier of the re-exported module, if any.
+    pub fn from(&self) -> Option<&str> {
+        match self {
+            Self::Named { from, .. } => from.as_deref(),
+            Self::All { from, .. } => Some(from),
+            Self::Declaration(_) | Self::Default(_) => None,
+        }
+    }
+
+    /// Gets the names exported by the declaration.
+    ///
+    /// `export * from "module";` has no name of its own.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-exportednames
+    pub fn exported_names(&self) -> Vec<&str> {
+        match self {
+            Self::Declaration(node) => declared_names(node),
+            Self::Default(_) => vec!["default"],
+            Self::Named { specifiers, .. } => {
+                specifiers.iter().map(ExportSpecifier::exported).collect()
+            }
+            Self::All { alias, .. } => alias.as_deref().into_iter().collect(),
+        }
+    }
+}
+
+impl fmt::Display for ExportDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Declaration(node) => write!(f, "export {}", node),
+            Self::Default(node) => write!(f, "export default {}", node),
+            Self::Named { specifiers, from } => {
+                let specifiers: Vec<_> = specifiers.iter().map(ToString::to_string).collect();
+                write!(f, "export {{ {} }}", specifiers.join(", "))?;
+                if let Some(from) = from {
+                    write!(f, " from \"{}\"", from)?;
+                }
+                Ok(())
+            }
+            Self::All { alias, from } => {
+                f.write_str("export *")?;
+                if let Some(alias) = alias {
+                    write!(f, " as {}", alias)?;
+                }
+                write!(f, " from \"{}\"", from)
+            }
+        }
+    }
+}
+
+impl From<ExportDecl> for ModuleItem {
+    fn from(export: ExportDecl) -> Self {
+        Self::ExportDecl(export)
+    }
+}
+
+/// A named export, as in `export { local as exported };`.
+///
+/// In a re-export, the local name is the name exported by the other module.
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ExportSpecifier {
+    local: Box<str>,
+    exported: Box<str>,
+}
+
+impl ExportSpecifier {
+    /// Creates a new export specifier.
+    pub(in crate::syntax) fn new<L, E>(local: L, exported: E) -> Self
+    where
+        L: Into<Box<str>>,
+        E: Into<Box<str>>,
+    {
+        Self {
+            local: local.into(),
+            exported: exported.into(),
+        }
+    }
+
+    /// Gets the local name.
+    pub fn local(&self) -> &str {
+        &self.local
+    }
+
+    /// Gets the exported name.
+    pub fn exported(&self) -> &str {
+        &self.exported
+    }
+}
+
+impl fmt::Display for ExportSpecifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.local == self.exported {
+            write!(f, "{}", self.local)
+        } else {
+            write!(f, "{} as {}", self.local, self.exported)
+        }
+    }
+}
+
+/// Gets the names bound by a declaration, or by a statement declaring variables.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-static-semantics-boundnames
+pub(crate) fn declared_names(node: &Node) -> Vec<&str> {
+    match node {
+        Node::VarDeclList(list) | Node::LetDeclList(list) | Node::ConstDeclList(list) => {
+            list.as_ref().iter().flat_map(Declaration::names).collect()
+        }
+        Node::FunctionDecl(function) => vec![function.name()],
+        Node::GeneratorDecl(generator) => vec![generator.name()],
+        Node::AsyncFunctionDecl(function) => function.name().into_iter().collect(),
+        Node::ClassDecl(class) => class.name().into_iter().collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Gets the names declared with `var` by a statement, including the ones nested in blocks, loops,
+/// conditionals, labelled, `switch` and `try` statements.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-static-semantics-vardeclarednames
+pub(crate) fn var_declared_names(node: &Node) -> Vec<&str> {
+    let mut names = Vec::new();
+    collect_var_declared_names(node, &mut names);
+    names
+}
+
+fn collect_var_declared_names<'a>(node: &'a Node, names: &mut Vec<&'a str>) {
+    match node {
+        Node::VarDeclList(list) => names.extend(list.as_ref().iter().flat_map(Declaration::names)),
+        Node::Block(block) => {
+            for item in block.items() {
+                collect_var_declared_names(item, names);
+            }
+        }
+        Node::If(if_node) => {
+            collect_var_declared_names(if_node.body(), names);
+            if let Some(else_node) = if_node.else_node() {
+                collect_var_declared_names(else_node, names);
+            }
+        }
+        Node::ForLoop(for_loop) => {
+            if let Some(init) = for_loop.init() {
+                collect_var_declared_names(init, names);
+            }
+            collect_var_declared_names(for_loop.body(), names);
+        }
+        Node::ForInLoop(for_in_loop) => {
+            collect_var_declared_names(for_in_loop.variable(), names);
+            collect_var_declared_names(for_in_loop.body(), names);
+        }
+        Node::ForOfLoop(for_of_loop) => {
+            collect_var_declared_names(for_of_loop.variable(), names);
+            collect_var_declared_names(for_of_loop.body(), names);
+        }
+        Node::WhileLoop(while_loop) => collect_var_declared_names(while_loop.expr(), names),
+        Node::DoWhileLoop(do_while_loop) => collect_var_declared_names(do_while_loop.body(), names),
+        Node::Labelled(labelled) => collect_var_declared_names(labelled.item(), names),
+        Node::With(with) => collect_var_declared_names(with.body(), names),
+        Node::Try(try_node) => {
+            for item in try_node.block().items() {
+                collect_var_declared_names(item, names);
+            }
+            if let Some(catch) = try_node.catch() {
+                for item in catch.block().items() {
+                    collect_var_declared_names(item, names);
+                }
+            }
+            if let Some(finally) = try_node.finally() {
+                for item in finally.items() {
+                    collect_var_declared_names(item, names);
+                }
+            }
+        }
+        Node::Switch(switch) => {
+            for case in switch.cases() {
+                for item in case.body().items() {
+                    collect_var_declared_names(item, names);
+                }
+            }
+            for item in switch.default().into_iter().flatten() {
+                collect_var_declared_names(item, names);
+            }
+        }
+        _ => {}
+    }
+}
@@ -1,8 +1,10 @@
 use crate::{
-    builtins::iterable,
     exec::Executable,
     gc::{Finalize, Trace},
-    syntax::ast::node::{Call, Node},
+    syntax::ast::{
+        node::{call::evaluate_arguments, Call, Node},
+        Span,
+    },
     value::Value,
     BoaProfiler, Context, Result,
 };
@@ -11,6 +13,9 @@ use std::fmt;
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "vm")]
+use crate::vm::{compilation::CodeGen, Compiler, Instruction};
+
 /// The `new` operator lets developers create an instance of a user-defined object type or of
 /// one of the built-in object types that has a constructor function.
 ///
@@ -42,36 +47,46 @@ impl New {
     pub fn args(&self) -> &[Node] {
         &self.call.args()
     }
+
+    /// Gets the span of the expression, which is kept by its call.
+    pub fn span(&self) -> Option<Span> {
+        self.call.span()
+    }
+
+    /// Sets the span of the expression, which is kept by its call.
+    pub(in crate::syntax) fn set_span(&mut self, span: Span) {
+        self.call.set_span(span)
+    }
 }
 
 impl Executable for New {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("New", "exec");
 
-        let func_object = self.expr().run(context)?;
-        let mut v_args = Vec::with_capacity(self.args().len());
-        for arg in self.args() {
-            if let Node::Spread(ref x) = arg {
-                let val = x.run(context)?;
-                let iterator_record = iterable::get_iterator(context, val)?;
-                loop {
-                    let next = iterator_record.next(context)?;
-                    if next.is_done() {
-                        break;
-                    }
-                    let next_value = next.value();
-                    v_args.push(next_value.clone());
+        context.resumable(|frame, context| {
+            let func_object = frame.run(self.expr(), context)?;
+            let v_args = evaluate_arguments(self.args(), frame, context)?;
+
+            match func_object {
+                Value::Object(ref object) => {
+                    object.construct(&v_args, object.clone().into(), context)
                 }
-                break; // after spread we don't accept any new arguments
-            } else {
-                v_args.push(arg.run(context)?);
+                _ => context
+                    .throw_type_error(format!("{} is not a constructor", self.expr().to_string(),)),
             }
-        }
+        })
+    }
+}
 
-        match func_object {
-            Value::Object(ref object) => object.construct(&v_args, object.clone().into(), context),
-            _ => context
-                .throw_type_error(format!("{} is not a constructor", self.expr().to_string(),)),
+#[cfg(feature = "vm")]
+impl CodeGen for New {
+    fn compile(&self, compiler: &mut Compiler) {
+        let _timer = BoaProfiler::global().start_event("New", "codeGen");
+        self.expr().compile(compiler);
+        if compiler.compile_arguments(self.args()) {
+            compiler.add_instruction(Instruction::NewWithArray);
+        } else {
+            compiler.add_instruction(Instruction::New(self.args().len()));
         }
     }
 }
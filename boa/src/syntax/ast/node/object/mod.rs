@@ -1,10 +1,13 @@
 //! Object node.
 
 use crate::{
+    builtins::function::{set_method_name, set_source_text, FunctionFlags},
     exec::Executable,
     gc::{Finalize, Trace},
     property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor},
-    syntax::ast::node::{MethodDefinitionKind, Node, PropertyDefinition},
+    syntax::ast::node::{
+        join_nodes, MethodDefinitionKind, Node, NodeSpan, PropertyDefinition, StatementList,
+    },
     Context, Result, Value,
 };
 use std::fmt;
@@ -12,6 +15,9 @@ use std::fmt;
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "vm")]
+use crate::vm::{compilation::CodeGen, Compiler, Instruction};
+
 /// Objects in JavaScript may be defined as an unordered collection of related data, of
 /// primitive or reference types, in the form of “key: value” pairs.
 ///
@@ -36,6 +42,8 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct Object {
     properties: Box<[PropertyDefinition]>,
+    #[cfg_attr(feature = "deser", serde(skip))]
+    span: NodeSpan,
 }
 
 impl Object {
@@ -50,93 +58,210 @@ impl Object {
         indent: usize,
     ) -> fmt::Result {
         f.write_str("{\n")?;
+        let indentation = "    ".repeat(indent + 1);
         for property in self.properties().iter() {
             match property {
                 PropertyDefinition::IdentifierReference(key) => {
-                    write!(f, "{}    {},", indent, key)?;
+                    writeln!(f, "{}{},", indentation, key)?;
                 }
                 PropertyDefinition::Property(key, value) => {
-                    write!(f, "{}    {}: {},", indent, key, value)?;
+                    writeln!(f, "{}{}: {},", indentation, key, value)?;
                 }
                 PropertyDefinition::SpreadObject(key) => {
-                    write!(f, "{}    ...{},", indent, key)?;
+                    writeln!(f, "{}...{},", indentation, key)?;
+                }
+                PropertyDefinition::CoverInitializedName(key, init) => {
+                    writeln!(f, "{}{} = {},", indentation, key, init)?;
                 }
-                PropertyDefinition::MethodDefinition(_kind, _key, _node) => {
-                    // TODO: Implement display for PropertyDefinition::MethodDefinition.
-                    unimplemented!("Display for PropertyDefinition::MethodDefinition");
+                PropertyDefinition::MethodDefinition(kind, key, node) => {
+                    f.write_str(&indentation)?;
+                    match kind {
+                        MethodDefinitionKind::Get => write!(f, "get ")?,
+                        MethodDefinitionKind::Set => write!(f, "set ")?,
+                        MethodDefinitionKind::Generator => write!(f, "*")?,
+                        MethodDefinitionKind::Async => write!(f, "async ")?,
+                        MethodDefinitionKind::Ordinary => (),
+                    }
+                    write!(f, "{}(", key)?;
+                    join_nodes(f, node.parameters())?;
+                    f.write_str(") {\n")?;
+                    StatementList::from(node.body()).display(f, indent + 2)?;
+                    writeln!(f, "{}}},", indentation)?;
                 }
             }
         }
-        f.write_str("}")
+        write!(f, "{}}}", "    ".repeat(indent))
     }
 }
 
+impl_span!(Object);
+
 impl Executable for Object {
     fn run(&self, context: &mut Context) -> Result<Value> {
-        let obj = Value::new_object(context);
+        context.resumable(|frame, context| {
+            let obj = frame.eval(context, |context| Ok(Value::new_object(context)))?;
 
-        // TODO: Implement the rest of the property types.
-        for property in self.properties().iter() {
-            match property {
-                PropertyDefinition::Property(key, value) => {
+            // TODO: Implement the rest of the property types.
+            // Every property is defined once, properties defined before a suspension are kept.
+            for property in self.properties().iter() {
+                frame.eval(context, |context| {
+                    define_property(&obj, property, context)?;
+                    Ok(Value::undefined())
+                })?;
+            }
+
+            Ok(obj)
+        })
+    }
+}
+
+/// Defines a property of an object literal on the object being created.
+fn define_property(
+    obj: &Value,
+    property: &PropertyDefinition,
+    context: &mut Context,
+) -> Result<()> {
+    match property {
+        PropertyDefinition::IdentifierReference(ident) => {
+            obj.set_property(
+                ident.clone(),
+                PropertyDescriptor::Data(DataDescriptor::new(
+                    context.get_binding_value(ident)?,
+                    Attribute::all(),
+                )),
+            );
+        }
+        PropertyDefinition::CoverInitializedName(..) => {
+            return Err(context.construct_syntax_error("invalid shorthand property initializer"));
+        }
+        PropertyDefinition::Property(key, value) => {
+            obj.set_property(
+                key.clone(),
+                PropertyDescriptor::Data(DataDescriptor::new(
+                    value.run(context)?,
+                    Attribute::all(),
+                )),
+            );
+        }
+        PropertyDefinition::MethodDefinition(kind, name, func) => {
+            // Methods are not constructors
+            // see <https://tc39.es/ecma262/#sec-runtime-semantics-definemethod>
+            let mut flags = match kind {
+                MethodDefinitionKind::Generator => {
+                    FunctionFlags::CALLABLE | FunctionFlags::GENERATOR
+                }
+                MethodDefinitionKind::Async => FunctionFlags::CALLABLE | FunctionFlags::ASYNC,
+                _ => FunctionFlags::CALLABLE,
+            };
+            if func.strict() {
+                flags |= FunctionFlags::STRICT;
+            }
+            let func = {
+                let function = context.create_function(
+                    func.parameters().to_vec(),
+                    func.body().to_vec(),
+                    flags,
+                )?;
+                set_source_text(&function, func);
+                function
+            };
+            set_method_name(&func, *kind, name);
+            // Methods can use `super` to access the prototype of the object
+            if let (Some(function), Some(home_object)) = (func.as_object(), obj.as_object()) {
+                if let Some(function) = function.borrow_mut().as_function_mut() {
+                    function.set_home_object(home_object);
+                }
+            }
+            match kind {
+                MethodDefinitionKind::Ordinary
+                | MethodDefinitionKind::Generator
+                | MethodDefinitionKind::Async => {
                     obj.set_property(
-                        key.clone(),
-                        PropertyDescriptor::Data(DataDescriptor::new(
-                            value.run(context)?,
-                            Attribute::all(),
-                        )),
+                        name.clone(),
+                        PropertyDescriptor::Data(DataDescriptor::new(func, Attribute::all())),
                     );
                 }
-                PropertyDefinition::MethodDefinition(kind, name, func) => match kind {
-                    MethodDefinitionKind::Ordinary => {
-                        obj.set_property(
-                            name.clone(),
-                            PropertyDescriptor::Data(DataDescriptor::new(
-                                func.run(context)?,
-                                Attribute::all(),
-                            )),
-                        );
-                    }
-                    MethodDefinitionKind::Get => {
-                        let set = obj
-                            .get_property(name.clone())
-                            .as_ref()
-                            .and_then(|p| p.as_accessor_descriptor())
-                            .and_then(|a| a.setter().cloned());
-                        obj.set_property(
-                            name.clone(),
-                            PropertyDescriptor::Accessor(AccessorDescriptor {
-                                get: func.run(context)?.as_object(),
-                                set,
-                                attributes: Attribute::WRITABLE
-                                    | Attribute::ENUMERABLE
-                                    | Attribute::CONFIGURABLE,
-                            }),
-                        )
-                    }
-                    MethodDefinitionKind::Set => {
-                        let get = obj
-                            .get_property(name.clone())
-                            .as_ref()
-                            .and_then(|p| p.as_accessor_descriptor())
-                            .and_then(|a| a.getter().cloned());
-                        obj.set_property(
-                            name.clone(),
-                            PropertyDescriptor::Accessor(AccessorDescriptor {
-                                get,
-                                set: func.run(context)?.as_object(),
-                                attributes: Attribute::WRITABLE
-                                    | Attribute::ENUMERABLE
-                                    | Attribute::CONFIGURABLE,
-                            }),
-                        )
-                    }
-                },
-                _ => {} //unimplemented!("{:?} type of property", i),
+                MethodDefinitionKind::Get => {
+                    let set = obj
+                        .get_property(name.clone())
+                        .as_ref()
+                        .and_then(|p| p.as_accessor_descriptor())
+                        .and_then(|a| a.setter().cloned());
+                    obj.set_property(
+                        name.clone(),
+                        PropertyDescriptor::Accessor(AccessorDescriptor {
+                            get: func.as_object(),
+                            set,
+                            attributes: Attribute::WRITABLE
+                                | Attribute::ENUMERABLE
+                                | Attribute::CONFIGURABLE,
+                        }),
+                    )
+                }
+                MethodDefinitionKind::Set => {
+                    let get = obj
+                        .get_property(name.clone())
+                        .as_ref()
+                        .and_then(|p| p.as_accessor_descriptor())
+                        .and_then(|a| a.getter().cloned());
+                    obj.set_property(
+                        name.clone(),
+                        PropertyDescriptor::Accessor(AccessorDescriptor {
+                            get,
+                            set: func.as_object(),
+                            attributes: Attribute::WRITABLE
+                                | Attribute::ENUMERABLE
+                                | Attribute::CONFIGURABLE,
+                        }),
+                    )
+                }
             }
         }
+        _ => {} //unimplemented!("{:?} type of property", i),
+    }
+    Ok(())
+}
 
-        Ok(obj)
+#[cfg(feature = "vm")]
+impl CodeGen for Object {
+    fn compile(&self, compiler: &mut Compiler) {
+        // Generator and async methods are created by the interpreter, and invalid shorthand
+        // properties throw its error.
+        let is_interpreted = self.properties().iter().any(|property| {
+            matches!(
+                property,
+                PropertyDefinition::MethodDefinition(MethodDefinitionKind::Generator, ..)
+                    | PropertyDefinition::MethodDefinition(MethodDefinitionKind::Async, ..)
+                    | PropertyDefinition::CoverInitializedName(..)
+            )
+        });
+        if is_interpreted {
+            return compiler.add_node_instruction(&self.clone().into());
+        }
+
+        compiler.add_instruction(Instruction::NewObject);
+        for property in self.properties().iter() {
+            match property {
+                PropertyDefinition::IdentifierReference(ident) => {
+                    compiler.add_name_instruction(ident, Instruction::GetName);
+                    compiler.add_name_instruction(ident, Instruction::DefineOwnPropertyByName);
+                }
+                PropertyDefinition::Property(key, value) => {
+                    value.compile(compiler);
+                    compiler.add_name_instruction(key, Instruction::DefineOwnPropertyByName);
+                }
+                PropertyDefinition::MethodDefinition(kind, name, func) => {
+                    func.compile_with_flags(FunctionFlags::CALLABLE, compiler);
+                    let instr = match kind {
+                        MethodDefinitionKind::Get => Instruction::DefineGetterByName,
+                        MethodDefinitionKind::Set => Instruction::DefineSetterByName,
+                        _ => Instruction::DefineMethodByName,
+                    };
+                    compiler.add_name_instruction(name, instr);
+                }
+                _ => {}
+            }
+        }
     }
 }
 
@@ -153,6 +278,7 @@ where
     fn from(props: T) -> Self {
         Self {
             properties: props.into(),
+            span: NodeSpan::default(),
         }
     }
 }
@@ -1,8 +1,7 @@
 use crate::{
-    environment::lexical_environment::VariableScope,
     exec::Executable,
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::node::{declaration::BindingKind, Node, NodeSpan},
     BoaProfiler, Context, Result, Value,
 };
 use std::fmt;
@@ -10,6 +9,9 @@ use std::fmt;
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "vm")]
+use crate::vm::{compilation::CodeGen, Compiler, Instruction};
+
 /// An assignment operator assigns a value to its left operand based on the value of its right
 /// operand.
 ///
@@ -26,6 +28,7 @@ use serde::{Deserialize, Serialize};
 pub struct Assign {
     lhs: Box<Node>,
     rhs: Box<Node>,
+    span: NodeSpan,
 }
 
 impl Assign {
@@ -38,6 +41,7 @@ impl Assign {
         Self {
             lhs: Box::new(lhs.into()),
             rhs: Box::new(rhs.into()),
+            span: NodeSpan::default(),
         }
     }
 
@@ -52,37 +56,95 @@ impl Assign {
     }
 }
 
+impl_span!(Assign);
+
+impl Assign {
+    /// Assigns `value` to the reference described by `target`.
+    ///
+    /// The target can be an identifier, a property access or a destructuring pattern.
+    pub(crate) fn assign_to(target: &Node, value: Value, context: &mut Context) -> Result<()> {
+        match target {
+            Node::Identifier(ref name) => {
+                BindingKind::Assignment.initialize(name.as_ref(), value, context)?;
+            }
+            Node::GetConstField(ref get_const_field) => {
+                let val_obj = get_const_field.obj().run(context)?;
+                val_obj.put_value(get_const_field.field(), value, context)?;
+            }
+            Node::GetField(ref get_field) => {
+                context.resumable(|frame, context| {
+                    let object = frame.run(get_field.obj(), context)?;
+                    let field = get_field.field().run(context)?;
+                    let key = field.to_property_key(context)?;
+                    object.put_value(key, value, context)
+                })?;
+            }
+            Node::GetPrivateField(ref get_private_field) => {
+                let object = get_private_field.obj().run(context)?;
+                get_private_field.assign(&object, value, context)?;
+            }
+            Node::GetSuperField(ref get_super_field) => {
+                let (base, key, this) = get_super_field.reference(context)?;
+                let key_name = key.to_string();
+                if !base.to_object(context)?.set(key, value, this, context)? && context.strict() {
+                    return Err(context.construct_type_error(format!(
+                        "cannot assign to read-only property {}",
+                        key_name
+                    )));
+                }
+            }
+            Node::DeclarationPattern(ref pattern) => {
+                pattern.bind(value, BindingKind::Assignment, context)?;
+            }
+            _ => (),
+        }
+        Ok(())
+    }
+}
+
 impl Executable for Assign {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("Assign", "exec");
-        let val = self.rhs().run(context)?;
+        context.resumable(|frame, context| {
+            let val = frame.run(self.rhs(), context)?;
+            Self::assign_to(self.lhs(), val.clone(), context)?;
+            Ok(val)
+        })
+    }
+}
+
+#[cfg(feature = "vm")]
+impl CodeGen for Assign {
+    fn compile(&self, compiler: &mut Compiler) {
         match self.lhs() {
             Node::Identifier(ref name) => {
-                if context.has_binding(name.as_ref()) {
-                    // Binding already exists
-                    context.set_mutable_binding(name.as_ref(), val.clone(), true)?;
-                } else {
-                    context.create_mutable_binding(
-                        name.as_ref().to_owned(),
-                        true,
-                        VariableScope::Function,
-                    )?;
-                    context.initialize_binding(name.as_ref(), val.clone())?;
-                }
+                self.rhs().compile(compiler);
+                compiler.add_name_instruction(name.as_ref(), Instruction::SetName);
             }
             Node::GetConstField(ref get_const_field) => {
-                let val_obj = get_const_field.obj().run(context)?;
-                val_obj.set_field(get_const_field.field(), val.clone(), context)?;
+                get_const_field.obj().compile(compiler);
+                self.rhs().compile(compiler);
+                compiler
+                    .add_name_instruction(get_const_field.field(), Instruction::SetPropertyByName);
             }
             Node::GetField(ref get_field) => {
-                let object = get_field.obj().run(context)?;
-                let field = get_field.field().run(context)?;
-                let key = field.to_property_key(context)?;
-                object.set_field(key, val.clone(), context)?;
+                get_field.obj().compile(compiler);
+                get_field.field().compile(compiler);
+                self.rhs().compile(compiler);
+                compiler.add_instruction(Instruction::SetPropertyByValue);
             }
-            _ => (),
+            Node::DeclarationPattern(ref pattern) => {
+                self.rhs().compile(compiler);
+                compiler.add_instruction(Instruction::Dup);
+                compiler.add_pattern_instruction(pattern, Instruction::BindAssign);
+            }
+            // Assignments to `super` properties need the home object of the function, and
+            // private names are resolved by the interpreter.
+            Node::GetSuperField(_) | Node::GetPrivateField(_) => {
+                compiler.add_node_instruction(&self.clone().into())
+            }
+            _ => self.rhs().compile(compiler),
         }
-        Ok(val)
     }
 }
 
@@ -1,9 +1,8 @@
 use crate::{
     exec::Executable,
     gc::{Finalize, Trace},
-    symbol::WellKnownSymbols,
     syntax::ast::{
-        node::Node,
+        node::{Node, NodeSpan},
         op::{self, AssignOp, BitOp, CompOp, LogOp, NumOp},
     },
     Context, Result, Value,
@@ -31,6 +30,7 @@ pub struct BinOp {
     op: op::BinOp,
     lhs: Box<Node>,
     rhs: Box<Node>,
+    span: NodeSpan,
 }
 
 impl BinOp {
@@ -45,6 +45,7 @@ impl BinOp {
             op: op.into(),
             lhs: Box::new(lhs.into()),
             rhs: Box::new(rhs.into()),
+            span: NodeSpan::default(),
         }
     }
 
@@ -103,11 +104,13 @@ impl BinOp {
     }
 }
 
+impl_span!(BinOp);
+
 impl Executable for BinOp {
     fn run(&self, context: &mut Context) -> Result<Value> {
-        match self.op() {
+        context.resumable(|frame, context| match self.op() {
             op::BinOp::Num(op) => {
-                let x = self.lhs().run(context)?;
+                let x = frame.run(self.lhs(), context)?;
                 let y = self.rhs().run(context)?;
                 match op {
                     NumOp::Add => x.add(&y, context),
@@ -119,7 +122,7 @@ impl Executable for BinOp {
                 }
             }
             op::BinOp::Bit(op) => {
-                let x = self.lhs().run(context)?;
+                let x = frame.run(self.lhs(), context)?;
                 let y = self.rhs().run(context)?;
                 match op {
                     BitOp::And => x.bitand(&y, context),
@@ -131,7 +134,7 @@ impl Executable for BinOp {
                 }
             }
             op::BinOp::Comp(op) => {
-                let x = self.lhs().run(context)?;
+                let x = frame.run(self.lhs(), context)?;
                 let y = self.rhs().run(context)?;
                 Ok(Value::from(match op {
                     CompOp::Equal => x.equals(&y, context)?,
@@ -150,37 +153,14 @@ impl Executable for BinOp {
                             ));
                         }
                         let key = x.to_property_key(context)?;
-                        context.has_property(&y, &key)
-                    }
-                    CompOp::InstanceOf => {
-                        if let Some(object) = y.as_object() {
-                            let key = WellKnownSymbols::has_instance();
-
-                            match object.get_method(context, key)? {
-                                Some(instance_of_handler) => {
-                                    instance_of_handler.call(&y, &[x], context)?.to_boolean()
-                                }
-                                None if object.is_callable() => {
-                                    object.ordinary_has_instance(context, &x)?
-                                }
-                                None => {
-                                    return context.throw_type_error(
-                                        "right-hand side of 'instanceof' is not callable",
-                                    );
-                                }
-                            }
-                        } else {
-                            return context.throw_type_error(format!(
-                                "right-hand side of 'instanceof' should be an object, got {}",
-                                y.get_type().as_str()
-                            ));
-                        }
+                        context.has_property(&y, &key)?
                     }
+                    CompOp::InstanceOf => x.instance_of(&y, context)?,
                 }))
             }
             op::BinOp::Log(op) => Ok(match op {
                 LogOp::And => {
-                    let left = self.lhs().run(context)?;
+                    let left = frame.run(self.lhs(), context)?;
                     if !left.to_boolean() {
                         left
                     } else {
@@ -188,7 +168,7 @@ impl Executable for BinOp {
                     }
                 }
                 LogOp::Or => {
-                    let left = self.lhs().run(context)?;
+                    let left = frame.run(self.lhs(), context)?;
                     if left.to_boolean() {
                         left
                     } else {
@@ -196,7 +176,7 @@ impl Executable for BinOp {
                     }
                 }
                 LogOp::Coalesce => {
-                    let left = self.lhs.run(context)?;
+                    let left = frame.run(self.lhs(), context)?;
                     if left.is_null_or_undefined() {
                         self.rhs().run(context)?
                     } else {
@@ -206,26 +186,55 @@ impl Executable for BinOp {
             }),
             op::BinOp::Assign(op) => match self.lhs() {
                 Node::Identifier(ref name) => {
-                    let v_a = context.get_binding_value(name.as_ref())?;
+                    let v_a =
+                        frame.eval(context, |context| context.get_binding_value(name.as_ref()))?;
 
                     let value = Self::run_assign(op, v_a, self.rhs(), context)?;
-                    context.set_mutable_binding(name.as_ref(), value.clone(), true)?;
+                    let strict = context.strict();
+                    context.set_mutable_binding(name.as_ref(), value.clone(), strict)?;
                     Ok(value)
                 }
                 Node::GetConstField(ref get_const_field) => {
-                    let v_r_a = get_const_field.obj().run(context)?;
-                    let v_a = v_r_a.get_field(get_const_field.field(), context)?;
+                    let v_r_a = frame.run(get_const_field.obj(), context)?;
+                    let v_a = frame.eval(context, |context| {
+                        v_r_a.get_field(get_const_field.field(), context)
+                    })?;
+                    let value = Self::run_assign(op, v_a, self.rhs(), context)?;
+                    v_r_a.put_value(get_const_field.field(), value.clone(), context)?;
+                    Ok(value)
+                }
+                Node::GetField(ref get_field) => {
+                    let v_r_a = frame.run(get_field.obj(), context)?;
+                    let key = frame
+                        .eval(context, |context| {
+                            Ok(get_field
+                                .field()
+                                .run(context)?
+                                .to_property_key(context)?
+                                .into())
+                        })?
+                        .to_property_key(context)?;
+                    let v_a =
+                        frame.eval(context, |context| v_r_a.get_field(key.clone(), context))?;
+                    let value = Self::run_assign(op, v_a, self.rhs(), context)?;
+                    v_r_a.put_value(key, value.clone(), context)?;
+                    Ok(value)
+                }
+                Node::GetPrivateField(ref get_private_field) => {
+                    let v_r_a = frame.run(get_private_field.obj(), context)?;
+                    let v_a =
+                        frame.eval(context, |context| get_private_field.get(&v_r_a, context))?;
                     let value = Self::run_assign(op, v_a, self.rhs(), context)?;
-                    v_r_a.set_field(get_const_field.field(), value.clone(), context)?;
+                    get_private_field.assign(&v_r_a, value.clone(), context)?;
                     Ok(value)
                 }
                 _ => Ok(Value::undefined()),
             },
             op::BinOp::Comma => {
-                self.lhs().run(context)?;
+                frame.run(self.lhs(), context)?;
                 Ok(self.rhs().run(context)?)
             }
-        }
+        })
     }
 }
 
@@ -274,11 +283,92 @@ impl CodeGen for BinOp {
                     CompOp::InstanceOf => compiler.add_instruction(Instruction::InstanceOf),
                 }
             }
-            _ => unimplemented!(),
+            op::BinOp::Log(op) => {
+                self.lhs().compile(compiler);
+                let jump = compiler.jump(match op {
+                    LogOp::And => Instruction::LogicalAnd,
+                    LogOp::Or => Instruction::LogicalOr,
+                    LogOp::Coalesce => Instruction::Coalesce,
+                });
+                self.rhs().compile(compiler);
+                compiler.patch_jump(jump);
+            }
+            op::BinOp::Assign(op) => match self.lhs() {
+                Node::Identifier(ref name) => {
+                    compiler.add_name_instruction(name.as_ref(), Instruction::GetName);
+                    Self::compile_assign(op, self.rhs(), compiler);
+                    compiler.add_name_instruction(name.as_ref(), Instruction::SetName);
+                }
+                Node::GetConstField(ref get_const_field) => {
+                    get_const_field.obj().compile(compiler);
+                    compiler.add_instruction(Instruction::Dup);
+                    compiler.add_name_instruction(
+                        get_const_field.field(),
+                        Instruction::GetPropertyByName,
+                    );
+                    Self::compile_assign(op, self.rhs(), compiler);
+                    compiler.add_name_instruction(
+                        get_const_field.field(),
+                        Instruction::SetPropertyByName,
+                    );
+                }
+                Node::GetField(ref get_field) => {
+                    get_field.obj().compile(compiler);
+                    get_field.field().compile(compiler);
+                    compiler.add_instruction(Instruction::Dup2);
+                    compiler.add_instruction(Instruction::GetPropertyByValue);
+                    Self::compile_assign(op, self.rhs(), compiler);
+                    compiler.add_instruction(Instruction::SetPropertyByValue);
+                }
+                // Private names are resolved by the interpreter.
+                Node::GetPrivateField(_) => compiler.add_node_instruction(&self.clone().into()),
+                _ => compiler.add_instruction(Instruction::Undefined),
+            },
+            op::BinOp::Comma => {
+                self.lhs().compile(compiler);
+                compiler.add_instruction(Instruction::Pop);
+                self.rhs().compile(compiler);
+            }
         }
     }
 }
 
+#[cfg(feature = "vm")]
+impl BinOp {
+    /// Compiles the assignment operators, whose left hand side value is on top of the stack.
+    ///
+    /// Like the interpreter, the logical assignment operators assign the value even if they
+    /// short-circuit.
+    fn compile_assign(op: AssignOp, y: &Node, compiler: &mut Compiler) {
+        let instr = match op {
+            AssignOp::Add => Instruction::Add,
+            AssignOp::Sub => Instruction::Sub,
+            AssignOp::Mul => Instruction::Mul,
+            AssignOp::Exp => Instruction::Pow,
+            AssignOp::Div => Instruction::Div,
+            AssignOp::Mod => Instruction::Mod,
+            AssignOp::And => Instruction::BitAnd,
+            AssignOp::Or => Instruction::BitOr,
+            AssignOp::Xor => Instruction::BitXor,
+            AssignOp::Shl => Instruction::Shl,
+            AssignOp::Shr => Instruction::Shr,
+            AssignOp::Ushr => Instruction::UShr,
+            AssignOp::BoolAnd | AssignOp::BoolOr | AssignOp::Coalesce => {
+                let jump = compiler.jump(match op {
+                    AssignOp::BoolAnd => Instruction::LogicalAnd,
+                    AssignOp::BoolOr => Instruction::LogicalOr,
+                    _ => Instruction::Coalesce,
+                });
+                y.compile(compiler);
+                compiler.patch_jump(jump);
+                return;
+            }
+        };
+        y.compile(compiler);
+        compiler.add_instruction(instr);
+    }
+}
+
 impl fmt::Display for BinOp {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{} {} {}", self.lhs, self.op, self.rhs)
@@ -10,7 +10,10 @@ fn assignmentoperator_lhs_not_defined() {
         }
         "#;
 
-    assert_eq!(&exec(scenario), "\"ReferenceError: a is not defined\"");
+    assert_eq!(
+        &exec(scenario),
+        "\"ReferenceError: a is not defined at line 3, col 11\""
+    );
 }
 
 #[test]
@@ -24,7 +27,10 @@ fn assignmentoperator_rhs_throws_error() {
         }
         "#;
 
-    assert_eq!(&exec(scenario), "\"ReferenceError: b is not defined\"");
+    assert_eq!(
+        &exec(scenario),
+        "\"ReferenceError: b is not defined at line 4, col 16\""
+    );
 }
 
 #[test]
@@ -40,7 +46,7 @@ fn instanceofoperator_rhs_not_object() {
 
     assert_eq!(
         &exec(scenario),
-        "\"TypeError: right-hand side of 'instanceof' should be an object, got number\""
+        "\"TypeError: right-hand side of 'instanceof' should be an object, got number at line 4, col 11\""
     );
 }
 
@@ -57,7 +63,7 @@ fn instanceofoperator_rhs_not_callable() {
 
     assert_eq!(
         &exec(scenario),
-        "\"TypeError: right-hand side of 'instanceof' is not callable\""
+        "\"TypeError: right-hand side of 'instanceof' is not callable at line 4, col 11\""
     );
 }
 
@@ -1,7 +1,10 @@
 use crate::{
     exec::Executable,
     gc::{Finalize, Trace},
-    syntax::ast::{node::Node, op},
+    syntax::ast::{
+        node::{Node, NodeSpan},
+        op,
+    },
     Context, Result, Value,
 };
 use std::fmt;
@@ -28,6 +31,7 @@ use crate::{
 pub struct UnaryOp {
     op: op::UnaryOp,
     target: Box<Node>,
+    span: NodeSpan,
 }
 
 impl UnaryOp {
@@ -39,6 +43,7 @@ impl UnaryOp {
         Self {
             op,
             target: Box::new(target.into()),
+            span: NodeSpan::default(),
         }
     }
 
@@ -53,33 +58,35 @@ impl UnaryOp {
     }
 }
 
+impl_span!(UnaryOp);
+
 impl Executable for UnaryOp {
     fn run(&self, context: &mut Context) -> Result<Value> {
         Ok(match self.op() {
             op::UnaryOp::Minus => self.target().run(context)?.neg(context)?,
             op::UnaryOp::Plus => Value::from(self.target().run(context)?.to_number(context)?),
-            op::UnaryOp::IncrementPost => {
-                let x = self.target().run(context)?;
+            op::UnaryOp::IncrementPost => context.resumable(|frame, context| {
+                let x = frame.run(self.target(), context)?;
                 let ret = x.clone();
                 let result = x.to_number(context)? + 1.0;
                 context.set_value(self.target(), result.into())?;
-                ret
-            }
-            op::UnaryOp::IncrementPre => {
-                let result = self.target().run(context)?.to_number(context)? + 1.0;
-                context.set_value(self.target(), result.into())?
-            }
-            op::UnaryOp::DecrementPost => {
-                let x = self.target().run(context)?;
+                Ok(ret)
+            })?,
+            op::UnaryOp::IncrementPre => context.resumable(|frame, context| {
+                let result = frame.run(self.target(), context)?.to_number(context)? + 1.0;
+                context.set_value(self.target(), result.into())
+            })?,
+            op::UnaryOp::DecrementPost => context.resumable(|frame, context| {
+                let x = frame.run(self.target(), context)?;
                 let ret = x.clone();
                 let result = x.to_number(context)? - 1.0;
                 context.set_value(self.target(), result.into())?;
-                ret
-            }
-            op::UnaryOp::DecrementPre => {
-                let result = self.target().run(context)?.to_number(context)? - 1.0;
-                context.set_value(self.target(), result.into())?
-            }
+                Ok(ret)
+            })?,
+            op::UnaryOp::DecrementPre => context.resumable(|frame, context| {
+                let result = frame.run(self.target(), context)?.to_number(context)? - 1.0;
+                context.set_value(self.target(), result.into())
+            })?,
             op::UnaryOp::Not => self.target().run(context)?.not(context)?.into(),
             op::UnaryOp::Tilde => {
                 let num_v_a = self.target().run(context)?.to_number(context)?;
@@ -99,17 +106,17 @@ impl Executable for UnaryOp {
                     get_const_field
                         .obj()
                         .run(context)?
-                        .to_object(context)?
-                        .delete(&get_const_field.field().into()),
+                        .delete_property(get_const_field.field(), context)?,
                 ),
                 Node::GetField(ref get_field) => {
-                    let obj = get_field.obj().run(context)?;
-                    let field = &get_field.field().run(context)?;
-                    let res = obj
-                        .to_object(context)?
-                        .delete(&field.to_property_key(context)?);
-                    return Ok(Value::boolean(res));
+                    return context.resumable(|frame, context| {
+                        let obj = frame.run(get_field.obj(), context)?;
+                        let field = &get_field.field().run(context)?;
+                        let key = field.to_property_key(context)?;
+                        Ok(Value::boolean(obj.delete_property(key, context)?))
+                    });
                 }
+                Node::OptionalChain(ref chain) => Value::boolean(chain.delete(context)?),
                 Node::Identifier(_) => Value::boolean(false),
                 Node::ArrayDecl(_)
                 | Node::Block(_)
@@ -121,7 +128,14 @@ impl Executable for UnaryOp {
                 | Node::UnaryOp(_) => Value::boolean(true),
                 _ => return context.throw_syntax_error(format!("wrong delete argument {}", self)),
             },
-            op::UnaryOp::TypeOf => Value::from(self.target().run(context)?.get_type().as_str()),
+            // The type of an unresolvable reference is `undefined`, instead of a `ReferenceError`.
+            // see <https://tc39.es/ecma262/#sec-typeof-operator-runtime-semantics-evaluation>
+            op::UnaryOp::TypeOf => match *self.target() {
+                Node::Identifier(ref name) if !context.has_binding(name.as_ref())? => {
+                    Value::from("undefined")
+                }
+                ref target => Value::from(target.run(context)?.get_type().as_str()),
+            },
         })
     }
 }
@@ -142,19 +156,116 @@ impl From<UnaryOp> for Node {
 impl CodeGen for UnaryOp {
     fn compile(&self, compiler: &mut Compiler) {
         let _timer = BoaProfiler::global().start_event("UnaryOp", "codeGen");
+        let instr = match self.op {
+            op::UnaryOp::Void => Instruction::Void,
+            op::UnaryOp::Plus => Instruction::Pos,
+            op::UnaryOp::Minus => Instruction::Neg,
+            // The interpreter checks if an identifier is resolvable.
+            op::UnaryOp::TypeOf if matches!(self.target(), Node::Identifier(_)) => {
+                return compiler.add_node_instruction(&self.clone().into())
+            }
+            op::UnaryOp::TypeOf => Instruction::TypeOf,
+            op::UnaryOp::Not => Instruction::Not,
+            op::UnaryOp::Tilde => Instruction::BitNot,
+            op::UnaryOp::IncrementPost => {
+                return self.compile_update(Instruction::Inc, true, compiler)
+            }
+            op::UnaryOp::IncrementPre => {
+                return self.compile_update(Instruction::Inc, false, compiler)
+            }
+            op::UnaryOp::DecrementPost => {
+                return self.compile_update(Instruction::Dec, true, compiler)
+            }
+            op::UnaryOp::DecrementPre => {
+                return self.compile_update(Instruction::Dec, false, compiler)
+            }
+            op::UnaryOp::Delete => return self.compile_delete(compiler),
+        };
         self.target().compile(compiler);
-        match self.op {
-            op::UnaryOp::Void => compiler.add_instruction(Instruction::Void),
-            op::UnaryOp::Plus => compiler.add_instruction(Instruction::Pos),
-            op::UnaryOp::Minus => compiler.add_instruction(Instruction::Neg),
-            op::UnaryOp::TypeOf => compiler.add_instruction(Instruction::TypeOf),
-            op::UnaryOp::Not => compiler.add_instruction(Instruction::Not),
-            op::UnaryOp::Tilde => compiler.add_instruction(Instruction::BitNot),
-            op::UnaryOp::IncrementPost => {}
-            op::UnaryOp::IncrementPre => {}
-            op::UnaryOp::DecrementPost => {}
-            op::UnaryOp::DecrementPre => {}
-            op::UnaryOp::Delete => {}
+        compiler.add_instruction(instr);
+    }
+}
+
+#[cfg(feature = "vm")]
+impl UnaryOp {
+    /// Compiles the increment and decrement operators.
+    ///
+    /// The postfix operators leave the old value on the stack, below the references to the
+    /// target.
+    fn compile_update(&self, instr: Instruction, is_postfix: bool, compiler: &mut Compiler) {
+        match *self.target() {
+            Node::Identifier(ref name) => {
+                compiler.add_name_instruction(name.as_ref(), Instruction::GetName);
+                compiler.add_instruction(Instruction::ToNumber);
+                if is_postfix {
+                    compiler.add_instruction(Instruction::Dup);
+                }
+                compiler.add_instruction(instr);
+                compiler.add_name_instruction(name.as_ref(), Instruction::SetName);
+            }
+            Node::GetConstField(ref get_const_field) => {
+                get_const_field.obj().compile(compiler);
+                compiler.add_instruction(Instruction::Dup);
+                compiler
+                    .add_name_instruction(get_const_field.field(), Instruction::GetPropertyByName);
+                compiler.add_instruction(Instruction::ToNumber);
+                if is_postfix {
+                    compiler.add_instruction(Instruction::Dup);
+                    compiler.add_instruction(Instruction::RotateDown(3));
+                }
+                compiler.add_instruction(instr);
+                compiler
+                    .add_name_instruction(get_const_field.field(), Instruction::SetPropertyByName);
+            }
+            Node::GetField(ref get_field) => {
+                get_field.obj().compile(compiler);
+                get_field.field().compile(compiler);
+                compiler.add_instruction(Instruction::Dup2);
+                compiler.add_instruction(Instruction::GetPropertyByValue);
+                compiler.add_instruction(Instruction::ToNumber);
+                if is_postfix {
+                    compiler.add_instruction(Instruction::Dup);
+                    compiler.add_instruction(Instruction::RotateDown(4));
+                }
+                compiler.add_instruction(instr);
+                compiler.add_instruction(Instruction::SetPropertyByValue);
+            }
+            // Other targets throw the interpreter's error.
+            _ => {
+                compiler.add_node_instruction(&self.clone().into());
+                return;
+            }
+        }
+        if is_postfix {
+            compiler.add_instruction(Instruction::Pop);
+        }
+    }
+
+    /// Compiles the `delete` operator.
+    fn compile_delete(&self, compiler: &mut Compiler) {
+        match *self.target() {
+            Node::GetConstField(ref get_const_field) => {
+                get_const_field.obj().compile(compiler);
+                compiler.add_name_instruction(
+                    get_const_field.field(),
+                    Instruction::DeletePropertyByName,
+                );
+            }
+            Node::GetField(ref get_field) => {
+                get_field.obj().compile(compiler);
+                get_field.field().compile(compiler);
+                compiler.add_instruction(Instruction::DeletePropertyByValue);
+            }
+            Node::Identifier(_) => compiler.add_instruction(Instruction::False),
+            Node::ArrayDecl(_)
+            | Node::Block(_)
+            | Node::Const(_)
+            | Node::FunctionDecl(_)
+            | Node::FunctionExpr(_)
+            | Node::New(_)
+            | Node::Object(_)
+            | Node::UnaryOp(_) => compiler.add_instruction(Instruction::True),
+            _ => compiler.add_node_instruction(&self.clone().into()),
         }
     }
 }
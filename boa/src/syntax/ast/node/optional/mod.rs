@@ -0,0 +1,364 @@
+use crate::{
+    exec::{coroutine::ResumeFrame, Executable, InterpreterState},
+    gc::{Finalize, Trace},
+    syntax::ast::node::{call::evaluate_arguments, join_nodes, Node, NodeSpan},
+    value::{Type, Value},
+    BoaProfiler, Context, Result,
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "vm")]
+use crate::vm::{compilation::CodeGen, Compiler, Instruction};
+
+#[cfg(test)]
+mod tests;
+
+/// The optional chaining operator (`?.`) accesses a property or calls a function, unless the
+/// object or the function is `null` or `undefined`, in which case the whole chain evaluates to
+/// `undefined`.
+///
+/// The chain is stored as the expression it starts from, followed by the operations applied to
+/// it. The operations starting with `?.` are the ones that can short-circuit the chain, like the
+/// `.b` of `a?.b`, while the others are the ones following them, like the `.c` of `a?.b.c`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-OptionalExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Optional_chaining
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct OptionalChain {
+    target: Box<Node>,
+    chain: Box<[OptionalOperation]>,
+    span: NodeSpan,
+}
+
+impl OptionalChain {
+    /// Creates a new `OptionalChain` AST node.
+    pub fn new<T, C>(target: T, chain: C) -> Self
+    where
+        T: Into<Node>,
+        C: Into<Box<[OptionalOperation]>>,
+    {
+        Self {
+            target: Box::new(target.into()),
+            chain: chain.into(),
+            span: NodeSpan::default(),
+        }
+    }
+
+    /// Gets the expression the chain starts from.
+    pub fn target(&self) -> &Node {
+        &self.target
+    }
+
+    /// Gets the operations of the chain.
+    pub fn chain(&self) -> &[OptionalOperation] {
+        &self.chain
+    }
+
+    /// Evaluates the target and the given operations of the chain, returning the resulting value
+    /// and the value it was read from, or `None` if the chain was short-circuited.
+    fn evaluate(
+        &self,
+        chain: &[OptionalOperation],
+        frame: &mut ResumeFrame,
+        context: &mut Context,
+    ) -> Result<Option<(Value, Value)>> {
+        // Calls of properties use the object the property was read from as `this`.
+        let (mut this, mut value) = match *self.target() {
+            Node::GetConstField(ref get_const_field) => {
+                let obj = frame.eval(context, |context| {
+                    to_object_value(get_const_field.obj().run(context)?, context)
+                })?;
+                let value = frame.eval(context, |context| {
+                    obj.get_field(get_const_field.field(), context)
+                })?;
+                (obj, value)
+            }
+            Node::GetField(ref get_field) => {
+                let obj = frame.eval(context, |context| {
+                    to_object_value(get_field.obj().run(context)?, context)
+                })?;
+                let value = frame.eval(context, |context| {
+                    let field = get_field.field().run(context)?;
+                    obj.get_field(field.to_property_key(context)?, context)
+                })?;
+                (obj, value)
+            }
+            Node::Identifier(ref name) => (
+                frame.eval(context, |context| {
+                    Ok(context
+                        .get_with_base_object(name.as_ref())?
+                        .map_or_else(Value::undefined, Value::from))
+                })?,
+                frame.run(self.target(), context)?,
+            ),
+            ref target => (Value::undefined(), frame.run(target, context)?),
+        };
+
+        for operation in chain {
+            if operation.shorted() && value.is_null_or_undefined() {
+                return Ok(None);
+            }
+
+            match *operation.kind() {
+                OptionalOperationKind::SimplePropertyAccess { ref field } => {
+                    let obj = frame.eval(context, |context| to_object_value(value, context))?;
+                    value =
+                        frame.eval(context, |context| obj.get_field(field.as_ref(), context))?;
+                    this = obj;
+                }
+                OptionalOperationKind::PropertyAccess { ref field } => {
+                    let obj = frame.eval(context, |context| to_object_value(value, context))?;
+                    value = frame.eval(context, |context| {
+                        let field = field.run(context)?;
+                        obj.get_field(field.to_property_key(context)?, context)
+                    })?;
+                    this = obj;
+                }
+                OptionalOperationKind::Call { ref args } => {
+                    let args = evaluate_arguments(args, frame, context)?;
+                    value = frame.eval(context, |context| {
+                        let result = context.call(&value, &this, &args);
+
+                        // unset the early return flag
+                        context
+                            .executor()
+                            .set_current_state(InterpreterState::Executing);
+
+                        result
+                    })?;
+                    this = Value::undefined();
+                }
+            }
+        }
+
+        Ok(Some((this, value)))
+    }
+
+    /// Evaluates `delete` applied to the chain, returning `true` if the chain was
+    /// short-circuited.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-delete-operator-runtime-semantics-evaluation
+    pub(crate) fn delete(&self, context: &mut Context) -> Result<bool> {
+        let (last, chain) = match self.chain.split_last() {
+            Some(split) => split,
+            None => return Ok(true),
+        };
+
+        context.resumable(|frame, context| {
+            let value = match self.evaluate(chain, frame, context)? {
+                Some((_, value)) if !(last.shorted() && value.is_null_or_undefined()) => value,
+                _ => return Ok(true),
+            };
+
+            match *last.kind() {
+                OptionalOperationKind::SimplePropertyAccess { ref field } => {
+                    value.delete_property(field.as_ref(), context)
+                }
+                OptionalOperationKind::PropertyAccess { ref field } => {
+                    let field = field.run(context)?;
+                    let key = field.to_property_key(context)?;
+                    value.delete_property(key, context)
+                }
+                // Deleting the result of a call deletes nothing, but the call is still evaluated.
+                OptionalOperationKind::Call { .. } => {
+                    self.evaluate(&self.chain, frame, context)?;
+                    Ok(true)
+                }
+            }
+        })
+    }
+}
+
+impl_span!(OptionalChain);
+
+/// Converts the value to an object, keeping objects as they are.
+fn to_object_value(value: Value, context: &mut Context) -> Result<Value> {
+    if value.get_type() == Type::Object {
+        Ok(value)
+    } else {
+        Ok(Value::Object(value.to_object(context)?))
+    }
+}
+
+impl Executable for OptionalChain {
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("OptionalChain", "exec");
+        context.resumable(|frame, context| {
+            Ok(self
+                .evaluate(&self.chain, frame, context)?
+                .map_or_else(Value::undefined, |(_, value)| value))
+        })
+    }
+}
+
+#[cfg(feature = "vm")]
+impl CodeGen for OptionalChain {
+    fn compile(&self, compiler: &mut Compiler) {
+        let _timer = BoaProfiler::global().start_event("OptionalChain", "codeGen");
+
+        // The stack holds the value of the chain so far, above the value it was read from, which
+        // calls use as `this`.
+        match *self.target() {
+            Node::GetConstField(ref get_const_field) => {
+                get_const_field.obj().compile(compiler);
+                compiler.add_instruction(Instruction::Dup);
+                compiler
+                    .add_name_instruction(get_const_field.field(), Instruction::GetPropertyByName);
+            }
+            Node::GetField(ref get_field) => {
+                get_field.obj().compile(compiler);
+                compiler.add_instruction(Instruction::Dup);
+                get_field.field().compile(compiler);
+                compiler.add_instruction(Instruction::GetPropertyByValue);
+            }
+            ref target => {
+                compiler.add_instruction(Instruction::Undefined);
+                target.compile(compiler);
+            }
+        }
+
+        let mut short_circuits = Vec::new();
+        for operation in self.chain() {
+            if operation.shorted() {
+                short_circuits.push(compiler.jump(Instruction::JumpIfNullOrUndefined));
+            }
+
+            match *operation.kind() {
+                OptionalOperationKind::SimplePropertyAccess { ref field } => {
+                    compiler.add_instruction(Instruction::Swap);
+                    compiler.add_instruction(Instruction::Pop);
+                    compiler.add_instruction(Instruction::Dup);
+                    compiler.add_name_instruction(field.as_ref(), Instruction::GetPropertyByName);
+                }
+                OptionalOperationKind::PropertyAccess { ref field } => {
+                    compiler.add_instruction(Instruction::Swap);
+                    compiler.add_instruction(Instruction::Pop);
+                    compiler.add_instruction(Instruction::Dup);
+                    field.compile(compiler);
+                    compiler.add_instruction(Instruction::GetPropertyByValue);
+                }
+                OptionalOperationKind::Call { ref args } => {
+                    if compiler.compile_arguments(args) {
+                        compiler.add_instruction(Instruction::CallWithArray);
+                    } else {
+                        compiler.add_instruction(Instruction::Call(args.len()));
+                    }
+                    compiler.add_instruction(Instruction::Undefined);
+                    compiler.add_instruction(Instruction::Swap);
+                }
+            }
+        }
+
+        compiler.add_instruction(Instruction::Swap);
+        compiler.add_instruction(Instruction::Pop);
+
+        if !short_circuits.is_empty() {
+            let end = compiler.jump(Instruction::Jump);
+            for jump in short_circuits {
+                compiler.patch_jump(jump);
+            }
+            compiler.add_instruction(Instruction::Pop);
+            compiler.add_instruction(Instruction::Pop);
+            compiler.add_instruction(Instruction::Undefined);
+            compiler.patch_jump(end);
+        }
+    }
+}
+
+impl fmt::Display for OptionalChain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.target())?;
+        for operation in self.chain() {
+            write!(f, "{}", operation)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<OptionalChain> for Node {
+    fn from(chain: OptionalChain) -> Self {
+        Self::OptionalChain(chain)
+    }
+}
+
+/// An operation of an [`OptionalChain`].
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct OptionalOperation {
+    kind: OptionalOperationKind,
+    shorted: bool,
+}
+
+impl OptionalOperation {
+    /// Creates a new operation of an optional chain.
+    ///
+    /// Operations that are `shorted` start with `?.`, and short-circuit the chain if the value
+    /// they are applied to is `null` or `undefined`.
+    pub fn new(kind: OptionalOperationKind, shorted: bool) -> Self {
+        Self { kind, shorted }
+    }
+
+    /// Gets the kind of the operation.
+    pub fn kind(&self) -> &OptionalOperationKind {
+        &self.kind
+    }
+
+    /// Returns whether the operation starts with `?.`.
+    pub fn shorted(&self) -> bool {
+        self.shorted
+    }
+}
+
+impl fmt::Display for OptionalOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.shorted {
+            f.write_str("?.")?;
+        }
+        match self.kind {
+            OptionalOperationKind::SimplePropertyAccess { ref field } => {
+                if !self.shorted {
+                    f.write_str(".")?;
+                }
+                f.write_str(field)
+            }
+            OptionalOperationKind::PropertyAccess { ref field } => write!(f, "[{}]", field),
+            OptionalOperationKind::Call { ref args } => {
+                f.write_str("(")?;
+                join_nodes(f, args)?;
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+/// The kinds of operations of an [`OptionalChain`].
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum OptionalOperationKind {
+    /// A property access with the dot notation, like `?.b` or `.b`.
+    SimplePropertyAccess {
+        /// The name of the property.
+        field: Box<str>,
+    },
+    /// A property access with the bracket notation, like `?.[b]` or `[b]`.
+    PropertyAccess {
+        /// The expression of the property key.
+        field: Node,
+    },
+    /// A call, like `?.(b)` or `(b)`.
+    Call {
+        /// The arguments of the call.
+        args: Box<[Node]>,
+    },
+}
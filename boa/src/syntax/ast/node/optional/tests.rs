@@ -0,0 +1,69 @@
+use crate::exec;
+
+#[test]
+fn property_access() {
+    let scenario = r#"
+    let a = { b: { c: 1 } };
+    let n = null;
+    [a?.b.c, a?.["b"]?.c, a.x?.c, n?.b, n?.b.c.d, a?.b?.x?.y, n?.[a.b.c = 2], a.b.c]
+    "#;
+    assert_eq!(
+        &exec(scenario),
+        "[ 1, 1, undefined, undefined, undefined, undefined, undefined, 1 ]"
+    );
+}
+
+#[test]
+fn call() {
+    let scenario = r#"
+    let o = {
+        v: 3,
+        get() { return this.v; },
+        make() { return { get: () => this.v * 2 }; },
+    };
+    let f;
+    [o.get?.(), o?.get(), o.make?.().get(), o.missing?.(), f?.(o.v++), o.v]
+    "#;
+    assert_eq!(&exec(scenario), "[ 3, 3, 6, undefined, undefined, 3 ]");
+}
+
+#[test]
+fn parenthesized_chain_is_not_short_circuited() {
+    let scenario = r#"
+    let n = null;
+    try {
+        (n?.a).b;
+    } catch (e) {
+        e.name
+    }
+    "#;
+    assert_eq!(&exec(scenario), r#""TypeError""#);
+}
+
+#[test]
+fn non_nullish_values_are_accessed() {
+    let scenario = r#"
+    let a = { b: undefined };
+    try {
+        a?.b.c;
+    } catch (e) {
+        e.name
+    }
+    "#;
+    assert_eq!(&exec(scenario), r#""TypeError""#);
+    assert_eq!(&exec("let s = 'abc'; s?.length"), "3");
+    assert_eq!(&exec("let z = 0; z?.toFixed(1)"), r#""0.0""#);
+}
+
+#[test]
+fn delete() {
+    let scenario = r#"
+    let a = { b: { c: 1 } };
+    let n;
+    [delete n?.b, delete a?.b.c, a.b.c, delete a?.["b"], a.b]
+    "#;
+    assert_eq!(
+        &exec(scenario),
+        "[ true, true, undefined, true, undefined ]"
+    );
+}
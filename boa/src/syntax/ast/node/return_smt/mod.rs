@@ -1,7 +1,7 @@
 use crate::{
     exec::{Executable, InterpreterState},
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::node::{Node, NodeSpan},
     Context, Result, Value,
 };
 use std::fmt;
@@ -9,6 +9,9 @@ use std::fmt;
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "vm")]
+use crate::vm::{compilation::CodeGen, Compiler, Instruction};
+
 /// The `return` statement ends function execution and specifies a value to be returned to the
 /// function caller.
 ///
@@ -32,6 +35,7 @@ use serde::{Deserialize, Serialize};
 pub struct Return {
     expr: Option<Box<Node>>,
     label: Option<Box<str>>,
+    span: NodeSpan,
 }
 
 impl Return {
@@ -53,10 +57,13 @@ impl Return {
         Self {
             expr: expr.into().map(E::into).map(Box::new),
             label: label.into(),
+            span: NodeSpan::default(),
         }
     }
 }
 
+impl_span!(Return);
+
 impl Executable for Return {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let result = match self.expr() {
@@ -71,6 +78,17 @@ impl Executable for Return {
     }
 }
 
+#[cfg(feature = "vm")]
+impl CodeGen for Return {
+    fn compile(&self, compiler: &mut Compiler) {
+        match self.expr() {
+            Some(expr) => expr.compile(compiler),
+            None => compiler.add_instruction(Instruction::Undefined),
+        }
+        compiler.compile_return();
+    }
+}
+
 impl From<Return> for Node {
     fn from(return_smt: Return) -> Node {
         Node::Return(return_smt)
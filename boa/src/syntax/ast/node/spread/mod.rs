@@ -1,7 +1,7 @@
 use crate::{
     exec::Executable,
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::node::{Node, NodeSpan},
     Context, Result, Value,
 };
 use std::fmt;
@@ -33,6 +33,8 @@ mod tests;
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct Spread {
     val: Box<Node>,
+    #[cfg_attr(feature = "deser", serde(skip))]
+    span: NodeSpan,
 }
 
 impl Spread {
@@ -47,10 +49,13 @@ impl Spread {
     {
         Self {
             val: Box::new(val.into()),
+            span: NodeSpan::default(),
         }
     }
 }
 
+impl_span!(Spread);
+
 impl Executable for Spread {
     fn run(&self, context: &mut Context) -> Result<Value> {
         // TODO: for now we can do nothing but return the value as-is
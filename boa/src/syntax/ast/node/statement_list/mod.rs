@@ -3,7 +3,7 @@
 use crate::{
     exec::{Executable, InterpreterState},
     gc::{empty_trace, Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::node::{Declaration, Node},
     BoaProfiler, Context, Result, Value,
 };
 use std::{collections::HashSet, fmt, ops::Deref, rc::Rc};
@@ -23,10 +23,18 @@ use crate::vm::{compilation::CodeGen, Compiler};
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-StatementList
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
-#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+#[derive(Clone, Debug, Trace, Finalize)]
 pub struct StatementList {
-    #[cfg_attr(feature = "deser", serde(flatten))]
     items: Box<[Node]>,
+    strict: bool,
+}
+
+/// Like spans, the strictness of parsed statements is not compared, so that they can be compared
+/// with the statements built by hand.
+impl PartialEq for StatementList {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items
+    }
 }
 
 impl StatementList {
@@ -35,17 +43,32 @@ impl StatementList {
         &self.items
     }
 
+    /// Returns `true` if the statements are the body of a script or a function that is strict
+    /// mode code, either because of a `"use strict"` directive or because it is contained in
+    /// strict mode code.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-strict-mode-code
+    pub fn strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Sets whether the statements are strict mode code.
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
     /// Implements the display formatting with indentation.
     pub(in crate::syntax::ast::node) fn display(
         &self,
         f: &mut fmt::Formatter<'_>,
         indentation: usize,
     ) -> fmt::Result {
-        let indent = "    ".repeat(indentation);
         // Print statements
         for node in self.items.iter() {
-            f.write_str(&indent)?;
-            node.display(f, indentation + 1)?;
+            node.display(f, indentation)?;
 
             match node {
                 Node::Block(_) | Node::If(_) | Node::Switch(_) | Node::WhileLoop(_) => {}
@@ -60,14 +83,19 @@ impl StatementList {
         let mut set = HashSet::new();
         for stmt in self.items() {
             if let Node::LetDeclList(decl_list) | Node::ConstDeclList(decl_list) = stmt {
-                for decl in decl_list.as_ref() {
-                    if !set.insert(decl.name()) {
+                for name in decl_list.as_ref().iter().flat_map(Declaration::names) {
+                    if !set.insert(name) {
                         // It is a Syntax Error if the LexicallyDeclaredNames of StatementList contains any duplicate entries.
                         // https://tc39.es/ecma262/#sec-block-static-semantics-early-errors
-                        unreachable!("Redeclaration of {}", decl.name());
+                        unreachable!("Redeclaration of {}", name);
                     }
                 }
             }
+            if let Node::ClassDecl(class) = stmt {
+                if let Some(name) = class.name() {
+                    set.insert(name);
+                }
+            }
         }
         set
     }
@@ -76,9 +104,7 @@ impl StatementList {
         let mut set = HashSet::new();
         for stmt in self.items() {
             if let Node::VarDeclList(decl_list) = stmt {
-                for decl in decl_list.as_ref() {
-                    set.insert(decl.name());
-                }
+                set.extend(decl_list.as_ref().iter().flat_map(Declaration::names));
             }
         }
         set
@@ -89,37 +115,39 @@ impl Executable for StatementList {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("StatementList", "exec");
 
-        // https://tc39.es/ecma262/#sec-block-runtime-semantics-evaluation
-        // The return value is uninitialized, which means it defaults to Value::Undefined
-        let mut obj = Value::default();
-        context
-            .executor()
-            .set_current_state(InterpreterState::Executing);
-        for (i, item) in self.items().iter().enumerate() {
-            let val = item.run(context)?;
-            match context.executor().get_current_state() {
-                InterpreterState::Return => {
-                    // Early return.
-                    obj = val;
-                    break;
-                }
-                InterpreterState::Break(_label) => {
-                    // Early break.
-                    break;
-                }
-                InterpreterState::Continue(_label) => {
-                    break;
+        context.resumable(|frame, context| {
+            // https://tc39.es/ecma262/#sec-block-runtime-semantics-evaluation
+            // The return value is uninitialized, which means it defaults to Value::Undefined
+            let mut obj = Value::default();
+            context
+                .executor()
+                .set_current_state(InterpreterState::Executing);
+            for (i, item) in self.items().iter().enumerate() {
+                let val = frame.run(item, context)?;
+                match context.executor().get_current_state() {
+                    InterpreterState::Return => {
+                        // Early return.
+                        obj = val;
+                        break;
+                    }
+                    InterpreterState::Break(_label) => {
+                        // Early break.
+                        break;
+                    }
+                    InterpreterState::Continue(_label) => {
+                        break;
+                    }
+                    InterpreterState::Executing => {
+                        // Continue execution
+                    }
                 }
-                InterpreterState::Executing => {
-                    // Continue execution
+                if i + 1 == self.items().len() {
+                    obj = val;
                 }
             }
-            if i + 1 == self.items().len() {
-                obj = val;
-            }
-        }
 
-        Ok(obj)
+            Ok(obj)
+        })
     }
 }
 
@@ -129,7 +157,7 @@ impl CodeGen for StatementList {
         let _timer = BoaProfiler::global().start_event("StatementList - Code Gen", "codeGen");
 
         for item in self.items().iter() {
-            item.compile(compiler);
+            compiler.compile_statement(item);
         }
     }
 }
@@ -139,7 +167,10 @@ where
     T: Into<Box<[Node]>>,
 {
     fn from(stm: T) -> Self {
-        Self { items: stm.into() }
+        Self {
+            items: stm.into(),
+            strict: false,
+        }
     }
 }
 
@@ -0,0 +1,100 @@
+use crate::{
+    exec::Executable,
+    gc::{Finalize, Trace},
+    syntax::ast::node::{call::evaluate_arguments, join_nodes, Node, NodeSpan},
+    BoaProfiler, Context, Result, Value,
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+/// The `super(...)` call invokes the constructor of the parent class from the constructor of a
+/// derived class.
+///
+/// The parent constructor creates the instance, which then becomes the `this` value of the
+/// derived constructor. Using `this` before calling `super(...)` throws a `ReferenceError`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-SuperCall
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/super
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct SuperCall {
+    args: Box<[Node]>,
+    span: NodeSpan,
+}
+
+impl SuperCall {
+    /// Creates a new `SuperCall` AST node.
+    pub fn new<A>(args: A) -> Self
+    where
+        A: Into<Box<[Node]>>,
+    {
+        Self {
+            args: args.into(),
+            span: NodeSpan::default(),
+        }
+    }
+
+    /// Retrieves the arguments passed to the parent constructor.
+    pub fn args(&self) -> &[Node] {
+        &self.args
+    }
+}
+
+impl_span!(SuperCall);
+
+impl Executable for SuperCall {
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("SuperCall", "exec");
+
+        let env = context.get_this_environment();
+        let (function, new_target) = match env.borrow().as_function_environment() {
+            Some(env) => (env.function.clone(), env.new_target.clone()),
+            None => return context.throw_syntax_error("'super' keyword unexpected here"),
+        };
+        if new_target.is_undefined() {
+            return context.throw_syntax_error("'super' keyword unexpected here");
+        }
+
+        // GetSuperConstructor ( )
+        let super_constructor = match function.get_prototype_of(context)? {
+            Value::Object(ref object) if object.is_constructable() => object.clone(),
+            _ => return context.throw_type_error("super constructor is not a constructor"),
+        };
+
+        let v_args =
+            context.resumable(|frame, context| evaluate_arguments(self.args(), frame, context))?;
+
+        let result = super_constructor.construct(&v_args, new_target, context)?;
+
+        env.borrow_mut()
+            .as_function_environment_mut()
+            .expect("the this environment must be a function environment")
+            .bind_this_value(result.clone(), context)?;
+
+        if let Value::Object(ref object) = result {
+            object.initialize_instance_elements(&function, context)?;
+        }
+
+        Ok(result)
+    }
+}
+
+impl fmt::Display for SuperCall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("super(")?;
+        join_nodes(f, &self.args)?;
+        f.write_str(")")
+    }
+}
+
+impl From<SuperCall> for Node {
+    fn from(call: SuperCall) -> Self {
+        Self::SuperCall(call)
+    }
+}
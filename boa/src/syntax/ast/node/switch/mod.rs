@@ -3,7 +3,7 @@
 use crate::{
     exec::{Executable, InterpreterState},
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::node::{Node, NodeSpan},
     Context, Result, Value,
 };
 use std::fmt;
@@ -13,6 +13,9 @@ use crate::syntax::ast::node::StatementList;
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "vm")]
+use crate::vm::{compilation::CodeGen, Compiler, Instruction};
+
 #[cfg(test)]
 mod tests;
 
@@ -69,6 +72,7 @@ pub struct Switch {
     val: Box<Node>,
     cases: Box<[Case]>,
     default: Option<StatementList>,
+    span: NodeSpan,
 }
 
 impl Switch {
@@ -83,6 +87,7 @@ impl Switch {
             val: Box::new(val.into()),
             cases: cases.into(),
             default: default.map(D::into),
+            span: NodeSpan::default(),
         }
     }
 
@@ -121,81 +126,136 @@ impl Switch {
     }
 }
 
+impl_span!(Switch);
+
 impl Executable for Switch {
     fn run(&self, context: &mut Context) -> Result<Value> {
-        let val = self.val().run(context)?;
-        let mut result = Value::null();
-        let mut matched = false;
-        context
-            .executor()
-            .set_current_state(InterpreterState::Executing);
-
-        // If a case block does not end with a break statement then subsequent cases will be run without
-        // checking their conditions until a break is encountered.
-        let mut fall_through: bool = false;
-
-        for case in self.cases().iter() {
-            let cond = case.condition();
-            let block = case.body();
-            if fall_through || val.strict_equals(&cond.run(context)?) {
-                matched = true;
-                let result = block.run(context)?;
-                match context.executor().get_current_state() {
-                    InterpreterState::Return => {
-                        // Early return.
-                        return Ok(result);
-                    }
-                    InterpreterState::Break(_label) => {
-                        // TODO, break to a label.
-                        // Break statement encountered so therefore end switch statement.
-                        context
-                            .executor()
-                            .set_current_state(InterpreterState::Executing);
-                        break;
-                    }
-                    InterpreterState::Continue(_label) => {
-                        // TODO, continue to a label.
-                        break;
-                    }
-                    InterpreterState::Executing => {
-                        // Continuing execution / falling through to next case statement(s).
-                        fall_through = true;
-                    }
-                }
-            }
-        }
+        context.resumable(|frame, context| {
+            let val = frame.run(self.val(), context)?;
+            let mut result = Value::null();
+            let mut matched = false;
+            context
+                .executor()
+                .set_current_state(InterpreterState::Executing);
+
+            // If a case block does not end with a break statement then subsequent cases will be run without
+            // checking their conditions until a break is encountered.
+            let mut fall_through: bool = false;
 
-        if !matched {
-            if let Some(default) = self.default() {
-                context
-                    .executor()
-                    .set_current_state(InterpreterState::Executing);
-                for (i, item) in default.iter().enumerate() {
-                    let val = item.run(context)?;
+            for case in self.cases().iter() {
+                let cond = case.condition();
+                let block = case.body();
+                if fall_through || val.strict_equals(&frame.run(cond, context)?) {
+                    matched = true;
+                    let result = frame.eval(context, |context| block.run(context))?;
                     match context.executor().get_current_state() {
                         InterpreterState::Return => {
                             // Early return.
-                            result = val;
-                            break;
+                            return Ok(result);
                         }
-                        InterpreterState::Break(_label) => {
-                            // TODO, break to a label.
-
-                            // Early break.
+                        InterpreterState::Break(None) => {
+                            // Break statement encountered so therefore end switch statement.
+                            context
+                                .executor()
+                                .set_current_state(InterpreterState::Executing);
                             break;
                         }
-                        _ => {
-                            // Continue execution
+                        InterpreterState::Break(Some(_)) | InterpreterState::Continue(_) => {
+                            // Leave the switch, the enclosing loop or labelled statement handles
+                            // the label.
+                            return Ok(result);
+                        }
+                        InterpreterState::Executing => {
+                            // Continuing execution / falling through to next case statement(s).
+                            fall_through = true;
                         }
                     }
-                    if i == default.len() - 1 {
-                        result = val;
+                }
+            }
+
+            if !matched {
+                if let Some(default) = self.default() {
+                    context
+                        .executor()
+                        .set_current_state(InterpreterState::Executing);
+                    for (i, item) in default.iter().enumerate() {
+                        let val = frame.run(item, context)?;
+                        match context.executor().get_current_state() {
+                            InterpreterState::Return => {
+                                // Early return.
+                                result = val;
+                                break;
+                            }
+                            InterpreterState::Break(None) => {
+                                // Early break.
+                                context
+                                    .executor()
+                                    .set_current_state(InterpreterState::Executing);
+                                break;
+                            }
+                            InterpreterState::Break(Some(_)) | InterpreterState::Continue(_) => {
+                                // Leave the switch, the enclosing loop or labelled statement handles
+                                // the label.
+                                return Ok(val);
+                            }
+                            _ => {
+                                // Continue execution
+                            }
+                        }
+                        if i == default.len() - 1 {
+                            result = val;
+                        }
                     }
                 }
             }
+
+            Ok(result)
+        })
+    }
+}
+
+#[cfg(feature = "vm")]
+impl CodeGen for Switch {
+    fn compile(&self, compiler: &mut Compiler) {
+        self.val().compile(compiler);
+        compiler.push_environment();
+        compiler.push_jumpable(&[], false);
+
+        let mut matches = Vec::with_capacity(self.cases().len());
+        for case in self.cases() {
+            compiler.add_instruction(Instruction::Dup);
+            case.condition().compile(compiler);
+            compiler.add_instruction(Instruction::StrictEq);
+            matches.push(compiler.jump(Instruction::JumpIfTrue));
+        }
+        compiler.add_instruction(Instruction::Pop);
+        let default = compiler.jump(Instruction::Jump);
+
+        // The matching case removes the value before jumping to its body.
+        let mut bodies = Vec::with_capacity(matches.len());
+        for jump in matches {
+            compiler.patch_jump(jump);
+            compiler.add_instruction(Instruction::Pop);
+            bodies.push(compiler.jump(Instruction::Jump));
+        }
+
+        for (case, jump) in self.cases().iter().zip(bodies) {
+            compiler.patch_jump(jump);
+            for node in case.body().items() {
+                compiler.compile_statement(node);
+            }
+        }
+        // Like the interpreter, the default case only runs if no other case matches.
+        compiler.compile_break(None);
+        compiler.patch_jump(default);
+        if let Some(default) = self.default() {
+            for node in default {
+                compiler.compile_statement(node);
+            }
         }
 
-        Ok(result)
+        compiler.pop_jumpable(usize::MAX);
+        compiler.pop_environment();
     }
 }
 
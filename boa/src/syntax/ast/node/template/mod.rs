@@ -1,13 +1,19 @@
 //! Template literal node.
 
 use super::Node;
-use crate::{builtins::Array, exec::Executable, value::Type, BoaProfiler, Context, Result, Value};
+use crate::{
+    builtins::Array, exec::Executable, syntax::ast::node::NodeSpan, value::Type, BoaProfiler,
+    Context, Result, Value,
+};
 use gc::{Finalize, Trace};
 
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+#[cfg(feature = "vm")]
+use crate::vm::{compilation::CodeGen, Compiler, Instruction};
+
 #[cfg(test)]
 mod tests;
 
@@ -23,32 +29,66 @@ mod tests;
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct TemplateLit {
     elements: Vec<TemplateElement>,
+    span: NodeSpan,
 }
 
 impl TemplateLit {
     pub fn new(elements: Vec<TemplateElement>) -> Self {
-        TemplateLit { elements }
+        TemplateLit {
+            elements,
+            span: NodeSpan::default(),
+        }
+    }
+
+    /// Gets the strings and expressions of the template literal.
+    #[cfg(feature = "vm")]
+    pub(crate) fn elements(&self) -> &[TemplateElement] {
+        &self.elements
     }
 }
 
+impl_span!(TemplateLit);
+
 impl Executable for TemplateLit {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("TemplateLiteral", "exec");
-        let mut result = String::new();
+        context.resumable(|frame, context| {
+            let mut result = String::new();
+
+            for element in self.elements.iter() {
+                match element {
+                    TemplateElement::String(s) => {
+                        result.push_str(s);
+                    }
+                    TemplateElement::Expr(node) => {
+                        let s = frame.eval(context, |context| {
+                            let value = node.run(context)?;
+                            Ok(value.to_string(context)?.into())
+                        })?;
+                        result.push_str(&s.to_string(context)?);
+                    }
+                }
+            }
+            Ok(result.into())
+        })
+    }
+}
 
-        for element in self.elements.iter() {
+#[cfg(feature = "vm")]
+impl CodeGen for TemplateLit {
+    fn compile(&self, compiler: &mut Compiler) {
+        let _timer = BoaProfiler::global().start_event("TemplateLiteral", "codeGen");
+        compiler.add_string_instruction("");
+        for element in self.elements() {
             match element {
-                TemplateElement::String(s) => {
-                    result.push_str(s);
-                }
+                TemplateElement::String(s) => compiler.add_string_instruction(s.as_ref()),
                 TemplateElement::Expr(node) => {
-                    let value = node.run(context)?;
-                    let s = value.to_string(context)?;
-                    result.push_str(&s);
+                    node.compile(compiler);
+                    compiler.add_instruction(Instruction::ToString);
                 }
             }
+            compiler.add_instruction(Instruction::Add);
         }
-        Ok(result.into())
     }
 }
 
@@ -71,6 +111,7 @@ pub struct TaggedTemplate {
     raws: Vec<Box<str>>,
     cookeds: Vec<Option<Box<str>>>,
     exprs: Vec<Node>,
+    span: NodeSpan,
 }
 
 impl TaggedTemplate {
@@ -85,10 +126,13 @@ impl TaggedTemplate {
             raws,
             cookeds,
             exprs,
+            span: NodeSpan::default(),
         }
     }
 }
 
+impl_span!(TaggedTemplate);
+
 impl Executable for TaggedTemplate {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("TaggedTemplate", "exec");
@@ -109,34 +153,39 @@ impl Executable for TaggedTemplate {
         }
         template_object.set_field("raw", raw_array, context)?;
 
-        let (this, func) = match *self.tag {
-            Node::GetConstField(ref get_const_field) => {
-                let mut obj = get_const_field.obj().run(context)?;
-                if obj.get_type() != Type::Object {
-                    obj = Value::Object(obj.to_object(context)?);
+        context.resumable(|frame, context| {
+            let (this, func) = match *self.tag {
+                Node::GetConstField(ref get_const_field) => {
+                    let obj = frame.eval(context, |context| {
+                        let obj = get_const_field.obj().run(context)?;
+                        if obj.get_type() != Type::Object {
+                            return Ok(Value::Object(obj.to_object(context)?));
+                        }
+                        Ok(obj)
+                    })?;
+                    let func = frame.eval(context, |context| {
+                        obj.get_field(get_const_field.field(), context)
+                    })?;
+                    (obj, func)
                 }
-                (
-                    obj.clone(),
-                    obj.get_field(get_const_field.field(), context)?,
-                )
-            }
-            Node::GetField(ref get_field) => {
-                let obj = get_field.obj().run(context)?;
-                let field = get_field.field().run(context)?;
-                (
-                    obj.clone(),
-                    obj.get_field(field.to_property_key(context)?, context)?,
-                )
-            }
-            _ => (context.global_object().into(), self.tag.run(context)?),
-        };
+                Node::GetField(ref get_field) => {
+                    let obj = frame.run(get_field.obj(), context)?;
+                    let func = frame.eval(context, |context| {
+                        let field = get_field.field().run(context)?;
+                        obj.get_field(field.to_property_key(context)?, context)
+                    })?;
+                    (obj, func)
+                }
+                _ => (Value::undefined(), frame.run(&self.tag, context)?),
+            };
 
-        let mut args = vec![template_object];
-        for expr in self.exprs.iter() {
-            args.push(expr.run(context)?);
-        }
+            let mut args = vec![template_object];
+            for expr in self.exprs.iter() {
+                args.push(frame.run(expr, context)?);
+            }
 
-        context.call(&func, &this, &args)
+            context.call(&func, &this, &args)
+        })
     }
 }
 
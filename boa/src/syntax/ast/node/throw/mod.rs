@@ -1,7 +1,7 @@
 use crate::{
     exec::Executable,
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::node::{Node, NodeSpan},
     Context, Result, Value,
 };
 use std::fmt;
@@ -9,6 +9,9 @@ use std::fmt;
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "vm")]
+use crate::vm::{compilation::CodeGen, Compiler, Instruction};
+
 /// The `throw` statement throws a user-defined exception.
 ///
 /// Syntax: `throw expression;`
@@ -27,6 +30,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct Throw {
     expr: Box<Node>,
+    span: NodeSpan,
 }
 
 impl Throw {
@@ -41,10 +45,13 @@ impl Throw {
     {
         Self {
             expr: Box::new(val.into()),
+            span: NodeSpan::default(),
         }
     }
 }
 
+impl_span!(Throw);
+
 impl Executable for Throw {
     #[inline]
     fn run(&self, context: &mut Context) -> Result<Value> {
@@ -52,6 +59,14 @@ impl Executable for Throw {
     }
 }
 
+#[cfg(feature = "vm")]
+impl CodeGen for Throw {
+    fn compile(&self, compiler: &mut Compiler) {
+        self.expr().compile(compiler);
+        compiler.add_instruction(Instruction::Throw);
+    }
+}
+
 impl fmt::Display for Throw {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "throw {}", self.expr)
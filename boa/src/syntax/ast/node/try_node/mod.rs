@@ -1,11 +1,11 @@
 use crate::{
-    environment::{
-        declarative_environment_record::DeclarativeEnvironmentRecord,
-        lexical_environment::VariableScope,
-    },
-    exec::Executable,
+    environment::declarative_environment_record::DeclarativeEnvironmentRecord,
+    exec::{coroutine::ResumeFrame, Executable, InterpreterState},
     gc::{Finalize, Trace},
-    syntax::ast::node::{Block, Identifier, Node},
+    syntax::ast::node::{
+        declaration::{BindingKind, BindingTarget},
+        Block, Node, NodeSpan,
+    },
     BoaProfiler, Context, Result, Value,
 };
 use std::fmt;
@@ -13,6 +13,9 @@ use std::fmt;
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "vm")]
+use crate::vm::{compilation::CodeGen, Compiler, Instruction};
+
 #[cfg(test)]
 mod tests;
 
@@ -35,6 +38,7 @@ pub struct Try {
     block: Block,
     catch: Option<Catch>,
     finally: Option<Finally>,
+    span: NodeSpan,
 }
 
 impl Try {
@@ -56,6 +60,7 @@ impl Try {
             block: block.into(),
             catch,
             finally,
+            span: NodeSpan::default(),
         }
     }
 
@@ -94,44 +99,149 @@ impl Try {
     }
 }
 
+impl_span!(Try);
+
 impl Executable for Try {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("Try", "exec");
-        let res = self.block().run(context).map_or_else(
-            |err| {
-                if let Some(catch) = self.catch() {
-                    {
-                        let env = context.get_current_environment();
-                        context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
-
-                        if let Some(param) = catch.parameter() {
-                            context.create_mutable_binding(
-                                param.to_owned(),
-                                false,
-                                VariableScope::Block,
-                            )?;
-                            context.initialize_binding(param, err)?;
-                        }
-                    }
+        context.resumable(|frame, context| {
+            let mut res = if let Some(res) = replay_completion(frame) {
+                res
+            } else {
+                let res = self.block().run(context);
+                // Neither the catch nor the finally block run when the function is suspended.
+                if context.is_suspending() {
+                    return res;
+                }
+                save_completion(frame, &res);
+                res
+            };
 
-                    let res = catch.block().run(context);
+            if let (Err(err), Some(catch)) = (&res, self.catch()) {
+                let err = err.clone();
+                let env = frame.environment(context, |context| {
+                    let env = context.get_current_environment();
+                    DeclarativeEnvironmentRecord::new(Some(env))
+                });
+                context.push_environment(env);
 
-                    // pop the block env
-                    let _ = context.pop_environment();
+                if let Some(param) = catch.parameter() {
+                    if let Err(error) = frame.eval(context, |context| {
+                        param.bind(err, BindingKind::Let, context)?;
+                        Ok(Value::undefined())
+                    }) {
+                        let _ = context.pop_environment();
+                        return Err(error);
+                    }
+                }
 
+                res = if let Some(res) = replay_completion(frame) {
                     res
                 } else {
-                    Err(err)
+                    let res = catch.block().run(context);
+                    if context.is_suspending() {
+                        return res;
+                    }
+                    save_completion(frame, &res);
+                    res
+                };
+
+                // pop the block env
+                let _ = context.pop_environment();
+            }
+
+            if let Some(finally) = self.finally() {
+                // A normal completion of the finally block keeps the completion of the try block,
+                // like an early return.
+                let state = frame.state(context);
+                finally.run(context)?;
+                if context.executor().get_current_state() == &InterpreterState::Executing {
+                    context.executor().set_current_state(state);
                 }
-            },
-            Ok,
-        );
+            }
 
-        if let Some(finally) = self.finally() {
-            finally.run(context)?;
+            res
+        })
+    }
+}
+
+/// Hands back the completion of a block that finished before the function was suspended.
+fn replay_completion(frame: &mut ResumeFrame) -> Option<Result<Value>> {
+    let thrown = frame.replay()?.to_boolean();
+    let value = frame
+        .replay()
+        .expect("the completion value must be recorded");
+    Some(if thrown { Err(value) } else { Ok(value) })
+}
+
+/// Records the completion of a block, for the finally block to use it after a suspension.
+fn save_completion(frame: &mut ResumeFrame, res: &Result<Value>) {
+    let (thrown, value) = match res {
+        Ok(value) => (false, value),
+        Err(value) => (true, value),
+    };
+    frame.save(thrown.into());
+    frame.save(value.clone());
+}
+
+#[cfg(feature = "vm")]
+impl CodeGen for Try {
+    fn compile(&self, compiler: &mut Compiler) {
+        let _timer = BoaProfiler::global().start_event("Try", "codeGen");
+        // The finally block protects the catch block too, so its handler is installed first.
+        let finally_handler = self.finally().map(|finally| {
+            let handler = compiler.jump(Instruction::TryStart);
+            compiler.push_handler(Some(finally));
+            handler
+        });
+
+        match self.catch() {
+            Some(catch) => {
+                let catch_handler = compiler.jump(Instruction::TryStart);
+                compiler.push_handler(None);
+                compiler.compile_block(self.block().items());
+                compiler.pop_control();
+                compiler.add_instruction(Instruction::TryEnd);
+                let end = compiler.jump(Instruction::Jump);
+
+                // The handler jumps here with the exception on the stack.
+                compiler.patch_jump(catch_handler);
+                compiler.push_environment();
+                match catch.parameter() {
+                    Some(BindingTarget::Identifier(name)) => {
+                        compiler.add_name_instruction(name.as_ref(), Instruction::DefLet);
+                        compiler.add_name_instruction(name.as_ref(), Instruction::InitLexical);
+                        compiler.add_instruction(Instruction::Pop);
+                    }
+                    Some(BindingTarget::Pattern(pattern)) => {
+                        compiler.add_pattern_instruction(pattern, Instruction::BindLet);
+                    }
+                    Some(BindingTarget::Property(_)) => {
+                        unreachable!("property references are only assignment targets")
+                    }
+                    None => compiler.add_instruction(Instruction::Pop),
+                }
+                compiler.compile_block(catch.block().items());
+                compiler.pop_environment();
+                compiler.patch_jump(end);
+            }
+            None => compiler.compile_block(self.block().items()),
         }
 
-        res
+        if let (Some(finally), Some(finally_handler)) = (self.finally(), finally_handler) {
+            compiler.pop_control();
+            compiler.add_instruction(Instruction::TryEnd);
+            compiler.compile_block(finally.items());
+            let end = compiler.jump(Instruction::Jump);
+
+            // The exception is thrown again after the finally block runs.
+            compiler.patch_jump(finally_handler);
+            compiler.push_value();
+            compiler.compile_block(finally.items());
+            compiler.pop_control();
+            compiler.add_instruction(Instruction::Throw);
+            compiler.patch_jump(end);
+        }
     }
 }
 
@@ -151,7 +261,7 @@ impl From<Try> for Node {
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct Catch {
-    parameter: Option<Identifier>,
+    parameter: Option<BindingTarget>,
     block: Block,
 }
 
@@ -160,7 +270,7 @@ impl Catch {
     pub(in crate::syntax) fn new<OI, I, B>(parameter: OI, block: B) -> Self
     where
         OI: Into<Option<I>>,
-        I: Into<Identifier>,
+        I: Into<BindingTarget>,
         B: Into<Block>,
     {
         Self {
@@ -170,8 +280,8 @@ impl Catch {
     }
 
     /// Gets the parameter of the catch block.
-    pub fn parameter(&self) -> Option<&str> {
-        self.parameter.as_ref().map(Identifier::as_ref)
+    pub fn parameter(&self) -> Option<&BindingTarget> {
+        self.parameter.as_ref()
     }
 
     /// Retrieves the catch execution block.
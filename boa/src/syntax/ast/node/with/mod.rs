@@ -0,0 +1,123 @@
+use crate::{
+    environment::object_environment_record::ObjectEnvironmentRecord,
+    exec::Executable,
+    gc::{Finalize, Trace},
+    syntax::ast::node::{Node, NodeSpan},
+    BoaProfiler, Context, Result, Value,
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "vm")]
+use crate::vm::{compilation::CodeGen, Compiler};
+
+#[cfg(test)]
+mod tests;
+
+/// The `with` statement extends the scope chain of a statement with the properties of an object.
+///
+/// The names used in the statement are first looked up in the object, except for the ones listed
+/// in its `@@unscopables` property. Functions found in the object are called with the object as
+/// their `this` value.
+///
+/// The `with` statement is not allowed in strict mode code.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-WithStatement
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/with
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct With {
+    expr: Box<Node>,
+    body: Box<Node>,
+    span: NodeSpan,
+}
+
+impl With {
+    /// Creates a `With` AST node.
+    pub fn new<E, B>(expr: E, body: B) -> Self
+    where
+        E: Into<Node>,
+        B: Into<Node>,
+    {
+        Self {
+            expr: Box::new(expr.into()),
+            body: Box::new(body.into()),
+            span: NodeSpan::default(),
+        }
+    }
+
+    /// Gets the expression of the object whose properties are added to the scope chain.
+    pub fn expr(&self) -> &Node {
+        &self.expr
+    }
+
+    /// Gets the statement executed with the properties of the object in scope.
+    pub fn body(&self) -> &Node {
+        &self.body
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        write!(f, "with ({}) ", self.expr)?;
+        self.body.display(f, indentation)
+    }
+}
+
+impl_span!(With);
+
+impl Executable for With {
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("With", "exec");
+        context.resumable(|frame, context| {
+            let object = frame.eval(context, |context| {
+                Ok(self.expr().run(context)?.to_object(context)?.into())
+            })?;
+
+            let env = frame.environment(context, |context| {
+                let env = context.get_current_environment();
+                ObjectEnvironmentRecord::new(object, true, Some(env))
+            });
+            context.push_environment(env);
+
+            let result = self.body().run(context);
+
+            // No matter how control leaves the statement, the environment is always restored.
+            context.pop_environment();
+
+            result
+        })
+    }
+}
+
+#[cfg(feature = "vm")]
+impl CodeGen for With {
+    fn compile(&self, compiler: &mut Compiler) {
+        let _timer = BoaProfiler::global().start_event("With", "codeGen");
+        self.expr().compile(compiler);
+        compiler.push_with_environment();
+        compiler.compile_statement(self.body());
+        compiler.pop_environment();
+    }
+}
+
+impl fmt::Display for With {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<With> for Node {
+    fn from(with: With) -> Self {
+        Self::With(with)
+    }
+}
@@ -0,0 +1,147 @@
+use crate::exec;
+
+#[test]
+fn with_reads_and_writes_properties() {
+    let scenario = r#"
+        let obj = { a: 1 };
+        let b = 2;
+        with (obj) {
+            a = a + b;
+            b = 5;
+        }
+        [obj.a, b].join()
+    "#;
+    assert_eq!(&exec(scenario), r#""3,5""#);
+}
+
+#[test]
+fn with_var_declaration() {
+    let scenario = r#"
+        let obj = { a: 1 };
+        with (obj) {
+            var a = 2, c = 3;
+        }
+        [obj.a, a === undefined, c, "c" in obj].join()
+    "#;
+    assert_eq!(&exec(scenario), r#""2,true,3,false""#);
+}
+
+#[test]
+fn with_environment_is_restored() {
+    let scenario = r#"
+        let a = "outer";
+        try {
+            with ({ a: "inner" }) {
+                throw a;
+            }
+        } catch (e) {
+            [e, a].join()
+        }
+    "#;
+    assert_eq!(&exec(scenario), r#""inner,outer""#);
+}
+
+#[test]
+fn with_unscopables() {
+    let scenario = r#"
+        let keys = "outer";
+        let values = [];
+        let result;
+        with ([]) {
+            result = [typeof keys, typeof values, typeof push];
+        }
+        result.join()
+    "#;
+    assert_eq!(&exec(scenario), r#""string,object,function""#);
+
+    let scenario = r#"
+        let a = "outer";
+        let obj = { a: "inner", b: "inner" };
+        obj[Symbol.unscopables] = { a: true, b: false };
+        let result;
+        with (obj) {
+            a = "assigned";
+            result = [a, b];
+        }
+        [result, obj.a].join()
+    "#;
+    assert_eq!(&exec(scenario), r#""assigned,inner,inner""#);
+}
+
+#[test]
+fn with_call_this() {
+    let scenario = r#"
+        let obj = {
+            name: "obj",
+            getName() {
+                return this.name;
+            }
+        };
+        with (obj) {
+            getName()
+        }
+    "#;
+    assert_eq!(&exec(scenario), r#""obj""#);
+}
+
+#[test]
+fn with_accessors() {
+    let scenario = r#"
+        let log = [];
+        let obj = {
+            get a() {
+                log.push("get");
+                return 1;
+            },
+            set a(value) {
+                log.push("set " + value);
+            }
+        };
+        with (obj) {
+            a = a + 1;
+        }
+        log.join()
+    "#;
+    assert_eq!(&exec(scenario), r#""get,set 2""#);
+}
+
+#[test]
+fn with_proxy_has_trap() {
+    let scenario = r#"
+        let log = [];
+        let b = "outer";
+        let proxy = new Proxy({ a: "proxy", b: "proxy" }, {
+            has(target, key) {
+                log.push(key);
+                return key === "a";
+            }
+        });
+        let result;
+        with (proxy) {
+            result = [a, b];
+        }
+        [log.sort().join(), result.join()].join(" ")
+    "#;
+    assert_eq!(&exec(scenario), r#""a,b,result proxy,outer""#);
+}
+
+#[test]
+fn with_unscopables_getter() {
+    let scenario = r#"
+        let log = [];
+        let a = "outer";
+        let obj = { a: "inner" };
+        Object.defineProperty(obj, Symbol.unscopables, {
+            get() {
+                log.push("unscopables");
+                return { a: true };
+            }
+        });
+        let result;
+        with (obj) {
+            result = a;
+        }
+        [log.join(), result].join(" ")
+    "#;
+    assert_eq!(&exec(scenario), r#""unscopables outer""#);
+}
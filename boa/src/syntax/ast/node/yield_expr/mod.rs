@@ -0,0 +1,183 @@
+//! Yield expression node.
+
+use super::Node;
+use crate::{
+    builtins::iterable::{create_iter_result_object, get_iterator},
+    exec::{coroutine::ResumeAction, Executable, InterpreterState},
+    syntax::ast::node::NodeSpan,
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+/// The `yield` keyword is used to pause and resume a generator function.
+///
+/// The `yield*` form delegates to another iterable, yielding each of its values in turn, and
+/// evaluates to the value the delegated iterator completes with.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-YieldExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/yield
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct YieldExpr {
+    expr: Option<Box<Node>>,
+    delegate: bool,
+    span: NodeSpan,
+}
+
+impl YieldExpr {
+    /// Creates a new yield expression.
+    pub fn new<E, OE>(expr: OE, delegate: bool) -> Self
+    where
+        E: Into<Node>,
+        OE: Into<Option<E>>,
+    {
+        Self {
+            expr: expr.into().map(E::into).map(Box::new),
+            delegate,
+            span: NodeSpan::default(),
+        }
+    }
+
+    /// Gets the expression of the yield, if any.
+    pub fn expr(&self) -> Option<&Node> {
+        self.expr.as_ref().map(Box::as_ref)
+    }
+
+    /// Returns `true` if this is a delegating `yield*` expression.
+    pub fn delegate(&self) -> bool {
+        self.delegate
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(super) fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
+        f.write_str(if self.delegate { "yield*" } else { "yield" })?;
+        if let Some(ref expr) = self.expr {
+            f.write_str(" ")?;
+            expr.display(f, indentation)?;
+        }
+        Ok(())
+    }
+}
+
+impl_span!(YieldExpr);
+
+/// Continues the evaluation of a resumed `yield` expression.
+///
+/// A return completion is propagated like a `return` statement.
+fn resume_value(action: ResumeAction, context: &mut Context) -> Result<Value> {
+    match action {
+        ResumeAction::Next(value) => Ok(value),
+        ResumeAction::Throw(error) => Err(error),
+        ResumeAction::Return(value) => {
+            context
+                .executor()
+                .set_current_state(InterpreterState::Return);
+            Ok(value)
+        }
+    }
+}
+
+impl Executable for YieldExpr {
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("YieldExpression", "exec");
+        context.resumable(|frame, context| {
+            let value = match self.expr {
+                Some(ref expr) => frame.run(expr, context)?,
+                None => Value::undefined(),
+            };
+
+            if !self.delegate {
+                let result = create_iter_result_object(context, value, false);
+                let action = context.suspend(frame, result)?;
+                return resume_value(action, context);
+            }
+
+            let iterator_record =
+                frame.iterator(context, |context| get_iterator(context, value))?;
+            let iterator = iterator_record.iterator_object().clone();
+            let mut received = ResumeAction::Next(Value::undefined());
+
+            // Every iteration is replayed up to its suspension when the generator is resumed.
+            let checkpoint = frame.checkpoint();
+            loop {
+                frame.restart(checkpoint);
+                let is_return = matches!(received, ResumeAction::Return(_));
+                let inner_result = if let Some(inner_result) = frame.replay() {
+                    inner_result
+                } else {
+                    let inner_result = match received {
+                        ResumeAction::Next(value) => {
+                            context.call(iterator_record.next_function(), &iterator, &[value])?
+                        }
+                        ResumeAction::Throw(error) => {
+                            let throw = match iterator.as_object() {
+                                Some(object) => object.get_method(context, "throw")?,
+                                None => None,
+                            };
+                            match throw {
+                                Some(throw) => context.call(&throw.into(), &iterator, &[error])?,
+                                None => {
+                                    iterator_record.close(Ok(Value::undefined()), context)?;
+                                    return context.throw_type_error(
+                                        "the iterator does not provide a 'throw' method",
+                                    );
+                                }
+                            }
+                        }
+                        ResumeAction::Return(value) => {
+                            let r#return = match iterator.as_object() {
+                                Some(object) => object.get_method(context, "return")?,
+                                None => None,
+                            };
+                            match r#return {
+                                Some(r#return) => {
+                                    context.call(&r#return.into(), &iterator, &[value])?
+                                }
+                                None => return resume_value(ResumeAction::Return(value), context),
+                            }
+                        }
+                    };
+                    frame.save(inner_result.clone());
+                    inner_result
+                };
+
+                if !inner_result.is_object() {
+                    return context.throw_type_error("iterator result is not an object");
+                }
+                let done = frame.eval(context, |context| {
+                    Ok(inner_result.get_field("done", context)?.to_boolean().into())
+                })?;
+                if done.to_boolean() {
+                    let value = inner_result.get_field("value", context)?;
+                    return if is_return {
+                        resume_value(ResumeAction::Return(value), context)
+                    } else {
+                        Ok(value)
+                    };
+                }
+
+                received = context.suspend(frame, inner_result)?;
+            }
+        })
+    }
+}
+
+impl fmt::Display for YieldExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<YieldExpr> for Node {
+    fn from(expr: YieldExpr) -> Self {
+        Self::YieldExpr(expr)
+    }
+}
@@ -1,5 +1,8 @@
 //! Module implementing the lexer cursor. This is used for managing the input byte stream.
-use crate::{profiler::BoaProfiler, syntax::ast::Position};
+use crate::{
+    profiler::BoaProfiler,
+    syntax::ast::{Position, Span},
+};
 use std::io::{self, Bytes, Error, ErrorKind, Read};
 
 /// Cursor over the source code.
@@ -7,7 +10,13 @@ use std::io::{self, Bytes, Error, ErrorKind, Read};
 pub(super) struct Cursor<R> {
     iter: InnerIter<R>,
     pos: Position,
+    /// The position of the first character of the source code.
+    start: Position,
+    /// The offsets of the lines in the source code read so far.
+    line_starts: Vec<usize>,
     strict_mode: bool,
+    property_names: bool,
+    member_name: bool,
 }
 
 impl<R> Cursor<R> {
@@ -29,6 +38,43 @@ impl<R> Cursor<R> {
     fn next_line(&mut self) {
         let next_line = self.pos.line_number() + 1;
         self.pos = Position::new(next_line, 1);
+        self.line_starts.push(self.iter.offset());
+    }
+
+    /// Gets the source text of the given span of the code read so far.
+    pub(super) fn source_text(&self, span: Span) -> Option<&str> {
+        let start = self.offset(span.start())?;
+        let end = self.offset(span.end())?;
+        std::str::from_utf8(self.iter.source.get(start..end)?).ok()
+    }
+
+    /// Gets the offset of a position in the source code read so far.
+    ///
+    /// Columns count characters, and a line can start with the rest of the line terminator
+    /// before it, as the lexer moves to the next line after the first byte of `U+2028` and
+    /// `U+2029`.
+    fn offset(&self, pos: Position) -> Option<usize> {
+        let line = pos.line_number().checked_sub(self.start.line_number())?;
+        let mut offset = *self.line_starts.get(line as usize)?;
+        let mut column = if line == 0 {
+            self.start.column_number()
+        } else {
+            1
+        };
+        let source = &self.iter.source;
+        let is_continuation =
+            |offset| matches!(source.get(offset), Some(&b) if !utf8_is_first_byte(b));
+        while is_continuation(offset) {
+            offset += 1;
+        }
+        while column < pos.column_number() {
+            offset += 1;
+            while is_continuation(offset) {
+                offset += 1;
+            }
+            column += 1;
+        }
+        Some(offset)
     }
 
     #[inline]
@@ -40,6 +86,29 @@ impl<R> Cursor<R> {
     pub(super) fn set_strict_mode(&mut self, strict_mode: bool) {
         self.strict_mode = strict_mode
     }
+
+    /// Checks if the identifiers are read in the body of a class or of an object literal, where
+    /// the strict mode reserved words can be the names of properties.
+    #[inline]
+    pub(super) fn property_names(&self) -> bool {
+        self.property_names
+    }
+
+    #[inline]
+    pub(super) fn set_property_names(&mut self, property_names: bool) {
+        self.property_names = property_names
+    }
+
+    /// Checks if the next identifier is the name of a property accessed with `.` or `?.`.
+    #[inline]
+    pub(super) fn member_name(&self) -> bool {
+        self.member_name
+    }
+
+    #[inline]
+    pub(super) fn set_member_name(&mut self, member_name: bool) {
+        self.member_name = member_name
+    }
 }
 
 impl<R> Cursor<R>
@@ -52,7 +121,11 @@ where
         Self {
             iter: InnerIter::new(inner.bytes()),
             pos: Position::new(1, 1),
+            start: Position::new(1, 1),
+            line_starts: vec![0],
             strict_mode: false,
+            property_names: false,
+            member_name: false,
         }
     }
 
@@ -62,7 +135,11 @@ where
         Self {
             iter: InnerIter::new(inner.bytes()),
             pos,
+            start: pos,
+            line_starts: vec![0],
             strict_mode: false,
+            property_names: false,
+            member_name: false,
         }
     }
 
@@ -286,6 +363,8 @@ where
 #[derive(Debug)]
 struct InnerIter<R> {
     iter: Bytes<R>,
+    /// The bytes read so far, including the peeked ones.
+    source: Vec<u8>,
     num_peeked_bytes: u8,
     peeked_bytes: u32,
     peeked_char: Option<Option<u32>>,
@@ -297,11 +376,18 @@ impl<R> InnerIter<R> {
     fn new(iter: Bytes<R>) -> Self {
         Self {
             iter,
+            source: Vec::new(),
             num_peeked_bytes: 0,
             peeked_bytes: 0,
             peeked_char: None,
         }
     }
+
+    /// Gets the offset of the next byte, which is the number of bytes consumed so far.
+    #[inline]
+    fn offset(&self) -> usize {
+        self.source.len() - self.num_peeked_bytes as usize
+    }
 }
 
 impl<R> InnerIter<R>
@@ -343,7 +429,7 @@ where
             let byte = self.peeked_bytes as u8;
             Ok(Some(byte))
         } else {
-            match self.iter.next().transpose()? {
+            match self.read_byte()? {
                 Some(byte) => {
                     self.num_peeked_bytes = 1;
                     self.peeked_bytes = byte as u32;
@@ -358,7 +444,7 @@ where
     #[inline]
     pub(super) fn peek_n_bytes(&mut self, n: u8) -> Result<u32, Error> {
         while self.num_peeked_bytes < n && self.num_peeked_bytes < 4 {
-            match self.iter.next().transpose()? {
+            match self.read_byte()? {
                 Some(byte) => {
                     self.peeked_bytes |= (byte as u32) << (self.num_peeked_bytes * 8);
                     self.num_peeked_bytes += 1;
@@ -430,10 +516,20 @@ where
             self.peeked_bytes >>= 8;
             Ok(Some(byte))
         } else {
-            self.iter.next().transpose()
+            self.read_byte()
         }
     }
 
+    /// Reads the next byte of the source code, recording it.
+    #[inline]
+    fn read_byte(&mut self) -> io::Result<Option<u8>> {
+        let byte = self.iter.next().transpose()?;
+        if let Some(byte) = byte {
+            self.source.push(byte);
+        }
+        Ok(byte)
+    }
+
     /// Retrieves the next unchecked char in u32 code point.
     #[inline]
     fn next_char(&mut self) -> io::Result<Option<u32>> {
@@ -496,7 +592,7 @@ fn utf8_acc_cont_byte(ch: u32, byte: u8) -> u32 {
 /// bits `11`).
 #[inline]
 fn utf8_is_first_byte(byte: u8) -> bool {
-    byte <= 0x7F || (byte >> 6) == 0x11
+    byte <= 0x7F || (byte >> 6) == 0b11
 }
 
 #[inline]
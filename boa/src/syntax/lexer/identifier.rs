@@ -13,9 +13,16 @@ use core::convert::TryFrom;
 use std::io::Read;
 use std::str;
 
-const STRICT_FORBIDDEN_IDENTIFIERS: [&str; 11] = [
-    "eval",
-    "arguments",
+/// Identifiers that are reserved words in strict mode code.
+///
+/// The lexer rejects them, unless they are the names of properties. The parser rejects them
+/// where they are bound or referenced.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-keywords-and-reserved-words
+pub(crate) const STRICT_FORBIDDEN_IDENTIFIERS: [&str; 9] = [
     "implements",
     "interface",
     "let",
@@ -108,7 +115,11 @@ impl<R> Tokenizer<R> for Identifier {
                     }
                     TokenKind::Keyword(keyword)
                 } else {
-                    if cursor.strict_mode() && STRICT_FORBIDDEN_IDENTIFIERS.contains(&slice) {
+                    if cursor.strict_mode()
+                        && !cursor.property_names()
+                        && !cursor.member_name()
+                        && STRICT_FORBIDDEN_IDENTIFIERS.contains(&slice)
+                    {
                         return Err(Error::Syntax(
                             format!(
                                 "using future reserved keyword '{}' not allowed in strict mode",
@@ -126,3 +137,44 @@ impl<R> Tokenizer<R> for Identifier {
         Ok(Token::new(tk, Span::new(start_pos, cursor.pos())))
     }
 }
+
+/// Private identifier lexing, like `#x`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-PrivateIdentifier
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes/Private_class_fields
+#[derive(Debug, Clone, Copy)]
+pub(super) struct PrivateIdentifier;
+
+impl<R> Tokenizer<R> for PrivateIdentifier {
+    fn lex(&mut self, cursor: &mut Cursor<R>, start_pos: Position) -> Result<Token, Error>
+    where
+        R: Read,
+    {
+        let _timer = BoaProfiler::global().start_event("PrivateIdentifier", "Lexing");
+
+        let init = match cursor.next_char()?.and_then(|ch| char::try_from(ch).ok()) {
+            Some(init) if Identifier::is_identifier_start(init as u32) => init,
+            _ => {
+                return Err(Error::syntax(
+                    "private identifiers must start with an identifier character",
+                    start_pos,
+                ))
+            }
+        };
+
+        let mut buf = vec![b'#'];
+        let mut init_buf = [0u8; 4];
+        buf.extend(init.encode_utf8(&mut init_buf).as_bytes());
+        cursor.take_while_char_pred(&mut buf, &Identifier::is_identifier_part)?;
+
+        let name = str::from_utf8(buf.as_slice()).expect("identifiers must be valid UTF-8");
+        Ok(Token::new(
+            TokenKind::private_identifier(name),
+            Span::new(start_pos, cursor.pos()),
+        ))
+    }
+}
@@ -32,7 +32,7 @@ mod tests;
 use self::{
     comment::{MultiLineComment, SingleLineComment},
     cursor::Cursor,
-    identifier::Identifier,
+    identifier::{Identifier, PrivateIdentifier},
     number::NumberLiteral,
     operator::Operator,
     regex::RegexLiteral,
@@ -44,6 +44,7 @@ use crate::syntax::ast::{Punctuator, Span};
 pub use crate::{profiler::BoaProfiler, syntax::ast::Position};
 use core::convert::TryFrom;
 pub use error::Error;
+pub(crate) use identifier::STRICT_FORBIDDEN_IDENTIFIERS;
 use std::io::Read;
 pub use token::{Token, TokenKind};
 
@@ -101,6 +102,24 @@ impl<R> Lexer<R> {
         self.cursor.set_strict_mode(strict_mode)
     }
 
+    /// Gets the source text of the given span of the code lexed so far.
+    #[inline]
+    pub(super) fn source_text(&self, span: Span) -> Option<&str> {
+        self.cursor.source_text(span)
+    }
+
+    /// Sets if the identifiers are read in the body of a class or of an object literal, where
+    /// the strict mode reserved words can be the names of properties.
+    #[inline]
+    pub(super) fn set_property_names(&mut self, property_names: bool) {
+        self.cursor.set_property_names(property_names)
+    }
+
+    #[inline]
+    pub(super) fn property_names(&self) -> bool {
+        self.cursor.property_names()
+    }
+
     /// Creates a new lexer.
     #[inline]
     pub fn new(reader: R) -> Self
@@ -249,6 +268,7 @@ impl<R> Lexer<R> {
                 _ if c.is_digit(10) => {
                     NumberLiteral::new(next_ch as u8).lex(&mut self.cursor, start)
                 }
+                '#' => PrivateIdentifier.lex(&mut self.cursor, start),
                 _ if Identifier::is_identifier_start(c as u32) => {
                     Identifier::new(c).lex(&mut self.cursor, start)
                 }
@@ -263,11 +283,18 @@ impl<R> Lexer<R> {
                 }
             }?;
 
-            if token.kind() == &TokenKind::Comment {
+            match token.kind() {
                 // Skip comment
-                self.next()
-            } else {
-                Ok(Some(token))
+                TokenKind::Comment => self.next(),
+                TokenKind::LineTerminator => Ok(Some(token)),
+                kind => {
+                    self.cursor.set_member_name(matches!(
+                        kind,
+                        TokenKind::Punctuator(Punctuator::Dot)
+                            | TokenKind::Punctuator(Punctuator::Optional)
+                    ));
+                    Ok(Some(token))
+                }
             }
         } else {
             Err(Error::syntax(
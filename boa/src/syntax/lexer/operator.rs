@@ -20,7 +20,6 @@ macro_rules! vop {
             None => Err(Error::syntax("abrupt end - could not preview next value as part of the operator", $cursor.pos())),
             Some(b'=') => {
                 $cursor.next_byte()?.expect("= token vanished");
-                $cursor.next_column();
                 $assign_op
             }
             Some(_) => $op,
@@ -31,12 +30,10 @@ macro_rules! vop {
             None => Err(Error::syntax("abrupt end - could not preview next value as part of the operator", $cursor.pos())),
             Some(b'=') => {
                 $cursor.next_byte()?.expect("= token vanished");
-                $cursor.next_column();
                 $assign_op
             },
             $($case => {
                 $cursor.next_byte()?.expect("Token vanished");
-                $cursor.next_column();
                 $block
             })+,
             _ => $op,
@@ -46,7 +43,6 @@ macro_rules! vop {
         match $cursor.peek().ok_or_else(|| Error::syntax("could not preview next value", $cursor.pos()))? {
             $($case => {
                 $cursor.next_byte()?;
-                $cursor.next_column();
                 $block
             })+,
             _ => $op
@@ -134,6 +130,14 @@ impl<R> Tokenizer<R> for Operator {
                         Ok(Punctuator::Coalesce)
                     )
                 }
+                // `?.` followed by a digit is a conditional operator and a number, like `a?.5:b`.
+                Some(b'.') if !((cursor.peek_n(2)? >> 8) as u8).is_ascii_digit() => {
+                    let _ = cursor.next_byte()?.expect(". vanished");
+                    Ok(Token::new(
+                        TokenKind::Punctuator(Punctuator::Optional),
+                        Span::new(start_pos, cursor.pos()),
+                    ))
+                }
                 _ => Ok(Token::new(
                     TokenKind::Punctuator(Punctuator::Question),
                     Span::new(start_pos, cursor.pos()),
@@ -378,6 +378,35 @@ fn check_line_numbers() {
     assert_eq!(lexer.next().unwrap().unwrap().span(), span((2, 2), (3, 1)));
 }
 
+#[test]
+fn check_positions_operators() {
+    let s = "a += b === c";
+
+    let mut lexer = Lexer::new(s.as_bytes());
+
+    assert_eq!(lexer.next().unwrap().unwrap().span(), span((1, 1), (1, 2)));
+    assert_eq!(lexer.next().unwrap().unwrap().span(), span((1, 3), (1, 5)));
+    assert_eq!(lexer.next().unwrap().unwrap().span(), span((1, 6), (1, 7)));
+    assert_eq!(lexer.next().unwrap().unwrap().span(), span((1, 8), (1, 11)));
+    assert_eq!(
+        lexer.next().unwrap().unwrap().span(),
+        span((1, 12), (1, 13))
+    );
+}
+
+#[test]
+fn check_source_text() {
+    let s = "x\r\n'\u{2764}' \u{2028}y\n z";
+
+    let mut lexer = Lexer::new(s.as_bytes());
+    while lexer.next().unwrap().is_some() {}
+
+    assert_eq!(lexer.source_text(span((1, 1), (1, 2))), Some("x"));
+    assert_eq!(lexer.source_text(span((2, 1), (2, 4))), Some("'\u{2764}'"));
+    assert_eq!(lexer.source_text(span((3, 1), (4, 3))), Some("y\n z"));
+    assert_eq!(lexer.source_text(span((5, 1), (5, 2))), None);
+}
+
 // Increment/Decrement
 #[test]
 fn check_decrement_advances_lexer_2_places() {
@@ -111,6 +111,9 @@ pub enum TokenKind {
     /// see: [`Keyword`](../keyword/enum.Keyword.html)
     Keyword(Keyword),
 
+    /// A private identifier, like `#x`, including the `#`.
+    PrivateIdentifier(Box<str>),
+
     /// A `null` literal.
     NullLiteral,
 
@@ -184,6 +187,14 @@ impl TokenKind {
         Self::Identifier(ident.into())
     }
 
+    /// Creates a `PrivateIdentifier` token type.
+    pub fn private_identifier<I>(ident: I) -> Self
+    where
+        I: Into<Box<str>>,
+    {
+        Self::PrivateIdentifier(ident.into())
+    }
+
     /// Creates a `Keyword` token kind.
     pub fn keyword(keyword: Keyword) -> Self {
         Self::Keyword(keyword)
@@ -245,6 +256,7 @@ impl Display for TokenKind {
             Self::EOF => write!(f, "end of file"),
             Self::Identifier(ref ident) => write!(f, "{}", ident),
             Self::Keyword(ref word) => write!(f, "{}", word),
+            Self::PrivateIdentifier(ref ident) => write!(f, "{}", ident),
             Self::NullLiteral => write!(f, "null"),
             Self::NumericLiteral(Numeric::Rational(num)) => write!(f, "{}", num),
             Self::NumericLiteral(Numeric::Integer(num)) => write!(f, "{}", num),
@@ -0,0 +1,459 @@
+//! Class definition parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes
+//! [spec]: https://tc39.es/ecma262/#sec-class-definitions
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    syntax::{
+        ast::{
+            node::{self, Class, FunctionExpr, MethodDefinitionKind, Node, PropertyName},
+            Keyword, Punctuator,
+        },
+        lexer::{Token, TokenKind},
+        parser::{
+            expression::{AssignmentExpression, LeftHandSideExpression},
+            function::{check_function_early_errors, FormalParameters, FunctionBody},
+            AllowAwait, AllowYield, Cursor, ParseError, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+use std::io::Read;
+
+/// Class tail parsing.
+///
+/// Parses the optional `extends` clause and the body of a class. All parts of a class are
+/// strict mode code.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassTail
+#[derive(Debug, Clone)]
+pub(in crate::syntax::parser) struct ClassTail {
+    name: Option<Box<str>>,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ClassTail {
+    /// Creates a new `ClassTail` parser.
+    pub(in crate::syntax::parser) fn new<N, Y, A>(name: N, allow_yield: Y, allow_await: A) -> Self
+    where
+        N: Into<Option<Box<str>>>,
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            name: name.into(),
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ClassTail
+where
+    R: Read,
+{
+    type Output = Class;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ClassTail", "Parsing");
+
+        let strict_mode = cursor.strict_mode();
+        let property_names = cursor.property_names();
+        cursor.set_strict_mode(true);
+        let result = self.parse_tail(cursor);
+        cursor.set_strict_mode(strict_mode);
+        cursor.set_property_names(property_names);
+
+        result
+    }
+}
+
+impl ClassTail {
+    /// Parses the tail of the class once strict mode has been enabled.
+    fn parse_tail<R>(self, cursor: &mut Cursor<R>) -> Result<Class, ParseError>
+    where
+        R: Read,
+    {
+        let super_ref = if cursor.next_if(Keyword::Extends)?.is_some() {
+            Some(LeftHandSideExpression::new(self.allow_yield, self.allow_await).parse(cursor)?)
+        } else {
+            None
+        };
+
+        // The names of the elements can be strict mode reserved words, like `static`.
+        cursor.set_property_names(true);
+        cursor.expect(Punctuator::OpenBlock, "class tail")?;
+        cursor.push_private_scope();
+
+        let mut constructor = None;
+        let mut elements: Vec<node::ClassElement> = Vec::new();
+        loop {
+            let token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+            match token.kind() {
+                TokenKind::Punctuator(Punctuator::CloseBlock) => break,
+                TokenKind::Punctuator(Punctuator::Semicolon) => {
+                    let _ = cursor.next()?.expect("semicolon token disappeared");
+                    continue;
+                }
+                _ => {}
+            }
+
+            let position = token.span().start();
+            match ClassElement::new(self.allow_yield, self.allow_await).parse(cursor)? {
+                ClassElementNode::Constructor(_) if constructor.is_some() => {
+                    return Err(ParseError::general(
+                        "a class may only have one constructor",
+                        position,
+                    ));
+                }
+                ClassElementNode::Constructor(function) => constructor = Some(function),
+                ClassElementNode::Element(element) => {
+                    if !is_valid_private_declaration(&elements, &element) {
+                        return Err(ParseError::general(
+                            "private names can only be declared once in a class body",
+                            position,
+                        ));
+                    }
+                    elements.push(*element)
+                }
+            }
+        }
+
+        cursor.expect(Punctuator::CloseBlock, "class tail")?;
+        let private_names: Vec<&str> = elements
+            .iter()
+            .filter_map(|element| element_name(element).1.private_name())
+            .collect();
+        cursor.pop_private_scope(&private_names)?;
+
+        Ok(Class::new(self.name, super_ref, constructor, elements))
+    }
+}
+
+/// The result of parsing a class element.
+#[derive(Debug)]
+enum ClassElementNode {
+    Constructor(FunctionExpr),
+    Element(Box<node::ClassElement>),
+}
+
+/// Class element parsing.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassElement
+#[derive(Debug, Clone, Copy)]
+struct ClassElement {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ClassElement {
+    /// Creates a new `ClassElement` parser.
+    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ClassElement
+where
+    R: Read,
+{
+    type Output = ClassElementNode;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ClassElement", "Parsing");
+
+        // `static` is only a modifier if it is followed by the name of the element.
+        let is_static = matches!(
+            cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind(),
+            TokenKind::Identifier(name) if name.as_ref() == "static"
+        ) && matches!(
+            cursor.peek(1)?.map(|t| t.kind()),
+            kind if is_property_name_start(kind)
+                || kind == Some(&TokenKind::Punctuator(Punctuator::Mul))
+        );
+        if is_static {
+            let _ = cursor.next()?.expect("static token disappeared");
+        }
+
+        let position = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
+        let is_generator = cursor.next_if(Punctuator::Mul)?.is_some();
+        let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+        // `async` is only a modifier if it is followed by the name of the method on the same line.
+        let is_async = !is_generator
+            && token.kind() == &TokenKind::Keyword(Keyword::Async)
+            && is_property_name_start(cursor.peek(0)?.map(|t| t.kind()))
+            && cursor
+                .peek_expect_no_lineterminator(0, "async method definition")
+                .is_ok();
+        let (kind, name) = match token.kind() {
+            _ if is_generator => (
+                MethodDefinitionKind::Generator,
+                ClassElementName::new(self.allow_yield, self.allow_await, token).parse(cursor)?,
+            ),
+            _ if is_async => {
+                let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+                (
+                    MethodDefinitionKind::Async,
+                    ClassElementName::new(self.allow_yield, self.allow_await, token)
+                        .parse(cursor)?,
+                )
+            }
+            TokenKind::Identifier(name)
+                if (name.as_ref() == "get" || name.as_ref() == "set")
+                    && is_property_name_start(cursor.peek(0)?.map(|t| t.kind())) =>
+            {
+                let kind = if name.as_ref() == "get" {
+                    MethodDefinitionKind::Get
+                } else {
+                    MethodDefinitionKind::Set
+                };
+                let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+                (
+                    kind,
+                    ClassElementName::new(self.allow_yield, self.allow_await, token)
+                        .parse(cursor)?,
+                )
+            }
+            _ => (
+                MethodDefinitionKind::Ordinary,
+                ClassElementName::new(self.allow_yield, self.allow_await, token).parse(cursor)?,
+            ),
+        };
+        let is_constructor = !is_static && name == PropertyName::from("constructor");
+
+        if cursor.next_if(Punctuator::OpenParen)?.is_none() {
+            // FieldDefinition
+            if kind != MethodDefinitionKind::Ordinary {
+                let next_token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+                return Err(ParseError::expected(
+                    vec![TokenKind::Punctuator(Punctuator::OpenParen)],
+                    next_token,
+                    "class accessor definition",
+                ));
+            }
+            if is_constructor || (is_static && name == PropertyName::from("prototype")) {
+                return Err(ParseError::general(
+                    "classes may not have a field with this name",
+                    position,
+                ));
+            }
+
+            let init = if cursor.next_if(Punctuator::Assign)?.is_some() {
+                Some(AssignmentExpression::new(true, false, false).parse(cursor)?)
+            } else {
+                None
+            };
+            cursor.expect_semicolon("class field definition")?;
+
+            return Ok(ClassElementNode::Element(Box::new(if is_static {
+                node::ClassElement::StaticFieldDefinition(name, init)
+            } else {
+                node::ClassElement::FieldDefinition(name, init)
+            })));
+        }
+
+        // MethodDefinition
+        let first_param = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.clone();
+        let params = FormalParameters::new(is_generator, is_async).parse(cursor)?;
+        cursor.expect(Punctuator::CloseParen, "class method definition")?;
+        match kind {
+            MethodDefinitionKind::Get if !params.is_empty() => {
+                return Err(ParseError::unexpected(
+                    first_param,
+                    "getter functions must have no arguments",
+                ));
+            }
+            MethodDefinitionKind::Set if params.len() != 1 => {
+                return Err(ParseError::unexpected(
+                    first_param,
+                    "setter functions must have one argument",
+                ));
+            }
+            _ => {}
+        }
+
+        cursor.expect(Punctuator::OpenBlock, "class method definition")?;
+        let body = FunctionBody::new(is_generator, is_async).parse(cursor)?;
+        cursor.expect(Punctuator::CloseBlock, "class method definition")?;
+        check_function_early_errors(cursor, None, &params, &body, true)?;
+
+        let function = cursor.spanned_method(FunctionExpr::new(None, params, body), position);
+        if is_constructor {
+            if kind != MethodDefinitionKind::Ordinary {
+                return Err(ParseError::general(
+                    "class constructor may not be an accessor, a generator or async",
+                    position,
+                ));
+            }
+            return Ok(ClassElementNode::Constructor(function));
+        }
+        if is_static && name == PropertyName::from("prototype") {
+            return Err(ParseError::general(
+                "classes may not have a static property named 'prototype'",
+                position,
+            ));
+        }
+
+        Ok(ClassElementNode::Element(Box::new(if is_static {
+            node::ClassElement::StaticMethodDefinition(kind, name, function)
+        } else {
+            node::ClassElement::MethodDefinition(kind, name, function)
+        })))
+    }
+}
+
+/// Class element name parsing.
+///
+/// The first token of the name has already been consumed by the element parser.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassElementName
+#[derive(Debug, Clone)]
+struct ClassElementName {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    token: Token,
+}
+
+impl ClassElementName {
+    /// Creates a new `ClassElementName` parser.
+    fn new<Y, A>(allow_yield: Y, allow_await: A, token: Token) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            token,
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ClassElementName
+where
+    R: Read,
+{
+    type Output = PropertyName;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        match self.token.kind() {
+            TokenKind::Identifier(_)
+            | TokenKind::Keyword(_)
+            | TokenKind::BooleanLiteral(_)
+            | TokenKind::NullLiteral
+            | TokenKind::StringLiteral(_)
+            | TokenKind::NumericLiteral(_) => {
+                Ok(PropertyName::Literal(self.token.to_string().into()))
+            }
+            TokenKind::PrivateIdentifier(name) if name.as_ref() == "#constructor" => {
+                Err(ParseError::general(
+                    "classes may not declare #constructor",
+                    self.token.span().start(),
+                ))
+            }
+            TokenKind::PrivateIdentifier(name) => Ok(PropertyName::Private(name.clone())),
+            TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                let name: Node =
+                    AssignmentExpression::new(true, self.allow_yield, self.allow_await)
+                        .parse(cursor)?;
+                cursor.expect(Punctuator::CloseBracket, "computed property name")?;
+                Ok(PropertyName::Computed(name))
+            }
+            _ => Err(ParseError::expected(
+                vec![
+                    TokenKind::identifier("identifier"),
+                    TokenKind::Punctuator(Punctuator::OpenBracket),
+                ],
+                self.token,
+                "class element name",
+            )),
+        }
+    }
+}
+
+/// Checks if a token can start the name of a class element.
+fn is_property_name_start(kind: Option<&TokenKind>) -> bool {
+    matches!(
+        kind,
+        Some(TokenKind::Identifier(_))
+            | Some(TokenKind::Keyword(_))
+            | Some(TokenKind::BooleanLiteral(_))
+            | Some(TokenKind::NullLiteral)
+            | Some(TokenKind::StringLiteral(_))
+            | Some(TokenKind::NumericLiteral(_))
+            | Some(TokenKind::PrivateIdentifier(_))
+            | Some(TokenKind::Punctuator(Punctuator::OpenBracket))
+    )
+}
+
+/// Gets the kind and the name of a class element, fields being ordinary elements.
+fn element_name(element: &node::ClassElement) -> (MethodDefinitionKind, &PropertyName, bool) {
+    match element {
+        node::ClassElement::MethodDefinition(kind, name, _) => (*kind, name, false),
+        node::ClassElement::StaticMethodDefinition(kind, name, _) => (*kind, name, true),
+        node::ClassElement::FieldDefinition(name, _) => {
+            (MethodDefinitionKind::Ordinary, name, false)
+        }
+        node::ClassElement::StaticFieldDefinition(name, _) => {
+            (MethodDefinitionKind::Ordinary, name, true)
+        }
+    }
+}
+
+/// Checks that a new element of a class body does not declare a private name that is already
+/// declared, unless they are a getter and a setter which are both static or both not static.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-class-definitions-static-semantics-early-errors
+fn is_valid_private_declaration(
+    elements: &[node::ClassElement],
+    element: &node::ClassElement,
+) -> bool {
+    let (kind, name, is_static) = element_name(element);
+    let name = match name.private_name() {
+        Some(name) => name,
+        None => return true,
+    };
+    let mut previous = elements
+        .iter()
+        .map(element_name)
+        .filter(|(_, previous, _)| previous.private_name() == Some(name));
+    match (previous.next(), previous.next()) {
+        (None, _) => true,
+        (Some((previous_kind, _, previous_static)), None) => {
+            previous_static == is_static
+                && matches!(
+                    (previous_kind, kind),
+                    (MethodDefinitionKind::Get, MethodDefinitionKind::Set)
+                        | (MethodDefinitionKind::Set, MethodDefinitionKind::Get)
+                )
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,212 @@
+use crate::syntax::{
+    ast::{
+        node::{
+            field::GetPrivateField, BinOp, Call, Class, ClassElement, FormalParameter,
+            FunctionExpr, GetSuperField, Identifier, MethodDefinitionKind, Node, PropertyName,
+            Return, SuperCall,
+        },
+        op::NumOp,
+        Const,
+    },
+    parser::{
+        tests::{check_invalid, check_parser},
+        Parser,
+    },
+};
+
+/// Helper to build a class declaration named `A`.
+fn class_a(elements: Vec<ClassElement>) -> Vec<Node> {
+    vec![Node::ClassDecl(Class::new(
+        Some("A".into()),
+        None,
+        None,
+        elements,
+    ))]
+}
+
+/// Checks methods, getters and setters.
+#[test]
+fn class_methods() {
+    check_parser(
+        "class A {
+            foo() {}
+            get bar() { return 1; }
+            set bar(v) {}
+            static baz() {}
+        }",
+        class_a(vec![
+            ClassElement::MethodDefinition(
+                MethodDefinitionKind::Ordinary,
+                "foo".into(),
+                FunctionExpr::new(None, vec![], vec![]),
+            ),
+            ClassElement::MethodDefinition(
+                MethodDefinitionKind::Get,
+                "bar".into(),
+                FunctionExpr::new(
+                    None,
+                    vec![],
+                    vec![Return::new::<_, _, Option<Box<str>>>(Const::from(1), None).into()],
+                ),
+            ),
+            ClassElement::MethodDefinition(
+                MethodDefinitionKind::Set,
+                "bar".into(),
+                FunctionExpr::new(None, vec![FormalParameter::new("v", None, false)], vec![]),
+            ),
+            ClassElement::StaticMethodDefinition(
+                MethodDefinitionKind::Ordinary,
+                "baz".into(),
+                FunctionExpr::new(None, vec![], vec![]),
+            ),
+        ]),
+    );
+}
+
+/// Checks that `get`, `set` and `static` can be used as element names.
+#[test]
+fn class_contextual_keywords_as_names() {
+    check_parser(
+        "class A {
+            get() {}
+            static() {}
+            static set() {}
+            static;
+        }",
+        class_a(vec![
+            ClassElement::MethodDefinition(
+                MethodDefinitionKind::Ordinary,
+                "get".into(),
+                FunctionExpr::new(None, vec![], vec![]),
+            ),
+            ClassElement::MethodDefinition(
+                MethodDefinitionKind::Ordinary,
+                "static".into(),
+                FunctionExpr::new(None, vec![], vec![]),
+            ),
+            ClassElement::StaticMethodDefinition(
+                MethodDefinitionKind::Ordinary,
+                "set".into(),
+                FunctionExpr::new(None, vec![], vec![]),
+            ),
+            ClassElement::FieldDefinition("static".into(), None),
+        ]),
+    );
+}
+
+/// Checks public fields and computed names.
+#[test]
+fn class_fields() {
+    check_parser(
+        "class A {
+            x = 1;
+            y
+            static z = 2;
+            ['a' + 'b']() {}
+        }",
+        class_a(vec![
+            ClassElement::FieldDefinition("x".into(), Some(Const::from(1).into())),
+            ClassElement::FieldDefinition("y".into(), None),
+            ClassElement::StaticFieldDefinition("z".into(), Some(Const::from(2).into())),
+            ClassElement::MethodDefinition(
+                MethodDefinitionKind::Ordinary,
+                PropertyName::Computed(
+                    BinOp::new(NumOp::Add, Const::from("a"), Const::from("b")).into(),
+                ),
+                FunctionExpr::new(None, vec![], vec![]),
+            ),
+        ]),
+    );
+}
+
+/// Checks `super` calls and properties.
+#[test]
+fn class_super() {
+    check_parser(
+        "class A extends B {
+            constructor() { super(1); }
+            foo() { return super.foo(); }
+        }",
+        vec![Node::ClassDecl(Class::new(
+            Some("A".into()),
+            Node::from(Identifier::from("B")),
+            FunctionExpr::new(
+                None,
+                vec![],
+                vec![SuperCall::new(vec![Const::from(1).into()]).into()],
+            ),
+            vec![ClassElement::MethodDefinition(
+                MethodDefinitionKind::Ordinary,
+                "foo".into(),
+                FunctionExpr::new(
+                    None,
+                    vec![],
+                    vec![Return::new::<_, _, Option<Box<str>>>(
+                        Call::new(GetSuperField::new(Const::from("foo")), vec![]),
+                        None,
+                    )
+                    .into()],
+                ),
+            )],
+        ))],
+    );
+}
+
+/// Checks private fields and methods, and their references.
+#[test]
+fn class_private_elements() {
+    check_parser(
+        "class A {
+            #x;
+            #m() { return this.#x; }
+        }",
+        class_a(vec![
+            ClassElement::FieldDefinition(PropertyName::Private("#x".into()), None),
+            ClassElement::MethodDefinition(
+                MethodDefinitionKind::Ordinary,
+                PropertyName::Private("#m".into()),
+                FunctionExpr::new(
+                    None,
+                    vec![],
+                    vec![Return::new::<_, _, Option<Box<str>>>(
+                        GetPrivateField::new(Node::This, "#x"),
+                        None,
+                    )
+                    .into()],
+                ),
+            ),
+        ]),
+    );
+}
+
+/// Checks invalid private names.
+#[test]
+fn class_private_invalid() {
+    check_invalid("class A { #constructor; }");
+    check_invalid("class A { #x; #x() {} }");
+    check_invalid("class A { get #x() {} static set #x(v) {} }");
+    check_invalid("class A { m() { return this.#y; } }");
+    check_invalid("class A { #x; m() { delete this.#x; } }");
+    check_invalid("this.#x");
+    check_invalid("class A { # x; }");
+}
+
+/// Checks that private names are visible in nested classes, and that a getter and a setter
+/// can share a name.
+#[test]
+fn class_private_nested() {
+    let source =
+        "class A { #x; get #y() {} set #y(v) {} m() { class B { n(o) { return o.#x; } } } }";
+    assert!(Parser::new(source.as_bytes(), false).parse_all().is_ok());
+}
+
+/// Checks invalid class bodies.
+#[test]
+fn class_invalid() {
+    check_invalid("class A { constructor() {} constructor() {} }");
+    check_invalid("class A { get constructor() {} }");
+    check_invalid("class A { static prototype() {} }");
+    check_invalid("class A { get x(a) {} }");
+    check_invalid("class A { set x() {} }");
+    check_invalid("class A { x = 1 y = 2 }");
+}
@@ -1,6 +1,7 @@
 use crate::{
     profiler::BoaProfiler,
     syntax::{
+        ast::Span,
         lexer::{InputElement, Lexer, Position, Token, TokenKind},
         parser::error::ParseError,
     },
@@ -29,6 +30,9 @@ pub(super) struct BufferedLexer<R> {
     peeked: [Option<Token>; PEEK_BUF_SIZE],
     read_index: usize,
     write_index: usize,
+
+    /// The end position of the last token that was consumed, other than line terminators.
+    last_end: Position,
 }
 
 impl<R> From<Lexer<R>> for BufferedLexer<R>
@@ -51,6 +55,7 @@ where
             ],
             read_index: 0,
             write_index: 0,
+            last_end: Position::new(1, 1),
         }
     }
 }
@@ -81,13 +86,23 @@ where
     pub(super) fn lex_regex(&mut self, start: Position) -> Result<Token, ParseError> {
         let _timer = BoaProfiler::global().start_event("cursor::lex_regex()", "Parsing");
         self.set_goal(InputElement::RegExp);
-        self.lexer.lex_slash_token(start).map_err(|e| e.into())
+        let token = self.lexer.lex_slash_token(start)?;
+        self.last_end = token.span().end();
+        Ok(token)
     }
 
     /// Lexes the next tokens as template middle or template tail assuming that the starting
     /// '}' has already been consumed.
     pub(super) fn lex_template(&mut self, start: Position) -> Result<Token, ParseError> {
-        self.lexer.lex_template(start).map_err(ParseError::from)
+        let token = self.lexer.lex_template(start)?;
+        self.last_end = token.span().end();
+        Ok(token)
+    }
+
+    /// Gets the end position of the last token that was consumed, other than line terminators.
+    #[inline]
+    pub(super) fn last_end(&self) -> Position {
+        self.last_end
     }
 
     #[inline]
@@ -100,6 +115,21 @@ where
         self.lexer.set_strict_mode(strict_mode)
     }
 
+    #[inline]
+    pub(super) fn source_text(&self, span: Span) -> Option<&str> {
+        self.lexer.source_text(span)
+    }
+
+    #[inline]
+    pub(super) fn property_names(&self) -> bool {
+        self.lexer.property_names()
+    }
+
+    #[inline]
+    pub(super) fn set_property_names(&mut self, property_names: bool) {
+        self.lexer.set_property_names(property_names)
+    }
+
     /// Fills the peeking buffer with the next token.
     ///
     /// It will not fill two line terminators one after the other.
@@ -177,6 +207,12 @@ where
             };
             self.read_index = (self.read_index + 1) % PEEK_BUF_SIZE;
 
+            if let Some(ref token) = tok {
+                if token.kind() != &TokenKind::LineTerminator {
+                    self.last_end = token.span().end();
+                }
+            }
+
             Ok(tok)
         } else {
             // We do not update the read index, since we should always return `None` from now on.
@@ -3,7 +3,10 @@ mod buffered_lexer;
 
 use super::ParseError;
 use crate::syntax::{
-    ast::Punctuator,
+    ast::{
+        node::{FunctionExpr, FunctionSource},
+        Node, Punctuator, Span,
+    },
     lexer::{InputElement, Lexer, Position, Token, TokenKind},
 };
 use buffered_lexer::BufferedLexer;
@@ -22,6 +25,9 @@ pub(super) enum SemicolonResult<'s> {
 #[derive(Debug)]
 pub(super) struct Cursor<R> {
     buffered_lexer: BufferedLexer<R>,
+    /// The private names referenced in the bodies of the classes being parsed, from the
+    /// outermost class to the innermost one, which are checked when each class body ends.
+    private_references: Vec<Vec<(Box<str>, Position)>>,
 }
 
 impl<R> Cursor<R>
@@ -33,6 +39,7 @@ where
     pub(super) fn new(reader: R) -> Self {
         Self {
             buffered_lexer: Lexer::new(reader).into(),
+            private_references: Vec::new(),
         }
     }
 
@@ -71,6 +78,110 @@ where
         self.buffered_lexer.set_strict_mode(strict_mode)
     }
 
+    /// Checks if the identifiers are lexed as the names of properties, which can be strict mode
+    /// reserved words.
+    #[inline]
+    pub(super) fn property_names(&self) -> bool {
+        self.buffered_lexer.property_names()
+    }
+
+    /// Sets if the identifiers are lexed as the names of properties, in the body of a class or
+    /// of an object literal.
+    ///
+    /// The tokens that were already peeked are not lexed again.
+    #[inline]
+    pub(super) fn set_property_names(&mut self, property_names: bool) {
+        self.buffered_lexer.set_property_names(property_names)
+    }
+
+    /// Starts collecting the private names referenced in the body of a class.
+    #[inline]
+    pub(super) fn push_private_scope(&mut self) {
+        self.private_references.push(Vec::new());
+    }
+
+    /// Records a reference to a private name, which must be declared by an enclosing class.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-allprivateidentifiersvalid
+    #[inline]
+    pub(super) fn reference_private_name(
+        &mut self,
+        name: &str,
+        position: Position,
+    ) -> Result<(), ParseError> {
+        match self.private_references.last_mut() {
+            Some(references) => {
+                references.push((name.into(), position));
+                Ok(())
+            }
+            None => Err(ParseError::general(
+                "private names can only be used in the body of a class declaring them",
+                position,
+            )),
+        }
+    }
+
+    /// Ends the body of a class, checking the private names referenced in it against the names
+    /// it declares.
+    ///
+    /// The names that are not declared are left to the enclosing class to check.
+    #[inline]
+    pub(super) fn pop_private_scope(&mut self, declared: &[&str]) -> Result<(), ParseError> {
+        let references = self.private_references.pop().unwrap_or_default();
+        for (name, position) in references {
+            if !declared.contains(&name.as_ref()) {
+                self.reference_private_name(&name, position)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Gets the span from the given start position to the end of the last token consumed.
+    #[inline]
+    pub(super) fn span_from(&self, start: Position) -> Span {
+        Span::new(start, self.buffered_lexer.last_end().max(start))
+    }
+
+    /// Gives a node created by a parser the span from the given start position to the end of the
+    /// last token consumed.
+    ///
+    /// Nodes that already have a span, like the expression of a parenthesized expression, keep it.
+    ///
+    /// Functions and classes also record the source text they were parsed from.
+    #[inline]
+    pub(super) fn spanned(&self, mut node: Node, start: Position) -> Node {
+        if node.span().is_none() {
+            let span = self.span_from(start);
+            node.set_span(span);
+            if let (Some(source_text), Some(text)) = (
+                node.source_text_mut(),
+                self.buffered_lexer.source_text(span),
+            ) {
+                source_text.set(text);
+            }
+        }
+        node
+    }
+
+    /// Gives the function of a method the span and the source text of the method, from the
+    /// given start position to the end of the last token consumed.
+    #[inline]
+    pub(super) fn spanned_method(
+        &self,
+        mut function: FunctionExpr,
+        start: Position,
+    ) -> FunctionExpr {
+        let span = self.span_from(start);
+        function.set_span(span);
+        if let Some(text) = self.buffered_lexer.source_text(span) {
+            function.node_source_text_mut().set(text);
+        }
+        function
+    }
+
     /// Returns an error if the next token is not of kind `kind`.
     #[inline]
     pub(super) fn expect<K>(&mut self, kind: K, context: &'static str) -> Result<Token, ParseError>
@@ -14,10 +14,10 @@ use crate::{
             node::{ArrowFunctionDecl, FormalParameter, Node, Return, StatementList},
             Punctuator,
         },
-        lexer::{Error as LexError, Position, TokenKind},
+        lexer::TokenKind,
         parser::{
             error::{ErrorContext, ParseError, ParseResult},
-            function::{FormalParameters, FunctionBody},
+            function::{check_function_early_errors, FormalParameters, FunctionBody},
             statement::BindingIdentifier,
             AllowAwait, AllowIn, AllowYield, Cursor, TokenParser,
         },
@@ -40,6 +40,7 @@ pub(in crate::syntax::parser) struct ArrowFunction {
     allow_in: AllowIn,
     allow_yield: AllowYield,
     allow_await: AllowAwait,
+    is_async: bool,
 }
 
 impl ArrowFunction {
@@ -58,6 +59,27 @@ impl ArrowFunction {
             allow_in: allow_in.into(),
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
+            is_async: false,
+        }
+    }
+
+    /// Creates a new `ArrowFunction` parser for an async arrow function, whose `async` keyword
+    /// was already consumed.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-AsyncArrowFunction
+    pub(in crate::syntax::parser) fn new_async<I, Y>(allow_in: I, allow_yield: Y) -> Self
+    where
+        I: Into<AllowIn>,
+        Y: Into<AllowYield>,
+    {
+        Self {
+            allow_in: allow_in.into(),
+            allow_yield: allow_yield.into(),
+            allow_await: true.into(),
+            is_async: true,
         }
     }
 }
@@ -86,30 +108,35 @@ where
             Box::new([FormalParameter::new(param, None, false)])
         };
 
+        self.parse_tail(params, cursor)
+    }
+}
+
+impl ArrowFunction {
+    /// Parses the arrow and the body of an arrow function whose parameters were already parsed.
+    ///
+    /// This is used when the parameters were parsed as a parenthesized expression and have been
+    /// converted from the `CoverParenthesizedExpressionAndArrowParameterList`.
+    pub(super) fn parse_tail<R>(
+        self,
+        params: Box<[FormalParameter]>,
+        cursor: &mut Cursor<R>,
+    ) -> Result<ArrowFunctionDecl, ParseError>
+    where
+        R: Read,
+    {
         cursor.peek_expect_no_lineterminator(0, "arrow function")?;
 
         cursor.expect(TokenKind::Punctuator(Punctuator::Arrow), "arrow function")?;
-        let body = ConciseBody::new(self.allow_in).parse(cursor)?;
-
-        // It is a Syntax Error if any element of the BoundNames of ArrowParameters
-        // also occurs in the LexicallyDeclaredNames of ConciseBody.
-        // https://tc39.es/ecma262/#sec-arrow-function-definitions-static-semantics-early-errors
-        {
-            let lexically_declared_names = body.lexically_declared_names();
-            for param in params.as_ref() {
-                if lexically_declared_names.contains(param.name()) {
-                    return Err(ParseError::lex(LexError::Syntax(
-                        format!("Redeclaration of formal parameter `{}`", param.name()).into(),
-                        match cursor.peek(0)? {
-                            Some(token) => token.span().end(),
-                            None => Position::new(1, 1),
-                        },
-                    )));
-                }
-            }
-        }
+        let body = ConciseBody::new(self.allow_in, self.is_async).parse(cursor)?;
+
+        check_function_early_errors(cursor, None, &params, &body, true)?;
 
-        Ok(ArrowFunctionDecl::new(params, body))
+        Ok(if self.is_async {
+            ArrowFunctionDecl::new_async(params, body)
+        } else {
+            ArrowFunctionDecl::new(params, body)
+        })
     }
 }
 
@@ -117,16 +144,19 @@ where
 #[derive(Debug, Clone, Copy)]
 struct ConciseBody {
     allow_in: AllowIn,
+    allow_await: AllowAwait,
 }
 
 impl ConciseBody {
     /// Creates a new `ConcideBody` parser.
-    fn new<I>(allow_in: I) -> Self
+    fn new<I, A>(allow_in: I, allow_await: A) -> Self
     where
         I: Into<AllowIn>,
+        A: Into<AllowAwait>,
     {
         Self {
             allow_in: allow_in.into(),
+            allow_await: allow_await.into(),
         }
     }
 }
@@ -138,18 +168,19 @@ where
     type Output = StatementList;
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
-        match cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind() {
-            TokenKind::Punctuator(Punctuator::OpenBlock) => {
-                let _ = cursor.next();
-                let body = FunctionBody::new(false, false).parse(cursor)?;
-                cursor.expect(Punctuator::CloseBlock, "arrow function")?;
-                Ok(body)
-            }
-            _ => Ok(StatementList::from(vec![Return::new(
-                ExpressionBody::new(self.allow_in, false).parse(cursor)?,
-                None,
-            )
-            .into()])),
+        let token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let start = token.span().start();
+        if token.kind() == &TokenKind::Punctuator(Punctuator::OpenBlock) {
+            let _ = cursor.next();
+            let body = FunctionBody::new(false, self.allow_await).parse(cursor)?;
+            cursor.expect(Punctuator::CloseBlock, "arrow function")?;
+            Ok(body)
+        } else {
+            let expr = ExpressionBody::new(self.allow_in, self.allow_await).parse(cursor)?;
+            let mut body =
+                StatementList::from(vec![cursor.spanned(Return::new(expr, None).into(), start)]);
+            body.set_strict(cursor.strict_mode());
+            Ok(body)
         }
     }
 }
@@ -13,7 +13,7 @@ use crate::{
         ast::{node::ConditionalOp, Node, Punctuator},
         parser::{
             expression::{AssignmentExpression, ShortCircuitExpression},
-            AllowAwait, AllowIn, AllowYield, Cursor, ParseResult, TokenParser,
+            AllowAwait, AllowIn, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
         },
     },
     BoaProfiler,
@@ -65,6 +65,7 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
         let _timer = BoaProfiler::global().start_event("ConditionalExpression", "Parsing");
 
+        let start = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
         let lhs = ShortCircuitExpression::new(self.allow_in, self.allow_yield, self.allow_await)
             .parse(cursor)?;
 
@@ -79,7 +80,10 @@ where
                 let else_clause =
                     AssignmentExpression::new(self.allow_in, self.allow_yield, self.allow_await)
                         .parse(cursor)?;
-                return Ok(ConditionalOp::new(lhs, then_clause, else_clause).into());
+                return Ok(cursor.spanned(
+                    ConditionalOp::new(lhs, then_clause, else_clause).into(),
+                    start,
+                ));
             }
         }
 
@@ -10,16 +10,25 @@
 mod arrow_function;
 mod conditional;
 mod exponentiation;
+mod yield_expr;
 
-use self::{arrow_function::ArrowFunction, conditional::ConditionalExpression};
-use crate::syntax::lexer::{Error as LexError, InputElement, TokenKind};
+use self::{
+    arrow_function::ArrowFunction, conditional::ConditionalExpression, yield_expr::YieldExpression,
+};
+use crate::syntax::lexer::{Error as LexError, InputElement, Position, TokenKind};
 use crate::{
     syntax::{
         ast::{
-            node::{Assign, BinOp, Node},
-            Keyword, Punctuator,
+            node::{
+                ArrayPattern, Assign, BinOp, BindingElement, BindingProperty, BindingTarget,
+                DeclarationPattern, FormalParameter, Node, ObjectPattern, PropertyDefinition,
+            },
+            op, Const, Keyword, Punctuator,
+        },
+        parser::{
+            statement::STRICT_RESTRICTED_NAMES, AllowAwait, AllowIn, AllowYield, Cursor,
+            ParseError, ParseResult, TokenParser,
         },
-        parser::{AllowAwait, AllowIn, AllowYield, Cursor, ParseError, ParseResult, TokenParser},
     },
     BoaProfiler,
 };
@@ -32,7 +41,7 @@ use std::io::Read;
 /// This can be one of the following:
 ///
 ///  - [`ConditionalExpression`](../conditional_operator/struct.ConditionalExpression.html)
-///  - `YieldExpression`
+///  - [`YieldExpression`](../yield_expr/struct.YieldExpression.html)
 ///  - [`ArrowFunction`](../../function/arrow_function/struct.ArrowFunction.html)
 ///  - `AsyncArrowFunction`
 ///  - [`LeftHandSideExpression`][lhs] `=` `AssignmentExpression`
@@ -82,8 +91,17 @@ where
         let _timer = BoaProfiler::global().start_event("AssignmentExpression", "Parsing");
         cursor.set_goal(InputElement::Div);
 
+        let start = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
+
         // Arrow function
         match cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind() {
+            // yield, only an expression inside of generators
+            TokenKind::Keyword(Keyword::Yield) if self.allow_yield.0 => {
+                return YieldExpression::new(self.allow_in, self.allow_await)
+                    .parse(cursor)
+                    .map(|node| cursor.spanned(node.into(), start));
+            }
+
             // a=>{}
             TokenKind::Identifier(_)
             | TokenKind::Keyword(Keyword::Yield)
@@ -96,12 +114,27 @@ where
                             self.allow_await,
                         )
                         .parse(cursor)
-                        .map(Node::ArrowFunctionDecl);
+                        .map(|arrow| cursor.spanned(Node::ArrowFunctionDecl(arrow), start));
                     }
                 }
             }
 
             // (a,b)=>{} or (a,b) or (Expression)
+            // AsyncArrowFunction
+            // https://tc39.es/ecma262/#prod-AsyncArrowFunction
+            TokenKind::Keyword(Keyword::Async) => {
+                if let Ok(tok) = cursor.peek_expect_no_lineterminator(1, "async arrow function") {
+                    if let TokenKind::Identifier(_) | TokenKind::Punctuator(Punctuator::OpenParen) =
+                        tok.kind()
+                    {
+                        let _ = cursor.next()?.expect("async token disappeared");
+                        return ArrowFunction::new_async(self.allow_in, self.allow_yield)
+                            .parse(cursor)
+                            .map(|arrow| cursor.spanned(Node::ArrowFunctionDecl(arrow), start));
+                    }
+                }
+            }
+
             TokenKind::Punctuator(Punctuator::OpenParen) => {
                 if let Some(next_token) = cursor.peek(1)? {
                     match *next_token.kind() {
@@ -116,7 +149,9 @@ where
                                         self.allow_await,
                                     )
                                     .parse(cursor)
-                                    .map(Node::ArrowFunctionDecl);
+                                    .map(|arrow| {
+                                        cursor.spanned(Node::ArrowFunctionDecl(arrow), start)
+                                    });
                                 }
                             }
                         }
@@ -127,7 +162,7 @@ where
                                 self.allow_await,
                             )
                             .parse(cursor)
-                            .map(Node::ArrowFunctionDecl);
+                            .map(|arrow| cursor.spanned(Node::ArrowFunctionDecl(arrow), start));
                         }
                         TokenKind::Identifier(_) => {
                             if let Some(t) = cursor.peek(2)? {
@@ -140,7 +175,9 @@ where
                                             self.allow_await,
                                         )
                                         .parse(cursor)
-                                        .map(Node::ArrowFunctionDecl);
+                                        .map(|arrow| {
+                                            cursor.spanned(Node::ArrowFunctionDecl(arrow), start)
+                                        });
                                     }
                                     TokenKind::Punctuator(Punctuator::CloseParen) => {
                                         // Need to check if the token after the close paren is an arrow, if so then this is an ArrowFunction
@@ -154,7 +191,12 @@ where
                                                     self.allow_await,
                                                 )
                                                 .parse(cursor)
-                                                .map(Node::ArrowFunctionDecl);
+                                                .map(|arrow| {
+                                                    cursor.spanned(
+                                                        Node::ArrowFunctionDecl(arrow),
+                                                        start,
+                                                    )
+                                                });
                                             }
                                         }
                                     }
@@ -179,10 +221,42 @@ where
         // TODO: can we avoid cloning?
         if let Some(tok) = cursor.peek(0)?.cloned() {
             match tok.kind() {
+                // CoverParenthesizedExpressionAndArrowParameterList, like `({ a }) => a`.
+                TokenKind::Punctuator(Punctuator::Arrow) => {
+                    return match to_formal_parameters(&lhs) {
+                        Some(params) => {
+                            ArrowFunction::new(self.allow_in, self.allow_yield, self.allow_await)
+                                .parse_tail(params.into(), cursor)
+                                .map(|arrow| cursor.spanned(Node::ArrowFunctionDecl(arrow), start))
+                        }
+                        None => Err(ParseError::lex(LexError::Syntax(
+                            "Invalid arrow function parameters".into(),
+                            tok.span().start(),
+                        ))),
+                    };
+                }
                 TokenKind::Punctuator(Punctuator::Assign) => {
                     cursor.next()?.expect("= token vanished"); // Consume the token.
-                    if is_assignable(&lhs) {
-                        lhs = Assign::new(lhs, self.parse(cursor)?).into();
+                    if let Node::Object(_) | Node::ArrayDecl(_) = lhs {
+                        // Destructuring assignment, like `[a, b] = [b, a]`.
+                        match to_assignment_pattern(&lhs) {
+                            Some(pattern) => {
+                                let pattern = Node::from(pattern);
+                                check_strict_target(cursor, &pattern, tok.span().start())?;
+                                let rhs = self.parse(cursor)?;
+                                lhs = cursor.spanned(Assign::new(pattern, rhs).into(), start);
+                            }
+                            None => {
+                                return Err(ParseError::lex(LexError::Syntax(
+                                    "Invalid destructuring assignment target".into(),
+                                    tok.span().start(),
+                                )));
+                            }
+                        }
+                    } else if is_assignable(&lhs) {
+                        check_strict_target(cursor, &lhs, tok.span().start())?;
+                        let rhs = self.parse(cursor)?;
+                        lhs = cursor.spanned(Assign::new(lhs, rhs).into(), start);
                     } else {
                         return Err(ParseError::lex(LexError::Syntax(
                             "Invalid left-hand side in assignment".into(),
@@ -193,10 +267,11 @@ where
                 TokenKind::Punctuator(p) if p.as_binop().is_some() && p != &Punctuator::Comma => {
                     cursor.next()?.expect("token vanished"); // Consume the token.
                     if is_assignable(&lhs) {
+                        check_strict_target(cursor, &lhs, tok.span().start())?;
                         let binop = p.as_binop().expect("binop disappeared");
                         let expr = self.parse(cursor)?;
 
-                        lhs = BinOp::new(binop, lhs, expr).into();
+                        lhs = cursor.spanned(BinOp::new(binop, lhs, expr).into(), start);
                     } else {
                         return Err(ParseError::lex(LexError::Syntax(
                             "Invalid left-hand side in assignment".into(),
@@ -221,9 +296,156 @@ pub(crate) fn is_assignable(node: &Node) -> bool {
         node,
         Node::GetConstField(_)
             | Node::GetField(_)
+            | Node::GetPrivateField(_)
             | Node::Assign(_)
             | Node::Call(_)
             | Node::Identifier(_)
             | Node::Object(_)
     )
 }
+
+/// Checks that the target of an assignment or of an update expression is not `eval` or
+/// `arguments` in strict mode code, including the targets of a destructuring assignment.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-identifiers-static-semantics-early-errors
+pub(in crate::syntax::parser) fn check_strict_target<R>(
+    cursor: &Cursor<R>,
+    target: &Node,
+    position: Position,
+) -> Result<(), ParseError>
+where
+    R: Read,
+{
+    if !cursor.strict_mode() {
+        return Ok(());
+    }
+    let restricted = match target {
+        Node::Identifier(ident) => STRICT_RESTRICTED_NAMES
+            .iter()
+            .find(|name| **name == ident.as_ref()),
+        Node::DeclarationPattern(pattern) => STRICT_RESTRICTED_NAMES
+            .iter()
+            .find(|name| pattern.names().contains(name)),
+        _ => None,
+    };
+    match restricted {
+        Some(name) => Err(ParseError::lex(LexError::Syntax(
+            format!("assignment to '{}' not allowed in strict mode", name).into(),
+            position,
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Converts an object or array literal into a destructuring assignment pattern.
+///
+/// Returns `None` if the literal is not a valid assignment pattern.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-destructuring-assignment
+pub(in crate::syntax::parser) fn to_assignment_pattern(node: &Node) -> Option<DeclarationPattern> {
+    to_pattern(node, true)
+}
+
+/// Converts the parenthesized expression before an `=>` into the parameters of an arrow function.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-arrow-function-definitions-static-semantics-early-errors
+fn to_formal_parameters(node: &Node) -> Option<Vec<FormalParameter>> {
+    match node {
+        Node::BinOp(bin_op) if bin_op.op() == op::BinOp::Comma => {
+            let mut params = to_formal_parameters(bin_op.lhs())?;
+            params.extend(to_formal_parameters(bin_op.rhs())?);
+            Some(params)
+        }
+        _ => Some(vec![to_element(node, false)?.into()]),
+    }
+}
+
+/// Converts an object or array literal into a pattern.
+///
+/// Property references, like `obj.a`, are only allowed as targets if `allow_properties` is set.
+fn to_pattern(node: &Node, allow_properties: bool) -> Option<DeclarationPattern> {
+    match node {
+        Node::Object(object) => {
+            let mut properties = Vec::new();
+            let mut rest = None;
+            for (i, property) in object.properties().iter().enumerate() {
+                let property = match property {
+                    PropertyDefinition::IdentifierReference(name) => {
+                        BindingProperty::new(name.as_ref(), BindingElement::new(name.clone(), None))
+                    }
+                    PropertyDefinition::CoverInitializedName(name, init) => BindingProperty::new(
+                        name.as_ref(),
+                        BindingElement::new(name.clone(), Some(init.clone())),
+                    ),
+                    PropertyDefinition::Property(key, value) => {
+                        BindingProperty::new(key.as_ref(), to_element(value, allow_properties)?)
+                    }
+                    PropertyDefinition::SpreadObject(target)
+                        if i == object.properties().len() - 1 =>
+                    {
+                        rest = Some(to_target(target, allow_properties)?);
+                        continue;
+                    }
+                    _ => return None,
+                };
+                properties.push(property);
+            }
+            Some(ObjectPattern::new(properties, rest).into())
+        }
+        Node::ArrayDecl(array) => {
+            let mut elements = Vec::new();
+            let mut rest = None;
+            for (i, element) in array.as_ref().iter().enumerate() {
+                match element {
+                    // Elisions are parsed as `undefined` constants.
+                    Node::Const(Const::Undefined) => elements.push(None),
+                    Node::Spread(spread) if i == array.as_ref().len() - 1 => {
+                        rest = Some(to_target(spread.val(), allow_properties)?);
+                    }
+                    _ => elements.push(Some(to_element(element, allow_properties)?)),
+                }
+            }
+            Some(ArrayPattern::new(elements, rest).into())
+        }
+        _ => None,
+    }
+}
+
+/// Converts an expression into a pattern element with an optional default value.
+fn to_element(node: &Node, allow_properties: bool) -> Option<BindingElement> {
+    match node {
+        Node::Assign(assign) => Some(BindingElement::new(
+            to_target(assign.lhs(), allow_properties)?,
+            Some(assign.rhs().clone()),
+        )),
+        _ => Some(BindingElement::new(
+            to_target(node, allow_properties)?,
+            None,
+        )),
+    }
+}
+
+/// Converts an expression into the target of a pattern element.
+fn to_target(node: &Node, allow_properties: bool) -> Option<BindingTarget> {
+    match node {
+        Node::Identifier(ident) => Some(ident.clone().into()),
+        Node::GetConstField(_) | Node::GetField(_) | Node::GetPrivateField(_)
+            if allow_properties =>
+        {
+            Some(BindingTarget::Property(Box::new(node.clone())))
+        }
+        Node::Object(_) | Node::ArrayDecl(_) => to_pattern(node, allow_properties).map(Into::into),
+        // Nested patterns are already converted when they are assigned to.
+        Node::DeclarationPattern(pattern) => Some(pattern.clone().into()),
+        _ => None,
+    }
+}
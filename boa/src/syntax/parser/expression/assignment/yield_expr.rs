@@ -0,0 +1,87 @@
+//! Yield expression parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/yield
+//! [spec]: https://tc39.es/ecma262/#prod-YieldExpression
+
+use super::AssignmentExpression;
+use crate::{
+    syntax::{
+        ast::{node::YieldExpr, Keyword, Punctuator},
+        lexer::TokenKind,
+        parser::{AllowAwait, AllowIn, Cursor, ParseError, TokenParser},
+    },
+    BoaProfiler,
+};
+use std::io::Read;
+
+/// Yield expression parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/yield
+/// [spec]: https://tc39.es/ecma262/#prod-YieldExpression
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct YieldExpression {
+    allow_in: AllowIn,
+    allow_await: AllowAwait,
+}
+
+impl YieldExpression {
+    /// Creates a new `YieldExpression` parser.
+    pub(in crate::syntax::parser) fn new<I, A>(allow_in: I, allow_await: A) -> Self
+    where
+        I: Into<AllowIn>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_in: allow_in.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for YieldExpression
+where
+    R: Read,
+{
+    type Output = YieldExpr;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("YieldExpression", "Parsing");
+
+        cursor.expect(Keyword::Yield, "yield expression")?;
+
+        // The operand has to start on the same line as the `yield` keyword.
+        let token = match cursor.peek_expect_no_lineterminator(0, "yield expression") {
+            Ok(token) => token,
+            Err(_) => return Ok(YieldExpr::new::<YieldExpr, _>(None, false)),
+        };
+
+        match token.kind() {
+            TokenKind::Punctuator(Punctuator::Mul) => {
+                let _ = cursor.next()?.expect("* token vanished");
+                let expr = AssignmentExpression::new(self.allow_in, true, self.allow_await)
+                    .parse(cursor)?;
+                Ok(YieldExpr::new(expr, true))
+            }
+            TokenKind::Punctuator(Punctuator::CloseParen)
+            | TokenKind::Punctuator(Punctuator::CloseBracket)
+            | TokenKind::Punctuator(Punctuator::CloseBlock)
+            | TokenKind::Punctuator(Punctuator::Comma)
+            | TokenKind::Punctuator(Punctuator::Semicolon)
+            | TokenKind::Punctuator(Punctuator::Colon)
+            | TokenKind::Keyword(Keyword::In) => Ok(YieldExpr::new::<YieldExpr, _>(None, false)),
+            _ => {
+                let expr = AssignmentExpression::new(self.allow_in, true, self.allow_await)
+                    .parse(cursor)?;
+                Ok(YieldExpr::new(expr, false))
+            }
+        }
+    }
+}
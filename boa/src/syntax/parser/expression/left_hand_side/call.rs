@@ -12,10 +12,10 @@ use crate::{
     syntax::{
         ast::{
             node::{
-                field::{GetConstField, GetField},
+                field::{GetConstField, GetField, GetPrivateField},
                 Call, Node,
             },
-            Punctuator,
+            Position, Punctuator,
         },
         lexer::TokenKind,
         parser::{
@@ -39,11 +39,20 @@ pub(super) struct CallExpression {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     first_member_expr: Node,
+    start: Position,
 }
 
 impl CallExpression {
     /// Creates a new `CallExpression` parser.
-    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A, first_member_expr: Node) -> Self
+    ///
+    /// The start is the position of the first member expression, where the spans of the calls
+    /// start.
+    pub(super) fn new<Y, A>(
+        allow_yield: Y,
+        allow_await: A,
+        first_member_expr: Node,
+        start: Position,
+    ) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -52,6 +61,7 @@ impl CallExpression {
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
             first_member_expr,
+            start,
         }
     }
 }
@@ -69,7 +79,7 @@ where
 
         let mut lhs = if token.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
             let args = Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?;
-            Node::from(Call::new(self.first_member_expr, args))
+            cursor.spanned(Call::new(self.first_member_expr, args).into(), self.start)
         } else {
             let next_token = cursor.next()?.expect("token vanished");
             return Err(ParseError::expected(
@@ -84,17 +94,29 @@ where
             match token.kind() {
                 TokenKind::Punctuator(Punctuator::OpenParen) => {
                     let args = Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?;
-                    lhs = Node::from(Call::new(lhs, args));
+                    lhs = cursor.spanned(Call::new(lhs, args).into(), self.start);
                 }
                 TokenKind::Punctuator(Punctuator::Dot) => {
                     cursor.next()?.ok_or(ParseError::AbruptEnd)?; // We move the parser forward.
 
-                    match &cursor.next()?.ok_or(ParseError::AbruptEnd)?.kind() {
+                    let field = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+                    match field.kind() {
                         TokenKind::Identifier(name) => {
-                            lhs = GetConstField::new(lhs, name.clone()).into();
+                            lhs = cursor
+                                .spanned(GetConstField::new(lhs, name.clone()).into(), self.start);
                         }
                         TokenKind::Keyword(kw) => {
-                            lhs = GetConstField::new(lhs, kw.to_string()).into();
+                            lhs = cursor.spanned(
+                                GetConstField::new(lhs, kw.to_string()).into(),
+                                self.start,
+                            );
+                        }
+                        TokenKind::PrivateIdentifier(name) => {
+                            cursor.reference_private_name(name, field.span().start())?;
+                            lhs = cursor.spanned(
+                                GetPrivateField::new(lhs, name.clone()).into(),
+                                self.start,
+                            );
                         }
                         _ => {
                             return Err(ParseError::expected(
@@ -110,16 +132,17 @@ where
                     let idx =
                         Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
                     cursor.expect(Punctuator::CloseBracket, "call expression")?;
-                    lhs = GetField::new(lhs, idx).into();
+                    lhs = cursor.spanned(GetField::new(lhs, idx).into(), self.start);
                 }
                 TokenKind::TemplateNoSubstitution { .. } | TokenKind::TemplateMiddle { .. } => {
-                    lhs = TaggedTemplateLiteral::new(
+                    let tagged_template = TaggedTemplateLiteral::new(
                         self.allow_yield,
                         self.allow_await,
                         tok.span().start(),
                         lhs,
                     )
                     .parse(cursor)?;
+                    lhs = cursor.spanned(tagged_template, self.start);
                 }
                 _ => break,
             }
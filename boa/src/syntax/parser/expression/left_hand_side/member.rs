@@ -10,10 +10,10 @@ use crate::{
     syntax::{
         ast::{
             node::{
-                field::{GetConstField, GetField},
-                Call, New, Node,
+                field::{GetConstField, GetField, GetPrivateField, GetSuperField},
+                Call, New, Node, SuperCall,
             },
-            Keyword, Punctuator,
+            Const, Keyword, Punctuator,
         },
         lexer::TokenKind,
         parser::{
@@ -64,7 +64,8 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
         let _timer = BoaProfiler::global().start_event("MemberExpression", "Parsing");
 
-        let mut lhs = if cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind()
+        let start = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
+        let lhs = if cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind()
             == &TokenKind::Keyword(Keyword::New)
         {
             let _ = cursor.next().expect("new keyword disappeared");
@@ -73,9 +74,53 @@ where
             let call_node = Call::new(lhs, args);
 
             Node::from(New::from(call_node))
+        } else if cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind()
+            == &TokenKind::Keyword(Keyword::Super)
+        {
+            let _ = cursor.next().expect("super keyword disappeared");
+            let token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+            match token.kind() {
+                TokenKind::Punctuator(Punctuator::OpenParen) => {
+                    let args = Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                    SuperCall::new(args).into()
+                }
+                TokenKind::Punctuator(Punctuator::Dot) => {
+                    let _ = cursor.next()?.expect("dot punctuator token disappeared");
+                    let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+                    match token.kind() {
+                        TokenKind::Identifier(name) => {
+                            GetSuperField::new(Const::from(name.as_ref())).into()
+                        }
+                        TokenKind::Keyword(kw) => {
+                            GetSuperField::new(Const::from(kw.to_string())).into()
+                        }
+                        _ => {
+                            return Err(ParseError::expected(
+                                vec![TokenKind::identifier("identifier")],
+                                token,
+                                "super property",
+                            ));
+                        }
+                    }
+                }
+                TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                    let _ = cursor
+                        .next()?
+                        .expect("open bracket punctuator token disappeared");
+                    let idx =
+                        Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+                    cursor.expect(Punctuator::CloseBracket, "super property")?;
+                    GetSuperField::new(idx).into()
+                }
+                _ => {
+                    let token = token.clone();
+                    return Err(ParseError::unexpected(token, "super keyword"));
+                }
+            }
         } else {
             PrimaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?
         };
+        let mut lhs = cursor.spanned(lhs, start);
         while let Some(tok) = cursor.peek(0)? {
             match tok.kind() {
                 TokenKind::Punctuator(Punctuator::Dot) => {
@@ -85,10 +130,17 @@ where
 
                     match token.kind() {
                         TokenKind::Identifier(name) => {
-                            lhs = GetConstField::new(lhs, name.clone()).into()
+                            lhs =
+                                cursor.spanned(GetConstField::new(lhs, name.clone()).into(), start)
                         }
                         TokenKind::Keyword(kw) => {
-                            lhs = GetConstField::new(lhs, kw.to_string()).into()
+                            lhs = cursor
+                                .spanned(GetConstField::new(lhs, kw.to_string()).into(), start)
+                        }
+                        TokenKind::PrivateIdentifier(name) => {
+                            cursor.reference_private_name(name, token.span().start())?;
+                            lhs = cursor
+                                .spanned(GetPrivateField::new(lhs, name.clone()).into(), start)
                         }
                         _ => {
                             return Err(ParseError::expected(
@@ -106,16 +158,17 @@ where
                     let idx =
                         Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
                     cursor.expect(Punctuator::CloseBracket, "member expression")?;
-                    lhs = GetField::new(lhs, idx).into();
+                    lhs = cursor.spanned(GetField::new(lhs, idx).into(), start);
                 }
                 TokenKind::TemplateNoSubstitution { .. } | TokenKind::TemplateMiddle { .. } => {
-                    lhs = TaggedTemplateLiteral::new(
+                    let tagged_template = TaggedTemplateLiteral::new(
                         self.allow_yield,
                         self.allow_await,
                         tok.span().start(),
                         lhs,
                     )
                     .parse(cursor)?;
+                    lhs = cursor.spanned(tagged_template, start);
                 }
                 _ => break,
             }
@@ -0,0 +1,134 @@
+//! Optional chain expression parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Optional_chaining
+//! [spec]: https://tc39.es/ecma262/#prod-OptionalExpression
+
+use super::arguments::Arguments;
+use crate::{
+    syntax::{
+        ast::{
+            node::{Node, OptionalChain, OptionalOperation, OptionalOperationKind},
+            Position, Punctuator,
+        },
+        lexer::TokenKind,
+        parser::{
+            expression::Expression, AllowAwait, AllowYield, Cursor, ParseError, ParseResult,
+            TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+
+use std::io::Read;
+
+/// Parses an optional chain, like `a?.b.c`, after the expression it starts from.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-OptionalExpression
+#[derive(Debug)]
+pub(super) struct OptionalExpression {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    target: Node,
+    start: Position,
+}
+
+impl OptionalExpression {
+    /// Creates a new `OptionalExpression` parser.
+    ///
+    /// The start is the position of the target, where the span of the chain starts.
+    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A, target: Node, start: Position) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            target,
+            start,
+        }
+    }
+
+    /// Parses the name of a property accessed with the dot notation.
+    fn parse_name<R>(cursor: &mut Cursor<R>) -> Result<Box<str>, ParseError>
+    where
+        R: Read,
+    {
+        let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+        match token.kind() {
+            TokenKind::Identifier(name) => Ok(name.clone()),
+            TokenKind::Keyword(kw) => Ok(kw.to_string().into()),
+            TokenKind::BooleanLiteral(true) => Ok("true".into()),
+            TokenKind::BooleanLiteral(false) => Ok("false".into()),
+            TokenKind::NullLiteral => Ok("null".into()),
+            _ => Err(ParseError::expected(
+                vec![TokenKind::identifier("identifier")],
+                token,
+                "optional chain",
+            )),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for OptionalExpression
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("OptionalExpression", "Parsing");
+
+        let mut chain = Vec::new();
+        while let Some(token) = cursor.peek(0)? {
+            let shorted = token.kind() == &TokenKind::Punctuator(Punctuator::Optional);
+            if shorted {
+                cursor.next()?.expect("?. punctuator token disappeared"); // We move the parser forward.
+            }
+
+            let token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+            let kind = match token.kind() {
+                TokenKind::Punctuator(Punctuator::OpenParen) => {
+                    let args = Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                    OptionalOperationKind::Call { args }
+                }
+                TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                    cursor
+                        .next()?
+                        .expect("open bracket punctuator token disappeared"); // We move the parser forward.
+                    let field =
+                        Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+                    cursor.expect(Punctuator::CloseBracket, "optional chain")?;
+                    OptionalOperationKind::PropertyAccess { field }
+                }
+                TokenKind::Punctuator(Punctuator::Dot) if !shorted => {
+                    cursor.next()?.expect("dot punctuator token disappeared"); // We move the parser forward.
+                    OptionalOperationKind::SimplePropertyAccess {
+                        field: Self::parse_name(cursor)?,
+                    }
+                }
+                TokenKind::TemplateNoSubstitution { .. } | TokenKind::TemplateMiddle { .. } => {
+                    return Err(ParseError::general(
+                        "tagged template cannot be used in optional chain",
+                        token.span().start(),
+                    ));
+                }
+                _ if shorted => OptionalOperationKind::SimplePropertyAccess {
+                    field: Self::parse_name(cursor)?,
+                },
+                _ => break,
+            };
+
+            chain.push(OptionalOperation::new(kind, shorted));
+        }
+
+        Ok(cursor.spanned(OptionalChain::new(self.target, chain).into(), self.start))
+    }
+}
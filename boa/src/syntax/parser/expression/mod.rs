@@ -18,7 +18,11 @@ mod update;
 pub(in crate::syntax::parser) mod await_expr;
 
 use self::assignment::ExponentiationExpression;
-pub(super) use self::{assignment::AssignmentExpression, primary::Initializer};
+pub(super) use self::{
+    assignment::{check_strict_target, to_assignment_pattern, AssignmentExpression},
+    left_hand_side::LeftHandSideExpression,
+    primary::Initializer,
+};
 use super::{AllowAwait, AllowIn, AllowYield, Cursor, ParseResult, TokenParser};
 
 use crate::{
@@ -79,24 +83,33 @@ macro_rules! expression { ($name:ident, $lower:ident, [$( $op:path ),*], [$( $lo
                 cursor.set_goal($goal.unwrap());
             }
 
+            let start = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
             let mut lhs = $lower::new($( self.$low_param ),*).parse(cursor)?;
             while let Some(tok) = cursor.peek(0)? {
                 match *tok.kind() {
                     TokenKind::Punctuator(op) if $( op == $op )||* => {
                         let _ = cursor.next().expect("token disappeared");
-                        lhs = BinOp::new(
-                            op.as_binop().expect("Could not get binary operation."),
-                            lhs,
-                            $lower::new($( self.$low_param ),*).parse(cursor)?
-                        ).into();
+                        let rhs = $lower::new($( self.$low_param ),*).parse(cursor)?;
+                        lhs = cursor.spanned(
+                            BinOp::new(
+                                op.as_binop().expect("Could not get binary operation."),
+                                lhs,
+                                rhs
+                            ).into(),
+                            start,
+                        );
                     }
                     TokenKind::Keyword(op) if $( op == $op )||* => {
                         let _ = cursor.next().expect("token disappeared");
-                        lhs = BinOp::new(
-                            op.as_binop().expect("Could not get binary operation."),
-                            lhs,
-                            $lower::new($( self.$low_param ),*).parse(cursor)?
-                        ).into();
+                        let rhs = $lower::new($( self.$low_param ),*).parse(cursor)?;
+                        lhs = cursor.spanned(
+                            BinOp::new(
+                                op.as_binop().expect("Could not get binary operation."),
+                                lhs,
+                                rhs
+                            ).into(),
+                            start,
+                        );
                     }
                     _ => break
                 }
@@ -214,6 +227,7 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
         let _timer = BoaProfiler::global().start_event("ShortCircuitExpression", "Parsing");
 
+        let start = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
         let mut current_node =
             BitwiseORExpression::new(self.allow_in, self.allow_yield, self.allow_await)
                 .parse(cursor)?;
@@ -235,7 +249,8 @@ where
                         BitwiseORExpression::new(self.allow_in, self.allow_yield, self.allow_await)
                             .parse(cursor)?;
 
-                    current_node = BinOp::new(LogOp::And, current_node, rhs).into();
+                    current_node =
+                        cursor.spanned(BinOp::new(LogOp::And, current_node, rhs).into(), start);
                 }
                 TokenKind::Punctuator(Punctuator::BoolOr) => {
                     if previous == PreviousExpr::Coalesce {
@@ -254,7 +269,8 @@ where
                         PreviousExpr::Logical,
                     )
                     .parse(cursor)?;
-                    current_node = BinOp::new(LogOp::Or, current_node, rhs).into();
+                    current_node =
+                        cursor.spanned(BinOp::new(LogOp::Or, current_node, rhs).into(), start);
                 }
                 TokenKind::Punctuator(Punctuator::Coalesce) => {
                     if previous == PreviousExpr::Logical {
@@ -272,7 +288,8 @@ where
                     let rhs =
                         BitwiseORExpression::new(self.allow_in, self.allow_yield, self.allow_await)
                             .parse(cursor)?;
-                    current_node = BinOp::new(LogOp::Coalesce, current_node, rhs).into();
+                    current_node = cursor
+                        .spanned(BinOp::new(LogOp::Coalesce, current_node, rhs).into(), start);
                 }
                 _ => break,
             }
@@ -486,6 +503,7 @@ where
             cursor.set_goal(None::<InputElement>.unwrap());
         }
 
+        let start = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
         let mut lhs = ShiftExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
         while let Some(tok) = cursor.peek(0)? {
             match *tok.kind() {
@@ -496,24 +514,34 @@ where
                         || op == Punctuator::GreaterThanOrEq =>
                 {
                     let _ = cursor.next().expect("token disappeared");
-                    lhs = BinOp::new(
-                        op.as_binop().expect("Could not get binary operation."),
-                        lhs,
-                        ShiftExpression::new(self.allow_yield, self.allow_await).parse(cursor)?,
-                    )
-                    .into();
+                    let rhs =
+                        ShiftExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                    lhs = cursor.spanned(
+                        BinOp::new(
+                            op.as_binop().expect("Could not get binary operation."),
+                            lhs,
+                            rhs,
+                        )
+                        .into(),
+                        start,
+                    );
                 }
                 TokenKind::Keyword(op)
                     if op == Keyword::InstanceOf
                         || (op == Keyword::In && self.allow_in == AllowIn(true)) =>
                 {
                     let _ = cursor.next().expect("token disappeared");
-                    lhs = BinOp::new(
-                        op.as_binop().expect("Could not get binary operation."),
-                        lhs,
-                        ShiftExpression::new(self.allow_yield, self.allow_await).parse(cursor)?,
-                    )
-                    .into();
+                    let rhs =
+                        ShiftExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                    lhs = cursor.spanned(
+                        BinOp::new(
+                            op.as_binop().expect("Could not get binary operation."),
+                            lhs,
+                            rhs,
+                        )
+                        .into(),
+                        start,
+                    );
                 }
                 _ => break,
             }
@@ -76,10 +76,10 @@ where
 
             let _ = cursor.peek(0)?.ok_or(ParseError::AbruptEnd); // Check that there are more tokens to read.
 
-            if cursor.next_if(Punctuator::Spread)?.is_some() {
+            if let Some(token) = cursor.next_if(Punctuator::Spread)? {
                 let node = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
                     .parse(cursor)?;
-                elements.push(Spread::new(node).into());
+                elements.push(cursor.spanned(Spread::new(node).into(), token.span().start()));
             } else {
                 elements.push(
                     AssignmentExpression::new(true, self.allow_yield, self.allow_await)
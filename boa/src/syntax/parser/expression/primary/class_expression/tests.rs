@@ -0,0 +1,41 @@
+use crate::syntax::{
+    ast::node::{Class, Declaration, DeclarationList, Node},
+    parser::tests::check_parser,
+};
+
+/// Checks class expression parsing.
+#[test]
+fn check_class_expression() {
+    check_parser(
+        "const Foo = class {};",
+        vec![DeclarationList::Const(
+            vec![Declaration::new(
+                "Foo",
+                Some(Node::ClassExpr(Class::new(None, None, None, vec![]))),
+            )]
+            .into(),
+        )
+        .into()],
+    );
+}
+
+/// Checks named class expression parsing.
+#[test]
+fn check_named_class_expression() {
+    check_parser(
+        "const Foo = class Bar {};",
+        vec![DeclarationList::Const(
+            vec![Declaration::new(
+                "Foo",
+                Some(Node::ClassExpr(Class::new(
+                    Some("Bar".into()),
+                    None,
+                    None,
+                    vec![],
+                ))),
+            )]
+            .into(),
+        )
+        .into()],
+    );
+}
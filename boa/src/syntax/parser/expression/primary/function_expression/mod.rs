@@ -13,9 +13,9 @@ mod tests;
 use crate::{
     syntax::{
         ast::{node::FunctionExpr, Keyword, Punctuator},
-        lexer::{Error as LexError, Position, TokenKind},
+        lexer::TokenKind,
         parser::{
-            function::{FormalParameters, FunctionBody},
+            function::{check_function_early_errors, FormalParameters, FunctionBody},
             statement::BindingIdentifier,
             Cursor, ParseError, TokenParser,
         },
@@ -69,23 +69,7 @@ where
 
         cursor.expect(Punctuator::CloseBlock, "function expression")?;
 
-        // It is a Syntax Error if any element of the BoundNames of FormalParameters
-        // also occurs in the LexicallyDeclaredNames of FunctionBody.
-        // https://tc39.es/ecma262/#sec-function-definitions-static-semantics-early-errors
-        {
-            let lexically_declared_names = body.lexically_declared_names();
-            for param in params.as_ref() {
-                if lexically_declared_names.contains(param.name()) {
-                    return Err(ParseError::lex(LexError::Syntax(
-                        format!("Redeclaration of formal parameter `{}`", param.name()).into(),
-                        match cursor.peek(0)? {
-                            Some(token) => token.span().end(),
-                            None => Position::new(1, 1),
-                        },
-                    )));
-                }
-            }
-        }
+        check_function_early_errors(cursor, name.as_deref(), &params, &body, false)?;
 
         Ok(FunctionExpr::new(name, params, body))
     }
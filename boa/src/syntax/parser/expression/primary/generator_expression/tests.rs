@@ -0,0 +1,59 @@
+use crate::syntax::{
+    ast::{
+        node::{Declaration, DeclarationList, GeneratorExpr, StatementList, YieldExpr},
+        Const,
+    },
+    parser::tests::check_parser,
+};
+
+/// Checks generator expression parsing.
+#[test]
+fn check_generator_expression() {
+    check_parser(
+        "const gen = function*() {
+            yield 1;
+        };
+        ",
+        vec![DeclarationList::Const(
+            vec![Declaration::new(
+                "gen",
+                Some(
+                    GeneratorExpr::new::<Option<Box<str>>, _, StatementList>(
+                        None,
+                        [],
+                        vec![YieldExpr::new(Const::from(1), false).into()].into(),
+                    )
+                    .into(),
+                ),
+            )]
+            .into(),
+        )
+        .into()],
+    );
+}
+
+/// Checks parsing of a named generator expression with an empty `yield`.
+#[test]
+fn check_named_generator_expression() {
+    check_parser(
+        "const gen = function* named() {
+            yield;
+        };
+        ",
+        vec![DeclarationList::Const(
+            vec![Declaration::new(
+                "gen",
+                Some(
+                    GeneratorExpr::new::<_, _, StatementList>(
+                        Some("named".into()),
+                        [],
+                        vec![YieldExpr::new::<YieldExpr, _>(None, false).into()].into(),
+                    )
+                    .into(),
+                ),
+            )]
+            .into(),
+        )
+        .into()],
+    );
+}
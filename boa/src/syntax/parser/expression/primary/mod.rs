@@ -9,7 +9,9 @@
 
 mod array_initializer;
 mod async_function_expression;
+mod class_expression;
 mod function_expression;
+mod generator_expression;
 mod object_initializer;
 mod template;
 #[cfg(test)]
@@ -17,7 +19,8 @@ mod tests;
 
 use self::{
     array_initializer::ArrayLiteral, async_function_expression::AsyncFunctionExpression,
-    function_expression::FunctionExpression, object_initializer::ObjectLiteral,
+    class_expression::ClassExpression, function_expression::FunctionExpression,
+    generator_expression::GeneratorExpression, object_initializer::ObjectLiteral,
 };
 use super::Expression;
 use crate::{
@@ -27,7 +30,10 @@ use crate::{
             node::{Call, Identifier, New, Node},
             Const, Keyword, Punctuator,
         },
-        lexer::{token::Numeric, InputElement, TokenKind},
+        lexer::{
+            token::Numeric, Error as LexError, InputElement, TokenKind,
+            STRICT_FORBIDDEN_IDENTIFIERS,
+        },
         parser::{
             expression::primary::template::TemplateLiteral, AllowAwait, AllowYield, Cursor,
             ParseError, ParseResult, TokenParser,
@@ -76,11 +82,20 @@ where
         let _timer = BoaProfiler::global().start_event("PrimaryExpression", "Parsing");
 
         let tok = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+        let start = tok.span().start();
 
-        match tok.kind() {
+        let node = match tok.kind() {
             TokenKind::Keyword(Keyword::This) => Ok(Node::This),
             TokenKind::Keyword(Keyword::Function) => {
-                FunctionExpression.parse(cursor).map(Node::from)
+                match cursor.peek(0)?.map(|token| token.kind()) {
+                    Some(TokenKind::Punctuator(Punctuator::Mul)) => {
+                        GeneratorExpression.parse(cursor).map(Node::from)
+                    }
+                    _ => FunctionExpression.parse(cursor).map(Node::from),
+                }
+            }
+            TokenKind::Keyword(Keyword::Class) => {
+                ClassExpression::new(self.allow_yield, self.allow_await).parse(cursor)
             }
             TokenKind::Keyword(Keyword::Async) => AsyncFunctionExpression::new(self.allow_yield)
                 .parse(cursor)
@@ -106,7 +121,20 @@ where
             }
             TokenKind::BooleanLiteral(boolean) => Ok(Const::from(*boolean).into()),
             TokenKind::NullLiteral => Ok(Const::Null.into()),
-            TokenKind::Identifier(ident) => Ok(Identifier::from(ident.as_ref()).into()), // TODO: IdentifierReference
+            TokenKind::Identifier(ident) => {
+                // TODO: IdentifierReference
+                if cursor.strict_mode() && STRICT_FORBIDDEN_IDENTIFIERS.contains(&ident.as_ref()) {
+                    return Err(ParseError::lex(LexError::Syntax(
+                        format!(
+                            "using future reserved keyword '{}' not allowed in strict mode",
+                            ident
+                        )
+                        .into(),
+                        tok.span().start(),
+                    )));
+                }
+                Ok(Identifier::from(ident.as_ref()).into())
+            }
             TokenKind::StringLiteral(s) => Ok(Const::from(s.as_ref()).into()),
             TokenKind::TemplateNoSubstitution(template_string) => {
                 Ok(Const::from(template_string.to_owned_cooked().map_err(ParseError::lex)?).into())
@@ -151,6 +179,8 @@ where
             .parse(cursor)
             .map(Node::TemplateLit),
             _ => Err(ParseError::unexpected(tok.clone(), "primary expression")),
-        }
+        }?;
+
+        Ok(cursor.spanned(node, start))
     }
 }
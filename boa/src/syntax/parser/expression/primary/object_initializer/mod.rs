@@ -9,16 +9,16 @@
 
 #[cfg(test)]
 mod tests;
-use crate::syntax::lexer::TokenKind;
+use crate::syntax::lexer::{TokenKind, STRICT_FORBIDDEN_IDENTIFIERS};
 use crate::{
     syntax::{
         ast::{
             node::{self, FunctionExpr, MethodDefinitionKind, Node, Object},
-            Punctuator,
+            Keyword, Position, Punctuator,
         },
         parser::{
             expression::AssignmentExpression,
-            function::{FormalParameters, FunctionBody},
+            function::{check_function_early_errors, FormalParameters, FunctionBody},
             AllowAwait, AllowIn, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
         },
     },
@@ -62,6 +62,23 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("ObjectLiteral", "Parsing");
+
+        let property_names = cursor.property_names();
+        cursor.set_property_names(true);
+        let result = self.parse_properties(cursor);
+        cursor.set_property_names(property_names);
+
+        result
+    }
+}
+
+impl ObjectLiteral {
+    /// Parses the property definitions of the object literal, whose names can be strict mode
+    /// reserved words.
+    fn parse_properties<R>(self, cursor: &mut Cursor<R>) -> Result<Object, ParseError>
+    where
+        R: Read,
+    {
         let mut elements = Vec::new();
 
         loop {
@@ -134,22 +151,101 @@ where
             return Ok(node::PropertyDefinition::SpreadObject(node));
         }
 
-        let prop_name = cursor.next()?.ok_or(ParseError::AbruptEnd)?.to_string();
+        let start = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
+
+        // GeneratorMethod
+        // https://tc39.es/ecma262/#prod-GeneratorMethod
+        if cursor.next_if(Punctuator::Mul)?.is_some() {
+            let prop_name = cursor.next()?.ok_or(ParseError::AbruptEnd)?.to_string();
+            cursor.expect(Punctuator::OpenParen, "generator method definition")?;
+            let params = FormalParameters::new(true, false).parse(cursor)?;
+            cursor.expect(Punctuator::CloseParen, "generator method definition")?;
+            cursor.expect(Punctuator::OpenBlock, "generator method definition")?;
+            let body = FunctionBody::new(true, false).parse(cursor)?;
+            cursor.expect(Punctuator::CloseBlock, "generator method definition")?;
+            check_function_early_errors(cursor, None, &params, &body, true)?;
+
+            return Ok(node::PropertyDefinition::method_definition(
+                MethodDefinitionKind::Generator,
+                prop_name,
+                cursor.spanned_method(FunctionExpr::new(None, params, body), start),
+            ));
+        }
+
+        let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+        let prop_name = token.to_string();
         if cursor.next_if(Punctuator::Colon)?.is_some() {
             let val = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
                 .parse(cursor)?;
             return Ok(node::PropertyDefinition::property(prop_name, val));
         }
 
-        // TODO GeneratorMethod
-        // https://tc39.es/ecma262/#prod-GeneratorMethod
-
-        if prop_name.as_str() == "async" {
-            // TODO - AsyncMethod.
-            // https://tc39.es/ecma262/#prod-AsyncMethod
+        // IdentifierReference and CoverInitializedName, like `{ a }` and `{ a = 1 }`.
+        if let TokenKind::Identifier(ident) = token.kind() {
+            let is_reference = matches!(
+                cursor.peek(0)?.map(|t| t.kind()),
+                Some(TokenKind::Punctuator(Punctuator::Comma))
+                    | Some(TokenKind::Punctuator(Punctuator::CloseBlock))
+                    | Some(TokenKind::Punctuator(Punctuator::Assign))
+            );
+            if is_reference
+                && cursor.strict_mode()
+                && STRICT_FORBIDDEN_IDENTIFIERS.contains(&ident.as_ref())
+            {
+                return Err(ParseError::general(
+                    "using future reserved keyword not allowed in strict mode",
+                    token.span().start(),
+                ));
+            }
+            match cursor.peek(0)?.map(|t| t.kind()) {
+                Some(TokenKind::Punctuator(Punctuator::Comma))
+                | Some(TokenKind::Punctuator(Punctuator::CloseBlock)) => {
+                    return Ok(node::PropertyDefinition::identifier_reference(
+                        ident.clone(),
+                    ));
+                }
+                Some(TokenKind::Punctuator(Punctuator::Assign)) => {
+                    let init =
+                        Initializer::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+                    return Ok(node::PropertyDefinition::cover_initialized_name(
+                        ident.clone(),
+                        init,
+                    ));
+                }
+                _ => {}
+            }
+        }
 
+        // AsyncMethod
+        // https://tc39.es/ecma262/#prod-AsyncMethod
+        let is_async_method = token.kind() == &TokenKind::Keyword(Keyword::Async)
+            && matches!(
+                cursor.peek(0)?.map(|t| t.kind()),
+                Some(TokenKind::Identifier(_))
+                    | Some(TokenKind::Keyword(_))
+                    | Some(TokenKind::BooleanLiteral(_))
+                    | Some(TokenKind::NullLiteral)
+                    | Some(TokenKind::StringLiteral(_))
+                    | Some(TokenKind::NumericLiteral(_))
+            );
+        if is_async_method {
             // TODO - AsyncGeneratorMethod
             // https://tc39.es/ecma262/#prod-AsyncGeneratorMethod
+            cursor.peek_expect_no_lineterminator(0, "async method definition")?;
+            let prop_name = cursor.next()?.ok_or(ParseError::AbruptEnd)?.to_string();
+            cursor.expect(Punctuator::OpenParen, "async method definition")?;
+            let params = FormalParameters::new(false, true).parse(cursor)?;
+            cursor.expect(Punctuator::CloseParen, "async method definition")?;
+            cursor.expect(Punctuator::OpenBlock, "async method definition")?;
+            let body = FunctionBody::new(false, true).parse(cursor)?;
+            cursor.expect(Punctuator::CloseBlock, "async method definition")?;
+            check_function_early_errors(cursor, None, &params, &body, true)?;
+
+            return Ok(node::PropertyDefinition::method_definition(
+                MethodDefinitionKind::Async,
+                prop_name,
+                cursor.spanned_method(FunctionExpr::new(None, params, body), start),
+            ));
         }
 
         if cursor
@@ -157,7 +253,7 @@ where
             .is_some()
             || ["get", "set"].contains(&prop_name.as_str())
         {
-            return MethodDefinition::new(self.allow_yield, self.allow_await, prop_name)
+            return MethodDefinition::new(self.allow_yield, self.allow_await, prop_name, start)
                 .parse(cursor);
         }
 
@@ -177,11 +273,12 @@ struct MethodDefinition {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     identifier: String,
+    start: Position,
 }
 
 impl MethodDefinition {
     /// Creates a new `MethodDefinition` parser.
-    fn new<Y, A, I>(allow_yield: Y, allow_await: A, identifier: I) -> Self
+    fn new<Y, A, I>(allow_yield: Y, allow_await: A, identifier: I, start: Position) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -191,6 +288,7 @@ impl MethodDefinition {
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
             identifier: identifier.into(),
+            start,
         }
     }
 }
@@ -261,11 +359,12 @@ where
             TokenKind::Punctuator(Punctuator::CloseBlock),
             "property method definition",
         )?;
+        check_function_early_errors(cursor, None, &params, &body, true)?;
 
         Ok(node::PropertyDefinition::method_definition(
             methodkind,
             prop_name,
-            FunctionExpr::new(None, params, body),
+            cursor.spanned_method(FunctionExpr::new(None, params, body), self.start),
         ))
     }
 }
@@ -1,8 +1,8 @@
 use crate::syntax::{
     ast::{
         node::{
-            Declaration, DeclarationList, FormalParameter, FunctionExpr, MethodDefinitionKind,
-            Object, PropertyDefinition,
+            AwaitExpr, Declaration, DeclarationList, FormalParameter, FunctionExpr,
+            MethodDefinitionKind, Node, Object, PropertyDefinition, YieldExpr,
         },
         Const,
     },
@@ -203,3 +203,65 @@ fn check_object_short_function_set() {
         .into()],
     );
 }
+
+/// Testing generator method syntax.
+#[test]
+fn check_object_generator_method() {
+    let object_properties = vec![PropertyDefinition::method_definition(
+        MethodDefinitionKind::Generator,
+        "gen",
+        FunctionExpr::new(
+            None,
+            vec![],
+            vec![YieldExpr::new(Const::from(1), false).into()],
+        ),
+    )];
+
+    check_parser(
+        "const x = {
+            *gen() { yield 1; },
+        };
+        ",
+        vec![DeclarationList::Const(
+            vec![Declaration::new(
+                "x",
+                Some(Object::from(object_properties).into()),
+            )]
+            .into(),
+        )
+        .into()],
+    );
+}
+
+/// Testing async method syntax.
+#[test]
+fn check_object_async_method() {
+    let object_properties = vec![
+        PropertyDefinition::method_definition(
+            MethodDefinitionKind::Async,
+            "run",
+            FunctionExpr::new(
+                None,
+                vec![],
+                vec![AwaitExpr::from(Node::from(Const::from(1))).into()],
+            ),
+        ),
+        PropertyDefinition::property("async", Const::from(2)),
+    ];
+
+    check_parser(
+        "const x = {
+            async run() { await 1; },
+            async: 2,
+        };
+        ",
+        vec![DeclarationList::Const(
+            vec![Declaration::new(
+                "x",
+                Some(Object::from(object_properties).into()),
+            )]
+            .into(),
+        )
+        .into()],
+    );
+}
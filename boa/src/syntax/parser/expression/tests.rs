@@ -1,7 +1,10 @@
 use crate::syntax::{
     ast::op::{AssignOp, BitOp, CompOp, LogOp, NumOp},
     ast::{
-        node::{BinOp, Identifier},
+        node::{
+            BinOp, ConditionalOp, Identifier, OptionalChain, OptionalOperation,
+            OptionalOperationKind,
+        },
         Const,
     },
     parser::tests::{check_invalid, check_parser},
@@ -281,3 +284,56 @@ fn check_logical_expressions() {
     check_invalid("a ?? b || c");
     check_invalid("a || b ?? c");
 }
+
+/// Checks optional chains.
+#[test]
+fn check_optional_chain() {
+    check_parser(
+        "a?.b.c",
+        vec![OptionalChain::new(
+            Identifier::from("a"),
+            vec![
+                OptionalOperation::new(
+                    OptionalOperationKind::SimplePropertyAccess { field: "b".into() },
+                    true,
+                ),
+                OptionalOperation::new(
+                    OptionalOperationKind::SimplePropertyAccess { field: "c".into() },
+                    false,
+                ),
+            ],
+        )
+        .into()],
+    );
+
+    check_parser(
+        "a?.[b]?.(c)",
+        vec![OptionalChain::new(
+            Identifier::from("a"),
+            vec![
+                OptionalOperation::new(
+                    OptionalOperationKind::PropertyAccess {
+                        field: Identifier::from("b").into(),
+                    },
+                    true,
+                ),
+                OptionalOperation::new(
+                    OptionalOperationKind::Call {
+                        args: vec![Identifier::from("c").into()].into(),
+                    },
+                    true,
+                ),
+            ],
+        )
+        .into()],
+    );
+
+    check_parser(
+        "a?.5:1",
+        vec![ConditionalOp::new(Identifier::from("a"), Const::from(0.5), Const::from(1)).into()],
+    );
+
+    check_invalid("a?.b = 1");
+    check_invalid("a?.b`c`");
+    check_invalid("a?.b.c`d`");
+}
@@ -17,8 +17,8 @@ use crate::{
         },
         lexer::{Error as LexError, TokenKind},
         parser::{
-            expression::update::UpdateExpression, AllowAwait, AllowYield, Cursor, ParseError,
-            ParseResult, TokenParser,
+            expression::{await_expr::AwaitExpression, update::UpdateExpression},
+            AllowAwait, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
         },
     },
 };
@@ -63,7 +63,7 @@ where
 
         let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
         let token_start = tok.span().start();
-        match tok.kind() {
+        let node = match tok.kind() {
             TokenKind::Keyword(Keyword::Delete) => {
                 cursor.next()?.expect("Delete keyword vanished"); // Consume the token.
                 let val = self.parse(cursor)?;
@@ -76,6 +76,12 @@ where
                         )));
                     }
                 }
+                if let Node::GetPrivateField(_) = val {
+                    return Err(ParseError::general(
+                        "private elements can't be deleted",
+                        token_start,
+                    ));
+                }
 
                 Ok(node::UnaryOp::new(UnaryOp::Delete, val).into())
             }
@@ -87,6 +93,11 @@ where
                 cursor.next()?.expect("TypeOf keyword vanished"); // Consume the token.
                 Ok(node::UnaryOp::new(UnaryOp::TypeOf, self.parse(cursor)?).into())
             }
+            TokenKind::Keyword(Keyword::Await) if self.allow_await.0 => {
+                AwaitExpression::new(self.allow_yield)
+                    .parse(cursor)
+                    .map(Node::from)
+            }
             TokenKind::Punctuator(Punctuator::Add) => {
                 cursor.next()?.expect("+ token vanished"); // Consume the token.
                 Ok(node::UnaryOp::new(UnaryOp::Plus, self.parse(cursor)?).into())
@@ -104,6 +115,8 @@ where
                 Ok(node::UnaryOp::new(UnaryOp::Not, self.parse(cursor)?).into())
             }
             _ => UpdateExpression::new(self.allow_yield, self.allow_await).parse(cursor),
-        }
+        }?;
+
+        Ok(cursor.spanned(node, token_start))
     }
 }
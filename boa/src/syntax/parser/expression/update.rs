@@ -12,8 +12,8 @@ use crate::{
         ast::{node, op::UnaryOp, Node, Punctuator},
         lexer::TokenKind,
         parser::{
-            expression::unary::UnaryExpression, AllowAwait, AllowYield, Cursor, ParseError,
-            ParseResult, TokenParser,
+            expression::{assignment::check_strict_target, unary::UnaryExpression},
+            AllowAwait, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
         },
     },
 };
@@ -56,22 +56,27 @@ where
         let _timer = BoaProfiler::global().start_event("UpdateExpression", "Parsing");
 
         let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let start = tok.span().start();
         match tok.kind() {
             TokenKind::Punctuator(Punctuator::Inc) => {
                 cursor.next()?.expect("Punctuator::Inc token disappeared");
-                return Ok(node::UnaryOp::new(
-                    UnaryOp::IncrementPre,
-                    UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?,
-                )
-                .into());
+                let target =
+                    UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                check_strict_target(cursor, &target, start)?;
+                return Ok(cursor.spanned(
+                    node::UnaryOp::new(UnaryOp::IncrementPre, target).into(),
+                    start,
+                ));
             }
             TokenKind::Punctuator(Punctuator::Dec) => {
                 cursor.next()?.expect("Punctuator::Dec token disappeared");
-                return Ok(node::UnaryOp::new(
-                    UnaryOp::DecrementPre,
-                    UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?,
-                )
-                .into());
+                let target =
+                    UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                check_strict_target(cursor, &target, start)?;
+                return Ok(cursor.spanned(
+                    node::UnaryOp::new(UnaryOp::DecrementPre, target).into(),
+                    start,
+                ));
             }
             _ => {}
         }
@@ -81,11 +86,19 @@ where
             match tok.kind() {
                 TokenKind::Punctuator(Punctuator::Inc) => {
                     cursor.next()?.expect("Punctuator::Inc token disappeared");
-                    return Ok(node::UnaryOp::new(UnaryOp::IncrementPost, lhs).into());
+                    check_strict_target(cursor, &lhs, start)?;
+                    return Ok(cursor.spanned(
+                        node::UnaryOp::new(UnaryOp::IncrementPost, lhs).into(),
+                        start,
+                    ));
                 }
                 TokenKind::Punctuator(Punctuator::Dec) => {
                     cursor.next()?.expect("Punctuator::Dec token disappeared");
-                    return Ok(node::UnaryOp::new(UnaryOp::DecrementPost, lhs).into());
+                    check_strict_target(cursor, &lhs, start)?;
+                    return Ok(cursor.spanned(
+                        node::UnaryOp::new(UnaryOp::DecrementPost, lhs).into(),
+                        start,
+                    ));
                 }
                 _ => {}
             }
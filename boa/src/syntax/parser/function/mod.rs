@@ -13,13 +13,14 @@ mod tests;
 use crate::{
     syntax::{
         ast::{
-            node::{self},
-            Punctuator,
+            node::{self, BindingTarget as BindingTargetNode, Node},
+            Const, Punctuator,
         },
-        lexer::{InputElement, TokenKind},
+        lexer::{Error as LexError, InputElement, Position, TokenKind},
         parser::{
-            expression::Initializer,
-            statement::{BindingIdentifier, StatementList},
+            statement::{
+                check_labels, BindingElement, BindingTarget, StatementList, STRICT_RESTRICTED_NAMES,
+            },
             AllowAwait, AllowYield, Cursor, ParseError, TokenParser,
         },
     },
@@ -154,10 +155,9 @@ where
         let _timer = BoaProfiler::global().start_event("BindingRestElement", "Parsing");
         cursor.expect(Punctuator::Spread, "rest parameter")?;
 
-        let param = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
-        // TODO: BindingPattern
+        let target = BindingTarget::new(self.allow_yield, self.allow_await).parse(cursor)?;
 
-        Ok(Self::Output::new(param, None, true))
+        Ok(Self::Output::new(target, None, true))
     }
 }
 
@@ -198,22 +198,9 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("FormalParameter", "Parsing");
 
-        // TODO: BindingPattern
+        let element = BindingElement::new(self.allow_yield, self.allow_await).parse(cursor)?;
 
-        let param = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
-
-        let init = if let Some(t) = cursor.peek(0)? {
-            // Check that this is an initilizer before attempting parse.
-            if *t.kind() == TokenKind::Punctuator(Punctuator::Assign) {
-                Some(Initializer::new(true, self.allow_yield, self.allow_await).parse(cursor)?)
-            } else {
-                None
-            }
-        } else {
-            None
-        };
-
-        Ok(Self::Output::new(param, init, false))
+        Ok(Self::Output::from(element))
     }
 }
 
@@ -264,29 +251,133 @@ where
         let _timer = BoaProfiler::global().start_event("FunctionStatementList", "Parsing");
 
         let global_strict_mode = cursor.strict_mode();
-        if let Some(tk) = cursor.peek(0)? {
-            match tk.kind() {
-                TokenKind::Punctuator(Punctuator::CloseBlock) => {
-                    return Ok(Vec::new().into());
-                }
-                TokenKind::StringLiteral(string) if string.as_ref() == "use strict" => {
-                    cursor.set_strict_mode(true);
-                }
-                _ => {}
-            }
-        }
-
+        // The body of a method is not part of the class body or object literal around it.
+        let property_names = cursor.property_names();
+        cursor.set_property_names(false);
         let stmlist = StatementList::new(
             self.allow_yield,
             self.allow_await,
             true,
-            true,
+            false,
             &FUNCTION_BREAK_TOKENS,
+            true,
         )
         .parse(cursor);
 
         // Reset strict mode back to the global scope.
         cursor.set_strict_mode(global_strict_mode);
-        stmlist
+        cursor.set_property_names(property_names);
+
+        let stmlist = stmlist?;
+        check_labels(stmlist.items())?;
+        Ok(stmlist)
     }
 }
+
+/// Checks the early errors of a function that depend on its body.
+///
+/// The name and the parameters of a function are parsed before its body, which can make the
+/// whole function strict mode code, so they can only be checked once the body has been parsed.
+/// The parameters of arrow functions and methods, and non-simple parameter lists, never share a
+/// name, which `unique_params` requires for the functions that are not already known to have
+/// them.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-function-definitions-static-semantics-early-errors
+pub(in crate::syntax::parser) fn check_function_early_errors<R>(
+    cursor: &mut Cursor<R>,
+    name: Option<&str>,
+    params: &[node::FormalParameter],
+    body: &node::StatementList,
+    unique_params: bool,
+) -> Result<(), ParseError>
+where
+    R: Read,
+{
+    let error = |message: String, cursor: &mut Cursor<R>| {
+        let position = match cursor.peek(0)? {
+            Some(token) => token.span().end(),
+            None => Position::new(1, 1),
+        };
+        Err(ParseError::lex(LexError::Syntax(message.into(), position)))
+    };
+
+    let is_simple = params.iter().all(|param| {
+        matches!(param.target(), BindingTargetNode::Identifier(_))
+            && param.init().is_none()
+            && !param.is_rest_param()
+    });
+
+    // It is a Syntax Error if FunctionBodyContainsUseStrict of FunctionBody is true and
+    // IsSimpleParameterList of FormalParameters is false.
+    if !is_simple && contains_use_strict(body) {
+        return error(
+            "Illegal 'use strict' directive in function with non-simple parameter list".into(),
+            cursor,
+        );
+    }
+
+    let strict = body.strict();
+    if strict {
+        if let Some(name) = name.filter(|name| STRICT_RESTRICTED_NAMES.contains(name)) {
+            return error(
+                format!("Unexpected `{}` as function name in strict mode", name),
+                cursor,
+            );
+        }
+    }
+
+    let lexically_declared_names = body.lexically_declared_names();
+    let mut param_names = Vec::new();
+    for param_name in params.iter().flat_map(|param| param.names()) {
+        if strict && STRICT_RESTRICTED_NAMES.contains(&param_name) {
+            return error(
+                format!(
+                    "Unexpected `{}` as parameter name in strict mode",
+                    param_name
+                ),
+                cursor,
+            );
+        }
+
+        // It is a Syntax Error if FormalParameters Contains any duplicate elements, in strict
+        // mode code or for the parameter lists that are not simple.
+        if (strict || unique_params || !is_simple) && param_names.contains(&param_name) {
+            return error(format!("Duplicate parameter name `{}`", param_name), cursor);
+        }
+        param_names.push(param_name);
+
+        // It is a Syntax Error if any element of the BoundNames of FormalParameters
+        // also occurs in the LexicallyDeclaredNames of FunctionBody.
+        if lexically_declared_names.contains(param_name) {
+            return error(
+                format!("Redeclaration of formal parameter `{}`", param_name),
+                cursor,
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks if the directive prologue of a function body contains a `"use strict"` directive.
+///
+/// The directives are the string literals at the start of the body, after the hoisted function
+/// declarations.
+fn contains_use_strict(body: &node::StatementList) -> bool {
+    body.items()
+        .iter()
+        .skip_while(|item| {
+            matches!(
+                item,
+                Node::FunctionDecl(_) | Node::AsyncFunctionDecl(_) | Node::GeneratorDecl(_)
+            )
+        })
+        .map_while(|item| match item {
+            Node::Const(Const::String(string)) => Some(string),
+            _ => None,
+        })
+        .any(|string| string.as_ref() == "use strict")
+}
@@ -1,10 +1,11 @@
 use crate::syntax::{
     ast::node::{
-        ArrowFunctionDecl, BinOp, Declaration, DeclarationList, FormalParameter, FunctionDecl,
-        Identifier, Node, Return,
+        ArrowFunctionDecl, AwaitExpr, BinOp, Declaration, DeclarationList, FormalParameter,
+        FunctionDecl, Identifier, Node, Return,
     },
     ast::op::NumOp,
-    parser::tests::check_parser,
+    parser::tests::{check_invalid, check_parser},
+    Parser,
 };
 
 /// Checks basic function declaration parsing.
@@ -106,6 +107,38 @@ fn check_arrow_rest() {
     );
 }
 
+/// Checks async arrow functions with a single parameter and with a parameter list.
+#[test]
+fn check_async_arrow() {
+    let body = || {
+        vec![Return::new::<Node, Option<_>, Option<_>>(
+            Some(AwaitExpr::from(Node::from(Identifier::from("a"))).into()),
+            None,
+        )
+        .into()]
+    };
+    check_parser(
+        "async a => await a",
+        vec![
+            ArrowFunctionDecl::new_async(vec![FormalParameter::new("a", None, false)], body())
+                .into(),
+        ],
+    );
+    check_parser(
+        "async (a) => { return await a; }",
+        vec![
+            ArrowFunctionDecl::new_async(vec![FormalParameter::new("a", None, false)], body())
+                .into(),
+        ],
+    );
+}
+
+/// Checks that `await` is not allowed as a parameter name of an async arrow function.
+#[test]
+fn check_async_arrow_await_parameter() {
+    check_invalid("async (await) => {}");
+}
+
 /// Checks an arrow function with expression return.
 #[test]
 fn check_arrow() {
@@ -391,3 +424,42 @@ fn check_arrow_assignment_3arg_nobrackets() {
         .into()],
     );
 }
+
+/// Checks that the directive prologue of a function body makes the function strict mode code.
+#[test]
+fn check_use_strict_directive() {
+    check_invalid("function f() { 'use strict'; with (a) {} }");
+    check_invalid("function f() { 'a'; \"use strict\"; 010; }");
+    check_invalid("(() => { 'use strict'; with (a) {} })");
+
+    // Only the string literals at the start of the body, without escapes, are directives.
+    Parser::new(
+        "function f() { 1; 'use strict'; with (a) {} }".as_bytes(),
+        false,
+    )
+    .parse_all()
+    .expect("failed to parse");
+    Parser::new(
+        "function f() { 'use\\x20strict'; with (a) {} }".as_bytes(),
+        false,
+    )
+    .parse_all()
+    .expect("failed to parse");
+}
+
+/// Checks the early errors of the parameters of strict mode functions.
+#[test]
+fn check_strict_parameters() {
+    check_invalid("function f(a, a) { 'use strict'; }");
+    check_invalid("'use strict'; function f(a, a) {}");
+    check_invalid("function eval() { 'use strict'; }");
+    check_invalid("function f(arguments) { 'use strict'; }");
+    check_invalid("function f(a = 1) { 'use strict'; }");
+    check_invalid("function f(a, [a]) {}");
+    check_invalid("(a, a) => a");
+    check_invalid("({ m(a, a) {} })");
+
+    Parser::new("function f(a, a) {}".as_bytes(), false)
+        .parse_all()
+        .expect("failed to parse");
+}
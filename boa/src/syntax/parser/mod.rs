@@ -1,15 +1,17 @@
 //! Boa parser implementation.
 
+mod class;
 mod cursor;
 pub mod error;
 mod expression;
 mod function;
+mod module;
 mod statement;
 #[cfg(test)]
 mod tests;
 
 pub use self::error::{ParseError, ParseResult};
-use crate::syntax::{ast::node::StatementList, lexer::TokenKind};
+use crate::syntax::ast::node::{Module, StatementList};
 
 use cursor::Cursor;
 
@@ -104,6 +106,16 @@ impl<R> Parser<R> {
     {
         Script.parse(&mut self.cursor)
     }
+
+    /// Parses the source with the `Module` goal symbol.
+    ///
+    /// Module code is always strict mode code.
+    pub fn parse_module(&mut self) -> Result<Module, ParseError>
+    where
+        R: Read,
+    {
+        module::Module.parse(&mut self.cursor)
+    }
 }
 
 /// Parses a full script.
@@ -122,17 +134,12 @@ where
     type Output = StatementList;
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
-        match cursor.peek(0)? {
-            Some(tok) => {
-                match tok.kind() {
-                    TokenKind::StringLiteral(string) if string.as_ref() == "use strict" => {
-                        cursor.set_strict_mode(true);
-                    }
-                    _ => {}
-                }
-                ScriptBody.parse(cursor)
-            }
-            None => Ok(StatementList::from(Vec::new())),
+        if cursor.peek(0)?.is_some() {
+            ScriptBody.parse(cursor)
+        } else {
+            let mut statement_list = StatementList::from(Vec::new());
+            statement_list.set_strict(cursor.strict_mode());
+            Ok(statement_list)
         }
     }
 }
@@ -153,6 +160,9 @@ where
     type Output = StatementList;
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
-        self::statement::StatementList::new(false, false, false, true, &[]).parse(cursor)
+        let statement_list =
+            statement::StatementList::new(false, false, false, false, &[], true).parse(cursor)?;
+        statement::check_labels(statement_list.items())?;
+        Ok(statement_list)
     }
 }
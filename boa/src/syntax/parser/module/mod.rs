@@ -0,0 +1,544 @@
+//! Module parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Modules
+//! [spec]: https://tc39.es/ecma262/#sec-modules
+
+#[cfg(test)]
+mod tests;
+
+use super::{
+    expression::AssignmentExpression,
+    statement::{
+        check_labels, BindingIdentifier, Declaration, StatementListItem, VariableStatement,
+    },
+    Cursor, ParseError, TokenParser,
+};
+use crate::{
+    syntax::{
+        ast::{
+            node::{
+                self,
+                module::{declared_names, var_declared_names},
+                ExportDecl, ExportSpecifier, ImportDecl, ImportSpecifier, ModuleItem, Node,
+            },
+            Keyword, Punctuator,
+        },
+        lexer::{Error as LexError, Position, TokenKind},
+    },
+    BoaProfiler,
+};
+use std::{collections::HashSet, io::Read, slice};
+
+/// Parses a full module.
+///
+/// Module code is always strict mode code.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-Module
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Module;
+
+impl<R> TokenParser<R> for Module
+where
+    R: Read,
+{
+    type Output = node::Module;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        cursor.set_strict_mode(true);
+        ModuleItemList.parse(cursor)
+    }
+}
+
+/// Parses the list of items of a module, and checks its early errors.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleItemList
+#[derive(Debug, Clone, Copy)]
+struct ModuleItemList;
+
+impl<R> TokenParser<R> for ModuleItemList
+where
+    R: Read,
+{
+    type Output = node::Module;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ModuleItemList", "Parsing");
+        let mut items = Vec::new();
+        let mut positions = Vec::new();
+
+        while let Some(token) = cursor.peek(0)? {
+            positions.push(token.span().start());
+            items.push(ModuleItemParser.parse(cursor)?);
+
+            // move the cursor forward for any consecutive semicolon.
+            while cursor.next_if(Punctuator::Semicolon)?.is_some() {}
+        }
+
+        check_early_errors(&items, &positions)?;
+
+        Ok(items.into())
+    }
+}
+
+/// Checks the early errors of a module.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-module-semantics-static-semantics-early-errors
+fn check_early_errors(items: &[ModuleItem], positions: &[Position]) -> Result<(), ParseError> {
+    let error = |message: String, position: Position| {
+        Err(ParseError::lex(LexError::Syntax(message.into(), position)))
+    };
+
+    let mut lexically_declared_names: HashSet<&str> = HashSet::new();
+    let mut var_names: HashSet<&str> = HashSet::new();
+    let mut exported_names: HashSet<&str> = HashSet::new();
+
+    for (item, &position) in items.iter().zip(positions) {
+        let (lexical, var) = match item {
+            ModuleItem::ImportDecl(import) => (import.names(), Vec::new()),
+            ModuleItem::ExportDecl(ExportDecl::Declaration(node))
+            | ModuleItem::ExportDecl(ExportDecl::Default(node))
+            | ModuleItem::Statement(node) => match node {
+                Node::VarDeclList(_) => (Vec::new(), declared_names(node)),
+                _ => (declared_names(node), var_declared_names(node)),
+            },
+            ModuleItem::ExportDecl(_) => (Vec::new(), Vec::new()),
+        };
+
+        for name in lexical {
+            if var_names.contains(name) || !lexically_declared_names.insert(name) {
+                return error(format!("Redeclaration of variable `{}`", name), position);
+            }
+        }
+        for name in var {
+            if lexically_declared_names.contains(name) {
+                return error(format!("Redeclaration of variable `{}`", name), position);
+            }
+            var_names.insert(name);
+        }
+
+        if let ModuleItem::Statement(node) = item {
+            check_labels(slice::from_ref(node))?;
+        }
+
+        if let ModuleItem::ExportDecl(export) = item {
+            for name in export.exported_names() {
+                if !exported_names.insert(name) {
+                    return error(format!("Duplicate export of `{}`", name), position);
+                }
+            }
+        }
+    }
+
+    for (item, &position) in items.iter().zip(positions) {
+        if let ModuleItem::ExportDecl(ExportDecl::Named {
+            specifiers,
+            from: None,
+        }) = item
+        {
+            for name in specifiers.iter().map(ExportSpecifier::local) {
+                if !lexically_declared_names.contains(name) && !var_names.contains(name) {
+                    return error(format!("Export of undeclared binding `{}`", name), position);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Parses a module item: an import declaration, an export declaration or a statement list item.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleItem
+#[derive(Debug, Clone, Copy)]
+struct ModuleItemParser;
+
+impl<R> TokenParser<R> for ModuleItemParser
+where
+    R: Read,
+{
+    type Output = ModuleItem;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+
+        match tok.kind() {
+            TokenKind::Keyword(Keyword::Import) => ImportDeclaration.parse(cursor).map(Into::into),
+            TokenKind::Keyword(Keyword::Export) => ExportDeclaration.parse(cursor).map(Into::into),
+            _ => StatementListItem::new(false, false, false, false)
+                .parse(cursor)
+                .map(ModuleItem::Statement),
+        }
+    }
+}
+
+/// Parses an `import` declaration.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import
+/// [spec]: https://tc39.es/ecma262/#prod-ImportDeclaration
+#[derive(Debug, Clone, Copy)]
+struct ImportDeclaration;
+
+impl<R> TokenParser<R> for ImportDeclaration
+where
+    R: Read,
+{
+    type Output = ImportDecl;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ImportDeclaration", "Parsing");
+        cursor.expect(Keyword::Import, "import declaration")?;
+
+        let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        if let TokenKind::StringLiteral(_) = tok.kind() {
+            let from = ModuleSpecifier.parse(cursor)?;
+            cursor.expect_semicolon("import declaration")?;
+            return Ok(ImportDecl::new(None, None, Vec::new(), from));
+        }
+
+        let mut default = None;
+        let mut namespace = None;
+        let mut specifiers = Vec::new();
+
+        let parse_rest = match tok.kind() {
+            TokenKind::Punctuator(Punctuator::Mul)
+            | TokenKind::Punctuator(Punctuator::OpenBlock) => true,
+            _ => {
+                default = Some(BindingIdentifier::new(false, false).parse(cursor)?);
+                cursor.next_if(Punctuator::Comma)?.is_some()
+            }
+        };
+
+        if parse_rest {
+            let tok = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+            match tok.kind() {
+                TokenKind::Punctuator(Punctuator::Mul) => {
+                    cursor.expect(TokenKind::identifier("as"), "import declaration")?;
+                    namespace = Some(BindingIdentifier::new(false, false).parse(cursor)?);
+                }
+                TokenKind::Punctuator(Punctuator::OpenBlock) => {
+                    specifiers = NamedImports.parse(cursor)?;
+                }
+                _ => {
+                    return Err(ParseError::expected(
+                        vec![
+                            TokenKind::Punctuator(Punctuator::Mul),
+                            TokenKind::Punctuator(Punctuator::OpenBlock),
+                        ],
+                        tok,
+                        "import declaration",
+                    ))
+                }
+            }
+        }
+
+        cursor.expect(TokenKind::identifier("from"), "import declaration")?;
+        let from = ModuleSpecifier.parse(cursor)?;
+        cursor.expect_semicolon("import declaration")?;
+
+        Ok(ImportDecl::new(default, namespace, specifiers, from))
+    }
+}
+
+/// Parses the named imports of an `import` declaration, after the opening brace.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-NamedImports
+#[derive(Debug, Clone, Copy)]
+struct NamedImports;
+
+impl<R> TokenParser<R> for NamedImports
+where
+    R: Read,
+{
+    type Output = Vec<ImportSpecifier>;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let mut specifiers = Vec::new();
+
+        loop {
+            if cursor.next_if(Punctuator::CloseBlock)?.is_some() {
+                break;
+            }
+
+            let is_renamed = cursor
+                .peek(1)?
+                .map_or(false, |tok| tok.kind() == &TokenKind::identifier("as"));
+            let specifier = if is_renamed {
+                let imported = IdentifierName.parse(cursor)?;
+                cursor.next()?;
+                let local = BindingIdentifier::new(false, false).parse(cursor)?;
+                ImportSpecifier::new(imported, local)
+            } else {
+                let local = BindingIdentifier::new(false, false).parse(cursor)?;
+                ImportSpecifier::new(local.clone(), local)
+            };
+            specifiers.push(specifier);
+
+            if cursor.next_if(Punctuator::Comma)?.is_none() {
+                cursor.expect(Punctuator::CloseBlock, "named imports")?;
+                break;
+            }
+        }
+
+        Ok(specifiers)
+    }
+}
+
+/// Parses an `export` declaration.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/export
+/// [spec]: https://tc39.es/ecma262/#prod-ExportDeclaration
+#[derive(Debug, Clone, Copy)]
+struct ExportDeclaration;
+
+impl<R> TokenParser<R> for ExportDeclaration
+where
+    R: Read,
+{
+    type Output = ExportDecl;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ExportDeclaration", "Parsing");
+        cursor.expect(Keyword::Export, "export declaration")?;
+
+        let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        match tok.kind() {
+            TokenKind::Punctuator(Punctuator::Mul) => {
+                cursor.next()?;
+                let alias = if cursor.next_if(TokenKind::identifier("as"))?.is_some() {
+                    Some(IdentifierName.parse(cursor)?)
+                } else {
+                    None
+                };
+                cursor.expect(TokenKind::identifier("from"), "export declaration")?;
+                let from = ModuleSpecifier.parse(cursor)?;
+                cursor.expect_semicolon("export declaration")?;
+
+                Ok(ExportDecl::All { alias, from })
+            }
+            TokenKind::Punctuator(Punctuator::OpenBlock) => {
+                let start = tok.span().start();
+                cursor.next()?;
+                let (specifiers, has_reserved_word) = NamedExports.parse(cursor)?;
+                let from = if cursor.next_if(TokenKind::identifier("from"))?.is_some() {
+                    Some(ModuleSpecifier.parse(cursor)?)
+                } else if has_reserved_word {
+                    return Err(ParseError::lex(LexError::Syntax(
+                        "Reserved word exported without `from` clause".into(),
+                        start,
+                    )));
+                } else {
+                    None
+                };
+                cursor.expect_semicolon("export declaration")?;
+
+                Ok(ExportDecl::Named {
+                    specifiers: specifiers.into(),
+                    from,
+                })
+            }
+            TokenKind::Keyword(Keyword::Var) => VariableStatement::new(false, false)
+                .parse(cursor)
+                .map(|list| ExportDecl::Declaration(Node::from(list))),
+            TokenKind::Keyword(Keyword::Default) => {
+                cursor.next()?;
+                ExportDefault.parse(cursor).map(ExportDecl::Default)
+            }
+            _ => Declaration::new(false, false, true)
+                .parse(cursor)
+                .map(ExportDecl::Declaration),
+        }
+    }
+}
+
+/// Parses the named exports of an `export` declaration, after the opening brace.
+///
+/// Also returns whether one of the local names is a reserved word, which is only allowed when
+/// re-exporting another module.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-NamedExports
+#[derive(Debug, Clone, Copy)]
+struct NamedExports;
+
+impl<R> TokenParser<R> for NamedExports
+where
+    R: Read,
+{
+    type Output = (Vec<ExportSpecifier>, bool);
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let mut specifiers = Vec::new();
+        let mut has_reserved_word = false;
+
+        loop {
+            if cursor.next_if(Punctuator::CloseBlock)?.is_some() {
+                break;
+            }
+
+            has_reserved_word |= !matches!(
+                cursor.peek(0)?.map(|tok| tok.kind()),
+                Some(TokenKind::Identifier(_))
+            );
+            let local = IdentifierName.parse(cursor)?;
+            let exported = if cursor.next_if(TokenKind::identifier("as"))?.is_some() {
+                IdentifierName.parse(cursor)?
+            } else {
+                local.clone()
+            };
+            specifiers.push(ExportSpecifier::new(local, exported));
+
+            if cursor.next_if(Punctuator::Comma)?.is_none() {
+                cursor.expect(Punctuator::CloseBlock, "named exports")?;
+                break;
+            }
+        }
+
+        Ok((specifiers, has_reserved_word))
+    }
+}
+
+/// Parses the exported value of an `export default` declaration.
+///
+/// Named function and class declarations are parsed as declarations. Anonymous ones are parsed as
+/// expressions, which are not followed by a semicolon.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ExportDeclaration
+#[derive(Debug, Clone, Copy)]
+struct ExportDefault;
+
+impl<R> TokenParser<R> for ExportDefault
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let is_identifier = |cursor: &mut Cursor<R>, skip_n| -> Result<bool, ParseError> {
+            Ok(matches!(
+                cursor.peek(skip_n)?.map(|tok| tok.kind()),
+                Some(TokenKind::Identifier(_))
+            ))
+        };
+        let is_punctuator = |cursor: &mut Cursor<R>, skip_n, punc| -> Result<bool, ParseError> {
+            Ok(cursor
+                .peek(skip_n)?
+                .map_or(false, |tok| tok.kind() == &TokenKind::Punctuator(punc)))
+        };
+        let is_function = |cursor: &mut Cursor<R>, skip_n| -> Result<bool, ParseError> {
+            Ok(cursor.peek(skip_n)?.map_or(false, |tok| {
+                tok.kind() == &TokenKind::Keyword(Keyword::Function)
+            }))
+        };
+
+        let (is_declaration, is_anonymous) = match cursor.peek(0)?.map(|tok| tok.kind().clone()) {
+            Some(TokenKind::Keyword(Keyword::Function)) => {
+                let is_declaration = is_identifier(cursor, 1)?
+                    || (is_punctuator(cursor, 1, Punctuator::Mul)? && is_identifier(cursor, 2)?);
+                (is_declaration, !is_declaration)
+            }
+            Some(TokenKind::Keyword(Keyword::Async)) if is_function(cursor, 1)? => {
+                let is_declaration = is_identifier(cursor, 2)?;
+                (is_declaration, !is_declaration)
+            }
+            Some(TokenKind::Keyword(Keyword::Class)) => {
+                let is_declaration = is_identifier(cursor, 1)?;
+                (is_declaration, !is_declaration)
+            }
+            _ => (false, false),
+        };
+
+        if is_declaration {
+            return Declaration::new(false, false, true).parse(cursor);
+        }
+
+        let expression = AssignmentExpression::new(true, false, false).parse(cursor)?;
+        if !is_anonymous {
+            cursor.expect_semicolon("export default declaration")?;
+        }
+
+        Ok(expression)
+    }
+}
+
+/// Parses the specifier of a module: a string literal.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleSpecifier
+#[derive(Debug, Clone, Copy)]
+struct ModuleSpecifier;
+
+impl<R> TokenParser<R> for ModuleSpecifier
+where
+    R: Read,
+{
+    type Output = Box<str>;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let tok = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+        match tok.kind() {
+            TokenKind::StringLiteral(specifier) => Ok(specifier.clone()),
+            _ => Err(ParseError::unexpected(tok, "expected a module specifier")),
+        }
+    }
+}
+
+/// Parses an identifier name: an identifier or a reserved word.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-IdentifierName
+#[derive(Debug, Clone, Copy)]
+struct IdentifierName;
+
+impl<R> TokenParser<R> for IdentifierName
+where
+    R: Read,
+{
+    type Output = Box<str>;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let tok = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+        match tok.kind() {
+            TokenKind::Identifier(name) => Ok(name.clone()),
+            TokenKind::Keyword(keyword) => Ok(keyword.as_str().into()),
+            TokenKind::BooleanLiteral(value) => Ok(value.to_string().into()),
+            TokenKind::NullLiteral => Ok("null".into()),
+            _ => Err(ParseError::unexpected(tok, "expected an identifier name")),
+        }
+    }
+}
@@ -0,0 +1,186 @@
+//! Tests for the module parser.
+
+use crate::syntax::{
+    ast::{
+        node::{
+            BinOp, Block, Declaration, DeclarationList, ExportDecl, ExportSpecifier, FunctionDecl,
+            FunctionExpr, Identifier, If, ImportDecl, ImportSpecifier, Module, ModuleItem, Node,
+        },
+        op::NumOp,
+        Const,
+    },
+    parser::Parser,
+};
+
+/// Checks that the given JavaScript module gives the expected items.
+#[allow(clippy::unwrap_used)]
+#[track_caller]
+fn check_module<L>(js: &str, items: L)
+where
+    L: Into<Box<[ModuleItem]>>,
+{
+    assert_eq!(
+        Parser::new(js.as_bytes(), false)
+            .parse_module()
+            .expect("failed to parse"),
+        Module::from(items)
+    );
+}
+
+/// Checks that the given JavaScript module creates a parse error.
+#[track_caller]
+fn check_invalid_module(js: &str) {
+    assert!(Parser::new(js.as_bytes(), false).parse_module().is_err());
+}
+
+#[test]
+fn import_declarations() {
+    check_module(
+        r#"
+            import "side-effect";
+            import a from "a";
+            import * as ns from "ns";
+            import { b, c as d, default as e } from "named";
+            import f, { g } from "mixed";
+            import h, * as i from "mixed";
+        "#,
+        vec![
+            ImportDecl::new(None, None, vec![], "side-effect").into(),
+            ImportDecl::new(Some("a".into()), None, vec![], "a").into(),
+            ImportDecl::new(None, Some("ns".into()), vec![], "ns").into(),
+            ImportDecl::new(
+                None,
+                None,
+                vec![
+                    ImportSpecifier::new("b", "b"),
+                    ImportSpecifier::new("c", "d"),
+                    ImportSpecifier::new("default", "e"),
+                ],
+                "named",
+            )
+            .into(),
+            ImportDecl::new(
+                Some("f".into()),
+                None,
+                vec![ImportSpecifier::new("g", "g")],
+                "mixed",
+            )
+            .into(),
+            ImportDecl::new(Some("h".into()), Some("i".into()), vec![], "mixed").into(),
+        ],
+    );
+}
+
+#[test]
+fn export_declarations() {
+    check_module(
+        r#"
+            export var a = 1;
+            export function f() {}
+            export { a as b, f as g };
+            export { default as c, x } from "other";
+            export * from "all";
+            export * as ns from "all";
+        "#,
+        vec![
+            ExportDecl::Declaration(
+                DeclarationList::Var(
+                    vec![Declaration::new("a", Node::from(Const::from(1)))].into(),
+                )
+                .into(),
+            )
+            .into(),
+            ExportDecl::Declaration(FunctionDecl::new(Box::from("f"), vec![], vec![]).into())
+                .into(),
+            ExportDecl::Named {
+                specifiers: vec![
+                    ExportSpecifier::new("a", "b"),
+                    ExportSpecifier::new("f", "g"),
+                ]
+                .into(),
+                from: None,
+            }
+            .into(),
+            ExportDecl::Named {
+                specifiers: vec![
+                    ExportSpecifier::new("default", "c"),
+                    ExportSpecifier::new("x", "x"),
+                ]
+                .into(),
+                from: Some("other".into()),
+            }
+            .into(),
+            ExportDecl::All {
+                alias: None,
+                from: "all".into(),
+            }
+            .into(),
+            ExportDecl::All {
+                alias: Some("ns".into()),
+                from: "all".into(),
+            }
+            .into(),
+        ],
+    );
+}
+
+#[test]
+fn export_default() {
+    check_module(
+        "export default a + 1;",
+        vec![ExportDecl::Default(
+            BinOp::new(NumOp::Add, Identifier::from("a"), Const::from(1)).into(),
+        )
+        .into()],
+    );
+    check_module(
+        "export default function () {} 1;",
+        vec![
+            ExportDecl::Default(FunctionExpr::new(None, vec![], vec![]).into()).into(),
+            ModuleItem::Statement(Const::from(1).into()),
+        ],
+    );
+    check_module(
+        "export default function f() {}",
+        vec![ExportDecl::Default(FunctionDecl::new(Box::from("f"), vec![], vec![]).into()).into()],
+    );
+}
+
+#[test]
+fn early_errors() {
+    check_invalid_module("export var a; export { a };");
+    check_invalid_module("export default 1; export default 2;");
+    check_invalid_module("export { undeclared };");
+    check_invalid_module("import a from 'a'; let a;");
+    check_invalid_module("import { a, b as a } from 'a';");
+    check_invalid_module("let a; var a;");
+    check_invalid_module("export { if };");
+    check_invalid_module("import { if } from 'a';");
+    check_invalid_module("import a from b;");
+    check_invalid_module("with (a) {}");
+}
+
+#[test]
+fn export_of_nested_var() {
+    check_module(
+        "if (true) { var a; } export { a };",
+        vec![
+            ModuleItem::Statement(
+                If::new::<_, _, Node, _>(
+                    Const::from(true),
+                    Block::from(vec![DeclarationList::Var(
+                        vec![Declaration::new("a", None)].into(),
+                    )
+                    .into()]),
+                    None,
+                )
+                .into(),
+            ),
+            ExportDecl::Named {
+                specifiers: vec![ExportSpecifier::new("a", "a")].into(),
+                from: None,
+            }
+            .into(),
+        ],
+    );
+}
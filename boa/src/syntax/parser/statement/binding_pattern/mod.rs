@@ -0,0 +1,417 @@
+//! Binding pattern parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Destructuring_assignment
+//! [spec]: https://tc39.es/ecma262/#prod-BindingPattern
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    syntax::{
+        ast::{
+            node::{
+                self, ArrayPattern, BindingProperty, DeclarationPattern, ObjectPattern,
+                PropertyName,
+            },
+            Punctuator,
+        },
+        lexer::TokenKind,
+        parser::{
+            expression::{AssignmentExpression, Initializer},
+            statement::BindingIdentifier,
+            AllowAwait, AllowYield, Cursor, ParseError, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+use std::io::Read;
+
+/// Binding pattern parsing.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-BindingPattern
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct BindingPattern {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl BindingPattern {
+    /// Creates a new `BindingPattern` parser.
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for BindingPattern
+where
+    R: Read,
+{
+    type Output = DeclarationPattern;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("BindingPattern", "Parsing");
+
+        let next_token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+        match next_token.kind() {
+            TokenKind::Punctuator(Punctuator::OpenBlock) => {
+                ObjectBindingPattern::new(self.allow_yield, self.allow_await)
+                    .parse(cursor)
+                    .map(DeclarationPattern::from)
+            }
+            TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                ArrayBindingPattern::new(self.allow_yield, self.allow_await)
+                    .parse(cursor)
+                    .map(DeclarationPattern::from)
+            }
+            _ => Err(ParseError::expected(
+                vec![
+                    TokenKind::Punctuator(Punctuator::OpenBlock),
+                    TokenKind::Punctuator(Punctuator::OpenBracket),
+                ],
+                next_token,
+                "binding pattern",
+            )),
+        }
+    }
+}
+
+/// Object binding pattern parsing.
+///
+/// The opening `{` has already been consumed.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ObjectBindingPattern
+#[derive(Debug, Clone, Copy)]
+struct ObjectBindingPattern {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ObjectBindingPattern {
+    /// Creates a new `ObjectBindingPattern` parser.
+    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ObjectBindingPattern
+where
+    R: Read,
+{
+    type Output = ObjectPattern;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ObjectBindingPattern", "Parsing");
+
+        let mut properties = Vec::new();
+        let mut rest = None;
+
+        loop {
+            if cursor.next_if(Punctuator::CloseBlock)?.is_some() {
+                break;
+            }
+
+            // BindingRestProperty
+            if cursor.next_if(Punctuator::Spread)?.is_some() {
+                let name =
+                    BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                rest = Some(name.into());
+                cursor.expect(Punctuator::CloseBlock, "object binding pattern")?;
+                break;
+            }
+
+            properties.push(
+                BindingPropertyParser::new(self.allow_yield, self.allow_await).parse(cursor)?,
+            );
+
+            if cursor.next_if(Punctuator::CloseBlock)?.is_some() {
+                break;
+            }
+
+            cursor.expect(Punctuator::Comma, "object binding pattern")?;
+        }
+
+        Ok(ObjectPattern::new(properties, rest))
+    }
+}
+
+/// Binding property parsing.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-BindingProperty
+#[derive(Debug, Clone, Copy)]
+struct BindingPropertyParser {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl BindingPropertyParser {
+    /// Creates a new `BindingPropertyParser` parser.
+    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for BindingPropertyParser
+where
+    R: Read,
+{
+    type Output = BindingProperty;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("BindingProperty", "Parsing");
+
+        let is_single_name =
+            cursor.peek(1)?.map(|t| t.kind()) != Some(&TokenKind::Punctuator(Punctuator::Colon));
+        let token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let key = match token.kind() {
+            TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                let _ = cursor.next()?.expect("[ token disappeared");
+                let name = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
+                    .parse(cursor)?;
+                cursor.expect(Punctuator::CloseBracket, "computed property name")?;
+                PropertyName::Computed(name)
+            }
+            // SingleNameBinding, like `{ a }` or `{ a = 1 }`.
+            TokenKind::Identifier(_) | TokenKind::Keyword(_) if is_single_name => {
+                let name =
+                    BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                let init = if cursor.peek(0)?.map(|t| t.kind())
+                    == Some(&TokenKind::Punctuator(Punctuator::Assign))
+                {
+                    Some(Initializer::new(true, self.allow_yield, self.allow_await).parse(cursor)?)
+                } else {
+                    None
+                };
+
+                let key = PropertyName::from(name.as_ref());
+                return Ok(BindingProperty::new(
+                    key,
+                    node::BindingElement::new(name, init),
+                ));
+            }
+            TokenKind::Identifier(_)
+            | TokenKind::Keyword(_)
+            | TokenKind::BooleanLiteral(_)
+            | TokenKind::NullLiteral
+            | TokenKind::StringLiteral(_)
+            | TokenKind::NumericLiteral(_) => {
+                let token = cursor.next()?.expect("property name token disappeared");
+                PropertyName::Literal(token.to_string().into())
+            }
+            _ => {
+                return Err(ParseError::expected(
+                    vec![
+                        TokenKind::identifier("identifier"),
+                        TokenKind::Punctuator(Punctuator::OpenBracket),
+                    ],
+                    cursor.next()?.expect("peeked token disappeared"),
+                    "binding property",
+                ))
+            }
+        };
+
+        cursor.expect(Punctuator::Colon, "binding property")?;
+        let element = BindingElement::new(self.allow_yield, self.allow_await).parse(cursor)?;
+
+        Ok(BindingProperty::new(key, element))
+    }
+}
+
+/// Array binding pattern parsing.
+///
+/// The opening `[` has already been consumed.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ArrayBindingPattern
+#[derive(Debug, Clone, Copy)]
+struct ArrayBindingPattern {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ArrayBindingPattern {
+    /// Creates a new `ArrayBindingPattern` parser.
+    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ArrayBindingPattern
+where
+    R: Read,
+{
+    type Output = ArrayPattern;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ArrayBindingPattern", "Parsing");
+
+        let mut elements = Vec::new();
+        let mut rest = None;
+
+        loop {
+            // Elision
+            while cursor.next_if(Punctuator::Comma)?.is_some() {
+                elements.push(None);
+            }
+
+            if cursor.next_if(Punctuator::CloseBracket)?.is_some() {
+                break;
+            }
+
+            // BindingRestElement
+            if cursor.next_if(Punctuator::Spread)?.is_some() {
+                rest = Some(BindingTarget::new(self.allow_yield, self.allow_await).parse(cursor)?);
+                cursor.expect(Punctuator::CloseBracket, "array binding pattern")?;
+                break;
+            }
+
+            elements.push(Some(
+                BindingElement::new(self.allow_yield, self.allow_await).parse(cursor)?,
+            ));
+
+            if cursor.next_if(Punctuator::CloseBracket)?.is_some() {
+                break;
+            }
+
+            cursor.expect(Punctuator::Comma, "array binding pattern")?;
+        }
+
+        Ok(ArrayPattern::new(elements, rest))
+    }
+}
+
+/// Binding element parsing.
+///
+/// Parses a binding identifier or a binding pattern, followed by an optional initializer.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-BindingElement
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct BindingElement {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl BindingElement {
+    /// Creates a new `BindingElement` parser.
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for BindingElement
+where
+    R: Read,
+{
+    type Output = node::BindingElement;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("BindingElement", "Parsing");
+
+        let target = BindingTarget::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let init = if cursor.peek(0)?.map(|t| t.kind())
+            == Some(&TokenKind::Punctuator(Punctuator::Assign))
+        {
+            Some(Initializer::new(true, self.allow_yield, self.allow_await).parse(cursor)?)
+        } else {
+            None
+        };
+
+        Ok(node::BindingElement::new(target, init))
+    }
+}
+
+/// Binding target parsing.
+///
+/// Parses either a `BindingIdentifier` or a `BindingPattern`.
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct BindingTarget {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl BindingTarget {
+    /// Creates a new `BindingTarget` parser.
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for BindingTarget
+where
+    R: Read,
+{
+    type Output = node::BindingTarget;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        match cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind() {
+            TokenKind::Punctuator(Punctuator::OpenBlock)
+            | TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                BindingPattern::new(self.allow_yield, self.allow_await)
+                    .parse(cursor)
+                    .map(node::BindingTarget::from)
+            }
+            _ => BindingIdentifier::new(self.allow_yield, self.allow_await)
+                .parse(cursor)
+                .map(node::BindingTarget::from),
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use crate::syntax::{
+    ast::{
+        node::{
+            ArrayPattern, Assign, BindingElement, BindingProperty, BindingTarget, Catch,
+            Declaration, DeclarationList, DeclarationPattern, GetConstField, Identifier, Node,
+            ObjectPattern, PropertyName, Try,
+        },
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+/// Checks object binding pattern parsing in a `const` declaration.
+#[test]
+fn object_pattern() {
+    check_parser(
+        "const { a, b: c = 1, ...d } = obj;",
+        vec![DeclarationList::Const(
+            vec![Declaration::new(
+                DeclarationPattern::from(ObjectPattern::new(
+                    vec![
+                        BindingProperty::new("a", BindingElement::new("a", None)),
+                        BindingProperty::new(
+                            "b",
+                            BindingElement::new("c", Some(Const::from(1).into())),
+                        ),
+                    ],
+                    Some("d".into()),
+                )),
+                Some(Identifier::from("obj").into()),
+            )]
+            .into(),
+        )
+        .into()],
+    );
+}
+
+/// Checks object binding pattern parsing with string and computed keys.
+#[test]
+fn object_pattern_keys() {
+    check_parser(
+        "let { 'a b': a, [key]: b } = obj;",
+        vec![DeclarationList::Let(
+            vec![Declaration::new(
+                DeclarationPattern::from(ObjectPattern::new(
+                    vec![
+                        BindingProperty::new("a b", BindingElement::new("a", None)),
+                        BindingProperty::new(
+                            PropertyName::Computed(Identifier::from("key").into()),
+                            BindingElement::new("b", None),
+                        ),
+                    ],
+                    None,
+                )),
+                Some(Identifier::from("obj").into()),
+            )]
+            .into(),
+        )
+        .into()],
+    );
+}
+
+/// Checks array binding pattern parsing with elisions, nesting and a rest element.
+#[test]
+fn array_pattern() {
+    check_parser(
+        "var [a, , [b] = arr, ...{ length }] = obj;",
+        vec![DeclarationList::Var(
+            vec![Declaration::new(
+                DeclarationPattern::from(ArrayPattern::new(
+                    vec![
+                        Some(BindingElement::new("a", None)),
+                        None,
+                        Some(BindingElement::new(
+                            DeclarationPattern::from(ArrayPattern::new(
+                                vec![Some(BindingElement::new("b", None))],
+                                None,
+                            )),
+                            Some(Identifier::from("arr").into()),
+                        )),
+                    ],
+                    Some(
+                        DeclarationPattern::from(ObjectPattern::new(
+                            vec![BindingProperty::new(
+                                "length",
+                                BindingElement::new("length", None),
+                            )],
+                            None,
+                        ))
+                        .into(),
+                    ),
+                )),
+                Some(Identifier::from("obj").into()),
+            )]
+            .into(),
+        )
+        .into()],
+    );
+}
+
+/// Checks binding pattern parsing in a `catch` parameter.
+#[test]
+fn catch_pattern() {
+    check_parser(
+        "try {} catch ({ message }) {}",
+        vec![Try::new(
+            vec![],
+            Some(Catch::new::<_, BindingTarget, _>(
+                Some(
+                    DeclarationPattern::from(ObjectPattern::new(
+                        vec![BindingProperty::new(
+                            "message",
+                            BindingElement::new("message", None),
+                        )],
+                        None,
+                    ))
+                    .into(),
+                ),
+                vec![],
+            )),
+            None,
+        )
+        .into()],
+    );
+}
+
+/// Checks that array literals on the left hand side of an assignment become patterns.
+#[test]
+fn assignment_pattern() {
+    check_parser(
+        "[a, b.c] = arr;",
+        vec![Assign::new(
+            DeclarationPattern::from(ArrayPattern::new(
+                vec![
+                    Some(BindingElement::new("a", None)),
+                    Some(BindingElement::new(
+                        BindingTarget::Property(Box::new(Node::from(GetConstField::new(
+                            Identifier::from("b"),
+                            "c",
+                        )))),
+                        None,
+                    )),
+                ],
+                None,
+            )),
+            Identifier::from("arr"),
+        )
+        .into()],
+    );
+}
+
+/// Checks invalid binding patterns.
+#[test]
+fn invalid_patterns() {
+    check_invalid("let { a: 1 } = obj;");
+    check_invalid("let [...a, b] = arr;");
+    check_invalid("let { ...a, b } = obj;");
+    check_invalid("[a + 1] = arr;");
+    check_invalid("({ a: 1 }) => a;");
+}
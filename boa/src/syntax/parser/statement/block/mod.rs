@@ -87,6 +87,7 @@ where
             self.allow_return,
             true,
             &BLOCK_BREAK_TOKENS,
+            false,
         )
         .parse(cursor)
         .map(node::Block::from)?;
@@ -1,6 +1,6 @@
 use crate::syntax::{
     ast::{
-        node::{Block, Continue, WhileLoop},
+        node::{Block, Continue, Node, WhileLoop},
         Const,
     },
     parser::tests::check_parser,
@@ -38,14 +38,13 @@ fn inline_block_semicolon_insertion() {
 #[test]
 fn new_line_semicolon_insertion() {
     check_parser(
-        "while (true) {
+        "test: while (true) {
             continue test
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
+        vec![labelled_loop(
+            "test",
             Block::from(vec![Continue::new("test").into()]),
-        )
-        .into()],
+        )],
     );
 }
 
@@ -64,39 +63,36 @@ fn inline_block() {
 #[test]
 fn new_line_block() {
     check_parser(
-        "while (true) {
+        "test: while (true) {
             continue test;
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
+        vec![labelled_loop(
+            "test",
             Block::from(vec![Continue::new("test").into()]),
-        )
-        .into()],
+        )],
     );
 }
 
 #[test]
 fn reserved_label() {
     check_parser(
-        "while (true) {
+        "await: while (true) {
             continue await;
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
+        vec![labelled_loop(
+            "await",
             Block::from(vec![Continue::new("await").into()]),
-        )
-        .into()],
+        )],
     );
 
     check_parser(
-        "while (true) {
+        "yield: while (true) {
             continue yield;
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
+        vec![labelled_loop(
+            "yield",
             Block::from(vec![Continue::new("yield").into()]),
-        )
-        .into()],
+        )],
     );
 }
 
@@ -127,3 +123,10 @@ fn new_line_block_empty_semicolon_insertion() {
         .into()],
     );
 }
+
+/// Creates a `while (true)` loop with the given label.
+fn labelled_loop(label: &str, body: Block) -> Node {
+    let mut while_loop = WhileLoop::new(Const::from(true), body);
+    while_loop.add_label(label.into());
+    while_loop.into()
+}
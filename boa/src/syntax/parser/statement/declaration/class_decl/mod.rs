@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    syntax::{
+        ast::{Keyword, Node},
+        parser::{
+            class::ClassTail, statement::BindingIdentifier, AllowAwait, AllowYield, Cursor,
+            ParseError, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+use std::io::Read;
+
+/// Class declaration parsing.
+///
+/// The anonymous class of an `export default` declaration is parsed as an expression, so the
+/// declaration always has a name.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/class
+/// [spec]: https://tc39.es/ecma262/#prod-ClassDeclaration
+#[derive(Debug, Clone, Copy)]
+pub(super) struct ClassDeclaration {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ClassDeclaration {
+    /// Creates a new `ClassDeclaration` parser.
+    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ClassDeclaration
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ClassDeclaration", "Parsing");
+        cursor.expect(Keyword::Class, "class declaration")?;
+
+        let name = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+
+        let class = ClassTail::new(name, self.allow_yield, self.allow_await).parse(cursor)?;
+
+        Ok(Node::ClassDecl(class))
+    }
+}
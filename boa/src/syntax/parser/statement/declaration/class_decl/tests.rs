@@ -0,0 +1,38 @@
+use crate::syntax::{
+    ast::node::{Class, FunctionExpr, Identifier, Node},
+    parser::tests::{check_invalid, check_parser},
+};
+
+/// Class declaration parsing.
+#[test]
+fn class_declaration() {
+    check_parser(
+        "class Foo {}",
+        vec![Node::ClassDecl(Class::new(
+            Some("Foo".into()),
+            None,
+            None,
+            vec![],
+        ))],
+    );
+}
+
+/// Class declaration parsing with a superclass.
+#[test]
+fn class_declaration_extends() {
+    check_parser(
+        "class Foo extends Bar { constructor() {} }",
+        vec![Node::ClassDecl(Class::new(
+            Some("Foo".into()),
+            Node::from(Identifier::from("Bar")),
+            FunctionExpr::new(None, vec![], vec![]),
+            vec![],
+        ))],
+    );
+}
+
+/// Class declarations need a name.
+#[test]
+fn class_declaration_without_name() {
+    check_invalid("class {}");
+}
@@ -5,9 +5,8 @@ use crate::syntax::{
     ast::{node::AsyncFunctionDecl, Keyword, Punctuator},
     lexer::TokenKind,
     parser::{
-        function::FormalParameters,
-        function::FunctionBody,
-        statement::{BindingIdentifier, LexError, Position},
+        function::{check_function_early_errors, FormalParameters, FunctionBody},
+        statement::BindingIdentifier,
         AllowAwait, AllowDefault, AllowYield, Cursor, ParseError, TokenParser,
     },
 };
@@ -86,23 +85,7 @@ where
 
         cursor.expect(Punctuator::CloseBlock, "async function declaration")?;
 
-        // It is a Syntax Error if any element of the BoundNames of FormalParameters
-        // also occurs in the LexicallyDeclaredNames of FunctionBody.
-        // https://tc39.es/ecma262/#sec-function-definitions-static-semantics-early-errors
-        {
-            let lexically_declared_names = body.lexically_declared_names();
-            for param in params.as_ref() {
-                if lexically_declared_names.contains(param.name()) {
-                    return Err(ParseError::lex(LexError::Syntax(
-                        format!("Redeclaration of formal parameter `{}`", param.name()).into(),
-                        match cursor.peek(0)? {
-                            Some(token) => token.span().end(),
-                            None => Position::new(1, 1),
-                        },
-                    )));
-                }
-            }
-        }
+        check_function_early_errors(cursor, name.as_deref(), &params, &body, false)?;
 
         Ok(AsyncFunctionDecl::new(name, params, body))
     }
@@ -0,0 +1,39 @@
+use crate::syntax::{
+    ast::node::{GeneratorDecl, Identifier, YieldExpr},
+    ast::Const,
+    parser::tests::{check_invalid, check_parser},
+};
+
+/// Generator declaration parsing.
+#[test]
+fn generator_declaration() {
+    check_parser(
+        "function* gen() { yield 1; }",
+        vec![GeneratorDecl::new(
+            Box::from("gen"),
+            vec![],
+            vec![YieldExpr::new(Const::from(1), false).into()],
+        )
+        .into()],
+    );
+}
+
+/// Generator declaration parsing with a delegating yield.
+#[test]
+fn generator_declaration_delegate() {
+    check_parser(
+        "function *gen() { yield* other; }",
+        vec![GeneratorDecl::new(
+            Box::from("gen"),
+            vec![],
+            vec![YieldExpr::new(Identifier::from("other"), true).into()],
+        )
+        .into()],
+    );
+}
+
+/// Yield is not allowed as the name of a parameter of a generator.
+#[test]
+fn generator_declaration_yield_param() {
+    check_invalid("function* gen(yield) {}");
+}
@@ -10,14 +10,16 @@ mod tests;
 
 mod async_function_decl;
 mod function_decl;
+mod generator_decl;
 
 use async_function_decl::AsyncFunctionDeclaration;
 use function_decl::FunctionDeclaration;
+use generator_decl::GeneratorDeclaration;
 
 use crate::{
     syntax::{
-        ast::{Keyword, Node},
-        lexer::TokenKind,
+        ast::{Keyword, Node, Punctuator},
+        lexer::{Token, TokenKind},
         parser::{
             AllowAwait, AllowDefault, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
         },
@@ -63,9 +65,18 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
         let _timer = BoaProfiler::global().start_event("HoistableDeclaration", "Parsing");
+        let is_generator = matches!(
+            cursor.peek(1)?.map(Token::kind),
+            Some(TokenKind::Punctuator(Punctuator::Mul))
+        );
         let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
 
         match tok.kind() {
+            TokenKind::Keyword(Keyword::Function) if is_generator => {
+                GeneratorDeclaration::new(self.allow_yield, self.allow_await)
+                    .parse(cursor)
+                    .map(Node::from)
+            }
             TokenKind::Keyword(Keyword::Function) => {
                 FunctionDeclaration::new(self.allow_yield, self.allow_await, self.is_default)
                     .parse(cursor)
@@ -12,6 +12,7 @@ use crate::{
     syntax::{
         ast::{
             node::{
+                self,
                 declaration::{Declaration, DeclarationList},
                 Node,
             },
@@ -20,7 +21,7 @@ use crate::{
         parser::{
             cursor::{Cursor, SemicolonResult},
             expression::Initializer,
-            statement::BindingIdentifier,
+            statement::BindingTarget,
             AllowAwait, AllowIn, AllowYield, ParseError, ParseResult, TokenParser,
         },
     },
@@ -154,14 +155,18 @@ where
         let mut const_decls = Vec::new();
 
         loop {
-            let (ident, init) =
+            let start = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
+            let (target, init) =
                 LexicalBinding::new(self.allow_in, self.allow_yield, self.allow_await)
                     .parse(cursor)?;
+            let has_init = init.is_some();
+            let mut decl = Declaration::new(target, init);
+            decl.set_span(cursor.span_from(start));
 
             if self.is_const {
                 if self.const_init_required {
-                    if init.is_some() {
-                        const_decls.push(Declaration::new(ident, init));
+                    if has_init {
+                        const_decls.push(decl);
                     } else {
                         return Err(ParseError::expected(
                             vec![TokenKind::Punctuator(Punctuator::Assign)],
@@ -170,10 +175,10 @@ where
                         ));
                     }
                 } else {
-                    const_decls.push(Declaration::new(ident, init))
+                    const_decls.push(decl)
                 }
             } else {
-                let_decls.push(Declaration::new(ident, init));
+                let_decls.push(decl);
             }
 
             match cursor.peek_semicolon()? {
@@ -243,12 +248,12 @@ impl<R> TokenParser<R> for LexicalBinding
 where
     R: Read,
 {
-    type Output = (Box<str>, Option<Node>);
+    type Output = (node::BindingTarget, Option<Node>);
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("LexicalBinding", "Parsing");
 
-        let ident = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let target = BindingTarget::new(self.allow_yield, self.allow_await).parse(cursor)?;
 
         let init = if let Some(t) = cursor.peek(0)? {
             if *t.kind() == TokenKind::Punctuator(Punctuator::Assign) {
@@ -263,6 +268,6 @@ where
             None
         };
 
-        Ok((ident, init))
+        Ok((target, init))
     }
 }
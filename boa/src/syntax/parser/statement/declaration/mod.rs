@@ -7,12 +7,15 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements#Declarations
 //! [spec]:https://tc39.es/ecma262/#sec-declarations-and-the-variable-statement
 
+mod class_decl;
 mod hoistable;
 mod lexical;
 #[cfg(test)]
 mod tests;
 
-use self::{hoistable::HoistableDeclaration, lexical::LexicalDeclaration};
+use self::{
+    class_decl::ClassDeclaration, hoistable::HoistableDeclaration, lexical::LexicalDeclaration,
+};
 
 use crate::syntax::lexer::TokenKind;
 use crate::{
@@ -32,14 +35,18 @@ use std::io::Read;
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-Declaration
 #[derive(Debug, Clone, Copy)]
-pub(super) struct Declaration {
+pub(in crate::syntax::parser) struct Declaration {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     const_init_required: bool,
 }
 
 impl Declaration {
-    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A, const_init_required: bool) -> Self
+    pub(in crate::syntax::parser) fn new<Y, A>(
+        allow_yield: Y,
+        allow_await: A,
+        const_init_required: bool,
+    ) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -61,11 +68,15 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("Declaration", "Parsing");
         let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let start = tok.span().start();
 
-        match tok.kind() {
+        let node = match tok.kind() {
             TokenKind::Keyword(Keyword::Function) | TokenKind::Keyword(Keyword::Async) => {
                 HoistableDeclaration::new(self.allow_yield, self.allow_await, false).parse(cursor)
             }
+            TokenKind::Keyword(Keyword::Class) => {
+                ClassDeclaration::new(self.allow_yield, self.allow_await).parse(cursor)
+            }
             TokenKind::Keyword(Keyword::Const) | TokenKind::Keyword(Keyword::Let) => {
                 LexicalDeclaration::new(
                     true,
@@ -76,6 +87,8 @@ where
                 .parse(cursor)
             }
             _ => unreachable!("unknown token found: {:?}", tok),
-        }
+        }?;
+
+        Ok(cursor.spanned(node, start))
     }
 }
@@ -15,7 +15,7 @@ use crate::{
             Const, Keyword, Punctuator,
         },
         parser::{
-            expression::Expression,
+            expression::{check_strict_target, to_assignment_pattern, Expression},
             statement::declaration::Declaration,
             statement::{variable::VariableDeclarationList, Statement},
             AllowAwait, AllowReturn, AllowYield, Cursor, ParseError, TokenParser,
@@ -88,6 +88,31 @@ where
             _ => Some(Expression::new(false, self.allow_yield, self.allow_await).parse(cursor)?),
         };
 
+        let init = match (init, cursor.peek(0)?) {
+            // Destructuring assignment in the head of a `for...in` or `for...of` loop.
+            (Some(node @ Node::Object(_)), Some(tok))
+            | (Some(node @ Node::ArrayDecl(_)), Some(tok))
+                if tok.kind() == &TokenKind::Keyword(Keyword::In)
+                    || tok.kind() == &TokenKind::Keyword(Keyword::Of) =>
+            {
+                match to_assignment_pattern(&node) {
+                    Some(pattern) => {
+                        let position = tok.span().start();
+                        let pattern = Node::from(pattern);
+                        check_strict_target(cursor, &pattern, position)?;
+                        Some(pattern)
+                    }
+                    None => {
+                        return Err(ParseError::general(
+                            "invalid destructuring target in for loop head",
+                            tok.span().start(),
+                        ))
+                    }
+                }
+            }
+            (init, _) => init,
+        };
+
         match cursor.peek(0)? {
             Some(tok) if tok.kind() == &TokenKind::Keyword(Keyword::In) && init.is_some() => {
                 let _ = cursor.next();
@@ -2,15 +2,20 @@ use std::io::Read;
 
 use super::{LabelIdentifier, Statement};
 use crate::{
-    syntax::ast::Node,
+    syntax::ast::{node::Labelled, Node},
     syntax::{
-        ast::Punctuator,
+        ast::{Position, Punctuator, Span},
+        lexer::Error as LexError,
         parser::{
             cursor::Cursor, error::ParseError, AllowAwait, AllowReturn, AllowYield, TokenParser,
         },
     },
     BoaProfiler,
 };
+
+#[cfg(test)]
+mod tests;
+
 /// Labelled Statement Parsing
 ///
 /// More information
@@ -51,21 +56,141 @@ where
         let _timer = BoaProfiler::global().start_event("Label", "Parsing");
         let name = LabelIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
         cursor.expect(Punctuator::Colon, "Labelled Statement")?;
-        let mut stmt =
+        let stmt =
             Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
 
-        set_label_for_node(&mut stmt, name);
-        Ok(stmt)
+        Ok(label_node(stmt, name))
     }
 }
 
-fn set_label_for_node(stmt: &mut Node, name: Box<str>) {
+/// Adds the label to the statement, which is kept by loops and wrapped around other statements.
+fn label_node(mut stmt: Node, name: Box<str>) -> Node {
     match stmt {
-        Node::ForLoop(ref mut for_loop) => for_loop.set_label(name),
-        Node::ForOfLoop(ref mut for_of_loop) => for_of_loop.set_label(name),
-        Node::ForInLoop(ref mut for_in_loop) => for_in_loop.set_label(name),
-        Node::DoWhileLoop(ref mut do_while_loop) => do_while_loop.set_label(name),
-        Node::WhileLoop(ref mut while_loop) => while_loop.set_label(name),
-        _ => (),
+        Node::ForLoop(ref mut for_loop) => for_loop.add_label(name),
+        Node::ForOfLoop(ref mut for_of_loop) => for_of_loop.add_label(name),
+        Node::ForInLoop(ref mut for_in_loop) => for_in_loop.add_label(name),
+        Node::DoWhileLoop(ref mut do_while_loop) => do_while_loop.add_label(name),
+        Node::WhileLoop(ref mut while_loop) => while_loop.add_label(name),
+        _ => return Labelled::new(name, stmt).into(),
+    }
+    stmt
+}
+
+/// Checks the early errors of the labels used in the given statements: every `break` and
+/// `continue` must refer to the label of a statement containing it, a `continue` can only refer
+/// to the label of a loop, and nested statements cannot have the same label.
+///
+/// Functions are checked on their own, since labels do not cross function boundaries.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-labelled-statements-static-semantics-early-errors
+pub(in crate::syntax::parser) fn check_labels(items: &[Node]) -> Result<(), ParseError> {
+    let mut labels = Vec::new();
+    items
+        .iter()
+        .try_for_each(|item| check_node_labels(item, &mut labels))
+}
+
+/// Checks the labels used in a statement, given the labels of the statements containing it and
+/// whether each of them is the label of a loop.
+fn check_node_labels<'a>(
+    node: &'a Node,
+    labels: &mut Vec<(&'a str, bool)>,
+) -> Result<(), ParseError> {
+    let error = |message: String, node: &Node| {
+        let position = node.span().map_or_else(|| Position::new(1, 1), Span::start);
+        Err(ParseError::lex(LexError::Syntax(message.into(), position)))
+    };
+
+    let (own_labels, body): (&[Box<str>], &Node) = match node {
+        Node::Labelled(labelled) => {
+            let label = labelled.label();
+            if labels.iter().any(|&(name, _)| name == label) {
+                return error(format!("Duplicate label `{}`", label), node);
+            }
+            labels.push((label, false));
+            let result = check_node_labels(labelled.item(), labels);
+            labels.pop();
+            return result;
+        }
+        Node::Break(break_node) => {
+            return match break_node.label() {
+                Some(label) if !labels.iter().any(|&(name, _)| name == label) => {
+                    error(format!("Undefined label `{}`", label), node)
+                }
+                _ => Ok(()),
+            };
+        }
+        Node::Continue(continue_node) => {
+            return match continue_node.label() {
+                Some(label) => match labels.iter().rev().find(|&&(name, _)| name == label) {
+                    None => error(format!("Undefined label `{}`", label), node),
+                    Some(&(_, false)) => {
+                        error(format!("Label `{}` does not refer to a loop", label), node)
+                    }
+                    Some(&(_, true)) => Ok(()),
+                },
+                None => Ok(()),
+            };
+        }
+        Node::ForLoop(for_loop) => (for_loop.labels(), for_loop.body()),
+        Node::ForInLoop(for_in_loop) => (for_in_loop.labels(), for_in_loop.body()),
+        Node::ForOfLoop(for_of_loop) => (for_of_loop.labels(), for_of_loop.body()),
+        Node::WhileLoop(while_loop) => (while_loop.labels(), while_loop.expr()),
+        Node::DoWhileLoop(do_while_loop) => (do_while_loop.labels(), do_while_loop.body()),
+        Node::Block(block) => {
+            return block
+                .items()
+                .iter()
+                .try_for_each(|item| check_node_labels(item, labels));
+        }
+        Node::If(if_node) => {
+            check_node_labels(if_node.body(), labels)?;
+            return if_node
+                .else_node()
+                .map_or(Ok(()), |else_node| check_node_labels(else_node, labels));
+        }
+        Node::With(with) => return check_node_labels(with.body(), labels),
+        Node::Switch(switch) => {
+            return switch
+                .cases()
+                .iter()
+                .flat_map(|case| case.body().items())
+                .chain(switch.default().into_iter().flatten())
+                .try_for_each(|item| check_node_labels(item, labels));
+        }
+        Node::Try(try_node) => {
+            return try_node
+                .block()
+                .items()
+                .iter()
+                .chain(
+                    try_node
+                        .catch()
+                        .into_iter()
+                        .flat_map(|catch| catch.block().items()),
+                )
+                .chain(
+                    try_node
+                        .finally()
+                        .into_iter()
+                        .flat_map(|finally| finally.items()),
+                )
+                .try_for_each(|item| check_node_labels(item, labels));
+        }
+        _ => return Ok(()),
+    };
+
+    let depth = labels.len();
+    for label in own_labels {
+        if labels.iter().any(|&(name, _)| name == label.as_ref()) {
+            return error(format!("Duplicate label `{}`", label), node);
+        }
+        labels.push((label, true));
     }
+    let result = check_node_labels(body, labels);
+    labels.truncate(depth);
+    result
 }
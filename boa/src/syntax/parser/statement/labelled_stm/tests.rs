@@ -0,0 +1,57 @@
+use crate::syntax::{
+    ast::{
+        node::{Block, Break, Continue, Labelled, WhileLoop},
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+#[test]
+fn labelled_block() {
+    check_parser(
+        "block: { break block; }",
+        vec![Labelled::new("block", Block::from(vec![Break::new("block").into()])).into()],
+    );
+}
+
+#[test]
+fn labelled_loop() {
+    let mut while_loop = WhileLoop::new(
+        Const::from(true),
+        Block::from(vec![Continue::new("outer").into()]),
+    );
+    while_loop.add_label("inner".into());
+    while_loop.add_label("outer".into());
+
+    check_parser(
+        "outer: inner: while (true) { continue outer; }",
+        vec![while_loop.into()],
+    );
+}
+
+#[test]
+fn undefined_label() {
+    check_invalid("while (true) { break outer; }");
+    check_invalid("while (true) { continue outer; }");
+    check_invalid("outer: while (true) { function f() { break outer; } }");
+    check_invalid("first: { } second: { break first; }");
+}
+
+#[test]
+fn continue_not_loop_label() {
+    check_invalid("outer: { while (true) { continue outer; } }");
+    check_invalid("outer: if (true) { while (true) { continue outer; } }");
+}
+
+#[test]
+fn duplicate_label() {
+    check_invalid("label: label: while (true) { }");
+    check_invalid("label: { label: { } }");
+    check_parser(
+        "label: { } label: { }",
+        vec![
+            Labelled::new("label", Block::from(vec![])).into(),
+            Labelled::new("label", Block::from(vec![])).into(),
+        ],
+    );
+}
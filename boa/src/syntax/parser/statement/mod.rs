@@ -7,6 +7,7 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements
 //! [spec]: https://tc39.es/ecma262/#sec-ecmascript-language-statements-and-declarations
 
+mod binding_pattern;
 mod block;
 mod break_stm;
 mod continue_stm;
@@ -20,12 +21,12 @@ mod switch;
 mod throw;
 mod try_stm;
 mod variable;
+mod with;
 
 use self::{
     block::BlockStatement,
     break_stm::BreakStatement,
     continue_stm::ContinueStatement,
-    declaration::Declaration,
     expression::ExpressionStatement,
     if_stm::IfStatement,
     iteration::{DoWhileStatement, ForStatement, WhileStatement},
@@ -33,21 +34,30 @@ use self::{
     switch::SwitchStatement,
     throw::ThrowStatement,
     try_stm::TryStatement,
-    variable::VariableStatement,
+    with::WithStatement,
 };
 
 use super::{AllowAwait, AllowReturn, AllowYield, Cursor, ParseError, TokenParser};
 
 use crate::{
     syntax::{
-        ast::{node, Keyword, Node, Punctuator},
-        lexer::{Error as LexError, InputElement, Position, TokenKind},
-        parser::expression::await_expr::AwaitExpression,
+        ast::{node, Const, Keyword, Node, Punctuator},
+        lexer::{
+            Error as LexError, InputElement, Position, Token, TokenKind,
+            STRICT_FORBIDDEN_IDENTIFIERS,
+        },
     },
     BoaProfiler,
 };
 use labelled_stm::LabelledStatement;
 
+pub(super) use self::{
+    binding_pattern::{BindingElement, BindingTarget},
+    declaration::Declaration,
+    labelled_stm::check_labels,
+    variable::VariableStatement,
+};
+
 use std::collections::HashSet;
 use std::io::Read;
 
@@ -110,11 +120,9 @@ where
         let _timer = BoaProfiler::global().start_event("Statement", "Parsing");
         // TODO: add BreakableStatement and divide Whiles, fors and so on to another place.
         let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let start = tok.span().start();
 
-        match tok.kind() {
-            TokenKind::Keyword(Keyword::Await) => AwaitExpression::new(self.allow_yield)
-                .parse(cursor)
-                .map(Node::from),
+        let node = match tok.kind() {
             TokenKind::Keyword(Keyword::If) => {
                 IfStatement::new(self.allow_yield, self.allow_await, self.allow_return)
                     .parse(cursor)
@@ -174,6 +182,11 @@ where
                     .parse(cursor)
                     .map(Node::from)
             }
+            TokenKind::Keyword(Keyword::With) => {
+                WithStatement::new(self.allow_yield, self.allow_await, self.allow_return)
+                    .parse(cursor)
+                    .map(Node::from)
+            }
             TokenKind::Punctuator(Punctuator::OpenBlock) => {
                 BlockStatement::new(self.allow_yield, self.allow_await, self.allow_return)
                     .parse(cursor)
@@ -184,7 +197,9 @@ where
                 cursor.next().expect("semicolon disappeared");
                 Ok(Node::Empty)
             }
-            TokenKind::Identifier(_) => {
+            TokenKind::Identifier(_)
+            | TokenKind::Keyword(Keyword::Yield)
+            | TokenKind::Keyword(Keyword::Await) => {
                 // Labelled Statement check
                 cursor.set_goal(InputElement::Div);
                 let tok = cursor.peek(1)?;
@@ -194,20 +209,18 @@ where
                         TokenKind::Punctuator(Punctuator::Colon)
                     )
                 {
-                    return LabelledStatement::new(
-                        self.allow_yield,
-                        self.allow_await,
-                        self.allow_return,
-                    )
-                    .parse(cursor)
-                    .map(Node::from);
+                    LabelledStatement::new(self.allow_yield, self.allow_await, self.allow_return)
+                        .parse(cursor)
+                        .map(Node::from)
+                } else {
+                    ExpressionStatement::new(self.allow_yield, self.allow_await).parse(cursor)
                 }
-
-                ExpressionStatement::new(self.allow_yield, self.allow_await).parse(cursor)
             }
 
             _ => ExpressionStatement::new(self.allow_yield, self.allow_await).parse(cursor),
-        }
+        }?;
+
+        Ok(cursor.spanned(node, start))
     }
 }
 
@@ -224,16 +237,21 @@ pub(super) struct StatementList {
     allow_return: AllowReturn,
     in_block: bool,
     break_nodes: &'static [TokenKind],
+    directive_prologues: bool,
 }
 
 impl StatementList {
     /// Creates a new `StatementList` parser.
+    ///
+    /// The statements of scripts and function bodies start with `directive_prologues`, which
+    /// can make them strict mode code.
     pub(super) fn new<Y, A, R>(
         allow_yield: Y,
         allow_await: A,
         allow_return: R,
         in_block: bool,
         break_nodes: &'static [TokenKind],
+        directive_prologues: bool,
     ) -> Self
     where
         Y: Into<AllowYield>,
@@ -246,6 +264,7 @@ impl StatementList {
             allow_return: allow_return.into(),
             in_block,
             break_nodes,
+            directive_prologues,
         }
     }
 }
@@ -269,13 +288,14 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("StatementList", "Parsing");
         let mut items = Vec::new();
+        let mut directive_prologues = self.directive_prologues;
 
         loop {
-            match cursor.peek(0)? {
+            let use_strict = match cursor.peek(0)? {
                 Some(token) if self.break_nodes.contains(token.kind()) => break,
                 None => break,
-                _ => {}
-            }
+                Some(token) => directive_prologues && is_use_strict_directive(token),
+            };
 
             let item = StatementListItem::new(
                 self.allow_yield,
@@ -284,6 +304,19 @@ where
                 self.in_block,
             )
             .parse(cursor)?;
+
+            // The directive prologue is the sequence of string literal statements at the start
+            // of the list, a `"use strict"` directive makes the code after it strict mode code.
+            // https://tc39.es/ecma262/#sec-directive-prologues-and-the-use-strict-directive
+            if directive_prologues {
+                if let Node::Const(Const::String(_)) = item {
+                    if use_strict {
+                        cursor.set_strict_mode(true);
+                    }
+                } else {
+                    directive_prologues = false;
+                }
+            }
             items.push(item);
 
             // move the cursor forward for any consecutive semicolon.
@@ -296,40 +329,37 @@ where
             let mut lexically_declared_names: HashSet<&str> = HashSet::new();
             let mut var_declared_names: HashSet<&str> = HashSet::new();
 
-            // TODO: Use more helpful positions in errors when spans are added to Nodes
             for item in &items {
                 match item {
                     Node::LetDeclList(decl_list) | Node::ConstDeclList(decl_list) => {
                         for decl in decl_list.as_ref() {
-                            // if name in VarDeclaredNames or can't be added to
-                            // LexicallyDeclaredNames, raise an error
-                            if var_declared_names.contains(decl.name())
-                                || !lexically_declared_names.insert(decl.name())
-                            {
-                                return Err(ParseError::lex(LexError::Syntax(
-                                    format!("Redeclaration of variable `{}`", decl.name()).into(),
-                                    match cursor.peek(0)? {
-                                        Some(token) => token.span().end(),
-                                        None => Position::new(1, 1),
-                                    },
-                                )));
+                            for name in decl.names() {
+                                // if name in VarDeclaredNames or can't be added to
+                                // LexicallyDeclaredNames, raise an error
+                                if var_declared_names.contains(name)
+                                    || !lexically_declared_names.insert(name)
+                                {
+                                    return Err(ParseError::lex(LexError::Syntax(
+                                        format!("Redeclaration of variable `{}`", name).into(),
+                                        redeclaration_position(cursor, decl)?,
+                                    )));
+                                }
                             }
                         }
                     }
                     Node::VarDeclList(decl_list) => {
                         for decl in decl_list.as_ref() {
-                            // if name in LexicallyDeclaredNames, raise an error
-                            if lexically_declared_names.contains(decl.name()) {
-                                return Err(ParseError::lex(LexError::Syntax(
-                                    format!("Redeclaration of variable `{}`", decl.name()).into(),
-                                    match cursor.peek(0)? {
-                                        Some(token) => token.span().end(),
-                                        None => Position::new(1, 1),
-                                    },
-                                )));
+                            for name in decl.names() {
+                                // if name in LexicallyDeclaredNames, raise an error
+                                if lexically_declared_names.contains(name) {
+                                    return Err(ParseError::lex(LexError::Syntax(
+                                        format!("Redeclaration of variable `{}`", name).into(),
+                                        redeclaration_position(cursor, decl)?,
+                                    )));
+                                }
+                                // otherwise, add to VarDeclaredNames
+                                var_declared_names.insert(name);
                             }
-                            // otherwise, add to VarDeclaredNames
-                            var_declared_names.insert(decl.name());
                         }
                     }
                     _ => (),
@@ -339,8 +369,43 @@ where
 
         items.sort_by(Node::hoistable_order);
 
-        Ok(items.into())
+        let mut statement_list = node::StatementList::from(items);
+        if self.directive_prologues {
+            statement_list.set_strict(cursor.strict_mode());
+        }
+        Ok(statement_list)
+    }
+}
+
+/// Checks if the token is a `"use strict"` directive, which must be written without escape
+/// sequences or line continuations.
+fn is_use_strict_directive(token: &Token) -> bool {
+    match token.kind() {
+        TokenKind::StringLiteral(string) if string.as_ref() == "use strict" => {
+            let (start, end) = (token.span().start(), token.span().end());
+            start.line_number() == end.line_number()
+                && end.column_number() - start.column_number() == "'use strict'".len() as u32
+        }
+        _ => false,
+    }
+}
+
+/// Gets the position reported by a redeclaration error: the start of the redeclaring
+/// declaration, or the end of the next token if the declaration has no span.
+fn redeclaration_position<R>(
+    cursor: &mut Cursor<R>,
+    decl: &node::Declaration,
+) -> Result<Position, ParseError>
+where
+    R: Read,
+{
+    if let Some(span) = decl.span() {
+        return Ok(span.start());
     }
+    Ok(match cursor.peek(0)? {
+        Some(token) => token.span().end(),
+        None => Position::new(1, 1),
+    })
 }
 
 /// Statement list item parsing
@@ -354,7 +419,7 @@ where
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements
 /// [spec]: https://tc39.es/ecma262/#prod-StatementListItem
 #[derive(Debug, Clone, Copy)]
-struct StatementListItem {
+pub(in crate::syntax::parser) struct StatementListItem {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     allow_return: AllowReturn,
@@ -363,7 +428,12 @@ struct StatementListItem {
 
 impl StatementListItem {
     /// Creates a new `StatementListItem` parser.
-    fn new<Y, A, R>(allow_yield: Y, allow_await: A, allow_return: R, in_block: bool) -> Self
+    pub(in crate::syntax::parser) fn new<Y, A, R>(
+        allow_yield: Y,
+        allow_await: A,
+        allow_return: R,
+        in_block: bool,
+    ) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -389,7 +459,16 @@ where
         let strict_mode = cursor.strict_mode();
         let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
 
+        // `async` only starts a declaration when it is followed by `function`, otherwise it
+        // starts an async arrow function.
+        let is_async_function = *tok.kind() == TokenKind::Keyword(Keyword::Async)
+            && cursor.peek(1)?.map(|t| t.kind()) == Some(&TokenKind::Keyword(Keyword::Function));
+        let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+
         match *tok.kind() {
+            TokenKind::Keyword(Keyword::Async) if !is_async_function => {
+                Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)
+            }
             TokenKind::Keyword(Keyword::Function) | TokenKind::Keyword(Keyword::Async) => {
                 if strict_mode && self.in_block {
                     return Err(ParseError::lex(LexError::Syntax(
@@ -399,7 +478,9 @@ where
                 }
                 Declaration::new(self.allow_yield, self.allow_await, true).parse(cursor)
             }
-            TokenKind::Keyword(Keyword::Const) | TokenKind::Keyword(Keyword::Let) => {
+            TokenKind::Keyword(Keyword::Const)
+            | TokenKind::Keyword(Keyword::Let)
+            | TokenKind::Keyword(Keyword::Class) => {
                 Declaration::new(self.allow_yield, self.allow_await, true).parse(cursor)
             }
             _ => {
@@ -409,6 +490,14 @@ where
     }
 }
 
+/// Identifiers that cannot be bound or assigned in strict mode code.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-identifiers-static-semantics-early-errors
+pub(super) const STRICT_RESTRICTED_NAMES: [&str; 2] = ["eval", "arguments"];
+
 /// Label identifier parsing.
 ///
 /// This seems to be the same as a `BindingIdentifier`.
@@ -458,7 +547,25 @@ where
         let next_token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
 
         match next_token.kind() {
-            TokenKind::Identifier(ref s) => Ok(s.clone()),
+            TokenKind::Identifier(ref s) => {
+                if cursor.strict_mode() && STRICT_FORBIDDEN_IDENTIFIERS.contains(&s.as_ref()) {
+                    return Err(ParseError::lex(LexError::Syntax(
+                        format!(
+                            "using future reserved keyword '{}' not allowed in strict mode",
+                            s
+                        )
+                        .into(),
+                        next_token.span().start(),
+                    )));
+                }
+                if cursor.strict_mode() && STRICT_RESTRICTED_NAMES.contains(&s.as_ref()) {
+                    return Err(ParseError::lex(LexError::Syntax(
+                        format!("binding '{}' not allowed in strict mode", s).into(),
+                        next_token.span().start(),
+                    )));
+                }
+                Ok(s.clone())
+            }
             TokenKind::Keyword(k @ Keyword::Yield) if !self.allow_yield.0 => {
                 if cursor.strict_mode() {
                     Err(ParseError::lex(LexError::Syntax(
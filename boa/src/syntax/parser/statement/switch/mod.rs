@@ -131,6 +131,7 @@ where
                         self.allow_return,
                         false,
                         &CASE_BREAK_TOKENS,
+                        false,
                     )
                     .parse(cursor)?;
 
@@ -153,6 +154,7 @@ where
                         self.allow_return,
                         false,
                         &CASE_BREAK_TOKENS,
+                        false,
                     )
                     .parse(cursor)?;
 
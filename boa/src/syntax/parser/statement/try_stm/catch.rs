@@ -1,11 +1,8 @@
 use crate::{
     syntax::{
-        ast::{
-            node::{self, Identifier},
-            Keyword, Punctuator,
-        },
+        ast::{node, Keyword, Punctuator},
         parser::{
-            statement::{block::Block, BindingIdentifier},
+            statement::{block::Block, BindingTarget},
             AllowAwait, AllowReturn, AllowYield, Cursor, ParseError, TokenParser,
         },
     },
@@ -64,7 +61,7 @@ where
         };
 
         // Catch block
-        Ok(node::Catch::new::<_, Identifier, _>(
+        Ok(node::Catch::new::<_, node::BindingTarget, _>(
             catch_param,
             Block::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?,
         ))
@@ -103,12 +100,9 @@ impl<R> TokenParser<R> for CatchParameter
 where
     R: Read,
 {
-    type Output = Identifier;
+    type Output = node::BindingTarget;
 
-    fn parse(self, cursor: &mut Cursor<R>) -> Result<Identifier, ParseError> {
-        // TODO: should accept BindingPattern
-        BindingIdentifier::new(self.allow_yield, self.allow_await)
-            .parse(cursor)
-            .map(Identifier::from)
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        BindingTarget::new(self.allow_yield, self.allow_await).parse(cursor)
     }
 }
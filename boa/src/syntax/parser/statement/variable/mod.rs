@@ -10,7 +10,7 @@ use crate::{
         parser::{
             cursor::{Cursor, SemicolonResult},
             expression::Initializer,
-            statement::BindingIdentifier,
+            statement::BindingTarget,
             AllowAwait, AllowIn, AllowYield, ParseError, TokenParser,
         },
     },
@@ -29,14 +29,14 @@ use std::io::Read;
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/var
 /// [spec]: https://tc39.es/ecma262/#prod-VariableStatement
 #[derive(Debug, Clone, Copy)]
-pub(in crate::syntax::parser::statement) struct VariableStatement {
+pub(in crate::syntax::parser) struct VariableStatement {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
 }
 
 impl VariableStatement {
     /// Creates a new `VariableStatement` parser.
-    pub(in crate::syntax::parser::statement) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -167,9 +167,8 @@ where
     type Output = Declaration;
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
-        // TODO: BindingPattern
-
-        let name = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let start = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
+        let target = BindingTarget::new(self.allow_yield, self.allow_await).parse(cursor)?;
 
         let init = if let Some(t) = cursor.peek(0)? {
             if *t.kind() == TokenKind::Punctuator(Punctuator::Assign) {
@@ -181,6 +180,8 @@ where
             None
         };
 
-        Ok(Declaration::new(name, init))
+        let mut decl = Declaration::new(target, init);
+        decl.set_span(cursor.span_from(start));
+        Ok(decl)
     }
 }
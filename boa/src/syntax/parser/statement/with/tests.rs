@@ -0,0 +1,41 @@
+use crate::syntax::{
+    ast::{
+        node::{Assign, Block, GetConstField, Identifier, With},
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+#[test]
+fn check_with_statement() {
+    check_parser(
+        "with (obj) { a = b.c; }",
+        vec![With::new(
+            Identifier::from("obj"),
+            Block::from(vec![Assign::new(
+                Identifier::from("a"),
+                GetConstField::new(Identifier::from("b"), "c"),
+            )
+            .into()]),
+        )
+        .into()],
+    );
+}
+
+#[test]
+fn check_with_statement_without_block() {
+    check_parser(
+        "with (obj) x = 1;",
+        vec![With::new(
+            Identifier::from("obj"),
+            Assign::new(Identifier::from("x"), Const::from(1)),
+        )
+        .into()],
+    );
+}
+
+#[test]
+fn check_with_statement_in_strict_mode() {
+    check_invalid("'use strict'; with (obj) {}");
+    check_invalid("function f() { 'use strict'; with (obj) {} }");
+}
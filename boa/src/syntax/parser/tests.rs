@@ -7,7 +7,7 @@ use crate::syntax::ast::{
         FormalParameter, FunctionDecl, Identifier, If, New, Node, Return, StatementList, UnaryOp,
     },
     op::{self, CompOp, LogOp, NumOp},
-    Const,
+    Const, Position, Span,
 };
 
 /// Checks that the given JavaScript string gives the expected expression.
@@ -338,3 +338,97 @@ fn empty_statement() {
         ],
     );
 }
+
+#[test]
+fn node_spans() {
+    let list = Parser::new(&b"let a = 1;\nif (a) {\n    foo(a + 2);\n}"[..], false)
+        .parse_all()
+        .expect("failed to parse");
+    let span = |start: (u32, u32), end: (u32, u32)| {
+        Some(Span::new(
+            Position::new(start.0, start.1),
+            Position::new(end.0, end.1),
+        ))
+    };
+
+    let items = list.items();
+    assert_eq!(items[0].span(), span((1, 5), (1, 10)));
+    assert_eq!(items[1].span(), span((2, 1), (4, 2)));
+
+    let block = match &items[1] {
+        Node::If(if_node) => {
+            assert_eq!(if_node.cond().span(), span((2, 5), (2, 6)));
+            if_node.body()
+        }
+        _ => panic!("expected an if statement"),
+    };
+    let call = match block {
+        Node::Block(block) => &block.items()[0],
+        _ => panic!("expected a block"),
+    };
+    assert_eq!(call.span(), span((3, 5), (3, 15)));
+    match call {
+        Node::Call(call) => assert_eq!(call.args()[0].span(), span((3, 9), (3, 14))),
+        _ => panic!("expected a call"),
+    }
+}
+
+/// Checks that a `"use strict"` directive makes the whole script strict mode code.
+#[test]
+fn check_script_strict_mode() {
+    let strict = |js: &str| {
+        Parser::new(js.as_bytes(), false)
+            .parse_all()
+            .expect("failed to parse")
+            .strict()
+    };
+    assert!(strict("'use strict'; var a;"));
+    assert!(strict("'a'\n'use strict'"));
+    assert!(!strict("var a; 'use strict';"));
+    assert!(!strict("('use strict');"));
+
+    check_invalid("'use strict'; eval = 1;");
+    check_invalid("'use strict'; arguments += 1;");
+    check_invalid("'use strict'; ++eval;");
+    check_invalid("'use strict'; var arguments;");
+    check_invalid("'use strict'; delete a;");
+    check_invalid("'use strict'; 010;");
+}
+
+/// Checks that `eval` and `arguments` can't be the targets of a destructuring assignment in strict
+/// mode code, while they can still be property names or default values of the pattern.
+#[test]
+fn check_strict_destructuring_assignment_targets() {
+    check_invalid("'use strict'; [arguments] = [];");
+    check_invalid("'use strict'; ({ eval } = {});");
+    check_invalid("'use strict'; ({ a: [eval] } = {});");
+    check_invalid("'use strict'; [...arguments] = [];");
+    check_invalid("'use strict'; for ([eval] of []) {}");
+    check_invalid("'use strict'; for ({ arguments } in {}) {}");
+
+    let parses = |js: &str| Parser::new(js.as_bytes(), false).parse_all().is_ok();
+    assert!(parses("[arguments] = []; ({ eval } = {});"));
+    assert!(parses("'use strict'; ({ eval: a } = {}); [b = eval] = [];"));
+    assert!(parses("'use strict'; [obj.eval, obj.arguments] = [];"));
+}
+
+/// Checks that the strict mode reserved words can't be used as identifiers in strict mode code,
+/// but can still be used as the names of properties.
+#[test]
+fn check_strict_mode_reserved_words() {
+    check_invalid("'use strict'; var public;");
+    check_invalid("'use strict'; implements = 1;");
+    check_invalid("'use strict'; let { interface } = {};");
+    check_invalid("'use strict'; ({ package });");
+    check_invalid("class A { m() { return private; } }");
+
+    let parses = |js: &str| Parser::new(js.as_bytes(), false).parse_all().is_ok();
+    assert!(parses("var public = 1; public;"));
+    assert!(parses("'use strict'; obj.public; obj?.private;"));
+    assert!(parses(
+        "'use strict'; ({ static: 1, implements() {}, get package() {} });"
+    ));
+    assert!(parses(
+        "class A { static x() {} static public() {} interface = 1; }"
+    ));
+}
@@ -11,6 +11,7 @@ use crate::{
         string::is_trimmable_whitespace,
         BigInt, Number,
     },
+    exec::call_stack::StackTrace,
     object::{GcObject, Object, ObjectData},
     property::{Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
     symbol::{RcSymbol, WellKnownSymbols},
@@ -288,12 +289,53 @@ impl Value {
         }
     }
 
+    /// Returns true if the value is an `Array`, or a proxy whose target is an `Array`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isarray
+    pub fn is_array(&self, context: &mut Context) -> Result<bool> {
+        if let Self::Object(ref object) = self {
+            if let Some(proxy) = object.as_proxy() {
+                return Ok(proxy.unwrap_target(context)?.is_array());
+            }
+            return Ok(object.is_array());
+        }
+        Ok(false)
+    }
+
     /// Returns true if the value is a function
     #[inline]
     pub fn is_function(&self) -> bool {
         matches!(self, Self::Object(o) if o.is_function())
     }
 
+    /// Returns the stack trace captured when the value was created, if it is an error object.
+    ///
+    /// This is mostly useful on the values thrown by scripts, to know where they were thrown from.
+    ///
+    /// # Examples
+    /// ```
+    /// # use boa::Context;
+    /// let mut context = Context::new();
+    ///
+    /// let error = context
+    ///     .eval("function fail() { throw new Error('oops'); }\nfail();")
+    ///     .unwrap_err();
+    /// let stack_trace = error.stack_trace().unwrap();
+    /// let frames = stack_trace.frames();
+    ///
+    /// assert_eq!(frames[0].function_name(), "fail");
+    /// assert_eq!(frames[0].span().unwrap().start().line_number(), 1);
+    /// assert_eq!(frames[1].function_name(), "<global>");
+    /// assert_eq!(frames[1].span().unwrap().start().line_number(), 2);
+    /// ```
+    pub fn stack_trace(&self) -> Option<StackTrace> {
+        self.as_object()
+            .and_then(|object| object.borrow().as_error().cloned())
+    }
+
     /// Returns true if the value is undefined.
     #[inline]
     pub fn is_undefined(&self) -> bool {
@@ -434,7 +476,7 @@ impl Value {
         let _timer = BoaProfiler::global().start_event("Value::get_property", "value");
         match self {
             Self::Object(ref object) => {
-                let property = object.get_own_property(&key);
+                let property = object.ordinary_get_own_property(&key);
                 if property.is_some() {
                     return property;
                 }
@@ -460,15 +502,15 @@ impl Value {
     }
 
     /// Check to see if the Value has the field, mainly used by environment records.
+    ///
+    /// It only looks at ordinary properties, so it does not run the traps of proxies.
     #[inline]
     pub fn has_field<K>(&self, key: K) -> bool
     where
         K: Into<PropertyKey>,
     {
         let _timer = BoaProfiler::global().start_event("Value::has_field", "value");
-        self.as_object()
-            .map(|object| object.has_property(&key.into()))
-            .unwrap_or(false)
+        self.get_property(key).is_some()
     }
 
     /// Set the field in the value
@@ -488,6 +530,53 @@ impl Value {
         Ok(value)
     }
 
+    /// Assigns `value` to the property `key` of this value, like an assignment expression.
+    ///
+    /// The properties of primitives are looked up on their wrapper objects. Failed assignments,
+    /// like the ones to read-only properties, throw a `TypeError` in strict mode code.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-putvalue
+    pub(crate) fn put_value<K, V>(&self, key: K, value: V, context: &mut Context) -> Result<Value>
+    where
+        K: Into<PropertyKey>,
+        V: Into<Value>,
+    {
+        let key = key.into();
+        let value = value.into();
+        let succeeded =
+            self.to_object(context)?
+                .set(key.clone(), value.clone(), self.clone(), context)?;
+        if !succeeded && context.strict() {
+            return context
+                .throw_type_error(format!("cannot assign to read-only property {}", key));
+        }
+        Ok(value)
+    }
+
+    /// Deletes the property `key` of this value, like a `delete` expression.
+    ///
+    /// Failing to delete a property, like a non-configurable one, throws a `TypeError` in strict
+    /// mode code.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-delete-operator-runtime-semantics-evaluation
+    pub(crate) fn delete_property<K>(&self, key: K, context: &mut Context) -> Result<bool>
+    where
+        K: Into<PropertyKey>,
+    {
+        let key = key.into();
+        let deleted = self.to_object(context)?.delete(&key, context)?;
+        if !deleted && context.strict() {
+            context.throw_type_error(format!("cannot delete non-configurable property {}", key))?;
+        }
+        Ok(deleted)
+    }
+
     /// Set the kind of an object.
     #[inline]
     pub fn set_data(&self, data: ObjectData) {
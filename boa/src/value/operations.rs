@@ -622,6 +622,37 @@ impl Value {
             AbstractRelation::True | AbstractRelation::Undefined => Ok(false),
         }
     }
+
+    /// The `instanceof` operator tests whether the prototype property of the `target`
+    /// constructor appears anywhere in the prototype chain of this value.
+    ///
+    /// More Information:
+    ///  - [MDN documentation][mdn]
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/instanceof
+    /// [spec]: https://tc39.es/ecma262/#sec-instanceofoperator
+    pub fn instance_of(&self, target: &Self, context: &mut Context) -> Result<bool> {
+        if let Some(object) = target.as_object() {
+            let key = WellKnownSymbols::has_instance();
+
+            match object.get_method(context, key)? {
+                Some(instance_of_handler) => Ok(instance_of_handler
+                    .call(target, &[self.clone()], context)?
+                    .to_boolean()),
+                None if object.is_callable() => object.ordinary_has_instance(context, self),
+                None => {
+                    Err(context
+                        .construct_type_error("right-hand side of 'instanceof' is not callable"))
+                }
+            }
+        } else {
+            Err(context.construct_type_error(format!(
+                "right-hand side of 'instanceof' should be an object, got {}",
+                target.get_type().as_str()
+            )))
+        }
+    }
 }
 
 /// The result of the [Abstract Relational Comparison][arc].
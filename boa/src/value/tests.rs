@@ -267,7 +267,8 @@ fn string_length_is_not_enumerable() {
 
     let object = Value::from("foo").to_object(&mut context).unwrap();
     let length_desc = object
-        .get_own_property(&PropertyKey::from("length"))
+        .get_own_property(&PropertyKey::from("length"), &mut context)
+        .unwrap()
         .unwrap();
     assert!(!length_desc.enumerable());
 }
@@ -279,7 +280,8 @@ fn string_length_is_in_utf16_codeunits() {
     // 😀 is one Unicode code point, but 2 UTF-16 code units
     let object = Value::from("😀").to_object(&mut context).unwrap();
     let length_desc = object
-        .get_own_property(&PropertyKey::from("length"))
+        .get_own_property(&PropertyKey::from("length"), &mut context)
+        .unwrap()
         .unwrap();
     assert_eq!(
         length_desc
@@ -652,7 +654,7 @@ mod cyclic_conversions {
 
         assert_eq!(
             forward(&mut context, src),
-            r#"Uncaught "TypeError": "cyclic object value""#,
+            r#"Uncaught "TypeError": "cyclic object value at line 4, col 13""#,
         );
     }
 
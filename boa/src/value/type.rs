@@ -47,7 +47,7 @@ impl Value {
             Self::Undefined => Type::Undefined,
             Self::BigInt(_) => Type::BigInt,
             Self::Object(ref object) => {
-                if object.is_function() {
+                if object.is_callable() {
                     Type::Function
                 } else {
                     Type::Object
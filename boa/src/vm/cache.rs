@@ -0,0 +1,186 @@
+//! A bounded cache of compiled code blocks, keyed by the hash of their source.
+
+use super::CodeBlock;
+use rustc_hash::FxHashMap;
+use std::{fmt, rc::Rc};
+
+/// The hash of the source of a script.
+///
+/// It is a 128-bit FNV-1a hash of the source bytes, so it is the same across runs and platforms
+/// and can be used to name code blocks stored outside of the engine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SourceHash(u128);
+
+impl SourceHash {
+    const OFFSET_BASIS: u128 = 0x6c62_272e_07bb_0142_62b8_2175_6295_c58d;
+    const PRIME: u128 = 0x0000_0000_0100_0000_0000_0000_0000_013b;
+
+    /// Hashes the given source.
+    pub fn of<T: AsRef<[u8]>>(src: T) -> Self {
+        let hash = src.as_ref().iter().fold(Self::OFFSET_BASIS, |hash, &byte| {
+            (hash ^ u128::from(byte)).wrapping_mul(Self::PRIME)
+        });
+        Self(hash)
+    }
+
+    /// Gets the value of the hash.
+    pub fn get(self) -> u128 {
+        self.0
+    }
+}
+
+impl From<u128> for SourceHash {
+    fn from(hash: u128) -> Self {
+        Self(hash)
+    }
+}
+
+impl fmt::Display for SourceHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:032x}", self.0)
+    }
+}
+
+/// A cache of compiled code blocks, keyed by the [`SourceHash`] of their source.
+///
+/// [`Context::eval`](crate::Context::eval) looks up the source it is given in the cache of the
+/// context before parsing it. Each entry keeps its source, which is compared on every lookup,
+/// so two sources with the same hash never share a code block.
+///
+/// The cache holds at most [`capacity`](CodeCache::capacity) code blocks, evicting the least
+/// recently used one when it is full, and a capacity of zero disables it. Cloning a cache is
+/// cheap, since the code blocks are shared, so a host can fill a cache once and install it in
+/// every context it creates.
+#[derive(Debug, Clone)]
+pub struct CodeCache {
+    entries: FxHashMap<SourceHash, CacheEntry>,
+    capacity: usize,
+    /// Incremented on every use of an entry, to find the least recently used one.
+    clock: u64,
+}
+
+/// A code block of the cache, with the source it was compiled from.
+#[derive(Debug, Clone)]
+struct CacheEntry {
+    source: Rc<[u8]>,
+    code: Rc<CodeBlock>,
+    last_used: u64,
+}
+
+impl CodeCache {
+    /// The number of code blocks held by the cache of a new context.
+    pub const DEFAULT_CAPACITY: usize = 64;
+
+    /// Creates an empty cache with the [default capacity](CodeCache::DEFAULT_CAPACITY).
+    pub fn new() -> Self {
+        Self::with_capacity(Self::DEFAULT_CAPACITY)
+    }
+
+    /// Creates an empty cache holding at most `capacity` code blocks.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: FxHashMap::default(),
+            capacity,
+            clock: 0,
+        }
+    }
+
+    /// Gets the maximum number of code blocks held by the cache.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Sets the maximum number of code blocks held by the cache, evicting the least recently
+    /// used ones if it holds more.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        while self.entries.len() > capacity {
+            self.evict();
+        }
+    }
+
+    /// Gets the code block compiled from the given source, marking it as recently used.
+    pub fn get<T: AsRef<[u8]>>(&mut self, src: T) -> Option<Rc<CodeBlock>> {
+        let src = src.as_ref();
+        let entry = self.entries.get_mut(&SourceHash::of(src))?;
+        if *entry.source != *src {
+            return None;
+        }
+        self.clock += 1;
+        entry.last_used = self.clock;
+        Some(entry.code.clone())
+    }
+
+    /// Inserts the code block compiled from the given source, returning the code block it
+    /// replaces, if any.
+    ///
+    /// The least recently used code block is evicted if the cache is full, and nothing is
+    /// inserted if its capacity is zero.
+    pub fn insert<T: AsRef<[u8]>>(&mut self, src: T, code: Rc<CodeBlock>) -> Option<Rc<CodeBlock>> {
+        if self.capacity == 0 {
+            return None;
+        }
+        let src = src.as_ref();
+        let hash = SourceHash::of(src);
+        if !self.entries.contains_key(&hash) && self.entries.len() >= self.capacity {
+            self.evict();
+        }
+        self.clock += 1;
+        let entry = CacheEntry {
+            source: src.into(),
+            code,
+            last_used: self.clock,
+        };
+        self.entries.insert(hash, entry).map(|entry| entry.code)
+    }
+
+    /// Removes the code block compiled from the given source.
+    pub fn remove<T: AsRef<[u8]>>(&mut self, src: T) -> Option<Rc<CodeBlock>> {
+        let src = src.as_ref();
+        let hash = SourceHash::of(src);
+        match self.entries.get(&hash) {
+            Some(entry) if *entry.source == *src => self.entries.remove(&hash).map(|e| e.code),
+            _ => None,
+        }
+    }
+
+    /// Removes the least recently used code block.
+    fn evict(&mut self) {
+        let oldest = self
+            .entries
+            .iter()
+            .min_by_key(|(_, entry)| entry.last_used)
+            .map(|(hash, _)| *hash);
+        if let Some(hash) = oldest {
+            self.entries.remove(&hash);
+        }
+    }
+
+    /// Returns an iterator over the cached code blocks and the hashes of their sources.
+    pub fn iter(&self) -> impl Iterator<Item = (SourceHash, &Rc<CodeBlock>)> {
+        self.entries
+            .iter()
+            .map(|(hash, entry)| (*hash, &entry.code))
+    }
+
+    /// Returns the number of cached code blocks.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the cache holds no code block.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes every code block from the cache.
+    pub fn clear(&mut self) {
+        self.entries.clear()
+    }
+}
+
+impl Default for CodeCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
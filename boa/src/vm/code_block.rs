@@ -0,0 +1,71 @@
+use super::{serialization, Instruction};
+use crate::{
+    builtins::function::FunctionFlags,
+    syntax::ast::{
+        node::{DeclarationPattern, FormalParameter, Node},
+        Span,
+    },
+    Value,
+};
+use serde::{Deserialize, Serialize};
+use std::{ops::Range, rc::Rc};
+
+/// The bytecode of a script or a function body, along with the data its instructions refer to.
+///
+/// A code block is the output of the [`Compiler`](super::Compiler). It can be encoded to bytes
+/// with [`CodeBlock::to_bytes`] and loaded back with [`CodeBlock::from_bytes`], so it can be run
+/// without parsing its source again.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct CodeBlock {
+    /// The name of the function, if any.
+    pub(crate) name: Option<Box<str>>,
+    /// The flags of the function.
+    #[serde(with = "serialization::flags")]
+    pub(crate) flags: FunctionFlags,
+    /// The number of parameters before the first one with a default value or the rest parameter.
+    pub(crate) length: usize,
+    /// The formal parameters of the function, bound before its code runs.
+    pub(crate) params: Box<[FormalParameter]>,
+    /// The source text of the function, returned by `Function.prototype.toString`.
+    pub(crate) source_text: Option<Box<str>>,
+    /// Vector of instructions
+    pub(crate) instructions: Vec<Instruction>,
+    /// The pool stores constant data that can be indexed with the opcodes and pushed on the stack.
+    ///
+    /// It only holds primitive values, so it never has to be traced by the garbage collector.
+    #[serde(with = "serialization::pool")]
+    pub(crate) pool: Vec<Value>,
+    /// The code blocks of the functions defined in this code.
+    #[serde(with = "serialization::functions")]
+    pub(crate) functions: Vec<Rc<CodeBlock>>,
+    /// The nodes that are not compiled to bytecode, but evaluated by the tree-walking interpreter.
+    pub(crate) nodes: Vec<Node>,
+    /// The destructuring patterns bound by this code.
+    pub(crate) patterns: Vec<DeclarationPattern>,
+    /// The spans of the nodes evaluated by the instructions, from the index of the first
+    /// instruction evaluating each of them.
+    pub(crate) spans: Vec<(usize, Option<Span>)>,
+}
+
+impl CodeBlock {
+    /// Gets the instructions of the code block.
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+
+    /// Gets the constant pool of the code block.
+    pub fn pool(&self) -> &[Value] {
+        &self.pool
+    }
+
+    /// Gets the span of the node evaluated by the instruction at the given index, along with the
+    /// range of the instructions evaluating the same node.
+    pub(crate) fn span_at(&self, index: usize) -> (Option<Span>, Range<usize>) {
+        let next = self.spans.partition_point(|&(start, _)| start <= index);
+        let end = self.spans.get(next).map_or(usize::MAX, |&(start, _)| start);
+        match next.checked_sub(1).map(|current| self.spans[current]) {
+            Some((start, span)) => (span, start..end),
+            None => (None, 0..end),
+        }
+    }
+}
@@ -1,5 +1,41 @@
 use super::*;
-use crate::{syntax::ast::Const, syntax::ast::Node, value::RcBigInt, value::RcString};
+use crate::{
+    builtins::function::FunctionFlags,
+    syntax::ast::{
+        node::{Block, DeclarationPattern, FormalParameter, StatementList},
+        Const, Node, Span,
+    },
+    value::{RcBigInt, RcString},
+};
+use std::{collections::HashMap, mem, rc::Rc};
+
+/// An entry of the control stack of the compiler.
+///
+/// The control stack describes the statements enclosing the code being compiled, so that
+/// `break`, `continue` and `return` can leave them cleanly: popping the environments they pushed,
+/// closing their iterators and running their `finally` blocks.
+#[derive(Debug)]
+enum Control {
+    /// A loop or a `switch` statement, which can be the target of `break` and, for loops,
+    /// `continue`.
+    Jumpable {
+        labels: Box<[Box<str>]>,
+        is_loop: bool,
+        breaks: Vec<usize>,
+        continues: Vec<usize>,
+    },
+    /// A labelled statement other than a loop, which can only be the target of a `break` with
+    /// its label.
+    Label { label: Box<str>, breaks: Vec<usize> },
+    /// A declarative environment pushed by a block or a loop.
+    Environment,
+    /// The iterator of a `for...in` or `for...of` loop.
+    Iterator,
+    /// An exception handler, with the `finally` block that runs when leaving it, if any.
+    Handler { finally: Option<Block> },
+    /// A value kept on the stack, like the exception while a `finally` block runs.
+    Value,
+}
 
 #[derive(Debug, Default)]
 /// The compiler struct holds all the instructions.
@@ -8,6 +44,17 @@ pub struct Compiler {
     pub(super) instructions: Vec<Instruction>,
     /// The pool stores constant data that can be indexed with the opcodes and pushed on the stack
     pub(super) pool: Vec<Value>,
+    /// The indexes of the names that are already in the pool.
+    names: HashMap<Box<str>, usize>,
+    functions: Vec<Rc<CodeBlock>>,
+    nodes: Vec<Node>,
+    patterns: Vec<DeclarationPattern>,
+    control: Vec<Control>,
+    /// The jumps to the end of the code, taken by a `break` or `continue` without a target.
+    exits: Vec<usize>,
+    /// The spans of the nodes evaluated by the instructions, from the index of the first
+    /// instruction evaluating each of them.
+    spans: Vec<(usize, Option<Span>)>,
 }
 
 impl Compiler {
@@ -35,6 +82,438 @@ impl Compiler {
         self.add_instruction(Instruction::BigInt(index));
         self.pool.push(bigint.into().into());
     }
+
+    /// Puts the name in the pool, if it is not there yet, then adds an instruction which points
+    /// to its index.
+    pub(crate) fn add_name_instruction(&mut self, name: &str, instr: fn(usize) -> Instruction) {
+        let index = if let Some(index) = self.names.get(name) {
+            *index
+        } else {
+            let index = self.pool.len();
+            self.pool.push(name.into());
+            self.names.insert(name.into(), index);
+            index
+        };
+        self.add_instruction(instr(index));
+    }
+
+    /// Adds an instruction which binds a value to the destructuring pattern.
+    pub(crate) fn add_pattern_instruction(
+        &mut self,
+        pattern: &DeclarationPattern,
+        instr: fn(usize) -> Instruction,
+    ) {
+        let index = self.patterns.len();
+        self.patterns.push(pattern.clone());
+        self.add_instruction(instr(index));
+    }
+
+    /// Adds an instruction which evaluates the node with the tree-walking interpreter.
+    ///
+    /// This is used for the nodes that need to suspend their execution, like generators and
+    /// async functions, and for classes.
+    pub(crate) fn add_node_instruction(&mut self, node: &Node) {
+        let index = self.nodes.len();
+        self.nodes.push(node.clone());
+        self.add_instruction(Instruction::Evaluate(index));
+    }
+
+    /// Compiles a function and adds an instruction which creates a function object for it.
+    pub(crate) fn add_function_instruction(
+        &mut self,
+        name: Option<&str>,
+        params: &[FormalParameter],
+        body: &StatementList,
+        mut flags: FunctionFlags,
+        source_text: &str,
+    ) {
+        if body.strict() {
+            flags |= FunctionFlags::STRICT;
+        }
+        let code = CodeBlock {
+            source_text: Some(source_text.into()),
+            ..Self::compile_function(name, params, body.items(), flags)
+        };
+        let index = self.functions.len();
+        self.functions.push(Rc::new(code));
+        self.add_instruction(Instruction::GetFunction(index));
+    }
+
+    /// Compiles the arguments of a call, returning true if they are collected in an array
+    /// because some of them are spread.
+    pub(crate) fn compile_arguments(&mut self, args: &[Node]) -> bool {
+        if args.iter().any(|arg| matches!(arg, Node::Spread(_))) {
+            self.add_instruction(Instruction::NewArray);
+            self.compile_elements(args);
+            true
+        } else {
+            for arg in args {
+                arg.compile(self);
+            }
+            false
+        }
+    }
+
+    /// Compiles the elements of an array literal, or spread arguments, appending them to the
+    /// array on top of the stack.
+    pub(crate) fn compile_elements(&mut self, elements: &[Node]) {
+        for element in elements {
+            if let Node::Spread(ref spread) = element {
+                spread.val().compile(self);
+                self.add_instruction(Instruction::PushIterableToArray);
+            } else {
+                element.compile(self);
+                self.add_instruction(Instruction::PushArrayElement);
+            }
+        }
+    }
+
+    /// Adds a jump instruction whose address is set later by [`patch_jump`](Self::patch_jump),
+    /// returning its index.
+    pub(crate) fn jump(&mut self, instr: fn(usize) -> Instruction) -> usize {
+        let index = self.instructions.len();
+        self.add_instruction(instr(usize::MAX));
+        index
+    }
+
+    /// Sets the address of the jump instruction at the index to the next instruction.
+    pub(crate) fn patch_jump(&mut self, index: usize) {
+        let target = self.next_address();
+        match self.instructions[index] {
+            Instruction::Jump(ref mut address)
+            | Instruction::JumpIfFalse(ref mut address)
+            | Instruction::JumpIfTrue(ref mut address)
+            | Instruction::LogicalAnd(ref mut address)
+            | Instruction::LogicalOr(ref mut address)
+            | Instruction::Coalesce(ref mut address)
+            | Instruction::JumpIfNullOrUndefined(ref mut address)
+            | Instruction::TryStart(ref mut address)
+            | Instruction::ForInIterator(ref mut address)
+            | Instruction::IteratorNext(ref mut address) => *address = target,
+            ref instr => unreachable!("{} is not a jump", instr),
+        }
+    }
+
+    /// Gets the address of the next instruction.
+    pub(crate) fn next_address(&self) -> usize {
+        self.instructions.len()
+    }
+
+    /// Compiles a statement, making the value of an expression statement the completion value of
+    /// the code.
+    pub(crate) fn compile_statement(&mut self, node: &Node) {
+        node.compile(self);
+        if !is_statement(node) {
+            self.add_instruction(Instruction::PopCompletion);
+        }
+    }
+
+    /// Compiles a statement or an expression whose value is not used.
+    pub(crate) fn compile_discarded(&mut self, node: &Node) {
+        node.compile(self);
+        if !is_statement(node) {
+            self.add_instruction(Instruction::Pop);
+        }
+    }
+
+    /// Compiles a statement list in a new declarative environment, like a block.
+    pub(crate) fn compile_block(&mut self, statements: &[Node]) {
+        self.push_environment();
+        for node in statements {
+            self.compile_statement(node);
+        }
+        self.pop_environment();
+    }
+
+    /// Pushes a declarative environment, which is popped when control leaves it.
+    pub(crate) fn push_environment(&mut self) {
+        self.add_instruction(Instruction::PushDeclarativeEnvironment);
+        self.control.push(Control::Environment);
+    }
+
+    /// Pushes the object environment of a `with` statement for the value on top of the stack,
+    /// which is popped when control leaves it.
+    pub(crate) fn push_with_environment(&mut self) {
+        self.add_instruction(Instruction::PushWithEnvironment);
+        self.control.push(Control::Environment);
+    }
+
+    /// Pops the environment pushed by [`push_environment`](Self::push_environment).
+    pub(crate) fn pop_environment(&mut self) {
+        self.pop_control();
+        self.add_instruction(Instruction::PopEnvironment);
+    }
+
+    /// Marks the start of the code running while a loop iterator is on the iterator stack.
+    pub(crate) fn push_iterator(&mut self) {
+        self.control.push(Control::Iterator);
+    }
+
+    /// Marks the start of the code protected by an exception handler.
+    pub(crate) fn push_handler(&mut self, finally: Option<&Block>) {
+        self.control.push(Control::Handler {
+            finally: finally.cloned(),
+        });
+    }
+
+    /// Marks the start of the code running while an extra value is on the stack.
+    pub(crate) fn push_value(&mut self) {
+        self.control.push(Control::Value);
+    }
+
+    /// Marks the start of a loop, or of a `switch` statement, which `break` can leave.
+    pub(crate) fn push_jumpable(&mut self, labels: &[Box<str>], is_loop: bool) {
+        self.control.push(Control::Jumpable {
+            labels: labels.into(),
+            is_loop,
+            breaks: Vec::new(),
+            continues: Vec::new(),
+        });
+    }
+
+    /// Marks the end of a loop or `switch` statement, setting the address of its `continue`
+    /// jumps to `continue_target` and of its `break` jumps to the next instruction.
+    pub(crate) fn pop_jumpable(&mut self, continue_target: usize) {
+        if let Some(Control::Jumpable {
+            breaks, continues, ..
+        }) = self.control.pop()
+        {
+            for index in continues {
+                if let Instruction::Jump(ref mut address) = self.instructions[index] {
+                    *address = continue_target;
+                }
+            }
+            for index in breaks {
+                self.patch_jump(index);
+            }
+        } else {
+            unreachable!("the innermost control entry is not a loop");
+        }
+    }
+
+    /// Marks the start of a labelled statement that is not a loop.
+    pub(crate) fn push_label(&mut self, label: &str) {
+        self.control.push(Control::Label {
+            label: label.into(),
+            breaks: Vec::new(),
+        });
+    }
+
+    /// Marks the end of a labelled statement, setting the address of its `break` jumps to the
+    /// next instruction.
+    pub(crate) fn pop_label(&mut self) {
+        if let Some(Control::Label { breaks, .. }) = self.control.pop() {
+            for index in breaks {
+                self.patch_jump(index);
+            }
+        } else {
+            unreachable!("the innermost control entry is not a labelled statement");
+        }
+    }
+
+    /// Marks the end of the innermost control entry.
+    pub(crate) fn pop_control(&mut self) {
+        self.control.pop();
+    }
+
+    /// Compiles a `break` statement, leaving the innermost loop or `switch`, or the statement with
+    /// the label.
+    pub(crate) fn compile_break(&mut self, label: Option<&str>) {
+        let target = self
+            .control
+            .iter()
+            .rposition(|control| match (control, label) {
+                (Control::Jumpable { .. }, None) => true,
+                (Control::Jumpable { labels, .. }, Some(label)) => {
+                    labels.iter().any(|name| name.as_ref() == label)
+                }
+                (Control::Label { label: name, .. }, Some(label)) => name.as_ref() == label,
+                _ => false,
+            });
+        self.compile_jump_out(target, true);
+    }
+
+    /// Compiles a `continue` statement, starting the next iteration of the innermost loop or of
+    /// the loop with the label.
+    pub(crate) fn compile_continue(&mut self, label: Option<&str>) {
+        let target = self.control.iter().rposition(|control| match control {
+            Control::Jumpable {
+                labels, is_loop, ..
+            } => {
+                *is_loop
+                    && match label {
+                        Some(label) => labels.iter().any(|name| name.as_ref() == label),
+                        None => true,
+                    }
+            }
+            _ => false,
+        });
+        self.compile_jump_out(target, false);
+    }
+
+    fn compile_jump_out(&mut self, target: Option<usize>, is_break: bool) {
+        if let Some(target) = target {
+            self.unwind(target + 1, false);
+            let jump = self.jump(Instruction::Jump);
+            match self.control.get_mut(target) {
+                Some(Control::Jumpable { breaks, .. }) | Some(Control::Label { breaks, .. })
+                    if is_break =>
+                {
+                    breaks.push(jump)
+                }
+                Some(Control::Jumpable { continues, .. }) => continues.push(jump),
+                _ => {}
+            }
+        } else {
+            // Like the interpreter, a `break` or `continue` outside of a loop stops the code.
+            self.unwind(0, false);
+            let jump = self.jump(Instruction::Jump);
+            self.exits.push(jump);
+        }
+    }
+
+    /// Compiles a `return` statement whose value is on top of the stack.
+    pub(crate) fn compile_return(&mut self) {
+        self.unwind(0, true);
+        self.add_instruction(Instruction::Return);
+    }
+
+    /// Emits the code leaving every control entry above `depth`, from the innermost one.
+    ///
+    /// If `has_value` is true, a value on top of the stack is kept, like the value of a `return`.
+    fn unwind(&mut self, depth: usize, has_value: bool) {
+        for index in (depth..self.control.len()).rev() {
+            match self.control[index] {
+                Control::Jumpable { .. } | Control::Label { .. } => {}
+                Control::Environment => self.add_instruction(Instruction::PopEnvironment),
+                Control::Iterator => self.add_instruction(Instruction::IteratorClose),
+                Control::Handler { ref finally } => {
+                    let finally = finally.clone();
+                    self.add_instruction(Instruction::TryEnd);
+                    if let Some(finally) = finally {
+                        // The finally block runs outside of the statements it protects.
+                        let inner = self.control.split_off(index);
+                        self.compile_block(finally.items());
+                        self.control.extend(inner);
+                    }
+                }
+                Control::Value => {
+                    if has_value {
+                        self.add_instruction(Instruction::Swap);
+                    }
+                    self.add_instruction(Instruction::Pop);
+                }
+            }
+        }
+    }
+
+    /// Compiles the parameters and body of a function.
+    pub(crate) fn compile_function(
+        name: Option<&str>,
+        params: &[FormalParameter],
+        body: &[Node],
+        flags: FunctionFlags,
+    ) -> CodeBlock {
+        let _timer = BoaProfiler::global().start_event("function - Code Gen", "codeGen");
+        let mut compiler = Self::default();
+        for node in body {
+            compiler.compile_statement(node);
+        }
+        compiler.patch_exits();
+        compiler.add_instruction(Instruction::Undefined);
+        compiler.add_instruction(Instruction::Return);
+
+        // The length of a function only counts the parameters before the first default value
+        // see <https://tc39.es/ecma262/#sec-function-definitions-static-semantics-expectedargumentcount>
+        let length = params
+            .iter()
+            .take_while(|param| param.init().is_none() && !param.is_rest_param())
+            .count();
+
+        CodeBlock {
+            name: name.map(Box::from),
+            flags,
+            length,
+            params: params.into(),
+            ..compiler.finish()
+        }
+    }
+
+    /// Records that the instructions added from now on evaluate the node with the given span,
+    /// returning the span of the enclosing node.
+    fn enter_span(&mut self, span: Span) -> Option<Span> {
+        let outer = self.spans.last().and_then(|&(_, span)| span);
+        self.record_span(Some(span));
+        outer
+    }
+
+    /// Records that the instructions added from now on evaluate the enclosing node again.
+    fn exit_span(&mut self, outer: Option<Span>) {
+        self.record_span(outer);
+    }
+
+    fn record_span(&mut self, span: Option<Span>) {
+        let index = self.instructions.len();
+        match self.spans.last_mut() {
+            Some(last) if last.0 == index => last.1 = span,
+            Some(&mut (_, last)) if last == span => {}
+            _ => self.spans.push((index, span)),
+        }
+    }
+
+    fn patch_exits(&mut self) {
+        for index in mem::take(&mut self.exits) {
+            self.patch_jump(index);
+        }
+    }
+
+    /// Finishes the compilation, returning the compiled code.
+    pub fn finish(mut self) -> CodeBlock {
+        self.patch_exits();
+        CodeBlock {
+            name: None,
+            flags: FunctionFlags::default(),
+            length: 0,
+            params: Box::new([]),
+            source_text: None,
+            instructions: self.instructions,
+            pool: self.pool,
+            functions: self.functions,
+            nodes: self.nodes,
+            patterns: self.patterns,
+            spans: self.spans,
+        }
+    }
+}
+
+/// Returns true if the node is a statement, which does not leave a value on the stack.
+fn is_statement(node: &Node) -> bool {
+    matches!(
+        node,
+        Node::Block(_)
+            | Node::Break(_)
+            | Node::Continue(_)
+            | Node::DoWhileLoop(_)
+            | Node::ForLoop(_)
+            | Node::ForInLoop(_)
+            | Node::ForOfLoop(_)
+            | Node::If(_)
+            | Node::Labelled(_)
+            | Node::Return(_)
+            | Node::Switch(_)
+            | Node::Throw(_)
+            | Node::Try(_)
+            | Node::WhileLoop(_)
+            | Node::With(_)
+            | Node::VarDeclList(_)
+            | Node::LetDeclList(_)
+            | Node::ConstDeclList(_)
+            | Node::FunctionDecl(_)
+            | Node::AsyncFunctionDecl(_)
+            | Node::GeneratorDecl(_)
+            | Node::ClassDecl(_)
+            | Node::Empty
+    )
 }
 
 pub(crate) trait CodeGen {
@@ -43,7 +522,23 @@ pub(crate) trait CodeGen {
 
 impl CodeGen for Node {
     fn compile(&self, compiler: &mut Compiler) {
-        let _timer = BoaProfiler::global().start_event(&format!("Node ({})", &self), "codeGen");
+        let _timer = BoaProfiler::global().start_event("Node", "codeGen");
+
+        // Keep track of the node evaluated by the instructions, for the error messages.
+        match self.span() {
+            Some(span) => {
+                let outer = compiler.enter_span(span);
+                self.compile_node(compiler);
+                compiler.exit_span(outer);
+            }
+            None => self.compile_node(compiler),
+        }
+    }
+}
+
+impl Node {
+    /// Compiles the node.
+    fn compile_node(&self, compiler: &mut Compiler) {
         match *self {
             Node::Const(Const::Undefined) => compiler.add_instruction(Instruction::Undefined),
             Node::Const(Const::Null) => compiler.add_instruction(Instruction::Null),
@@ -61,49 +556,63 @@ impl CodeGen for Node {
             Node::Const(Const::BigInt(ref bigint)) => {
                 compiler.add_bigint_instruction(bigint.clone())
             }
+            Node::This => compiler.add_instruction(Instruction::This),
+            Node::Identifier(ref name) => {
+                compiler.add_name_instruction(name.as_ref(), Instruction::GetName)
+            }
+            Node::GetConstField(ref field) => field.compile(compiler),
+            Node::GetField(ref field) => field.compile(compiler),
+            Node::GetPrivateField(ref field) => field.compile(compiler),
             Node::BinOp(ref op) => op.compile(compiler),
             Node::UnaryOp(ref op) => op.compile(compiler),
-            Node::VarDeclList(ref list) => {
-                for var_decl in list.as_ref() {
-                    let name = var_decl.name();
-                    let index = compiler.pool.len();
-                    compiler.add_instruction(Instruction::DefVar(index));
-                    compiler.pool.push(name.into());
-
-                    if let Some(v) = var_decl.init() {
-                        v.compile(compiler);
-                        compiler.add_instruction(Instruction::InitLexical(index))
-                    };
-                }
-            }
-            Node::LetDeclList(ref list) => {
-                for let_decl in list.as_ref() {
-                    let name = let_decl.name();
-                    let index = compiler.pool.len();
-                    compiler.add_instruction(Instruction::DefLet(index));
-                    compiler.pool.push(name.into());
-
-                    // If name has a value we can init here too
-                    if let Some(v) = let_decl.init() {
-                        v.compile(compiler);
-                        compiler.add_instruction(Instruction::InitLexical(index))
-                    };
-                }
-            }
-            Node::ConstDeclList(ref list) => {
-                for const_decl in list.as_ref() {
-                    let name = const_decl.name();
-                    let index = compiler.pool.len();
-                    compiler.add_instruction(Instruction::DefConst(index));
-                    compiler.pool.push(name.into());
-
-                    if let Some(v) = const_decl.init() {
-                        v.compile(compiler);
-                        compiler.add_instruction(Instruction::InitLexical(index))
-                    };
-                }
+            Node::Assign(ref assign) => assign.compile(compiler),
+            Node::ConditionalOp(ref op) => op.compile(compiler),
+            Node::Call(ref call) => call.compile(compiler),
+            Node::OptionalChain(ref chain) => chain.compile(compiler),
+            Node::New(ref new) => new.compile(compiler),
+            Node::Object(ref object) => object.compile(compiler),
+            Node::ArrayDecl(ref array) => array.compile(compiler),
+            Node::TemplateLit(ref template) => template.compile(compiler),
+            Node::FunctionDecl(ref decl) => decl.compile(compiler),
+            Node::FunctionExpr(ref expr) => expr.compile(compiler),
+            Node::ArrowFunctionDecl(ref decl) => decl.compile(compiler),
+            Node::VarDeclList(ref list)
+            | Node::LetDeclList(ref list)
+            | Node::ConstDeclList(ref list) => list.compile(compiler),
+            Node::Block(ref block) => block.compile(compiler),
+            Node::If(ref if_smt) => if_smt.compile(compiler),
+            Node::Labelled(ref labelled) => labelled.compile(compiler),
+            Node::WhileLoop(ref while_loop) => while_loop.compile(compiler),
+            Node::DoWhileLoop(ref do_while) => do_while.compile(compiler),
+            Node::ForLoop(ref for_loop) => for_loop.compile(compiler),
+            Node::ForInLoop(ref for_in_loop) => for_in_loop.compile(compiler),
+            Node::ForOfLoop(ref for_of_loop) => for_of_loop.compile(compiler),
+            Node::Switch(ref switch) => switch.compile(compiler),
+            Node::Break(ref break_node) => break_node.compile(compiler),
+            Node::Continue(ref continue_node) => continue_node.compile(compiler),
+            Node::Return(ref ret) => ret.compile(compiler),
+            Node::Throw(ref throw) => throw.compile(compiler),
+            Node::Try(ref try_node) => try_node.compile(compiler),
+            Node::With(ref with) => with.compile(compiler),
+            Node::Empty => {}
+            // Declarations that are evaluated by the interpreter still produce a value.
+            Node::ClassDecl(_) | Node::AsyncFunctionDecl(_) | Node::GeneratorDecl(_) => {
+                compiler.add_node_instruction(self);
+                compiler.add_instruction(Instruction::Pop);
             }
-            _ => unimplemented!(),
+            // Generators and async functions suspend their execution, which the interpreter does
+            // with coroutines, so they are created and run by the interpreter, as well as the
+            // classes and `super`, which need the home objects and fields of ordinary functions.
+            Node::ClassExpr(_)
+            | Node::AsyncFunctionExpr(_)
+            | Node::GeneratorExpr(_)
+            | Node::AwaitExpr(_)
+            | Node::YieldExpr(_)
+            | Node::GetSuperField(_)
+            | Node::SuperCall(_)
+            | Node::TaggedTemplate(_)
+            | Node::Spread(_)
+            | Node::DeclarationPattern(_) => compiler.add_node_instruction(self),
         }
     }
 }
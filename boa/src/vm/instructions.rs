@@ -1,4 +1,12 @@
-#[derive(Debug, Clone, Copy)]
+use serde::{Deserialize, Serialize};
+
+/// The instructions of the virtual machine.
+///
+/// Every instruction operates on the operand stack of the [`VM`](super::VM). Operands that are
+/// not known until runtime are popped from the stack, while the `usize` operands are indexes into
+/// the tables of the [`CodeBlock`](super::CodeBlock) being run, or addresses of instructions for
+/// the jumps.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum Instruction {
     Undefined,
     Null,
@@ -15,6 +23,24 @@ pub enum Instruction {
     /// Loads an f32 onto the stack
     Rational(f64),
 
+    /// Removes the value on top of the stack.
+    Pop,
+
+    /// Duplicates the value on top of the stack.
+    Dup,
+
+    /// Duplicates the two values on top of the stack, keeping their order.
+    Dup2,
+
+    /// Swaps the two values on top of the stack.
+    Swap,
+
+    /// Moves the value on top of the stack down, so it becomes the nth value from the top.
+    RotateDown(usize),
+
+    /// Moves the nth value from the top of the stack up to the top.
+    RotateUp(usize),
+
     /// Adds the values from destination and source and stores the result in destination
     Add,
 
@@ -58,61 +84,188 @@ pub enum Instruction {
     BitNot,
     Not,
 
+    /// Converts the value on top of the stack to a number.
+    ToNumber,
+
+    /// Converts the value on top of the stack to a string.
+    ToString,
+
+    /// Adds one to the number on top of the stack.
+    Inc,
+
+    /// Subtracts one from the number on top of the stack.
+    Dec,
+
     /// The usize is the index of the variable name in the pool
     DefVar(usize),
     /// The usize is the index of the variable name in the pool
     DefLet(usize),
     /// The usize is the index of the variable name in the pool
     DefConst(usize),
-    /// The usize is the index of the value to initiate the variable with in the pool
+    /// Initializes the binding whose name is at the index in the pool with the value on top of
+    /// the stack, leaving the value on the stack.
     InitLexical(usize),
+
+    /// Loads the value of the binding whose name is at the index in the pool.
+    GetName(usize),
+
+    /// Loads the object of the `with` statement that holds the binding whose name is at the
+    /// index in the pool, or `undefined` if the binding is not in the object of a `with`.
+    GetWithBaseObject(usize),
+
+    /// Assigns the value on top of the stack to the binding whose name is at the index in the
+    /// pool, leaving the value on the stack.
+    SetName(usize),
+
+    /// Binds the value on top of the stack to the pattern at the index as a `var` declaration.
+    BindVar(usize),
+    /// Binds the value on top of the stack to the pattern at the index as a `let` declaration.
+    BindLet(usize),
+    /// Binds the value on top of the stack to the pattern at the index as a `const` declaration.
+    BindConst(usize),
+    /// Assigns the value on top of the stack to the pattern at the index.
+    BindAssign(usize),
+
+    /// Loads the `this` binding.
+    This,
+
+    /// Gets the property whose name is at the index in the pool from the object on the stack.
+    GetPropertyByName(usize),
+
+    /// Gets the property named by the value on top of the stack from the object below it.
+    GetPropertyByValue,
+
+    /// Sets the property whose name is at the index in the pool, taking the object and the value
+    /// from the stack and leaving the value.
+    SetPropertyByName(usize),
+
+    /// Sets the property named by a value on the stack, taking the object, the key and the value
+    /// from the stack and leaving the value.
+    SetPropertyByValue,
+
+    /// Deletes the property whose name is at the index in the pool from the object on the stack.
+    DeletePropertyByName(usize),
+
+    /// Deletes the property named by the value on top of the stack from the object below it.
+    DeletePropertyByValue,
+
+    /// Creates an empty object.
+    NewObject,
+
+    /// Defines a data property whose name is at the index in the pool on the object below the
+    /// value on top of the stack.
+    DefineOwnPropertyByName(usize),
+
+    /// Defines a method whose name is at the index in the pool on the object below the function
+    /// on top of the stack.
+    DefineMethodByName(usize),
+
+    /// Defines a getter whose name is at the index in the pool on the object below the function
+    /// on top of the stack.
+    DefineGetterByName(usize),
+
+    /// Defines a setter whose name is at the index in the pool on the object below the function
+    /// on top of the stack.
+    DefineSetterByName(usize),
+
+    /// Creates an empty array.
+    NewArray,
+
+    /// Appends the value on top of the stack to the array below it.
+    PushArrayElement,
+
+    /// Appends every value of the iterable on top of the stack to the array below it.
+    PushIterableToArray,
+
+    /// Appends a hole to the array on top of the stack, by increasing its length.
+    PushHoleToArray,
+
+    /// Calls a function with the number of arguments on top of the stack, which are preceded by
+    /// the function and the `this` value.
+    Call(usize),
+
+    /// Calls a function with the arguments in the array on top of the stack, which is preceded by
+    /// the function and the `this` value.
+    CallWithArray,
+
+    /// Constructs an object from the function followed by the number of arguments on the stack.
+    New(usize),
+
+    /// Constructs an object from the function followed by an array of arguments on the stack.
+    NewWithArray,
+
+    /// Creates a function object from the code block at the index.
+    GetFunction(usize),
+
+    /// Evaluates the node at the index with the tree-walking interpreter.
+    Evaluate(usize),
+
+    /// Jumps to the address.
+    Jump(usize),
+
+    /// Pops the value on top of the stack and jumps to the address if it is falsy.
+    JumpIfFalse(usize),
+
+    /// Pops the value on top of the stack and jumps to the address if it is truthy.
+    JumpIfTrue(usize),
+
+    /// Jumps to the address if the value on top of the stack is falsy, or pops it otherwise.
+    LogicalAnd(usize),
+
+    /// Jumps to the address if the value on top of the stack is truthy, or pops it otherwise.
+    LogicalOr(usize),
+
+    /// Jumps to the address if the value on top of the stack is neither `null` nor `undefined`,
+    /// or pops it otherwise.
+    Coalesce(usize),
+
+    /// Jumps to the address if the value on top of the stack is `null` or `undefined`, without
+    /// popping it.
+    JumpIfNullOrUndefined(usize),
+
+    /// Pops the value of an expression statement, which becomes the completion value of the code.
+    PopCompletion,
+
+    /// Returns the value on top of the stack from the function.
+    Return,
+
+    /// Throws the value on top of the stack.
+    Throw,
+
+    /// Installs an exception handler at the address, until the matching `TryEnd`.
+    TryStart(usize),
+
+    /// Removes the innermost exception handler.
+    TryEnd,
+
+    /// Pushes a new declarative environment.
+    PushDeclarativeEnvironment,
+
+    /// Pops a value, converts it to an object and pushes a new object environment of a `with`
+    /// statement for it.
+    PushWithEnvironment,
+
+    /// Pops the current environment.
+    PopEnvironment,
+
+    /// Gets the iterator of the value on top of the stack and pushes it on the iterator stack.
+    GetIterator,
+
+    /// Creates the `for...in` iterator of the value on top of the stack and pushes it on the
+    /// iterator stack, or jumps to the address if the value is `null` or `undefined`.
+    ForInIterator(usize),
+
+    /// Pushes the next value of the innermost iterator, or removes the exhausted iterator and
+    /// jumps to the address.
+    IteratorNext(usize),
+
+    /// Removes the innermost iterator and closes it.
+    IteratorClose,
 }
 
 impl std::fmt::Display for Instruction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match *self {
-            Self::Undefined => write!(f, "Undefined"),
-            Self::Null => write!(f, "Null"),
-            Self::True => write!(f, "True"),
-            Self::False => write!(f, "False"),
-            Self::Zero => write!(f, "Zero"),
-            Self::One => write!(f, "One"),
-            Self::String(usize) => write!(f, "String({})", usize),
-            Self::BigInt(usize) => write!(f, "BigInt({})", usize),
-            Self::Int32(i32) => write!(f, "Int32({})", i32),
-            Self::Rational(f64) => write!(f, "Rational({})", f64),
-            Self::Add => write!(f, "Add"),
-            Self::Sub => write!(f, "Sub"),
-            Self::Mul => write!(f, "Mul"),
-            Self::Div => write!(f, "Div"),
-            Self::Pow => write!(f, "Pow"),
-            Self::Mod => write!(f, "Mod"),
-            Self::BitAnd => write!(f, "BitAnd"),
-            Self::BitOr => write!(f, "BitOr"),
-            Self::BitXor => write!(f, "BitXor"),
-            Self::Shl => write!(f, "Shl"),
-            Self::Shr => write!(f, "Shr"),
-            Self::UShr => write!(f, "UShr"),
-            Self::Eq => write!(f, "Eq"),
-            Self::NotEq => write!(f, "NotEq"),
-            Self::StrictEq => write!(f, "StrictEq"),
-            Self::StrictNotEq => write!(f, "StrictNotEq"),
-            Self::Gt => write!(f, "Gt"),
-            Self::Ge => write!(f, "Ge"),
-            Self::Lt => write!(f, "Lt"),
-            Self::Le => write!(f, "Le"),
-            Self::In => write!(f, "In"),
-            Self::InstanceOf => write!(f, "InstanceOf"),
-            Self::Void => write!(f, "Void"),
-            Self::TypeOf => write!(f, "TypeOf"),
-            Self::Pos => write!(f, "Pos"),
-            Self::Neg => write!(f, "Neg"),
-            Self::BitNot => write!(f, "BitNot"),
-            Self::Not => write!(f, "Not"),
-            Self::DefVar(name) => write!(f, "DefVar({})", name),
-            Self::DefLet(name) => write!(f, "DefLet({})", name),
-            Self::DefConst(name) => write!(f, "DefConst({})", name),
-            Self::InitLexical(value) => write!(f, "InitLexical({})", value),
-        }
+        // An instruction is displayed as its name followed by its operands, like `String(0)`.
+        f.pad(&format!("{:?}", self))
     }
 }
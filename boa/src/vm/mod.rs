@@ -1,27 +1,73 @@
 //! The Virtual Machine (VM) handles generating instructions, then executing them.
 //! This module will provide an instruction set for the AST to use, various traits, plus an interpreter to execute those instructions
 
-use crate::{environment::lexical_environment::VariableScope, BoaProfiler, Context, Result, Value};
+use crate::{
+    builtins::{
+        function::set_method_name,
+        iterable::{get_iterator, IteratorRecord},
+        Array, ForInIterator,
+    },
+    environment::{
+        declarative_environment_record::DeclarativeEnvironmentRecord,
+        lexical_environment::VariableScope, object_environment_record::ObjectEnvironmentRecord,
+    },
+    property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
+    syntax::ast::node::{declaration::BindingKind, MethodDefinitionKind},
+    BoaProfiler, Context, Executable, Result, Value,
+};
 
+pub(crate) mod cache;
+pub(crate) mod code_block;
 pub(crate) mod compilation;
 pub(crate) mod instructions;
+pub(crate) mod serialization;
 
+#[cfg(test)]
+mod tests;
+
+pub use cache::{CodeCache, SourceHash};
+pub use code_block::CodeBlock;
 pub use compilation::Compiler;
 pub use instructions::Instruction;
-use std::time::{Duration, Instant};
+pub use serialization::{DecodeError, FORMAT_VERSION, MAGIC};
+use std::{
+    ops::Range,
+    time::{Duration, Instant},
+};
 
 /// Virtual Machine.
 #[derive(Debug)]
 pub struct VM<'a> {
     ctx: &'a mut Context,
     idx: usize,
-    instructions: Vec<Instruction>,
-    pool: Vec<Value>,
+    code: &'a CodeBlock,
     stack: Vec<Value>,
-    stack_pointer: usize,
+    /// The value of the last expression statement.
+    completion: Value,
+    handlers: Vec<Handler>,
+    /// The iterators of the `for...in` and `for...of` loops being run.
+    iterators: Vec<IteratorRecord>,
+    /// The depth of the environment stack when the VM started.
+    environments: usize,
+    /// The number of nodes being evaluated in the current frame when the VM started.
+    span_depth: usize,
+    /// The instructions evaluating the same node as the current one.
+    span_range: Range<usize>,
     profile: Profiler,
     is_trace: bool,
 }
+
+/// An exception handler installed by [`Instruction::TryStart`].
+///
+/// It records the state to restore when an exception is caught.
+#[derive(Debug, Clone, Copy)]
+struct Handler {
+    address: usize,
+    stack_len: usize,
+    environments: usize,
+    iterators: usize,
+}
+
 /// This profiler is used to output trace information when `--trace` is provided by the CLI or trace is set to `true` on the [`VM`] object
 #[derive(Debug)]
 struct Profiler {
@@ -32,15 +78,19 @@ struct Profiler {
 }
 
 impl<'a> VM<'a> {
-    pub fn new(compiler: Compiler, ctx: &'a mut Context) -> Self {
+    pub fn new(code: &'a CodeBlock, ctx: &'a mut Context) -> Self {
         let trace = ctx.trace;
         Self {
             ctx,
             idx: 0,
-            instructions: compiler.instructions,
-            pool: compiler.pool,
+            code,
             stack: vec![],
-            stack_pointer: 0,
+            completion: Value::undefined(),
+            handlers: Vec::new(),
+            iterators: Vec::new(),
+            environments: 0,
+            span_depth: 0,
+            span_range: 0..0,
             is_trace: trace,
             profile: Profiler {
                 instant: Instant::now(),
@@ -67,220 +117,705 @@ impl<'a> VM<'a> {
         self.stack.pop().unwrap()
     }
 
+    /// Get the value on top of the stack.
+    ///
+    /// # Panics
+    ///
+    /// If the stack is empty, then this will panic.
+    #[inline]
+    fn peek(&self) -> &Value {
+        self.stack.last().unwrap()
+    }
+
+    /// Pops the given number of values off the stack, keeping their order.
+    #[inline]
+    fn pop_n(&mut self, n: usize) -> Vec<Value> {
+        self.stack.split_off(self.stack.len() - n)
+    }
+
+    /// Gets the name at the index in the pool.
+    #[inline]
+    fn name(&self, index: usize) -> &'a str {
+        let code = self.code;
+        code.pool[index]
+            .as_string()
+            .expect("names in the pool must be strings")
+    }
+
+    fn environment_depth(&self) -> usize {
+        self.ctx.realm.environment.environment_stack.len()
+    }
+
+    /// Runs the code, returning the value of a `return` statement or, for a script, the value of
+    /// its last expression statement.
     pub fn run(&mut self) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("runVM", "vm");
         self.idx = 0;
+        self.environments = self.environment_depth();
+        self.span_depth = self.ctx.executor().call_stack.span_depth();
+        self.span_range = 0..0;
+
+        let result = loop {
+            if self.idx >= self.code.instructions.len() {
+                break Ok(self.completion.clone());
+            }
+
+            self.update_span();
 
-        while self.idx < self.instructions.len() {
             if self.is_trace {
                 self.trace_print(false);
             };
 
-            let _timer =
-                BoaProfiler::global().start_event(&self.instructions[self.idx].to_string(), "vm");
-
-            macro_rules! bin_op {
-                ($op:ident) => {{
-                    let r = self.pop();
-                    let l = self.pop();
-                    let val = l.$op(&r, self.ctx)?;
-                    Some(val.into())
-                }};
-            }
-            let result = match self.instructions[self.idx] {
-                Instruction::Undefined => Some(Value::undefined()),
-                Instruction::Null => Some(Value::null()),
-                Instruction::True => Some(Value::boolean(true)),
-                Instruction::False => Some(Value::boolean(false)),
-                Instruction::Zero => Some(Value::integer(0)),
-                Instruction::One => Some(Value::integer(1)),
-                Instruction::Int32(i) => Some(Value::integer(i)),
-                Instruction::Rational(r) => Some(Value::rational(r)),
-                Instruction::String(index) => Some(self.pool[index].clone()),
-                Instruction::BigInt(index) => Some(self.pool[index].clone()),
-                Instruction::Add => {
-                    bin_op!(add)
-                }
-                Instruction::Sub => {
-                    bin_op!(sub)
-                }
-                Instruction::Mul => {
-                    bin_op!(mul)
-                }
-                Instruction::Div => {
-                    bin_op!(div)
-                }
-                Instruction::Pow => {
-                    bin_op!(pow)
-                }
-                Instruction::Mod => {
-                    bin_op!(rem)
-                }
-                Instruction::BitAnd => {
-                    bin_op!(bitand)
-                }
-                Instruction::BitOr => {
-                    bin_op!(bitor)
-                }
-                Instruction::BitXor => {
-                    bin_op!(bitxor)
-                }
-                Instruction::Shl => {
-                    bin_op!(shl)
-                }
-                Instruction::Shr => {
-                    bin_op!(shr)
-                }
-                Instruction::UShr => {
-                    bin_op!(ushr)
-                }
-                Instruction::Eq => {
-                    let r = self.pop();
-                    let l = self.pop();
-                    Some((l.equals(&r, self.ctx)?).into())
-                }
-                Instruction::NotEq => {
-                    let r = self.pop();
-                    let l = self.pop();
-                    Some((!l.equals(&r, self.ctx)?).into())
-                }
-                Instruction::StrictEq => {
-                    let r = self.pop();
-                    let l = self.pop();
-                    Some((l.strict_equals(&r)).into())
-                }
-                Instruction::StrictNotEq => {
-                    let r = self.pop();
-                    let l = self.pop();
-                    Some((!l.strict_equals(&r)).into())
-                }
-                Instruction::Gt => {
-                    bin_op!(gt)
-                }
-                Instruction::Ge => {
-                    bin_op!(ge)
-                }
-                Instruction::Lt => {
-                    bin_op!(lt)
-                }
-                Instruction::Le => {
-                    bin_op!(le)
-                }
-                Instruction::In => {
-                    let r = self.pop();
-                    let l = self.pop();
-
-                    if !r.is_object() {
-                        return self.ctx.throw_type_error(format!(
-                            "right-hand side of 'in' should be an object, got {}",
-                            r.get_type().as_str()
-                        ));
+            match self.execute_instruction() {
+                Ok(Some(value)) => break Ok(value),
+                Ok(None) => {}
+                Err(error) => {
+                    if let Some(handler) = self.handlers.pop() {
+                        self.catch(handler, error);
+                    } else {
+                        self.close_iterators(0, &error);
+                        break Err(error);
                     }
-                    let key = l.to_property_key(self.ctx)?;
-                    Some(self.ctx.has_property(&r, &key).into())
                 }
-                Instruction::InstanceOf => {
-                    let r = self.pop();
-                    let _l = self.pop();
-                    if !r.is_object() {
-                        return self.ctx.throw_type_error(format!(
-                            "right-hand side of 'instanceof' should be an object, got {}",
-                            r.get_type().as_str()
-                        ));
-                    }
+            }
+        };
 
-                    // spec: https://tc39.es/ecma262/#sec-instanceofoperator
-                    todo!("instanceof operator")
+        // No matter how control leaves the code, the environments it pushed are popped.
+        while self.environment_depth() > self.environments {
+            self.ctx.pop_environment();
+        }
+        self.ctx
+            .executor()
+            .call_stack
+            .truncate_spans(self.span_depth);
+
+        if self.is_trace {
+            self.trace_print(true);
+        };
+        result
+    }
+
+    /// Records the span of the node evaluated by the current instruction in the call stack, when
+    /// it changes.
+    fn update_span(&mut self) {
+        if self.span_range.contains(&self.idx) {
+            return;
+        }
+        let (span, range) = self.code.span_at(self.idx);
+        self.span_range = range;
+
+        let call_stack = &mut self.ctx.executor().call_stack;
+        call_stack.truncate_spans(self.span_depth);
+        if let Some(span) = span {
+            call_stack.enter(span);
+        }
+    }
+
+    /// Restores the state recorded by the exception handler, then jumps to it with the exception
+    /// on the stack.
+    fn catch(&mut self, handler: Handler, error: Value) {
+        self.close_iterators(handler.iterators, &error);
+        self.stack.truncate(handler.stack_len);
+        while self.environment_depth() > handler.environments {
+            self.ctx.pop_environment();
+        }
+        self.push(error);
+        self.idx = handler.address;
+    }
+
+    /// Closes the iterators above `len`, which are left by an exception.
+    fn close_iterators(&mut self, len: usize, error: &Value) {
+        while self.iterators.len() > len {
+            let iterator = self.iterators.pop().expect("iterator stack is not empty");
+            // The exception takes precedence over any error thrown while closing.
+            let _ = iterator.close(Err(error.clone()), self.ctx);
+        }
+    }
+
+    /// Executes the current instruction and moves to the next one.
+    ///
+    /// Returns the value of the code if the instruction returns from it.
+    fn execute_instruction(&mut self) -> Result<Option<Value>> {
+        let _timer =
+            BoaProfiler::global().start_event(&self.code.instructions[self.idx].to_string(), "vm");
+
+        macro_rules! bin_op {
+            ($op:ident) => {{
+                let r = self.pop();
+                let l = self.pop();
+                let val = l.$op(&r, self.ctx)?;
+                Some(val.into())
+            }};
+        }
+        let instruction = self.code.instructions[self.idx];
+        self.idx += 1;
+        let result = match instruction {
+            Instruction::Undefined => Some(Value::undefined()),
+            Instruction::Null => Some(Value::null()),
+            Instruction::True => Some(Value::boolean(true)),
+            Instruction::False => Some(Value::boolean(false)),
+            Instruction::Zero => Some(Value::integer(0)),
+            Instruction::One => Some(Value::integer(1)),
+            Instruction::Int32(i) => Some(Value::integer(i)),
+            Instruction::Rational(r) => Some(Value::rational(r)),
+            Instruction::String(index) => Some(self.code.pool[index].clone()),
+            Instruction::BigInt(index) => Some(self.code.pool[index].clone()),
+            Instruction::Pop => {
+                self.pop();
+                None
+            }
+            Instruction::Dup => Some(self.peek().clone()),
+            Instruction::Dup2 => {
+                let len = self.stack.len();
+                let values = self.stack[len - 2..].to_vec();
+                self.stack.extend(values);
+                None
+            }
+            Instruction::Swap => {
+                let len = self.stack.len();
+                self.stack.swap(len - 1, len - 2);
+                None
+            }
+            Instruction::RotateDown(n) => {
+                let value = self.pop();
+                let index = self.stack.len() + 1 - n;
+                self.stack.insert(index, value);
+                None
+            }
+            Instruction::RotateUp(n) => {
+                let value = self.stack.remove(self.stack.len() - n);
+                Some(value)
+            }
+            Instruction::Add => {
+                bin_op!(add)
+            }
+            Instruction::Sub => {
+                bin_op!(sub)
+            }
+            Instruction::Mul => {
+                bin_op!(mul)
+            }
+            Instruction::Div => {
+                bin_op!(div)
+            }
+            Instruction::Pow => {
+                bin_op!(pow)
+            }
+            Instruction::Mod => {
+                bin_op!(rem)
+            }
+            Instruction::BitAnd => {
+                bin_op!(bitand)
+            }
+            Instruction::BitOr => {
+                bin_op!(bitor)
+            }
+            Instruction::BitXor => {
+                bin_op!(bitxor)
+            }
+            Instruction::Shl => {
+                bin_op!(shl)
+            }
+            Instruction::Shr => {
+                bin_op!(shr)
+            }
+            Instruction::UShr => {
+                bin_op!(ushr)
+            }
+            Instruction::Eq => {
+                let r = self.pop();
+                let l = self.pop();
+                Some((l.equals(&r, self.ctx)?).into())
+            }
+            Instruction::NotEq => {
+                let r = self.pop();
+                let l = self.pop();
+                Some((!l.equals(&r, self.ctx)?).into())
+            }
+            Instruction::StrictEq => {
+                let r = self.pop();
+                let l = self.pop();
+                Some((l.strict_equals(&r)).into())
+            }
+            Instruction::StrictNotEq => {
+                let r = self.pop();
+                let l = self.pop();
+                Some((!l.strict_equals(&r)).into())
+            }
+            Instruction::Gt => {
+                bin_op!(gt)
+            }
+            Instruction::Ge => {
+                bin_op!(ge)
+            }
+            Instruction::Lt => {
+                bin_op!(lt)
+            }
+            Instruction::Le => {
+                bin_op!(le)
+            }
+            Instruction::In => {
+                let r = self.pop();
+                let l = self.pop();
+
+                if !r.is_object() {
+                    return Err(self.ctx.construct_type_error(format!(
+                        "right-hand side of 'in' should be an object, got {}",
+                        r.get_type().as_str()
+                    )));
+                }
+                let key = l.to_property_key(self.ctx)?;
+                Some(self.ctx.has_property(&r, &key)?.into())
+            }
+            Instruction::InstanceOf => {
+                bin_op!(instance_of)
+            }
+            Instruction::Void => {
+                let _value = self.pop();
+                Some(Value::undefined())
+            }
+            Instruction::TypeOf => {
+                let value = self.pop();
+                Some(value.get_type().as_str().into())
+            }
+            Instruction::Pos => {
+                let value = self.pop();
+                let value = value.to_number(self.ctx)?;
+                Some(value.into())
+            }
+            Instruction::Neg => {
+                let value = self.pop();
+                Some(value.neg(self.ctx)?)
+            }
+            Instruction::Not => {
+                let value = self.pop();
+                Some((!value.to_boolean()).into())
+            }
+            Instruction::BitNot => {
+                let target = self.pop();
+                let num = target.to_number(self.ctx)?;
+                let value = if num.is_nan() {
+                    -1
+                } else {
+                    // TODO: this is not spec compliant.
+                    !(num as i32)
+                };
+                Some(value.into())
+            }
+            Instruction::ToNumber => {
+                let value = self.pop();
+                Some(value.to_number(self.ctx)?.into())
+            }
+            Instruction::ToString => {
+                let value = self.pop();
+                Some(value.to_string(self.ctx)?.into())
+            }
+            Instruction::Inc => {
+                let value = self.pop();
+                Some((value.to_number(self.ctx)? + 1.0).into())
+            }
+            Instruction::Dec => {
+                let value = self.pop();
+                Some((value.to_number(self.ctx)? - 1.0).into())
+            }
+            Instruction::DefVar(name_index) => {
+                let name = self.name(name_index);
+
+                // Redeclaring a variable keeps its value
+                if !self.ctx.has_var_scope_binding(name) {
+                    self.ctx.create_mutable_binding(
+                        name.to_string(),
+                        false,
+                        VariableScope::Function,
+                    )?;
+                    self.ctx.initialize_binding(name, Value::undefined())?;
+                }
+
+                None
+            }
+            Instruction::DefLet(name_index) => {
+                let name = self.name(name_index);
+
+                self.ctx
+                    .create_mutable_binding(name.to_string(), false, VariableScope::Block)?;
+
+                None
+            }
+            Instruction::DefConst(name_index) => {
+                let name = self.name(name_index);
+
+                // Assignments to constants always throw, even in sloppy mode code.
+                self.ctx
+                    .create_immutable_binding(name.to_string(), true, VariableScope::Block)?;
+
+                None
+            }
+            Instruction::InitLexical(name_index) => {
+                let name = self.name(name_index);
+                let value = self.peek().clone();
+                self.ctx.initialize_binding(name, value)?;
+
+                None
+            }
+            Instruction::GetName(name_index) => {
+                let name = self.name(name_index);
+                Some(self.ctx.get_binding_value(name)?)
+            }
+            Instruction::GetWithBaseObject(name_index) => {
+                let name = self.name(name_index);
+                Some(
+                    self.ctx
+                        .get_with_base_object(name)?
+                        .map_or(Value::Undefined, Value::from),
+                )
+            }
+            Instruction::SetName(name_index) => {
+                let name = self.name(name_index);
+                let value = self.peek().clone();
+                BindingKind::Assignment.initialize(name, value, self.ctx)?;
+
+                None
+            }
+            Instruction::BindVar(index) => self.bind(index, BindingKind::Var)?,
+            Instruction::BindLet(index) => self.bind(index, BindingKind::Let)?,
+            Instruction::BindConst(index) => self.bind(index, BindingKind::Const)?,
+            Instruction::BindAssign(index) => self.bind(index, BindingKind::Assignment)?,
+            Instruction::This => Some(self.ctx.get_this_binding()?),
+            Instruction::GetPropertyByName(name_index) => {
+                let object = self.pop().to_object(self.ctx)?;
+                let name = self.name(name_index);
+                Some(Value::from(object).get_field(name, self.ctx)?)
+            }
+            Instruction::GetPropertyByValue => {
+                let key = self.pop();
+                let object = self.pop().to_object(self.ctx)?;
+                let key = key.to_property_key(self.ctx)?;
+                Some(Value::from(object).get_field(key, self.ctx)?)
+            }
+            Instruction::SetPropertyByName(name_index) => {
+                let value = self.pop();
+                let object = self.pop();
+                let name = self.name(name_index);
+                Some(object.put_value(name, value, self.ctx)?)
+            }
+            Instruction::SetPropertyByValue => {
+                let value = self.pop();
+                let key = self.pop();
+                let object = self.pop();
+                let key = key.to_property_key(self.ctx)?;
+                Some(object.put_value(key, value, self.ctx)?)
+            }
+            Instruction::DeletePropertyByName(name_index) => {
+                let object = self.pop();
+                let name = self.name(name_index);
+                Some(object.delete_property(name, self.ctx)?.into())
+            }
+            Instruction::DeletePropertyByValue => {
+                let key = self.pop();
+                let object = self.pop();
+                let key = key.to_property_key(self.ctx)?;
+                Some(object.delete_property(key, self.ctx)?.into())
+            }
+            Instruction::NewObject => Some(Value::new_object(self.ctx)),
+            Instruction::DefineOwnPropertyByName(name_index) => {
+                let value = self.pop();
+                let name = self.name(name_index);
+                self.peek()
+                    .set_property(name, DataDescriptor::new(value, Attribute::all()));
+                None
+            }
+            Instruction::DefineMethodByName(name_index) => {
+                let name = self.name(name_index);
+                let function = self.method(MethodDefinitionKind::Ordinary, name)?;
+                self.peek()
+                    .set_property(name, DataDescriptor::new(function, Attribute::all()));
+                None
+            }
+            Instruction::DefineGetterByName(name_index) => {
+                let name = self.name(name_index);
+                let function = self.method(MethodDefinitionKind::Get, name)?;
+                let key = PropertyKey::from(name);
+                let object = self.peek();
+                let set = object
+                    .get_property(key.clone())
+                    .as_ref()
+                    .and_then(|p| p.as_accessor_descriptor())
+                    .and_then(|a| a.setter().cloned());
+                object.set_property(
+                    key,
+                    PropertyDescriptor::Accessor(AccessorDescriptor {
+                        get: function.as_object(),
+                        set,
+                        attributes: Attribute::WRITABLE
+                            | Attribute::ENUMERABLE
+                            | Attribute::CONFIGURABLE,
+                    }),
+                );
+                None
+            }
+            Instruction::DefineSetterByName(name_index) => {
+                let name = self.name(name_index);
+                let function = self.method(MethodDefinitionKind::Set, name)?;
+                let key = PropertyKey::from(name);
+                let object = self.peek();
+                let get = object
+                    .get_property(key.clone())
+                    .as_ref()
+                    .and_then(|p| p.as_accessor_descriptor())
+                    .and_then(|a| a.getter().cloned());
+                object.set_property(
+                    key,
+                    PropertyDescriptor::Accessor(AccessorDescriptor {
+                        get,
+                        set: function.as_object(),
+                        attributes: Attribute::WRITABLE
+                            | Attribute::ENUMERABLE
+                            | Attribute::CONFIGURABLE,
+                    }),
+                );
+                None
+            }
+            Instruction::NewArray => Some(Array::new_array(self.ctx)),
+            Instruction::PushArrayElement => {
+                let value = self.pop();
+                let array = self.peek().clone();
+                Array::add_to_array_object(&array, &[value], self.ctx)?;
+                None
+            }
+            Instruction::PushIterableToArray => {
+                let iterable = self.pop();
+                let values = self.collect_iterable(iterable)?;
+                let array = self.peek().clone();
+                Array::add_to_array_object(&array, &values, self.ctx)?;
+                None
+            }
+            Instruction::PushHoleToArray => {
+                let array = self.peek().clone();
+                Array::add_hole_to_array_object(&array, self.ctx)?;
+                None
+            }
+            Instruction::Call(argc) => {
+                let args = self.pop_n(argc);
+                Some(self.call(&args)?)
+            }
+            Instruction::CallWithArray => {
+                let array = self.pop();
+                let args = self.array_elements(&array)?;
+                Some(self.call(&args)?)
+            }
+            Instruction::New(argc) => {
+                let args = self.pop_n(argc);
+                Some(self.construct(&args)?)
+            }
+            Instruction::NewWithArray => {
+                let array = self.pop();
+                let args = self.array_elements(&array)?;
+                Some(self.construct(&args)?)
+            }
+            Instruction::GetFunction(index) => {
+                let code = self.code.functions[index].clone();
+                Some(self.ctx.create_compiled_function(code)?)
+            }
+            Instruction::Evaluate(index) => {
+                let code = self.code;
+                Some(code.nodes[index].run(self.ctx)?)
+            }
+            Instruction::Jump(address) => {
+                self.idx = address;
+                None
+            }
+            Instruction::JumpIfFalse(address) => {
+                if !self.pop().to_boolean() {
+                    self.idx = address;
                 }
-                Instruction::Void => {
-                    let _value = self.pop();
-                    Some(Value::undefined())
+                None
+            }
+            Instruction::JumpIfTrue(address) => {
+                if self.pop().to_boolean() {
+                    self.idx = address;
                 }
-                Instruction::TypeOf => {
-                    let value = self.pop();
-                    Some(value.get_type().as_str().into())
+                None
+            }
+            Instruction::LogicalAnd(address) => {
+                if !self.peek().to_boolean() {
+                    self.idx = address;
+                } else {
+                    self.pop();
                 }
-                Instruction::Pos => {
-                    let value = self.pop();
-                    let value = value.to_number(self.ctx)?;
-                    Some(value.into())
+                None
+            }
+            Instruction::LogicalOr(address) => {
+                if self.peek().to_boolean() {
+                    self.idx = address;
+                } else {
+                    self.pop();
                 }
-                Instruction::Neg => {
-                    let value = self.pop();
-                    Some(Value::from(!value.to_boolean()))
+                None
+            }
+            Instruction::Coalesce(address) => {
+                if !self.peek().is_null_or_undefined() {
+                    self.idx = address;
+                } else {
+                    self.pop();
                 }
-                Instruction::Not => {
-                    let value = self.pop();
-                    Some((!value.to_boolean()).into())
+                None
+            }
+            Instruction::JumpIfNullOrUndefined(address) => {
+                if self.peek().is_null_or_undefined() {
+                    self.idx = address;
                 }
-                Instruction::BitNot => {
-                    let target = self.pop();
-                    let num = target.to_number(self.ctx)?;
-                    let value = if num.is_nan() {
-                        -1
-                    } else {
-                        // TODO: this is not spec compliant.
-                        !(num as i32)
-                    };
-                    Some(value.into())
+                None
+            }
+            Instruction::PopCompletion => {
+                self.completion = self.pop();
+                None
+            }
+            Instruction::Return => return Ok(Some(self.pop())),
+            Instruction::Throw => return Err(self.pop()),
+            Instruction::TryStart(address) => {
+                self.handlers.push(Handler {
+                    address,
+                    stack_len: self.stack.len(),
+                    environments: self.environment_depth(),
+                    iterators: self.iterators.len(),
+                });
+                None
+            }
+            Instruction::TryEnd => {
+                self.handlers.pop();
+                None
+            }
+            Instruction::PushDeclarativeEnvironment => {
+                let env = self.ctx.get_current_environment();
+                self.ctx
+                    .push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
+                None
+            }
+            Instruction::PushWithEnvironment => {
+                let object = self.pop().to_object(self.ctx)?;
+                let env = self.ctx.get_current_environment();
+                self.ctx.push_environment(ObjectEnvironmentRecord::new(
+                    object.into(),
+                    true,
+                    Some(env),
+                ));
+                None
+            }
+            Instruction::PopEnvironment => {
+                self.ctx.pop_environment();
+                None
+            }
+            Instruction::GetIterator => {
+                let iterable = self.pop();
+                let iterator = get_iterator(self.ctx, iterable)?;
+                self.iterators.push(iterator);
+                None
+            }
+            Instruction::ForInIterator(address) => {
+                let object = self.pop();
+                if object.is_null_or_undefined() {
+                    self.idx = address;
+                } else {
+                    let object = object.to_object(self.ctx)?;
+                    let iterator =
+                        ForInIterator::create_for_in_iterator(self.ctx, Value::from(object));
+                    let next_function = iterator.get_field("next", self.ctx)?;
+                    self.iterators
+                        .push(IteratorRecord::new(iterator, next_function));
+                }
+                None
+            }
+            Instruction::IteratorNext(address) => {
+                let iterator = self.iterators.last().expect("no iterator to advance");
+                let result = match iterator.next(self.ctx) {
+                    Ok(result) => result,
+                    Err(error) => {
+                        // An iterator that throws is not closed.
+                        self.iterators.pop();
+                        return Err(error);
+                    }
+                };
+                if result.is_done() {
+                    self.iterators.pop();
+                    self.idx = address;
+                    None
+                } else {
+                    Some(result.value())
                 }
-                Instruction::DefVar(name_index) => {
-                    let name: String = self.pool[name_index].to_string(self.ctx)?.to_string();
+            }
+            Instruction::IteratorClose => {
+                let iterator = self.iterators.pop().expect("no iterator to close");
+                iterator.close(Ok(Value::undefined()), self.ctx)?;
+                None
+            }
+        };
 
-                    self.ctx
-                        .realm_mut()
-                        .environment
-                        .create_mutable_binding(name.to_string(), false, VariableScope::Function)
-                        .map_err(|e| e.to_error(self.ctx))?;
+        if let Some(value) = result {
+            self.push(value);
+        }
 
-                    None
-                }
-                Instruction::DefLet(name_index) => {
-                    let name = self.pool[name_index].to_string(self.ctx)?;
+        Ok(None)
+    }
 
-                    self.ctx
-                        .realm_mut()
-                        .environment
-                        .create_mutable_binding(name.to_string(), false, VariableScope::Block)
-                        .map_err(|e| e.to_error(self.ctx))?;
+    /// Binds the value on top of the stack to the pattern at the index.
+    fn bind(&mut self, index: usize, kind: BindingKind) -> Result<Option<Value>> {
+        let value = self.pop();
+        let code = self.code;
+        code.patterns[index].bind(value, kind, self.ctx)?;
+        Ok(None)
+    }
 
-                    None
-                }
-                Instruction::DefConst(name_index) => {
-                    let name = self.pool[name_index].to_string(self.ctx)?;
+    /// Pops a method, making the object below it its home object and naming it after its key.
+    fn method(&mut self, kind: MethodDefinitionKind, key: &str) -> Result<Value> {
+        let function = self.pop();
+        set_method_name(&function, kind, key);
+        // Methods can use `super` to access the prototype of the object
+        if let (Some(function), Some(home_object)) = (function.as_object(), self.peek().as_object())
+        {
+            if let Some(function) = function.borrow_mut().as_function_mut() {
+                function.set_home_object(home_object);
+            }
+        }
+        Ok(function)
+    }
 
-                    self.ctx
-                        .realm_mut()
-                        .environment
-                        .create_immutable_binding(name.to_string(), false, VariableScope::Block)
-                        .map_err(|e| e.to_error(self.ctx))?;
+    /// Calls the function below the arguments, with the `this` value below it.
+    fn call(&mut self, args: &[Value]) -> Result<Value> {
+        let function = self.pop();
+        let this = self.pop();
+        self.ctx.call(&function, &this, args)
+    }
 
-                    None
-                }
-                Instruction::InitLexical(name_index) => {
-                    let name = self.pool[name_index].to_string(self.ctx)?;
-                    let value = self.pop();
-                    self.ctx
-                        .realm_mut()
-                        .environment
-                        .initialize_binding(&name, value.clone())
-                        .map_err(|e| e.to_error(self.ctx))?;
+    /// Constructs an object from the function below the arguments.
+    fn construct(&mut self, args: &[Value]) -> Result<Value> {
+        match self.pop() {
+            Value::Object(ref object) => object.construct(args, object.clone().into(), self.ctx),
+            function => Err(self
+                .ctx
+                .construct_type_error(format!("{} is not a constructor", function.display()))),
+        }
+    }
 
-                    Some(value)
-                }
-            };
-            if let Some(value) = result {
-                self.push(value);
+    /// Collects the values of an iterable, like a spread element.
+    fn collect_iterable(&mut self, iterable: Value) -> Result<Vec<Value>> {
+        let iterator = get_iterator(self.ctx, iterable)?;
+        let mut values = Vec::new();
+        loop {
+            let next = iterator.next(self.ctx)?;
+            if next.is_done() {
+                break;
             }
-
-            self.idx += 1;
+            values.push(next.value());
         }
+        Ok(values)
+    }
 
-        if self.is_trace {
-            self.trace_print(true);
-        };
-        let res = self.pop();
-        Ok(res)
+    /// Gets the elements of an array built by the VM.
+    fn array_elements(&mut self, array: &Value) -> Result<Vec<Value>> {
+        let length = array.get_field("length", self.ctx)?.to_length(self.ctx)?;
+        (0..length)
+            .map(|index| array.get_field(index, self.ctx))
+            .collect()
     }
 
     pub fn trace_print(&mut self, end: bool) {
@@ -311,7 +846,7 @@ impl<'a> VM<'a> {
                 "{0:<20}  {1}",
                 format!(
                     "{:<20}",
-                    self.instructions[if end { self.idx - 1 } else { self.idx }]
+                    self.code.instructions[if end { self.idx - 1 } else { self.idx }]
                 ),
                 match self.stack.last() {
                     None => "<empty>".to_string(),
@@ -323,7 +858,7 @@ impl<'a> VM<'a> {
         if end {
             println!();
             println!("Pool");
-            for (i, val) in self.pool.iter().enumerate() {
+            for (i, val) in self.code.pool.iter().enumerate() {
                 println!("{:<10} {:<10} {:p}", i, val.display(), val);
             }
 
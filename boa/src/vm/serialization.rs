@@ -0,0 +1,300 @@
+//! Serialization of compiled [`CodeBlock`]s to a versioned binary format.
+//!
+//! An encoded code block starts with a header made of the [`MAGIC`] bytes and the
+//! [`FORMAT_VERSION`] as a little endian `u32`, followed by the version of the engine that
+//! produced it and the code block itself. Since the code block embeds the parts of the AST that
+//! are not compiled to bytecode, code blocks can only be loaded by the same version of the engine
+//! that produced them.
+
+use super::{CodeBlock, Instruction};
+use crate::{
+    builtins::{function::FunctionFlags, BigInt},
+    Value,
+};
+use bincode::Options;
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+use std::{error, fmt, rc::Rc};
+
+/// The bytes every encoded code block starts with.
+pub const MAGIC: [u8; 4] = *b"BOAC";
+
+/// The version of the encoding.
+///
+/// It must be incremented whenever the layout of the encoded code blocks changes.
+pub const FORMAT_VERSION: u32 = 3;
+
+/// The version of the engine, encoded after the header.
+const ENGINE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The length of the header, in bytes.
+const HEADER_LEN: usize = MAGIC.len() + 4;
+
+/// An error that occurred while decoding a code block.
+#[derive(Debug)]
+pub enum DecodeError {
+    /// The bytes do not start with the [`MAGIC`] bytes.
+    InvalidMagic,
+    /// The bytes were encoded with another version of the format.
+    UnsupportedVersion(u32),
+    /// The bytes were encoded by another version of the engine.
+    EngineMismatch(Box<str>),
+    /// The encoded code block is malformed.
+    Malformed(bincode::Error),
+    /// An instruction of the code block refers to data the code block does not have.
+    InvalidCode(Box<str>),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidMagic => f.write_str("not an encoded code block"),
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "unsupported code block format version {}, expected {}",
+                version, FORMAT_VERSION
+            ),
+            Self::EngineMismatch(version) => write!(
+                f,
+                "code block encoded by engine version {}, expected {}",
+                version, ENGINE_VERSION
+            ),
+            Self::Malformed(err) => write!(f, "malformed code block: {}", err),
+            Self::InvalidCode(reason) => write!(f, "invalid code block: {}", reason),
+        }
+    }
+}
+
+impl error::Error for DecodeError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        if let Self::Malformed(err) = self {
+            Some(err)
+        } else {
+            None
+        }
+    }
+}
+
+impl CodeBlock {
+    /// Encodes the code block, and the code blocks of the functions it defines, to bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN);
+        bytes.extend_from_slice(&MAGIC);
+        bytes.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+        options()
+            .serialize_into(&mut bytes, &(ENGINE_VERSION, self))
+            .expect("code blocks only hold serializable data");
+        bytes
+    }
+
+    /// Decodes a code block encoded by [`CodeBlock::to_bytes`].
+    ///
+    /// The decoded code block is validated, so that running it can not index out of its tables or
+    /// jump out of its instructions.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        if bytes.len() < HEADER_LEN || bytes[..MAGIC.len()] != MAGIC {
+            return Err(DecodeError::InvalidMagic);
+        }
+
+        let mut version = [0; 4];
+        version.copy_from_slice(&bytes[MAGIC.len()..HEADER_LEN]);
+        let version = u32::from_le_bytes(version);
+        if version != FORMAT_VERSION {
+            return Err(DecodeError::UnsupportedVersion(version));
+        }
+
+        // The lengths read from the bytes are not trusted to allocate more than the bytes hold.
+        let options = options().with_limit(bytes.len() as u64);
+        let mut body = &bytes[HEADER_LEN..];
+        let engine: Box<str> = options
+            .deserialize_from(&mut body)
+            .map_err(DecodeError::Malformed)?;
+        if &*engine != ENGINE_VERSION {
+            return Err(DecodeError::EngineMismatch(engine));
+        }
+
+        let code: Self = options.deserialize(body).map_err(DecodeError::Malformed)?;
+        code.validate()?;
+        Ok(code)
+    }
+
+    /// Checks that every operand of the instructions is a valid index into the tables of the code
+    /// block or a valid address, and does the same for the code blocks of its functions.
+    fn validate(&self) -> Result<(), DecodeError> {
+        for (address, instruction) in self.instructions.iter().enumerate() {
+            let invalid = |operand: &str, index: usize| {
+                Err(DecodeError::InvalidCode(
+                    format!(
+                        "invalid {} {} in instruction {} at {}",
+                        operand, index, instruction, address
+                    )
+                    .into(),
+                ))
+            };
+            match *instruction {
+                Instruction::String(index)
+                | Instruction::DefVar(index)
+                | Instruction::DefLet(index)
+                | Instruction::DefConst(index)
+                | Instruction::InitLexical(index)
+                | Instruction::GetName(index)
+                | Instruction::GetWithBaseObject(index)
+                | Instruction::SetName(index)
+                | Instruction::GetPropertyByName(index)
+                | Instruction::SetPropertyByName(index)
+                | Instruction::DeletePropertyByName(index)
+                | Instruction::DefineOwnPropertyByName(index)
+                | Instruction::DefineMethodByName(index)
+                | Instruction::DefineGetterByName(index)
+                | Instruction::DefineSetterByName(index)
+                    if !matches!(self.pool.get(index), Some(Value::String(_))) =>
+                {
+                    return invalid("string index", index);
+                }
+                Instruction::BigInt(index)
+                    if !matches!(self.pool.get(index), Some(Value::BigInt(_))) =>
+                {
+                    return invalid("bigint index", index);
+                }
+                Instruction::GetFunction(index) if index >= self.functions.len() => {
+                    return invalid("function index", index);
+                }
+                Instruction::Evaluate(index) if index >= self.nodes.len() => {
+                    return invalid("node index", index);
+                }
+                Instruction::BindVar(index)
+                | Instruction::BindLet(index)
+                | Instruction::BindConst(index)
+                | Instruction::BindAssign(index)
+                    if index >= self.patterns.len() =>
+                {
+                    return invalid("pattern index", index);
+                }
+                // Jumping right after the last instruction ends the code.
+                Instruction::Jump(target)
+                | Instruction::JumpIfFalse(target)
+                | Instruction::JumpIfTrue(target)
+                | Instruction::LogicalAnd(target)
+                | Instruction::LogicalOr(target)
+                | Instruction::Coalesce(target)
+                | Instruction::JumpIfNullOrUndefined(target)
+                | Instruction::TryStart(target)
+                | Instruction::ForInIterator(target)
+                | Instruction::IteratorNext(target)
+                    if target > self.instructions.len() =>
+                {
+                    return invalid("jump target", target);
+                }
+                _ => {}
+            }
+        }
+
+        self.functions
+            .iter()
+            .try_for_each(|function| function.validate())
+    }
+}
+
+/// The options of the encoding, which stores integers with a fixed size.
+fn options() -> impl Options + Copy {
+    bincode::options().with_fixint_encoding()
+}
+
+/// Serializes [`FunctionFlags`] as their bits.
+pub(super) mod flags {
+    use super::*;
+
+    pub(in crate::vm) fn serialize<S>(
+        flags: &FunctionFlags,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        flags.bits().serialize(serializer)
+    }
+
+    pub(in crate::vm) fn deserialize<'de, D>(deserializer: D) -> Result<FunctionFlags, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bits = u8::deserialize(deserializer)?;
+        FunctionFlags::from_bits(bits)
+            .ok_or_else(|| de::Error::custom(format!("invalid function flags {:#010b}", bits)))
+    }
+}
+
+/// A value of the constant pool.
+///
+/// Bigints are stored as their hexadecimal digits, since the digits are read like any other
+/// string, without trusting the encoded length to allocate them.
+#[derive(Serialize, Deserialize)]
+enum Constant {
+    String(Box<str>),
+    BigInt(Box<str>),
+}
+
+/// Serializes the constant pool, which only holds strings and bigints.
+pub(super) mod pool {
+    use super::*;
+
+    pub(in crate::vm) fn serialize<S>(pool: &[Value], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let constants = pool
+            .iter()
+            .map(|value| match value {
+                Value::String(string) => Ok(Constant::String(string.as_str().into())),
+                Value::BigInt(bigint) => Ok(Constant::BigInt(
+                    bigint.as_inner().to_string_radix(16).into(),
+                )),
+                _ => Err(ser::Error::custom(format!(
+                    "unexpected {} in the constant pool",
+                    value.get_type().as_str()
+                ))),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        constants.serialize(serializer)
+    }
+
+    pub(in crate::vm) fn deserialize<'de, D>(deserializer: D) -> Result<Vec<Value>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let constants = Vec::<Constant>::deserialize(deserializer)?;
+        constants
+            .into_iter()
+            .map(|constant| match constant {
+                Constant::String(string) => Ok(Value::from(string)),
+                Constant::BigInt(digits) => BigInt::from_string_radix(&digits, 16)
+                    .map(Value::from)
+                    .ok_or_else(|| de::Error::custom(format!("invalid bigint {}", digits))),
+            })
+            .collect()
+    }
+}
+
+/// Serializes the code blocks of the functions defined by a code block.
+pub(super) mod functions {
+    use super::*;
+
+    pub(in crate::vm) fn serialize<S>(
+        functions: &[Rc<CodeBlock>],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(functions.iter().map(|function| &**function))
+    }
+
+    pub(in crate::vm) fn deserialize<'de, D>(
+        deserializer: D,
+    ) -> Result<Vec<Rc<CodeBlock>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let functions = Vec::<CodeBlock>::deserialize(deserializer)?;
+        Ok(functions.into_iter().map(Rc::new).collect())
+    }
+}
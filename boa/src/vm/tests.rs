@@ -0,0 +1,389 @@
+use super::{CodeBlock, CodeCache, DecodeError, Instruction, FORMAT_VERSION, MAGIC};
+use crate::{exec, Context};
+use std::rc::Rc;
+
+#[test]
+fn identifiers_and_properties() {
+    let scenario = r#"
+        var a = { b: { c: 1 } };
+        let key = "c";
+        a.b[key] += 2;
+        a.b.d = a.b.c * 2;
+        a.b.c + a.b.d;
+    "#;
+
+    assert_eq!(&exec(scenario), "9");
+}
+
+#[test]
+fn update_expressions() {
+    let scenario = r#"
+        let a = 1;
+        let o = { x: 1 };
+        let k = "x";
+        [a++, ++a, o.x++, ++o.x, o[k]--, --o[k], a, o.x].join();
+    "#;
+
+    assert_eq!(&exec(scenario), "\"1,3,1,3,3,1,3,1\"");
+}
+
+#[test]
+fn calls_and_closures() {
+    let scenario = r#"
+        function counter() {
+            let count = 0;
+            return { increment: () => ++count };
+        }
+        let c = counter();
+        c.increment();
+        c.increment();
+    "#;
+
+    assert_eq!(&exec(scenario), "2");
+}
+
+#[test]
+fn array_literal_holes() {
+    let scenario = r#"
+        let arr = [, ...[1, 2], , 3];
+        [arr.length, 0 in arr, 3 in arr, arr[1] + arr[2] + arr[4]].join();
+    "#;
+
+    assert_eq!(&exec(scenario), "\"5,false,false,6\"");
+}
+
+#[test]
+fn recursive_function() {
+    let scenario = r#"
+        function fib(n) {
+            return n < 2 ? n : fib(n - 1) + fib(n - 2);
+        }
+        fib(15);
+    "#;
+
+    assert_eq!(&exec(scenario), "610");
+}
+
+#[test]
+fn method_this_and_constructor() {
+    let scenario = r#"
+        function Point(x, y) {
+            this.x = x;
+            this.y = y;
+        }
+        Point.prototype.sum = function () { return this.x + this.y; };
+        new Point(...[1, 2]).sum();
+    "#;
+
+    assert_eq!(&exec(scenario), "3");
+}
+
+#[test]
+fn loops_with_break_and_continue() {
+    let scenario = r#"
+        let result = "";
+        outer: for (let i = 0; i < 4; i++) {
+            let j = 0;
+            while (true) {
+                j++;
+                if (j > i) continue outer;
+                if (i == 3) break outer;
+                result += i;
+            }
+        }
+        do {
+            result += "!";
+        } while (false);
+        result;
+    "#;
+
+    assert_eq!(&exec(scenario), "\"122!\"");
+}
+
+#[test]
+fn for_of_closes_iterator_on_break() {
+    let scenario = r#"
+        let closed = false;
+        let iterable = {};
+        iterable[Symbol.iterator] = function () {
+            let n = 0;
+            return {
+                next() { return { value: n++, done: false }; },
+                return() { closed = true; return {}; },
+            };
+        };
+        let last;
+        for (const value of iterable) {
+            last = value;
+            if (value == 3) break;
+        }
+        [last, closed].join();
+    "#;
+
+    assert_eq!(&exec(scenario), "\"3,true\"");
+}
+
+#[test]
+fn for_in_loop() {
+    let scenario = r#"
+        let keys = [];
+        for (const key in { a: 1 }) keys.push(key);
+        for (var key in null) keys.push(key);
+        keys.join();
+    "#;
+
+    assert_eq!(&exec(scenario), "\"a\"");
+}
+
+#[test]
+fn try_catch_finally() {
+    let scenario = r#"
+        let log = [];
+        function f() {
+            try {
+                null.x;
+            } catch ({ name }) {
+                log.push(name);
+                return "returned";
+            } finally {
+                log.push("finally");
+            }
+        }
+        log.push(f());
+        try {
+            try {
+                throw 1;
+            } finally {
+                log.push("inner");
+            }
+        } catch (e) {
+            log.push(e);
+        }
+        log.join();
+    "#;
+
+    assert_eq!(&exec(scenario), "\"TypeError,finally,returned,inner,1\"");
+}
+
+#[test]
+fn switch_statement() {
+    let scenario = r#"
+        function test(value) {
+            let result = "";
+            switch (value) {
+                case 1:
+                    result += "one";
+                case 2:
+                    result += "two";
+                    break;
+                default:
+                    result += "default";
+            }
+            return result;
+        }
+        [test(1), test(2), test(3)].join();
+    "#;
+
+    assert_eq!(&exec(scenario), "\"onetwo,two,default\"");
+}
+
+#[test]
+fn interpreted_nodes() {
+    let scenario = r#"
+        class A {
+            constructor() { this.x = 1; }
+        }
+        function* gen() { yield new A().x; }
+        [...gen()][0];
+    "#;
+
+    assert_eq!(&exec(scenario), "1");
+}
+
+#[test]
+fn uncaught_exception() {
+    let scenario = r#"
+        for (const value of [1]) {
+            throw new Error("oops");
+        }
+    "#;
+
+    assert_eq!(&exec(scenario), "\"Error\": \"oops\"");
+}
+
+#[test]
+fn code_block_round_trip() {
+    let scenario = r#"
+        function greet({ name }, ...rest) {
+            return `${name}:${rest.length}`;
+        }
+        class A {
+            constructor() { this.x = 10n; }
+        }
+        let values = [greet({ name: "a" }, 1, 2), new A().x, /a+/g.flags];
+        values.join();
+    "#;
+
+    let mut context = Context::new();
+    let code = context.compile(scenario).unwrap();
+    let bytes = code.to_bytes();
+    assert_eq!(&bytes[..MAGIC.len()], &MAGIC);
+
+    let loaded = CodeBlock::from_bytes(&bytes).unwrap();
+    assert_eq!(loaded.instructions().len(), code.instructions().len());
+    assert_eq!(loaded.to_bytes(), bytes);
+
+    let mut context = Context::new();
+    let value = context.execute(&loaded).unwrap();
+    assert_eq!(value.display().to_string(), "\"a:2,10,g\"");
+}
+
+#[test]
+fn code_block_decode_errors() {
+    let bytes = Context::new().compile("1 + 1").unwrap().to_bytes();
+
+    assert!(matches!(
+        CodeBlock::from_bytes(b"not bytecode"),
+        Err(DecodeError::InvalidMagic)
+    ));
+
+    let mut other_version = bytes.clone();
+    other_version[MAGIC.len()..MAGIC.len() + 4]
+        .copy_from_slice(&(FORMAT_VERSION + 1).to_le_bytes());
+    assert!(matches!(
+        CodeBlock::from_bytes(&other_version),
+        Err(DecodeError::UnsupportedVersion(version)) if version == FORMAT_VERSION + 1
+    ));
+
+    assert!(matches!(
+        CodeBlock::from_bytes(&bytes[..bytes.len() - 1]),
+        Err(DecodeError::Malformed(_))
+    ));
+}
+
+#[test]
+fn decode_truncated_code_block() {
+    let bytes = Context::new()
+        .compile("function f(a, b = 1) { return a + b; } f(1)")
+        .unwrap()
+        .to_bytes();
+
+    for len in 0..bytes.len() {
+        assert!(
+            CodeBlock::from_bytes(&bytes[..len]).is_err(),
+            "length {}",
+            len
+        );
+    }
+}
+
+#[test]
+fn decode_corrupted_code_block() {
+    let bytes = Context::new()
+        .compile("for (var i = 0; i < 2; i++) { [i] = [i + 1n]; }")
+        .unwrap()
+        .to_bytes();
+
+    // Corrupted lengths and operands must be rejected without panicking or allocating what the
+    // bytes do not hold.
+    for index in MAGIC.len() + 4..bytes.len() {
+        for &byte in &[0x00, 0x7f, 0xff] {
+            let mut corrupted = bytes.clone();
+            corrupted[index] = byte;
+            let _ = CodeBlock::from_bytes(&corrupted);
+        }
+    }
+}
+
+#[test]
+fn decode_invalid_operands() {
+    let invalid_blocks = vec![
+        vec![Instruction::String(0)],
+        vec![Instruction::BigInt(0)],
+        vec![Instruction::GetName(1)],
+        vec![Instruction::GetFunction(0)],
+        vec![Instruction::Evaluate(0)],
+        vec![Instruction::BindLet(0)],
+        vec![Instruction::Jump(2)],
+        vec![Instruction::TryStart(usize::MAX)],
+    ];
+    for instructions in invalid_blocks {
+        let code = CodeBlock {
+            instructions,
+            ..CodeBlock::default()
+        };
+        assert!(matches!(
+            CodeBlock::from_bytes(&code.to_bytes()),
+            Err(DecodeError::InvalidCode(_))
+        ));
+    }
+
+    // The code blocks of the functions are validated as well.
+    let code = CodeBlock {
+        instructions: vec![Instruction::GetFunction(0), Instruction::Jump(2)],
+        functions: vec![Rc::new(CodeBlock {
+            instructions: vec![Instruction::Evaluate(0)],
+            ..CodeBlock::default()
+        })],
+        ..CodeBlock::default()
+    };
+    assert!(matches!(
+        CodeBlock::from_bytes(&code.to_bytes()),
+        Err(DecodeError::InvalidCode(_))
+    ));
+}
+
+#[test]
+fn code_cache() {
+    let source = "var count = (typeof count === 'number' ? count : 0) + 1; count";
+
+    let mut context = Context::new();
+    assert_eq!(context.eval(source).unwrap().as_number(), Some(1.0));
+    assert_eq!(context.code_cache().len(), 1);
+    let cached = context.code_cache_mut().get(source).unwrap();
+
+    // Evaluating the same source again runs the cached code block.
+    assert_eq!(context.eval(source).unwrap().as_number(), Some(2.0));
+    assert_eq!(context.code_cache().len(), 1);
+    assert!(Rc::ptr_eq(&cached, &context.compile(source).unwrap()));
+
+    // A preloaded cache lets the context skip the parser.
+    let mut other = Context::new();
+    other.code_cache_mut().insert("count", cached.clone());
+    assert_eq!(other.eval("count").unwrap().as_number(), Some(1.0));
+    assert!(other.eval("not javascript").is_err());
+}
+
+#[test]
+fn code_cache_compares_sources() {
+    let mut cache = CodeCache::new();
+    let code = Context::new().compile("1").unwrap();
+    cache.insert("1", code.clone());
+    assert!(Rc::ptr_eq(&cache.get("1").unwrap(), &code));
+    assert!(cache.get("2").is_none());
+    assert!(cache.remove("2").is_none());
+    assert!(cache.remove("1").is_some());
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn code_cache_evicts_least_recently_used() {
+    let mut context = Context::new();
+    context.code_cache_mut().set_capacity(2);
+    context.compile("1").unwrap();
+    context.compile("2").unwrap();
+    // Using the first code block makes the second one the least recently used.
+    context.compile("1").unwrap();
+    context.compile("3").unwrap();
+
+    let cache = context.code_cache_mut();
+    assert_eq!(cache.len(), 2);
+    assert!(cache.get("1").is_some());
+    assert!(cache.get("2").is_none());
+    assert!(cache.get("3").is_some());
+
+    cache.set_capacity(0);
+    assert!(cache.is_empty());
+    context.compile("4").unwrap();
+    assert!(context.code_cache().is_empty());
+}
@@ -25,10 +25,14 @@
     clippy::as_conversions
 )]
 
-use boa::{syntax::ast::node::StatementList, Context};
+use boa::{builtins::date::TimeZone, syntax::ast::node::StatementList, Context};
 use colored::*;
 use rustyline::{config::Config, error::ReadlineError, EditMode, Editor};
-use std::{fs::read, path::PathBuf};
+use std::{
+    fs::read,
+    io::{Error, ErrorKind},
+    path::PathBuf,
+};
 use structopt::{clap::arg_enum, StructOpt};
 
 mod helper;
@@ -74,6 +78,11 @@ struct Opt {
     /// Use vi mode in the REPL
     #[structopt(long = "vi")]
     vi_mode: bool,
+
+    /// The time zone used for local time, like `Europe/Paris`. Defaults to the time zone of
+    /// the host.
+    #[structopt(long = "time-zone", value_name = "TZ")]
+    time_zone: Option<String>,
 }
 
 impl Opt {
@@ -143,7 +152,7 @@ fn dump<T: AsRef<[u8]>>(src: T, args: &Opt) -> Result<(), String> {
     Ok(())
 }
 
-pub fn main() -> Result<(), std::io::Error> {
+pub fn main() -> Result<(), Error> {
     let args = Opt::from_args();
 
     let mut context = Context::new();
@@ -152,6 +161,12 @@ pub fn main() -> Result<(), std::io::Error> {
     #[cfg(feature = "vm")]
     context.set_trace(args.trace);
 
+    if let Some(ref name) = args.time_zone {
+        let time_zone =
+            TimeZone::from_name(name).map_err(|err| Error::new(ErrorKind::InvalidInput, err))?;
+        context.set_time_zone(time_zone);
+    }
+
     for file in &args.files {
         let buffer = read(file)?;
 
@@ -164,6 +179,9 @@ pub fn main() -> Result<(), std::io::Error> {
                 Ok(v) => println!("{}", v.display()),
                 Err(v) => eprintln!("Uncaught {}", v.display()),
             }
+            while let Err(v) = context.run_jobs() {
+                eprintln!("Uncaught {}", v.display());
+            }
         }
     }
 
@@ -202,6 +220,9 @@ pub fn main() -> Result<(), std::io::Error> {
                                 eprintln!("{}: {}", "Uncaught".red(), v.display().to_string().red())
                             }
                         }
+                        while let Err(v) = context.run_jobs() {
+                            eprintln!("{}: {}", "Uncaught".red(), v.display().to_string().red())
+                        }
                     }
                 }
 
@@ -4,9 +4,9 @@ use super::{
     Harness, Outcome, Phase, SuiteResult, Test, TestFlags, TestOutcomeResult, TestResult,
     TestSuite, IGNORED,
 };
-use boa::{parse, Context, Value};
+use boa::{module::FsModuleLoader, parse, parse_module, Context, Value};
 use colored::Colorize;
-use std::panic;
+use std::{panic, path::Path};
 
 impl TestSuite {
     /// Runs the test suite.
@@ -86,7 +86,8 @@ impl Test {
     /// Runs the test.
     pub(crate) fn run(&self, harness: &Harness, verbose: u8) -> Vec<TestResult> {
         let mut results = Vec::new();
-        if self.flags.contains(TestFlags::STRICT) {
+        // Modules are always strict mode code.
+        if self.flags.contains(TestFlags::STRICT) || self.flags.contains(TestFlags::MODULE) {
             results.push(self.run_once(harness, true, verbose));
         }
 
@@ -110,35 +111,14 @@ impl Test {
         let (result, result_text) = if !IGNORED.contains_any_flag(self.flags)
             && !IGNORED.contains_test(&self.name)
             && !IGNORED.contains_any_feature(&self.features)
-            && (matches!(self.expected_outcome, Outcome::Positive)
-                || matches!(
-                    self.expected_outcome,
-                    Outcome::Negative {
-                        phase: Phase::Parse,
-                        error_type: _,
-                    }
-                )
-                || matches!(
-                    self.expected_outcome,
-                    Outcome::Negative {
-                        phase: Phase::Early,
-                        error_type: _,
-                    }
-                )
-                || matches!(
-                    self.expected_outcome,
-                    Outcome::Negative {
-                        phase: Phase::Runtime,
-                        error_type: _,
-                    }
-                )) {
+        {
             let res = panic::catch_unwind(|| match self.expected_outcome {
                 Outcome::Positive => {
                     // TODO: implement async and add `harness/doneprintHandle.js` to the includes.
 
                     match self.set_up_env(&harness, strict) {
                         Ok(mut context) => {
-                            let res = context.eval(&self.content.as_ref());
+                            let res = self.eval(&mut context);
 
                             let passed = res.is_ok();
                             let text = match res {
@@ -166,24 +146,24 @@ impl Test {
                         self.name
                     );
 
-                    match parse(&self.content.as_ref(), strict) {
-                        Ok(n) => (false, format!("{:?}", n)),
+                    match self.parse(strict) {
+                        Ok(n) => (false, n),
                         Err(e) => (true, format!("Uncaught {}", e)),
                     }
                 }
                 Outcome::Negative {
                     phase: Phase::Resolution,
-                    error_type: _,
-                } => todo!("check module resolution errors"),
-                Outcome::Negative {
+                    ref error_type,
+                }
+                | Outcome::Negative {
                     phase: Phase::Runtime,
                     ref error_type,
                 } => {
-                    if let Err(e) = parse(&self.content.as_ref(), strict) {
+                    if let Err(e) = self.parse(strict) {
                         (false, format!("Uncaught {}", e))
                     } else {
                         match self.set_up_env(&harness, strict) {
-                            Ok(mut context) => match context.eval(&self.content.as_ref()) {
+                            Ok(mut context) => match self.eval(&mut context) {
                                 Ok(res) => (false, format!("{}", res.display())),
                                 Err(e) => {
                                     let passed =
@@ -257,6 +237,33 @@ impl Test {
         }
     }
 
+    /// Parses the test, as a module if it has the `module` flag, returning the debug output of
+    /// the parsed code or the parsing error.
+    fn parse(&self, strict: bool) -> Result<String, String> {
+        if self.flags.contains(TestFlags::MODULE) {
+            parse_module(self.content.as_ref())
+                .map(|module| format!("{:?}", module))
+                .map_err(|e| e.to_string())
+        } else {
+            parse(self.content.as_ref(), strict)
+                .map(|statement_list| format!("{:?}", statement_list))
+                .map_err(|e| e.to_string())
+        }
+    }
+
+    /// Evaluates the test, as a module if it has the `module` flag.
+    ///
+    /// The modules imported by the test are loaded from the directory of the test.
+    fn eval(&self, context: &mut Context) -> boa::Result<Value> {
+        if self.flags.contains(TestFlags::MODULE) {
+            let directory = self.path.parent().unwrap_or_else(|| Path::new(""));
+            context.set_module_loader(FsModuleLoader::new(directory));
+            context.eval_module_source(&self.path.to_string_lossy(), self.content.as_ref())
+        } else {
+            context.eval(self.content.as_ref())
+        }
+    }
+
     /// Sets the environment up to run the test.
     fn set_up_env(&self, harness: &Harness, strict: bool) -> Result<Context, String> {
         // Create new Realm
@@ -274,7 +281,7 @@ impl Test {
             })?;
         // TODO: add the $262 object.
 
-        if strict {
+        if strict && !self.flags.contains(TestFlags::MODULE) {
             context
                 .eval(r#""use strict";"#)
                 .map_err(|e| format!("could not set strict mode:\n{}", e.display()))?;
@@ -352,15 +352,17 @@ struct Test {
     expected_outcome: Outcome,
     includes: Box<[Box<str>]>,
     locale: Locale,
+    path: PathBuf,
     content: Box<str>,
 }
 
 impl Test {
     /// Creates a new test.
     #[inline]
-    fn new<N, C>(name: N, content: C, metadata: MetaData) -> Self
+    fn new<N, P, C>(name: N, path: P, content: C, metadata: MetaData) -> Self
     where
         N: Into<Box<str>>,
+        P: Into<PathBuf>,
         C: Into<Box<str>>,
     {
         Self {
@@ -373,6 +375,7 @@ impl Test {
             expected_outcome: Outcome::from(metadata.negative),
             includes: metadata.includes,
             locale: metadata.locale,
+            path: path.into(),
             content: content.into(),
         }
     }
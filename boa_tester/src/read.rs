@@ -167,7 +167,7 @@ pub(super) fn read_test(path: &Path) -> io::Result<Test> {
     let content = fs::read_to_string(path)?;
     let metadata = read_metadata(&content)?;
 
-    Ok(Test::new(name, content, metadata))
+    Ok(Test::new(name, path, content, metadata))
 }
 
 /// Reads the metadata from the input test code.
@@ -1,6 +1,7 @@
 use boa::{exec::Executable, parse, Context};
 use wasm_bindgen::prelude::*;
 
+/// Evaluates the given source code and returns the displayed result.
 #[wasm_bindgen]
 pub fn evaluate(src: &str) -> Result<String, JsValue> {
     // Setup executor
@@ -19,7 +20,10 @@ pub fn evaluate(src: &str) -> Result<String, JsValue> {
             .into());
         }
     };
-    expr.run(&mut context)
+    let result = expr.run(&mut context);
+    let jobs = context.run_jobs();
+    result
+        .and_then(|v| jobs.map(|_| v))
         .map_err(|e| JsValue::from(format!("Uncaught {}", e.display())))
         .map(|v| v.display().to_string())
 }